@@ -1,28 +1,341 @@
 use crate::{
-    exchange::{binance::futures::BinanceFuturesUsd, coinbase::Coinbase, ftx::Ftx, kraken::Kraken},
-    model::subscription::{SubKind, Subscription},
-    ExchangeId, ExchangeWsStream, MarketEvent, MarketStream,
+    candles,
+    exchange::{
+        binance::us::BinanceUs,
+        binance::{
+            futures::BinanceFuturesUsd,
+            futures_coin::BinanceFuturesCoin,
+            managed, open_interest,
+            sandbox::{BinanceFuturesCoinSandbox, BinanceFuturesUsdSandbox},
+            sbe::BinanceSbe,
+            status, user_data,
+        },
+        bitfinex::Bitfinex,
+        bitget::Bitget,
+        bithumb::Bithumb,
+        bitmex::Bitmex,
+        bitstamp::Bitstamp,
+        bybit::{futures::BybitFuturesUsd, spot::Bybit},
+        coinbase::{sandbox::CoinbaseSandbox, Coinbase},
+        coinbase_advanced::CoinbaseAdvanced,
+        cryptocom::Cryptocom,
+        deribit::Deribit,
+        dydx::Dydx,
+        ftx::Ftx,
+        gateio::Gateio,
+        gemini::Gemini,
+        hyperliquid::Hyperliquid,
+        kraken::l3::KrakenL3,
+        kraken::Kraken,
+        kraken_futures::KrakenFutures,
+        kucoin::Kucoin,
+        mexc::Mexc,
+        okx::sandbox::OkxSandbox,
+        okx::Okx,
+        poloniex::Poloniex,
+        upbit::Upbit,
+    },
+    model::subscription::{SubKind, Subscription, SubscriptionPattern},
+    orderbook::{ChecksumPolicy, ChecksumValidation},
+    parallel::ParallelWsStream,
+    raw::RawWsStream,
+    retry::{Exponential, RetryPolicy},
+    rollover::{self, RollingFutures},
+    ExchangeId, ExchangeTransformer, ExchangeWsStream, MarketEvent, MarketStream, Subscriber,
 };
 use barter_integration::{
     error::SocketError,
-    model::{InstrumentKind, Symbol},
-    Event, Validator,
+    model::{Instrument, InstrumentKind, Symbol},
+    Event, Transformer, Validator,
 };
 use futures::{stream::Map, StreamExt};
-use std::{collections::HashMap, time::Duration};
-use tokio::sync::mpsc;
+use std::{
+    collections::{HashMap, HashSet},
+    future::Future,
+    sync::{Arc, RwLock},
+    time::Duration,
+};
+use tokio::{sync::mpsc, task::JoinHandle};
 use tokio_stream::{wrappers::UnboundedReceiverStream, StreamMap};
 use tracing::{error, info, warn};
 
 /// Initial duration that the [`consume`] function should wait before attempting to re-initialise
-/// a [`MarketStream`]. This duration will increase exponentially as a result of repeated
-/// disconnections with re-initialisation failures.
+/// a [`MarketStream`] when using the default [`RetryPolicy`]. This duration will increase
+/// exponentially as a result of repeated disconnections with re-initialisation failures.
 const STARTING_RECONNECT_BACKOFF_MS: u64 = 125;
 
+/// Maximum duration that the [`consume`] function should wait before attempting to re-initialise
+/// a [`MarketStream`] when using the default [`RetryPolicy`].
+const MAX_RECONNECT_BACKOFF_MS: u64 = 30_000;
+
+/// Default [`RetryPolicy`] used by [`consume`] for exchanges without a configured
+/// [`RetryPolicy`]: exponential backoff with jitter, retrying forever.
+pub fn default_retry_policy() -> Arc<dyn RetryPolicy> {
+    Arc::new(Exponential {
+        initial: Duration::from_millis(STARTING_RECONNECT_BACKOFF_MS),
+        max: Duration::from_millis(MAX_RECONNECT_BACKOFF_MS),
+        jitter: true,
+    })
+}
+
+/// [`MarketStream`] initialised for each exchange by [`StreamBuilder::init`].
+#[cfg(not(feature = "profiling"))]
+type MarketWsStream<Exchange> = ExchangeWsStream<Exchange>;
+
+/// [`MarketStream`] initialised for each exchange by [`StreamBuilder::init`], recording the
+/// processing time of every message.
+#[cfg(feature = "profiling")]
+type MarketWsStream<Exchange> = ExchangeWsStream<crate::profiling::Profiled<Exchange>>;
+
+/// [`MarketStream`] initialised for exchanges configured via [`StreamBuilder::raw`].
+#[cfg(not(feature = "profiling"))]
+type MarketRawStream<Exchange> = RawWsStream<Exchange>;
+
+/// [`MarketStream`] initialised for exchanges configured via [`StreamBuilder::raw`], recording
+/// the processing time of every message.
+#[cfg(feature = "profiling")]
+type MarketRawStream<Exchange> = RawWsStream<crate::profiling::Profiled<Exchange>>;
+
+/// Exchange transformer of the [`MarketStream`]s initialised by [`StreamBuilder::init`].
+#[cfg(not(feature = "profiling"))]
+type MarketExchange<Exchange> = Exchange;
+
+/// Exchange transformer of the [`MarketStream`]s initialised by [`StreamBuilder::init`],
+/// recording the processing time of every message.
+#[cfg(feature = "profiling")]
+type MarketExchange<Exchange> = crate::profiling::Profiled<Exchange>;
+
+/// [`MarketStream`] initialised for exchanges configured via [`StreamBuilder::parallel`].
+type MarketParallelStream<Exchange> = ParallelWsStream<MarketExchange<Exchange>>;
+
+/// Kind of [`MarketStream`] initialised for an exchange by [`StreamBuilder::init`].
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+enum StreamMode {
+    /// [`MarketWsStream`].
+    Ws,
+    /// [`MarketRawStream`], configured via [`StreamBuilder::raw`].
+    Raw,
+    /// [`MarketParallelStream`] parsing frames with the contained number of workers, configured
+    /// via [`StreamBuilder::parallel`].
+    Parallel(usize),
+}
+
+/// Spawn a [`consume`] loop for the provided exchange, utilising the [`MarketStream`] of the
+/// provided [`StreamMode`].
+fn spawn_consumer<Exchange>(
+    mode: StreamMode,
+    exchange: ExchangeId,
+    subscriptions: Vec<Subscription>,
+    exchange_tx: mpsc::UnboundedSender<Event<MarketEvent>>,
+    control: SubscriptionControl,
+    retry: Arc<dyn RetryPolicy>,
+) -> JoinHandle<SocketError>
+where
+    Exchange: ExchangeTransformer,
+    MarketWsStream<Exchange>: MarketStream + Send + 'static,
+    MarketRawStream<Exchange>: MarketStream + Send + 'static,
+    MarketExchange<Exchange>: Subscriber + ExchangeTransformer + Send + Unpin + 'static,
+    <MarketExchange<Exchange> as Transformer<MarketEvent>>::Input: Send + 'static,
+{
+    match mode {
+        StreamMode::Ws => tokio::spawn(consume::<MarketWsStream<Exchange>>(
+            exchange,
+            subscriptions,
+            exchange_tx,
+            control,
+            retry,
+        )),
+        StreamMode::Raw => tokio::spawn(consume::<MarketRawStream<Exchange>>(
+            exchange,
+            subscriptions,
+            exchange_tx,
+            control,
+            retry,
+        )),
+        StreamMode::Parallel(workers) => tokio::spawn(consume_with(
+            exchange,
+            subscriptions,
+            move |subscriptions| async move {
+                MarketParallelStream::<Exchange>::init_with_workers(&subscriptions, workers).await
+            },
+            exchange_tx,
+            control,
+            retry,
+        )),
+    }
+}
+
+/// [`spawn_consumer`] for exchanges maintaining checksummed order books, configuring the provided
+/// [`ChecksumPolicy`] on every initialised [`MarketStream`].
+fn spawn_checksum_consumer<Exchange>(
+    mode: StreamMode,
+    exchange: ExchangeId,
+    subscriptions: Vec<Subscription>,
+    exchange_tx: mpsc::UnboundedSender<Event<MarketEvent>>,
+    control: SubscriptionControl,
+    retry: Arc<dyn RetryPolicy>,
+    policy: ChecksumPolicy,
+) where
+    Exchange: ExchangeTransformer,
+    MarketWsStream<Exchange>: MarketStream + ChecksumValidation + Send + 'static,
+    MarketRawStream<Exchange>: MarketStream + ChecksumValidation + Send + 'static,
+    MarketExchange<Exchange>:
+        Subscriber + ExchangeTransformer + ChecksumValidation + Send + Unpin + 'static,
+    <MarketExchange<Exchange> as Transformer<MarketEvent>>::Input: Send + 'static,
+{
+    match mode {
+        StreamMode::Ws => tokio::spawn(consume_with(
+            exchange,
+            subscriptions,
+            move |subscriptions| {
+                init_with_checksum_policy::<MarketWsStream<Exchange>>(subscriptions, policy)
+            },
+            exchange_tx,
+            control,
+            retry,
+        )),
+        StreamMode::Raw => tokio::spawn(consume_with(
+            exchange,
+            subscriptions,
+            move |subscriptions| {
+                init_with_checksum_policy::<MarketRawStream<Exchange>>(subscriptions, policy)
+            },
+            exchange_tx,
+            control,
+            retry,
+        )),
+        StreamMode::Parallel(workers) => tokio::spawn(consume_with(
+            exchange,
+            subscriptions,
+            move |subscriptions| async move {
+                let mut stream =
+                    MarketParallelStream::<Exchange>::init_with_workers(&subscriptions, workers)
+                        .await?;
+                stream.set_checksum_policy(policy);
+                Ok(stream)
+            },
+            exchange_tx,
+            control,
+            retry,
+        )),
+    };
+}
+
+/// Spawn a [`rollover::roll_futures`] task for the provided [`RollingFutures`], which spawns a
+/// [`spawn_consumer`] of the active & next dated future after every roll.
+fn spawn_roll_manager<Exchange>(
+    mode: StreamMode,
+    futures: RollingFutures,
+    exchange_tx: mpsc::UnboundedSender<Event<MarketEvent>>,
+    control: SubscriptionControl,
+    retry: Arc<dyn RetryPolicy>,
+) where
+    Exchange: ExchangeTransformer,
+    MarketWsStream<Exchange>: MarketStream + Send + 'static,
+    MarketRawStream<Exchange>: MarketStream + Send + 'static,
+    MarketExchange<Exchange>: Subscriber + ExchangeTransformer + Send + Unpin + 'static,
+    <MarketExchange<Exchange> as Transformer<MarketEvent>>::Input: Send + 'static,
+{
+    let exchange = futures.exchange;
+    tokio::spawn(rollover::roll_futures(
+        futures,
+        move |subscriptions, exchange_tx| {
+            spawn_consumer::<Exchange>(
+                mode,
+                exchange,
+                subscriptions,
+                exchange_tx,
+                SubscriptionControl::default(),
+                retry.clone(),
+            )
+        },
+        exchange_tx,
+        control,
+    ));
+}
+
+/// Initialise a [`MarketStream`] & configure the provided [`ChecksumPolicy`].
+async fn init_with_checksum_policy<Stream>(
+    subscriptions: Vec<Subscription>,
+    policy: ChecksumPolicy,
+) -> Result<Stream, SocketError>
+where
+    Stream: MarketStream + ChecksumValidation,
+{
+    let mut stream = Stream::init(&subscriptions).await?;
+    stream.set_checksum_policy(policy);
+    Ok(stream)
+}
+
+/// Spawn a [`consume_with`] loop maintaining the `Binance` diff depth books of every
+/// [`SubKind::OrderBookL2Managed`] [`Subscription`] provided (see
+/// [`managed::init_managed_order_books`]), returning the remaining [`Subscription`]s.
+fn spawn_managed_order_books(
+    exchange: ExchangeId,
+    subscriptions: Vec<Subscription>,
+    exchange_tx: &mpsc::UnboundedSender<Event<MarketEvent>>,
+    control: &SubscriptionControl,
+    retry: &Arc<dyn RetryPolicy>,
+) -> Vec<Subscription> {
+    let (managed_subscriptions, subscriptions): (Vec<_>, Vec<_>) = subscriptions
+        .into_iter()
+        .partition(|subscription| subscription.kind == SubKind::OrderBookL2Managed);
+
+    if !managed_subscriptions.is_empty() {
+        tokio::spawn(consume_with(
+            exchange,
+            managed_subscriptions,
+            move |subscriptions| async move {
+                managed::init_managed_order_books(exchange, &subscriptions).await
+            },
+            exchange_tx.clone(),
+            control.clone(),
+            retry.clone(),
+        ));
+    }
+
+    subscriptions
+}
+
+/// Spawn a [`consume_with`] loop distributing the account updates of every [`SubKind::UserData`]
+/// [`Subscription`] provided (see [`user_data::init_user_data`]), authenticated with the
+/// provided API key, returning the remaining [`Subscription`]s.
+fn spawn_user_data(
+    exchange: ExchangeId,
+    subscriptions: Vec<Subscription>,
+    api_key: Option<&String>,
+    exchange_tx: &mpsc::UnboundedSender<Event<MarketEvent>>,
+    control: &SubscriptionControl,
+    retry: &Arc<dyn RetryPolicy>,
+) -> Vec<Subscription> {
+    let (user_data_subscriptions, subscriptions): (Vec<_>, Vec<_>) = subscriptions
+        .into_iter()
+        .partition(|subscription| subscription.kind == SubKind::UserData);
+
+    // UserData Subscriptions are validated to have an API key configured via user_data(..)
+    if !user_data_subscriptions.is_empty() {
+        let api_key = api_key.cloned().unwrap_or_default();
+        tokio::spawn(consume_with(
+            exchange,
+            user_data_subscriptions,
+            move |subscriptions| {
+                let api_key = api_key.clone();
+                async move { user_data::init_user_data(exchange, &api_key, &subscriptions).await }
+            },
+            exchange_tx.clone(),
+            control.clone(),
+            retry.clone(),
+        ));
+    }
+
+    subscriptions
+}
+
 /// Collection of exchange [`MarketEvent`] streams.
 #[derive(Debug)]
 pub struct Streams {
     pub streams: HashMap<ExchangeId, mpsc::UnboundedReceiver<Event<MarketEvent>>>,
+    pub control: SubscriptionControl,
 }
 
 impl Streams {
@@ -31,6 +344,30 @@ impl Streams {
         StreamBuilder::new()
     }
 
+    /// Stop delivering [`MarketEvent`]s for the provided [`Subscription`] until it is resumed.
+    /// See [`SubscriptionControl::pause`].
+    pub fn pause(&self, subscription: &Subscription) -> bool {
+        self.control.pause(subscription)
+    }
+
+    /// Resume delivering [`MarketEvent`]s for the provided paused [`Subscription`].
+    /// See [`SubscriptionControl::resume`].
+    pub fn resume(&self, subscription: &Subscription) -> bool {
+        self.control.resume(subscription)
+    }
+
+    /// Stop delivering [`MarketEvent`]s for every [`Subscription`] in the provided named group
+    /// until it is resumed. See [`SubscriptionControl::pause_group`].
+    pub fn pause_group(&self, group: &str) -> bool {
+        self.control.pause_group(group)
+    }
+
+    /// Resume delivering [`MarketEvent`]s for every [`Subscription`] in the provided named group.
+    /// See [`SubscriptionControl::resume_group`].
+    pub fn resume_group(&self, group: &str) -> bool {
+        self.control.resume_group(group)
+    }
+
     /// Remove an exchange [`MarketEvent`] stream from the [`Streams`] `HashMap`.
     pub fn select(
         &mut self,
@@ -77,10 +414,148 @@ impl Streams {
     }
 }
 
+/// Shared handle used to pause & resume the delivery of [`MarketEvent`]s for individual
+/// [`Subscription`]s, allowing consumers to temporarily shed load.
+///
+/// Paused [`Subscription`]s remain actioned with the exchange, and their messages are still
+/// parsed & transformed, so stateful transformers (eg/ those validating OrderBook sequence
+/// continuity) remain consistent & delivery resumes immediately without re-subscribing.
+///
+/// [`Subscription`]s added via [`StreamBuilder::subscribe_group`] can also be paused & resumed
+/// collectively by group name.
+#[derive(Clone, Debug, Default)]
+pub struct SubscriptionControl {
+    paused: Arc<RwLock<PausedSubscriptions>>,
+    groups: Arc<HashMap<String, Vec<Subscription>>>,
+}
+
+/// Paused [`SubKind`]s indexed by [`ExchangeId`] & [`Instrument`], so determining if a
+/// [`MarketEvent`] is paused does not require a scan of every paused [`Subscription`].
+type PausedSubscriptions = HashMap<ExchangeId, HashMap<Instrument, HashSet<SubKind>>>;
+
+impl SubscriptionControl {
+    /// Pause the delivery of [`MarketEvent`]s for the provided [`Subscription`]. Returns `false`
+    /// if the [`Subscription`] was already paused.
+    pub fn pause(&self, subscription: &Subscription) -> bool {
+        let mut paused = self
+            .paused
+            .write()
+            .expect("SubscriptionControl lock poisoned");
+        insert_paused(&mut paused, subscription)
+    }
+
+    /// Resume the delivery of [`MarketEvent`]s for the provided [`Subscription`]. Returns `false`
+    /// if the [`Subscription`] was not paused.
+    pub fn resume(&self, subscription: &Subscription) -> bool {
+        let mut paused = self
+            .paused
+            .write()
+            .expect("SubscriptionControl lock poisoned");
+        remove_paused(&mut paused, subscription)
+    }
+
+    /// Pause the delivery of [`MarketEvent`]s for every [`Subscription`] in the provided named
+    /// group atomically. Returns `false` if the group does not exist.
+    pub fn pause_group(&self, group: &str) -> bool {
+        let subscriptions = match self.groups.get(group) {
+            Some(subscriptions) => subscriptions,
+            None => return false,
+        };
+
+        let mut paused = self
+            .paused
+            .write()
+            .expect("SubscriptionControl lock poisoned");
+        subscriptions.iter().for_each(|subscription| {
+            insert_paused(&mut paused, subscription);
+        });
+        true
+    }
+
+    /// Resume the delivery of [`MarketEvent`]s for every [`Subscription`] in the provided named
+    /// group atomically. Returns `false` if the group does not exist.
+    pub fn resume_group(&self, group: &str) -> bool {
+        let subscriptions = match self.groups.get(group) {
+            Some(subscriptions) => subscriptions,
+            None => return false,
+        };
+
+        let mut paused = self
+            .paused
+            .write()
+            .expect("SubscriptionControl lock poisoned");
+        subscriptions.iter().for_each(|subscription| {
+            remove_paused(&mut paused, subscription);
+        });
+        true
+    }
+
+    /// Return the [`Subscription`]s of the provided named group, if it exists.
+    pub fn group(&self, group: &str) -> Option<&[Subscription]> {
+        self.groups.get(group).map(Vec::as_slice)
+    }
+
+    /// Determines if the provided exchange [`MarketEvent`] was produced by a paused
+    /// [`Subscription`].
+    pub fn is_paused(&self, exchange: ExchangeId, event: &MarketEvent) -> bool {
+        let paused = self
+            .paused
+            .read()
+            .expect("SubscriptionControl lock poisoned");
+        paused
+            .get(&exchange)
+            .and_then(|instruments| instruments.get(&event.instrument))
+            .is_some_and(|kinds| kinds.iter().any(|kind| kind.produces(&event.kind)))
+    }
+}
+
+/// Insert the provided [`Subscription`] into the [`PausedSubscriptions`]. Returns `false` if the
+/// [`Subscription`] was already paused.
+fn insert_paused(paused: &mut PausedSubscriptions, subscription: &Subscription) -> bool {
+    paused
+        .entry(subscription.exchange)
+        .or_default()
+        .entry(subscription.instrument.clone())
+        .or_default()
+        .insert(subscription.kind)
+}
+
+/// Remove the provided [`Subscription`] from the [`PausedSubscriptions`], dropping any emptied
+/// index entries. Returns `false` if the [`Subscription`] was not paused.
+fn remove_paused(paused: &mut PausedSubscriptions, subscription: &Subscription) -> bool {
+    let instruments = match paused.get_mut(&subscription.exchange) {
+        Some(instruments) => instruments,
+        None => return false,
+    };
+    let kinds = match instruments.get_mut(&subscription.instrument) {
+        Some(kinds) => kinds,
+        None => return false,
+    };
+
+    let removed = kinds.remove(&subscription.kind);
+    if kinds.is_empty() {
+        instruments.remove(&subscription.instrument);
+    }
+    if instruments.is_empty() {
+        paused.remove(&subscription.exchange);
+    }
+    removed
+}
+
 /// Builder to configure and initialise [`Streams`] instances.
 #[derive(Debug)]
 pub struct StreamBuilder {
     pub exchange_subscriptions: HashMap<ExchangeId, Vec<Subscription>>,
+    pub groups: HashMap<String, Vec<Subscription>>,
+    pub patterns: Vec<SubscriptionPattern>,
+    pub retry_policies: HashMap<ExchangeId, Arc<dyn RetryPolicy>>,
+    pub rolling: Vec<RollingFutures>,
+    pub raw: HashSet<ExchangeId>,
+    pub parallel: HashMap<ExchangeId, usize>,
+    pub sandbox: HashSet<ExchangeId>,
+    pub binance_sbe: Option<String>,
+    pub user_data: HashMap<ExchangeId, String>,
+    pub checksum_policy: ChecksumPolicy,
 }
 
 impl StreamBuilder {
@@ -88,6 +563,16 @@ impl StreamBuilder {
     fn new() -> Self {
         Self {
             exchange_subscriptions: HashMap::new(),
+            groups: HashMap::new(),
+            patterns: Vec::new(),
+            retry_policies: HashMap::new(),
+            rolling: Vec::new(),
+            raw: HashSet::new(),
+            parallel: HashMap::new(),
+            sandbox: HashSet::new(),
+            binance_sbe: None,
+            user_data: HashMap::new(),
+            checksum_policy: ChecksumPolicy::default(),
         }
     }
 
@@ -112,6 +597,203 @@ impl StreamBuilder {
         self
     }
 
+    /// Add a collection of [`Subscription`]s to the [`StreamBuilder`] as part of a named group
+    /// (eg/ "majors"), which can later be paused & resumed collectively via the
+    /// [`SubscriptionControl`] of the initialised [`Streams`]. Note that the provided
+    /// [`Subscription`]s are not actioned until the [`init()`](StreamBuilder::init()) method
+    /// is invoked.
+    pub fn subscribe_group<SubIter, Sub>(
+        mut self,
+        group: impl Into<String>,
+        subscriptions: SubIter,
+    ) -> Self
+    where
+        SubIter: IntoIterator<Item = Sub>,
+        Sub: Into<Subscription>,
+    {
+        let subscriptions = subscriptions
+            .into_iter()
+            .map(Sub::into)
+            .collect::<Vec<Subscription>>();
+
+        self.groups
+            .entry(group.into())
+            .or_default()
+            .extend(subscriptions.iter().cloned());
+
+        self.subscribe(subscriptions)
+    }
+
+    /// Add a [`Subscription`] of the provided [`SubKind`] for both the
+    /// [`InstrumentKind::Spot`] & [`InstrumentKind::FuturePerpetual`] instruments of a base &
+    /// quote pair to each provided exchange, enabling basis monitoring. Exchanges only listing
+    /// one of the [`InstrumentKind`]s (eg/ `BinanceFuturesUsd`) are only subscribed to that
+    /// [`InstrumentKind`]. Every [`MarketEvent`] is tagged with the [`InstrumentKind`] of it's
+    /// [`Instrument`](barter_integration::model::Instrument).
+    ///
+    /// eg/ `subscribe_spot_and_perpetual([Okx, Bybit, BybitFuturesUsd], "btc", "usdt", Trade)`
+    pub fn subscribe_spot_and_perpetual<ExchangeIter, S>(
+        self,
+        exchanges: ExchangeIter,
+        base: S,
+        quote: S,
+        kind: SubKind,
+    ) -> Self
+    where
+        ExchangeIter: IntoIterator<Item = ExchangeId>,
+        S: Into<Symbol>,
+    {
+        let (base, quote) = (base.into(), quote.into());
+
+        let subscriptions = exchanges
+            .into_iter()
+            .flat_map(|exchange| {
+                [
+                    (InstrumentKind::Spot, exchange.supports_spot()),
+                    (InstrumentKind::FuturePerpetual, exchange.supports_futures()),
+                ]
+                .into_iter()
+                .filter(|(_, supported)| *supported)
+                .map(move |(instrument_kind, _)| (exchange, instrument_kind))
+            })
+            .map(|(exchange, instrument_kind)| {
+                Subscription::from((exchange, base.clone(), quote.clone(), instrument_kind, kind))
+            })
+            .collect::<Vec<_>>();
+
+        self.subscribe(subscriptions)
+    }
+
+    /// Attach the original exchange payload to every [`MarketEvent`] produced by the provided
+    /// exchange's [`MarketStream`] (see [`MarketEvent::raw`]), utilising a [`RawWsStream`].
+    pub fn raw(mut self, exchange: ExchangeId) -> Self {
+        self.raw.insert(exchange);
+        self
+    }
+
+    /// Parse the provided exchange's WebSocket frames with a pool of the provided number of
+    /// workers, utilising a [`ParallelWsStream`]. Suited to very high-rate connections where
+    /// deserialisation on a single core cannot keep up.
+    pub fn parallel(mut self, exchange: ExchangeId, workers: usize) -> Self {
+        self.parallel.insert(exchange, workers);
+        self
+    }
+
+    /// Connect the provided exchange's [`MarketStream`] to the exchange sandbox environment
+    /// (see [`ExchangeId::sandbox_ws_base_url`]) rather than production. Emitted [`MarketEvent`]s
+    /// remain tagged with the provided [`ExchangeId`].
+    pub fn sandbox(mut self, exchange: ExchangeId) -> Self {
+        self.sandbox.insert(exchange);
+        self
+    }
+
+    /// Consume [`ExchangeId::Binance`] trades & L2 deltas via the SBE market data stream rather
+    /// than JSON (see [`BinanceSbe`]), authenticating with the provided API key.
+    pub fn binance_sbe(mut self, api_key: impl Into<String>) -> Self {
+        self.binance_sbe = Some(api_key.into());
+        self
+    }
+
+    /// Authenticate the provided exchange's [`SubKind::UserData`] [`Subscription`]s with the
+    /// provided API key (see [`user_data::init_user_data`]).
+    pub fn user_data(mut self, exchange: ExchangeId, api_key: impl Into<String>) -> Self {
+        self.user_data.insert(exchange, api_key.into());
+        self
+    }
+
+    /// Configure the [`ChecksumPolicy`] applied by [`ExchangeId::Okx`] streams when the checksum
+    /// of a maintained [`SubKind::OrderBookL2Managed`] order book does not match the exchange
+    /// checksum. Defaults to [`ChecksumPolicy::Resync`].
+    pub fn checksum_policy(mut self, policy: ChecksumPolicy) -> Self {
+        self.checksum_policy = policy;
+        self
+    }
+
+    /// Configure the [`RetryPolicy`] used to re-connect the provided exchange's [`MarketStream`].
+    /// Exchanges without a configured [`RetryPolicy`] use the [`default_retry_policy`].
+    pub fn retry_policy<Policy>(mut self, exchange: ExchangeId, policy: Policy) -> Self
+    where
+        Policy: RetryPolicy + 'static,
+    {
+        self.retry_policies.insert(exchange, Arc::new(policy));
+        self
+    }
+
+    /// Add a collection of [`SubscriptionPattern`]s to the [`StreamBuilder`] (eg/ every `*-USDT`
+    /// perpetual). Note that the provided [`SubscriptionPattern`]s are expanded into
+    /// [`Subscription`]s using the [`Instrument`](barter_integration::model::Instrument)s
+    /// discovered from each exchange when the [`init()`](StreamBuilder::init()) method is invoked.
+    ///
+    /// Scope limitations:
+    ///  - Patterns are expanded once during [`init()`](StreamBuilder::init()), so instruments
+    ///    listed afterwards are not subscribed to.
+    ///  - Only the `*` glob is supported (ie/ no regular expressions).
+    ///  - Only exchanges providing an `exchangeInfo` instrument discovery endpoint are supported
+    ///    (ie/ [`ExchangeId::BinanceFuturesUsd`] & [`ExchangeId::BinanceFuturesCoin`] perpetuals),
+    ///    any other exchange fails [`init()`](StreamBuilder::init()) with a [`SocketError`].
+    pub fn subscribe_pattern<PatternIter, Pattern>(mut self, patterns: PatternIter) -> Self
+    where
+        PatternIter: IntoIterator<Item = Pattern>,
+        Pattern: Into<SubscriptionPattern>,
+    {
+        self.patterns
+            .extend(patterns.into_iter().map(Pattern::into));
+        self
+    }
+
+    /// Subscribe to the provided [`RollingFutures`], rolling from the active dated future to the
+    /// next according to it's [`RollRule`](rollover::RollRule). [`MarketEvent`]s, including a
+    /// [`Rollover`](crate::model::Rollover) after every roll, are distributed via the stream of
+    /// the [`RollingFutures`] exchange.
+    pub fn subscribe_rolling(mut self, futures: RollingFutures) -> Self {
+        self.rolling.push(futures);
+        self
+    }
+
+    /// Determine the [`StreamMode`] of the provided exchange's [`MarketStream`].
+    fn stream_mode(&self, exchange: ExchangeId) -> StreamMode {
+        match (self.raw.contains(&exchange), self.parallel.get(&exchange)) {
+            (true, _) => StreamMode::Raw,
+            (false, Some(workers)) => StreamMode::Parallel(*workers),
+            (false, None) => StreamMode::Ws,
+        }
+    }
+
+    /// Expand every [`SubscriptionPattern`] into [`Subscription`]s using the perpetual
+    /// instruments discovered from each exchange's `exchangeInfo` endpoint. This is performed
+    /// once, so newly listed instruments are not picked up until the stream is re-initialised.
+    async fn expand_patterns(mut self) -> Result<Self, SocketError> {
+        for pattern in std::mem::take(&mut self.patterns) {
+            let instruments = match status::exchange_info_url(pattern.exchange) {
+                Some(url) => status::fetch_exchange_info(&url)
+                    .await
+                    .map(|info| status::perpetual_instruments(&info))
+                    .map_err(|error| {
+                        SocketError::Subscribe(format!(
+                            "failed to discover {} instruments: {error}",
+                            pattern.exchange
+                        ))
+                    })?,
+                None => {
+                    return Err(SocketError::Unsupported {
+                        entity: pattern.exchange.as_str(),
+                        item: "instrument discovery".to_owned(),
+                    })
+                }
+            };
+
+            let subscriptions = pattern.expand(&instruments);
+            info!(
+                ?pattern,
+                matched = subscriptions.len(),
+                "expanded SubscriptionPattern"
+            );
+            self = self.subscribe(subscriptions);
+        }
+
+        Ok(self)
+    }
+
     /// Add a set of [`Subscription`]s for an exchange to the [`StreamBuilder`]. Note that the
     /// provided [`Subscription`]s are not actioned until the [`init()`](StreamBuilder::init())
     /// method is invoked.
@@ -144,14 +826,20 @@ impl StreamBuilder {
     /// Spawn a [`MarketEvent`] consumer loop for each exchange. Each consumer loop distributes
     /// consumed [`MarketEvent`]s to the [`Streams`] `HashMap` (returned by this method).
     pub async fn init(mut self) -> Result<Streams, SocketError> {
-        // Validate Subscriptions provided to the StreamBuilder are supported
-        self = self.validate()?;
+        // Expand SubscriptionPatterns & validate Subscriptions provided are supported
+        self = self.expand_patterns().await?.validate()?;
 
         // Construct HashMap containing a stream receiver for each ExchangeId
         let num_exchanges = self.exchange_subscriptions.len();
         let mut exchange_streams = HashMap::with_capacity(num_exchanges);
+        let control = SubscriptionControl {
+            groups: Arc::new(std::mem::take(&mut self.groups)),
+            ..SubscriptionControl::default()
+        };
 
-        for (exchange, mut subscriptions) in self.exchange_subscriptions {
+        let mut rolling_txs = HashMap::new();
+
+        for (exchange, mut subscriptions) in std::mem::take(&mut self.exchange_subscriptions) {
             // Remove duplicate Subscriptions for this ExchangeId
             subscriptions.sort();
             subscriptions.dedup();
@@ -159,41 +847,486 @@ impl StreamBuilder {
             // Create channel for this ExchangeId stream
             let (exchange_tx, exchange_rx) = mpsc::unbounded_channel();
 
+            // Retain a transmitter to this ExchangeId stream for any of it's RollingFutures
+            if self
+                .rolling
+                .iter()
+                .any(|futures| futures.exchange == exchange)
+            {
+                rolling_txs.insert(exchange, exchange_tx.clone());
+            }
+
+            // Determine the RetryPolicy used to re-connect this ExchangeId stream
+            let retry = self
+                .retry_policies
+                .get(&exchange)
+                .cloned()
+                .unwrap_or_else(default_retry_policy);
+
+            // Determine the kind of MarketStream initialised for this ExchangeId stream
+            let mode = self.stream_mode(exchange);
+
+            // Determine if this ExchangeId stream connects to the exchange sandbox environment
+            let sandbox = self.sandbox.contains(&exchange);
+
+            // Candles are aggregated from trades for exchanges without a kline channel. The
+            // aggregation task applies the SubscriptionControl to the user-visible Subscriptions, so
+            // the consumer is given a control that never pauses the injected trade Subscriptions
+            let (subscriptions, exchange_tx, control) = if exchange.supports_trade_candles()
+                && subscriptions
+                    .iter()
+                    .any(|subscription| matches!(subscription.kind, SubKind::Candle(_)))
+            {
+                let (candle_subscriptions, mut subscriptions): (Vec<_>, Vec<_>) = subscriptions
+                    .into_iter()
+                    .partition(|subscription| matches!(subscription.kind, SubKind::Candle(_)));
+
+                let aggregated = subscriptions
+                    .iter()
+                    .cloned()
+                    .chain(candle_subscriptions)
+                    .collect::<Vec<_>>();
+
+                subscriptions.extend(
+                    aggregated
+                        .iter()
+                        .filter(|subscription| matches!(subscription.kind, SubKind::Candle(_)))
+                        .map(|subscription| Subscription {
+                            kind: SubKind::Trade,
+                            ..subscription.clone()
+                        }),
+                );
+                subscriptions.sort();
+                subscriptions.dedup();
+
+                let (trade_tx, trade_rx) = mpsc::unbounded_channel();
+                tokio::spawn(candles::aggregate_trade_candles(
+                    exchange,
+                    aggregated,
+                    trade_rx,
+                    exchange_tx,
+                    control.clone(),
+                ));
+
+                (subscriptions, trade_tx, SubscriptionControl::default())
+            } else {
+                (subscriptions, exchange_tx, control.clone())
+            };
+
             // Spawn a MarketStream consumer loop with this exchange's Subscriptions
             match exchange {
                 ExchangeId::BinanceFuturesUsd => {
-                    tokio::spawn(consume::<ExchangeWsStream<BinanceFuturesUsd>>(
+                    // InstrumentStatus is polled via exchangeInfo rather than streamed
+                    let (status_subscriptions, subscriptions): (Vec<_>, Vec<_>) = subscriptions
+                        .into_iter()
+                        .partition(|subscription| subscription.kind == SubKind::InstrumentStatus);
+
+                    if !status_subscriptions.is_empty() {
+                        tokio::spawn(status::poll_instrument_status(
+                            exchange,
+                            status_subscriptions,
+                            exchange_tx.clone(),
+                            control.clone(),
+                        ));
+                    }
+
+                    // OpenInterest is polled via the openInterest endpoint rather than streamed
+                    let (open_interest_subscriptions, subscriptions): (Vec<_>, Vec<_>) =
+                        subscriptions
+                            .into_iter()
+                            .partition(|subscription| subscription.kind == SubKind::OpenInterest);
+
+                    if !open_interest_subscriptions.is_empty() {
+                        tokio::spawn(open_interest::poll_open_interest(
+                            exchange,
+                            open_interest_subscriptions,
+                            exchange_tx.clone(),
+                            control.clone(),
+                        ));
+                    }
+
+                    let subscriptions = spawn_user_data(
+                        exchange,
+                        subscriptions,
+                        self.user_data.get(&exchange),
+                        &exchange_tx,
+                        &control,
+                        &retry,
+                    );
+
+                    let subscriptions = spawn_managed_order_books(
+                        exchange,
+                        subscriptions,
+                        &exchange_tx,
+                        &control,
+                        &retry,
+                    );
+
+                    match (subscriptions.is_empty(), sandbox) {
+                        (true, _) => {}
+                        (false, true) => {
+                            spawn_consumer::<BinanceFuturesUsdSandbox>(
+                                mode,
+                                exchange,
+                                subscriptions,
+                                exchange_tx,
+                                control.clone(),
+                                retry,
+                            );
+                        }
+                        (false, false) => {
+                            spawn_consumer::<BinanceFuturesUsd>(
+                                mode,
+                                exchange,
+                                subscriptions,
+                                exchange_tx,
+                                control.clone(),
+                                retry,
+                            );
+                        }
+                    }
+                }
+                ExchangeId::Binance => {
+                    let subscriptions = spawn_user_data(
+                        exchange,
+                        subscriptions,
+                        self.user_data.get(&exchange),
+                        &exchange_tx,
+                        &control,
+                        &retry,
+                    );
+
+                    // Binance spot Subscriptions are validated to have binance_sbe configured
+                    if !subscriptions.is_empty() {
+                        let api_key = self.binance_sbe.clone().unwrap_or_default();
+                        tokio::spawn(consume_with(
+                            exchange,
+                            subscriptions,
+                            move |subscriptions| {
+                                let api_key = api_key.clone();
+                                async move { BinanceSbe::init(&api_key, &subscriptions).await }
+                            },
+                            exchange_tx,
+                            control.clone(),
+                            retry,
+                        ));
+                    }
+                }
+                ExchangeId::BinanceFuturesCoin => {
+                    let subscriptions = spawn_user_data(
+                        exchange,
+                        subscriptions,
+                        self.user_data.get(&exchange),
+                        &exchange_tx,
+                        &control,
+                        &retry,
+                    );
+
+                    match (subscriptions.is_empty(), sandbox) {
+                        (true, _) => {}
+                        (false, true) => {
+                            spawn_consumer::<BinanceFuturesCoinSandbox>(
+                                mode,
+                                exchange,
+                                subscriptions,
+                                exchange_tx,
+                                control.clone(),
+                                retry,
+                            );
+                        }
+                        (false, false) => {
+                            spawn_consumer::<BinanceFuturesCoin>(
+                                mode,
+                                exchange,
+                                subscriptions,
+                                exchange_tx,
+                                control.clone(),
+                                retry,
+                            );
+                        }
+                    }
+                }
+                ExchangeId::Bitstamp => {
+                    spawn_consumer::<Bitstamp>(
+                        mode,
+                        exchange,
+                        subscriptions,
+                        exchange_tx,
+                        control.clone(),
+                        retry,
+                    );
+                }
+                ExchangeId::Bybit => {
+                    spawn_consumer::<Bybit>(
+                        mode,
+                        exchange,
+                        subscriptions,
+                        exchange_tx,
+                        control.clone(),
+                        retry,
+                    );
+                }
+                ExchangeId::BybitFuturesUsd => {
+                    spawn_consumer::<BybitFuturesUsd>(
+                        mode,
+                        exchange,
+                        subscriptions,
+                        exchange_tx,
+                        control.clone(),
+                        retry,
+                    );
+                }
+                ExchangeId::Coinbase if sandbox => {
+                    spawn_consumer::<CoinbaseSandbox>(
+                        mode,
                         exchange,
                         subscriptions,
                         exchange_tx,
-                    ));
+                        control.clone(),
+                        retry,
+                    );
                 }
                 ExchangeId::Coinbase => {
-                    tokio::spawn(consume::<ExchangeWsStream<Coinbase>>(
+                    spawn_consumer::<Coinbase>(
+                        mode,
+                        exchange,
+                        subscriptions,
+                        exchange_tx,
+                        control.clone(),
+                        retry,
+                    );
+                }
+                ExchangeId::Deribit => {
+                    spawn_consumer::<Deribit>(
+                        mode,
                         exchange,
                         subscriptions,
                         exchange_tx,
-                    ));
+                        control.clone(),
+                        retry,
+                    );
                 }
                 ExchangeId::Ftx => {
-                    tokio::spawn(consume::<ExchangeWsStream<Ftx>>(
+                    spawn_consumer::<Ftx>(
+                        mode,
                         exchange,
                         subscriptions,
                         exchange_tx,
-                    ));
+                        control.clone(),
+                        retry,
+                    );
                 }
                 ExchangeId::Kraken => {
-                    tokio::spawn(consume::<ExchangeWsStream<Kraken>>(
+                    spawn_consumer::<Kraken>(
+                        mode,
+                        exchange,
+                        subscriptions,
+                        exchange_tx,
+                        control.clone(),
+                        retry,
+                    );
+                }
+                ExchangeId::Okx if sandbox => {
+                    spawn_checksum_consumer::<OkxSandbox>(
+                        mode,
+                        exchange,
+                        subscriptions,
+                        exchange_tx,
+                        control.clone(),
+                        retry,
+                        self.checksum_policy,
+                    );
+                }
+                ExchangeId::Okx => {
+                    spawn_checksum_consumer::<Okx>(
+                        mode,
+                        exchange,
+                        subscriptions,
+                        exchange_tx,
+                        control.clone(),
+                        retry,
+                        self.checksum_policy,
+                    );
+                }
+                ExchangeId::Kucoin => {
+                    spawn_consumer::<Kucoin>(
+                        mode,
+                        exchange,
+                        subscriptions,
+                        exchange_tx,
+                        control.clone(),
+                        retry,
+                    );
+                }
+                ExchangeId::Gateio => {
+                    spawn_consumer::<Gateio>(
+                        mode,
+                        exchange,
+                        subscriptions,
+                        exchange_tx,
+                        control.clone(),
+                        retry,
+                    );
+                }
+                ExchangeId::Bitmex => {
+                    spawn_consumer::<Bitmex>(
+                        mode,
+                        exchange,
+                        subscriptions,
+                        exchange_tx,
+                        control.clone(),
+                        retry,
+                    );
+                }
+                ExchangeId::Gemini => {
+                    spawn_consumer::<Gemini>(
+                        mode,
+                        exchange,
+                        subscriptions,
+                        exchange_tx,
+                        control.clone(),
+                        retry,
+                    );
+                }
+                ExchangeId::Cryptocom => {
+                    spawn_consumer::<Cryptocom>(
+                        mode,
+                        exchange,
+                        subscriptions,
+                        exchange_tx,
+                        control.clone(),
+                        retry,
+                    );
+                }
+                ExchangeId::Mexc => {
+                    spawn_consumer::<Mexc>(
+                        mode,
+                        exchange,
+                        subscriptions,
+                        exchange_tx,
+                        control.clone(),
+                        retry,
+                    );
+                }
+                ExchangeId::Bitget => {
+                    spawn_consumer::<Bitget>(
+                        mode,
+                        exchange,
+                        subscriptions,
+                        exchange_tx,
+                        control.clone(),
+                        retry,
+                    );
+                }
+                ExchangeId::Dydx => {
+                    spawn_consumer::<Dydx>(
+                        mode,
+                        exchange,
+                        subscriptions,
+                        exchange_tx,
+                        control.clone(),
+                        retry,
+                    );
+                }
+                ExchangeId::Hyperliquid => {
+                    spawn_consumer::<Hyperliquid>(
+                        mode,
+                        exchange,
+                        subscriptions,
+                        exchange_tx,
+                        control.clone(),
+                        retry,
+                    );
+                }
+                ExchangeId::CoinbaseAdvanced => {
+                    spawn_consumer::<CoinbaseAdvanced>(
+                        mode,
+                        exchange,
+                        subscriptions,
+                        exchange_tx,
+                        control.clone(),
+                        retry,
+                    );
+                }
+                ExchangeId::KrakenFutures => {
+                    spawn_consumer::<KrakenFutures>(
+                        mode,
+                        exchange,
+                        subscriptions,
+                        exchange_tx,
+                        control.clone(),
+                        retry,
+                    );
+                }
+                ExchangeId::Upbit => {
+                    spawn_consumer::<Upbit>(
+                        mode,
+                        exchange,
+                        subscriptions,
+                        exchange_tx,
+                        control.clone(),
+                        retry,
+                    );
+                }
+                ExchangeId::Bithumb => {
+                    spawn_consumer::<Bithumb>(
+                        mode,
+                        exchange,
+                        subscriptions,
+                        exchange_tx,
+                        control.clone(),
+                        retry,
+                    );
+                }
+                ExchangeId::Poloniex => {
+                    spawn_consumer::<Poloniex>(
+                        mode,
+                        exchange,
+                        subscriptions,
+                        exchange_tx,
+                        control.clone(),
+                        retry,
+                    );
+                }
+                ExchangeId::Bitfinex => {
+                    spawn_consumer::<Bitfinex>(
+                        mode,
                         exchange,
                         subscriptions,
                         exchange_tx,
-                    ));
+                        control.clone(),
+                        retry,
+                    );
+                }
+                ExchangeId::BinanceUs => {
+                    let subscriptions = spawn_managed_order_books(
+                        exchange,
+                        subscriptions,
+                        &exchange_tx,
+                        &control,
+                        &retry,
+                    );
+
+                    if !subscriptions.is_empty() {
+                        spawn_consumer::<BinanceUs>(
+                            mode,
+                            exchange,
+                            subscriptions,
+                            exchange_tx,
+                            control.clone(),
+                            retry,
+                        );
+                    }
                 }
-                not_supported => {
-                    return Err(SocketError::Subscribe(format!(
-                        "Streams::init() does not support: {}",
-                        not_supported
-                    )))
+                ExchangeId::KrakenL3 => {
+                    spawn_consumer::<KrakenL3>(
+                        mode,
+                        exchange,
+                        subscriptions,
+                        exchange_tx,
+                        control.clone(),
+                        retry,
+                    );
                 }
             }
 
@@ -201,8 +1334,63 @@ impl StreamBuilder {
             exchange_streams.insert(exchange, exchange_rx);
         }
 
+        // Spawn a roll manager for every RollingFutures, sharing the stream of it's ExchangeId
+        for futures in std::mem::take(&mut self.rolling) {
+            let exchange = futures.exchange;
+            let exchange_tx = rolling_txs
+                .entry(exchange)
+                .or_insert_with(|| {
+                    let (exchange_tx, exchange_rx) = mpsc::unbounded_channel();
+                    exchange_streams.insert(exchange, exchange_rx);
+                    exchange_tx
+                })
+                .clone();
+
+            let retry = self
+                .retry_policies
+                .get(&exchange)
+                .cloned()
+                .unwrap_or_else(default_retry_policy);
+
+            let mode = self.stream_mode(exchange);
+            let sandbox = self.sandbox.contains(&exchange);
+
+            match exchange {
+                ExchangeId::BinanceFuturesCoin if sandbox => {
+                    spawn_roll_manager::<BinanceFuturesCoinSandbox>(
+                        mode,
+                        futures,
+                        exchange_tx,
+                        control.clone(),
+                        retry,
+                    )
+                }
+                ExchangeId::BinanceFuturesCoin => spawn_roll_manager::<BinanceFuturesCoin>(
+                    mode,
+                    futures,
+                    exchange_tx,
+                    control.clone(),
+                    retry,
+                ),
+                ExchangeId::KrakenFutures => spawn_roll_manager::<KrakenFutures>(
+                    mode,
+                    futures,
+                    exchange_tx,
+                    control.clone(),
+                    retry,
+                ),
+                other => {
+                    return Err(SocketError::Unsupported {
+                        entity: other.as_str(),
+                        item: "dated futures".to_owned(),
+                    })
+                }
+            }
+        }
+
         Ok(Streams {
             streams: exchange_streams,
+            control,
         })
     }
 }
@@ -213,7 +1401,7 @@ impl Validator for StreamBuilder {
         Self: Sized,
     {
         // Ensure at least one exchange Subscription has been provided
-        if self.exchange_subscriptions.is_empty() {
+        if self.exchange_subscriptions.is_empty() && self.rolling.is_empty() {
             return Err(SocketError::Subscribe(
                 "StreamBuilder contains no Subscription to action".to_owned(),
             ));
@@ -226,55 +1414,178 @@ impl Validator for StreamBuilder {
             .map(|subscription| subscription.validate())
             .collect::<Result<Vec<_>, SocketError>>()?;
 
+        // Validate each RollingFutures ExchangeId supports dated futures & it's Subscriptions
+        self.rolling
+            .iter()
+            .map(|futures| futures.validate())
+            .collect::<Result<Vec<_>, SocketError>>()?;
+
+        // Validate Binance spot market data Subscriptions are consumed via the SBE market data
+        // stream, since there is no JSON Binance spot MarketStream
+        if self.binance_sbe.is_none()
+            && self
+                .exchange_subscriptions
+                .get(&ExchangeId::Binance)
+                .is_some_and(|subscriptions| {
+                    subscriptions
+                        .iter()
+                        .any(|subscription| subscription.kind != SubKind::UserData)
+                })
+        {
+            return Err(SocketError::Subscribe(
+                "Binance spot requires binance_sbe(..) to be configured".to_owned(),
+            ));
+        }
+
+        // Validate each UserData Subscription is authenticated with an API key
+        if let Some(subscription) =
+            self.exchange_subscriptions
+                .values()
+                .flatten()
+                .find(|subscription| {
+                    subscription.kind == SubKind::UserData
+                        && !self.user_data.contains_key(&subscription.exchange)
+                })
+        {
+            return Err(SocketError::Subscribe(format!(
+                "{} UserData requires user_data(..) to be configured",
+                subscription.exchange
+            )));
+        }
+
+        // Validate each parallel ExchangeId is parsed by at least one worker, & does not also
+        // attach raw exchange payloads since a RawWsStream cannot parse frames in parallel
+        if let Some((exchange, workers)) = self
+            .parallel
+            .iter()
+            .find(|(exchange, workers)| **workers == 0 || self.raw.contains(exchange))
+        {
+            return Err(SocketError::Subscribe(match workers {
+                0 => format!("{exchange} parallel(..) requires at least one worker"),
+                _ => format!("{exchange} cannot be configured as both raw(..) & parallel(..)"),
+            }));
+        }
+
+        // Validate each sandbox ExchangeId provides a sandbox environment
+        if let Some(exchange) = self
+            .sandbox
+            .iter()
+            .find(|exchange| exchange.sandbox_ws_base_url().is_none())
+        {
+            return Err(SocketError::Subscribe(format!(
+                "{exchange} does not provide a sandbox environment"
+            )));
+        }
+
+        // Validate sandbox Binance futures Subscriptions are streamed via the testnet WebSocket
+        // API, rather than polled, synchronised or authenticated via the production REST API
+        if let Some(subscription) =
+            self.exchange_subscriptions
+                .values()
+                .flatten()
+                .find(|subscription| {
+                    self.sandbox.contains(&subscription.exchange)
+                        && matches!(
+                            subscription.exchange,
+                            ExchangeId::BinanceFuturesUsd | ExchangeId::BinanceFuturesCoin
+                        )
+                        && matches!(
+                            subscription.kind,
+                            SubKind::OrderBookL2Managed
+                                | SubKind::InstrumentStatus
+                                | SubKind::OpenInterest
+                                | SubKind::UserData
+                        )
+                })
+        {
+            return Err(SocketError::Subscribe(format!(
+                "{} sandbox does not support {} Subscriptions",
+                subscription.exchange, subscription.kind
+            )));
+        }
+
         Ok(self)
     }
 }
 
 /// Central [`MarketEvent`] consumer loop. Initialises an exchange [`MarketStream`] using a
 /// collection of [`Subscription`]s. Consumed events are distributed downstream via the
-/// `exchange_tx mpsc::UnboundedSender`. A re-connection mechanism with an exponential backoff
-/// policy is utilised to ensure maximum up-time.
+/// `exchange_tx mpsc::UnboundedSender`, unless their [`Subscription`] is paused via the provided
+/// [`SubscriptionControl`]. A re-connection mechanism backing off according to the provided
+/// [`RetryPolicy`] is utilised to ensure maximum up-time.
 pub async fn consume<Stream>(
     exchange: ExchangeId,
     subscriptions: Vec<Subscription>,
     exchange_tx: mpsc::UnboundedSender<Event<MarketEvent>>,
+    control: SubscriptionControl,
+    retry: Arc<dyn RetryPolicy>,
 ) -> SocketError
 where
     Stream: MarketStream,
+{
+    consume_with(
+        exchange,
+        subscriptions,
+        |subscriptions| async move { Stream::init(&subscriptions).await },
+        exchange_tx,
+        control,
+        retry,
+    )
+    .await
+}
+
+/// [`consume`] loop initialising the stream using the provided `init` function, for streams
+/// requiring more than their [`Subscription`]s to initialise (eg/ an API key).
+pub async fn consume_with<Stream, Init, InitFuture>(
+    exchange: ExchangeId,
+    subscriptions: Vec<Subscription>,
+    init: Init,
+    exchange_tx: mpsc::UnboundedSender<Event<MarketEvent>>,
+    control: SubscriptionControl,
+    retry: Arc<dyn RetryPolicy>,
+) -> SocketError
+where
+    Stream: futures::Stream<Item = Result<Event<MarketEvent>, SocketError>> + Unpin,
+    Init: Fn(Vec<Subscription>) -> InitFuture,
+    InitFuture: Future<Output = Result<Stream, SocketError>>,
 {
     info!(
         %exchange,
         ?subscriptions,
-        policy = "retry connection with exponential backoff",
+        ?retry,
         "MarketStream consumer loop running",
     );
 
-    // Consumer loop retry parameters
+    // Number of consecutive re-connection attempts since the MarketStream was last initialised
     let mut attempt: u32 = 0;
-    let mut backoff_ms: u64 = STARTING_RECONNECT_BACKOFF_MS;
+    let mut initialised = false;
 
     loop {
-        // Increment retry parameters at start of every iteration
-        attempt += 1;
-        backoff_ms *= 2;
         info!(%exchange, attempt, "attempting to initialise MarketStream");
 
         // Attempt to initialise MarketStream: if it fails on first attempt return SocketError
-        let mut stream = match Stream::init(&subscriptions).await {
+        let mut stream = match init(subscriptions.clone()).await {
             Ok(stream) => {
                 info!(%exchange, attempt, "successfully initialised MarketStream");
                 attempt = 0;
-                backoff_ms = STARTING_RECONNECT_BACKOFF_MS;
+                initialised = true;
                 stream
             }
             Err(error) => {
                 error!(%exchange, attempt, ?error, "failed to initialise MarketStream");
 
-                // Exit function function if Stream::init failed the first attempt, else retry
-                if attempt == 1 {
+                // Exit function if Stream::init failed the first attempt, else retry
+                if !initialised {
                     return error;
-                } else {
-                    continue;
+                }
+
+                attempt += 1;
+                match backoff(exchange, retry.as_ref(), attempt) {
+                    Some(backoff) => {
+                        tokio::time::sleep(backoff).await;
+                        continue;
+                    }
+                    None => return error,
                 }
             }
         };
@@ -282,8 +1593,18 @@ where
         // Consume Result<Event<MarketData>, SocketError> from MarketStream
         while let Some(event_result) = stream.next().await {
             match event_result {
+                // If Ok & Subscription is paused: drop Event<MarketData>
+                Ok(market_event) if control.is_paused(exchange, &market_event.payload) => {
+                    continue;
+                }
                 // If Ok: send Event<MarketData> to exchange receiver
                 Ok(market_event) => {
+                    #[cfg(feature = "profiling")]
+                    let (kind, start) = (
+                        crate::profiling::kind_name(Some(&market_event.payload.kind)),
+                        std::time::Instant::now(),
+                    );
+
                     let _ = exchange_tx.send(market_event).map_err(|err| {
                         error!(
                             payload = ?err.0,
@@ -291,6 +1612,14 @@ where
                             "failed to send Event<MarketData> to Exchange receiver"
                         );
                     });
+
+                    #[cfg(feature = "profiling")]
+                    crate::profiling::record(
+                        exchange,
+                        kind,
+                        crate::profiling::Stage::Route,
+                        start.elapsed(),
+                    );
                 }
                 // If SocketError: log & continue to next Result<Event<MarketData>, SocketError>
                 Err(error) => {
@@ -305,26 +1634,118 @@ where
             }
         }
 
-        // If MarketStream ends unexpectedly, attempt re-connection after backoff_ms
-        warn!(
+        // If MarketStream ends unexpectedly, attempt re-connection after backoff
+        warn!(%exchange, "exchange MarketStream unexpectedly ended");
+        attempt += 1;
+        match backoff(exchange, retry.as_ref(), attempt) {
+            Some(backoff) => tokio::time::sleep(backoff).await,
+            None => {
+                return SocketError::Subscribe(format!(
+                    "{exchange} MarketStream ended & RetryPolicy gave up re-connecting"
+                ))
+            }
+        }
+    }
+}
+
+/// Determine the `Duration` to wait before the provided re-connection attempt using the
+/// [`RetryPolicy`], logging the outcome.
+fn backoff(exchange: ExchangeId, retry: &dyn RetryPolicy, attempt: u32) -> Option<Duration> {
+    let backoff = retry.backoff(attempt);
+    match backoff {
+        Some(backoff) => warn!(
             %exchange,
-            backoff_ms,
+            attempt,
+            ?backoff,
             action = "attempt re-connection after backoff",
-            "exchange MarketStream unexpectedly ended"
-        );
-        tokio::time::sleep(Duration::from_millis(backoff_ms)).await;
+            "MarketStream disconnected"
+        ),
+        None => error!(
+            %exchange,
+            attempt,
+            action = "give up re-connecting",
+            "RetryPolicy exhausted"
+        ),
     }
+    backoff
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use barter_integration::model::Instrument;
+    use crate::{
+        model::{subscription::SubscriptionMeta, DataKind, FutureContract},
+        rollover::RollRule,
+        SubscriptionIds,
+    };
+    use barter_integration::{model::Instrument, protocol::websocket::WsMessage};
+    use chrono::NaiveDate;
+    use futures::SinkExt;
+    use std::sync::OnceLock;
+
+    /// Base URL of the local WebSocket server that [`LocalCoinbase`] connects to.
+    static LOCAL_URL: OnceLock<String> = OnceLock::new();
+
+    /// [`Coinbase`] connecting to a local WebSocket server, without awaiting subscription
+    /// responses, in order to test the [`MarketStream`]s initialised by [`StreamBuilder`].
+    #[derive(Debug)]
+    struct LocalCoinbase(Coinbase);
+
+    impl Subscriber for LocalCoinbase {
+        type SubResponse = <Coinbase as Subscriber>::SubResponse;
+
+        fn base_url() -> &'static str {
+            LOCAL_URL.get().unwrap()
+        }
+
+        fn build_subscription_meta(
+            subscriptions: &[Subscription],
+        ) -> Result<SubscriptionMeta, SocketError> {
+            Coinbase::build_subscription_meta(subscriptions).map(|meta| SubscriptionMeta {
+                ids: meta.ids,
+                subscriptions: vec![],
+                expected_responses: 0,
+            })
+        }
+    }
+
+    impl ExchangeTransformer for LocalCoinbase {
+        const EXCHANGE: ExchangeId = ExchangeId::Coinbase;
+        fn new(ws_sink_tx: mpsc::UnboundedSender<WsMessage>, ids: SubscriptionIds) -> Self {
+            Self(Coinbase::new(ws_sink_tx, ids))
+        }
+    }
+
+    impl Transformer<MarketEvent> for LocalCoinbase {
+        type Input = <Coinbase as Transformer<MarketEvent>>::Input;
+        type OutputIter = <Coinbase as Transformer<MarketEvent>>::OutputIter;
+
+        fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+            self.0.transform(input)
+        }
+    }
 
     fn stream_builder(subscription: Subscription) -> StreamBuilder {
         StreamBuilder::new().subscribe([subscription])
     }
 
+    fn rolling_futures(exchange: ExchangeId) -> RollingFutures {
+        RollingFutures {
+            exchange,
+            contracts: ["240329", "240628"]
+                .into_iter()
+                .map(|expiry| FutureContract {
+                    underlying: "btc".to_owned(),
+                    quote: "usd".to_owned(),
+                    expiry: NaiveDate::parse_from_str(expiry, "%y%m%d").unwrap(),
+                })
+                .collect(),
+            kinds: vec![SubKind::Trade],
+            rule: RollRule::Date(chrono::Duration::days(1)),
+            continuous: true,
+        }
+    }
+
     #[test]
     fn test_stream_builder_validate() {
         struct TestCase {
@@ -339,12 +1760,13 @@ mod tests {
                 expected: Err(SocketError::Subscribe("".to_string())),
             },
             TestCase {
-                // TC1: Valid StreamBuilder w/ valid Binance Spot sub
+                // TC1: Valid StreamBuilder w/ valid Binance Spot sub via SBE
                 input: stream_builder(Subscription {
                     exchange: ExchangeId::Binance,
                     instrument: Instrument::from(("btc", "usd", InstrumentKind::Spot)),
                     kind: SubKind::Trade,
-                }),
+                })
+                .binance_sbe("api_key"),
                 expected: Ok(stream_builder(Subscription {
                     exchange: ExchangeId::Binance,
                     instrument: Instrument::from(("btc", "usd", InstrumentKind::Spot)),
@@ -360,6 +1782,146 @@ mod tests {
                 }),
                 expected: Err(SocketError::Subscribe("".to_string())),
             },
+            TestCase {
+                // TC3: Valid StreamBuilder w/ Coinbase sandbox Spot sub
+                input: stream_builder(Subscription {
+                    exchange: ExchangeId::Coinbase,
+                    instrument: Instrument::from(("btc", "usd", InstrumentKind::Spot)),
+                    kind: SubKind::Trade,
+                })
+                .sandbox(ExchangeId::Coinbase),
+                expected: Ok(stream_builder(Subscription {
+                    exchange: ExchangeId::Coinbase,
+                    instrument: Instrument::from(("btc", "usd", InstrumentKind::Spot)),
+                    kind: SubKind::Trade,
+                })),
+            },
+            TestCase {
+                // TC4: Invalid StreamBuilder w/ sandbox for exchange without a sandbox
+                input: stream_builder(Subscription {
+                    exchange: ExchangeId::Binance,
+                    instrument: Instrument::from(("btc", "usd", InstrumentKind::Spot)),
+                    kind: SubKind::Trade,
+                })
+                .binance_sbe("api_key")
+                .sandbox(ExchangeId::Binance),
+                expected: Err(SocketError::Subscribe("".to_string())),
+            },
+            TestCase {
+                // TC5: Invalid StreamBuilder w/ Binance Spot sub without SBE
+                input: stream_builder(Subscription {
+                    exchange: ExchangeId::Binance,
+                    instrument: Instrument::from(("btc", "usd", InstrumentKind::Spot)),
+                    kind: SubKind::Trade,
+                }),
+                expected: Err(SocketError::Subscribe("".to_string())),
+            },
+            TestCase {
+                // TC6: Valid StreamBuilder w/ Coinbase sub parsed in parallel
+                input: stream_builder(Subscription {
+                    exchange: ExchangeId::Coinbase,
+                    instrument: Instrument::from(("btc", "usd", InstrumentKind::Spot)),
+                    kind: SubKind::Trade,
+                })
+                .parallel(ExchangeId::Coinbase, 2),
+                expected: Ok(stream_builder(Subscription {
+                    exchange: ExchangeId::Coinbase,
+                    instrument: Instrument::from(("btc", "usd", InstrumentKind::Spot)),
+                    kind: SubKind::Trade,
+                })),
+            },
+            TestCase {
+                // TC7: Invalid StreamBuilder w/ Coinbase sub parsed in parallel by zero workers
+                input: stream_builder(Subscription {
+                    exchange: ExchangeId::Coinbase,
+                    instrument: Instrument::from(("btc", "usd", InstrumentKind::Spot)),
+                    kind: SubKind::Trade,
+                })
+                .parallel(ExchangeId::Coinbase, 0),
+                expected: Err(SocketError::Subscribe("".to_string())),
+            },
+            TestCase {
+                // TC8: Invalid StreamBuilder w/ Coinbase sub both raw & parsed in parallel
+                input: stream_builder(Subscription {
+                    exchange: ExchangeId::Coinbase,
+                    instrument: Instrument::from(("btc", "usd", InstrumentKind::Spot)),
+                    kind: SubKind::Trade,
+                })
+                .raw(ExchangeId::Coinbase)
+                .parallel(ExchangeId::Coinbase, 2),
+                expected: Err(SocketError::Subscribe("".to_string())),
+            },
+            TestCase {
+                // TC9: Valid StreamBuilder w/ only BinanceFuturesCoin RollingFutures
+                input: StreamBuilder::new()
+                    .subscribe_rolling(rolling_futures(ExchangeId::BinanceFuturesCoin)),
+                expected: Ok(StreamBuilder::new()
+                    .subscribe_rolling(rolling_futures(ExchangeId::BinanceFuturesCoin))),
+            },
+            TestCase {
+                // TC10: Invalid StreamBuilder w/ BinanceFuturesUsd RollingFutures
+                input: StreamBuilder::new()
+                    .subscribe_rolling(rolling_futures(ExchangeId::BinanceFuturesUsd)),
+                expected: Err(SocketError::Subscribe("".to_string())),
+            },
+            TestCase {
+                // TC11: Valid StreamBuilder w/ Binance Spot UserData sub without SBE
+                input: stream_builder(Subscription {
+                    exchange: ExchangeId::Binance,
+                    instrument: Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
+                    kind: SubKind::UserData,
+                })
+                .user_data(ExchangeId::Binance, "api_key"),
+                expected: Ok(stream_builder(Subscription {
+                    exchange: ExchangeId::Binance,
+                    instrument: Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
+                    kind: SubKind::UserData,
+                })),
+            },
+            TestCase {
+                // TC12: Invalid StreamBuilder w/ UserData sub without an API key
+                input: stream_builder(Subscription {
+                    exchange: ExchangeId::BinanceFuturesUsd,
+                    instrument: Instrument::from(("btc", "usdt", InstrumentKind::FuturePerpetual)),
+                    kind: SubKind::UserData,
+                })
+                .user_data(ExchangeId::Binance, "api_key"),
+                expected: Err(SocketError::Subscribe("".to_string())),
+            },
+            TestCase {
+                // TC13: Invalid StreamBuilder w/ UserData sub for exchange without user data
+                input: stream_builder(Subscription {
+                    exchange: ExchangeId::Coinbase,
+                    instrument: Instrument::from(("btc", "usd", InstrumentKind::Spot)),
+                    kind: SubKind::UserData,
+                })
+                .user_data(ExchangeId::Coinbase, "api_key"),
+                expected: Err(SocketError::Subscribe("".to_string())),
+            },
+            TestCase {
+                // TC14: Valid StreamBuilder w/ BinanceFuturesUsd sandbox FuturePerpetual sub
+                input: stream_builder(Subscription {
+                    exchange: ExchangeId::BinanceFuturesUsd,
+                    instrument: Instrument::from(("btc", "usdt", InstrumentKind::FuturePerpetual)),
+                    kind: SubKind::Trade,
+                })
+                .sandbox(ExchangeId::BinanceFuturesUsd),
+                expected: Ok(stream_builder(Subscription {
+                    exchange: ExchangeId::BinanceFuturesUsd,
+                    instrument: Instrument::from(("btc", "usdt", InstrumentKind::FuturePerpetual)),
+                    kind: SubKind::Trade,
+                })),
+            },
+            TestCase {
+                // TC15: Invalid StreamBuilder w/ BinanceFuturesUsd sandbox sub polled via REST
+                input: stream_builder(Subscription {
+                    exchange: ExchangeId::BinanceFuturesUsd,
+                    instrument: Instrument::from(("btc", "usdt", InstrumentKind::FuturePerpetual)),
+                    kind: SubKind::OpenInterest,
+                })
+                .sandbox(ExchangeId::BinanceFuturesUsd),
+                expected: Err(SocketError::Subscribe("".to_string())),
+            },
         ];
 
         for (index, test) in cases.into_iter().enumerate() {
@@ -379,4 +1941,204 @@ mod tests {
             }
         }
     }
+
+    #[tokio::test]
+    async fn test_stream_builder_init_binance_without_sbe() {
+        // Coinbase is validated alongside Binance, so no consumer is spawned before the error
+        let actual = StreamBuilder::new()
+            .subscribe([
+                Subscription::new(
+                    ExchangeId::Coinbase,
+                    ("btc", "usd", InstrumentKind::Spot),
+                    SubKind::Trade,
+                ),
+                Subscription::new(
+                    ExchangeId::Binance,
+                    ("btc", "usdt", InstrumentKind::Spot),
+                    SubKind::Trade,
+                ),
+            ])
+            .init()
+            .await;
+
+        match actual {
+            Err(SocketError::Subscribe(error)) => assert!(error.contains("binance_sbe")),
+            other => panic!("expected Binance without SBE to fail validation: {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_stream_builder_parallel_consumer() {
+        // Local WebSocket server sending Coinbase trades
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        LOCAL_URL
+            .set(format!("ws://{}", listener.local_addr().unwrap()))
+            .unwrap();
+        let (done_tx, done_rx) = tokio::sync::oneshot::channel::<()>();
+        tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let (mut sink, _) = tokio_tungstenite::accept_async(stream)
+                .await
+                .unwrap()
+                .split();
+            for id in 0..8 {
+                sink.send(WsMessage::Text(format!(
+                    r#"{{"type":"match","trade_id":{id},"time":"2014-11-07T08:19:27.028459Z","product_id":"BTC-USD","size":"1","price":"1","side":"buy"}}"#
+                )))
+                .await
+                .unwrap();
+            }
+            // Keep the connection open until the test completes
+            let _ = done_rx.await;
+        });
+
+        let subscription = Subscription::new(
+            ExchangeId::Coinbase,
+            ("btc", "usd", InstrumentKind::Spot),
+            SubKind::Trade,
+        );
+        let builder = StreamBuilder::new()
+            .subscribe([subscription.clone()])
+            .parallel(ExchangeId::Coinbase, 3)
+            .validate()
+            .unwrap();
+        let mode = builder.stream_mode(ExchangeId::Coinbase);
+        assert_eq!(mode, StreamMode::Parallel(3));
+
+        let (exchange_tx, mut exchange_rx) = mpsc::unbounded_channel();
+        spawn_consumer::<LocalCoinbase>(
+            mode,
+            ExchangeId::Coinbase,
+            vec![subscription],
+            exchange_tx,
+            SubscriptionControl::default(),
+            default_retry_policy(),
+        );
+
+        let mut actual = Vec::new();
+        while actual.len() < 8 {
+            let event = exchange_rx.recv().await.unwrap();
+            match event.payload.kind {
+                DataKind::Trade(trade) => actual.push((event.sequence, trade.id)),
+                other => panic!("unexpected DataKind: {other:?}"),
+            }
+        }
+        drop(done_tx);
+
+        let expected = (0..8).map(|id| (id, id.to_string())).collect::<Vec<_>>();
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_stream_builder_subscribe_spot_and_perpetual() {
+        let builder = StreamBuilder::new().subscribe_spot_and_perpetual(
+            [
+                ExchangeId::Okx,
+                ExchangeId::BinanceUs,
+                ExchangeId::BinanceFuturesUsd,
+            ],
+            "btc",
+            "usdt",
+            SubKind::Trade,
+        );
+
+        let subscriptions = |exchange| {
+            let mut subscriptions = builder.exchange_subscriptions[&exchange].clone();
+            subscriptions.sort();
+            subscriptions
+        };
+
+        assert_eq!(
+            subscriptions(ExchangeId::Okx),
+            vec![
+                Subscription::new(
+                    ExchangeId::Okx,
+                    ("btc", "usdt", InstrumentKind::Spot),
+                    SubKind::Trade
+                ),
+                Subscription::new(
+                    ExchangeId::Okx,
+                    ("btc", "usdt", InstrumentKind::FuturePerpetual),
+                    SubKind::Trade
+                ),
+            ]
+        );
+        assert_eq!(
+            subscriptions(ExchangeId::BinanceUs),
+            vec![Subscription::new(
+                ExchangeId::BinanceUs,
+                ("btc", "usdt", InstrumentKind::Spot),
+                SubKind::Trade
+            )]
+        );
+        assert_eq!(
+            subscriptions(ExchangeId::BinanceFuturesUsd),
+            vec![Subscription::new(
+                ExchangeId::BinanceFuturesUsd,
+                ("btc", "usdt", InstrumentKind::FuturePerpetual),
+                SubKind::Trade
+            )]
+        );
+        assert!(builder.validate().is_ok());
+    }
+
+    #[test]
+    fn test_subscription_control() {
+        use barter_integration::model::Side;
+
+        let control = SubscriptionControl::default();
+        let trades = Subscription::new(
+            ExchangeId::Binance,
+            ("btc", "usdt", InstrumentKind::Spot),
+            SubKind::Trade,
+        );
+        let event = crate::test_util::market_trade(Side::Buy);
+        let candle = crate::test_util::market_candle(chrono::Duration::minutes(1));
+
+        assert!(!control.is_paused(ExchangeId::Binance, &event));
+
+        assert!(control.pause(&trades));
+        assert!(!control.pause(&trades));
+        assert!(control.is_paused(ExchangeId::Binance, &event));
+
+        // Other exchanges & DataKinds of the same Instrument are still delivered
+        assert!(!control.is_paused(ExchangeId::BinanceFuturesUsd, &event));
+        assert!(!control.is_paused(ExchangeId::Binance, &candle));
+
+        assert!(control.resume(&trades));
+        assert!(!control.resume(&trades));
+        assert!(!control.is_paused(ExchangeId::Binance, &event));
+    }
+
+    #[test]
+    fn test_subscription_control_groups() {
+        use barter_integration::model::Side;
+
+        let trades = Subscription::new(
+            ExchangeId::Binance,
+            ("btc", "usdt", InstrumentKind::Spot),
+            SubKind::Trade,
+        );
+        let builder = StreamBuilder::new().subscribe_group("majors", [trades.clone()]);
+        assert_eq!(
+            builder.exchange_subscriptions[&ExchangeId::Binance],
+            vec![trades.clone()]
+        );
+
+        let control = SubscriptionControl {
+            groups: Arc::new(builder.groups),
+            ..SubscriptionControl::default()
+        };
+        let event = crate::test_util::market_trade(Side::Buy);
+
+        assert_eq!(control.group("majors"), Some([trades].as_slice()));
+        assert!(!control.pause_group("alts"));
+
+        assert!(control.pause_group("majors"));
+        assert!(control.is_paused(ExchangeId::Binance, &event));
+
+        assert!(control.resume_group("majors"));
+        assert!(!control.is_paused(ExchangeId::Binance, &event));
+    }
 }
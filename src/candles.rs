@@ -0,0 +1,417 @@
+use crate::{
+    builder::SubscriptionControl,
+    model::{
+        subscription::{Interval, SubKind, Subscription},
+        Candle, DataKind, MarketEvent,
+    },
+    ExchangeId,
+};
+use barter_integration::{model::Instrument, Event};
+use std::collections::{HashMap, HashSet};
+use tokio::sync::mpsc;
+use tracing::debug;
+
+/// Aggregates [`PublicTrade`](crate::model::PublicTrade) [`MarketEvent`]s into [`Candle`]
+/// [`MarketEvent`]s of each subscribed [`Interval`], for exchanges without a native kline
+/// channel.
+///
+/// A [`Candle`] is only distributed once a trade belonging to a later [`Interval`] is received,
+/// so [`Interval`]s without any trades are skipped. Trades belonging to an already distributed
+/// [`Candle`] (ie/ received out of order) are ignored.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct TradeCandles {
+    intervals: HashMap<Instrument, Vec<Interval>>,
+    candles: HashMap<(Instrument, Interval), Candle>,
+}
+
+impl TradeCandles {
+    /// Construct a new [`TradeCandles`] aggregating trades into the [`Interval`] of every
+    /// [`SubKind::Candle`] [`Subscription`] provided.
+    pub fn new(subscriptions: &[Subscription]) -> Self {
+        let mut intervals = HashMap::<Instrument, Vec<Interval>>::new();
+        for subscription in subscriptions {
+            if let SubKind::Candle(interval) = subscription.kind {
+                let instrument_intervals = intervals
+                    .entry(subscription.instrument.clone())
+                    .or_default();
+                if !instrument_intervals.contains(&interval) {
+                    instrument_intervals.push(interval);
+                }
+            }
+        }
+
+        Self {
+            intervals,
+            candles: HashMap::new(),
+        }
+    }
+
+    /// Update the open [`Candle`]s of the provided trade [`MarketEvent`]'s [`Instrument`],
+    /// returning any [`Candle`] [`MarketEvent`]s closed by it.
+    pub fn update(&mut self, event: &MarketEvent) -> Vec<MarketEvent> {
+        let trade = match &event.kind {
+            DataKind::Trade(trade) => trade,
+            _ => return vec![],
+        };
+
+        let intervals = match self.intervals.get(&event.instrument) {
+            Some(intervals) => intervals,
+            None => return vec![],
+        };
+
+        let mut closed = Vec::new();
+        for interval in intervals {
+            let start_time = interval.start_time(event.exchange_time);
+            let open = || Candle {
+                start_time,
+                end_time: interval.end_time(start_time),
+                open: trade.price,
+                high: trade.price,
+                low: trade.price,
+                close: trade.price,
+                volume: trade.quantity,
+                trade_count: 1,
+            };
+
+            let key = (event.instrument.clone(), *interval);
+            match self.candles.get_mut(&key) {
+                Some(candle) if candle.start_time == start_time => {
+                    candle.high = candle.high.max(trade.price);
+                    candle.low = candle.low.min(trade.price);
+                    candle.close = trade.price;
+                    candle.volume += trade.quantity;
+                    candle.trade_count += 1;
+                }
+                Some(candle) if candle.start_time < start_time => {
+                    let candle = std::mem::replace(candle, open());
+                    closed.push(MarketEvent {
+                        exchange_time: candle.end_time,
+                        received_time: event.received_time,
+                        received_instant: None,
+                        raw: None,
+                        exchange: event.exchange.clone(),
+                        instrument: event.instrument.clone(),
+                        kind: DataKind::Candle(candle),
+                    });
+                }
+                Some(_) => {}
+                None => {
+                    self.candles.insert(key, open());
+                }
+            }
+        }
+
+        closed
+    }
+}
+
+/// Aggregate the trades consumed via the `trade_rx` into [`Candle`]s for every
+/// [`SubKind::Candle`] [`Subscription`] provided, distributing them downstream via the
+/// `exchange_tx`. Every other [`MarketEvent`] is forwarded, except trades without an associated
+/// [`SubKind::Trade`] [`Subscription`]. Forwarded [`Event`]s are re-sequenced.
+///
+/// The [`SubscriptionControl`] is applied to the forwarded [`MarketEvent`]s only, so pausing the
+/// [`SubKind::Trade`] [`Subscription`] of an [`Instrument`] does not pause the aggregation of it's
+/// [`Candle`]s, which are paused via their own [`SubKind::Candle`] [`Subscription`].
+///
+/// Runs until the `trade_rx` sender or the `exchange_tx` receiver is dropped.
+pub async fn aggregate_trade_candles(
+    exchange: ExchangeId,
+    subscriptions: Vec<Subscription>,
+    mut trade_rx: mpsc::UnboundedReceiver<Event<MarketEvent>>,
+    exchange_tx: mpsc::UnboundedSender<Event<MarketEvent>>,
+    control: SubscriptionControl,
+) {
+    let mut candles = TradeCandles::new(&subscriptions);
+    let trades = subscriptions
+        .iter()
+        .filter(|subscription| subscription.kind == SubKind::Trade)
+        .map(|subscription| subscription.instrument.clone())
+        .collect::<HashSet<_>>();
+    let mut sequence = 0;
+
+    while let Some(Event { payload, .. }) = trade_rx.recv().await {
+        let closed = candles.update(&payload);
+
+        let forward = match &payload.kind {
+            DataKind::Trade(_) => trades.contains(&payload.instrument),
+            _ => true,
+        };
+
+        for payload in forward.then_some(payload).into_iter().chain(closed) {
+            if control.is_paused(exchange, &payload) {
+                continue;
+            }
+            if let DataKind::Candle(candle) = &payload.kind {
+                debug!(%exchange, instrument = %payload.instrument, ?candle, "aggregated Candle");
+            }
+            if exchange_tx.send(Event { sequence, payload }).is_err() {
+                return;
+            }
+            sequence += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::PublicTrade;
+    use barter_integration::model::{Exchange, InstrumentKind, Side};
+    use chrono::{DateTime, Utc};
+
+    fn trade(time: &str, price: f64, quantity: f64) -> MarketEvent {
+        let time = time.parse::<DateTime<Utc>>().unwrap();
+        MarketEvent {
+            exchange_time: time,
+            received_time: time,
+            received_instant: None,
+            raw: None,
+            exchange: Exchange::from(ExchangeId::Coinbase),
+            instrument: Instrument::from(("btc", "usd", InstrumentKind::Spot)),
+            kind: DataKind::Trade(PublicTrade {
+                id: "id".to_owned(),
+                price,
+                quantity,
+                side: Side::Buy,
+            }),
+        }
+    }
+
+    #[test]
+    fn test_trade_candles_update() {
+        let mut candles = TradeCandles::new(&[Subscription::new(
+            ExchangeId::Coinbase,
+            ("btc", "usd", InstrumentKind::Spot),
+            SubKind::Candle(Interval::Minute1),
+        )]);
+
+        let candle = |start_time: &str, end_time: &str, ohlc: [f64; 4], volume, trade_count| {
+            DataKind::Candle(Candle {
+                start_time: start_time.parse().unwrap(),
+                end_time: end_time.parse().unwrap(),
+                open: ohlc[0],
+                high: ohlc[1],
+                low: ohlc[2],
+                close: ohlc[3],
+                volume,
+                trade_count,
+            })
+        };
+
+        struct TestCase {
+            input: MarketEvent,
+            expected: Vec<DataKind>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: first trade opens a Candle
+                input: trade("2023-01-01T00:00:10Z", 100.0, 1.0),
+                expected: vec![],
+            },
+            TestCase {
+                // TC1: trade in the same Interval updates the open Candle
+                input: trade("2023-01-01T00:00:30Z", 110.0, 2.0),
+                expected: vec![],
+            },
+            TestCase {
+                // TC2: trade in the same Interval updates the open Candle
+                input: trade("2023-01-01T00:00:59Z", 90.0, 1.0),
+                expected: vec![],
+            },
+            TestCase {
+                // TC3: trade in a later Interval closes the open Candle
+                input: trade("2023-01-01T00:02:05Z", 95.0, 1.0),
+                expected: vec![candle(
+                    "2023-01-01T00:00:00Z",
+                    "2023-01-01T00:01:00Z",
+                    [100.0, 110.0, 90.0, 90.0],
+                    4.0,
+                    3,
+                )],
+            },
+            TestCase {
+                // TC4: out of order trade of a closed Candle is ignored
+                input: trade("2023-01-01T00:00:45Z", 1000.0, 1.0),
+                expected: vec![],
+            },
+            TestCase {
+                // TC5: trade in a later Interval closes the open Candle
+                input: trade("2023-01-01T00:03:00Z", 96.0, 1.0),
+                expected: vec![candle(
+                    "2023-01-01T00:02:00Z",
+                    "2023-01-01T00:03:00Z",
+                    [95.0, 95.0, 95.0, 95.0],
+                    1.0,
+                    1,
+                )],
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = candles
+                .update(&test.input)
+                .into_iter()
+                .map(|event| event.kind)
+                .collect::<Vec<_>>();
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_trade_candles_week_starts_on_monday() {
+        let mut candles = TradeCandles::new(&[Subscription::new(
+            ExchangeId::Coinbase,
+            ("btc", "usd", InstrumentKind::Spot),
+            SubKind::Candle(Interval::Week1),
+        )]);
+
+        // 2024-01-04 is a Thursday, & 2024-01-01 the Monday of it's week
+        assert!(candles
+            .update(&trade("2024-01-04T12:00:00Z", 100.0, 1.0))
+            .is_empty());
+
+        let actual = candles
+            .update(&trade("2024-01-08T00:00:00Z", 100.0, 1.0))
+            .into_iter()
+            .map(|event| event.kind)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            actual,
+            vec![DataKind::Candle(Candle {
+                start_time: "2024-01-01T00:00:00Z".parse().unwrap(),
+                end_time: "2024-01-08T00:00:00Z".parse().unwrap(),
+                open: 100.0,
+                high: 100.0,
+                low: 100.0,
+                close: 100.0,
+                volume: 1.0,
+                trade_count: 1,
+            })]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_aggregate_trade_candles() {
+        let (trade_tx, trade_rx) = mpsc::unbounded_channel();
+        let (exchange_tx, mut exchange_rx) = mpsc::unbounded_channel();
+
+        // Candles only are subscribed, so the aggregated trades are not forwarded
+        tokio::spawn(aggregate_trade_candles(
+            ExchangeId::Coinbase,
+            vec![Subscription::new(
+                ExchangeId::Coinbase,
+                ("btc", "usd", InstrumentKind::Spot),
+                SubKind::Candle(Interval::Minute1),
+            )],
+            trade_rx,
+            exchange_tx,
+            SubscriptionControl::default(),
+        ));
+
+        for (sequence, payload) in [
+            trade("2023-01-01T00:00:10Z", 100.0, 1.0),
+            trade("2023-01-01T00:01:10Z", 101.0, 1.0),
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            trade_tx
+                .send(Event {
+                    sequence: sequence as u64,
+                    payload,
+                })
+                .unwrap();
+        }
+        drop(trade_tx);
+
+        let mut actual = Vec::new();
+        while let Some(event) = exchange_rx.recv().await {
+            actual.push((event.sequence, event.payload.kind));
+        }
+
+        assert_eq!(
+            actual,
+            vec![(
+                0,
+                DataKind::Candle(Candle {
+                    start_time: "2023-01-01T00:00:00Z".parse().unwrap(),
+                    end_time: "2023-01-01T00:01:00Z".parse().unwrap(),
+                    open: 100.0,
+                    high: 100.0,
+                    low: 100.0,
+                    close: 100.0,
+                    volume: 1.0,
+                    trade_count: 1,
+                })
+            )]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_aggregate_trade_candles_trade_paused() {
+        let (trade_tx, trade_rx) = mpsc::unbounded_channel();
+        let (exchange_tx, mut exchange_rx) = mpsc::unbounded_channel();
+
+        let trades = Subscription::new(
+            ExchangeId::Coinbase,
+            ("btc", "usd", InstrumentKind::Spot),
+            SubKind::Trade,
+        );
+        let control = SubscriptionControl::default();
+        control.pause(&trades);
+
+        // Paused trades are not forwarded, but are still aggregated into Candles
+        tokio::spawn(aggregate_trade_candles(
+            ExchangeId::Coinbase,
+            vec![
+                trades,
+                Subscription::new(
+                    ExchangeId::Coinbase,
+                    ("btc", "usd", InstrumentKind::Spot),
+                    SubKind::Candle(Interval::Minute1),
+                ),
+            ],
+            trade_rx,
+            exchange_tx,
+            control,
+        ));
+
+        for (sequence, payload) in [
+            trade("2023-01-01T00:00:10Z", 100.0, 1.0),
+            trade("2023-01-01T00:01:10Z", 101.0, 1.0),
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            trade_tx
+                .send(Event {
+                    sequence: sequence as u64,
+                    payload,
+                })
+                .unwrap();
+        }
+        drop(trade_tx);
+
+        let mut actual = Vec::new();
+        while let Some(event) = exchange_rx.recv().await {
+            actual.push(event.payload.kind);
+        }
+
+        assert_eq!(
+            actual,
+            vec![DataKind::Candle(Candle {
+                start_time: "2023-01-01T00:00:00Z".parse().unwrap(),
+                end_time: "2023-01-01T00:01:00Z".parse().unwrap(),
+                open: 100.0,
+                high: 100.0,
+                low: 100.0,
+                close: 100.0,
+                volume: 1.0,
+                trade_count: 1,
+            })]
+        );
+    }
+}
@@ -0,0 +1,255 @@
+use crate::model::{
+    DataKind, Level, MarketEvent, OrderBook, OrderBookL3Delta, OrderBookL3DeltaKind,
+};
+use barter_integration::model::Side;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+
+/// Default absolute quantity difference below which an L2 [`Level`] & the L3 projection are
+/// considered consistent, tolerating floating point accumulation error.
+pub const DEFAULT_TOLERANCE: f64 = 1e-9;
+
+/// Price level at which an exchange L2 [`OrderBook`] diverged from the L2 projection of the L3
+/// book, as reported by a [`ConsistencyChecker`].
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct Divergence {
+    pub side: Side,
+    pub price: f64,
+    pub l2_quantity: f64,
+    pub l3_quantity: f64,
+}
+
+/// Validation mode that maintains an L2 projection of the L3 book built from
+/// [`OrderBookL3Delta`] [`MarketEvent`]s, and diffs every L2 [`OrderBook`] [`MarketEvent`] of the
+/// same instrument against it, reporting [`Divergence`]s. This catches bugs in either the
+/// exchange feeds or the book implementations.
+///
+/// L2 [`OrderBook`]s are usually depth limited, so only the price range spanned by each side of
+/// the L2 [`OrderBook`] is compared.
+#[derive(Clone, PartialEq, Debug)]
+pub struct ConsistencyChecker {
+    tolerance: f64,
+    orders: HashMap<String, (Side, f64, f64)>,
+}
+
+impl Default for ConsistencyChecker {
+    fn default() -> Self {
+        Self::new(DEFAULT_TOLERANCE)
+    }
+}
+
+impl ConsistencyChecker {
+    /// Construct a new [`ConsistencyChecker`] using the provided absolute quantity tolerance.
+    pub fn new(tolerance: f64) -> Self {
+        Self {
+            tolerance,
+            orders: HashMap::new(),
+        }
+    }
+
+    /// Update the L3 projection with an [`OrderBookL3Delta`] [`MarketEvent`], or diff an L2
+    /// [`OrderBook`] [`MarketEvent`] against the L3 projection & return any [`Divergence`]s.
+    pub fn update(&mut self, event: &MarketEvent) -> Vec<Divergence> {
+        match &event.kind {
+            DataKind::OrderBookL3Delta(delta) => {
+                self.apply(delta);
+                vec![]
+            }
+            DataKind::OrderBook(book) => self.diff(book),
+            _ => vec![],
+        }
+    }
+
+    /// Apply an [`OrderBookL3Delta`] to the maintained L3 orders.
+    fn apply(&mut self, delta: &OrderBookL3Delta) {
+        match delta.kind {
+            OrderBookL3DeltaKind::Open | OrderBookL3DeltaKind::Change => {
+                self.orders
+                    .insert(delta.id.clone(), (delta.side, delta.price, delta.quantity));
+            }
+            OrderBookL3DeltaKind::Delete => {
+                self.orders.remove(&delta.id);
+            }
+        }
+    }
+
+    /// Aggregate the maintained L3 orders of the provided [`Side`] into L2 quantities, keyed by
+    /// the bits of each price.
+    fn projection(&self, side: Side) -> BTreeMap<u64, f64> {
+        self.orders
+            .values()
+            .filter(|(order_side, _, _)| *order_side == side)
+            .fold(BTreeMap::new(), |mut levels, (_, price, quantity)| {
+                *levels.entry(price.to_bits()).or_default() += quantity;
+                levels
+            })
+    }
+
+    /// Diff both sides of the provided L2 [`OrderBook`] against the L3 projection.
+    fn diff(&self, book: &OrderBook) -> Vec<Divergence> {
+        let mut divergences = self.diff_side(Side::Buy, &book.bids);
+        divergences.extend(self.diff_side(Side::Sell, &book.asks));
+        divergences
+    }
+
+    /// Diff one side of an L2 [`OrderBook`] against the L3 projection within the price range
+    /// spanned by the L2 [`Level`]s.
+    fn diff_side(&self, side: Side, levels: &[Level]) -> Vec<Divergence> {
+        if levels.is_empty() {
+            return vec![];
+        }
+
+        let (low, high) = levels
+            .iter()
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(low, high), level| {
+                (low.min(level.price), high.max(level.price))
+            });
+
+        let mut projection = self.projection(side);
+        projection.retain(|price, _| (low..=high).contains(&f64::from_bits(*price)));
+
+        let mut divergences = levels
+            .iter()
+            .filter_map(|level| {
+                let l3_quantity = projection
+                    .remove(&level.price.to_bits())
+                    .unwrap_or_default();
+                self.diverged(side, level.price, level.quantity, l3_quantity)
+            })
+            .collect::<Vec<_>>();
+
+        // Remaining L3 price levels within the range are missing from the L2 OrderBook
+        divergences.extend(projection.into_iter().filter_map(|(price, l3_quantity)| {
+            self.diverged(side, f64::from_bits(price), 0.0, l3_quantity)
+        }));
+
+        divergences
+    }
+
+    /// Return a [`Divergence`] if the provided L2 & L3 quantities differ by more than the
+    /// tolerance.
+    fn diverged(
+        &self,
+        side: Side,
+        price: f64,
+        l2_quantity: f64,
+        l3_quantity: f64,
+    ) -> Option<Divergence> {
+        ((l2_quantity - l3_quantity).abs() > self.tolerance).then_some(Divergence {
+            side,
+            price,
+            l2_quantity,
+            l3_quantity,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    fn event(kind: DataKind) -> MarketEvent {
+        let mut event = crate::test_util::market_trade(Side::Buy);
+        event.kind = kind;
+        event
+    }
+
+    fn l3(
+        id: &str,
+        kind: OrderBookL3DeltaKind,
+        side: Side,
+        price: f64,
+        quantity: f64,
+    ) -> MarketEvent {
+        event(DataKind::OrderBookL3Delta(OrderBookL3Delta {
+            id: id.to_owned(),
+            kind,
+            side,
+            price,
+            quantity,
+        }))
+    }
+
+    fn l2(bids: Vec<Level>, asks: Vec<Level>) -> MarketEvent {
+        event(DataKind::OrderBook(OrderBook {
+            last_update_time: Utc::now(),
+            last_update_id: 0,
+            bids,
+            asks,
+        }))
+    }
+
+    #[test]
+    fn test_consistency_checker() {
+        let mut checker = ConsistencyChecker::default();
+
+        for delta in [
+            l3("1", OrderBookL3DeltaKind::Open, Side::Buy, 100.0, 1.0),
+            l3("2", OrderBookL3DeltaKind::Open, Side::Buy, 100.0, 2.0),
+            l3("3", OrderBookL3DeltaKind::Open, Side::Buy, 98.0, 5.0),
+            l3("4", OrderBookL3DeltaKind::Open, Side::Sell, 101.0, 1.0),
+            l3("5", OrderBookL3DeltaKind::Open, Side::Sell, 102.0, 1.0),
+            l3("2", OrderBookL3DeltaKind::Change, Side::Buy, 100.0, 1.5),
+            l3("5", OrderBookL3DeltaKind::Delete, Side::Sell, 102.0, 0.0),
+        ] {
+            assert!(checker.update(&delta).is_empty());
+        }
+
+        struct TestCase {
+            input: MarketEvent,
+            expected: Vec<Divergence>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: consistent depth limited L2 OrderBook excludes L3 levels out of range
+                input: l2(vec![Level::new(100.0, 2.5)], vec![Level::new(101.0, 1.0)]),
+                expected: vec![],
+            },
+            TestCase {
+                // TC1: L2 quantity differs from the L3 projection
+                input: l2(vec![Level::new(100.0, 3.0)], vec![]),
+                expected: vec![Divergence {
+                    side: Side::Buy,
+                    price: 100.0,
+                    l2_quantity: 3.0,
+                    l3_quantity: 2.5,
+                }],
+            },
+            TestCase {
+                // TC2: L2 level absent from the L3 projection & L3 level missing from L2
+                input: l2(vec![], vec![Level::new(101.0, 1.0), Level::new(102.0, 1.0)]),
+                expected: vec![Divergence {
+                    side: Side::Sell,
+                    price: 102.0,
+                    l2_quantity: 1.0,
+                    l3_quantity: 0.0,
+                }],
+            },
+            TestCase {
+                // TC3: L3 level within the L2 range is missing from L2
+                input: l2(vec![Level::new(100.0, 2.5), Level::new(97.0, 1.0)], vec![]),
+                expected: vec![
+                    Divergence {
+                        side: Side::Buy,
+                        price: 97.0,
+                        l2_quantity: 1.0,
+                        l3_quantity: 0.0,
+                    },
+                    Divergence {
+                        side: Side::Buy,
+                        price: 98.0,
+                        l2_quantity: 0.0,
+                        l3_quantity: 5.0,
+                    },
+                ],
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = checker.update(&test.input);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
@@ -0,0 +1,799 @@
+use super::model::BinanceLevel;
+use crate::model::{cold::ColdLevels, upsert_level, Level, OrderBook};
+use barter_integration::{error::SocketError, model::Side};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::{cmp::Ordering, collections::HashMap};
+
+/// `Binance` depth stream that a [`BinanceDepthManager`] maintains an [`OrderBook`] from.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#partial-book-depth-streams>
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#diff-depth-stream>
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BinanceDepth {
+    /// Partial book depth snapshots of the top 5 levels every 100ms.
+    Partial5,
+    /// Partial book depth snapshots of the top 10 levels every 100ms.
+    Partial10,
+    /// Partial book depth snapshots of the top 20 levels every 100ms.
+    Partial20,
+    /// Diffs of the full book every 100ms. Requires an initial [`BinanceDepthSnapshot`].
+    Diff,
+}
+
+impl BinanceDepth {
+    /// Return the `Binance` channel name associated with this [`BinanceDepth`].
+    ///
+    /// eg/ BinanceDepth::Partial20 => "@depth20@100ms"
+    pub fn channel(&self) -> &'static str {
+        match self {
+            BinanceDepth::Partial5 => "@depth5@100ms",
+            BinanceDepth::Partial10 => "@depth10@100ms",
+            BinanceDepth::Partial20 => "@depth20@100ms",
+            BinanceDepth::Diff => "@depth@100ms",
+        }
+    }
+
+    /// Determines if this [`BinanceDepth`] is a partial book depth snapshot stream.
+    pub fn is_partial(&self) -> bool {
+        !matches!(self, BinanceDepth::Diff)
+    }
+}
+
+/// `Binance` depth message variants that can be consumed by a [`BinanceDepthManager`].
+///
+/// Spot partial depth streams & the REST depth endpoint deliver a [`BinanceDepthSnapshot`],
+/// whereas diff depth streams (and Futures partial depth streams) deliver a
+/// [`BinanceDepthUpdate`].
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum BinanceDepthMessage {
+    Update(BinanceDepthUpdate),
+    Snapshot(BinanceDepthSnapshot),
+}
+
+/// `Binance` full depth snapshot of the top N levels.
+///
+/// eg/ {"lastUpdateId":160,"bids":[["0.0024","10"]],"asks":[["0.0026","100"]]}
+///
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#partial-book-depth-streams>
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BinanceDepthSnapshot {
+    #[serde(alias = "lastUpdateId", deserialize_with = "crate::exchange::de_str")]
+    pub last_update_id: u64,
+    pub bids: Vec<BinanceLevel>,
+    pub asks: Vec<BinanceLevel>,
+}
+
+/// `Binance` depth update containing the levels that changed between the `first_update_id` & the
+/// `last_update_id`. `Futures` updates also contain the `last_update_id` of the previous update.
+///
+/// eg/ {"e":"depthUpdate","E":123456789,"s":"BNBBTC","U":157,"u":160,"b":[["0.0024","10"]],"a":[]}
+///
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#diff-depth-stream>
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#diff-book-depth-streams>
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BinanceDepthUpdate {
+    #[serde(
+        alias = "E",
+        deserialize_with = "crate::exchange::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+
+    #[serde(alias = "U", deserialize_with = "crate::exchange::de_str")]
+    pub first_update_id: u64,
+
+    #[serde(alias = "u", deserialize_with = "crate::exchange::de_str")]
+    pub last_update_id: u64,
+
+    #[serde(
+        alias = "pu",
+        default,
+        deserialize_with = "crate::exchange::de_str_opt"
+    )]
+    pub prev_last_update_id: Option<u64>,
+
+    #[serde(alias = "b")]
+    pub bids: Vec<BinanceLevel>,
+
+    #[serde(alias = "a")]
+    pub asks: Vec<BinanceLevel>,
+}
+
+/// Maintains a consistent [`OrderBook`] from either `Binance` partial depth snapshots or
+/// diff depth updates, hiding the very different message shapes of each [`BinanceDepth`].
+///
+/// [`BinanceDepth::Diff`] books follow the `Binance` local book algorithm:
+///  1. Buffer [`BinanceDepthUpdate`]s until a [`BinanceDepthSnapshot`] (eg/ from REST) arrives.
+///     If more than [`Self::MAX_BUFFERED_UPDATES`] are buffered, the snapshot is assumed lost, so
+///     the buffer is discarded & a [`SocketError`] is returned so a new snapshot can be provided.
+///  2. Drop any buffered update where `last_update_id` <= the snapshot `last_update_id`.
+///  3. The first applied update must satisfy `first_update_id` <= snapshot + 1 <= `last_update_id`.
+///  4. Every subsequent update must continue from the previous update, else the book is
+///     discarded & a [`SocketError`] is returned so a new snapshot can be provided.
+///
+/// Optionally, only the top `hot_depth` levels of each side are kept in the maintained
+/// [`OrderBook`], with deeper levels spilled to [`ColdLevels`] & rehydrated lazily when they move
+/// into the top `hot_depth` (eg/ for analytics that only need top-of-book speed).
+///
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#how-to-manage-a-local-order-book-correctly>
+#[derive(Clone, PartialEq, Debug)]
+pub struct BinanceDepthManager {
+    pub depth: BinanceDepth,
+    pub book: Option<OrderBook>,
+    pub stats: BinanceDepthStats,
+    hot_depth: Option<usize>,
+    cold_bids: ColdLevels,
+    cold_asks: ColdLevels,
+    buffer: Vec<BinanceDepthUpdate>,
+    synchronised: bool,
+}
+
+/// Statistics describing the work performed by a [`BinanceDepthManager`].
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default)]
+pub struct BinanceDepthStats {
+    /// Number of messages & level updates that were superseded by a later update to the same
+    /// book or level within one processing batch, and therefore never applied.
+    pub conflated: u64,
+}
+
+impl BinanceDepthManager {
+    /// Maximum number of [`BinanceDepthUpdate`]s buffered while awaiting a snapshot.
+    pub const MAX_BUFFERED_UPDATES: usize = 1000;
+
+    /// Construct a new [`BinanceDepthManager`] for the provided [`BinanceDepth`] stream.
+    pub fn new(depth: BinanceDepth) -> Self {
+        Self {
+            depth,
+            book: None,
+            stats: BinanceDepthStats::default(),
+            hot_depth: None,
+            cold_bids: ColdLevels::new(Side::Buy),
+            cold_asks: ColdLevels::new(Side::Sell),
+            buffer: Vec::new(),
+            synchronised: false,
+        }
+    }
+
+    /// Construct a new [`BinanceDepthManager`] for the provided [`BinanceDepth`] stream that
+    /// keeps only the top `hot_depth` levels of each side in the maintained [`OrderBook`],
+    /// spilling deeper levels to compressed [`ColdLevels`].
+    pub fn with_hot_depth(depth: BinanceDepth, hot_depth: usize) -> Self {
+        Self {
+            hot_depth: Some(hot_depth),
+            ..Self::new(depth)
+        }
+    }
+
+    /// Return the `Binance` channel name the consumed depth messages should be sourced from.
+    pub fn channel(&self) -> &'static str {
+        self.depth.channel()
+    }
+
+    /// Return a reference to the maintained [`OrderBook`], if it is currently consistent.
+    pub fn book(&self) -> Option<&OrderBook> {
+        self.book.as_ref()
+    }
+
+    /// Return the maintained [`OrderBook`] including every level spilled to [`ColdLevels`], if it
+    /// is currently consistent. This rehydrates the deep levels, so should be used sparingly.
+    pub fn full_book(&self) -> Option<OrderBook> {
+        self.book.as_ref().map(|book| {
+            let mut book = book.clone();
+            book.bids.extend(self.cold_bids.levels());
+            book.asks.extend(self.cold_asks.levels());
+            book
+        })
+    }
+
+    /// Update the maintained [`OrderBook`] using the provided [`BinanceDepthMessage`].
+    ///
+    /// Returns `Ok(Some(&OrderBook))` if the book changed, and `Ok(None)` if the message was
+    /// buffered awaiting a snapshot, or skipped because it was stale.
+    pub fn update(
+        &mut self,
+        message: BinanceDepthMessage,
+    ) -> Result<Option<&OrderBook>, SocketError> {
+        match (self.depth.is_partial(), message) {
+            // Partial depth messages always replace the entire book
+            (true, BinanceDepthMessage::Snapshot(snapshot)) => {
+                self.replace(Some(OrderBook::from(snapshot)));
+            }
+            (true, BinanceDepthMessage::Update(update)) => {
+                self.replace(Some(OrderBook::from(update)));
+            }
+
+            // Diff depth snapshots seed the book & apply any buffered updates
+            (false, BinanceDepthMessage::Snapshot(snapshot)) => {
+                self.replace(Some(OrderBook::from(snapshot)));
+                self.synchronised = false;
+                let buffered = std::mem::take(&mut self.buffer);
+                self.apply_batch(buffered)?;
+            }
+
+            // Diff depth updates are buffered until a snapshot is provided
+            (false, BinanceDepthMessage::Update(update)) => {
+                if self.book.is_none() {
+                    self.buffer(std::iter::once(update))?;
+                    return Ok(None);
+                }
+                if !self.apply(update)? {
+                    return Ok(None);
+                }
+            }
+        }
+
+        Ok(self.book.as_ref())
+    }
+
+    /// Update the maintained [`OrderBook`] using a batch of [`BinanceDepthMessage`]s received
+    /// together (eg/ during a burst), conflating redundant updates so only the final state of each
+    /// level is applied. Superseded updates are counted in the [`BinanceDepthStats`].
+    ///
+    /// Returns `Ok(Some(&OrderBook))` if the book changed, and `Ok(None)` otherwise.
+    pub fn update_batch(
+        &mut self,
+        messages: Vec<BinanceDepthMessage>,
+    ) -> Result<Option<&OrderBook>, SocketError> {
+        // Partial depth messages always replace the entire book, so only the last one matters
+        if self.depth.is_partial() {
+            let superseded = messages.len().saturating_sub(1) as u64;
+            return match messages.into_iter().last() {
+                Some(message) => {
+                    self.stats.conflated += superseded;
+                    self.update(message)
+                }
+                None => Ok(None),
+            };
+        }
+
+        let mut changed = false;
+        let mut run = Vec::with_capacity(messages.len());
+        for message in messages {
+            match message {
+                BinanceDepthMessage::Update(update) => run.push(update),
+                snapshot @ BinanceDepthMessage::Snapshot(_) => {
+                    changed |= self.apply_batch(std::mem::take(&mut run))?;
+                    changed |= self.update(snapshot)?.is_some();
+                }
+            }
+        }
+        changed |= self.apply_batch(run)?;
+
+        Ok(self.book.as_ref().filter(|_| changed))
+    }
+
+    /// Conflate & apply a batch of [`BinanceDepthUpdate`]s to the maintained diff depth
+    /// [`OrderBook`], buffering them if the book is awaiting a snapshot. Returns `Ok(true)` if
+    /// the book changed.
+    fn apply_batch(&mut self, updates: Vec<BinanceDepthUpdate>) -> Result<bool, SocketError> {
+        let last_update_id = match &self.book {
+            Some(book) => book.last_update_id,
+            None => {
+                self.buffer(updates)?;
+                return Ok(false);
+            }
+        };
+
+        // Drop any update that has already been reflected in the book before conflating
+        let updates = updates
+            .into_iter()
+            .filter(|update| update.last_update_id > last_update_id)
+            .collect();
+
+        let (updates, conflated) = conflate(updates);
+        self.stats.conflated += conflated;
+
+        let mut changed = false;
+        for update in updates {
+            changed |= self.apply(update)?;
+        }
+
+        Ok(changed)
+    }
+
+    /// Buffer the provided [`BinanceDepthUpdate`]s until a snapshot is provided. If the buffer
+    /// exceeds [`Self::MAX_BUFFERED_UPDATES`] it is discarded & a [`SocketError`] is returned.
+    fn buffer<Updates>(&mut self, updates: Updates) -> Result<(), SocketError>
+    where
+        Updates: IntoIterator<Item = BinanceDepthUpdate>,
+    {
+        self.buffer.extend(updates);
+        if self.buffer.len() <= Self::MAX_BUFFERED_UPDATES {
+            return Ok(());
+        }
+
+        let error = SocketError::Exchange(format!(
+            "Binance depth buffer exceeded {} updates awaiting a snapshot",
+            Self::MAX_BUFFERED_UPDATES
+        ));
+        self.buffer.clear();
+        Err(error)
+    }
+
+    /// Apply a [`BinanceDepthUpdate`] to the maintained diff depth [`OrderBook`], validating it
+    /// continues from the previously applied update. Returns `Ok(false)` if the update is stale.
+    fn apply(&mut self, update: BinanceDepthUpdate) -> Result<bool, SocketError> {
+        let Self {
+            book,
+            hot_depth,
+            cold_bids,
+            cold_asks,
+            ..
+        } = self;
+
+        let book = match book.as_mut() {
+            Some(book) => book,
+            None => return Ok(false),
+        };
+
+        // Drop any update that has already been reflected in the book
+        if update.last_update_id <= book.last_update_id {
+            return Ok(false);
+        }
+
+        let continuous = if !self.synchronised {
+            update.first_update_id <= book.last_update_id + 1
+        } else {
+            match update.prev_last_update_id {
+                Some(prev_last_update_id) => prev_last_update_id == book.last_update_id,
+                None => update.first_update_id == book.last_update_id + 1,
+            }
+        };
+
+        if !continuous {
+            let error = SocketError::Exchange(format!(
+                "Binance depth update {}..={} is discontinuous with book last_update_id: {}",
+                update.first_update_id, update.last_update_id, book.last_update_id
+            ));
+            self.replace(None);
+            self.synchronised = false;
+            return Err(error);
+        }
+
+        update.bids.into_iter().for_each(|level| {
+            upsert_tiered_level(
+                &mut book.bids,
+                cold_bids,
+                *hot_depth,
+                Level::from(level),
+                |a, b| b.total_cmp(a),
+            )
+        });
+        update.asks.into_iter().for_each(|level| {
+            upsert_tiered_level(
+                &mut book.asks,
+                cold_asks,
+                *hot_depth,
+                Level::from(level),
+                f64::total_cmp,
+            )
+        });
+
+        book.last_update_id = update.last_update_id;
+        book.last_update_time = update.time;
+        self.synchronised = true;
+        self.tier();
+
+        Ok(true)
+    }
+
+    /// Replace the maintained [`OrderBook`], discarding any levels spilled to [`ColdLevels`].
+    fn replace(&mut self, book: Option<OrderBook>) {
+        self.book = book;
+        self.cold_bids.clear();
+        self.cold_asks.clear();
+        self.tier();
+    }
+
+    /// Spill levels beyond the `hot_depth` to [`ColdLevels`], or rehydrate the best cold levels
+    /// if the maintained [`OrderBook`] has fewer than `hot_depth` levels.
+    fn tier(&mut self) {
+        if let (Some(hot_depth), Some(book)) = (self.hot_depth, self.book.as_mut()) {
+            tier_levels(&mut book.bids, &mut self.cold_bids, hot_depth);
+            tier_levels(&mut book.asks, &mut self.cold_asks, hot_depth);
+        }
+    }
+}
+
+/// Merge each run of continuous [`BinanceDepthUpdate`]s into a single update containing only the
+/// final state of every changed level. Discontinuous updates are not merged, so the continuity
+/// validation performed when applying them is preserved.
+///
+/// Returns the merged updates & the number of level updates that were superseded.
+fn conflate(updates: Vec<BinanceDepthUpdate>) -> (Vec<BinanceDepthUpdate>, u64) {
+    let mut conflated = 0;
+    let mut merged: Vec<BinanceDepthUpdate> = Vec::with_capacity(updates.len());
+
+    for update in updates {
+        let continuous = merged
+            .last()
+            .is_some_and(|previous| match update.prev_last_update_id {
+                Some(prev_last_update_id) => prev_last_update_id == previous.last_update_id,
+                None => update.first_update_id == previous.last_update_id + 1,
+            });
+
+        match merged.last_mut() {
+            Some(previous) if continuous => {
+                conflated += merge_levels(&mut previous.bids, update.bids);
+                conflated += merge_levels(&mut previous.asks, update.asks);
+                previous.last_update_id = update.last_update_id;
+                previous.time = update.time;
+            }
+            _ => merged.push(update),
+        }
+    }
+
+    (merged, conflated)
+}
+
+/// Merge the `next` [`BinanceLevel`]s into the `current` levels, replacing any level with the
+/// same price. Returns the number of replaced levels.
+fn merge_levels(current: &mut Vec<BinanceLevel>, next: Vec<BinanceLevel>) -> u64 {
+    let mut indexes = current
+        .iter()
+        .enumerate()
+        .map(|(index, level)| (level.price.to_bits(), index))
+        .collect::<HashMap<_, _>>();
+
+    let mut replaced = 0;
+    for level in next {
+        match indexes.get(&level.price.to_bits()) {
+            Some(&index) => {
+                current[index] = level;
+                replaced += 1;
+            }
+            None => {
+                indexes.insert(level.price.to_bits(), current.len());
+                current.push(level);
+            }
+        }
+    }
+
+    replaced
+}
+
+/// Upsert the provided [`Level`] into the `hot` levels, or into the `cold` levels if a
+/// `hot_depth` is configured and the [`Level`] is priced beyond the full set of `hot` levels.
+fn upsert_tiered_level<F>(
+    hot: &mut Vec<Level>,
+    cold: &mut ColdLevels,
+    hot_depth: Option<usize>,
+    level: Level,
+    compare: F,
+) where
+    F: Fn(&f64, &f64) -> Ordering,
+{
+    let is_cold = hot_depth.is_some_and(|hot_depth| {
+        // Rehydrate the best cold levels first so every cold level is priced beyond the hot levels
+        tier_levels(hot, cold, hot_depth);
+
+        hot.len() >= hot_depth
+            && hot
+                .last()
+                .is_some_and(|worst| compare(&worst.price, &level.price) == Ordering::Less)
+    });
+
+    match is_cold {
+        true => cold.upsert(level),
+        false => upsert_level(hot, level, compare),
+    }
+}
+
+/// Spill `hot` levels beyond the `hot_depth` to the `cold` levels, or rehydrate the best `cold`
+/// levels if there are fewer than `hot_depth` `hot` levels.
+fn tier_levels(hot: &mut Vec<Level>, cold: &mut ColdLevels, hot_depth: usize) {
+    match hot.len().cmp(&hot_depth) {
+        Ordering::Greater => hot
+            .split_off(hot_depth)
+            .into_iter()
+            .for_each(|level| cold.upsert(level)),
+        Ordering::Less if !cold.is_empty() => hot.extend(cold.take_best(hot_depth - hot.len())),
+        _ => {}
+    }
+}
+
+impl From<BinanceDepthSnapshot> for OrderBook {
+    fn from(snapshot: BinanceDepthSnapshot) -> Self {
+        Self {
+            last_update_time: Utc::now(),
+            last_update_id: snapshot.last_update_id,
+            bids: snapshot.bids.into_iter().map(Level::from).collect(),
+            asks: snapshot.asks.into_iter().map(Level::from).collect(),
+        }
+    }
+}
+
+impl From<BinanceDepthUpdate> for OrderBook {
+    fn from(update: BinanceDepthUpdate) -> Self {
+        Self {
+            last_update_time: update.time,
+            last_update_id: update.last_update_id,
+            bids: update.bids.into_iter().map(Level::from).collect(),
+            asks: update.asks.into_iter().map(Level::from).collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn level(price: f64, quantity: f64) -> BinanceLevel {
+        BinanceLevel { price, quantity }
+    }
+
+    fn snapshot(last_update_id: u64) -> BinanceDepthMessage {
+        BinanceDepthMessage::Snapshot(BinanceDepthSnapshot {
+            last_update_id,
+            bids: vec![level(100.0, 1.0), level(99.0, 1.0)],
+            asks: vec![level(101.0, 1.0), level(102.0, 1.0)],
+        })
+    }
+
+    fn update(
+        first_update_id: u64,
+        last_update_id: u64,
+        bids: Vec<BinanceLevel>,
+        asks: Vec<BinanceLevel>,
+    ) -> BinanceDepthMessage {
+        BinanceDepthMessage::Update(BinanceDepthUpdate {
+            time: Utc::now(),
+            first_update_id,
+            last_update_id,
+            prev_last_update_id: None,
+            bids,
+            asks,
+        })
+    }
+
+    #[test]
+    fn test_deserialise_binance_depth_message() {
+        struct TestCase {
+            input: &'static str,
+            expected: Result<BinanceDepthMessage, SocketError>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: valid Spot partial depth snapshot
+                input: r#"{"lastUpdateId":160,"bids":[["0.0024","10"]],"asks":[["0.0026","100"]]}"#,
+                expected: Ok(BinanceDepthMessage::Snapshot(BinanceDepthSnapshot {
+                    last_update_id: 160,
+                    bids: vec![level(0.0024, 10.0)],
+                    asks: vec![level(0.0026, 100.0)],
+                })),
+            },
+            TestCase {
+                // TC1: valid Spot diff depth update
+                input: r#"{
+                    "e":"depthUpdate","E":1671217200000,"s":"BNBBTC","U":157,"u":160,
+                    "b":[["0.0024","10"]],"a":[["0.0026","0"]]
+                }"#,
+                expected: Ok(BinanceDepthMessage::Update(BinanceDepthUpdate {
+                    time: crate::exchange::datetime_utc_from_epoch_duration(
+                        std::time::Duration::from_millis(1671217200000),
+                    ),
+                    first_update_id: 157,
+                    last_update_id: 160,
+                    prev_last_update_id: None,
+                    bids: vec![level(0.0024, 10.0)],
+                    asks: vec![level(0.0026, 0.0)],
+                })),
+            },
+            TestCase {
+                // TC2: valid Futures diff depth update w/ previous update id
+                input: r#"{
+                    "e":"depthUpdate","E":1671217200000,"T":1671217199999,"s":"BTCUSDT",
+                    "U":157,"u":160,"pu":149,"b":[],"a":[]
+                }"#,
+                expected: Ok(BinanceDepthMessage::Update(BinanceDepthUpdate {
+                    time: crate::exchange::datetime_utc_from_epoch_duration(
+                        std::time::Duration::from_millis(1671217200000),
+                    ),
+                    first_update_id: 157,
+                    last_update_id: 160,
+                    prev_last_update_id: Some(149),
+                    bids: vec![],
+                    asks: vec![],
+                })),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = serde_json::from_str::<BinanceDepthMessage>(test.input);
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => {
+                    assert_eq!(actual, expected, "TC{} failed", index)
+                }
+                (Err(_), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_binance_depth_manager_partial() {
+        let mut manager = BinanceDepthManager::new(BinanceDepth::Partial5);
+        assert_eq!(manager.channel(), "@depth5@100ms");
+
+        // Partial snapshots always replace the book
+        manager.update(snapshot(10)).unwrap();
+        let book = manager
+            .update(update(11, 12, vec![level(50.0, 1.0)], vec![]))
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(book.last_update_id, 12);
+        assert_eq!(book.bids, vec![Level::new(50.0, 1.0)]);
+        assert!(book.asks.is_empty());
+    }
+
+    #[test]
+    fn test_binance_depth_manager_diff() {
+        let mut manager = BinanceDepthManager::new(BinanceDepth::Diff);
+        assert_eq!(manager.channel(), "@depth@100ms");
+
+        // Updates received before the snapshot are buffered
+        assert!(matches!(
+            manager.update(update(5, 8, vec![level(98.0, 1.0)], vec![])),
+            Ok(None)
+        ));
+        assert!(matches!(
+            manager.update(update(9, 11, vec![level(99.0, 0.0)], vec![])),
+            Ok(None)
+        ));
+
+        // Snapshot seeds the book, drops stale buffered updates & applies the remainder
+        let book = manager.update(snapshot(10)).unwrap().unwrap().clone();
+        assert_eq!(book.last_update_id, 11);
+        assert_eq!(book.bids, vec![Level::new(100.0, 1.0)]);
+
+        // Continuous update inserts, replaces & removes levels while preserving ordering
+        let book = manager
+            .update(update(
+                12,
+                12,
+                vec![level(100.5, 2.0), level(100.0, 3.0)],
+                vec![level(101.0, 0.0), level(101.5, 1.0)],
+            ))
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            book.bids,
+            vec![Level::new(100.5, 2.0), Level::new(100.0, 3.0)]
+        );
+        assert_eq!(
+            book.asks,
+            vec![Level::new(101.5, 1.0), Level::new(102.0, 1.0)]
+        );
+
+        // Stale update is skipped
+        assert!(matches!(
+            manager.update(update(12, 12, vec![], vec![])),
+            Ok(None)
+        ));
+
+        // Discontinuous update discards the book
+        assert!(manager.update(update(20, 21, vec![], vec![])).is_err());
+        assert!(manager.book().is_none());
+    }
+
+    #[test]
+    fn test_binance_depth_manager_buffer_overflow() {
+        let mut manager = BinanceDepthManager::new(BinanceDepth::Diff);
+
+        // Updates up to the maximum are buffered awaiting a snapshot
+        for id in 1..=BinanceDepthManager::MAX_BUFFERED_UPDATES as u64 {
+            assert!(matches!(
+                manager.update(update(id, id, vec![], vec![])),
+                Ok(None)
+            ));
+        }
+
+        // Exceeding the maximum discards the buffer so a new snapshot can be requested
+        let overflow = BinanceDepthManager::MAX_BUFFERED_UPDATES as u64 + 1;
+        assert!(manager
+            .update(update(overflow, overflow, vec![], vec![]))
+            .is_err());
+
+        // Snapshot seeds the book without any of the discarded updates
+        let book = manager.update(snapshot(overflow)).unwrap().unwrap();
+        assert_eq!(book.last_update_id, overflow);
+        assert_eq!(
+            book.bids,
+            vec![Level::new(100.0, 1.0), Level::new(99.0, 1.0)]
+        );
+
+        // Batched updates are also bounded while awaiting a snapshot
+        let mut manager = BinanceDepthManager::new(BinanceDepth::Diff);
+        let updates = (0..=BinanceDepthManager::MAX_BUFFERED_UPDATES as u64)
+            .map(|id| update(id, id, vec![], vec![]))
+            .collect();
+        assert!(manager.update_batch(updates).is_err());
+    }
+
+    #[test]
+    fn test_binance_depth_manager_update_batch_conflates() {
+        let mut manager = BinanceDepthManager::new(BinanceDepth::Diff);
+        manager.update(snapshot(10)).unwrap();
+
+        // Burst of continuous updates repeatedly changing the same levels, plus a stale update
+        let book = manager
+            .update_batch(vec![
+                update(9, 10, vec![level(90.0, 1.0)], vec![]),
+                update(11, 11, vec![level(100.0, 2.0)], vec![level(101.0, 5.0)]),
+                update(12, 12, vec![level(100.0, 3.0)], vec![level(101.0, 0.0)]),
+                update(13, 13, vec![level(100.0, 4.0), level(99.5, 1.0)], vec![]),
+            ])
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(book.last_update_id, 13);
+        assert_eq!(
+            book.bids,
+            vec![
+                Level::new(100.0, 4.0),
+                Level::new(99.5, 1.0),
+                Level::new(99.0, 1.0)
+            ]
+        );
+        assert_eq!(book.asks, vec![Level::new(102.0, 1.0)]);
+        assert_eq!(manager.stats.conflated, 3);
+
+        // Discontinuous update within a batch is not merged & still discards the book
+        assert!(manager
+            .update_batch(vec![
+                update(14, 14, vec![level(100.0, 5.0)], vec![]),
+                update(20, 21, vec![], vec![]),
+            ])
+            .is_err());
+        assert!(manager.book().is_none());
+
+        // Partial depth batches only apply the final message
+        let mut manager = BinanceDepthManager::new(BinanceDepth::Partial5);
+        let book = manager
+            .update_batch(vec![snapshot(10), snapshot(11), snapshot(12)])
+            .unwrap()
+            .unwrap();
+        assert_eq!(book.last_update_id, 12);
+        assert_eq!(manager.stats.conflated, 2);
+    }
+
+    #[test]
+    fn test_binance_depth_manager_hot_depth() {
+        let mut manager = BinanceDepthManager::with_hot_depth(BinanceDepth::Diff, 1);
+
+        // Snapshot levels beyond the hot depth are spilled to cold storage
+        let book = manager.update(snapshot(10)).unwrap().unwrap();
+        assert_eq!(book.bids, vec![Level::new(100.0, 1.0)]);
+        assert_eq!(book.asks, vec![Level::new(101.0, 1.0)]);
+
+        // Deep level updates are applied to cold storage, & cold levels are rehydrated when the
+        // hot levels are removed
+        let book = manager
+            .update(update(
+                11,
+                11,
+                vec![level(100.0, 0.0), level(99.0, 2.0), level(98.0, 1.0)],
+                vec![level(102.0, 0.0)],
+            ))
+            .unwrap()
+            .unwrap();
+        assert_eq!(book.bids, vec![Level::new(99.0, 2.0)]);
+        assert_eq!(book.asks, vec![Level::new(101.0, 1.0)]);
+
+        let full = manager.full_book().unwrap();
+        assert_eq!(
+            full.bids,
+            vec![Level::new(99.0, 2.0), Level::new(98.0, 1.0)]
+        );
+        assert_eq!(full.asks, vec![Level::new(101.0, 1.0)]);
+    }
+}
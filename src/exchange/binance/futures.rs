@@ -1,14 +1,20 @@
-use super::model::{BinanceMessage, BinanceSubResponse};
+use super::model::{
+    mini_ticker_events, partial_depth_events, trade_events, BinanceCombinedStream, BinanceMessage,
+    BinanceStream, BinanceSubResponse,
+};
 use crate::{
-    model::{subscription::SubKind, MarketEvent},
-    ExchangeId, ExchangeTransformer, Subscriber, Subscription, SubscriptionIds, SubscriptionMeta,
+    model::{
+        subscription::{Depth, Interval, SubKind, UpdateSpeed},
+        MarketEvent,
+    },
+    ExchangeId, ExchangeTransformer, RateLimit, Subscriber, Subscription, SubscriptionIds,
+    SubscriptionMeta,
 };
 use barter_integration::{
     error::SocketError, model::SubscriptionId, protocol::websocket::WsMessage, Transformer,
     Validator,
 };
 use serde::{Deserialize, Serialize};
-use serde_json::json;
 use std::collections::HashMap;
 use tokio::sync::mpsc;
 
@@ -24,7 +30,27 @@ impl Subscriber for BinanceFuturesUsd {
     type SubResponse = BinanceSubResponse;
 
     fn base_url() -> &'static str {
-        "wss://fstream.binance.com/ws"
+        ExchangeId::BinanceFuturesUsd.ws_base_url()
+    }
+
+    fn ws_rate_limit() -> Option<RateLimit> {
+        ExchangeId::BinanceFuturesUsd.ws_rate_limit()
+    }
+
+    fn url(subscriptions: &[Subscription]) -> Result<String, SocketError> {
+        // Map Barter Subscriptions to BinanceFuturesUsd 'StreamNames' eg/ "btcusdt@aggTrade"
+        let mut stream_names = Vec::with_capacity(subscriptions.len());
+        for subscription in subscriptions {
+            let (channel, market) = Self::build_channel_meta(subscription)?;
+            let stream_name = Self::stream_name(&subscription.kind, &channel, &market);
+
+            // All market 'StreamNames' are shared by every Subscription, so only action once
+            if !stream_names.contains(&stream_name) {
+                stream_names.push(stream_name);
+            }
+        }
+
+        Self::combined_stream_url(&stream_names)
     }
 
     fn build_subscription_meta(
@@ -33,34 +59,27 @@ impl Subscriber for BinanceFuturesUsd {
         // Allocate SubscriptionIds HashMap to track identifiers for each actioned Subscription
         let mut ids = SubscriptionIds(HashMap::with_capacity(subscriptions.len()));
 
-        // Map Barter Subscriptions to BinanceFuturesUsd 'StreamNames'
-        let stream_names = subscriptions
-            .iter()
-            .map(|subscription| {
-                // Determine BinanceFuturesUsd specific channel & market for this Barter Subscription
-                let (channel, market) = Self::build_channel_meta(subscription)?;
-
-                // Use "channel|market" as the SubscriptionId key in the SubscriptionIds
-                // '--> Uppercase market to match incoming exchange event
-                // eg/ SubscriptionId("@aggTrade|BTCUSDT")
-                ids.insert(
-                    BinanceFuturesUsd::subscription_id(channel, &market.to_uppercase()),
-                    subscription.clone(),
-                );
-
-                // Construct BinanceFuturesUsd 'StreamName' eg/ "btcusdt@aggTrade"
-                // '--> Lowercase market because the subscription 'StreamName' must be lowercase
-                Ok(format!("{market}{channel}"))
-            })
-            .collect::<Result<Vec<_>, SocketError>>()?;
+        for subscription in subscriptions {
+            // Determine BinanceFuturesUsd specific channel & market for this Barter Subscription
+            let (channel, market) = Self::build_channel_meta(subscription)?;
 
-        // Use channels to construct a Binance subscription WsMessage
-        let subscriptions = Self::subscriptions(stream_names);
+            // Use "channel|market" as the SubscriptionId key in the SubscriptionIds
+            // '--> Uppercase market to match incoming exchange event
+            // eg/ SubscriptionId("@aggTrade|BTCUSDT")
+            BinanceFuturesUsd::insert_subscription_id(
+                &mut ids,
+                &channel,
+                &market.to_uppercase(),
+                subscription,
+            )?;
+        }
 
+        // 'StreamNames' are actioned via the combined stream url, so no subscription messages
+        // are sent & no subscription responses are expected
         Ok(SubscriptionMeta {
             ids,
-            expected_responses: subscriptions.len(),
-            subscriptions,
+            expected_responses: 0,
+            subscriptions: vec![],
         })
     }
 }
@@ -73,37 +92,102 @@ impl ExchangeTransformer for BinanceFuturesUsd {
 }
 
 impl Transformer<MarketEvent> for BinanceFuturesUsd {
-    type Input = BinanceMessage;
+    type Input = BinanceStream;
     type OutputIter = Vec<Result<MarketEvent, SocketError>>;
 
     fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
-        match input {
+        let input = match input {
+            BinanceStream::Message(input) => input,
+            BinanceStream::MiniTickers(input) => {
+                return mini_ticker_events(BinanceFuturesUsd::EXCHANGE, &self.ids, input.data)
+            }
+            BinanceStream::PartialDepth(input) => {
+                return partial_depth_events(BinanceFuturesUsd::EXCHANGE, &self.ids, input)
+            }
+        };
+
+        // Funding rates are received as mark price messages via a distinct 'StreamName'
+        if input.is_funding_rate() {
+            return match self.ids.find_instrument(&input.subscription_id()) {
+                Ok(instrument) => vec![Ok(MarketEvent::from((
+                    BinanceFuturesUsd::EXCHANGE,
+                    instrument,
+                    input,
+                )))],
+                Err(error) => vec![Err(error)],
+            };
+        }
+
+        match input.data {
             BinanceMessage::Trade(trade) => {
-                match self.ids.find_instrument(&trade.subscription_id) {
+                trade_events(BinanceFuturesUsd::EXCHANGE, &self.ids, trade)
+            }
+            BinanceMessage::OrderBookSnapshot(snapshot) => partial_depth_events(
+                BinanceFuturesUsd::EXCHANGE,
+                &self.ids,
+                BinanceCombinedStream {
+                    stream: input.stream,
+                    data: snapshot,
+                },
+            ),
+            BinanceMessage::Liquidation(liquidation) => {
+                match self.ids.find_instrument(&liquidation.order.subscription_id) {
                     Ok(instrument) => vec![Ok(MarketEvent::from((
                         BinanceFuturesUsd::EXCHANGE,
                         instrument,
-                        trade,
+                        liquidation,
                     )))],
                     Err(error) => vec![Err(error)],
                 }
             }
-            BinanceMessage::OrderBookSnapshot(snapshot) => {
-                match self.ids.find_instrument(&snapshot.subscription_id) {
+            BinanceMessage::Candle(kline) => {
+                match self.ids.find_instrument(&kline.subscription_id()) {
                     Ok(instrument) => vec![Ok(MarketEvent::from((
                         BinanceFuturesUsd::EXCHANGE,
                         instrument,
-                        snapshot,
+                        kline,
                     )))],
                     Err(error) => vec![Err(error)],
                 }
             }
-            BinanceMessage::Liquidation(liquidation) => {
-                match self.ids.find_instrument(&liquidation.order.subscription_id) {
+            BinanceMessage::MarkPrice(mark_price) => {
+                match self.ids.find_instrument(&mark_price.subscription_id) {
                     Ok(instrument) => vec![Ok(MarketEvent::from((
                         BinanceFuturesUsd::EXCHANGE,
                         instrument,
-                        liquidation,
+                        mark_price,
+                    )))],
+                    Err(error) => vec![Err(error)],
+                }
+            }
+            BinanceMessage::Ticker(ticker) => {
+                match self.ids.find_instrument(&ticker.subscription_id) {
+                    Ok(instrument) => vec![Ok(MarketEvent::from((
+                        BinanceFuturesUsd::EXCHANGE,
+                        instrument,
+                        ticker,
+                    )))],
+                    Err(error) => vec![Err(error)],
+                }
+            }
+            BinanceMessage::BookTicker(book_ticker) => {
+                match self.ids.find_instrument(&book_ticker.subscription_id) {
+                    Ok(instrument) => vec![Ok(MarketEvent::from((
+                        BinanceFuturesUsd::EXCHANGE,
+                        instrument,
+                        book_ticker,
+                    )))],
+                    Err(error) => vec![Err(error)],
+                }
+            }
+            message @ (BinanceMessage::RollingTicker1h(_)
+            | BinanceMessage::RollingTicker4h(_)
+            | BinanceMessage::RollingTicker1d(_)) => {
+                match self.ids.find_instrument(&message.subscription_id()) {
+                    Ok(instrument) => vec![Ok(MarketEvent::from((
+                        BinanceFuturesUsd::EXCHANGE,
+                        instrument,
+                        message,
                     )))],
                     Err(error) => vec![Err(error)],
                 }
@@ -113,7 +197,8 @@ impl Transformer<MarketEvent> for BinanceFuturesUsd {
 }
 
 impl BinanceFuturesUsd {
-    /// [`BinanceFuturesUsd`] aggregated trades channel name.
+    /// [`BinanceFuturesUsd`] aggregated trades channel name, shared by [`SubKind::Trade`] &
+    /// [`SubKind::AggTrade`] subscriptions.
     ///
     /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#aggregate-trade-streams>
     pub const CHANNEL_TRADES: &'static str = "@aggTrade";
@@ -124,11 +209,51 @@ impl BinanceFuturesUsd {
     /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#partial-book-depth-streams>
     pub const CHANNEL_ORDER_BOOK: &'static str = "@depth20@100ms";
 
+    /// [`BinanceFuturesUsd`] best bid & best ask (ie/ top of book) channel name, pushed in
+    /// real-time.
+    ///
+    /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#individual-symbol-book-ticker-streams>
+    pub const CHANNEL_BOOK_TICKER: &'static str = "@bookTicker";
+
+    /// [`BinanceFuturesUsd`] rolling 24 hour ticker statistics channel name, updated every
+    /// second.
+    ///
+    /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#individual-symbol-ticker-streams>
+    pub const CHANNEL_TICKER: &'static str = "@ticker";
+
+    /// [`BinanceFuturesUsd`] mini ticker channel name, used to identify the markets of messages
+    /// received via the [`Self::STREAM_MINI_TICKER_ALL`] 'StreamName'.
+    pub const CHANNEL_MINI_TICKER: &'static str = "@miniTicker";
+
+    /// [`BinanceFuturesUsd`] all market rolling 24 hour mini ticker 'StreamName', pushing an array
+    /// of every market that changed each second.
+    ///
+    /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#all-market-mini-tickers-stream>
+    pub const STREAM_MINI_TICKER_ALL: &'static str = "!miniTicker@arr";
+
     /// [`BinanceFuturesUsd`] liquidation orders channel name
     ///
     /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#liquidation-order-streams>
     pub const CHANNEL_LIQUIDATIONS: &'static str = "@forceOrder";
 
+    /// [`BinanceFuturesUsd`] kline/candlestick channel name prefix. The Binance interval
+    /// (eg/ "1m") is appended to form the full channel name (eg/ "@kline_1m").
+    ///
+    /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#kline-candlestick-streams>
+    pub const CHANNEL_CANDLES: &'static str = "@kline_";
+
+    /// [`BinanceFuturesUsd`] mark price & funding rate channel name, updated every second.
+    ///
+    /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#mark-price-stream>
+    pub const CHANNEL_MARK_PRICE: &'static str = "@markPrice@1s";
+
+    /// [`BinanceFuturesUsd`] funding rate channel name. Funding rates are sourced from the mark
+    /// price stream updated every 3 seconds, so the 'StreamName' is distinct from the
+    /// [`Self::CHANNEL_MARK_PRICE`] stream of the same market.
+    ///
+    /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#mark-price-stream>
+    pub const CHANNEL_FUNDING_RATE: &'static str = "@markPrice";
+
     /// Determine the [`BinanceFuturesUsd`] channel metadata associated with an input
     /// Barter [`Subscription`]. This includes the [`BinanceFuturesUsd`] `&str` channel
     /// identifier, and a `String` market identifier. Both are used to build a
@@ -136,15 +261,26 @@ impl BinanceFuturesUsd {
     ///
     /// Example Ok return: Ok("@aggTrade", "btcusdt")
     /// where channel == "@aggTrade" & market == "btcusdt"
-    pub fn build_channel_meta(sub: &Subscription) -> Result<(&str, String), SocketError> {
+    pub fn build_channel_meta(sub: &Subscription) -> Result<(String, String), SocketError> {
         // Validate provided Subscription InstrumentKind is supported by BinanceFuturesUsd
         let sub = sub.validate()?;
 
         // Determine the BinanceFuturesUsd channel
         let channel = match &sub.kind {
-            SubKind::Trade => Self::CHANNEL_TRADES,
-            SubKind::OrderBook => Self::CHANNEL_ORDER_BOOK,
-            SubKind::Liquidation => Self::CHANNEL_LIQUIDATIONS,
+            SubKind::Trade | SubKind::AggTrade => Self::CHANNEL_TRADES.to_owned(),
+            SubKind::Candle(interval) => {
+                format!("{}{}", Self::CHANNEL_CANDLES, Self::interval(*interval)?)
+            }
+            SubKind::OrderBook => Self::CHANNEL_ORDER_BOOK.to_owned(),
+            SubKind::OrderBookL1 => Self::CHANNEL_BOOK_TICKER.to_owned(),
+            SubKind::OrderBookL2Snapshot(depth, speed) => {
+                Self::partial_depth_channel(Self::EXCHANGE, *depth, *speed)?
+            }
+            SubKind::Liquidation => Self::CHANNEL_LIQUIDATIONS.to_owned(),
+            SubKind::MarkPrice => Self::CHANNEL_MARK_PRICE.to_owned(),
+            SubKind::FundingRate => Self::CHANNEL_FUNDING_RATE.to_owned(),
+            SubKind::Ticker => Self::CHANNEL_TICKER.to_owned(),
+            SubKind::TickerAll => Self::CHANNEL_MINI_TICKER.to_owned(),
             other => {
                 return Err(SocketError::Unsupported {
                     entity: BinanceFuturesUsd::EXCHANGE.as_str(),
@@ -159,6 +295,72 @@ impl BinanceFuturesUsd {
         Ok((channel, market))
     }
 
+    /// Map a Barter [`Interval`] to the associated [`BinanceFuturesUsd`] kline interval
+    /// (eg/ Interval::Minute1 => "1m").
+    ///
+    /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#kline-candlestick-streams>
+    pub fn interval(interval: Interval) -> Result<&'static str, SocketError> {
+        match interval {
+            Interval::Minute1 => Ok("1m"),
+            Interval::Minute3 => Ok("3m"),
+            Interval::Minute5 => Ok("5m"),
+            Interval::Minute15 => Ok("15m"),
+            Interval::Minute30 => Ok("30m"),
+            Interval::Hour1 => Ok("1h"),
+            Interval::Hour2 => Ok("2h"),
+            Interval::Hour4 => Ok("4h"),
+            Interval::Hour6 => Ok("6h"),
+            Interval::Hour8 => Ok("8h"),
+            Interval::Hour12 => Ok("12h"),
+            Interval::Day1 => Ok("1d"),
+            Interval::Day3 => Ok("3d"),
+            Interval::Week1 => Ok("1w"),
+            Interval::Month1 => Ok("1M"),
+            other => Err(SocketError::Unsupported {
+                entity: BinanceFuturesUsd::EXCHANGE.as_str(),
+                item: other.to_string(),
+            }),
+        }
+    }
+
+    /// Map a Barter [`Depth`] & [`UpdateSpeed`] to the associated `Binance` partial book depth
+    /// channel of the provided [`ExchangeId`] (eg/ Depth(5), UpdateSpeed::Ms100 => "@depth5@100ms").
+    ///
+    /// Supported depths are 5, 10 & 20 levels. `Spot` streams are pushed every 1000ms or 100ms,
+    /// whereas `Futures` streams are pushed every 250ms, 500ms or 100ms. The default speed of
+    /// each is not included in the channel.
+    ///
+    /// See docs: <https://binance-docs.github.io/apidocs/spot/en/#partial-book-depth-streams>
+    /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#partial-book-depth-streams>
+    pub fn partial_depth_channel(
+        exchange: ExchangeId,
+        depth: Depth,
+        speed: UpdateSpeed,
+    ) -> Result<String, SocketError> {
+        let unsupported = |item: String| SocketError::Unsupported {
+            entity: exchange.as_str(),
+            item,
+        };
+
+        if !matches!(*depth, 5 | 10 | 20) {
+            return Err(unsupported(format!("partial depth {depth}")));
+        }
+
+        let is_futures = matches!(
+            exchange,
+            ExchangeId::BinanceFuturesUsd | ExchangeId::BinanceFuturesCoin
+        );
+        let speed = match speed {
+            UpdateSpeed::Ms100 => "@100ms",
+            UpdateSpeed::Ms250 if is_futures => "",
+            UpdateSpeed::Ms500 if is_futures => "@500ms",
+            UpdateSpeed::Ms1000 if !is_futures => "",
+            other => return Err(unsupported(format!("partial depth update speed {other}"))),
+        };
+
+        Ok(format!("@depth{depth}{speed}"))
+    }
+
     /// Build a [`BinanceFuturesUsd`] compatible [`SubscriptionId`] using the channel & market
     /// provided. This is used to associate [`BinanceFuturesUsd`] data structures received over
     /// the WebSocket with it's original Barter [`Subscription`].
@@ -168,17 +370,61 @@ impl BinanceFuturesUsd {
         SubscriptionId::from(format!("{channel}|{market}"))
     }
 
-    /// Build a [`BinanceFuturesUsd`] compatible subscription message using the
-    /// 'StreamNames' provided.
-    pub fn subscriptions(stream_names: Vec<String>) -> Vec<WsMessage> {
-        vec![WsMessage::Text(
-            json!({
-                "method": "SUBSCRIBE",
-                "params": stream_names,
-                "id": 1
-            })
-            .to_string(),
-        )]
+    /// Insert the provided [`Subscription`] into the [`SubscriptionIds`] using the
+    /// [`BinanceFuturesUsd`] compatible [`SubscriptionId`] of the channel & market provided.
+    ///
+    /// Subscriptions of different [`SubKind`]s sharing a channel (eg/ [`SubKind::Trade`] &
+    /// [`SubKind::AggTrade`] both consume "@aggTrade") cannot be distinguished, so are rejected.
+    pub fn insert_subscription_id(
+        ids: &mut SubscriptionIds,
+        channel: &str,
+        market: &str,
+        subscription: &Subscription,
+    ) -> Result<(), SocketError> {
+        let id = Self::subscription_id(channel, market);
+        match ids.insert(id.clone(), subscription.clone()) {
+            Some(existing) if existing != *subscription => Err(SocketError::Subscribe(format!(
+                "{existing} & {subscription} share the Binance SubscriptionId: {id}"
+            ))),
+            _ => Ok(()),
+        }
+    }
+
+    /// Build the [`BinanceFuturesUsd`] 'StreamName' of a [`SubKind`] using the channel & market
+    /// provided. Every [`SubKind::TickerAll`] market shares the [`Self::STREAM_MINI_TICKER_ALL`]
+    /// 'StreamName'.
+    ///
+    /// eg/ "btcusdt@aggTrade"
+    pub fn stream_name(kind: &SubKind, channel: &str, market: &str) -> String {
+        match kind {
+            SubKind::TickerAll => Self::STREAM_MINI_TICKER_ALL.to_owned(),
+            _ => format!("{market}{channel}"),
+        }
+    }
+
+    /// Build a [`BinanceFuturesUsd`] combined stream url for the 'StreamNames' provided. Each
+    /// message received via a combined stream is wrapped in a [`BinanceCombinedStream`] envelope.
+    ///
+    /// eg/ "wss://fstream.binance.com/stream?streams=btcusdt@aggTrade/ethusdt@aggTrade"
+    ///
+    /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#websocket-market-streams>
+    pub fn combined_stream_url(stream_names: &[String]) -> Result<String, SocketError> {
+        if let Some(max_streams) = Self::EXCHANGE.max_streams_per_connection() {
+            if stream_names.len() > max_streams {
+                return Err(SocketError::Subscribe(format!(
+                    "{} StreamNames exceeds the {} combined stream limit of {}",
+                    stream_names.len(),
+                    Self::EXCHANGE,
+                    max_streams
+                )));
+            }
+        }
+
+        Ok(format!(
+            "{}?streams={}",
+            Self::base_url(),
+            stream_names.join("/")
+        ))
     }
 }
 
@@ -186,10 +432,14 @@ impl BinanceFuturesUsd {
 mod tests {
     use super::*;
     use crate::exchange::binance::model::{
-        BinanceLiquidation, BinanceLiquidationOrder, BinanceTrade,
+        BinanceBookTicker, BinanceKline, BinanceKlineData, BinanceLevel, BinanceLiquidation,
+        BinanceLiquidationOrder, BinanceMarkPrice, BinanceMiniTicker, BinanceOrderBook,
+        BinanceTrade,
+    };
+    use crate::model::{
+        AggTrade, Candle, DataKind, FundingRate, Level, Liquidation, MarkPrice, OrderBook,
+        OrderBookL1, OrderBookL2Snapshot, PublicTrade, Ticker,
     };
-    use crate::model::Liquidation;
-    use crate::model::{subscription::Interval, DataKind, PublicTrade};
     use barter_integration::model::{Exchange, Instrument, InstrumentKind, Side};
     use chrono::Utc;
 
@@ -213,6 +463,18 @@ mod tests {
                                     .to_uppercase(),
                             )
                         }
+                        (SubKind::Candle(_), InstrumentKind::FuturePerpetual)
+                        | (SubKind::AggTrade, InstrumentKind::FuturePerpetual)
+                        | (SubKind::MarkPrice, InstrumentKind::FuturePerpetual)
+                        | (SubKind::OrderBookL1, InstrumentKind::FuturePerpetual)
+                        | (SubKind::FundingRate, InstrumentKind::FuturePerpetual)
+                        | (SubKind::TickerAll, InstrumentKind::FuturePerpetual)
+                        | (SubKind::OrderBook, InstrumentKind::FuturePerpetual)
+                        | (SubKind::OrderBookL2Snapshot(..), InstrumentKind::FuturePerpetual) => {
+                            let (channel, market) =
+                                BinanceFuturesUsd::build_channel_meta(&sub).unwrap();
+                            BinanceFuturesUsd::subscription_id(&channel, &market.to_uppercase())
+                        }
                         (_, _) => {
                             panic!("not supported")
                         }
@@ -228,9 +490,9 @@ mod tests {
 
     #[test]
     fn test_build_channel_meta() {
-        struct TestCase<'a> {
+        struct TestCase {
             input: Subscription,
-            expected: Result<(&'a str, String), SocketError>,
+            expected: Result<(String, String), SocketError>,
         }
 
         let cases = vec![
@@ -253,7 +515,7 @@ mod tests {
                     ("btc", "usdt", InstrumentKind::FuturePerpetual),
                     SubKind::Trade,
                 ),
-                expected: Ok(("@aggTrade", "btcusdt".to_owned())),
+                expected: Ok(("@aggTrade".to_owned(), "btcusdt".to_owned())),
             },
             TestCase {
                 // TC2: Unsupported InstrumentKind::FuturePerpetual OrderBookL2Delta subscription
@@ -268,12 +530,117 @@ mod tests {
                 }),
             },
             TestCase {
-                // TC3: Unsupported InstrumentKind::FuturePerpetual candle subscription
+                // TC3: Supported InstrumentKind::FuturePerpetual candle subscription
                 input: Subscription::new(
                     ExchangeId::BinanceFuturesUsd,
                     ("btc", "usdt", InstrumentKind::FuturePerpetual),
                     SubKind::Candle(Interval::Minute5),
                 ),
+                expected: Ok(("@kline_5m".to_owned(), "btcusdt".to_owned())),
+            },
+            TestCase {
+                // TC4: Unsupported InstrumentKind::FuturePerpetual 3 month candle subscription
+                input: Subscription::new(
+                    ExchangeId::BinanceFuturesUsd,
+                    ("btc", "usdt", InstrumentKind::FuturePerpetual),
+                    SubKind::Candle(Interval::Month3),
+                ),
+                expected: Err(SocketError::Unsupported {
+                    entity: "",
+                    item: "".to_string(),
+                }),
+            },
+            TestCase {
+                // TC5: Supported InstrumentKind::FuturePerpetual mark price subscription
+                input: Subscription::new(
+                    ExchangeId::BinanceFuturesUsd,
+                    ("btc", "usdt", InstrumentKind::FuturePerpetual),
+                    SubKind::MarkPrice,
+                ),
+                expected: Ok(("@markPrice@1s".to_owned(), "btcusdt".to_owned())),
+            },
+            TestCase {
+                // TC6: Supported InstrumentKind::FuturePerpetual funding rate subscription
+                input: Subscription::new(
+                    ExchangeId::BinanceFuturesUsd,
+                    ("btc", "usdt", InstrumentKind::FuturePerpetual),
+                    SubKind::FundingRate,
+                ),
+                expected: Ok(("@markPrice".to_owned(), "btcusdt".to_owned())),
+            },
+            TestCase {
+                // TC7: Supported InstrumentKind::FuturePerpetual OrderBookL1 subscription
+                input: Subscription::new(
+                    ExchangeId::BinanceFuturesUsd,
+                    ("btc", "usdt", InstrumentKind::FuturePerpetual),
+                    SubKind::OrderBookL1,
+                ),
+                expected: Ok(("@bookTicker".to_owned(), "btcusdt".to_owned())),
+            },
+            TestCase {
+                // TC8: Supported InstrumentKind::FuturePerpetual aggregated trades subscription
+                input: Subscription::new(
+                    ExchangeId::BinanceFuturesUsd,
+                    ("btc", "usdt", InstrumentKind::FuturePerpetual),
+                    SubKind::AggTrade,
+                ),
+                expected: Ok(("@aggTrade".to_owned(), "btcusdt".to_owned())),
+            },
+            TestCase {
+                // TC9: Supported InstrumentKind::FuturePerpetual ticker subscription
+                input: Subscription::new(
+                    ExchangeId::BinanceFuturesUsd,
+                    ("btc", "usdt", InstrumentKind::FuturePerpetual),
+                    SubKind::Ticker,
+                ),
+                expected: Ok(("@ticker".to_owned(), "btcusdt".to_owned())),
+            },
+            TestCase {
+                // TC10: Supported InstrumentKind::FuturePerpetual all market ticker subscription
+                input: Subscription::new(
+                    ExchangeId::BinanceFuturesUsd,
+                    ("btc", "usdt", InstrumentKind::FuturePerpetual),
+                    SubKind::TickerAll,
+                ),
+                expected: Ok(("@miniTicker".to_owned(), "btcusdt".to_owned())),
+            },
+            TestCase {
+                // TC11: Supported InstrumentKind::FuturePerpetual 100ms partial depth subscription
+                input: Subscription::new(
+                    ExchangeId::BinanceFuturesUsd,
+                    ("btc", "usdt", InstrumentKind::FuturePerpetual),
+                    SubKind::OrderBookL2Snapshot(Depth::from(5), UpdateSpeed::Ms100),
+                ),
+                expected: Ok(("@depth5@100ms".to_owned(), "btcusdt".to_owned())),
+            },
+            TestCase {
+                // TC12: Supported InstrumentKind::FuturePerpetual default speed partial depth
+                input: Subscription::new(
+                    ExchangeId::BinanceFuturesUsd,
+                    ("btc", "usdt", InstrumentKind::FuturePerpetual),
+                    SubKind::OrderBookL2Snapshot(Depth::from(10), UpdateSpeed::Ms250),
+                ),
+                expected: Ok(("@depth10".to_owned(), "btcusdt".to_owned())),
+            },
+            TestCase {
+                // TC13: Unsupported InstrumentKind::FuturePerpetual partial depth update speed
+                input: Subscription::new(
+                    ExchangeId::BinanceFuturesUsd,
+                    ("btc", "usdt", InstrumentKind::FuturePerpetual),
+                    SubKind::OrderBookL2Snapshot(Depth::from(20), UpdateSpeed::Ms1000),
+                ),
+                expected: Err(SocketError::Unsupported {
+                    entity: "",
+                    item: "".to_string(),
+                }),
+            },
+            TestCase {
+                // TC14: Unsupported InstrumentKind::FuturePerpetual partial depth
+                input: Subscription::new(
+                    ExchangeId::BinanceFuturesUsd,
+                    ("btc", "usdt", InstrumentKind::FuturePerpetual),
+                    SubKind::OrderBookL2Snapshot(Depth::from(50), UpdateSpeed::Ms100),
+                ),
                 expected: Err(SocketError::Unsupported {
                     entity: "",
                     item: "".to_string(),
@@ -298,6 +665,167 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_url() {
+        struct TestCase {
+            input: Vec<Subscription>,
+            expected: Result<String, SocketError>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: Multiple Subscriptions are combined into a single url
+                input: vec![
+                    Subscription::new(
+                        ExchangeId::BinanceFuturesUsd,
+                        ("btc", "usdt", InstrumentKind::FuturePerpetual),
+                        SubKind::Trade,
+                    ),
+                    Subscription::new(
+                        ExchangeId::BinanceFuturesUsd,
+                        ("eth", "usdt", InstrumentKind::FuturePerpetual),
+                        SubKind::Liquidation,
+                    ),
+                ],
+                expected: Ok(
+                    "wss://fstream.binance.com/stream?streams=btcusdt@aggTrade/ethusdt@forceOrder"
+                        .to_owned(),
+                ),
+            },
+            TestCase {
+                // TC1: Unsupported Subscription fails url construction
+                input: vec![Subscription::new(
+                    ExchangeId::BinanceFuturesUsd,
+                    ("btc", "usdt", InstrumentKind::Spot),
+                    SubKind::Trade,
+                )],
+                expected: Err(SocketError::Unsupported {
+                    entity: "",
+                    item: "".to_string(),
+                }),
+            },
+            TestCase {
+                // TC2: Too many Subscriptions for a single connection
+                input: (0..=ExchangeId::BinanceFuturesUsd
+                    .max_streams_per_connection()
+                    .unwrap())
+                    .map(|index| {
+                        Subscription::new(
+                            ExchangeId::BinanceFuturesUsd,
+                            (
+                                format!("base{index}"),
+                                "usdt".to_owned(),
+                                InstrumentKind::FuturePerpetual,
+                            ),
+                            SubKind::Trade,
+                        )
+                    })
+                    .collect(),
+                expected: Err(SocketError::Subscribe("".to_string())),
+            },
+            TestCase {
+                // TC3: All market Subscriptions share a single 'StreamName'
+                input: vec![
+                    Subscription::new(
+                        ExchangeId::BinanceFuturesUsd,
+                        ("btc", "usdt", InstrumentKind::FuturePerpetual),
+                        SubKind::TickerAll,
+                    ),
+                    Subscription::new(
+                        ExchangeId::BinanceFuturesUsd,
+                        ("eth", "usdt", InstrumentKind::FuturePerpetual),
+                        SubKind::TickerAll,
+                    ),
+                    Subscription::new(
+                        ExchangeId::BinanceFuturesUsd,
+                        ("eth", "usdt", InstrumentKind::FuturePerpetual),
+                        SubKind::Trade,
+                    ),
+                ],
+                expected: Ok(
+                    "wss://fstream.binance.com/stream?streams=!miniTicker@arr/ethusdt@aggTrade"
+                        .to_owned(),
+                ),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = BinanceFuturesUsd::url(&test.input);
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => {
+                    assert_eq!(actual, expected, "TC{} failed", index)
+                }
+                (Err(_), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_build_subscription_meta() {
+        struct TestCase {
+            input: Vec<Subscription>,
+            expected: Result<usize, SocketError>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: Trade & AggTrade Subscriptions of different markets
+                input: vec![
+                    Subscription::new(
+                        ExchangeId::BinanceFuturesUsd,
+                        ("btc", "usdt", InstrumentKind::FuturePerpetual),
+                        SubKind::Trade,
+                    ),
+                    Subscription::new(
+                        ExchangeId::BinanceFuturesUsd,
+                        ("eth", "usdt", InstrumentKind::FuturePerpetual),
+                        SubKind::AggTrade,
+                    ),
+                ],
+                expected: Ok(2),
+            },
+            TestCase {
+                // TC1: Trade & AggTrade Subscriptions of the same market share "@aggTrade"
+                input: vec![
+                    Subscription::new(
+                        ExchangeId::BinanceFuturesUsd,
+                        ("btc", "usdt", InstrumentKind::FuturePerpetual),
+                        SubKind::Trade,
+                    ),
+                    Subscription::new(
+                        ExchangeId::BinanceFuturesUsd,
+                        ("btc", "usdt", InstrumentKind::FuturePerpetual),
+                        SubKind::AggTrade,
+                    ),
+                ],
+                expected: Err(SocketError::Subscribe("".to_string())),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual =
+                BinanceFuturesUsd::build_subscription_meta(&test.input).map(|meta| meta.ids.len());
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => {
+                    assert_eq!(actual, expected, "TC{} failed", index)
+                }
+                (Err(_), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+
     #[test]
     fn test_binance_transform() {
         let mut transformer = binance_futures_usd(vec![
@@ -315,6 +843,34 @@ mod tests {
                 InstrumentKind::FuturePerpetual,
                 SubKind::Liquidation,
             )),
+            Subscription::from((
+                ExchangeId::BinanceFuturesUsd,
+                "btc",
+                "usdt",
+                InstrumentKind::FuturePerpetual,
+                SubKind::Candle(Interval::Minute1),
+            )),
+            Subscription::from((
+                ExchangeId::BinanceFuturesUsd,
+                "btc",
+                "usdt",
+                InstrumentKind::FuturePerpetual,
+                SubKind::MarkPrice,
+            )),
+            Subscription::from((
+                ExchangeId::BinanceFuturesUsd,
+                "btc",
+                "usdt",
+                InstrumentKind::FuturePerpetual,
+                SubKind::OrderBookL1,
+            )),
+            Subscription::from((
+                ExchangeId::BinanceFuturesUsd,
+                "eth",
+                "usdt",
+                InstrumentKind::FuturePerpetual,
+                SubKind::AggTrade,
+            )),
         ]);
 
         let time = Utc::now();
@@ -331,6 +887,8 @@ mod tests {
                     subscription_id: SubscriptionId::from("unknown"),
                     time,
                     id: 0,
+                    first_trade_id: 0,
+                    last_trade_id: 0,
                     price: 1000.0,
                     quantity: 1.0,
                     side: Side::Buy,
@@ -345,6 +903,8 @@ mod tests {
                     subscription_id: SubscriptionId::from("@aggTrade|BTCUSDT"),
                     time,
                     id: 0,
+                    first_trade_id: 0,
+                    last_trade_id: 0,
                     price: 1000.0,
                     quantity: 1.0,
                     side: Side::Buy,
@@ -352,6 +912,8 @@ mod tests {
                 expected: vec![Ok(MarketEvent {
                     exchange_time: time,
                     received_time: time,
+                    received_instant: None,
+                    raw: None,
                     exchange: Exchange::from(ExchangeId::BinanceFuturesUsd),
                     instrument: Instrument::from(("btc", "usdt", InstrumentKind::FuturePerpetual)),
                     kind: DataKind::Trade(PublicTrade {
@@ -376,6 +938,8 @@ mod tests {
                 expected: vec![Ok(MarketEvent {
                     exchange_time: time,
                     received_time: time,
+                    received_instant: None,
+                    raw: None,
                     exchange: Exchange::from(ExchangeId::BinanceFuturesUsd),
                     instrument: Instrument::from(("btc", "usdt", InstrumentKind::FuturePerpetual)),
                     kind: DataKind::Liquidation(Liquidation {
@@ -386,10 +950,132 @@ mod tests {
                     }),
                 })],
             },
+            TestCase {
+                // TC3: BinanceMessage FuturePerpetual kline
+                input: BinanceMessage::Candle(BinanceKline {
+                    kline: BinanceKlineData {
+                        market: "BTCUSDT".to_owned(),
+                        interval: "1m".to_owned(),
+                        start_time: time,
+                        end_time: time,
+                        open: 1.0,
+                        high: 3.0,
+                        low: 0.5,
+                        close: 2.0,
+                        volume: 10.0,
+                        trade_count: 5,
+                    },
+                }),
+                expected: vec![Ok(MarketEvent {
+                    exchange_time: time,
+                    received_time: time,
+                    received_instant: None,
+                    raw: None,
+                    exchange: Exchange::from(ExchangeId::BinanceFuturesUsd),
+                    instrument: Instrument::from(("btc", "usdt", InstrumentKind::FuturePerpetual)),
+                    kind: DataKind::Candle(Candle {
+                        start_time: time,
+                        end_time: time,
+                        open: 1.0,
+                        high: 3.0,
+                        low: 0.5,
+                        close: 2.0,
+                        volume: 10.0,
+                        trade_count: 5,
+                    }),
+                })],
+            },
+            TestCase {
+                // TC4: BinanceMessage FuturePerpetual mark price
+                input: BinanceMessage::MarkPrice(BinanceMarkPrice {
+                    subscription_id: SubscriptionId::from("@markPrice@1s|BTCUSDT"),
+                    time,
+                    price: 100.0,
+                    index_price: 99.5,
+                    funding_rate: 0.0001,
+                    next_funding_time: time,
+                }),
+                expected: vec![Ok(MarketEvent {
+                    exchange_time: time,
+                    received_time: time,
+                    received_instant: None,
+                    raw: None,
+                    exchange: Exchange::from(ExchangeId::BinanceFuturesUsd),
+                    instrument: Instrument::from(("btc", "usdt", InstrumentKind::FuturePerpetual)),
+                    kind: DataKind::MarkPrice(MarkPrice {
+                        price: 100.0,
+                        index_price: 99.5,
+                        funding_rate: 0.0001,
+                        next_funding_time: time,
+                    }),
+                })],
+            },
+            TestCase {
+                // TC5: BinanceMessage FuturePerpetual book ticker
+                input: BinanceMessage::BookTicker(BinanceBookTicker {
+                    subscription_id: SubscriptionId::from("@bookTicker|BTCUSDT"),
+                    time,
+                    best_bid_price: 99.0,
+                    best_bid_quantity: 2.0,
+                    best_ask_price: 101.0,
+                    best_ask_quantity: 3.0,
+                }),
+                expected: vec![Ok(MarketEvent {
+                    exchange_time: time,
+                    received_time: time,
+                    received_instant: None,
+                    raw: None,
+                    exchange: Exchange::from(ExchangeId::BinanceFuturesUsd),
+                    instrument: Instrument::from(("btc", "usdt", InstrumentKind::FuturePerpetual)),
+                    kind: DataKind::OrderBookL1(OrderBookL1 {
+                        last_update_time: time,
+                        best_bid: Level {
+                            price: 99.0,
+                            quantity: 2.0,
+                        },
+                        best_ask: Level {
+                            price: 101.0,
+                            quantity: 3.0,
+                        },
+                    }),
+                })],
+            },
+            TestCase {
+                // TC6: BinanceMessage FuturePerpetual trade w/ AggTrade Subscription
+                input: BinanceMessage::Trade(BinanceTrade {
+                    subscription_id: SubscriptionId::from("@aggTrade|ETHUSDT"),
+                    time,
+                    id: 5,
+                    first_trade_id: 10,
+                    last_trade_id: 12,
+                    price: 1000.0,
+                    quantity: 3.0,
+                    side: Side::Sell,
+                }),
+                expected: vec![Ok(MarketEvent {
+                    exchange_time: time,
+                    received_time: time,
+                    received_instant: None,
+                    raw: None,
+                    exchange: Exchange::from(ExchangeId::BinanceFuturesUsd),
+                    instrument: Instrument::from(("eth", "usdt", InstrumentKind::FuturePerpetual)),
+                    kind: DataKind::AggTrade(AggTrade {
+                        id: "5".to_string(),
+                        first_trade_id: "10".to_string(),
+                        last_trade_id: "12".to_string(),
+                        price: 1000.0,
+                        quantity: 3.0,
+                        side: Side::Sell,
+                    }),
+                })],
+            },
         ];
 
         for (index, test) in cases.into_iter().enumerate() {
-            let actual = transformer.transform(test.input);
+            let actual = transformer.transform(BinanceStream::Message(BinanceCombinedStream {
+                stream: "btcusdt@aggTrade".to_owned(),
+                data: test.input,
+            }));
             assert_eq!(
                 actual.len(),
                 test.expected.len(),
@@ -408,6 +1094,8 @@ mod tests {
                         // Scrub Utc::now() timestamps to allow comparison
                         let actual = MarketEvent {
                             received_time: time,
+                            received_instant: None,
+                            raw: None,
                             ..actual
                         };
                         assert_eq!(
@@ -427,4 +1115,229 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_binance_transform_funding_rate() {
+        let mut transformer = binance_futures_usd(vec![
+            Subscription::from((
+                ExchangeId::BinanceFuturesUsd,
+                "btc",
+                "usdt",
+                InstrumentKind::FuturePerpetual,
+                SubKind::MarkPrice,
+            )),
+            Subscription::from((
+                ExchangeId::BinanceFuturesUsd,
+                "btc",
+                "usdt",
+                InstrumentKind::FuturePerpetual,
+                SubKind::FundingRate,
+            )),
+        ]);
+
+        let time = Utc::now();
+        let mark_price = BinanceMarkPrice {
+            subscription_id: SubscriptionId::from("@markPrice@1s|BTCUSDT"),
+            time,
+            price: 100.0,
+            index_price: 99.5,
+            funding_rate: 0.0001,
+            next_funding_time: time,
+        };
+
+        // Mark price messages received via the funding rate 'StreamName' are FundingRates
+        let actual = transformer.transform(BinanceStream::Message(BinanceCombinedStream {
+            stream: "btcusdt@markPrice".to_owned(),
+            data: BinanceMessage::MarkPrice(mark_price.clone()),
+        }));
+        let kinds = actual
+            .into_iter()
+            .map(|event| event.unwrap().kind)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            kinds,
+            vec![DataKind::FundingRate(FundingRate {
+                rate: 0.0001,
+                next_funding_time: time,
+            })]
+        );
+
+        // Mark price messages received via the mark price 'StreamName' are MarkPrices
+        let actual = transformer.transform(BinanceStream::Message(BinanceCombinedStream {
+            stream: "btcusdt@markPrice@1s".to_owned(),
+            data: BinanceMessage::MarkPrice(mark_price),
+        }));
+        let kinds = actual
+            .into_iter()
+            .map(|event| event.unwrap().kind)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            kinds,
+            vec![DataKind::MarkPrice(MarkPrice {
+                price: 100.0,
+                index_price: 99.5,
+                funding_rate: 0.0001,
+                next_funding_time: time,
+            })]
+        );
+    }
+
+    #[test]
+    fn test_binance_transform_mini_tickers() {
+        let mut transformer = binance_futures_usd(vec![
+            Subscription::from((
+                ExchangeId::BinanceFuturesUsd,
+                "btc",
+                "usdt",
+                InstrumentKind::FuturePerpetual,
+                SubKind::TickerAll,
+            )),
+            Subscription::from((
+                ExchangeId::BinanceFuturesUsd,
+                "eth",
+                "usdt",
+                InstrumentKind::FuturePerpetual,
+                SubKind::TickerAll,
+            )),
+        ]);
+
+        let time = Utc::now();
+        let mini_ticker = |market: &str, last_price: f64| BinanceMiniTicker {
+            subscription_id: BinanceFuturesUsd::subscription_id(
+                BinanceFuturesUsd::CHANNEL_MINI_TICKER,
+                market,
+            ),
+            time,
+            last_price,
+            open: 100.0,
+            high: 120.0,
+            low: 90.0,
+            volume: 1000.0,
+        };
+
+        // Mini tickers are fanned out per subscribed market, skipping unsubscribed markets
+        let actual = transformer.transform(BinanceStream::MiniTickers(BinanceCombinedStream {
+            stream: BinanceFuturesUsd::STREAM_MINI_TICKER_ALL.to_owned(),
+            data: vec![
+                mini_ticker("BTCUSDT", 110.0),
+                mini_ticker("SOLUSDT", 100.0),
+                mini_ticker("ETHUSDT", 95.0),
+            ],
+        }));
+        let events = actual
+            .into_iter()
+            .map(|event| {
+                let event = event.unwrap();
+                (event.instrument, event.kind)
+            })
+            .collect::<Vec<_>>();
+        let ticker = |last_price, change_percent_24h| {
+            DataKind::Ticker(Ticker {
+                last_price,
+                volume_24h: 1000.0,
+                high_24h: 120.0,
+                low_24h: 90.0,
+                change_percent_24h,
+            })
+        };
+        assert_eq!(
+            events,
+            vec![
+                (
+                    Instrument::from(("btc", "usdt", InstrumentKind::FuturePerpetual)),
+                    ticker(110.0, 10.0),
+                ),
+                (
+                    Instrument::from(("eth", "usdt", InstrumentKind::FuturePerpetual)),
+                    ticker(95.0, -5.0),
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_binance_transform_partial_depth() {
+        let mut transformer = binance_futures_usd(vec![
+            Subscription::from((
+                ExchangeId::BinanceFuturesUsd,
+                "btc",
+                "usdt",
+                InstrumentKind::FuturePerpetual,
+                SubKind::OrderBookL2Snapshot(Depth::from(5), UpdateSpeed::Ms100),
+            )),
+            Subscription::from((
+                ExchangeId::BinanceFuturesUsd,
+                "eth",
+                "usdt",
+                InstrumentKind::FuturePerpetual,
+                SubKind::OrderBook,
+            )),
+        ]);
+
+        let time = Utc::now();
+        let depth = |stream: &str, market: &str| {
+            BinanceStream::Message(BinanceCombinedStream {
+                stream: stream.to_owned(),
+                data: BinanceMessage::OrderBookSnapshot(BinanceOrderBook {
+                    subscription_id: BinanceFuturesUsd::subscription_id(
+                        BinanceFuturesUsd::CHANNEL_ORDER_BOOK,
+                        market,
+                    ),
+                    time,
+                    last_update_id: 10,
+                    bids: vec![BinanceLevel {
+                        price: 100.0,
+                        quantity: 1.0,
+                    }],
+                    asks: vec![BinanceLevel {
+                        price: 101.0,
+                        quantity: 2.0,
+                    }],
+                }),
+            })
+        };
+
+        struct TestCase {
+            input: BinanceStream,
+            expected: Option<DataKind>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: partial depth 'StreamName' of an OrderBookL2Snapshot Subscription
+                input: depth("btcusdt@depth5@100ms", "BTCUSDT"),
+                expected: Some(DataKind::OrderBookL2Snapshot(OrderBookL2Snapshot {
+                    last_update_time: time,
+                    last_update_id: 10,
+                    bids: vec![Level::new(100.0, 1.0)],
+                    asks: vec![Level::new(101.0, 2.0)],
+                })),
+            },
+            TestCase {
+                // TC1: partial depth 'StreamName' of an OrderBook Subscription
+                input: depth("ethusdt@depth20@100ms", "ETHUSDT"),
+                expected: Some(DataKind::OrderBook(OrderBook {
+                    last_update_time: time,
+                    last_update_id: 10,
+                    bids: vec![Level::new(100.0, 1.0)],
+                    asks: vec![Level::new(101.0, 2.0)],
+                })),
+            },
+            TestCase {
+                // TC2: partial depth 'StreamName' w/ unknown depth
+                input: depth("btcusdt@depth10@100ms", "BTCUSDT"),
+                expected: None,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = transformer
+                .transform(test.input)
+                .into_iter()
+                .next()
+                .and_then(|event| event.ok())
+                .map(|event| event.kind);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
 }
@@ -0,0 +1,413 @@
+use super::{
+    futures::BinanceFuturesUsd,
+    model::{
+        mini_ticker_events, partial_depth_events, trade_events, BinanceCoinContract,
+        BinanceCombinedStream, BinanceMessage, BinanceStream, BinanceSubResponse,
+    },
+};
+use crate::{
+    model::{subscription::SubKind, MarketEvent},
+    ExchangeId, ExchangeTransformer, RateLimit, Subscriber, Subscription, SubscriptionIds,
+    SubscriptionMeta,
+};
+use barter_integration::{
+    error::SocketError, protocol::websocket::WsMessage, Transformer, Validator,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use tokio::sync::mpsc;
+
+/// [`BinanceFuturesCoin`] [`Subscriber`](crate::Subscriber) &
+/// [`ExchangeTransformer`](crate::ExchangeTransformer) implementor for the collection
+/// of coin margined `Futures` data.
+///
+/// Channels & messages are identical to [`BinanceFuturesUsd`], but markets are identified by
+/// coin margined contract symbols (eg/ "BTCUSD_PERP"). Delivery contracts (eg/ "BTCUSD_240628")
+/// are subscribed via an [`Instrument`](barter_integration::model::Instrument) encoding a
+/// [`FutureContract`](crate::model::FutureContract).
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct BinanceFuturesCoin {
+    pub ids: SubscriptionIds,
+}
+
+impl Subscriber for BinanceFuturesCoin {
+    type SubResponse = BinanceSubResponse;
+
+    fn base_url() -> &'static str {
+        ExchangeId::BinanceFuturesCoin.ws_base_url()
+    }
+
+    fn ws_rate_limit() -> Option<RateLimit> {
+        ExchangeId::BinanceFuturesCoin.ws_rate_limit()
+    }
+
+    fn url(subscriptions: &[Subscription]) -> Result<String, SocketError> {
+        // Map Barter Subscriptions to BinanceFuturesCoin 'StreamNames' eg/ "btcusd_perp@aggTrade"
+        let mut stream_names = Vec::with_capacity(subscriptions.len());
+        for subscription in subscriptions {
+            let (channel, market) = Self::build_channel_meta(subscription)?;
+            let stream_name = BinanceFuturesUsd::stream_name(&subscription.kind, &channel, &market);
+
+            // All market 'StreamNames' are shared by every Subscription, so only action once
+            if !stream_names.contains(&stream_name) {
+                stream_names.push(stream_name);
+            }
+        }
+
+        if let Some(max_streams) = Self::EXCHANGE.max_streams_per_connection() {
+            if stream_names.len() > max_streams {
+                return Err(SocketError::Subscribe(format!(
+                    "{} StreamNames exceeds the {} combined stream limit of {}",
+                    stream_names.len(),
+                    Self::EXCHANGE,
+                    max_streams
+                )));
+            }
+        }
+
+        Ok(format!(
+            "{}?streams={}",
+            Self::base_url(),
+            stream_names.join("/")
+        ))
+    }
+
+    fn build_subscription_meta(
+        subscriptions: &[Subscription],
+    ) -> Result<SubscriptionMeta, SocketError> {
+        // Allocate SubscriptionIds HashMap to track identifiers for each actioned Subscription
+        let mut ids = SubscriptionIds(HashMap::with_capacity(subscriptions.len()));
+
+        for subscription in subscriptions {
+            // Determine BinanceFuturesCoin specific channel & market for this Barter Subscription
+            let (channel, market) = Self::build_channel_meta(subscription)?;
+
+            // Use "channel|market" as the SubscriptionId key in the SubscriptionIds
+            // '--> Uppercase market to match incoming exchange event
+            // eg/ SubscriptionId("@aggTrade|BTCUSD_PERP")
+            BinanceFuturesUsd::insert_subscription_id(
+                &mut ids,
+                &channel,
+                &market.to_uppercase(),
+                subscription,
+            )?;
+        }
+
+        // 'StreamNames' are actioned via the combined stream url, so no subscription messages
+        // are sent & no subscription responses are expected
+        Ok(SubscriptionMeta {
+            ids,
+            expected_responses: 0,
+            subscriptions: vec![],
+        })
+    }
+}
+
+impl ExchangeTransformer for BinanceFuturesCoin {
+    const EXCHANGE: ExchangeId = ExchangeId::BinanceFuturesCoin;
+    fn new(_: mpsc::UnboundedSender<WsMessage>, ids: SubscriptionIds) -> Self {
+        Self { ids }
+    }
+}
+
+impl Transformer<MarketEvent> for BinanceFuturesCoin {
+    type Input = BinanceStream;
+    type OutputIter = Vec<Result<MarketEvent, SocketError>>;
+
+    fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+        let input = match input {
+            // Partial book depth snapshots are identified by the 'StreamName' they were received from
+            BinanceStream::Message(BinanceCombinedStream {
+                stream,
+                data: BinanceMessage::OrderBookSnapshot(snapshot),
+            }) => {
+                return partial_depth_events(
+                    BinanceFuturesCoin::EXCHANGE,
+                    &self.ids,
+                    BinanceCombinedStream {
+                        stream,
+                        data: snapshot,
+                    },
+                )
+            }
+            BinanceStream::Message(BinanceCombinedStream {
+                data: BinanceMessage::Trade(trade),
+                ..
+            }) => return trade_events(BinanceFuturesCoin::EXCHANGE, &self.ids, trade),
+            BinanceStream::Message(input) => input,
+            BinanceStream::MiniTickers(input) => {
+                return mini_ticker_events(BinanceFuturesCoin::EXCHANGE, &self.ids, input.data)
+            }
+            BinanceStream::PartialDepth(input) => {
+                return partial_depth_events(BinanceFuturesCoin::EXCHANGE, &self.ids, input)
+            }
+        };
+
+        match self.ids.find_instrument(&input.subscription_id()) {
+            Ok(instrument) => vec![Ok(MarketEvent::from((
+                BinanceFuturesCoin::EXCHANGE,
+                instrument,
+                input,
+            )))],
+            Err(error) => vec![Err(error)],
+        }
+    }
+}
+
+impl BinanceFuturesCoin {
+    /// Determine the [`BinanceFuturesCoin`] channel metadata associated with an input
+    /// Barter [`Subscription`]. This includes the `String` channel identifier, and a `String`
+    /// market identifier. Both are used to build a [`BinanceFuturesCoin`] 'StreamName'.
+    ///
+    /// Example Ok return: Ok("@aggTrade", "btcusd_perp")
+    /// where channel == "@aggTrade" & market == "btcusd_perp"
+    pub fn build_channel_meta(sub: &Subscription) -> Result<(String, String), SocketError> {
+        // Validate provided Subscription InstrumentKind is supported by BinanceFuturesCoin
+        let sub = sub.validate()?;
+
+        // Determine the BinanceFuturesCoin channel, which are shared with BinanceFuturesUsd
+        let channel = match &sub.kind {
+            SubKind::Trade | SubKind::AggTrade => BinanceFuturesUsd::CHANNEL_TRADES.to_owned(),
+            SubKind::Candle(interval) => format!(
+                "{}{}",
+                BinanceFuturesUsd::CHANNEL_CANDLES,
+                BinanceFuturesUsd::interval(*interval)?
+            ),
+            SubKind::OrderBook => BinanceFuturesUsd::CHANNEL_ORDER_BOOK.to_owned(),
+            SubKind::OrderBookL1 => BinanceFuturesUsd::CHANNEL_BOOK_TICKER.to_owned(),
+            SubKind::OrderBookL2Snapshot(depth, speed) => {
+                BinanceFuturesUsd::partial_depth_channel(Self::EXCHANGE, *depth, *speed)?
+            }
+            SubKind::Liquidation => BinanceFuturesUsd::CHANNEL_LIQUIDATIONS.to_owned(),
+            SubKind::MarkPrice => BinanceFuturesUsd::CHANNEL_MARK_PRICE.to_owned(),
+            SubKind::FundingRate => BinanceFuturesUsd::CHANNEL_FUNDING_RATE.to_owned(),
+            SubKind::Ticker => BinanceFuturesUsd::CHANNEL_TICKER.to_owned(),
+            SubKind::TickerAll => BinanceFuturesUsd::CHANNEL_MINI_TICKER.to_owned(),
+            other => {
+                return Err(SocketError::Unsupported {
+                    entity: BinanceFuturesCoin::EXCHANGE.as_str(),
+                    item: other.to_string(),
+                })
+            }
+        };
+
+        // Determine BinanceFuturesCoin market using the contract symbol of the Instrument
+        let market = BinanceCoinContract::try_from(&sub.instrument)?
+            .symbol()
+            .to_lowercase();
+
+        Ok((channel, market))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchange::binance::model::BinanceTrade;
+    use crate::model::{subscription::Interval, DataKind, PublicTrade};
+    use barter_integration::model::{Exchange, Instrument, InstrumentKind, Side, SubscriptionId};
+    use chrono::Utc;
+
+    #[test]
+    fn test_build_channel_meta() {
+        struct TestCase {
+            input: Subscription,
+            expected: Result<(String, String), SocketError>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: Unsupported InstrumentKind::Spot subscription
+                input: Subscription::new(
+                    ExchangeId::BinanceFuturesCoin,
+                    ("btc", "usd", InstrumentKind::Spot),
+                    SubKind::Trade,
+                ),
+                expected: Err(SocketError::Unsupported {
+                    entity: "",
+                    item: "".to_string(),
+                }),
+            },
+            TestCase {
+                // TC1: Unsupported non-usd quoted InstrumentKind::FuturePerpetual subscription
+                input: Subscription::new(
+                    ExchangeId::BinanceFuturesCoin,
+                    ("btc", "usdt", InstrumentKind::FuturePerpetual),
+                    SubKind::Trade,
+                ),
+                expected: Err(SocketError::Unsupported {
+                    entity: "",
+                    item: "".to_string(),
+                }),
+            },
+            TestCase {
+                // TC2: Supported InstrumentKind::FuturePerpetual trades subscription
+                input: Subscription::new(
+                    ExchangeId::BinanceFuturesCoin,
+                    ("btc", "usd", InstrumentKind::FuturePerpetual),
+                    SubKind::Trade,
+                ),
+                expected: Ok(("@aggTrade".to_owned(), "btcusd_perp".to_owned())),
+            },
+            TestCase {
+                // TC3: Supported InstrumentKind::FuturePerpetual candle subscription
+                input: Subscription::new(
+                    ExchangeId::BinanceFuturesCoin,
+                    ("eth", "usd", InstrumentKind::FuturePerpetual),
+                    SubKind::Candle(Interval::Hour1),
+                ),
+                expected: Ok(("@kline_1h".to_owned(), "ethusd_perp".to_owned())),
+            },
+            TestCase {
+                // TC4: Unsupported InstrumentKind::FuturePerpetual OrderBookL2Delta subscription
+                input: Subscription::new(
+                    ExchangeId::BinanceFuturesCoin,
+                    ("btc", "usd", InstrumentKind::FuturePerpetual),
+                    SubKind::OrderBookL2Delta,
+                ),
+                expected: Err(SocketError::Unsupported {
+                    entity: "",
+                    item: "".to_string(),
+                }),
+            },
+            TestCase {
+                // TC5: Supported InstrumentKind::FuturePerpetual aggregated trades subscription
+                input: Subscription::new(
+                    ExchangeId::BinanceFuturesCoin,
+                    ("btc", "usd", InstrumentKind::FuturePerpetual),
+                    SubKind::AggTrade,
+                ),
+                expected: Ok(("@aggTrade".to_owned(), "btcusd_perp".to_owned())),
+            },
+            TestCase {
+                // TC6: Supported InstrumentKind::FuturePerpetual all market ticker subscription
+                input: Subscription::new(
+                    ExchangeId::BinanceFuturesCoin,
+                    ("btc", "usd", InstrumentKind::FuturePerpetual),
+                    SubKind::TickerAll,
+                ),
+                expected: Ok(("@miniTicker".to_owned(), "btcusd_perp".to_owned())),
+            },
+            TestCase {
+                // TC7: Supported dated FutureContract trades subscription
+                input: Subscription::new(
+                    ExchangeId::BinanceFuturesCoin,
+                    ("btc-240628", "usd", InstrumentKind::FuturePerpetual),
+                    SubKind::Trade,
+                ),
+                expected: Ok(("@aggTrade".to_owned(), "btcusd_240628".to_owned())),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = BinanceFuturesCoin::build_channel_meta(&test.input);
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => {
+                    assert_eq!(actual, expected, "TC{} failed", index)
+                }
+                (Err(_), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_binance_futures_coin_transform() {
+        let subscriptions = vec![Subscription::new(
+            ExchangeId::BinanceFuturesCoin,
+            ("btc", "usd", InstrumentKind::FuturePerpetual),
+            SubKind::Trade,
+        )];
+        let mut transformer = BinanceFuturesCoin {
+            ids: BinanceFuturesCoin::build_subscription_meta(&subscriptions)
+                .unwrap()
+                .ids,
+        };
+
+        let time = Utc::now();
+
+        struct TestCase {
+            input: BinanceMessage,
+            expected: Vec<Result<MarketEvent, SocketError>>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: BinanceMessage with unknown SubscriptionId
+                input: BinanceMessage::Trade(BinanceTrade {
+                    subscription_id: SubscriptionId::from("@aggTrade|ETHUSD_PERP"),
+                    time,
+                    id: 0,
+                    first_trade_id: 0,
+                    last_trade_id: 0,
+                    price: 1000.0,
+                    quantity: 1.0,
+                    side: Side::Buy,
+                }),
+                expected: vec![Err(SocketError::Unidentifiable(SubscriptionId::from(
+                    "@aggTrade|ETHUSD_PERP",
+                )))],
+            },
+            TestCase {
+                // TC1: BinanceMessage FuturePerpetual trade w/ known SubscriptionId
+                input: BinanceMessage::Trade(BinanceTrade {
+                    subscription_id: SubscriptionId::from("@aggTrade|BTCUSD_PERP"),
+                    time,
+                    id: 0,
+                    first_trade_id: 0,
+                    last_trade_id: 0,
+                    price: 1000.0,
+                    quantity: 1.0,
+                    side: Side::Sell,
+                }),
+                expected: vec![Ok(MarketEvent {
+                    exchange_time: time,
+                    received_time: time,
+                    received_instant: None,
+                    raw: None,
+                    exchange: Exchange::from(ExchangeId::BinanceFuturesCoin),
+                    instrument: Instrument::from(("btc", "usd", InstrumentKind::FuturePerpetual)),
+                    kind: DataKind::Trade(PublicTrade {
+                        id: "0".to_string(),
+                        price: 1000.0,
+                        quantity: 1.0,
+                        side: Side::Sell,
+                    }),
+                })],
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = transformer.transform(BinanceStream::Message(BinanceCombinedStream {
+                stream: "btcusd_perp@aggTrade".to_owned(),
+                data: test.input,
+            }));
+            assert_eq!(actual.len(), test.expected.len(), "TC{} failed", index);
+
+            for (actual, expected) in actual.into_iter().zip(test.expected) {
+                match (actual, expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        // Scrub Utc::now() timestamps to allow comparison
+                        let actual = MarketEvent {
+                            received_time: time,
+                            ..actual
+                        };
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,367 @@
+use super::{
+    depth::{
+        BinanceDepth, BinanceDepthManager, BinanceDepthMessage, BinanceDepthSnapshot,
+        BinanceDepthUpdate,
+    },
+    model::BinanceCombinedStream,
+};
+use crate::{
+    model::{subscription::Subscription, DataKind},
+    ExchangeId, MarketEvent,
+};
+use barter_integration::{
+    error::SocketError,
+    model::{Exchange, SubscriptionId},
+    protocol::websocket::{connect, is_websocket_disconnected, WebSocket, WsMessage},
+    Event,
+};
+use futures::StreamExt;
+use std::{collections::HashMap, time::Duration};
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::UnboundedReceiverStream;
+use tracing::{debug, warn};
+
+/// Maximum number of levels requested from the `Binance` REST depth endpoint.
+pub const SNAPSHOT_LIMIT: u16 = 1000;
+
+/// Duration to wait before re-fetching a `Binance` REST depth snapshot that failed.
+pub const SNAPSHOT_RETRY_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Convenient type alias for the stream of managed [`OrderBook`](crate::model::OrderBook)
+/// [`MarketEvent`]s initialised by [`init_managed_order_books`].
+pub type BinanceManagedStream = UnboundedReceiverStream<Result<Event<MarketEvent>, SocketError>>;
+
+/// Return the REST depth snapshot endpoint of the provided `Binance` [`ExchangeId`].
+///
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#order-book>
+/// See docs: <https://docs.binance.us/#order-book-depth>
+pub fn depth_snapshot_url(exchange: ExchangeId) -> Result<String, SocketError> {
+    let path = match exchange {
+        ExchangeId::BinanceFuturesUsd => "/fapi/v1/depth",
+        ExchangeId::BinanceUs => "/api/v3/depth",
+        other => {
+            return Err(SocketError::Unsupported {
+                entity: other.as_str(),
+                item: "managed order book".to_owned(),
+            })
+        }
+    };
+
+    Ok(format!("{}{}", exchange.rest_base_url(), path))
+}
+
+/// Fetch the [`BinanceDepthSnapshot`] of the provided symbol (eg/ "BTCUSDT") from the provided
+/// REST depth endpoint.
+pub async fn fetch_depth_snapshot(
+    url: &str,
+    symbol: &str,
+) -> Result<BinanceDepthSnapshot, reqwest::Error> {
+    reqwest::get(format!("{url}?symbol={symbol}&limit={SNAPSHOT_LIMIT}"))
+        .await?
+        .json()
+        .await
+}
+
+/// Diff depth [`BinanceDepthManager`] of every
+/// [`SubKind::OrderBookL2Managed`](crate::model::subscription::SubKind) [`Subscription`] of a
+/// `Binance` exchange, keyed by symbol (eg/ "BTCUSDT").
+#[derive(Clone, PartialEq, Debug)]
+pub struct BinanceManagedBooks {
+    pub exchange: ExchangeId,
+    books: HashMap<String, (Subscription, BinanceDepthManager)>,
+}
+
+impl BinanceManagedBooks {
+    /// Construct a new [`BinanceManagedBooks`] with a [`BinanceDepth::Diff`] manager for each of
+    /// the provided [`Subscription`]s.
+    pub fn new(exchange: ExchangeId, subscriptions: &[Subscription]) -> Self {
+        let books = subscriptions
+            .iter()
+            .map(|subscription| {
+                let symbol = format!(
+                    "{}{}",
+                    subscription.instrument.base, subscription.instrument.quote
+                )
+                .to_uppercase();
+
+                (
+                    symbol,
+                    (
+                        subscription.clone(),
+                        BinanceDepthManager::new(BinanceDepth::Diff),
+                    ),
+                )
+            })
+            .collect();
+
+        Self { exchange, books }
+    }
+
+    /// Return the symbol of every managed book (eg/ "BTCUSDT").
+    pub fn symbols(&self) -> Vec<String> {
+        self.books.keys().cloned().collect()
+    }
+
+    /// Return the diff depth 'StreamName' of every managed book (eg/ "btcusdt@depth@100ms").
+    pub fn stream_names(&self) -> Vec<String> {
+        self.books
+            .keys()
+            .map(|symbol| format!("{}{}", symbol.to_lowercase(), BinanceDepth::Diff.channel()))
+            .collect()
+    }
+
+    /// Update the book of the provided symbol using the provided [`BinanceDepthMessage`],
+    /// returning an [`OrderBook`](crate::model::OrderBook) [`MarketEvent`] if the book changed.
+    ///
+    /// A discontinuous update discards the book & returns an error, in which case a new
+    /// [`BinanceDepthSnapshot`] must be provided to re-synchronise it.
+    pub fn update(
+        &mut self,
+        symbol: &str,
+        message: BinanceDepthMessage,
+    ) -> Result<Option<MarketEvent>, SocketError> {
+        let (subscription, manager) = self
+            .books
+            .get_mut(symbol)
+            .ok_or_else(|| SocketError::Unidentifiable(SubscriptionId::from(symbol)))?;
+
+        Ok(manager.update(message)?.map(|book| MarketEvent {
+            exchange_time: book.last_update_time,
+            received_time: chrono::Utc::now(),
+            received_instant: None,
+            raw: None,
+            exchange: Exchange::from(self.exchange),
+            instrument: subscription.instrument.clone(),
+            kind: DataKind::OrderBook(book.clone()),
+        }))
+    }
+}
+
+/// Connect to the diff depth streams of the provided `Binance` exchange, and maintain an
+/// [`OrderBook`](crate::model::OrderBook) for each of the provided [`Subscription`]s following
+/// the `Binance` local book algorithm (see [`BinanceDepthManager`]).
+///
+/// Diff depth updates are buffered while the REST depth snapshot of each symbol is fetched, and
+/// a new snapshot is fetched whenever a gap is detected. Every book change is yielded as an
+/// [`OrderBook`](crate::model::OrderBook) [`MarketEvent`]. The stream ends when the WebSocket
+/// disconnects.
+pub async fn init_managed_order_books(
+    exchange: ExchangeId,
+    subscriptions: &[Subscription],
+) -> Result<BinanceManagedStream, SocketError> {
+    let snapshot_url = depth_snapshot_url(exchange)?;
+    let books = BinanceManagedBooks::new(exchange, subscriptions);
+
+    let websocket = connect(format!(
+        "{}?streams={}",
+        exchange.ws_base_url(),
+        books.stream_names().join("/")
+    ))
+    .await?;
+
+    let (event_tx, event_rx) = mpsc::unbounded_channel();
+    tokio::spawn(maintain_order_books(
+        books,
+        snapshot_url,
+        websocket,
+        event_tx,
+    ));
+
+    Ok(UnboundedReceiverStream::new(event_rx))
+}
+
+/// Apply the diff depth updates consumed from the provided [`WebSocket`] & the REST depth
+/// snapshots fetched concurrently to the [`BinanceManagedBooks`], distributing the outputs via
+/// the `event_tx`.
+async fn maintain_order_books(
+    mut books: BinanceManagedBooks,
+    snapshot_url: String,
+    mut websocket: WebSocket,
+    event_tx: mpsc::UnboundedSender<Result<Event<MarketEvent>, SocketError>>,
+) {
+    let exchange = books.exchange;
+    let (snapshot_tx, mut snapshot_rx) = mpsc::unbounded_channel();
+    for symbol in books.symbols() {
+        tokio::spawn(fetch_snapshot(
+            exchange,
+            snapshot_url.clone(),
+            symbol,
+            snapshot_tx.clone(),
+        ));
+    }
+
+    let mut sequence = 0;
+    loop {
+        let (symbol, message) = tokio::select! {
+            Some((symbol, snapshot)) = snapshot_rx.recv() => {
+                (symbol, BinanceDepthMessage::Snapshot(snapshot))
+            },
+            message = websocket.next() => match message {
+                Some(Ok(WsMessage::Text(payload))) => {
+                    let update =
+                        serde_json::from_str::<BinanceCombinedStream<BinanceDepthUpdate>>(&payload);
+                    match update {
+                        Ok(update) => (
+                            update.stream_symbol(),
+                            BinanceDepthMessage::Update(update.data),
+                        ),
+                        Err(error) => {
+                            if event_tx.send(Err(SocketError::Serde { error, payload })).is_err() {
+                                return;
+                            }
+                            continue;
+                        }
+                    }
+                }
+                Some(Ok(WsMessage::Close(close_frame))) => {
+                    debug!(%exchange, ?close_frame, "managed order book WebSocket closed");
+                    return;
+                }
+                Some(Ok(_)) => continue,
+                Some(Err(error)) if is_websocket_disconnected(&error) => return,
+                Some(Err(error)) => {
+                    if event_tx.send(Err(SocketError::WebSocket(error))).is_err() {
+                        return;
+                    }
+                    continue;
+                }
+                None => return,
+            },
+        };
+
+        let output = match books.update(&symbol, message) {
+            Ok(Some(payload)) => {
+                let event = Event { sequence, payload };
+                sequence += 1;
+                Ok(event)
+            }
+            Ok(None) => continue,
+            Err(error) => {
+                // Re-synchronise the discarded book from a new snapshot
+                warn!(%exchange, %symbol, %error, action = "fetch new snapshot", "order book gap");
+                tokio::spawn(fetch_snapshot(
+                    exchange,
+                    snapshot_url.clone(),
+                    symbol,
+                    snapshot_tx.clone(),
+                ));
+                Err(error)
+            }
+        };
+
+        if event_tx.send(output).is_err() {
+            return;
+        }
+    }
+}
+
+/// Fetch the REST [`BinanceDepthSnapshot`] of the provided symbol, retrying every
+/// [`SNAPSHOT_RETRY_INTERVAL`] until it succeeds or the `snapshot_tx` receiver is dropped.
+async fn fetch_snapshot(
+    exchange: ExchangeId,
+    url: String,
+    symbol: String,
+    snapshot_tx: mpsc::UnboundedSender<(String, BinanceDepthSnapshot)>,
+) {
+    while !snapshot_tx.is_closed() {
+        match fetch_depth_snapshot(&url, &symbol).await {
+            Ok(snapshot) => {
+                let _ = snapshot_tx.send((symbol, snapshot));
+                return;
+            }
+            Err(error) => {
+                warn!(
+                    %exchange,
+                    %symbol,
+                    %error,
+                    action = "retry",
+                    "failed to fetch depth snapshot"
+                );
+                tokio::time::sleep(SNAPSHOT_RETRY_INTERVAL).await;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{subscription::SubKind, Level, OrderBook};
+    use barter_integration::model::InstrumentKind;
+
+    #[test]
+    fn test_binance_managed_books_update() {
+        let mut books = BinanceManagedBooks::new(
+            ExchangeId::BinanceFuturesUsd,
+            &[Subscription::new(
+                ExchangeId::BinanceFuturesUsd,
+                ("btc", "usdt", InstrumentKind::FuturePerpetual),
+                SubKind::OrderBookL2Managed,
+            )],
+        );
+        assert_eq!(books.stream_names(), vec!["btcusdt@depth@100ms"]);
+
+        let update = |payload: &str| {
+            let update =
+                serde_json::from_str::<BinanceCombinedStream<BinanceDepthUpdate>>(payload).unwrap();
+            (
+                update.stream_symbol(),
+                BinanceDepthMessage::Update(update.data),
+            )
+        };
+        let snapshot =
+            |payload: &str| BinanceDepthMessage::Snapshot(serde_json::from_str(payload).unwrap());
+        let book = |event: Option<MarketEvent>| match event.map(|event| event.kind) {
+            Some(DataKind::OrderBook(OrderBook {
+                last_update_id,
+                bids,
+                asks,
+                ..
+            })) => Some((last_update_id, bids, asks)),
+            _ => None,
+        };
+
+        // Diff updates are buffered until the REST snapshot arrives
+        let (symbol, message) = update(
+            r#"{"stream":"btcusdt@depth@100ms","data":{"e":"depthUpdate","E":1571889248277,
+            "T":1571889248276,"s":"BTCUSDT","U":157,"u":160,"pu":149,
+            "b":[["100.0","2.0"]],"a":[["101.0","0.0"]]}}"#,
+        );
+        assert_eq!(symbol, "BTCUSDT");
+        assert_eq!(book(books.update(&symbol, message).unwrap()), None);
+
+        // Snapshot applies the buffered update continuing from it
+        let actual = books
+            .update(
+                "BTCUSDT",
+                snapshot(
+                    r#"{"lastUpdateId":158,"E":1571889248277,"T":1571889248276,
+                    "bids":[["100.0","1.0"],["99.0","3.0"]],"asks":[["101.0","1.0"]]}"#,
+                ),
+            )
+            .unwrap();
+        assert_eq!(
+            book(actual),
+            Some((
+                160,
+                vec![Level::new(100.0, 2.0), Level::new(99.0, 3.0)],
+                vec![]
+            ))
+        );
+
+        // Gap between updates discards the book & returns an error
+        let (symbol, message) = update(
+            r#"{"stream":"btcusdt@depth@100ms","data":{"e":"depthUpdate","E":1571889248280,
+            "T":1571889248279,"s":"BTCUSDT","U":170,"u":175,"pu":165,"b":[],"a":[]}}"#,
+        );
+        assert!(books.update(&symbol, message).is_err());
+
+        // Unknown symbol
+        assert!(books
+            .update(
+                "ETHUSDT",
+                snapshot(r#"{"lastUpdateId":1,"bids":[],"asks":[]}"#)
+            )
+            .is_err());
+    }
+}
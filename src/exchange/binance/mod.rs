@@ -1,7 +1,54 @@
-/// Binance & [`BinanceFuturesUsd`](futures::BinanceFuturesUsd) specific data structures.
+/// Binance, [`BinanceFuturesUsd`](futures::BinanceFuturesUsd) &
+/// [`BinanceFuturesCoin`](futures_coin::BinanceFuturesCoin) specific data structures.
 pub mod model;
 
 /// [`BinanceFuturesUsd`](futures::BinanceFuturesUsd) specific [`Subscriber`](crate::Subscriber) &
 /// [`ExchangeTransformer`](crate::ExchangeTransformer) implementor for the collection of
 /// Futures data.
 pub mod futures;
+
+/// [`BinanceFuturesCoin`](futures_coin::BinanceFuturesCoin) specific
+/// [`Subscriber`](crate::Subscriber) & [`ExchangeTransformer`](crate::ExchangeTransformer)
+/// implementor for the collection of coin margined Futures data.
+pub mod futures_coin;
+
+/// [`BinanceFuturesUsdSandbox`](sandbox::BinanceFuturesUsdSandbox) &
+/// [`BinanceFuturesCoinSandbox`](sandbox::BinanceFuturesCoinSandbox) [`Subscriber`](crate::Subscriber)
+/// & [`ExchangeTransformer`](crate::ExchangeTransformer) implementors for the Binance futures
+/// testnets.
+pub mod sandbox;
+
+/// [`BinanceUs`](us::BinanceUs) specific [`Subscriber`](crate::Subscriber) &
+/// [`ExchangeTransformer`](crate::ExchangeTransformer) implementor for the collection of
+/// Binance.US Spot data.
+pub mod us;
+
+/// [`BinanceDepthManager`](depth::BinanceDepthManager) utility for maintaining a consistent
+/// [`OrderBook`](crate::model::OrderBook) from Binance partial or diff depth streams.
+pub mod depth;
+
+/// `Binance` diff depth [`OrderBook`](crate::model::OrderBook)s synchronised from REST depth
+/// snapshots & maintained via a [`BinanceDepthManager`](depth::BinanceDepthManager).
+pub mod managed;
+
+/// `Binance` private user data stream models normalised into account events (eg/
+/// [`OrderUpdate`](crate::model::OrderUpdate), [`BalanceUpdate`](crate::model::BalanceUpdate),
+/// [`PositionUpdate`](crate::model::PositionUpdate)).
+pub mod user;
+
+/// Authenticated `Binance` user data stream, creating & keeping alive a listenKey via REST and
+/// distributing the account updates of [`SubKind::UserData`](crate::model::subscription::SubKind)
+/// [`Subscription`](crate::model::subscription::Subscription)s as [`MarketEvent`](crate::MarketEvent)s.
+pub mod user_data;
+
+/// `Binance` exchangeInfo poller distributing normalised
+/// [`InstrumentStatus`](crate::model::InstrumentStatus) changes.
+pub mod status;
+
+/// `Binance` openInterest poller distributing normalised
+/// [`OpenInterest`](crate::model::OpenInterest) updates.
+pub mod open_interest;
+
+/// Opt-in [`BinanceSbe`](sbe::BinanceSbe) market data stream decoding `Binance` Spot trades & depth
+/// updates from Simple Binary Encoding (SBE) frames rather than JSON.
+pub mod sbe;
@@ -1,18 +1,25 @@
-use super::futures::BinanceFuturesUsd;
-use crate::model::{Level, Liquidation, OrderBook};
+use super::{depth::BinanceDepthSnapshot, futures::BinanceFuturesUsd, us::BinanceUs};
+use crate::model::{
+    AggTrade, Candle, FundingRate, FutureContract, Level, Liquidation, MarkPrice, OrderBook,
+    OrderBookL1, OrderBookL2Snapshot, RollingTicker, Ticker,
+};
 use crate::{
-    model::{DataKind, PublicTrade},
-    ExchangeId, MarketEvent,
+    model::{
+        subscription::{SubKind, TickerWindow},
+        DataKind, PublicTrade,
+    },
+    ExchangeId, MarketEvent, Subscription, SubscriptionIds,
 };
 use barter_integration::{
     error::SocketError,
-    model::{Exchange, Instrument, Side, SubscriptionId},
+    model::{Exchange, Instrument, InstrumentKind, Side, SubscriptionId},
     Validator,
 };
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
+use std::str::FromStr;
 
-/// `Binance` & `BinanceFuturesUsd` `Subscription` response message.
+/// `Binance`, `BinanceFuturesUsd` & `BinanceFuturesCoin` `Subscription` response message.
 ///
 /// See docs: <https://binance-docs.github.io/apidocs/spot/en/#live-subscribing-unsubscribing-to-streams>
 #[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
@@ -36,6 +43,160 @@ impl Validator for BinanceSubResponse {
     }
 }
 
+/// `Binance` combined stream envelope wrapping each message with the 'StreamName' it was
+/// received from.
+///
+/// eg/ {"stream":"btcusdt@aggTrade","data":{"e":"aggTrade", ...}}
+///
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#websocket-market-streams>
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BinanceCombinedStream<T> {
+    pub stream: String,
+    pub data: T,
+}
+
+impl<T> BinanceCombinedStream<T> {
+    /// Determine the [`SubscriptionId`] associated with the 'StreamName' this message was
+    /// received from.
+    ///
+    /// eg/ "btcusdt@markPrice" => SubscriptionId("@markPrice|BTCUSDT")
+    pub fn stream_subscription_id(&self) -> SubscriptionId {
+        let (market, channel) = self
+            .stream
+            .split_at(self.stream.find('@').unwrap_or(self.stream.len()));
+        BinanceFuturesUsd::subscription_id(channel, &market.to_uppercase())
+    }
+
+    /// Determine the uppercase symbol of the 'StreamName' this message was received from.
+    ///
+    /// eg/ "btcusdt@depth@100ms" => "BTCUSDT"
+    pub fn stream_symbol(&self) -> String {
+        self.stream
+            .split('@')
+            .next()
+            .unwrap_or_default()
+            .to_uppercase()
+    }
+}
+
+impl BinanceCombinedStream<BinanceMessage> {
+    /// Determines if this message was received from a
+    /// [`BinanceFuturesUsd::CHANNEL_FUNDING_RATE`] 'StreamName'. Both the mark price & funding
+    /// rate 'StreamName's send [`BinanceMarkPrice`] messages, so only the 'StreamName' can tell
+    /// them apart.
+    pub fn is_funding_rate(&self) -> bool {
+        matches!(self.data, BinanceMessage::MarkPrice(_))
+            && self
+                .stream
+                .ends_with(BinanceFuturesUsd::CHANNEL_FUNDING_RATE)
+    }
+
+    /// Determine the [`SubscriptionId`] associated with this message.
+    pub fn subscription_id(&self) -> SubscriptionId {
+        if self.is_funding_rate() {
+            self.stream_subscription_id()
+        } else {
+            self.data.subscription_id()
+        }
+    }
+}
+
+impl
+    From<(
+        ExchangeId,
+        Instrument,
+        BinanceCombinedStream<BinanceMessage>,
+    )> for MarketEvent
+{
+    fn from(
+        (exchange, instrument, message): (
+            ExchangeId,
+            Instrument,
+            BinanceCombinedStream<BinanceMessage>,
+        ),
+    ) -> Self {
+        let is_funding_rate = message.is_funding_rate();
+        match message.data {
+            BinanceMessage::MarkPrice(mark_price) if is_funding_rate => {
+                MarketEvent::from((exchange, instrument, BinanceFundingRate(mark_price)))
+            }
+            data => MarketEvent::from((exchange, instrument, data)),
+        }
+    }
+}
+
+/// `Binance` combined stream messages that could be received over
+/// [`WebSocket`](crate::WebSocket). All market 'StreamNames' (eg/ "!miniTicker@arr") deliver an
+/// array of messages, and `Spot` partial book depth 'StreamNames' deliver an untagged
+/// [`BinanceDepthSnapshot`], whereas every other 'StreamName' delivers a single
+/// [`BinanceMessage`].
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum BinanceStream {
+    Message(BinanceCombinedStream<BinanceMessage>),
+    MiniTickers(BinanceCombinedStream<Vec<BinanceMiniTicker>>),
+    PartialDepth(BinanceCombinedStream<BinanceDepthSnapshot>),
+}
+
+/// Map the [`BinanceMiniTicker`]s received via the all market mini ticker 'StreamName' into
+/// [`Ticker`] [`MarketEvent`]s. Markets without an associated [`Subscription`](crate::Subscription)
+/// are skipped, since every market on the exchange is received.
+pub fn mini_ticker_events(
+    exchange: ExchangeId,
+    ids: &SubscriptionIds,
+    mini_tickers: Vec<BinanceMiniTicker>,
+) -> Vec<Result<MarketEvent, SocketError>> {
+    mini_tickers
+        .into_iter()
+        .filter_map(|mini_ticker| {
+            ids.find_instrument(&mini_ticker.subscription_id)
+                .ok()
+                .map(|instrument| Ok(MarketEvent::from((exchange, instrument, mini_ticker))))
+        })
+        .collect()
+}
+
+/// Map a `Binance` partial book depth message into a [`MarketEvent`]. The depth & update speed
+/// are not included in the message, so the [`Subscription`](crate::Subscription) is identified
+/// by the 'StreamName' it was received from.
+///
+/// [`SubKind::OrderBookL2Snapshot`] subscriptions receive an [`OrderBookL2Snapshot`], whereas
+/// [`SubKind::OrderBook`] subscriptions receive an [`OrderBook`].
+pub fn partial_depth_events<T>(
+    exchange: ExchangeId,
+    ids: &SubscriptionIds,
+    message: BinanceCombinedStream<T>,
+) -> Vec<Result<MarketEvent, SocketError>>
+where
+    T: Into<OrderBookL2Snapshot>,
+{
+    let subscription_id = message.stream_subscription_id();
+    let snapshot: OrderBookL2Snapshot = message.data.into();
+    let exchange_time = snapshot.last_update_time;
+
+    let (instrument, kind) = match ids.get(&subscription_id) {
+        Some(Subscription {
+            instrument,
+            kind: SubKind::OrderBookL2Snapshot(..),
+            ..
+        }) => (instrument, DataKind::OrderBookL2Snapshot(snapshot)),
+        Some(Subscription { instrument, .. }) => {
+            (instrument, DataKind::OrderBook(OrderBook::from(snapshot)))
+        }
+        None => return vec![Err(SocketError::Unidentifiable(subscription_id))],
+    };
+
+    vec![Ok(MarketEvent {
+        exchange_time,
+        received_time: Utc::now(),
+        received_instant: None,
+        raw: None,
+        exchange: Exchange::from(exchange),
+        instrument: instrument.clone(),
+        kind,
+    })]
+}
+
 /// `Binance` message variants that could be received over [`WebSocket`](crate::WebSocket).
 #[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
 #[serde(tag = "e", rename_all = "camelCase")]
@@ -46,6 +207,38 @@ pub enum BinanceMessage {
     OrderBookSnapshot(BinanceOrderBook),
     #[serde(alias = "forceOrder")]
     Liquidation(BinanceLiquidation),
+    #[serde(alias = "kline")]
+    Candle(BinanceKline),
+    #[serde(alias = "markPriceUpdate")]
+    MarkPrice(BinanceMarkPrice),
+    #[serde(alias = "bookTicker")]
+    BookTicker(BinanceBookTicker),
+    #[serde(alias = "24hrTicker")]
+    Ticker(BinanceTicker),
+    #[serde(rename = "1hTicker")]
+    RollingTicker1h(BinanceRollingTicker),
+    #[serde(rename = "4hTicker")]
+    RollingTicker4h(BinanceRollingTicker),
+    #[serde(rename = "1dTicker")]
+    RollingTicker1d(BinanceRollingTicker),
+}
+
+impl BinanceMessage {
+    /// Determine the [`SubscriptionId`] associated with this [`BinanceMessage`].
+    pub fn subscription_id(&self) -> SubscriptionId {
+        match self {
+            BinanceMessage::Trade(trade) => trade.subscription_id.clone(),
+            BinanceMessage::OrderBookSnapshot(snapshot) => snapshot.subscription_id.clone(),
+            BinanceMessage::Liquidation(liquidation) => liquidation.order.subscription_id.clone(),
+            BinanceMessage::Candle(kline) => kline.subscription_id(),
+            BinanceMessage::MarkPrice(mark_price) => mark_price.subscription_id.clone(),
+            BinanceMessage::BookTicker(book_ticker) => book_ticker.subscription_id.clone(),
+            BinanceMessage::Ticker(ticker) => ticker.subscription_id.clone(),
+            BinanceMessage::RollingTicker1h(ticker) => ticker.subscription_id(TickerWindow::Hour1),
+            BinanceMessage::RollingTicker4h(ticker) => ticker.subscription_id(TickerWindow::Hour4),
+            BinanceMessage::RollingTicker1d(ticker) => ticker.subscription_id(TickerWindow::Day1),
+        }
+    }
 }
 
 impl From<(ExchangeId, Instrument, BinanceMessage)> for MarketEvent {
@@ -58,13 +251,144 @@ impl From<(ExchangeId, Instrument, BinanceMessage)> for MarketEvent {
             BinanceMessage::Liquidation(liquidation) => {
                 MarketEvent::from((exchange, instrument, liquidation))
             }
+            BinanceMessage::Candle(kline) => MarketEvent::from((exchange, instrument, kline)),
+            BinanceMessage::MarkPrice(mark_price) => {
+                MarketEvent::from((exchange, instrument, mark_price))
+            }
+            BinanceMessage::BookTicker(book_ticker) => {
+                MarketEvent::from((exchange, instrument, book_ticker))
+            }
+            BinanceMessage::Ticker(ticker) => MarketEvent::from((exchange, instrument, ticker)),
+            BinanceMessage::RollingTicker1h(ticker) => {
+                MarketEvent::from((exchange, instrument, (TickerWindow::Hour1, ticker)))
+            }
+            BinanceMessage::RollingTicker4h(ticker) => {
+                MarketEvent::from((exchange, instrument, (TickerWindow::Hour4, ticker)))
+            }
+            BinanceMessage::RollingTicker1d(ticker) => {
+                MarketEvent::from((exchange, instrument, (TickerWindow::Day1, ticker)))
+            }
         }
     }
 }
 
-/// `Binance` real-time trade message.
+/// `BinanceFuturesCoin` coin margined contract parsed from a symbol, either perpetual
+/// (eg/ "BTCUSD_PERP") or delivering on an expiry date (eg/ "BTCUSD_240628").
 ///
-/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#trade-streams>
+/// See docs: <https://binance-docs.github.io/apidocs/delivery/en/#exchange-information>
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct BinanceCoinContract {
+    pub base: String,
+    pub quote: String,
+    /// Delivery date of the contract, or `None` if the contract is perpetual.
+    pub expiry: Option<NaiveDate>,
+}
+
+impl BinanceCoinContract {
+    /// Quote asset every `BinanceFuturesCoin` contract is denominated in.
+    pub const QUOTE: &'static str = "usd";
+
+    /// Symbol suffix identifying a perpetual contract.
+    pub const PERPETUAL: &'static str = "PERP";
+
+    /// Format of the symbol suffix identifying the expiry date of a delivery contract.
+    pub const EXPIRY_FORMAT: &'static str = "%y%m%d";
+
+    /// Build the `BinanceFuturesCoin` symbol associated with this [`BinanceCoinContract`]
+    /// (eg/ "BTCUSD_PERP", "BTCUSD_240628").
+    pub fn symbol(&self) -> String {
+        let suffix = match self.expiry {
+            Some(expiry) => expiry.format(Self::EXPIRY_FORMAT).to_string(),
+            None => Self::PERPETUAL.to_owned(),
+        };
+
+        format!("{}{}_{}", self.base, self.quote, suffix).to_uppercase()
+    }
+
+    /// Normalise this [`BinanceCoinContract`] into a Barter [`Instrument`]. Delivery contracts
+    /// are encoded as a [`FutureContract`].
+    pub fn instrument(&self) -> Instrument {
+        match self.expiry {
+            None => Instrument::from((
+                self.base.as_str(),
+                self.quote.as_str(),
+                InstrumentKind::FuturePerpetual,
+            )),
+            Some(expiry) => Instrument::from(&FutureContract {
+                underlying: self.base.clone(),
+                quote: self.quote.clone(),
+                expiry,
+            }),
+        }
+    }
+}
+
+impl FromStr for BinanceCoinContract {
+    type Err = SocketError;
+
+    fn from_str(symbol: &str) -> Result<Self, Self::Err> {
+        let unsupported = || SocketError::Unsupported {
+            entity: ExchangeId::BinanceFuturesCoin.as_str(),
+            item: symbol.to_owned(),
+        };
+
+        let symbol_lower = symbol.to_lowercase();
+        let (pair, suffix) = symbol_lower.split_once('_').ok_or_else(unsupported)?;
+
+        let base = match pair.strip_suffix(Self::QUOTE) {
+            Some(base) if !base.is_empty() => base,
+            _ => return Err(unsupported()),
+        };
+
+        let expiry = if suffix.eq_ignore_ascii_case(Self::PERPETUAL) {
+            None
+        } else {
+            Some(
+                NaiveDate::parse_from_str(suffix, Self::EXPIRY_FORMAT)
+                    .map_err(|_| unsupported())?,
+            )
+        };
+
+        Ok(Self {
+            base: base.to_owned(),
+            quote: Self::QUOTE.to_owned(),
+            expiry,
+        })
+    }
+}
+
+impl TryFrom<&Instrument> for BinanceCoinContract {
+    type Error = SocketError;
+
+    fn try_from(instrument: &Instrument) -> Result<Self, Self::Error> {
+        match instrument.kind {
+            InstrumentKind::FuturePerpetual if instrument.quote.as_ref() == Self::QUOTE => {
+                // Delivery contracts are encoded as a FutureContract, else perpetual
+                Ok(match FutureContract::try_from(instrument) {
+                    Ok(contract) => Self {
+                        base: contract.underlying,
+                        quote: contract.quote,
+                        expiry: Some(contract.expiry),
+                    },
+                    Err(_) => Self {
+                        base: instrument.base.to_string(),
+                        quote: instrument.quote.to_string(),
+                        expiry: None,
+                    },
+                })
+            }
+            _ => Err(SocketError::Unsupported {
+                entity: ExchangeId::BinanceFuturesCoin.as_str(),
+                item: format!("{} {}", instrument.quote, instrument.kind),
+            }),
+        }
+    }
+}
+
+/// `Binance` aggregated trade message, aggregating the trades filled at the same price by a single
+/// taker order.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#aggregate-trade-streams>
 #[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
 pub struct BinanceTrade {
     #[serde(alias = "s", deserialize_with = "de_trade_subscription_id")]
@@ -74,8 +398,12 @@ pub struct BinanceTrade {
         deserialize_with = "crate::exchange::de_u64_epoch_ms_as_datetime_utc"
     )]
     pub time: DateTime<Utc>,
-    #[serde(alias = "a")]
+    #[serde(alias = "a", deserialize_with = "crate::exchange::de_str")]
     pub id: u64,
+    #[serde(alias = "f", deserialize_with = "crate::exchange::de_str")]
+    pub first_trade_id: u64,
+    #[serde(alias = "l", deserialize_with = "crate::exchange::de_str")]
+    pub last_trade_id: u64,
     #[serde(alias = "p", deserialize_with = "crate::exchange::de_str")]
     pub price: f64,
     #[serde(alias = "q", deserialize_with = "crate::exchange::de_str")]
@@ -89,6 +417,8 @@ impl From<(ExchangeId, Instrument, BinanceTrade)> for MarketEvent {
         Self {
             exchange_time: trade.time,
             received_time: Utc::now(),
+            received_instant: None,
+            raw: None,
             exchange: Exchange::from(exchange_id),
             instrument,
             kind: DataKind::Trade(PublicTrade {
@@ -101,6 +431,47 @@ impl From<(ExchangeId, Instrument, BinanceTrade)> for MarketEvent {
     }
 }
 
+/// Map a [`BinanceTrade`] into a [`MarketEvent`]. [`SubKind::Trade`] & [`SubKind::AggTrade`]
+/// subscriptions share the "@aggTrade" 'StreamName', so the associated
+/// [`Subscription`](crate::Subscription) determines the kind of [`MarketEvent`] produced.
+///
+/// [`SubKind::AggTrade`] subscriptions receive an [`AggTrade`], whereas [`SubKind::Trade`]
+/// subscriptions receive a [`PublicTrade`].
+pub fn trade_events(
+    exchange: ExchangeId,
+    ids: &SubscriptionIds,
+    trade: BinanceTrade,
+) -> Vec<Result<MarketEvent, SocketError>> {
+    let instrument = match ids.get(&trade.subscription_id) {
+        Some(Subscription {
+            instrument,
+            kind: SubKind::AggTrade,
+            ..
+        }) => instrument.clone(),
+        Some(Subscription { instrument, .. }) => {
+            return vec![Ok(MarketEvent::from((exchange, instrument.clone(), trade)))]
+        }
+        None => return vec![Err(SocketError::Unidentifiable(trade.subscription_id))],
+    };
+
+    vec![Ok(MarketEvent {
+        exchange_time: trade.time,
+        received_time: Utc::now(),
+        received_instant: None,
+        raw: None,
+        exchange: Exchange::from(exchange),
+        instrument,
+        kind: DataKind::AggTrade(AggTrade {
+            id: trade.id.to_string(),
+            first_trade_id: trade.first_trade_id.to_string(),
+            last_trade_id: trade.last_trade_id.to_string(),
+            price: trade.price,
+            quantity: trade.quantity,
+            side: trade.side,
+        }),
+    })]
+}
+
 /// `Binance` OrderBook snapshot message.
 ///
 /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#partial-book-depth-streams>
@@ -115,7 +486,7 @@ pub struct BinanceOrderBook {
     )]
     pub time: DateTime<Utc>,
 
-    #[serde(alias = "u")]
+    #[serde(alias = "u", deserialize_with = "crate::exchange::de_str")]
     pub last_update_id: u64,
 
     #[serde(alias = "b")]
@@ -143,6 +514,8 @@ impl From<(ExchangeId, Instrument, BinanceOrderBook)> for MarketEvent {
         Self {
             exchange_time: snapshot.time,
             received_time: Utc::now(),
+            received_instant: None,
+            raw: None,
             exchange: Exchange::from(exchange_id),
             instrument,
             kind: DataKind::OrderBook(OrderBook {
@@ -155,6 +528,29 @@ impl From<(ExchangeId, Instrument, BinanceOrderBook)> for MarketEvent {
     }
 }
 
+impl From<BinanceOrderBook> for OrderBookL2Snapshot {
+    fn from(snapshot: BinanceOrderBook) -> Self {
+        Self {
+            last_update_time: snapshot.time,
+            last_update_id: snapshot.last_update_id,
+            bids: snapshot.bids.into_iter().map(Level::from).collect(),
+            asks: snapshot.asks.into_iter().map(Level::from).collect(),
+        }
+    }
+}
+
+impl From<BinanceDepthSnapshot> for OrderBookL2Snapshot {
+    /// `Spot` partial book depth snapshots do not contain a time, so the time received is used.
+    fn from(snapshot: BinanceDepthSnapshot) -> Self {
+        Self {
+            last_update_time: Utc::now(),
+            last_update_id: snapshot.last_update_id,
+            bids: snapshot.bids.into_iter().map(Level::from).collect(),
+            asks: snapshot.asks.into_iter().map(Level::from).collect(),
+        }
+    }
+}
+
 impl From<BinanceLevel> for Level {
     fn from(level: BinanceLevel) -> Self {
         Self {
@@ -180,6 +576,8 @@ impl From<(ExchangeId, Instrument, BinanceLiquidation)> for MarketEvent {
         Self {
             exchange_time: liquidation.order.time,
             received_time: Utc::now(),
+            received_instant: None,
+            raw: None,
             exchange: Exchange::from(exchange_id),
             instrument,
             kind: DataKind::Liquidation(Liquidation {
@@ -227,6 +625,391 @@ pub struct BinanceLiquidationOrder {
     pub time: DateTime<Utc>,
 }
 
+/// `Binance` kline/candlestick message.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#kline-candlestick-streams>
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BinanceKline {
+    #[serde(alias = "k")]
+    pub kline: BinanceKlineData,
+}
+
+impl BinanceKline {
+    /// Build the [`SubscriptionId`] associated with this [`BinanceKline`] from it's interval &
+    /// market (eg/ "@kline_1m|BTCUSDT").
+    pub fn subscription_id(&self) -> SubscriptionId {
+        BinanceFuturesUsd::subscription_id(
+            &format!(
+                "{}{}",
+                BinanceFuturesUsd::CHANNEL_CANDLES,
+                self.kline.interval
+            ),
+            &self.kline.market,
+        )
+    }
+}
+
+/// `Binance` kline/candlestick data.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#kline-candlestick-streams>
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BinanceKlineData {
+    #[serde(alias = "s")]
+    pub market: String,
+    #[serde(alias = "i")]
+    pub interval: String,
+    #[serde(
+        alias = "t",
+        deserialize_with = "crate::exchange::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub start_time: DateTime<Utc>,
+    #[serde(
+        alias = "T",
+        deserialize_with = "crate::exchange::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub end_time: DateTime<Utc>,
+    #[serde(alias = "o", deserialize_with = "crate::exchange::de_str")]
+    pub open: f64,
+    #[serde(alias = "h", deserialize_with = "crate::exchange::de_str")]
+    pub high: f64,
+    #[serde(alias = "l", deserialize_with = "crate::exchange::de_str")]
+    pub low: f64,
+    #[serde(alias = "c", deserialize_with = "crate::exchange::de_str")]
+    pub close: f64,
+    #[serde(alias = "v", deserialize_with = "crate::exchange::de_str")]
+    pub volume: f64,
+    #[serde(alias = "n")]
+    pub trade_count: u64,
+}
+
+impl From<(ExchangeId, Instrument, BinanceKline)> for MarketEvent {
+    fn from((exchange_id, instrument, kline): (ExchangeId, Instrument, BinanceKline)) -> Self {
+        Self {
+            exchange_time: kline.kline.end_time,
+            received_time: Utc::now(),
+            received_instant: None,
+            raw: None,
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: DataKind::Candle(Candle {
+                start_time: kline.kline.start_time,
+                end_time: kline.kline.end_time,
+                open: kline.kline.open,
+                high: kline.kline.high,
+                low: kline.kline.low,
+                close: kline.kline.close,
+                volume: kline.kline.volume,
+                trade_count: kline.kline.trade_count,
+            }),
+        }
+    }
+}
+
+/// `Binance` mark price & funding rate message.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#mark-price-stream>
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BinanceMarkPrice {
+    #[serde(alias = "s", deserialize_with = "de_mark_price_subscription_id")]
+    pub subscription_id: SubscriptionId,
+    #[serde(
+        alias = "E",
+        deserialize_with = "crate::exchange::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+    #[serde(alias = "p", deserialize_with = "crate::exchange::de_str")]
+    pub price: f64,
+    #[serde(alias = "i", deserialize_with = "crate::exchange::de_str")]
+    pub index_price: f64,
+    #[serde(alias = "r", deserialize_with = "crate::exchange::de_str")]
+    pub funding_rate: f64,
+    #[serde(
+        alias = "T",
+        deserialize_with = "crate::exchange::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub next_funding_time: DateTime<Utc>,
+}
+
+impl From<(ExchangeId, Instrument, BinanceMarkPrice)> for MarketEvent {
+    fn from(
+        (exchange_id, instrument, mark_price): (ExchangeId, Instrument, BinanceMarkPrice),
+    ) -> Self {
+        Self {
+            exchange_time: mark_price.time,
+            received_time: Utc::now(),
+            received_instant: None,
+            raw: None,
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: DataKind::MarkPrice(MarkPrice {
+                price: mark_price.price,
+                index_price: mark_price.index_price,
+                funding_rate: mark_price.funding_rate,
+                next_funding_time: mark_price.next_funding_time,
+            }),
+        }
+    }
+}
+
+/// `Binance` funding rate, received as a [`BinanceMarkPrice`] message via a
+/// [`BinanceFuturesUsd::CHANNEL_FUNDING_RATE`] 'StreamName'.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#mark-price-stream>
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BinanceFundingRate(pub BinanceMarkPrice);
+
+impl From<(ExchangeId, Instrument, BinanceFundingRate)> for MarketEvent {
+    fn from(
+        (exchange_id, instrument, BinanceFundingRate(mark_price)): (
+            ExchangeId,
+            Instrument,
+            BinanceFundingRate,
+        ),
+    ) -> Self {
+        Self {
+            exchange_time: mark_price.time,
+            received_time: Utc::now(),
+            received_instant: None,
+            raw: None,
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: DataKind::FundingRate(FundingRate {
+                rate: mark_price.funding_rate,
+                next_funding_time: mark_price.next_funding_time,
+            }),
+        }
+    }
+}
+
+/// `Binance` best bid & best ask (ie/ top of book) message, pushed in real-time whenever either
+/// changes.
+///
+/// eg/ {"e":"bookTicker","u":400900217,"E":1568014460893,"T":1568014460891,"s":"BNBUSDT",
+///      "b":"25.35190000","B":"31.21000000","a":"25.36520000","A":"40.66000000"}
+///
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#individual-symbol-book-ticker-streams>
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BinanceBookTicker {
+    #[serde(alias = "s", deserialize_with = "de_book_ticker_subscription_id")]
+    pub subscription_id: SubscriptionId,
+    #[serde(
+        alias = "T",
+        deserialize_with = "crate::exchange::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+    #[serde(alias = "b", deserialize_with = "crate::exchange::de_str")]
+    pub best_bid_price: f64,
+    #[serde(alias = "B", deserialize_with = "crate::exchange::de_str")]
+    pub best_bid_quantity: f64,
+    #[serde(alias = "a", deserialize_with = "crate::exchange::de_str")]
+    pub best_ask_price: f64,
+    #[serde(alias = "A", deserialize_with = "crate::exchange::de_str")]
+    pub best_ask_quantity: f64,
+}
+
+impl From<(ExchangeId, Instrument, BinanceBookTicker)> for MarketEvent {
+    fn from(
+        (exchange_id, instrument, book_ticker): (ExchangeId, Instrument, BinanceBookTicker),
+    ) -> Self {
+        Self {
+            exchange_time: book_ticker.time,
+            received_time: Utc::now(),
+            received_instant: None,
+            raw: None,
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: DataKind::OrderBookL1(OrderBookL1 {
+                last_update_time: book_ticker.time,
+                best_bid: Level {
+                    price: book_ticker.best_bid_price,
+                    quantity: book_ticker.best_bid_quantity,
+                },
+                best_ask: Level {
+                    price: book_ticker.best_ask_price,
+                    quantity: book_ticker.best_ask_quantity,
+                },
+            }),
+        }
+    }
+}
+
+/// `Binance` rolling 24 hour ticker statistics message, pushed every second.
+///
+/// eg/ {"e":"24hrTicker","E":123456789,"s":"BTCUSDT","p":"0.0015","P":"250.00","w":"0.0018",
+///      "c":"0.0025","Q":"10","o":"0.0010","h":"0.0025","l":"0.0010","v":"10000","q":"18", ...}
+///
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#individual-symbol-ticker-streams>
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BinanceTicker {
+    #[serde(alias = "s", deserialize_with = "de_ticker_subscription_id")]
+    pub subscription_id: SubscriptionId,
+    #[serde(
+        alias = "E",
+        deserialize_with = "crate::exchange::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+    #[serde(alias = "c", deserialize_with = "crate::exchange::de_str")]
+    pub last_price: f64,
+    #[serde(alias = "h", deserialize_with = "crate::exchange::de_str")]
+    pub high: f64,
+    #[serde(alias = "l", deserialize_with = "crate::exchange::de_str")]
+    pub low: f64,
+    #[serde(alias = "v", deserialize_with = "crate::exchange::de_str")]
+    pub volume: f64,
+    #[serde(alias = "P", deserialize_with = "crate::exchange::de_str")]
+    pub change_percent: f64,
+}
+
+impl From<(ExchangeId, Instrument, BinanceTicker)> for MarketEvent {
+    fn from((exchange_id, instrument, ticker): (ExchangeId, Instrument, BinanceTicker)) -> Self {
+        Self {
+            exchange_time: ticker.time,
+            received_time: Utc::now(),
+            received_instant: None,
+            raw: None,
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: DataKind::Ticker(Ticker {
+                last_price: ticker.last_price,
+                volume_24h: ticker.volume,
+                high_24h: ticker.high,
+                low_24h: ticker.low,
+                change_percent_24h: ticker.change_percent,
+            }),
+        }
+    }
+}
+
+/// `Binance` rolling window ticker statistics message, pushed every second. The window is
+/// identified by the event type (eg/ "1hTicker", "4hTicker" or "1dTicker"), so it is not
+/// contained in the [`BinanceRollingTicker`] itself.
+///
+/// eg/ {"e":"1hTicker","E":123456789,"s":"BNBBTC","p":"0.0015","P":"250.00","o":"0.0010",
+///      "h":"0.0025","l":"0.0010","c":"0.0025","w":"0.0018","v":"10000","q":"18","n":18151, ...}
+///
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#individual-symbol-rolling-window-statistics-streams>
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BinanceRollingTicker {
+    #[serde(alias = "s")]
+    pub market: String,
+    #[serde(
+        alias = "E",
+        deserialize_with = "crate::exchange::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+    #[serde(alias = "o", deserialize_with = "crate::exchange::de_str")]
+    pub open: f64,
+    #[serde(alias = "c", deserialize_with = "crate::exchange::de_str")]
+    pub last_price: f64,
+    #[serde(alias = "h", deserialize_with = "crate::exchange::de_str")]
+    pub high: f64,
+    #[serde(alias = "l", deserialize_with = "crate::exchange::de_str")]
+    pub low: f64,
+    #[serde(alias = "v", deserialize_with = "crate::exchange::de_str")]
+    pub volume: f64,
+    #[serde(alias = "q", deserialize_with = "crate::exchange::de_str")]
+    pub quote_volume: f64,
+    #[serde(alias = "P", deserialize_with = "crate::exchange::de_str")]
+    pub change_percent: f64,
+    #[serde(alias = "n")]
+    pub trade_count: u64,
+}
+
+impl BinanceRollingTicker {
+    /// Build the [`SubscriptionId`] associated with this [`BinanceRollingTicker`] from the
+    /// provided [`TickerWindow`] & it's market (eg/ "@ticker_1h|BTCUSD").
+    pub fn subscription_id(&self, window: TickerWindow) -> SubscriptionId {
+        BinanceFuturesUsd::subscription_id(&BinanceUs::rolling_ticker_channel(window), &self.market)
+    }
+}
+
+impl From<(ExchangeId, Instrument, (TickerWindow, BinanceRollingTicker))> for MarketEvent {
+    fn from(
+        (exchange_id, instrument, (window, ticker)): (
+            ExchangeId,
+            Instrument,
+            (TickerWindow, BinanceRollingTicker),
+        ),
+    ) -> Self {
+        Self {
+            exchange_time: ticker.time,
+            received_time: Utc::now(),
+            received_instant: None,
+            raw: None,
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: DataKind::RollingTicker(RollingTicker {
+                window,
+                open: ticker.open,
+                last_price: ticker.last_price,
+                high: ticker.high,
+                low: ticker.low,
+                volume: ticker.volume,
+                quote_volume: ticker.quote_volume,
+                change_percent: ticker.change_percent,
+                trade_count: ticker.trade_count,
+            }),
+        }
+    }
+}
+
+/// `Binance` rolling 24 hour mini ticker statistics message, pushed every second for every
+/// market that changed via the all market mini ticker 'StreamName'.
+///
+/// eg/ {"e":"24hrMiniTicker","E":123456789,"s":"BTCUSDT","c":"0.0025","o":"0.0010",
+///      "h":"0.0025","l":"0.0010","v":"10000","q":"18"}
+///
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#all-market-mini-tickers-stream>
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BinanceMiniTicker {
+    #[serde(alias = "s", deserialize_with = "de_mini_ticker_subscription_id")]
+    pub subscription_id: SubscriptionId,
+    #[serde(
+        alias = "E",
+        deserialize_with = "crate::exchange::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+    #[serde(alias = "c", deserialize_with = "crate::exchange::de_str")]
+    pub last_price: f64,
+    #[serde(alias = "o", deserialize_with = "crate::exchange::de_str")]
+    pub open: f64,
+    #[serde(alias = "h", deserialize_with = "crate::exchange::de_str")]
+    pub high: f64,
+    #[serde(alias = "l", deserialize_with = "crate::exchange::de_str")]
+    pub low: f64,
+    #[serde(alias = "v", deserialize_with = "crate::exchange::de_str")]
+    pub volume: f64,
+}
+
+impl From<(ExchangeId, Instrument, BinanceMiniTicker)> for MarketEvent {
+    fn from(
+        (exchange_id, instrument, mini_ticker): (ExchangeId, Instrument, BinanceMiniTicker),
+    ) -> Self {
+        // Mini tickers do not contain the 24 hour change, so derive it from the 24 hour open
+        let change_percent_24h = if mini_ticker.open == 0.0 {
+            0.0
+        } else {
+            (mini_ticker.last_price - mini_ticker.open) / mini_ticker.open * 100.0
+        };
+
+        Self {
+            exchange_time: mini_ticker.time,
+            received_time: Utc::now(),
+            received_instant: None,
+            raw: None,
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: DataKind::Ticker(Ticker {
+                last_price: mini_ticker.last_price,
+                volume_24h: mini_ticker.volume,
+                high_24h: mini_ticker.high,
+                low_24h: mini_ticker.low,
+                change_percent_24h,
+            }),
+        }
+    }
+}
+
 /// Deserialize a [`BinanceTrade`] "s" (eg/ "BTCUSDT") as the associated [`SubscriptionId`]
 /// (eg/ "@aggTrade|BTCUSDT").
 pub fn de_trade_subscription_id<'de, D>(deserializer: D) -> Result<SubscriptionId, D::Error>
@@ -277,6 +1060,49 @@ where
     })
 }
 
+/// Deserialize a [`BinanceMarkPrice`] "s" (eg/ "BTCUSDT") as the associated [`SubscriptionId`]
+/// (eg/ "@markPrice@1s|BTCUSDT").
+pub fn de_mark_price_subscription_id<'de, D>(deserializer: D) -> Result<SubscriptionId, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    serde::de::Deserialize::deserialize(deserializer).map(|market| {
+        BinanceFuturesUsd::subscription_id(BinanceFuturesUsd::CHANNEL_MARK_PRICE, market)
+    })
+}
+
+/// Deserialize a [`BinanceBookTicker`] "s" (eg/ "BTCUSDT") as the associated [`SubscriptionId`]
+/// (eg/ "@bookTicker|BTCUSDT").
+pub fn de_book_ticker_subscription_id<'de, D>(deserializer: D) -> Result<SubscriptionId, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    serde::de::Deserialize::deserialize(deserializer).map(|market| {
+        BinanceFuturesUsd::subscription_id(BinanceFuturesUsd::CHANNEL_BOOK_TICKER, market)
+    })
+}
+
+/// Deserialize a [`BinanceTicker`] "s" (eg/ "BTCUSDT") as the associated [`SubscriptionId`]
+/// (eg/ "@ticker|BTCUSDT").
+pub fn de_ticker_subscription_id<'de, D>(deserializer: D) -> Result<SubscriptionId, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    serde::de::Deserialize::deserialize(deserializer)
+        .map(|market| BinanceFuturesUsd::subscription_id(BinanceFuturesUsd::CHANNEL_TICKER, market))
+}
+
+/// Deserialize a [`BinanceMiniTicker`] "s" (eg/ "BTCUSDT") as the associated [`SubscriptionId`]
+/// (eg/ "@miniTicker|BTCUSDT").
+pub fn de_mini_ticker_subscription_id<'de, D>(deserializer: D) -> Result<SubscriptionId, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    serde::de::Deserialize::deserialize(deserializer).map(|market| {
+        BinanceFuturesUsd::subscription_id(BinanceFuturesUsd::CHANNEL_MINI_TICKER, market)
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -388,6 +1214,8 @@ mod tests {
                     subscription_id: SubscriptionId::from("@aggTrade|ETHUSDT"),
                     time: datetime_utc_from_epoch_duration(Duration::from_millis(1662494217032)),
                     id: 1048104319,
+                    first_trade_id: 2189899361,
+                    last_trade_id: 2189899363,
                     price: 1575.96,
                     quantity: 0.704,
                     side: Side::Sell,
@@ -403,15 +1231,17 @@ mod tests {
                     subscription_id: SubscriptionId::from("@aggTrade|ETHUSDT"),
                     time: datetime_utc_from_epoch_duration(Duration::from_millis(1662494217032)),
                     id: 1048104319,
+                    first_trade_id: 2189899361,
+                    last_trade_id: 2189899363,
                     price: 1575.96,
                     quantity: 0.704,
                     side: Side::Buy,
                 })),
             },
             TestCase {
-                // TC3: invalid BinanceMessage Spot trade w/ non-string price
+                // TC3: invalid BinanceMessage Spot trade w/ non-numeric price
                 input: r#"{
-                    "e":"aggTrade","E":1662494217187,"a":1048104319,"s":"ETHUSDT","p":1575.96,
+                    "e":"aggTrade","E":1662494217187,"a":1048104319,"s":"ETHUSDT","p":"price",
                     "q":"0.704","f":2189899361,"l":2189899363,"T":1662494217032,"m":false
                 }"#,
                 expected: Err(SocketError::Serde {
@@ -450,6 +1280,105 @@ mod tests {
                     },
                 })),
             },
+            TestCase {
+                // TC5: valid BinanceMessage FuturePerpetualUsd kline
+                input: r#"{
+                    "e":"kline","E":1638747660000,"s":"BTCUSDT",
+                    "k":{
+                        "t":1638747660000,"T":1638747719999,"s":"BTCUSDT","i":"1m",
+                        "f":100,"L":200,"o":"0.0010","c":"0.0020","h":"0.0025","l":"0.0015",
+                        "v":"1000","n":100,"x":false,"q":"1.0000","V":"500","Q":"0.500","B":"123456"
+                    }
+                }"#,
+                expected: Ok(BinanceMessage::Candle(BinanceKline {
+                    kline: BinanceKlineData {
+                        market: "BTCUSDT".to_owned(),
+                        interval: "1m".to_owned(),
+                        start_time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1638747660000,
+                        )),
+                        end_time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1638747719999,
+                        )),
+                        open: 0.0010,
+                        high: 0.0025,
+                        low: 0.0015,
+                        close: 0.0020,
+                        volume: 1000.0,
+                        trade_count: 100,
+                    },
+                })),
+            },
+            TestCase {
+                // TC6: valid BinanceMessage FuturePerpetualUsd mark price
+                input: r#"{
+                    "e":"markPriceUpdate","E":1562305380000,"s":"BTCUSDT","p":"11794.15000000",
+                    "i":"11784.62659091","P":"11784.25641265","r":"0.00038167","T":1562306400000
+                }"#,
+                expected: Ok(BinanceMessage::MarkPrice(BinanceMarkPrice {
+                    subscription_id: SubscriptionId::from("@markPrice@1s|BTCUSDT"),
+                    time: datetime_utc_from_epoch_duration(Duration::from_millis(1562305380000)),
+                    price: 11794.15,
+                    index_price: 11784.62659091,
+                    funding_rate: 0.00038167,
+                    next_funding_time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                        1562306400000,
+                    )),
+                })),
+            },
+            TestCase {
+                // TC7: valid BinanceMessage FuturePerpetualUsd book ticker
+                input: r#"{
+                    "e":"bookTicker","u":400900217,"E":1568014460893,"T":1568014460891,
+                    "s":"BNBUSDT","b":"25.35190000","B":"31.21000000","a":"25.36520000",
+                    "A":"40.66000000"
+                }"#,
+                expected: Ok(BinanceMessage::BookTicker(BinanceBookTicker {
+                    subscription_id: SubscriptionId::from("@bookTicker|BNBUSDT"),
+                    time: datetime_utc_from_epoch_duration(Duration::from_millis(1568014460891)),
+                    best_bid_price: 25.3519,
+                    best_bid_quantity: 31.21,
+                    best_ask_price: 25.3652,
+                    best_ask_quantity: 40.66,
+                })),
+            },
+            TestCase {
+                // TC8: valid BinanceMessage FuturePerpetualUsd 24hr ticker
+                input: r#"{
+                    "e":"24hrTicker","E":123456789,"s":"BTCUSDT","p":"0.0015","P":"250.00",
+                    "w":"0.0018","c":"0.0025","Q":"10","o":"0.0010","h":"0.0025","l":"0.0010",
+                    "v":"10000","q":"18","O":0,"C":86400000,"F":0,"L":18150,"n":18151
+                }"#,
+                expected: Ok(BinanceMessage::Ticker(BinanceTicker {
+                    subscription_id: SubscriptionId::from("@ticker|BTCUSDT"),
+                    time: datetime_utc_from_epoch_duration(Duration::from_millis(123456789)),
+                    last_price: 0.0025,
+                    high: 0.0025,
+                    low: 0.0010,
+                    volume: 10000.0,
+                    change_percent: 250.0,
+                })),
+            },
+            TestCase {
+                // TC9: valid BinanceMessage Spot 4h rolling window ticker
+                input: r#"{
+                    "e":"4hTicker","E":123456789,"s":"BNBBTC","p":"0.0015","P":"250.00",
+                    "o":"0.0010","h":"0.0025","l":"0.0010","c":"0.0025","w":"0.0018",
+                    "v":"10000","q":"18","O":0,"C":14400000,"F":0,"L":18150,"n":18151
+                }"#,
+                expected: Ok(BinanceMessage::RollingTicker4h(BinanceRollingTicker {
+                    market: "BNBBTC".to_owned(),
+                    time: datetime_utc_from_epoch_duration(Duration::from_millis(123456789)),
+                    open: 0.0010,
+                    last_price: 0.0025,
+                    high: 0.0025,
+                    low: 0.0010,
+                    volume: 10000.0,
+                    quote_volume: 18.0,
+                    change_percent: 250.0,
+                    trade_count: 18151,
+                })),
+            },
         ];
 
         for (index, test) in cases.into_iter().enumerate() {
@@ -468,4 +1397,277 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_parse_binance_coin_contract() {
+        struct TestCase {
+            input: &'static str,
+            expected: Result<BinanceCoinContract, SocketError>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: valid perpetual contract
+                input: "BTCUSD_PERP",
+                expected: Ok(BinanceCoinContract {
+                    base: "btc".to_owned(),
+                    quote: "usd".to_owned(),
+                    expiry: None,
+                }),
+            },
+            TestCase {
+                // TC1: valid delivery contract
+                input: "ETHUSD_240628",
+                expected: Ok(BinanceCoinContract {
+                    base: "eth".to_owned(),
+                    quote: "usd".to_owned(),
+                    expiry: NaiveDate::from_ymd_opt(2024, 6, 28),
+                }),
+            },
+            TestCase {
+                // TC2: invalid contract w/o suffix
+                input: "BTCUSD",
+                expected: Err(SocketError::Unsupported {
+                    entity: "",
+                    item: "".to_owned(),
+                }),
+            },
+            TestCase {
+                // TC3: invalid contract w/ non-usd quote
+                input: "BTCUSDT_PERP",
+                expected: Err(SocketError::Unsupported {
+                    entity: "",
+                    item: "".to_owned(),
+                }),
+            },
+            TestCase {
+                // TC4: invalid contract w/ malformed expiry
+                input: "BTCUSD_241332",
+                expected: Err(SocketError::Unsupported {
+                    entity: "",
+                    item: "".to_owned(),
+                }),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = BinanceCoinContract::from_str(test.input);
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => {
+                    assert_eq!(actual.symbol(), test.input, "TC{} failed", index);
+                    assert_eq!(
+                        BinanceCoinContract::try_from(&actual.instrument()).ok(),
+                        Some(expected.clone()),
+                        "TC{} failed",
+                        index
+                    );
+                    assert_eq!(actual, expected, "TC{} failed", index)
+                }
+                (Err(_), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_deserialise_binance_combined_stream() {
+        let input = r#"{
+            "stream": "ethusdt@aggTrade",
+            "data": {
+                "e":"aggTrade","E":1662494217187,"a":1048104319,"s":"ETHUSDT","p":"1575.96",
+                "q":"0.704","f":2189899361,"l":2189899363,"T":1662494217032,"m":true
+            }
+        }"#;
+
+        let actual = serde_json::from_str::<BinanceCombinedStream<BinanceMessage>>(input).unwrap();
+        let expected = BinanceCombinedStream {
+            stream: "ethusdt@aggTrade".to_owned(),
+            data: BinanceMessage::Trade(BinanceTrade {
+                subscription_id: SubscriptionId::from("@aggTrade|ETHUSDT"),
+                time: datetime_utc_from_epoch_duration(Duration::from_millis(1662494217032)),
+                id: 1048104319,
+                first_trade_id: 2189899361,
+                last_trade_id: 2189899363,
+                price: 1575.96,
+                quantity: 0.704,
+                side: Side::Sell,
+            }),
+        };
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_deserialise_binance_stream() {
+        struct TestCase {
+            input: &'static str,
+            expected: Result<BinanceStream, SocketError>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: input single message is deserialised
+                input: r#"{
+                    "stream": "btcusdt@ticker",
+                    "data": {
+                        "e":"24hrTicker","E":1662494217187,"s":"BTCUSDT","p":"100.0","P":"0.5",
+                        "c":"20100.0","o":"20000.0","h":"20500.0","l":"19500.0","v":"1000.0"
+                    }
+                }"#,
+                expected: Ok(BinanceStream::Message(BinanceCombinedStream {
+                    stream: "btcusdt@ticker".to_owned(),
+                    data: BinanceMessage::Ticker(BinanceTicker {
+                        subscription_id: SubscriptionId::from("@ticker|BTCUSDT"),
+                        time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1662494217187,
+                        )),
+                        last_price: 20100.0,
+                        high: 20500.0,
+                        low: 19500.0,
+                        volume: 1000.0,
+                        change_percent: 0.5,
+                    }),
+                })),
+            },
+            TestCase {
+                // TC1: input all market mini ticker array is deserialised
+                input: r#"{
+                    "stream": "!miniTicker@arr",
+                    "data": [
+                        {
+                            "e":"24hrMiniTicker","E":1662494217187,"s":"BTCUSDT","c":"20100.0",
+                            "o":"20000.0","h":"20500.0","l":"19500.0","v":"1000.0","q":"20000000.0"
+                        },
+                        {
+                            "e":"24hrMiniTicker","E":1662494217188,"s":"ETHUSDT","c":"1500.0",
+                            "o":"1600.0","h":"1650.0","l":"1450.0","v":"5000.0","q":"7500000.0"
+                        }
+                    ]
+                }"#,
+                expected: Ok(BinanceStream::MiniTickers(BinanceCombinedStream {
+                    stream: "!miniTicker@arr".to_owned(),
+                    data: vec![
+                        BinanceMiniTicker {
+                            subscription_id: SubscriptionId::from("@miniTicker|BTCUSDT"),
+                            time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                                1662494217187,
+                            )),
+                            last_price: 20100.0,
+                            open: 20000.0,
+                            high: 20500.0,
+                            low: 19500.0,
+                            volume: 1000.0,
+                        },
+                        BinanceMiniTicker {
+                            subscription_id: SubscriptionId::from("@miniTicker|ETHUSDT"),
+                            time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                                1662494217188,
+                            )),
+                            last_price: 1500.0,
+                            open: 1600.0,
+                            high: 1650.0,
+                            low: 1450.0,
+                            volume: 5000.0,
+                        },
+                    ],
+                })),
+            },
+            TestCase {
+                // TC2: input Spot partial book depth snapshot is deserialised
+                input: r#"{
+                    "stream": "btcusdt@depth5@100ms",
+                    "data": {"lastUpdateId":160,"bids":[["0.0024","10"]],"asks":[["0.0026","100"]]}
+                }"#,
+                expected: Ok(BinanceStream::PartialDepth(BinanceCombinedStream {
+                    stream: "btcusdt@depth5@100ms".to_owned(),
+                    data: BinanceDepthSnapshot {
+                        last_update_id: 160,
+                        bids: vec![BinanceLevel {
+                            price: 0.0024,
+                            quantity: 10.0,
+                        }],
+                        asks: vec![BinanceLevel {
+                            price: 0.0026,
+                            quantity: 100.0,
+                        }],
+                    },
+                })),
+            },
+            TestCase {
+                // TC3: input unknown message is rejected
+                input: r#"{"stream": "btcusdt@unknown", "data": {"e":"unknown"}}"#,
+                expected: Err(SocketError::Serde {
+                    error: serde_json::Error::custom(""),
+                    payload: "".to_owned(),
+                }),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = serde_json::from_str::<BinanceStream>(test.input);
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => {
+                    assert_eq!(actual, expected, "TC{} failed", index)
+                }
+                (Err(_), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_mini_ticker_events() {
+        let subscription = crate::Subscription::new(
+            ExchangeId::BinanceFuturesUsd,
+            ("btc", "usdt", InstrumentKind::FuturePerpetual),
+            crate::model::subscription::SubKind::TickerAll,
+        );
+        let ids = SubscriptionIds(std::collections::HashMap::from([(
+            SubscriptionId::from("@miniTicker|BTCUSDT"),
+            subscription,
+        )]));
+        let mini_ticker = |market: &str| BinanceMiniTicker {
+            subscription_id: BinanceFuturesUsd::subscription_id("@miniTicker", market),
+            time: datetime_utc_from_epoch_duration(Duration::from_millis(1662494217187)),
+            last_price: 20100.0,
+            open: 20000.0,
+            high: 20500.0,
+            low: 19500.0,
+            volume: 1000.0,
+        };
+
+        let actual = mini_ticker_events(
+            ExchangeId::BinanceFuturesUsd,
+            &ids,
+            vec![mini_ticker("ETHUSDT"), mini_ticker("BTCUSDT")],
+        );
+
+        // Unsubscribed ETHUSDT market is skipped
+        assert_eq!(actual.len(), 1);
+        let event = actual.into_iter().next().unwrap().unwrap();
+        assert_eq!(
+            event.instrument,
+            Instrument::from(("btc", "usdt", InstrumentKind::FuturePerpetual))
+        );
+        assert_eq!(
+            event.kind,
+            DataKind::Ticker(Ticker {
+                last_price: 20100.0,
+                volume_24h: 1000.0,
+                high_24h: 20500.0,
+                low_24h: 19500.0,
+                change_percent_24h: 0.5,
+            })
+        );
+    }
 }
@@ -0,0 +1,223 @@
+use crate::{
+    builder::SubscriptionControl,
+    exchange::{de_str, de_u64_epoch_ms_as_datetime_utc},
+    model::{subscription::Subscription, DataKind, OpenInterest},
+    ExchangeId, MarketEvent,
+};
+use barter_integration::{model::Exchange, Event};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, time::Duration};
+use tokio::sync::mpsc;
+use tracing::{debug, warn};
+
+/// Return the REST openInterest endpoint, containing the present open interest of a symbol, of
+/// the provided `Binance` [`ExchangeId`], or `None` if the [`ExchangeId`] is not supported.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#open-interest>
+pub fn open_interest_url(exchange: ExchangeId) -> Option<String> {
+    match exchange {
+        ExchangeId::BinanceFuturesUsd => {
+            Some(format!("{}/fapi/v1/openInterest", exchange.rest_base_url()))
+        }
+        _ => None,
+    }
+}
+
+/// Interval at which the `Binance` openInterest endpoint is polled for every subscribed symbol.
+pub const POLL_INTERVAL: Duration = Duration::from_secs(10);
+
+/// `Binance` openInterest response.
+///
+/// eg/ {"openInterest":"10659.509","symbol":"BTCUSDT","time":1589437530011}
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct BinanceOpenInterest {
+    pub symbol: String,
+    #[serde(rename = "openInterest", deserialize_with = "de_str")]
+    pub open_interest: f64,
+    #[serde(deserialize_with = "de_u64_epoch_ms_as_datetime_utc")]
+    pub time: DateTime<Utc>,
+}
+
+/// Fetch the [`BinanceOpenInterest`] of the provided symbol (eg/ "BTCUSDT") from the provided
+/// openInterest endpoint.
+pub async fn fetch_open_interest(
+    url: &str,
+    symbol: &str,
+) -> Result<BinanceOpenInterest, reqwest::Error> {
+    reqwest::get(format!("{url}?symbol={symbol}"))
+        .await?
+        .json()
+        .await
+}
+
+/// Map a polled [`BinanceOpenInterest`] into an [`OpenInterest`] [`MarketEvent`], skipping it if
+/// the open interest has not been updated since the `last` poll of the same symbol.
+pub fn open_interest_event(
+    exchange: ExchangeId,
+    subscription: &Subscription,
+    open_interest: BinanceOpenInterest,
+    last: &mut HashMap<String, DateTime<Utc>>,
+) -> Option<MarketEvent> {
+    if last.insert(open_interest.symbol, open_interest.time) == Some(open_interest.time) {
+        return None;
+    }
+
+    Some(MarketEvent {
+        exchange_time: open_interest.time,
+        received_time: Utc::now(),
+        received_instant: None,
+        raw: None,
+        exchange: Exchange::from(exchange),
+        instrument: subscription.instrument.clone(),
+        kind: DataKind::OpenInterest(OpenInterest {
+            quantity: open_interest.open_interest,
+            notional: None,
+        }),
+    })
+}
+
+/// Poll the `Binance` openInterest endpoint every [`POLL_INTERVAL`] for each of the provided
+/// [`Subscription`]s, distributing [`OpenInterest`] [`MarketEvent`]s downstream via the
+/// `exchange_tx` whenever a symbol's open interest is updated. Updates for [`Subscription`]s
+/// paused via the [`SubscriptionControl`] are not distributed.
+///
+/// Runs until the `exchange_tx` receiver is dropped.
+pub async fn poll_open_interest(
+    exchange: ExchangeId,
+    subscriptions: Vec<Subscription>,
+    exchange_tx: mpsc::UnboundedSender<Event<MarketEvent>>,
+    control: SubscriptionControl,
+) {
+    let url = match open_interest_url(exchange) {
+        Some(url) => url,
+        None => {
+            warn!(
+                %exchange,
+                action = "stop polling",
+                "openInterest polling is not supported"
+            );
+            return;
+        }
+    };
+
+    let mut last = HashMap::with_capacity(subscriptions.len());
+    let mut sequence = 0;
+    let mut interval = tokio::time::interval(POLL_INTERVAL);
+
+    loop {
+        interval.tick().await;
+
+        for subscription in &subscriptions {
+            // Determine Binance symbol using the Instrument (eg/ "BTCUSDT")
+            let symbol = format!(
+                "{}{}",
+                subscription.instrument.base, subscription.instrument.quote
+            )
+            .to_uppercase();
+
+            let open_interest = match fetch_open_interest(&url, &symbol).await {
+                Ok(open_interest) => open_interest,
+                Err(error) => {
+                    warn!(
+                        %exchange,
+                        %symbol,
+                        %error,
+                        action = "retry next interval",
+                        "failed to poll openInterest"
+                    );
+                    continue;
+                }
+            };
+
+            let payload =
+                match open_interest_event(exchange, subscription, open_interest, &mut last) {
+                    Some(payload) => payload,
+                    None => continue,
+                };
+
+            if control.is_paused(exchange, &payload) {
+                continue;
+            }
+            debug!(%exchange, ?payload, "polled OpenInterest update");
+            if exchange_tx.send(Event { sequence, payload }).is_err() {
+                return;
+            }
+            sequence += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{exchange::datetime_utc_from_epoch_duration, model::subscription::SubKind};
+    use barter_integration::model::InstrumentKind;
+
+    #[test]
+    fn test_deserialise_binance_open_interest() {
+        let input = r#"{"openInterest":"10659.509","symbol":"BTCUSDT","time":1589437530011}"#;
+
+        let actual = serde_json::from_str::<BinanceOpenInterest>(input).unwrap();
+        let expected = BinanceOpenInterest {
+            symbol: "BTCUSDT".to_owned(),
+            open_interest: 10659.509,
+            time: datetime_utc_from_epoch_duration(Duration::from_millis(1589437530011)),
+        };
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_open_interest_event() {
+        let subscription = Subscription::new(
+            ExchangeId::BinanceFuturesUsd,
+            ("btc", "usdt", InstrumentKind::FuturePerpetual),
+            SubKind::OpenInterest,
+        );
+        let open_interest = |quantity, time| BinanceOpenInterest {
+            symbol: "BTCUSDT".to_owned(),
+            open_interest: quantity,
+            time: datetime_utc_from_epoch_duration(Duration::from_millis(time)),
+        };
+
+        struct TestCase {
+            input: BinanceOpenInterest,
+            expected: Option<f64>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: first poll is distributed
+                input: open_interest(10.0, 1589437530011),
+                expected: Some(10.0),
+            },
+            TestCase {
+                // TC1: open interest not updated since last poll is not distributed
+                input: open_interest(10.0, 1589437530011),
+                expected: None,
+            },
+            TestCase {
+                // TC2: updated open interest is distributed
+                input: open_interest(12.5, 1589437540011),
+                expected: Some(12.5),
+            },
+        ];
+
+        let mut last = HashMap::new();
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = open_interest_event(
+                ExchangeId::BinanceFuturesUsd,
+                &subscription,
+                test.input,
+                &mut last,
+            )
+            .map(|event| match event.kind {
+                DataKind::OpenInterest(open_interest) => open_interest.quantity,
+                other => panic!("TC{index} failed with unexpected DataKind: {other:?}"),
+            });
+
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
@@ -0,0 +1,185 @@
+use super::{
+    futures::BinanceFuturesUsd,
+    futures_coin::BinanceFuturesCoin,
+    model::{BinanceStream, BinanceSubResponse},
+};
+use crate::{
+    model::subscription::{SubscriptionIds, SubscriptionMeta},
+    ExchangeId, ExchangeTransformer, MarketEvent, RateLimit, Subscriber, Subscription,
+};
+use barter_integration::{error::SocketError, protocol::websocket::WsMessage, Transformer};
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc;
+
+/// [`BinanceFuturesUsdSandbox`] [`Subscriber`] & [`ExchangeTransformer`] implementor for the
+/// collection of `Futures` data from the Binance USD-M futures testnet.
+///
+/// Channels & messages are identical to [`BinanceFuturesUsd`], so subscriptions are actioned &
+/// messages are transformed by an inner [`BinanceFuturesUsd`]. Enabled via
+/// [`StreamBuilder::sandbox`](crate::builder::StreamBuilder::sandbox).
+///
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#general-info>
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct BinanceFuturesUsdSandbox {
+    pub futures: BinanceFuturesUsd,
+}
+
+impl Subscriber for BinanceFuturesUsdSandbox {
+    type SubResponse = BinanceSubResponse;
+
+    fn base_url() -> &'static str {
+        ExchangeId::BinanceFuturesUsd
+            .sandbox_ws_base_url()
+            .expect("BinanceFuturesUsd provides a sandbox WebSocket API")
+    }
+
+    fn ws_rate_limit() -> Option<RateLimit> {
+        BinanceFuturesUsd::ws_rate_limit()
+    }
+
+    fn url(subscriptions: &[Subscription]) -> Result<String, SocketError> {
+        Ok(sandbox_url(
+            &BinanceFuturesUsd::url(subscriptions)?,
+            BinanceFuturesUsd::base_url(),
+            Self::base_url(),
+        ))
+    }
+
+    fn build_subscription_meta(
+        subscriptions: &[Subscription],
+    ) -> Result<SubscriptionMeta, SocketError> {
+        BinanceFuturesUsd::build_subscription_meta(subscriptions)
+    }
+}
+
+impl ExchangeTransformer for BinanceFuturesUsdSandbox {
+    const EXCHANGE: ExchangeId = ExchangeId::BinanceFuturesUsd;
+
+    fn new(ws_sink_tx: mpsc::UnboundedSender<WsMessage>, ids: SubscriptionIds) -> Self {
+        Self {
+            futures: BinanceFuturesUsd::new(ws_sink_tx, ids),
+        }
+    }
+}
+
+impl Transformer<MarketEvent> for BinanceFuturesUsdSandbox {
+    type Input = BinanceStream;
+    type OutputIter = Vec<Result<MarketEvent, SocketError>>;
+
+    fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+        self.futures.transform(input)
+    }
+}
+
+/// [`BinanceFuturesCoinSandbox`] [`Subscriber`] & [`ExchangeTransformer`] implementor for the
+/// collection of coin margined `Futures` data from the Binance COIN-M futures testnet.
+///
+/// Channels & messages are identical to [`BinanceFuturesCoin`], so subscriptions are actioned &
+/// messages are transformed by an inner [`BinanceFuturesCoin`]. Enabled via
+/// [`StreamBuilder::sandbox`](crate::builder::StreamBuilder::sandbox).
+///
+/// See docs: <https://binance-docs.github.io/apidocs/delivery/en/#general-info>
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct BinanceFuturesCoinSandbox {
+    pub futures: BinanceFuturesCoin,
+}
+
+impl Subscriber for BinanceFuturesCoinSandbox {
+    type SubResponse = BinanceSubResponse;
+
+    fn base_url() -> &'static str {
+        ExchangeId::BinanceFuturesCoin
+            .sandbox_ws_base_url()
+            .expect("BinanceFuturesCoin provides a sandbox WebSocket API")
+    }
+
+    fn ws_rate_limit() -> Option<RateLimit> {
+        BinanceFuturesCoin::ws_rate_limit()
+    }
+
+    fn url(subscriptions: &[Subscription]) -> Result<String, SocketError> {
+        Ok(sandbox_url(
+            &BinanceFuturesCoin::url(subscriptions)?,
+            BinanceFuturesCoin::base_url(),
+            Self::base_url(),
+        ))
+    }
+
+    fn build_subscription_meta(
+        subscriptions: &[Subscription],
+    ) -> Result<SubscriptionMeta, SocketError> {
+        BinanceFuturesCoin::build_subscription_meta(subscriptions)
+    }
+}
+
+impl ExchangeTransformer for BinanceFuturesCoinSandbox {
+    const EXCHANGE: ExchangeId = ExchangeId::BinanceFuturesCoin;
+
+    fn new(ws_sink_tx: mpsc::UnboundedSender<WsMessage>, ids: SubscriptionIds) -> Self {
+        Self {
+            futures: BinanceFuturesCoin::new(ws_sink_tx, ids),
+        }
+    }
+}
+
+impl Transformer<MarketEvent> for BinanceFuturesCoinSandbox {
+    type Input = BinanceStream;
+    type OutputIter = Vec<Result<MarketEvent, SocketError>>;
+
+    fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+        self.futures.transform(input)
+    }
+}
+
+/// Replace the production base URL of the provided combined stream url with the sandbox base URL,
+/// since `Binance` subscriptions are actioned via the connection url.
+///
+/// eg/ "wss://fstream.binance.com/stream?streams=btcusdt@aggTrade"
+///  => "wss://fstream.binancefuture.com/stream?streams=btcusdt@aggTrade"
+fn sandbox_url(url: &str, base_url: &str, sandbox_base_url: &str) -> String {
+    url.replacen(base_url, sandbox_base_url, 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::subscription::SubKind;
+    use barter_integration::model::InstrumentKind;
+
+    #[test]
+    fn test_binance_futures_sandbox_url() {
+        struct TestCase {
+            actual: Result<String, SocketError>,
+            expected: &'static str,
+        }
+
+        let subscription = |exchange| {
+            Subscription::new(
+                exchange,
+                ("btc", "usd", InstrumentKind::FuturePerpetual),
+                SubKind::Trade,
+            )
+        };
+
+        let cases = vec![
+            TestCase {
+                // TC0: BinanceFuturesUsd testnet combined stream url
+                actual: BinanceFuturesUsdSandbox::url(&[subscription(
+                    ExchangeId::BinanceFuturesUsd,
+                )]),
+                expected: "wss://fstream.binancefuture.com/stream?streams=btcusd@aggTrade",
+            },
+            TestCase {
+                // TC1: BinanceFuturesCoin testnet combined stream url
+                actual: BinanceFuturesCoinSandbox::url(&[subscription(
+                    ExchangeId::BinanceFuturesCoin,
+                )]),
+                expected: "wss://dstream.binancefuture.com/stream?streams=btcusd_perp@aggTrade",
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            assert_eq!(test.actual.unwrap(), test.expected, "TC{} failed", index);
+        }
+    }
+}
@@ -0,0 +1,727 @@
+use super::{futures::BinanceFuturesUsd, user_data::HEADER_API_KEY};
+use crate::{
+    distribute_responses_to_the_exchange,
+    model::{subscription::SubKind, DataKind, Level, OrderBookL2Delta, PublicTrade},
+    receipt::StampedStream,
+    ExchangeId, ExchangeTransformer, MarketEvent, Subscription, SubscriptionIds,
+};
+use barter_integration::{
+    error::SocketError,
+    model::{Exchange, Instrument, Side, SubscriptionId},
+    protocol::{
+        websocket::{connect, WebSocketParser, WsError, WsMessage, WsStream},
+        StreamParser,
+    },
+    Transformer, Validator,
+};
+use chrono::{DateTime, TimeZone, Utc};
+use futures::StreamExt;
+use serde::{
+    de::{DeserializeOwned, Error, Visitor},
+    Deserialize, Deserializer,
+};
+use std::{collections::HashMap, fmt::Formatter};
+use tokio::sync::mpsc;
+use tokio_tungstenite::tungstenite::{client::IntoClientRequest, http::HeaderValue};
+use tracing::warn;
+
+/// Convenient type alias for a [`StampedStream`] decoding `Binance` SBE frames via the
+/// [`SbeParser`]. Every yielded [`MarketEvent`] is stamped with the monotonic instant its frame
+/// was read.
+pub type BinanceSbeStream = StampedStream<SbeParser, WsStream, BinanceSbe>;
+
+/// [`StreamParser`] that deserialises binary WebSocket frames directly from their bytes (eg/ SBE
+/// encoded messages) rather than as JSON. Every other frame is handled by the
+/// [`WebSocketParser`].
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct SbeParser;
+
+impl StreamParser for SbeParser {
+    type Message = WsMessage;
+    type Error = WsError;
+
+    fn parse<Output>(
+        input: Result<Self::Message, Self::Error>,
+    ) -> Option<Result<Output, SocketError>>
+    where
+        Output: DeserializeOwned,
+    {
+        match input {
+            Ok(WsMessage::Binary(payload)) => Some(
+                Output::deserialize(
+                    serde::de::value::BytesDeserializer::<serde_json::Error>::new(&payload),
+                )
+                .map_err(|error| {
+                    warn!(
+                        ?error,
+                        ?payload,
+                        action = "returning Some(Err(err))",
+                        "failed to decode binary WebSocket Message into domain specific Message"
+                    );
+                    SocketError::Serde {
+                        error,
+                        payload: format!("{payload:?}"),
+                    }
+                }),
+            ),
+            input => WebSocketParser::parse(input),
+        }
+    }
+}
+
+/// [`BinanceSbe`] [`ExchangeTransformer`] implementor for the opt-in collection of `Binance` Spot
+/// trades & depth updates via the Simple Binary Encoding (SBE) market data stream, which avoids
+/// the JSON parsing overhead of high volume symbols. Connecting requires an API key.
+///
+/// Supported [`SubKind`]s are [`SubKind::Trade`] & [`SubKind::OrderBookL2Delta`].
+///
+/// Note that [`SubKind::OrderBookL2Delta`] diffs are emitted as received, without a snapshot or
+/// any update id continuity validation. Each [`OrderBookL2Delta`] carries the `first_update_id`
+/// & `last_update_id` it spans, so consumers maintaining a local book must seed it from a REST
+/// depth snapshot & validate continuity themselves (eg/ via the
+/// [`BinanceDepthManager`](super::depth::BinanceDepthManager)).
+///
+/// See docs: <https://developers.binance.com/docs/binance-spot-api-docs/sbe-market-data-streams>
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct BinanceSbe {
+    pub ids: SubscriptionIds,
+}
+
+impl ExchangeTransformer for BinanceSbe {
+    const EXCHANGE: ExchangeId = ExchangeId::Binance;
+    fn new(_: mpsc::UnboundedSender<WsMessage>, ids: SubscriptionIds) -> Self {
+        Self { ids }
+    }
+}
+
+impl Transformer<MarketEvent> for BinanceSbe {
+    type Input = BinanceSbeMessage;
+    type OutputIter = Vec<Result<MarketEvent, SocketError>>;
+
+    fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+        match self.ids.find_instrument(&input.subscription_id()) {
+            Ok(instrument) => input.into_market_events(instrument),
+            Err(error) => vec![Err(error)],
+        }
+    }
+}
+
+impl BinanceSbe {
+    /// [`BinanceSbe`] market data stream base url.
+    pub const BASE_URL: &'static str = "wss://stream-sbe.binance.com:9443/stream";
+
+    /// [`BinanceSbe`] trade channel name.
+    pub const CHANNEL_TRADES: &'static str = "@trade";
+
+    /// [`BinanceSbe`] diff depth channel name.
+    pub const CHANNEL_DEPTH: &'static str = "@depth";
+
+    /// SBE `templateId` of a `TradesStreamEvent`.
+    pub const TEMPLATE_TRADES: u16 = 10000;
+
+    /// SBE `templateId` of a `DepthDiffStreamEvent`.
+    pub const TEMPLATE_DEPTH_DIFF: u16 = 10003;
+
+    /// Determine the [`BinanceSbe`] channel metadata associated with an input Barter
+    /// [`Subscription`]. This includes the `String` channel identifier, and a `String` market
+    /// identifier.
+    ///
+    /// Example Ok return: Ok("@trade", "btcusdt")
+    pub fn build_channel_meta(sub: &Subscription) -> Result<(&'static str, String), SocketError> {
+        // Validate provided Subscription InstrumentKind is supported by Binance
+        let sub = sub.validate()?;
+
+        // Determine the BinanceSbe channel
+        let channel = match &sub.kind {
+            SubKind::Trade => Self::CHANNEL_TRADES,
+            SubKind::OrderBookL2Delta => Self::CHANNEL_DEPTH,
+            other => {
+                return Err(SocketError::Unsupported {
+                    entity: "binance_sbe",
+                    item: other.to_string(),
+                })
+            }
+        };
+
+        // Determine Binance market using the Instrument
+        let market = format!("{}{}", sub.instrument.base, sub.instrument.quote);
+
+        Ok((channel, market))
+    }
+
+    /// Build the [`BinanceSbe`] combined stream url & [`SubscriptionIds`] of the provided
+    /// [`Subscription`]s. Streams are actioned via the url, so no subscription messages are sent.
+    ///
+    /// eg/ "wss://stream-sbe.binance.com:9443/stream?streams=btcusdt@trade/btcusdt@depth"
+    pub fn build_subscription_meta(
+        subscriptions: &[Subscription],
+    ) -> Result<(String, SubscriptionIds), SocketError> {
+        let mut ids = SubscriptionIds(HashMap::with_capacity(subscriptions.len()));
+        let mut stream_names = Vec::with_capacity(subscriptions.len());
+
+        for subscription in subscriptions {
+            let (channel, market) = Self::build_channel_meta(subscription)?;
+
+            let stream_name = format!("{market}{channel}");
+            if !stream_names.contains(&stream_name) {
+                stream_names.push(stream_name);
+            }
+
+            // Use "channel|market" as the SubscriptionId key in the SubscriptionIds
+            // '--> Uppercase market to match incoming exchange event
+            // eg/ SubscriptionId("@trade|BTCUSDT")
+            ids.insert(
+                BinanceFuturesUsd::subscription_id(channel, &market.to_uppercase()),
+                subscription.clone(),
+            );
+        }
+
+        if let Some(max_streams) = ExchangeId::Binance.max_streams_per_connection() {
+            if stream_names.len() > max_streams {
+                return Err(SocketError::Subscribe(format!(
+                    "{} StreamNames exceeds the binance_sbe combined stream limit of {}",
+                    stream_names.len(),
+                    max_streams
+                )));
+            }
+        }
+
+        let url = format!("{}?streams={}", Self::BASE_URL, stream_names.join("/"));
+
+        Ok((url, ids))
+    }
+
+    /// Connect to the [`BinanceSbe`] market data stream, authenticating with the provided API key,
+    /// and initialise a [`BinanceSbeStream`] for the provided [`Subscription`]s.
+    pub async fn init(
+        api_key: &str,
+        subscriptions: &[Subscription],
+    ) -> Result<BinanceSbeStream, SocketError> {
+        let (url, ids) = Self::build_subscription_meta(subscriptions)?;
+
+        // SBE market data streams require the API key header
+        let mut request = url.into_client_request().map_err(SocketError::WebSocket)?;
+        request.headers_mut().insert(
+            HEADER_API_KEY,
+            HeaderValue::from_str(api_key).map_err(|error| {
+                SocketError::Subscribe(format!("invalid binance_sbe API key header: {error}"))
+            })?,
+        );
+
+        // Split WebSocket into WsStream & WsSink components
+        let (ws_sink, ws_stream) = connect(request).await?.split();
+
+        // Task to distribute ExchangeTransformer outgoing messages to the exchange
+        let (ws_sink_tx, ws_sink_rx) = mpsc::unbounded_channel();
+        tokio::spawn(distribute_responses_to_the_exchange(
+            ExchangeId::Binance,
+            ws_sink,
+            ws_sink_rx,
+        ));
+
+        Ok(BinanceSbeStream::new(
+            ws_stream,
+            <BinanceSbe as ExchangeTransformer>::new(ws_sink_tx, ids),
+        ))
+    }
+}
+
+/// `Binance` SBE market data message, decoded from a binary frame beginning with the SBE message
+/// header (blockLength, templateId, schemaId & version as little endian u16s).
+#[derive(Clone, PartialEq, Debug)]
+pub enum BinanceSbeMessage {
+    Trades(BinanceSbeTrades),
+    DepthDiff(BinanceSbeDepthDiff),
+}
+
+/// `Binance` SBE `TradesStreamEvent` containing every trade of a single matching event.
+#[derive(Clone, PartialEq, Debug)]
+pub struct BinanceSbeTrades {
+    pub symbol: String,
+    pub event_time: DateTime<Utc>,
+    pub trades: Vec<BinanceSbeTrade>,
+}
+
+/// `Binance` SBE trade contained in a [`BinanceSbeTrades`] event.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct BinanceSbeTrade {
+    pub id: i64,
+    pub price: f64,
+    pub quantity: f64,
+    pub side: Side,
+}
+
+/// `Binance` SBE `DepthDiffStreamEvent` containing the changed bid & ask levels, where a zero
+/// quantity removes the level.
+#[derive(Clone, PartialEq, Debug)]
+pub struct BinanceSbeDepthDiff {
+    pub symbol: String,
+    pub event_time: DateTime<Utc>,
+    pub first_update_id: i64,
+    pub last_update_id: i64,
+    pub bids: Vec<Level>,
+    pub asks: Vec<Level>,
+}
+
+impl BinanceSbeMessage {
+    /// Determine the [`SubscriptionId`] of this [`BinanceSbeMessage`] (eg/ "@trade|BTCUSDT").
+    pub fn subscription_id(&self) -> SubscriptionId {
+        match self {
+            BinanceSbeMessage::Trades(trades) => {
+                BinanceFuturesUsd::subscription_id(BinanceSbe::CHANNEL_TRADES, &trades.symbol)
+            }
+            BinanceSbeMessage::DepthDiff(depth) => {
+                BinanceFuturesUsd::subscription_id(BinanceSbe::CHANNEL_DEPTH, &depth.symbol)
+            }
+        }
+    }
+
+    /// Convert this [`BinanceSbeMessage`] into the normalised [`MarketEvent`]s of the provided
+    /// [`Instrument`]. A [`BinanceSbeTrades`] event yields a [`PublicTrade`] per trade.
+    pub fn into_market_events(
+        self,
+        instrument: Instrument,
+    ) -> Vec<Result<MarketEvent, SocketError>> {
+        let event = |exchange_time, kind| {
+            Ok(MarketEvent {
+                exchange_time,
+                received_time: Utc::now(),
+                received_instant: None,
+                raw: None,
+                exchange: Exchange::from(ExchangeId::Binance),
+                instrument: instrument.clone(),
+                kind,
+            })
+        };
+
+        match self {
+            BinanceSbeMessage::Trades(trades) => trades
+                .trades
+                .into_iter()
+                .map(|trade| {
+                    event(
+                        trades.event_time,
+                        DataKind::Trade(PublicTrade {
+                            id: trade.id.to_string(),
+                            price: trade.price,
+                            quantity: trade.quantity,
+                            side: trade.side,
+                        }),
+                    )
+                })
+                .collect(),
+            BinanceSbeMessage::DepthDiff(depth) => vec![event(
+                depth.event_time,
+                DataKind::OrderBookL2Delta(OrderBookL2Delta {
+                    last_update_time: depth.event_time,
+                    first_update_id: Some(depth.first_update_id as u64),
+                    last_update_id: depth.last_update_id as u64,
+                    bids: depth.bids,
+                    asks: depth.asks,
+                }),
+            )],
+        }
+    }
+
+    /// Decode a [`BinanceSbeMessage`] from the provided SBE encoded bytes.
+    pub fn decode(bytes: &[u8]) -> Result<Self, String> {
+        let mut reader = SbeReader { bytes, offset: 0 };
+
+        // Message header
+        let block_length = reader.u16()? as usize;
+        let template_id = reader.u16()?;
+        let _schema_id = reader.u16()?;
+        let _version = reader.u16()?;
+
+        match template_id {
+            BinanceSbe::TEMPLATE_TRADES => {
+                let mut block = reader.block(block_length)?;
+                let event_time = block.timestamp_us()?;
+                let _transact_time = block.i64()?;
+                let price_exponent = block.i8()?;
+                let qty_exponent = block.i8()?;
+
+                let (trade_length, num_trades) = (reader.u16()? as usize, reader.u32()? as usize);
+                let mut trades = Vec::with_capacity(num_trades);
+                for _ in 0..num_trades {
+                    let mut trade = reader.block(trade_length)?;
+                    trades.push(BinanceSbeTrade {
+                        id: trade.i64()?,
+                        price: trade.decimal(price_exponent)?,
+                        quantity: trade.decimal(qty_exponent)?,
+                        // Buyer is maker => taker sold
+                        side: match trade.u8()? {
+                            0 => Side::Buy,
+                            _ => Side::Sell,
+                        },
+                    });
+                }
+
+                Ok(BinanceSbeMessage::Trades(BinanceSbeTrades {
+                    symbol: reader.var_string8()?,
+                    event_time,
+                    trades,
+                }))
+            }
+            BinanceSbe::TEMPLATE_DEPTH_DIFF => {
+                let mut block = reader.block(block_length)?;
+                let event_time = block.timestamp_us()?;
+                let first_update_id = block.i64()?;
+                let last_update_id = block.i64()?;
+                let price_exponent = block.i8()?;
+                let qty_exponent = block.i8()?;
+
+                let bids = reader.levels(price_exponent, qty_exponent)?;
+                let asks = reader.levels(price_exponent, qty_exponent)?;
+
+                Ok(BinanceSbeMessage::DepthDiff(BinanceSbeDepthDiff {
+                    symbol: reader.var_string8()?,
+                    event_time,
+                    first_update_id,
+                    last_update_id,
+                    bids,
+                    asks,
+                }))
+            }
+            other => Err(format!("unsupported binance_sbe templateId: {other}")),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for BinanceSbeMessage {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct SbeVisitor;
+
+        impl<'de> Visitor<'de> for SbeVisitor {
+            type Value = BinanceSbeMessage;
+
+            fn expecting(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("SBE encoded BinanceSbeMessage bytes")
+            }
+
+            fn visit_bytes<E>(self, bytes: &[u8]) -> Result<Self::Value, E>
+            where
+                E: Error,
+            {
+                BinanceSbeMessage::decode(bytes).map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_bytes(SbeVisitor)
+    }
+}
+
+/// Little endian cursor over SBE encoded bytes.
+#[derive(Debug)]
+struct SbeReader<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> SbeReader<'a> {
+    /// Take the next `length` bytes.
+    fn take(&mut self, length: usize) -> Result<&'a [u8], String> {
+        let bytes = self
+            .bytes
+            .get(self.offset..self.offset + length)
+            .ok_or_else(|| format!("binance_sbe message truncated at byte {}", self.offset))?;
+        self.offset += length;
+        Ok(bytes)
+    }
+
+    /// Take the next block of `length` bytes as a new [`SbeReader`], skipping any trailing fields
+    /// added by a newer schema version.
+    fn block(&mut self, length: usize) -> Result<SbeReader<'a>, String> {
+        self.take(length)
+            .map(|bytes| SbeReader { bytes, offset: 0 })
+    }
+
+    fn u8(&mut self) -> Result<u8, String> {
+        self.take(1).map(|bytes| bytes[0])
+    }
+
+    fn i8(&mut self) -> Result<i8, String> {
+        self.u8().map(|byte| byte as i8)
+    }
+
+    fn u16(&mut self) -> Result<u16, String> {
+        self.take(2)
+            .map(|bytes| u16::from_le_bytes([bytes[0], bytes[1]]))
+    }
+
+    fn u32(&mut self) -> Result<u32, String> {
+        self.take(4)
+            .map(|bytes| u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    fn i64(&mut self) -> Result<i64, String> {
+        let mut buffer = [0u8; 8];
+        buffer.copy_from_slice(self.take(8)?);
+        Ok(i64::from_le_bytes(buffer))
+    }
+
+    /// Decode a microsecond epoch timestamp.
+    fn timestamp_us(&mut self) -> Result<DateTime<Utc>, String> {
+        let micros = self.i64()?;
+        Utc.timestamp_opt(
+            micros.div_euclid(1_000_000),
+            micros.rem_euclid(1_000_000) as u32 * 1_000,
+        )
+        .single()
+        .ok_or_else(|| format!("invalid binance_sbe timestamp: {micros}"))
+    }
+
+    /// Decode an i64 mantissa scaled by the provided exponent.
+    fn decimal(&mut self, exponent: i8) -> Result<f64, String> {
+        self.i64().map(|mantissa| match exponent {
+            exponent if exponent < 0 => mantissa as f64 / 10f64.powi(-exponent as i32),
+            exponent => mantissa as f64 * 10f64.powi(exponent as i32),
+        })
+    }
+
+    /// Decode a group of price & quantity [`Level`]s with a u16 blockLength & numInGroup header.
+    fn levels(&mut self, price_exponent: i8, qty_exponent: i8) -> Result<Vec<Level>, String> {
+        let (level_length, num_levels) = (self.u16()? as usize, self.u16()? as usize);
+
+        (0..num_levels)
+            .map(|_| {
+                let mut level = self.block(level_length)?;
+                Ok(Level {
+                    price: level.decimal(price_exponent)?,
+                    quantity: level.decimal(qty_exponent)?,
+                })
+            })
+            .collect()
+    }
+
+    /// Decode a `varString8`, ie/ a u8 length prefixed UTF-8 string.
+    fn var_string8(&mut self) -> Result<String, String> {
+        let length = self.u8()? as usize;
+        String::from_utf8(self.take(length)?.to_vec()).map_err(|error| error.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::model::InstrumentKind;
+
+    fn header(block_length: u16, template_id: u16) -> Vec<u8> {
+        [block_length, template_id, 1, 0]
+            .into_iter()
+            .flat_map(u16::to_le_bytes)
+            .collect()
+    }
+
+    fn symbol(symbol: &str) -> Vec<u8> {
+        let mut bytes = vec![symbol.len() as u8];
+        bytes.extend(symbol.as_bytes());
+        bytes
+    }
+
+    fn trades_bytes() -> Vec<u8> {
+        let mut bytes = header(18, BinanceSbe::TEMPLATE_TRADES);
+        bytes.extend(1_700_000_000_000_000i64.to_le_bytes());
+        bytes.extend(1_700_000_000_000_000i64.to_le_bytes());
+        bytes.extend([-2i8 as u8, -3i8 as u8]);
+
+        // Trades group w/ 25 byte blocks
+        bytes.extend(25u16.to_le_bytes());
+        bytes.extend(2u32.to_le_bytes());
+        for (id, price, qty, is_buyer_maker) in
+            [(1i64, 3500050i64, 1500i64, 0u8), (2, 3500000, 250, 1)]
+        {
+            bytes.extend(id.to_le_bytes());
+            bytes.extend(price.to_le_bytes());
+            bytes.extend(qty.to_le_bytes());
+            bytes.push(is_buyer_maker);
+        }
+
+        bytes.extend(symbol("BTCUSDT"));
+        bytes
+    }
+
+    fn depth_bytes() -> Vec<u8> {
+        // Root block padded w/ an unknown trailing field
+        let mut bytes = header(30, BinanceSbe::TEMPLATE_DEPTH_DIFF);
+        bytes.extend(1_700_000_000_000_000i64.to_le_bytes());
+        bytes.extend(100i64.to_le_bytes());
+        bytes.extend(105i64.to_le_bytes());
+        bytes.extend([-1i8 as u8, 0]);
+        bytes.extend([0xFF; 4]);
+
+        // Bids group w/ 1 level
+        bytes.extend(16u16.to_le_bytes());
+        bytes.extend(1u16.to_le_bytes());
+        bytes.extend(350005i64.to_le_bytes());
+        bytes.extend(2i64.to_le_bytes());
+
+        // Asks group w/ 0 levels
+        bytes.extend(16u16.to_le_bytes());
+        bytes.extend(0u16.to_le_bytes());
+
+        bytes.extend(symbol("ETHUSDT"));
+        bytes
+    }
+
+    #[test]
+    fn test_decode_binance_sbe_message() {
+        let event_time = Utc.timestamp_opt(1_700_000_000, 0).unwrap();
+
+        struct TestCase {
+            input: Vec<u8>,
+            expected: Result<BinanceSbeMessage, SocketError>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: TradesStreamEvent w/ buyer & seller taker trades
+                input: trades_bytes(),
+                expected: Ok(BinanceSbeMessage::Trades(BinanceSbeTrades {
+                    symbol: "BTCUSDT".to_owned(),
+                    event_time,
+                    trades: vec![
+                        BinanceSbeTrade {
+                            id: 1,
+                            price: 35000.5,
+                            quantity: 1.5,
+                            side: Side::Buy,
+                        },
+                        BinanceSbeTrade {
+                            id: 2,
+                            price: 35000.0,
+                            quantity: 0.25,
+                            side: Side::Sell,
+                        },
+                    ],
+                })),
+            },
+            TestCase {
+                // TC1: DepthDiffStreamEvent w/ unknown root block field skipped
+                input: depth_bytes(),
+                expected: Ok(BinanceSbeMessage::DepthDiff(BinanceSbeDepthDiff {
+                    symbol: "ETHUSDT".to_owned(),
+                    event_time,
+                    first_update_id: 100,
+                    last_update_id: 105,
+                    bids: vec![Level {
+                        price: 35000.5,
+                        quantity: 2.0,
+                    }],
+                    asks: vec![],
+                })),
+            },
+            TestCase {
+                // TC2: unsupported templateId
+                input: header(0, 10001),
+                expected: Err(SocketError::Serde {
+                    error: serde_json::Error::custom(""),
+                    payload: "".to_owned(),
+                }),
+            },
+            TestCase {
+                // TC3: truncated TradesStreamEvent
+                input: trades_bytes()[..30].to_vec(),
+                expected: Err(SocketError::Serde {
+                    error: serde_json::Error::custom(""),
+                    payload: "".to_owned(),
+                }),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual =
+                SbeParser::parse::<BinanceSbeMessage>(Ok(WsMessage::Binary(test.input))).unwrap();
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => {
+                    assert_eq!(actual, expected, "TC{} failed", index)
+                }
+                (Err(_), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_binance_sbe_transform() {
+        let btc = Subscription::new(
+            ExchangeId::Binance,
+            ("btc", "usdt", InstrumentKind::Spot),
+            SubKind::Trade,
+        );
+        let eth = Subscription::new(
+            ExchangeId::Binance,
+            ("eth", "usdt", InstrumentKind::Spot),
+            SubKind::OrderBookL2Delta,
+        );
+
+        let (url, ids) = BinanceSbe::build_subscription_meta(&[btc.clone(), eth.clone()]).unwrap();
+        assert_eq!(
+            url,
+            "wss://stream-sbe.binance.com:9443/stream?streams=btcusdt@trade/ethusdt@depth"
+        );
+
+        let mut transformer = BinanceSbe { ids };
+
+        let trades = transformer
+            .transform(BinanceSbeMessage::decode(&trades_bytes()).unwrap())
+            .into_iter()
+            .map(|event| {
+                let event = event.unwrap();
+                assert_eq!(event.instrument, btc.instrument);
+                event.kind
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            trades,
+            vec![
+                DataKind::Trade(PublicTrade {
+                    id: "1".to_owned(),
+                    price: 35000.5,
+                    quantity: 1.5,
+                    side: Side::Buy,
+                }),
+                DataKind::Trade(PublicTrade {
+                    id: "2".to_owned(),
+                    price: 35000.0,
+                    quantity: 0.25,
+                    side: Side::Sell,
+                }),
+            ]
+        );
+
+        let depth = transformer.transform(BinanceSbeMessage::decode(&depth_bytes()).unwrap());
+        assert_eq!(depth.len(), 1);
+        let depth = depth.into_iter().next().unwrap().unwrap();
+        assert_eq!(depth.instrument, eth.instrument);
+        assert_eq!(
+            depth.kind,
+            DataKind::OrderBookL2Delta(OrderBookL2Delta {
+                last_update_time: Utc.timestamp_opt(1_700_000_000, 0).unwrap(),
+                first_update_id: Some(100),
+                last_update_id: 105,
+                bids: vec![Level {
+                    price: 35000.5,
+                    quantity: 2.0,
+                }],
+                asks: vec![],
+            })
+        );
+
+        // Unsupported SubKind
+        assert!(BinanceSbe::build_subscription_meta(&[Subscription::new(
+            ExchangeId::Binance,
+            ("btc", "usdt", InstrumentKind::Spot),
+            SubKind::Ticker,
+        )])
+        .is_err());
+    }
+}
@@ -0,0 +1,306 @@
+use crate::{
+    builder::SubscriptionControl,
+    model::{subscription::Subscription, DataKind, InstrumentStatus},
+    ExchangeId, MarketEvent,
+};
+use barter_integration::{
+    model::{Exchange, Instrument, InstrumentKind},
+    Event,
+};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, time::Duration};
+use tokio::sync::mpsc;
+use tracing::{debug, warn};
+
+/// Return the REST exchangeInfo endpoint, containing the trading status of every symbol, of the
+/// provided `Binance` futures [`ExchangeId`], or `None` if the [`ExchangeId`] is not supported.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#exchange-information>
+/// See docs: <https://binance-docs.github.io/apidocs/delivery/en/#exchange-information>
+pub fn exchange_info_url(exchange: ExchangeId) -> Option<String> {
+    let path = match exchange {
+        ExchangeId::BinanceFuturesUsd => "/fapi/v1/exchangeInfo",
+        ExchangeId::BinanceFuturesCoin => "/dapi/v1/exchangeInfo",
+        _ => return None,
+    };
+
+    Some(format!("{}{}", exchange.rest_base_url(), path))
+}
+
+/// Interval at which the `Binance` exchangeInfo endpoint is polled for symbol status changes.
+pub const POLL_INTERVAL: Duration = Duration::from_secs(60);
+
+/// `Binance` exchangeInfo response. Only the symbol statuses are deserialised.
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct BinanceExchangeInfo {
+    pub symbols: Vec<BinanceSymbolStatus>,
+}
+
+/// `Binance` symbol trading status contained in a [`BinanceExchangeInfo`] response.
+///
+/// eg/ {"symbol":"BTCUSDT","contractType":"PERPETUAL","status":"TRADING","baseAsset":"BTC", ...}
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct BinanceSymbolStatus {
+    pub symbol: String,
+    pub status: String,
+    #[serde(default, rename = "contractType")]
+    pub contract_type: String,
+    #[serde(default, rename = "baseAsset")]
+    pub base_asset: String,
+    #[serde(default, rename = "quoteAsset")]
+    pub quote_asset: String,
+}
+
+impl From<&BinanceSymbolStatus> for InstrumentStatus {
+    fn from(symbol: &BinanceSymbolStatus) -> Self {
+        match symbol.status.as_str() {
+            "TRADING" => InstrumentStatus::Trading,
+            "DELIVERED" | "CLOSE" => InstrumentStatus::Delisted,
+            // eg/ "HALT", "BREAK", "PENDING_TRADING", "PRE_DELIVERING", "SETTLING", etc.
+            _ => InstrumentStatus::Halted,
+        }
+    }
+}
+
+/// Discover every perpetual [`Instrument`] currently trading in the [`BinanceExchangeInfo`].
+pub fn perpetual_instruments(info: &BinanceExchangeInfo) -> Vec<Instrument> {
+    info.symbols
+        .iter()
+        .filter(|symbol| symbol.status == "TRADING" && symbol.contract_type == "PERPETUAL")
+        .map(|symbol| {
+            Instrument::from((
+                symbol.base_asset.to_lowercase(),
+                symbol.quote_asset.to_lowercase(),
+                InstrumentKind::FuturePerpetual,
+            ))
+        })
+        .collect()
+}
+
+/// Fetch the [`BinanceExchangeInfo`] from the provided exchangeInfo endpoint.
+pub async fn fetch_exchange_info(url: &str) -> Result<BinanceExchangeInfo, reqwest::Error> {
+    reqwest::get(url).await?.json().await
+}
+
+/// Map the [`BinanceSymbolStatus`]es of the subscribed symbols into [`MarketEvent`]s, skipping
+/// any symbol whose [`InstrumentStatus`] has not changed since the `last` poll.
+pub fn status_events(
+    exchange: ExchangeId,
+    subscriptions: &[Subscription],
+    info: &BinanceExchangeInfo,
+    last: &mut HashMap<String, InstrumentStatus>,
+) -> Vec<MarketEvent> {
+    subscriptions
+        .iter()
+        .filter_map(|subscription| {
+            // Determine Binance symbol using the Instrument (eg/ "BTCUSDT")
+            let symbol = format!(
+                "{}{}",
+                subscription.instrument.base, subscription.instrument.quote
+            )
+            .to_uppercase();
+
+            let status = info
+                .symbols
+                .iter()
+                .find(|status| status.symbol == symbol)
+                .map(InstrumentStatus::from)
+                // Symbols absent from the exchangeInfo have been removed from the exchange
+                .unwrap_or(InstrumentStatus::Delisted);
+
+            if last.insert(symbol, status) == Some(status) {
+                return None;
+            }
+
+            let now = Utc::now();
+            Some(MarketEvent {
+                exchange_time: now,
+                received_time: now,
+                received_instant: None,
+                raw: None,
+                exchange: Exchange::from(exchange),
+                instrument: subscription.instrument.clone(),
+                kind: DataKind::InstrumentStatus(status),
+            })
+        })
+        .collect()
+}
+
+/// Poll the `Binance` exchangeInfo endpoint every [`POLL_INTERVAL`], distributing
+/// [`InstrumentStatus`] [`MarketEvent`]s for the provided [`Subscription`]s downstream via the
+/// `exchange_tx` whenever a symbol status changes. The first poll distributes every status.
+/// Changes for [`Subscription`]s paused via the [`SubscriptionControl`] are not distributed.
+///
+/// Runs until the `exchange_tx` receiver is dropped.
+pub async fn poll_instrument_status(
+    exchange: ExchangeId,
+    subscriptions: Vec<Subscription>,
+    exchange_tx: mpsc::UnboundedSender<Event<MarketEvent>>,
+    control: SubscriptionControl,
+) {
+    let url = match exchange_info_url(exchange) {
+        Some(url) => url,
+        None => {
+            warn!(
+                %exchange,
+                action = "stop polling",
+                "exchangeInfo InstrumentStatus polling is not supported"
+            );
+            return;
+        }
+    };
+
+    let mut last = HashMap::with_capacity(subscriptions.len());
+    let mut sequence = 0;
+    let mut interval = tokio::time::interval(POLL_INTERVAL);
+
+    loop {
+        interval.tick().await;
+
+        let info = match fetch_exchange_info(&url).await {
+            Ok(info) => info,
+            Err(error) => {
+                warn!(
+                    %exchange,
+                    %error,
+                    action = "retry next interval",
+                    "failed to poll exchangeInfo for InstrumentStatus"
+                );
+                continue;
+            }
+        };
+
+        for payload in status_events(exchange, &subscriptions, &info, &mut last) {
+            if control.is_paused(exchange, &payload) {
+                continue;
+            }
+            debug!(%exchange, ?payload, "polled InstrumentStatus change");
+            if exchange_tx.send(Event { sequence, payload }).is_err() {
+                return;
+            }
+            sequence += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::subscription::SubKind;
+
+    #[test]
+    fn test_deserialise_binance_exchange_info() {
+        let input = r#"{
+            "timezone": "UTC", "serverTime": 1671217200000,
+            "symbols": [
+                {"symbol": "BTCUSDT", "pair": "BTCUSDT", "contractType": "PERPETUAL", "status": "TRADING", "baseAsset": "BTC", "quoteAsset": "USDT"},
+                {"symbol": "ETHUSDT", "pair": "ETHUSDT", "contractType": "PERPETUAL", "status": "SETTLING", "baseAsset": "ETH", "quoteAsset": "USDT"}
+            ]
+        }"#;
+
+        let actual = serde_json::from_str::<BinanceExchangeInfo>(input).unwrap();
+        let expected = BinanceExchangeInfo {
+            symbols: vec![
+                BinanceSymbolStatus {
+                    symbol: "BTCUSDT".to_owned(),
+                    status: "TRADING".to_owned(),
+                    contract_type: "PERPETUAL".to_owned(),
+                    base_asset: "BTC".to_owned(),
+                    quote_asset: "USDT".to_owned(),
+                },
+                BinanceSymbolStatus {
+                    symbol: "ETHUSDT".to_owned(),
+                    status: "SETTLING".to_owned(),
+                    contract_type: "PERPETUAL".to_owned(),
+                    base_asset: "ETH".to_owned(),
+                    quote_asset: "USDT".to_owned(),
+                },
+            ],
+        };
+
+        assert_eq!(actual, expected);
+        assert_eq!(
+            perpetual_instruments(&actual),
+            vec![Instrument::from((
+                "btc",
+                "usdt",
+                InstrumentKind::FuturePerpetual
+            ))]
+        );
+    }
+
+    #[test]
+    fn test_status_events() {
+        let subscriptions = vec![
+            Subscription::new(
+                ExchangeId::BinanceFuturesUsd,
+                ("btc", "usdt", InstrumentKind::FuturePerpetual),
+                SubKind::InstrumentStatus,
+            ),
+            Subscription::new(
+                ExchangeId::BinanceFuturesUsd,
+                ("eth", "usdt", InstrumentKind::FuturePerpetual),
+                SubKind::InstrumentStatus,
+            ),
+        ];
+        let info = |btc: &str, eth: Option<&str>| BinanceExchangeInfo {
+            symbols: std::iter::once(("BTCUSDT", btc))
+                .chain(eth.map(|eth| ("ETHUSDT", eth)))
+                .map(|(symbol, status)| BinanceSymbolStatus {
+                    symbol: symbol.to_owned(),
+                    status: status.to_owned(),
+                    contract_type: "PERPETUAL".to_owned(),
+                    base_asset: String::new(),
+                    quote_asset: String::new(),
+                })
+                .collect(),
+        };
+
+        struct TestCase {
+            input: BinanceExchangeInfo,
+            expected: Vec<InstrumentStatus>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: first poll distributes every subscribed status
+                input: info("TRADING", Some("TRADING")),
+                expected: vec![InstrumentStatus::Trading, InstrumentStatus::Trading],
+            },
+            TestCase {
+                // TC1: unchanged statuses are not distributed
+                input: info("TRADING", Some("TRADING")),
+                expected: vec![],
+            },
+            TestCase {
+                // TC2: halted symbol is distributed
+                input: info("HALT", Some("TRADING")),
+                expected: vec![InstrumentStatus::Halted],
+            },
+            TestCase {
+                // TC3: symbol removed from exchangeInfo is Delisted
+                input: info("HALT", None),
+                expected: vec![InstrumentStatus::Delisted],
+            },
+        ];
+
+        let mut last = HashMap::new();
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = status_events(
+                ExchangeId::BinanceFuturesUsd,
+                &subscriptions,
+                &test.input,
+                &mut last,
+            )
+            .into_iter()
+            .map(|event| match event.kind {
+                DataKind::InstrumentStatus(status) => status,
+                other => panic!("TC{index} failed with unexpected DataKind: {other:?}"),
+            })
+            .collect::<Vec<_>>();
+
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
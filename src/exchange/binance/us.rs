@@ -0,0 +1,486 @@
+use super::{
+    futures::BinanceFuturesUsd,
+    model::{
+        mini_ticker_events, partial_depth_events, trade_events, BinanceCombinedStream,
+        BinanceMessage, BinanceStream, BinanceSubResponse,
+    },
+};
+use crate::{
+    model::{
+        subscription::{SubKind, TickerWindow},
+        MarketEvent,
+    },
+    ExchangeId, ExchangeTransformer, RateLimit, Subscriber, Subscription, SubscriptionIds,
+    SubscriptionMeta,
+};
+use barter_integration::{
+    error::SocketError, protocol::websocket::WsMessage, Transformer, Validator,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use tokio::sync::mpsc;
+
+/// [`BinanceUs`] [`Subscriber`](crate::Subscriber) &
+/// [`ExchangeTransformer`](crate::ExchangeTransformer) implementor for the collection
+/// of `Spot` data via Binance.US.
+///
+/// Binance.US is a separate exchange to Binance, with it's own symbol availability, so emitted
+/// [`MarketEvent`]s are tagged with [`ExchangeId::BinanceUs`]. Channel names & message formats
+/// are shared with [`BinanceFuturesUsd`].
+///
+/// See docs: <https://docs.binance.us/#websocket-streams>
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct BinanceUs {
+    pub ids: SubscriptionIds,
+}
+
+impl Subscriber for BinanceUs {
+    type SubResponse = BinanceSubResponse;
+
+    fn base_url() -> &'static str {
+        ExchangeId::BinanceUs.ws_base_url()
+    }
+
+    fn ws_rate_limit() -> Option<RateLimit> {
+        ExchangeId::BinanceUs.ws_rate_limit()
+    }
+
+    fn url(subscriptions: &[Subscription]) -> Result<String, SocketError> {
+        // Map Barter Subscriptions to BinanceUs 'StreamNames' eg/ "btcusd@aggTrade"
+        let mut stream_names = Vec::with_capacity(subscriptions.len());
+        for subscription in subscriptions {
+            let (channel, market) = Self::build_channel_meta(subscription)?;
+            let stream_name = BinanceFuturesUsd::stream_name(&subscription.kind, &channel, &market);
+
+            // All market 'StreamNames' are shared by every Subscription, so only action once
+            if !stream_names.contains(&stream_name) {
+                stream_names.push(stream_name);
+            }
+        }
+
+        if let Some(max_streams) = Self::EXCHANGE.max_streams_per_connection() {
+            if stream_names.len() > max_streams {
+                return Err(SocketError::Subscribe(format!(
+                    "{} StreamNames exceeds the {} combined stream limit of {}",
+                    stream_names.len(),
+                    Self::EXCHANGE,
+                    max_streams
+                )));
+            }
+        }
+
+        Ok(format!(
+            "{}?streams={}",
+            Self::base_url(),
+            stream_names.join("/")
+        ))
+    }
+
+    fn build_subscription_meta(
+        subscriptions: &[Subscription],
+    ) -> Result<SubscriptionMeta, SocketError> {
+        // Allocate SubscriptionIds HashMap to track identifiers for each actioned Subscription
+        let mut ids = SubscriptionIds(HashMap::with_capacity(subscriptions.len()));
+
+        for subscription in subscriptions {
+            // Determine BinanceUs specific channel & market for this Barter Subscription
+            let (channel, market) = Self::build_channel_meta(subscription)?;
+
+            // Use "channel|market" as the SubscriptionId key in the SubscriptionIds
+            // '--> Uppercase market to match incoming exchange event
+            // eg/ SubscriptionId("@aggTrade|BTCUSD")
+            BinanceFuturesUsd::insert_subscription_id(
+                &mut ids,
+                &channel,
+                &market.to_uppercase(),
+                subscription,
+            )?;
+        }
+
+        // 'StreamNames' are actioned via the combined stream url, so no subscription messages
+        // are sent & no subscription responses are expected
+        Ok(SubscriptionMeta {
+            ids,
+            expected_responses: 0,
+            subscriptions: vec![],
+        })
+    }
+}
+
+impl ExchangeTransformer for BinanceUs {
+    const EXCHANGE: ExchangeId = ExchangeId::BinanceUs;
+    fn new(_: mpsc::UnboundedSender<WsMessage>, ids: SubscriptionIds) -> Self {
+        Self { ids }
+    }
+}
+
+impl Transformer<MarketEvent> for BinanceUs {
+    type Input = BinanceStream;
+    type OutputIter = Vec<Result<MarketEvent, SocketError>>;
+
+    fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+        let input = match input {
+            BinanceStream::Message(BinanceCombinedStream {
+                data: BinanceMessage::Trade(trade),
+                ..
+            }) => return trade_events(BinanceUs::EXCHANGE, &self.ids, trade),
+            BinanceStream::Message(input) => input,
+            BinanceStream::MiniTickers(input) => {
+                return mini_ticker_events(BinanceUs::EXCHANGE, &self.ids, input.data)
+            }
+            BinanceStream::PartialDepth(input) => {
+                return partial_depth_events(BinanceUs::EXCHANGE, &self.ids, input)
+            }
+        };
+
+        match self.ids.find_instrument(&input.data.subscription_id()) {
+            Ok(instrument) => vec![Ok(MarketEvent::from((
+                BinanceUs::EXCHANGE,
+                instrument,
+                input.data,
+            )))],
+            Err(error) => vec![Err(error)],
+        }
+    }
+}
+
+impl BinanceUs {
+    /// [`BinanceUs`] rolling window ticker statistics channel name prefix, suffixed with the
+    /// window size (eg/ "@ticker_1h").
+    ///
+    /// See docs: <https://docs.binance.us/#rolling-window-statistics-stream>
+    pub const CHANNEL_ROLLING_TICKER: &'static str = "@ticker_";
+
+    /// Build the [`BinanceUs`] rolling window ticker channel of the provided [`TickerWindow`].
+    ///
+    /// eg/ "@ticker_4h"
+    pub fn rolling_ticker_channel(window: TickerWindow) -> String {
+        let window = match window {
+            TickerWindow::Hour1 => "1h",
+            TickerWindow::Hour4 => "4h",
+            TickerWindow::Day1 => "1d",
+        };
+
+        format!("{}{}", Self::CHANNEL_ROLLING_TICKER, window)
+    }
+
+    /// Determine the [`BinanceUs`] channel metadata associated with an input Barter
+    /// [`Subscription`]. This includes the `String` channel identifier, and a `String` market
+    /// identifier. Both are used to build a [`BinanceUs`] 'StreamName'.
+    ///
+    /// Example Ok return: Ok("@aggTrade", "btcusd")
+    /// where channel == "@aggTrade" & market == "btcusd"
+    pub fn build_channel_meta(sub: &Subscription) -> Result<(String, String), SocketError> {
+        // Validate provided Subscription InstrumentKind is supported by BinanceUs
+        let sub = sub.validate()?;
+
+        // Determine the BinanceUs channel, which are shared with BinanceFuturesUsd
+        let channel = match &sub.kind {
+            SubKind::Trade | SubKind::AggTrade => BinanceFuturesUsd::CHANNEL_TRADES.to_owned(),
+            SubKind::Ticker => BinanceFuturesUsd::CHANNEL_TICKER.to_owned(),
+            SubKind::TickerAll => BinanceFuturesUsd::CHANNEL_MINI_TICKER.to_owned(),
+            SubKind::RollingTicker(window) => Self::rolling_ticker_channel(*window),
+            SubKind::OrderBookL2Snapshot(depth, speed) => {
+                BinanceFuturesUsd::partial_depth_channel(Self::EXCHANGE, *depth, *speed)?
+            }
+            SubKind::Candle(interval) => format!(
+                "{}{}",
+                BinanceFuturesUsd::CHANNEL_CANDLES,
+                BinanceFuturesUsd::interval(*interval)?
+            ),
+            other => {
+                return Err(SocketError::Unsupported {
+                    entity: BinanceUs::EXCHANGE.as_str(),
+                    item: other.to_string(),
+                })
+            }
+        };
+
+        // Determine BinanceUs market using the Instrument
+        let market = format!("{}{}", sub.instrument.base, sub.instrument.quote);
+
+        Ok((channel, market))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchange::binance::model::{BinanceRollingTicker, BinanceTrade};
+    use crate::model::{
+        subscription::{Depth, Interval, UpdateSpeed},
+        DataKind, PublicTrade, RollingTicker,
+    };
+    use barter_integration::model::{Exchange, Instrument, InstrumentKind, Side, SubscriptionId};
+    use chrono::Utc;
+
+    #[test]
+    fn test_build_channel_meta() {
+        struct TestCase {
+            input: Subscription,
+            expected: Result<(String, String), SocketError>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: Supported InstrumentKind::Spot trades subscription
+                input: Subscription::new(
+                    ExchangeId::BinanceUs,
+                    ("btc", "usd", InstrumentKind::Spot),
+                    SubKind::Trade,
+                ),
+                expected: Ok(("@aggTrade".to_owned(), "btcusd".to_owned())),
+            },
+            TestCase {
+                // TC1: Supported InstrumentKind::Spot candle subscription
+                input: Subscription::new(
+                    ExchangeId::BinanceUs,
+                    ("eth", "usdt", InstrumentKind::Spot),
+                    SubKind::Candle(Interval::Minute15),
+                ),
+                expected: Ok(("@kline_15m".to_owned(), "ethusdt".to_owned())),
+            },
+            TestCase {
+                // TC2: Unsupported InstrumentKind::FuturePerpetual subscription
+                input: Subscription::new(
+                    ExchangeId::BinanceUs,
+                    ("btc", "usd", InstrumentKind::FuturePerpetual),
+                    SubKind::Trade,
+                ),
+                expected: Err(SocketError::Unsupported {
+                    entity: "",
+                    item: "".to_string(),
+                }),
+            },
+            TestCase {
+                // TC3: Unsupported InstrumentKind::Spot MarkPrice subscription
+                input: Subscription::new(
+                    ExchangeId::BinanceUs,
+                    ("btc", "usd", InstrumentKind::Spot),
+                    SubKind::MarkPrice,
+                ),
+                expected: Err(SocketError::Unsupported {
+                    entity: "",
+                    item: "".to_string(),
+                }),
+            },
+            TestCase {
+                // TC4: Supported InstrumentKind::Spot aggregated trades subscription
+                input: Subscription::new(
+                    ExchangeId::BinanceUs,
+                    ("btc", "usd", InstrumentKind::Spot),
+                    SubKind::AggTrade,
+                ),
+                expected: Ok(("@aggTrade".to_owned(), "btcusd".to_owned())),
+            },
+            TestCase {
+                // TC5: Supported InstrumentKind::Spot ticker subscription
+                input: Subscription::new(
+                    ExchangeId::BinanceUs,
+                    ("btc", "usd", InstrumentKind::Spot),
+                    SubKind::Ticker,
+                ),
+                expected: Ok(("@ticker".to_owned(), "btcusd".to_owned())),
+            },
+            TestCase {
+                // TC6: Supported InstrumentKind::Spot all market ticker subscription
+                input: Subscription::new(
+                    ExchangeId::BinanceUs,
+                    ("btc", "usd", InstrumentKind::Spot),
+                    SubKind::TickerAll,
+                ),
+                expected: Ok(("@miniTicker".to_owned(), "btcusd".to_owned())),
+            },
+            TestCase {
+                // TC7: Supported InstrumentKind::Spot default speed partial depth subscription
+                input: Subscription::new(
+                    ExchangeId::BinanceUs,
+                    ("btc", "usd", InstrumentKind::Spot),
+                    SubKind::OrderBookL2Snapshot(Depth::from(5), UpdateSpeed::Ms1000),
+                ),
+                expected: Ok(("@depth5".to_owned(), "btcusd".to_owned())),
+            },
+            TestCase {
+                // TC8: Unsupported InstrumentKind::Spot partial depth update speed
+                input: Subscription::new(
+                    ExchangeId::BinanceUs,
+                    ("btc", "usd", InstrumentKind::Spot),
+                    SubKind::OrderBookL2Snapshot(Depth::from(5), UpdateSpeed::Ms500),
+                ),
+                expected: Err(SocketError::Unsupported {
+                    entity: "",
+                    item: "".to_string(),
+                }),
+            },
+            TestCase {
+                // TC9: Supported InstrumentKind::Spot 4h rolling window ticker subscription
+                input: Subscription::new(
+                    ExchangeId::BinanceUs,
+                    ("btc", "usd", InstrumentKind::Spot),
+                    SubKind::RollingTicker(TickerWindow::Hour4),
+                ),
+                expected: Ok(("@ticker_4h".to_owned(), "btcusd".to_owned())),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = BinanceUs::build_channel_meta(&test.input);
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => {
+                    assert_eq!(actual, expected, "TC{} failed", index)
+                }
+                (Err(_), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_binance_us_transform() {
+        let subscriptions = vec![
+            Subscription::new(
+                ExchangeId::BinanceUs,
+                ("btc", "usd", InstrumentKind::Spot),
+                SubKind::Trade,
+            ),
+            Subscription::new(
+                ExchangeId::BinanceUs,
+                ("btc", "usd", InstrumentKind::Spot),
+                SubKind::RollingTicker(TickerWindow::Hour1),
+            ),
+        ];
+        let mut transformer = BinanceUs {
+            ids: BinanceUs::build_subscription_meta(&subscriptions)
+                .unwrap()
+                .ids,
+        };
+
+        let time = Utc::now();
+        let ticker = BinanceRollingTicker {
+            market: "BTCUSD".to_owned(),
+            time,
+            open: 1000.0,
+            last_price: 1100.0,
+            high: 1200.0,
+            low: 900.0,
+            volume: 10.0,
+            quote_volume: 10500.0,
+            change_percent: 10.0,
+            trade_count: 100,
+        };
+
+        struct TestCase {
+            input: BinanceMessage,
+            expected: Vec<Result<MarketEvent, SocketError>>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: BinanceMessage with unknown SubscriptionId
+                input: BinanceMessage::Trade(BinanceTrade {
+                    subscription_id: SubscriptionId::from("@aggTrade|ETHUSD"),
+                    time,
+                    id: 0,
+                    first_trade_id: 0,
+                    last_trade_id: 0,
+                    price: 1000.0,
+                    quantity: 1.0,
+                    side: Side::Buy,
+                }),
+                expected: vec![Err(SocketError::Unidentifiable(SubscriptionId::from(
+                    "@aggTrade|ETHUSD",
+                )))],
+            },
+            TestCase {
+                // TC1: BinanceMessage Spot trade w/ known SubscriptionId is tagged BinanceUs
+                input: BinanceMessage::Trade(BinanceTrade {
+                    subscription_id: SubscriptionId::from("@aggTrade|BTCUSD"),
+                    time,
+                    id: 0,
+                    first_trade_id: 0,
+                    last_trade_id: 0,
+                    price: 1000.0,
+                    quantity: 1.0,
+                    side: Side::Sell,
+                }),
+                expected: vec![Ok(MarketEvent {
+                    exchange_time: time,
+                    received_time: time,
+                    received_instant: None,
+                    raw: None,
+                    exchange: Exchange::from(ExchangeId::BinanceUs),
+                    instrument: Instrument::from(("btc", "usd", InstrumentKind::Spot)),
+                    kind: DataKind::Trade(PublicTrade {
+                        id: "0".to_string(),
+                        price: 1000.0,
+                        quantity: 1.0,
+                        side: Side::Sell,
+                    }),
+                })],
+            },
+            TestCase {
+                // TC2: BinanceMessage 1h rolling ticker w/ known SubscriptionId carries window
+                input: BinanceMessage::RollingTicker1h(ticker.clone()),
+                expected: vec![Ok(MarketEvent {
+                    exchange_time: time,
+                    received_time: time,
+                    received_instant: None,
+                    raw: None,
+                    exchange: Exchange::from(ExchangeId::BinanceUs),
+                    instrument: Instrument::from(("btc", "usd", InstrumentKind::Spot)),
+                    kind: DataKind::RollingTicker(RollingTicker {
+                        window: TickerWindow::Hour1,
+                        open: 1000.0,
+                        last_price: 1100.0,
+                        high: 1200.0,
+                        low: 900.0,
+                        volume: 10.0,
+                        quote_volume: 10500.0,
+                        change_percent: 10.0,
+                        trade_count: 100,
+                    }),
+                })],
+            },
+            TestCase {
+                // TC3: BinanceMessage 4h rolling ticker w/o 4h Subscription is unidentifiable
+                input: BinanceMessage::RollingTicker4h(ticker),
+                expected: vec![Err(SocketError::Unidentifiable(SubscriptionId::from(
+                    "@ticker_4h|BTCUSD",
+                )))],
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = transformer.transform(BinanceStream::Message(BinanceCombinedStream {
+                stream: "btcusd@aggTrade".to_owned(),
+                data: test.input,
+            }));
+            assert_eq!(actual.len(), test.expected.len(), "TC{} failed", index);
+
+            for (actual, expected) in actual.into_iter().zip(test.expected) {
+                match (actual, expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        // Scrub Utc::now() timestamps to allow comparison
+                        let actual = MarketEvent {
+                            received_time: time,
+                            ..actual
+                        };
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,555 @@
+use crate::{
+    model::{
+        Balance, BalanceUpdate, DataKind, OrderFill, OrderStatus, OrderUpdate, PositionUpdate,
+    },
+    ExchangeId, MarketEvent,
+};
+use barter_integration::model::{Exchange, Instrument, Side};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// `Binance` & `BinanceFuturesUsd` private user data stream message variants.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#user-data-streams>
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#user-data-streams>
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[serde(tag = "e")]
+pub enum BinanceUserData {
+    #[serde(rename = "executionReport")]
+    ExecutionReport(BinanceOrder),
+    #[serde(rename = "ORDER_TRADE_UPDATE")]
+    OrderTradeUpdate(BinanceOrderTradeUpdate),
+    #[serde(rename = "outboundAccountPosition")]
+    OutboundAccountPosition(BinanceAccountPosition),
+    #[serde(rename = "ACCOUNT_UPDATE")]
+    AccountUpdate(BinanceAccountUpdate),
+}
+
+impl BinanceUserData {
+    /// Return the `Binance` symbol (eg/ "BTCUSDT") this [`BinanceUserData`] message relates to,
+    /// if it relates to a single symbol.
+    pub fn symbol(&self) -> Option<&str> {
+        match self {
+            BinanceUserData::ExecutionReport(order) => Some(&order.symbol),
+            BinanceUserData::OrderTradeUpdate(update) => Some(&update.order.symbol),
+            BinanceUserData::OutboundAccountPosition(_) | BinanceUserData::AccountUpdate(_) => None,
+        }
+    }
+}
+
+impl From<(ExchangeId, Instrument, BinanceOrder)> for MarketEvent {
+    fn from((exchange_id, instrument, order): (ExchangeId, Instrument, BinanceOrder)) -> Self {
+        Self {
+            exchange_time: order.time,
+            received_time: Utc::now(),
+            received_instant: None,
+            raw: None,
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: DataKind::OrderUpdate(OrderUpdate::from(order)),
+        }
+    }
+}
+
+/// `BinanceFuturesUsd` order update message wrapping the updated [`BinanceOrder`].
+///
+/// eg/ {"e":"ORDER_TRADE_UPDATE","E":1568879465651,"T":1568879465650,"o":{"s":"BTCUSDT", ...}}
+///
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#event-order-update>
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BinanceOrderTradeUpdate {
+    #[serde(alias = "o")]
+    pub order: BinanceOrder,
+}
+
+/// `Binance` order state contained in a spot executionReport, or a futures
+/// [`BinanceOrderTradeUpdate`].
+///
+/// eg/ {"s":"BTCUSDT","c":"myOrder1","S":"BUY","o":"LIMIT","q":"1.00000000","p":"0.10264410",
+///      "X":"PARTIALLY_FILLED","i":4293153,"z":"0.25000000","T":1499405658657, ...}
+///
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#payloads-order-update>
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BinanceOrder {
+    #[serde(alias = "s")]
+    pub symbol: String,
+    #[serde(alias = "i", deserialize_with = "crate::exchange::de_str")]
+    pub id: u64,
+    #[serde(alias = "c")]
+    pub client_id: String,
+    #[serde(alias = "S")]
+    pub side: Side,
+    #[serde(alias = "X")]
+    pub status: BinanceOrderStatus,
+    #[serde(alias = "p", deserialize_with = "crate::exchange::de_str")]
+    pub price: f64,
+    #[serde(alias = "q", deserialize_with = "crate::exchange::de_str")]
+    pub quantity: f64,
+    #[serde(alias = "z", deserialize_with = "crate::exchange::de_str")]
+    pub filled: f64,
+    #[serde(alias = "x")]
+    pub execution: BinanceExecutionType,
+    /// Identifier of the trade that filled this order, if the [`BinanceExecutionType::Trade`].
+    #[serde(alias = "t")]
+    pub trade_id: i64,
+    /// Price of the trade that filled this order, if the [`BinanceExecutionType::Trade`].
+    #[serde(alias = "L", deserialize_with = "crate::exchange::de_str")]
+    pub last_price: f64,
+    /// Quantity of the trade that filled this order, if the [`BinanceExecutionType::Trade`].
+    #[serde(alias = "l", deserialize_with = "crate::exchange::de_str")]
+    pub last_quantity: f64,
+    #[serde(alias = "m", default)]
+    pub is_maker: bool,
+    #[serde(
+        alias = "T",
+        deserialize_with = "crate::exchange::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+}
+
+/// `Binance` spot account balances that changed, sent with every balance change.
+///
+/// eg/ {"e":"outboundAccountPosition","E":1564034571105,"u":1564034571073,
+///      "B":[{"a":"ETH","f":"10000.000000","l":"0.000000"}]}
+///
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#payload-account-update>
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BinanceAccountPosition {
+    #[serde(
+        alias = "u",
+        deserialize_with = "crate::exchange::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+    #[serde(alias = "B")]
+    pub balances: Vec<BinanceSpotBalance>,
+}
+
+/// `Binance` spot asset balance contained in a [`BinanceAccountPosition`].
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BinanceSpotBalance {
+    #[serde(alias = "a")]
+    pub asset: String,
+    #[serde(alias = "f", deserialize_with = "crate::exchange::de_str")]
+    pub free: f64,
+    #[serde(alias = "l", deserialize_with = "crate::exchange::de_str")]
+    pub locked: f64,
+}
+
+impl From<BinanceAccountPosition> for BalanceUpdate {
+    fn from(position: BinanceAccountPosition) -> Self {
+        Self {
+            time: position.time,
+            balances: position
+                .balances
+                .into_iter()
+                .map(|balance| Balance {
+                    total: balance.free + balance.locked,
+                    available: balance.free,
+                    asset: balance.asset,
+                })
+                .collect(),
+        }
+    }
+}
+
+/// `BinanceFuturesUsd` account update, sent when a balance or position changes.
+///
+/// eg/ {"e":"ACCOUNT_UPDATE","E":1564745798939,"T":1564745798938,
+///      "a":{"m":"ORDER","B":[{"a":"USDT","wb":"122624.12345678","cw":"100.12345678","bc":"50.12345678"}],"P":[]}}
+///
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#event-balance-and-position-update>
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BinanceAccountUpdate {
+    #[serde(
+        alias = "T",
+        deserialize_with = "crate::exchange::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+    #[serde(alias = "a")]
+    pub account: BinanceAccountUpdateData,
+}
+
+/// Changed balances & positions contained in a [`BinanceAccountUpdate`].
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BinanceAccountUpdateData {
+    #[serde(alias = "B")]
+    pub balances: Vec<BinanceFuturesBalance>,
+    #[serde(alias = "P", default)]
+    pub positions: Vec<BinanceFuturesPosition>,
+}
+
+/// `BinanceFuturesUsd` asset balance contained in a [`BinanceAccountUpdate`].
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BinanceFuturesBalance {
+    #[serde(alias = "a")]
+    pub asset: String,
+    #[serde(alias = "wb", deserialize_with = "crate::exchange::de_str")]
+    pub wallet_balance: f64,
+    #[serde(alias = "cw", deserialize_with = "crate::exchange::de_str")]
+    pub cross_wallet_balance: f64,
+}
+
+/// `BinanceFuturesUsd` position contained in a [`BinanceAccountUpdate`].
+///
+/// eg/ {"s":"BTCUSDT","pa":"-0.5","ep":"16000.0","cr":"200","up":"0","mt":"isolated","iw":"500.0","ps":"BOTH"}
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BinanceFuturesPosition {
+    #[serde(alias = "s")]
+    pub symbol: String,
+    #[serde(alias = "pa", deserialize_with = "crate::exchange::de_str")]
+    pub amount: f64,
+    #[serde(alias = "ep", deserialize_with = "crate::exchange::de_str")]
+    pub entry_price: f64,
+    #[serde(alias = "iw", deserialize_with = "crate::exchange::de_str")]
+    pub isolated_wallet: f64,
+}
+
+impl From<(DateTime<Utc>, &BinanceFuturesPosition)> for PositionUpdate {
+    fn from((time, position): (DateTime<Utc>, &BinanceFuturesPosition)) -> Self {
+        Self {
+            time,
+            size: position.amount,
+            entry_price: position.entry_price,
+            // Binance does not provide the liquidation price via the user data stream
+            liquidation_price: None,
+            // Cross margined positions share the cross wallet, so have zero isolated margin
+            margin: position.isolated_wallet,
+        }
+    }
+}
+
+impl BinanceAccountUpdate {
+    /// Map every [`BinanceFuturesPosition`] into a [`PositionUpdate`], paired with its symbol.
+    pub fn position_updates(&self) -> Vec<(&str, PositionUpdate)> {
+        self.account
+            .positions
+            .iter()
+            .map(|position| {
+                (
+                    position.symbol.as_str(),
+                    PositionUpdate::from((self.time, position)),
+                )
+            })
+            .collect()
+    }
+}
+
+impl From<&BinanceAccountUpdate> for BalanceUpdate {
+    fn from(update: &BinanceAccountUpdate) -> Self {
+        Self {
+            time: update.time,
+            balances: update
+                .account
+                .balances
+                .iter()
+                .map(|balance| Balance {
+                    asset: balance.asset.clone(),
+                    total: balance.wallet_balance,
+                    available: balance.cross_wallet_balance,
+                })
+                .collect(),
+        }
+    }
+}
+
+/// `Binance` execution type of an order update, determining if the update was caused by a fill.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#enum-definitions>
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum BinanceExecutionType {
+    Trade,
+    #[serde(other)]
+    Other,
+}
+
+/// `Binance` order status.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#enum-definitions>
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum BinanceOrderStatus {
+    New,
+    PartiallyFilled,
+    Filled,
+    Canceled,
+    PendingCancel,
+    Rejected,
+    Expired,
+    ExpiredInMatch,
+}
+
+impl From<BinanceOrderStatus> for OrderStatus {
+    fn from(status: BinanceOrderStatus) -> Self {
+        match status {
+            BinanceOrderStatus::New => OrderStatus::New,
+            BinanceOrderStatus::PartiallyFilled => OrderStatus::PartiallyFilled,
+            BinanceOrderStatus::Filled => OrderStatus::Filled,
+            BinanceOrderStatus::Canceled | BinanceOrderStatus::PendingCancel => {
+                OrderStatus::Cancelled
+            }
+            BinanceOrderStatus::Rejected => OrderStatus::Rejected,
+            BinanceOrderStatus::Expired | BinanceOrderStatus::ExpiredInMatch => {
+                OrderStatus::Expired
+            }
+        }
+    }
+}
+
+impl From<BinanceOrder> for OrderUpdate {
+    fn from(order: BinanceOrder) -> Self {
+        Self {
+            id: order.id.to_string(),
+            client_id: Some(order.client_id).filter(|client_id| !client_id.is_empty()),
+            side: order.side,
+            status: OrderStatus::from(order.status),
+            price: order.price,
+            quantity: order.quantity,
+            filled: order.filled,
+            remaining: (order.quantity - order.filled).max(0.0),
+            fill: (order.execution == BinanceExecutionType::Trade).then(|| OrderFill {
+                trade_id: order.trade_id.to_string(),
+                price: order.last_price,
+                quantity: order.last_quantity,
+                is_maker: order.is_maker,
+            }),
+            time: order.time,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchange::datetime_utc_from_epoch_duration;
+    use barter_integration::error::SocketError;
+    use std::time::Duration;
+
+    #[test]
+    fn test_deserialise_binance_user_data() {
+        struct TestCase {
+            input: &'static str,
+            expected: Result<BinanceUserData, SocketError>,
+        }
+
+        let order = |status, execution, trade_id, last_price, last_quantity| BinanceOrder {
+            symbol: "BTCUSDT".to_owned(),
+            id: 4293153,
+            client_id: "myOrder1".to_owned(),
+            side: Side::Buy,
+            status,
+            price: 0.1,
+            quantity: 1.0,
+            filled: 0.25,
+            execution,
+            trade_id,
+            last_price,
+            last_quantity,
+            is_maker: false,
+            time: datetime_utc_from_epoch_duration(Duration::from_millis(1499405658657)),
+        };
+
+        let cases = vec![
+            TestCase {
+                // TC0: input is a valid spot executionReport
+                input: r#"{
+                    "e":"executionReport","E":1499405658658,"s":"BTCUSDT","c":"myOrder1",
+                    "S":"BUY","o":"LIMIT","f":"GTC","q":"1.00000000","p":"0.10000000",
+                    "x":"TRADE","X":"PARTIALLY_FILLED","r":"NONE","i":4293153,"l":"0.25000000",
+                    "z":"0.25000000","L":"0.10000000","T":1499405658657,"t":-1
+                }"#,
+                expected: Ok(BinanceUserData::ExecutionReport(order(
+                    BinanceOrderStatus::PartiallyFilled,
+                    BinanceExecutionType::Trade,
+                    -1,
+                    0.1,
+                    0.25,
+                ))),
+            },
+            TestCase {
+                // TC1: input is a valid futures ORDER_TRADE_UPDATE
+                input: r#"{
+                    "e":"ORDER_TRADE_UPDATE","E":1499405658658,"T":1499405658657,
+                    "o":{
+                        "s":"BTCUSDT","c":"myOrder1","S":"BUY","o":"LIMIT","f":"GTC",
+                        "q":"1","p":"0.1","x":"CANCELED","X":"CANCELED","i":4293153,
+                        "l":"0","z":"0.25","L":"0","T":1499405658657,"t":0
+                    }
+                }"#,
+                expected: Ok(BinanceUserData::OrderTradeUpdate(BinanceOrderTradeUpdate {
+                    order: order(
+                        BinanceOrderStatus::Canceled,
+                        BinanceExecutionType::Other,
+                        0,
+                        0.0,
+                        0.0,
+                    ),
+                })),
+            },
+            TestCase {
+                // TC2: input is a valid spot outboundAccountPosition
+                input: r#"{
+                    "e":"outboundAccountPosition","E":1564034571105,"u":1564034571073,
+                    "B":[{"a":"ETH","f":"10000.000000","l":"1.500000"}]
+                }"#,
+                expected: Ok(BinanceUserData::OutboundAccountPosition(
+                    BinanceAccountPosition {
+                        time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1564034571073,
+                        )),
+                        balances: vec![BinanceSpotBalance {
+                            asset: "ETH".to_owned(),
+                            free: 10000.0,
+                            locked: 1.5,
+                        }],
+                    },
+                )),
+            },
+            TestCase {
+                // TC3: input is a valid futures ACCOUNT_UPDATE
+                input: r#"{
+                    "e":"ACCOUNT_UPDATE","E":1564745798939,"T":1564745798938,
+                    "a":{
+                        "m":"ORDER","B":[{"a":"USDT","wb":"122624.5","cw":"100.5","bc":"50.1"}],
+                        "P":[{"s":"BTCUSDT","pa":"-0.5","ep":"16000.0","cr":"200","up":"0","mt":"isolated","iw":"500.0","ps":"BOTH"}]
+                    }
+                }"#,
+                expected: Ok(BinanceUserData::AccountUpdate(BinanceAccountUpdate {
+                    time: datetime_utc_from_epoch_duration(Duration::from_millis(1564745798938)),
+                    account: BinanceAccountUpdateData {
+                        balances: vec![BinanceFuturesBalance {
+                            asset: "USDT".to_owned(),
+                            wallet_balance: 122624.5,
+                            cross_wallet_balance: 100.5,
+                        }],
+                        positions: vec![BinanceFuturesPosition {
+                            symbol: "BTCUSDT".to_owned(),
+                            amount: -0.5,
+                            entry_price: 16000.0,
+                            isolated_wallet: 500.0,
+                        }],
+                    },
+                })),
+            },
+            TestCase {
+                // TC4: input is an unsupported user data event
+                input: r#"{"e":"listenKeyExpired","E":1576653824250}"#,
+                expected: Err(SocketError::Unsupported {
+                    entity: "",
+                    item: "".to_owned(),
+                }),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = serde_json::from_str::<BinanceUserData>(test.input);
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => {
+                    assert_eq!(actual, expected, "TC{} failed", index)
+                }
+                (Err(_), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_binance_order_to_order_update() {
+        let time = datetime_utc_from_epoch_duration(Duration::from_millis(1499405658657));
+        let input = BinanceOrder {
+            symbol: "BTCUSDT".to_owned(),
+            id: 1,
+            client_id: "".to_owned(),
+            side: Side::Sell,
+            status: BinanceOrderStatus::ExpiredInMatch,
+            price: 100.0,
+            quantity: 2.0,
+            filled: 0.5,
+            execution: BinanceExecutionType::Trade,
+            trade_id: 7,
+            last_price: 100.0,
+            last_quantity: 0.5,
+            is_maker: true,
+            time,
+        };
+
+        let expected = OrderUpdate {
+            id: "1".to_owned(),
+            client_id: None,
+            side: Side::Sell,
+            status: OrderStatus::Expired,
+            price: 100.0,
+            quantity: 2.0,
+            filled: 0.5,
+            remaining: 1.5,
+            fill: Some(OrderFill {
+                trade_id: "7".to_owned(),
+                price: 100.0,
+                quantity: 0.5,
+                is_maker: true,
+            }),
+            time,
+        };
+
+        assert_eq!(OrderUpdate::from(input), expected);
+    }
+
+    #[test]
+    fn test_binance_account_position_to_balance_update() {
+        let time = datetime_utc_from_epoch_duration(Duration::from_millis(1564034571073));
+        let input = BinanceAccountPosition {
+            time,
+            balances: vec![BinanceSpotBalance {
+                asset: "BTC".to_owned(),
+                free: 1.0,
+                locked: 0.5,
+            }],
+        };
+
+        let expected = BalanceUpdate {
+            time,
+            balances: vec![Balance {
+                asset: "BTC".to_owned(),
+                total: 1.5,
+                available: 1.0,
+            }],
+        };
+
+        assert_eq!(BalanceUpdate::from(input), expected);
+    }
+
+    #[test]
+    fn test_binance_account_update_position_updates() {
+        let time = datetime_utc_from_epoch_duration(Duration::from_millis(1564745798938));
+        let input = BinanceAccountUpdate {
+            time,
+            account: BinanceAccountUpdateData {
+                balances: vec![],
+                positions: vec![BinanceFuturesPosition {
+                    symbol: "ETHUSDT".to_owned(),
+                    amount: 2.0,
+                    entry_price: 1200.0,
+                    isolated_wallet: 0.0,
+                }],
+            },
+        };
+
+        let expected = vec![(
+            "ETHUSDT",
+            PositionUpdate {
+                time,
+                size: 2.0,
+                entry_price: 1200.0,
+                liquidation_price: None,
+                margin: 0.0,
+            },
+        )];
+
+        assert_eq!(input.position_updates(), expected);
+    }
+}
@@ -0,0 +1,665 @@
+use super::{model::BinanceCoinContract, user::BinanceUserData};
+use crate::{
+    model::{subscription::Subscription, BalanceUpdate, DataKind, OrderUpdate, PositionUpdate},
+    ExchangeId, MarketEvent,
+};
+use barter_integration::{
+    error::SocketError,
+    model::{Exchange, Instrument},
+    protocol::websocket::{connect, WebSocket, WsMessage},
+    Event,
+};
+use chrono::{DateTime, Utc};
+use futures::StreamExt;
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, time::Duration};
+use tokio::{sync::mpsc, task::JoinHandle};
+use tokio_stream::wrappers::UnboundedReceiverStream;
+use tracing::{debug, info, warn};
+
+/// Header used to authenticate `Binance` listenKey requests with an API key. Creating &
+/// keeping alive a listenKey only requires the API key, so no request signature is needed.
+pub const HEADER_API_KEY: &str = "X-MBX-APIKEY";
+
+/// Interval at which the `Binance` listenKey is kept alive. A listenKey expires 60 minutes after
+/// it was created or last kept alive.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#listen-key-spot>
+pub const KEEP_ALIVE_INTERVAL: Duration = Duration::from_secs(30 * 60);
+
+/// Convenient type alias for the stream of user data [`MarketEvent`]s initialised by
+/// [`init_user_data`].
+pub type BinanceUserDataStream = UnboundedReceiverStream<Result<Event<MarketEvent>, SocketError>>;
+
+/// `Binance` listenKey response, identifying the user data stream to connect to.
+///
+/// eg/ {"listenKey":"pqia91ma19a5s61cv6a81va65sdf19v8a65a1a5s61cv6a81va65sdf19v8a65a1"}
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct BinanceListenKey {
+    #[serde(rename = "listenKey")]
+    pub listen_key: String,
+}
+
+/// Normalised account event distributed by a `Binance` user data stream. Events are paired
+/// with the `Binance` symbol (eg/ "BTCUSDT") they relate to, where applicable.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub enum UserDataEvent {
+    OrderUpdate {
+        symbol: String,
+        update: OrderUpdate,
+    },
+    BalanceUpdate(BalanceUpdate),
+    PositionUpdate {
+        symbol: String,
+        update: PositionUpdate,
+    },
+}
+
+impl From<BinanceUserData> for Vec<UserDataEvent> {
+    fn from(message: BinanceUserData) -> Self {
+        match message {
+            BinanceUserData::ExecutionReport(order) => vec![UserDataEvent::OrderUpdate {
+                symbol: order.symbol.clone(),
+                update: OrderUpdate::from(order),
+            }],
+            BinanceUserData::OrderTradeUpdate(update) => vec![UserDataEvent::OrderUpdate {
+                symbol: update.order.symbol.clone(),
+                update: OrderUpdate::from(update.order),
+            }],
+            BinanceUserData::OutboundAccountPosition(position) => {
+                vec![UserDataEvent::BalanceUpdate(BalanceUpdate::from(position))]
+            }
+            BinanceUserData::AccountUpdate(update) => {
+                std::iter::once(UserDataEvent::BalanceUpdate(BalanceUpdate::from(&update)))
+                    .chain(
+                        update
+                            .position_updates()
+                            .into_iter()
+                            .map(|(symbol, update)| UserDataEvent::PositionUpdate {
+                                symbol: symbol.to_owned(),
+                                update,
+                            }),
+                    )
+                    .collect()
+            }
+        }
+    }
+}
+
+/// Determine the REST path of the listenKey endpoint associated with the provided `Binance`
+/// [`ExchangeId`].
+///
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#listen-key-spot>
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#start-user-data-stream-user_stream>
+pub fn listen_key_path(exchange: ExchangeId) -> Result<&'static str, SocketError> {
+    match exchange {
+        ExchangeId::Binance => Ok("/api/v3/userDataStream"),
+        ExchangeId::BinanceFuturesUsd => Ok("/fapi/v1/listenKey"),
+        ExchangeId::BinanceFuturesCoin => Ok("/dapi/v1/listenKey"),
+        other => Err(SocketError::Unsupported {
+            entity: other.as_str(),
+            item: "user_data".to_owned(),
+        }),
+    }
+}
+
+/// Build the WebSocket url of the user data stream identified by the provided listenKey. User
+/// data is served as a raw stream from the "/ws" path of the [`ExchangeId::ws_base_url`] host,
+/// rather than the "/stream" combined streams path.
+///
+/// eg/ "wss://fstream.binance.com/ws/pqia91ma19a5s61cv6a81va65sdf19v8a65a1a5s61cv6a81va65sdf19v8a65a1"
+pub fn ws_url(exchange: ExchangeId, listen_key: &str) -> Result<String, SocketError> {
+    // Validate the ExchangeId provides a user data stream
+    listen_key_path(exchange)?;
+
+    let host = exchange.ws_base_url().trim_end_matches("/stream");
+    Ok(format!("{host}/ws/{listen_key}"))
+}
+
+/// Create a [`BinanceListenKey`] via the provided listenKey endpoint, authenticated with the
+/// provided API key.
+pub async fn create_listen_key(
+    url: &str,
+    api_key: &str,
+) -> Result<BinanceListenKey, reqwest::Error> {
+    reqwest::Client::new()
+        .post(url)
+        .header(HEADER_API_KEY, api_key)
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await
+}
+
+/// Extend the validity of the provided listenKey by 60 minutes via the provided listenKey
+/// endpoint, authenticated with the provided API key.
+pub async fn keep_alive_listen_key(
+    url: &str,
+    api_key: &str,
+    listen_key: &str,
+) -> Result<(), reqwest::Error> {
+    reqwest::Client::new()
+        .put(url)
+        .header(HEADER_API_KEY, api_key)
+        .query(&[("listenKey", listen_key)])
+        .send()
+        .await?
+        .error_for_status()
+        .map(|_| ())
+}
+
+/// Routes normalised [`UserDataEvent`]s to the [`Instrument`]s of the subscribed
+/// [`SubKind::UserData`](crate::model::subscription::SubKind::UserData) [`Subscription`]s.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct BinanceUserDataRouter {
+    pub exchange: ExchangeId,
+    /// Subscribed [`Instrument`]s indexed by `Binance` symbol (eg/ "BTCUSDT").
+    pub instruments: HashMap<String, Instrument>,
+}
+
+impl BinanceUserDataRouter {
+    /// Map the provided [`UserDataEvent`] into a [`MarketEvent`] for each subscribed
+    /// [`Instrument`] it relates to. A [`BalanceUpdate`] is filtered to the base & quote asset
+    /// balances of each [`Instrument`], skipping [`Instrument`]s whose balances are unchanged.
+    pub fn route(&self, event: UserDataEvent) -> Vec<MarketEvent> {
+        match event {
+            UserDataEvent::OrderUpdate { symbol, update } => self
+                .instruments
+                .get(&symbol)
+                .map(|instrument| {
+                    self.event(instrument, update.time, DataKind::OrderUpdate(update))
+                })
+                .into_iter()
+                .collect(),
+            UserDataEvent::PositionUpdate { symbol, update } => self
+                .instruments
+                .get(&symbol)
+                .map(|instrument| {
+                    self.event(instrument, update.time, DataKind::PositionUpdate(update))
+                })
+                .into_iter()
+                .collect(),
+            UserDataEvent::BalanceUpdate(update) => self
+                .instruments
+                .values()
+                .filter_map(|instrument| {
+                    let balances = update
+                        .balances
+                        .iter()
+                        .filter(|balance| {
+                            balance.asset.eq_ignore_ascii_case(instrument.base.as_ref())
+                                || balance
+                                    .asset
+                                    .eq_ignore_ascii_case(instrument.quote.as_ref())
+                        })
+                        .cloned()
+                        .collect::<Vec<_>>();
+
+                    (!balances.is_empty()).then(|| {
+                        self.event(
+                            instrument,
+                            update.time,
+                            DataKind::BalanceUpdate(BalanceUpdate {
+                                time: update.time,
+                                balances,
+                            }),
+                        )
+                    })
+                })
+                .collect(),
+        }
+    }
+
+    /// Construct a [`MarketEvent`] of the provided [`Instrument`] & [`DataKind`].
+    fn event(
+        &self,
+        instrument: &Instrument,
+        exchange_time: DateTime<Utc>,
+        kind: DataKind,
+    ) -> MarketEvent {
+        MarketEvent {
+            exchange_time,
+            received_time: Utc::now(),
+            received_instant: None,
+            raw: None,
+            exchange: Exchange::from(self.exchange),
+            instrument: instrument.clone(),
+            kind,
+        }
+    }
+}
+
+/// Initialise a `Binance` user data stream for the account associated with the provided API key,
+/// distributing account updates relating to the provided [`Subscription`]s.
+///
+/// A listenKey is created via REST & kept alive every [`KEEP_ALIVE_INTERVAL`], and each
+/// `executionReport`, `outboundAccountPosition`, `ORDER_TRADE_UPDATE` & `ACCOUNT_UPDATE` message
+/// received is routed to the subscribed [`Instrument`]s by the [`BinanceUserDataRouter`]. The
+/// stream ends when the connection is dropped or the listenKey expires, and the listenKey stops
+/// being kept alive once the stream is dropped.
+pub async fn init_user_data(
+    exchange: ExchangeId,
+    api_key: &str,
+    subscriptions: &[Subscription],
+) -> Result<BinanceUserDataStream, SocketError> {
+    // Index the subscribed Instruments by Binance symbol (eg/ "BTCUSDT", "BTCUSD_PERP")
+    let mut instruments = HashMap::with_capacity(subscriptions.len());
+    for subscription in subscriptions {
+        let symbol = match exchange {
+            ExchangeId::BinanceFuturesCoin => {
+                BinanceCoinContract::try_from(&subscription.instrument)?.symbol()
+            }
+            _ => format!(
+                "{}{}",
+                subscription.instrument.base, subscription.instrument.quote
+            )
+            .to_uppercase(),
+        };
+        instruments.insert(symbol, subscription.instrument.clone());
+    }
+    let router = BinanceUserDataRouter {
+        exchange,
+        instruments,
+    };
+    let url = format!("{}{}", exchange.rest_base_url(), listen_key_path(exchange)?);
+
+    // Create a listenKey identifying the user data stream
+    let BinanceListenKey { listen_key } = create_listen_key(&url, api_key)
+        .await
+        .map_err(|error| SocketError::Subscribe(format!("failed to create listenKey: {error}")))?;
+
+    // Connect to the user data stream
+    let websocket = connect(ws_url(exchange, &listen_key)?).await?;
+    info!(%exchange, "connected to user data stream");
+
+    let (event_tx, event_rx) = mpsc::unbounded_channel();
+
+    let keep_alive = tokio::spawn(keep_alive(
+        exchange,
+        url,
+        api_key.to_owned(),
+        listen_key,
+        event_tx.clone(),
+    ));
+    tokio::spawn(distribute_user_data(
+        router, websocket, event_tx, keep_alive,
+    ));
+
+    Ok(UnboundedReceiverStream::new(event_rx))
+}
+
+/// Keep the provided listenKey alive every [`KEEP_ALIVE_INTERVAL`] until the user data stream
+/// receiver is dropped.
+async fn keep_alive(
+    exchange: ExchangeId,
+    url: String,
+    api_key: String,
+    listen_key: String,
+    event_tx: mpsc::UnboundedSender<Result<Event<MarketEvent>, SocketError>>,
+) {
+    let mut interval = tokio::time::interval(KEEP_ALIVE_INTERVAL);
+
+    // First tick completes immediately, but the listenKey was just created
+    interval.tick().await;
+
+    loop {
+        tokio::select! {
+            _ = interval.tick() => {},
+            _ = event_tx.closed() => break,
+        }
+
+        if let Err(error) = keep_alive_listen_key(&url, &api_key, &listen_key).await {
+            warn!(
+                %exchange,
+                %error,
+                action = "retry next interval",
+                "failed to keep alive listenKey"
+            );
+        }
+    }
+}
+
+/// Distribute the [`MarketEvent`]s routed from the user data messages consumed from the provided
+/// [`WebSocket`] via the `event_tx`, until the connection or receiver is dropped. The
+/// `keep_alive` task is aborted on exit.
+async fn distribute_user_data(
+    router: BinanceUserDataRouter,
+    mut websocket: WebSocket,
+    event_tx: mpsc::UnboundedSender<Result<Event<MarketEvent>, SocketError>>,
+    keep_alive: JoinHandle<()>,
+) {
+    let exchange = router.exchange;
+    let mut sequence = 0;
+
+    'stream: loop {
+        let message = tokio::select! {
+            message = websocket.next() => match message {
+                Some(message) => message,
+                None => break,
+            },
+            _ = event_tx.closed() => break,
+        };
+
+        let payload = match message {
+            Ok(WsMessage::Text(payload)) => payload,
+            Ok(WsMessage::Close(frame)) => {
+                warn!(%exchange, ?frame, "user data stream closed by the server");
+                break;
+            }
+            Ok(_) => continue,
+            Err(error) => {
+                warn!(%exchange, %error, "user data stream connection error");
+                break;
+            }
+        };
+
+        let message = match serde_json::from_str::<BinanceUserData>(&payload) {
+            Ok(message) => message,
+            Err(error) => {
+                // No further events are delivered once the listenKey has expired
+                if payload.contains("listenKeyExpired") {
+                    warn!(%exchange, "user data stream listenKey expired");
+                    break;
+                }
+                debug!(%exchange, %error, %payload, "skipping unsupported user data message");
+                continue;
+            }
+        };
+
+        for payload in Vec::<UserDataEvent>::from(message)
+            .into_iter()
+            .flat_map(|event| router.route(event))
+        {
+            if event_tx.send(Ok(Event { sequence, payload })).is_err() {
+                break 'stream;
+            }
+            sequence += 1;
+        }
+    }
+
+    keep_alive.abort();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        exchange::{
+            binance::user::{
+                BinanceAccountPosition, BinanceAccountUpdate, BinanceAccountUpdateData,
+                BinanceExecutionType, BinanceFuturesBalance, BinanceFuturesPosition, BinanceOrder,
+                BinanceOrderStatus, BinanceSpotBalance,
+            },
+            datetime_utc_from_epoch_duration,
+        },
+        model::{Balance, OrderStatus},
+    };
+    use barter_integration::model::{InstrumentKind, Side};
+
+    #[test]
+    fn test_deserialise_binance_listen_key() {
+        let input =
+            r#"{"listenKey":"pqia91ma19a5s61cv6a81va65sdf19v8a65a1a5s61cv6a81va65sdf19v8a65a1"}"#;
+
+        let actual = serde_json::from_str::<BinanceListenKey>(input).unwrap();
+        let expected = BinanceListenKey {
+            listen_key: "pqia91ma19a5s61cv6a81va65sdf19v8a65a1a5s61cv6a81va65sdf19v8a65a1"
+                .to_owned(),
+        };
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_ws_url() {
+        struct TestCase {
+            input: ExchangeId,
+            expected: Result<String, SocketError>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: Binance spot user data stream
+                input: ExchangeId::Binance,
+                expected: Ok("wss://stream.binance.com:9443/ws/key".to_owned()),
+            },
+            TestCase {
+                // TC1: BinanceFuturesUsd user data stream
+                input: ExchangeId::BinanceFuturesUsd,
+                expected: Ok("wss://fstream.binance.com/ws/key".to_owned()),
+            },
+            TestCase {
+                // TC2: BinanceFuturesCoin user data stream
+                input: ExchangeId::BinanceFuturesCoin,
+                expected: Ok("wss://dstream.binance.com/ws/key".to_owned()),
+            },
+            TestCase {
+                // TC3: Unsupported ExchangeId
+                input: ExchangeId::Kraken,
+                expected: Err(SocketError::Unsupported {
+                    entity: "",
+                    item: "".to_owned(),
+                }),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = ws_url(test.input, "key");
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => {
+                    assert_eq!(actual, expected, "TC{} failed", index)
+                }
+                (Err(_), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_binance_user_data_to_user_data_events() {
+        let time = datetime_utc_from_epoch_duration(Duration::from_millis(1499405658657));
+
+        struct TestCase {
+            input: BinanceUserData,
+            expected: Vec<UserDataEvent>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: executionReport is an OrderUpdate of the associated symbol
+                input: BinanceUserData::ExecutionReport(BinanceOrder {
+                    symbol: "BTCUSDT".to_owned(),
+                    id: 4293153,
+                    client_id: "myOrder1".to_owned(),
+                    side: Side::Buy,
+                    status: BinanceOrderStatus::PartiallyFilled,
+                    price: 0.1,
+                    quantity: 1.0,
+                    filled: 0.25,
+                    execution: BinanceExecutionType::Other,
+                    trade_id: -1,
+                    last_price: 0.0,
+                    last_quantity: 0.0,
+                    is_maker: false,
+                    time,
+                }),
+                expected: vec![UserDataEvent::OrderUpdate {
+                    symbol: "BTCUSDT".to_owned(),
+                    update: OrderUpdate {
+                        id: "4293153".to_owned(),
+                        client_id: Some("myOrder1".to_owned()),
+                        side: Side::Buy,
+                        status: OrderStatus::PartiallyFilled,
+                        price: 0.1,
+                        quantity: 1.0,
+                        filled: 0.25,
+                        remaining: 0.75,
+                        fill: None,
+                        time,
+                    },
+                }],
+            },
+            TestCase {
+                // TC1: outboundAccountPosition is a BalanceUpdate
+                input: BinanceUserData::OutboundAccountPosition(BinanceAccountPosition {
+                    time,
+                    balances: vec![BinanceSpotBalance {
+                        asset: "ETH".to_owned(),
+                        free: 10.0,
+                        locked: 1.5,
+                    }],
+                }),
+                expected: vec![UserDataEvent::BalanceUpdate(BalanceUpdate {
+                    time,
+                    balances: vec![Balance {
+                        asset: "ETH".to_owned(),
+                        total: 11.5,
+                        available: 10.0,
+                    }],
+                })],
+            },
+            TestCase {
+                // TC2: ACCOUNT_UPDATE is a BalanceUpdate followed by each PositionUpdate
+                input: BinanceUserData::AccountUpdate(BinanceAccountUpdate {
+                    time,
+                    account: BinanceAccountUpdateData {
+                        balances: vec![BinanceFuturesBalance {
+                            asset: "USDT".to_owned(),
+                            wallet_balance: 122624.5,
+                            cross_wallet_balance: 100.5,
+                        }],
+                        positions: vec![BinanceFuturesPosition {
+                            symbol: "BTCUSDT".to_owned(),
+                            amount: -0.5,
+                            entry_price: 16000.0,
+                            isolated_wallet: 500.0,
+                        }],
+                    },
+                }),
+                expected: vec![
+                    UserDataEvent::BalanceUpdate(BalanceUpdate {
+                        time,
+                        balances: vec![Balance {
+                            asset: "USDT".to_owned(),
+                            total: 122624.5,
+                            available: 100.5,
+                        }],
+                    }),
+                    UserDataEvent::PositionUpdate {
+                        symbol: "BTCUSDT".to_owned(),
+                        update: PositionUpdate {
+                            time,
+                            size: -0.5,
+                            entry_price: 16000.0,
+                            liquidation_price: None,
+                            margin: 500.0,
+                        },
+                    },
+                ],
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = Vec::<UserDataEvent>::from(test.input);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_binance_user_data_router_route() {
+        struct TestCase {
+            input: UserDataEvent,
+            expected: Vec<(Instrument, DataKind)>,
+        }
+
+        let time = datetime_utc_from_epoch_duration(Duration::from_millis(1499405658657));
+        let btc_usdt = Instrument::from(("btc", "usdt", InstrumentKind::FuturePerpetual));
+        let eth_btc = Instrument::from(("eth", "btc", InstrumentKind::FuturePerpetual));
+        let router = BinanceUserDataRouter {
+            exchange: ExchangeId::BinanceFuturesUsd,
+            instruments: HashMap::from([
+                ("BTCUSDT".to_owned(), btc_usdt.clone()),
+                ("ETHBTC".to_owned(), eth_btc.clone()),
+            ]),
+        };
+
+        let balance = |asset: &str| Balance {
+            asset: asset.to_owned(),
+            total: 1.0,
+            available: 0.5,
+        };
+        let position = PositionUpdate {
+            time,
+            size: -0.5,
+            entry_price: 16000.0,
+            liquidation_price: None,
+            margin: 500.0,
+        };
+
+        let cases = vec![
+            TestCase {
+                // TC0: PositionUpdate of a subscribed symbol
+                input: UserDataEvent::PositionUpdate {
+                    symbol: "BTCUSDT".to_owned(),
+                    update: position,
+                },
+                expected: vec![(btc_usdt.clone(), DataKind::PositionUpdate(position))],
+            },
+            TestCase {
+                // TC1: PositionUpdate of an unsubscribed symbol is skipped
+                input: UserDataEvent::PositionUpdate {
+                    symbol: "SOLUSDT".to_owned(),
+                    update: position,
+                },
+                expected: vec![],
+            },
+            TestCase {
+                // TC2: BalanceUpdate filtered to the base & quote assets of each Instrument
+                input: UserDataEvent::BalanceUpdate(BalanceUpdate {
+                    time,
+                    balances: vec![balance("USDT"), balance("ETH"), balance("BNB")],
+                }),
+                expected: vec![
+                    (
+                        btc_usdt.clone(),
+                        DataKind::BalanceUpdate(BalanceUpdate {
+                            time,
+                            balances: vec![balance("USDT")],
+                        }),
+                    ),
+                    (
+                        eth_btc.clone(),
+                        DataKind::BalanceUpdate(BalanceUpdate {
+                            time,
+                            balances: vec![balance("ETH")],
+                        }),
+                    ),
+                ],
+            },
+            TestCase {
+                // TC3: BalanceUpdate of unsubscribed assets is skipped
+                input: UserDataEvent::BalanceUpdate(BalanceUpdate {
+                    time,
+                    balances: vec![balance("BNB")],
+                }),
+                expected: vec![],
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let mut actual = router
+                .route(test.input)
+                .into_iter()
+                .map(|event| (event.instrument, event.kind))
+                .collect::<Vec<_>>();
+            actual.sort_by(|a, b| a.0.cmp(&b.0));
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
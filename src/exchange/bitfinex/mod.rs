@@ -0,0 +1,673 @@
+use crate::{
+    model::{
+        subscription::{Interval, SubKind, SubscriptionIds, SubscriptionMeta},
+        OrderBookL3DeltaKind,
+    },
+    ExchangeId, ExchangeTransformer, MarketEvent, Subscriber, Subscription, Validator,
+};
+use barter_integration::{
+    error::SocketError, model::SubscriptionId, protocol::websocket::WsMessage, Transformer,
+};
+use model::{
+    BitfinexEvent, BitfinexInterval, BitfinexMessage, BitfinexSubResponse, BitfinexSymbol,
+    BitfinexTradeUpdate,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::collections::HashMap;
+use tokio::sync::mpsc;
+use tracing::debug;
+
+/// [`Bitfinex`] specific data structures.
+pub mod model;
+
+/// [`Bitfinex`] [`Subscriber`] & [`ExchangeTransformer`] implementor for the collection
+/// of `Spot` data via the Bitfinex v2 public WebSocket API.
+///
+/// [`Bitfinex`] allocates a numeric channel identifier to each subscription, and only includes
+/// this identifier in subsequent channel payloads. The [`ExchangeTransformer`] therefore tracks
+/// the channel identifier allocated in each subscription confirmation, and uses it to route
+/// channel payloads to the associated Barter [`Subscription`].
+///
+/// Raw book (R0) payloads do not distinguish new orders from updated orders, so the price of
+/// each resting order is also tracked per channel identifier.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct Bitfinex {
+    pub ids: SubscriptionIds,
+    pub channels: HashMap<u64, Subscription>,
+    pub orders: HashMap<u64, HashMap<u64, f64>>,
+}
+
+impl Subscriber for Bitfinex {
+    type SubResponse = BitfinexSubResponse;
+
+    fn base_url() -> &'static str {
+        ExchangeId::Bitfinex.ws_base_url()
+    }
+
+    fn build_subscription_meta(
+        subscriptions: &[Subscription],
+    ) -> Result<SubscriptionMeta, SocketError> {
+        // Allocate SubscriptionIds HashMap to track identifiers for each actioned Subscription
+        let mut ids = SubscriptionIds(HashMap::with_capacity(subscriptions.len()));
+
+        // Map Barter Subscriptions to Bitfinex channels
+        let subscriptions = subscriptions
+            .iter()
+            .map(|subscription| {
+                // Determine the Bitfinex specific channel & subscription target
+                let (channel, target) = Self::build_channel_meta(subscription)?;
+
+                // Use "channel|target" as the SubscriptionId key in the SubscriptionIds
+                // eg/ SubscriptionId("trades|tBTCUSD")
+                ids.insert(
+                    Bitfinex::subscription_id(channel, &target),
+                    subscription.clone(),
+                );
+
+                // Construct Bitfinex specific subscription message
+                Ok(Self::subscription(channel, &target))
+            })
+            .collect::<Result<Vec<_>, SocketError>>()?;
+
+        Ok(SubscriptionMeta {
+            ids,
+            // Subscription confirmations are consumed by the ExchangeTransformer in order to
+            // track the allocated channel identifiers
+            expected_responses: 0,
+            subscriptions,
+        })
+    }
+}
+
+impl ExchangeTransformer for Bitfinex {
+    const EXCHANGE: ExchangeId = ExchangeId::Bitfinex;
+    fn new(_: mpsc::UnboundedSender<WsMessage>, ids: SubscriptionIds) -> Self {
+        Self {
+            channels: HashMap::with_capacity(ids.len()),
+            orders: HashMap::new(),
+            ids,
+        }
+    }
+}
+
+impl Transformer<MarketEvent> for Bitfinex {
+    type Input = BitfinexMessage;
+    type OutputIter = Vec<Result<MarketEvent, SocketError>>;
+
+    fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+        match input {
+            BitfinexMessage::Event(BitfinexEvent::Subscribed {
+                channel,
+                channel_id,
+                symbol,
+                key,
+            }) => {
+                // Associate the allocated channel identifier with the original Subscription
+                let subscription_id =
+                    Self::subscription_id(&channel, &key.or(symbol).unwrap_or_default());
+
+                match self.ids.get(&subscription_id) {
+                    Some(subscription) => {
+                        self.channels.insert(channel_id, subscription.clone());
+                        vec![]
+                    }
+                    None => vec![Err(SocketError::Unidentifiable(subscription_id))],
+                }
+            }
+            BitfinexMessage::Event(BitfinexEvent::Info) => vec![],
+            BitfinexMessage::Event(BitfinexEvent::Error { msg, code }) => {
+                vec![Err(SocketError::Exchange(format!("{code}: {msg}")))]
+            }
+            BitfinexMessage::Heartbeat(channel_id, _) => {
+                debug!(exchange_id = %Self::EXCHANGE, channel_id, "received heartbeat");
+                vec![]
+            }
+            BitfinexMessage::Trade(_, BitfinexTradeUpdate::Updated, _) => {
+                // Duplicate of a previously received BitfinexTradeUpdate::Executed
+                vec![]
+            }
+            BitfinexMessage::Trade(channel_id, BitfinexTradeUpdate::Executed, trade) => {
+                match self.find_subscription(channel_id) {
+                    Ok(subscription) => vec![Ok(MarketEvent::from((
+                        Self::EXCHANGE,
+                        subscription.instrument,
+                        trade,
+                    )))],
+                    Err(error) => vec![Err(error)],
+                }
+            }
+            BitfinexMessage::TradeSnapshot(channel_id, trades) => {
+                let subscription = match self.find_subscription(channel_id) {
+                    Ok(subscription) => subscription,
+                    Err(error) => return vec![Err(error)],
+                };
+
+                trades
+                    .into_iter()
+                    .map(|trade| {
+                        Ok(MarketEvent::from((
+                            Self::EXCHANGE,
+                            subscription.instrument.clone(),
+                            trade,
+                        )))
+                    })
+                    .collect()
+            }
+            BitfinexMessage::Candle(channel_id, candle) => {
+                match self.find_candle_subscription(channel_id) {
+                    Ok((subscription, interval)) => vec![Ok(MarketEvent::from((
+                        Self::EXCHANGE,
+                        subscription.instrument,
+                        interval,
+                        candle,
+                    )))],
+                    Err(error) => vec![Err(error)],
+                }
+            }
+            BitfinexMessage::CandleSnapshot(channel_id, candles) => {
+                let (subscription, interval) = match self.find_candle_subscription(channel_id) {
+                    Ok(subscription) => subscription,
+                    Err(error) => return vec![Err(error)],
+                };
+
+                candles
+                    .into_iter()
+                    .map(|candle| {
+                        Ok(MarketEvent::from((
+                            Self::EXCHANGE,
+                            subscription.instrument.clone(),
+                            interval,
+                            candle,
+                        )))
+                    })
+                    .collect()
+            }
+            BitfinexMessage::RawOrderSnapshot(channel_id, orders) => {
+                let subscription = match self.find_subscription(channel_id) {
+                    Ok(subscription) => subscription,
+                    Err(error) => return vec![Err(error)],
+                };
+
+                // Snapshot replaces any previously tracked resting orders
+                let resting = self.orders.entry(channel_id).or_default();
+                resting.clear();
+
+                orders
+                    .into_iter()
+                    .map(|order| {
+                        resting.insert(order.id, order.price);
+                        Ok(MarketEvent::from((
+                            Self::EXCHANGE,
+                            subscription.instrument.clone(),
+                            OrderBookL3DeltaKind::Open,
+                            order,
+                        )))
+                    })
+                    .collect()
+            }
+            BitfinexMessage::RawOrder(channel_id, mut order) => {
+                let subscription = match self.find_subscription(channel_id) {
+                    Ok(subscription) => subscription,
+                    Err(error) => return vec![Err(error)],
+                };
+
+                let resting = self.orders.entry(channel_id).or_default();
+                let kind = if order.is_removed() {
+                    // Removed orders have a zero price, so use the tracked resting price
+                    if let Some(price) = resting.remove(&order.id) {
+                        order.price = price;
+                    }
+                    OrderBookL3DeltaKind::Delete
+                } else if resting.insert(order.id, order.price).is_some() {
+                    OrderBookL3DeltaKind::Change
+                } else {
+                    OrderBookL3DeltaKind::Open
+                };
+
+                vec![Ok(MarketEvent::from((
+                    Self::EXCHANGE,
+                    subscription.instrument,
+                    kind,
+                    order,
+                )))]
+            }
+        }
+    }
+}
+
+impl Bitfinex {
+    /// [`Bitfinex`] trades channel name.
+    ///
+    /// See docs: <https://docs.bitfinex.com/reference/ws-public-trades>
+    pub const CHANNEL_TRADES: &'static str = "trades";
+
+    /// [`Bitfinex`] candles channel name.
+    ///
+    /// See docs: <https://docs.bitfinex.com/reference/ws-public-candles>
+    pub const CHANNEL_CANDLES: &'static str = "candles";
+
+    /// [`Bitfinex`] book channel name, subscribed to with raw (R0) precision to receive
+    /// individual orders.
+    ///
+    /// See docs: <https://docs.bitfinex.com/reference/ws-public-raw-books>
+    pub const CHANNEL_BOOK: &'static str = "book";
+
+    /// Determine the [`Bitfinex`] channel metadata associated with an input Barter
+    /// [`Subscription`]. This includes the [`Bitfinex`] &str channel, and a `String`
+    /// subscription target. For trades the target is the symbol, and for candles it is the
+    /// candle key. Both are used to build a [`Bitfinex`] subscription payload.
+    ///
+    /// Example Ok return: Ok("candles", "trade:1m:tBTCUSD")
+    /// where channel == "candles" & target == "trade:1m:tBTCUSD".
+    pub fn build_channel_meta(sub: &Subscription) -> Result<(&str, String), SocketError> {
+        // Validate provided Subscription InstrumentKind is supported by Bitfinex
+        let sub = sub.validate()?;
+
+        // Determine Bitfinex symbol using the Instrument (eg/ "tBTCUSD")
+        let BitfinexSymbol(symbol) = BitfinexSymbol::from(&sub.instrument);
+
+        // Determine Bitfinex channel & subscription target using the Subscription SubKind
+        match &sub.kind {
+            SubKind::Trade => Ok((Self::CHANNEL_TRADES, symbol)),
+            SubKind::Candle(interval) => Ok((
+                Self::CHANNEL_CANDLES,
+                format!("trade:{}:{symbol}", BitfinexInterval::try_from(interval)?.0),
+            )),
+            SubKind::OrderBookL3Delta => Ok((Self::CHANNEL_BOOK, symbol)),
+            other => Err(SocketError::Unsupported {
+                entity: Self::EXCHANGE.as_str(),
+                item: other.to_string(),
+            }),
+        }
+    }
+
+    /// Build a [`Bitfinex`] compatible subscription message using the channel & subscription
+    /// target provided.
+    ///
+    /// eg/ {"event":"subscribe","channel":"trades","symbol":"tBTCUSD"}
+    /// eg/ {"event":"subscribe","channel":"candles","key":"trade:1m:tBTCUSD"}
+    /// eg/ {"event":"subscribe","channel":"book","symbol":"tBTCUSD","prec":"R0","len":"250"}
+    pub fn subscription(channel: &str, target: &str) -> WsMessage {
+        let subscription = if channel == Self::CHANNEL_CANDLES {
+            json!({
+                "event": "subscribe",
+                "channel": channel,
+                "key": target,
+            })
+        } else if channel == Self::CHANNEL_BOOK {
+            json!({
+                "event": "subscribe",
+                "channel": channel,
+                "symbol": target,
+                "prec": "R0",
+                "len": "250",
+            })
+        } else {
+            json!({
+                "event": "subscribe",
+                "channel": channel,
+                "symbol": target,
+            })
+        };
+
+        WsMessage::Text(subscription.to_string())
+    }
+
+    /// Build a [`Bitfinex`] compatible [`SubscriptionId`] using the channel & subscription
+    /// target provided. This is used to associate [`Bitfinex`] subscription confirmations with
+    /// their original Barter [`Subscription`].
+    ///
+    /// eg/ SubscriptionId("trades|tBTCUSD")
+    pub fn subscription_id(channel: &str, target: &str) -> SubscriptionId {
+        SubscriptionId::from(format!("{channel}|{target}"))
+    }
+
+    /// Find the Barter [`Subscription`] associated with the provided [`Bitfinex`] channel
+    /// identifier.
+    pub fn find_subscription(&self, channel_id: u64) -> Result<Subscription, SocketError> {
+        self.channels.get(&channel_id).cloned().ok_or_else(|| {
+            SocketError::Unidentifiable(SubscriptionId::from(channel_id.to_string()))
+        })
+    }
+
+    /// Find the Barter candle [`Subscription`] & it's [`Interval`] associated with the
+    /// provided [`Bitfinex`] channel identifier.
+    pub fn find_candle_subscription(
+        &self,
+        channel_id: u64,
+    ) -> Result<(Subscription, Interval), SocketError> {
+        let subscription = self.find_subscription(channel_id)?;
+        match subscription.kind {
+            SubKind::Candle(interval) => Ok((subscription, interval)),
+            other => Err(SocketError::Unsupported {
+                entity: Self::EXCHANGE.as_str(),
+                item: format!("candle payload for {other} channel {channel_id}"),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        exchange::datetime_utc_from_epoch_duration,
+        model::{Candle, DataKind, OrderBookL3Delta, PublicTrade},
+    };
+    use barter_integration::model::{InstrumentKind, Side};
+    use std::time::Duration;
+
+    #[test]
+    fn test_build_channel_meta() {
+        struct TestCase {
+            input: Subscription,
+            expected: Result<(&'static str, String), SocketError>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: Supported InstrumentKind::Spot trades subscription
+                input: Subscription::new(
+                    ExchangeId::Bitfinex,
+                    ("btc", "usd", InstrumentKind::Spot),
+                    SubKind::Trade,
+                ),
+                expected: Ok(("trades", "tBTCUSD".to_owned())),
+            },
+            TestCase {
+                // TC1: Supported InstrumentKind::Spot candles subscription
+                input: Subscription::new(
+                    ExchangeId::Bitfinex,
+                    ("eth", "usd", InstrumentKind::Spot),
+                    SubKind::Candle(Interval::Day1),
+                ),
+                expected: Ok(("candles", "trade:1D:tETHUSD".to_owned())),
+            },
+            TestCase {
+                // TC2: Supported InstrumentKind::Spot raw book subscription
+                input: Subscription::new(
+                    ExchangeId::Bitfinex,
+                    ("btc", "usd", InstrumentKind::Spot),
+                    SubKind::OrderBookL3Delta,
+                ),
+                expected: Ok(("book", "tBTCUSD".to_owned())),
+            },
+            TestCase {
+                // TC3: Unsupported candle Interval
+                input: Subscription::new(
+                    ExchangeId::Bitfinex,
+                    ("btc", "usd", InstrumentKind::Spot),
+                    SubKind::Candle(Interval::Minute3),
+                ),
+                expected: Err(SocketError::Subscribe("unsupported".to_owned())),
+            },
+            TestCase {
+                // TC4: Unsupported InstrumentKind::FuturePerpetual subscription
+                input: Subscription::new(
+                    ExchangeId::Bitfinex,
+                    ("btc", "usd", InstrumentKind::FuturePerpetual),
+                    SubKind::Trade,
+                ),
+                expected: Err(SocketError::Subscribe("unsupported".to_owned())),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = Bitfinex::build_channel_meta(&test.input);
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => {
+                    assert_eq!(actual, expected, "TC{} failed", index)
+                }
+                (Err(_), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_bitfinex_transform() {
+        let mut transformer = Bitfinex::new(
+            mpsc::unbounded_channel().0,
+            Bitfinex::build_subscription_meta(&[
+                Subscription::new(
+                    ExchangeId::Bitfinex,
+                    ("btc", "usd", InstrumentKind::Spot),
+                    SubKind::Trade,
+                ),
+                Subscription::new(
+                    ExchangeId::Bitfinex,
+                    ("btc", "usd", InstrumentKind::Spot),
+                    SubKind::Candle(Interval::Minute1),
+                ),
+            ])
+            .unwrap()
+            .ids,
+        );
+
+        struct TestCase {
+            input: &'static str,
+            expected: Vec<Result<DataKind, SocketError>>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: trade payload before the channel identifier is known
+                input: r#"[17470,"te",[401597395,1574694478808,0.005,7245.3]]"#,
+                expected: vec![Err(SocketError::Unidentifiable(SubscriptionId::from(
+                    "17470",
+                )))],
+            },
+            TestCase {
+                // TC1: trades subscription confirmation is tracked
+                input: r#"{"event":"subscribed","channel":"trades","chanId":17470,"symbol":"tBTCUSD","pair":"BTCUSD"}"#,
+                expected: vec![],
+            },
+            TestCase {
+                // TC2: candles subscription confirmation is tracked
+                input: r#"{"event":"subscribed","channel":"candles","chanId":343351,"key":"trade:1m:tBTCUSD"}"#,
+                expected: vec![],
+            },
+            TestCase {
+                // TC3: trade snapshot routed via the tracked channel identifier
+                input: r#"[17470,[[401597395,1574694478808,0.005,7245.3],[401597394,1574694478000,-0.1,7245.1]]]"#,
+                expected: vec![
+                    Ok(DataKind::Trade(PublicTrade {
+                        id: "401597395".to_owned(),
+                        price: 7245.3,
+                        quantity: 0.005,
+                        side: Side::Buy,
+                    })),
+                    Ok(DataKind::Trade(PublicTrade {
+                        id: "401597394".to_owned(),
+                        price: 7245.1,
+                        quantity: 0.1,
+                        side: Side::Sell,
+                    })),
+                ],
+            },
+            TestCase {
+                // TC4: executed trade routed via the tracked channel identifier
+                input: r#"[17470,"te",[401597396,1574694478808,-0.5,7245.2]]"#,
+                expected: vec![Ok(DataKind::Trade(PublicTrade {
+                    id: "401597396".to_owned(),
+                    price: 7245.2,
+                    quantity: 0.5,
+                    side: Side::Sell,
+                }))],
+            },
+            TestCase {
+                // TC5: duplicate trade update is ignored
+                input: r#"[17470,"tu",[401597396,1574694478808,-0.5,7245.2]]"#,
+                expected: vec![],
+            },
+            TestCase {
+                // TC6: candle update routed via the tracked channel identifier
+                input: r#"[343351,[1574698260000,7379.7,7383.8,7388.3,7379.1,1.5]]"#,
+                expected: vec![Ok(DataKind::Candle(Candle {
+                    start_time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                        1574698260000,
+                    )),
+                    end_time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                        1574698320000,
+                    )),
+                    open: 7379.7,
+                    high: 7388.3,
+                    low: 7379.1,
+                    close: 7383.8,
+                    volume: 1.5,
+                    trade_count: 0,
+                }))],
+            },
+            TestCase {
+                // TC7: heartbeat is ignored
+                input: r#"[343351,"hb"]"#,
+                expected: vec![],
+            },
+            TestCase {
+                // TC8: error event
+                input: r#"{"event":"error","msg":"symbol: invalid","code":10300}"#,
+                expected: vec![Err(SocketError::Exchange(
+                    "10300: symbol: invalid".to_owned(),
+                ))],
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let input = serde_json::from_str::<BitfinexMessage>(test.input).unwrap();
+            let actual = transformer.transform(input);
+            assert_eq!(actual.len(), test.expected.len(), "TC{} failed", index);
+
+            for (actual, expected) in actual.into_iter().zip(test.expected) {
+                match (actual, expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual.kind, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_bitfinex_raw_book_transform() {
+        let mut transformer = Bitfinex::new(
+            mpsc::unbounded_channel().0,
+            Bitfinex::build_subscription_meta(&[Subscription::new(
+                ExchangeId::Bitfinex,
+                ("btc", "usd", InstrumentKind::Spot),
+                SubKind::OrderBookL3Delta,
+            )])
+            .unwrap()
+            .ids,
+        );
+
+        struct TestCase {
+            input: &'static str,
+            expected: Vec<Result<OrderBookL3Delta, SocketError>>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: raw book subscription confirmation is tracked
+                input: r#"{"event":"subscribed","channel":"book","chanId":260419,"symbol":"tBTCUSD","prec":"R0","freq":"F0","len":"250","pair":"BTCUSD"}"#,
+                expected: vec![],
+            },
+            TestCase {
+                // TC1: raw book snapshot orders are opened
+                input: r#"[260419,[[1,7247.3,0.5],[2,7248.1,-1.5]]]"#,
+                expected: vec![
+                    Ok(OrderBookL3Delta {
+                        id: "1".to_owned(),
+                        kind: OrderBookL3DeltaKind::Open,
+                        side: Side::Buy,
+                        price: 7247.3,
+                        quantity: 0.5,
+                    }),
+                    Ok(OrderBookL3Delta {
+                        id: "2".to_owned(),
+                        kind: OrderBookL3DeltaKind::Open,
+                        side: Side::Sell,
+                        price: 7248.1,
+                        quantity: 1.5,
+                    }),
+                ],
+            },
+            TestCase {
+                // TC2: raw book update to a resting order is a change
+                input: r#"[260419,[1,7247.3,0.25]]"#,
+                expected: vec![Ok(OrderBookL3Delta {
+                    id: "1".to_owned(),
+                    kind: OrderBookL3DeltaKind::Change,
+                    side: Side::Buy,
+                    price: 7247.3,
+                    quantity: 0.25,
+                })],
+            },
+            TestCase {
+                // TC3: raw book update for an unknown order is an open
+                input: r#"[260419,[3,7249.0,-2.0]]"#,
+                expected: vec![Ok(OrderBookL3Delta {
+                    id: "3".to_owned(),
+                    kind: OrderBookL3DeltaKind::Open,
+                    side: Side::Sell,
+                    price: 7249.0,
+                    quantity: 2.0,
+                })],
+            },
+            TestCase {
+                // TC4: raw book zero price update is a delete at the resting price
+                input: r#"[260419,[2,0,-1]]"#,
+                expected: vec![Ok(OrderBookL3Delta {
+                    id: "2".to_owned(),
+                    kind: OrderBookL3DeltaKind::Delete,
+                    side: Side::Sell,
+                    price: 7248.1,
+                    quantity: 0.0,
+                })],
+            },
+            TestCase {
+                // TC5: raw book update routed to an unknown channel identifier
+                input: r#"[1,[2,7248.1,-1.5]]"#,
+                expected: vec![Err(SocketError::Unidentifiable(SubscriptionId::from("1")))],
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let input = serde_json::from_str::<BitfinexMessage>(test.input).unwrap();
+            let actual = transformer.transform(input);
+            assert_eq!(actual.len(), test.expected.len(), "TC{} failed", index);
+
+            for (actual, expected) in actual.into_iter().zip(test.expected) {
+                match (actual, expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(
+                            actual.kind,
+                            DataKind::OrderBookL3Delta(expected),
+                            "TC{} failed",
+                            index
+                        )
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,488 @@
+use crate::{
+    exchange::de_u64_epoch_ms_as_datetime_utc,
+    model::{
+        subscription::Interval, Candle, DataKind, OrderBookL3Delta, OrderBookL3DeltaKind,
+        PublicTrade,
+    },
+    ExchangeId, MarketEvent,
+};
+use barter_integration::{
+    error::SocketError,
+    model::{Exchange, Instrument, Side},
+    Validator,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// [`Bitfinex`](super::Bitfinex) subscription confirmations are routed to the transformer in
+/// order to track the channel identifiers, so this is never deserialised. Every
+/// [`Bitfinex`](super::Bitfinex) subscription is considered successful until an error event
+/// is received.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct BitfinexSubResponse;
+
+impl Validator for BitfinexSubResponse {
+    fn validate(self) -> Result<Self, SocketError>
+    where
+        Self: Sized,
+    {
+        Ok(self)
+    }
+}
+
+/// [`Bitfinex`](super::Bitfinex) trading pair symbol (eg/ "tBTCUSD", "tTESTBTC:TESTUSD").
+///
+/// See docs: <https://docs.bitfinex.com/docs/ws-general#supported-pairs>
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct BitfinexSymbol(pub String);
+
+impl From<&Instrument> for BitfinexSymbol {
+    fn from(instrument: &Instrument) -> Self {
+        let (base, quote) = (instrument.base.as_ref(), instrument.quote.as_ref());
+
+        // Symbols w/ a base or quote longer than three characters are separated by a colon
+        let pair = if base.len() > 3 || quote.len() > 3 {
+            format!("{base}:{quote}")
+        } else {
+            format!("{base}{quote}")
+        };
+
+        Self(format!("t{}", pair.to_uppercase()))
+    }
+}
+
+/// [`Bitfinex`](super::Bitfinex) candle time frame, used in the candles subscription key
+/// (eg/ "trade:1m:tBTCUSD").
+///
+/// See docs: <https://docs.bitfinex.com/reference/ws-public-candles>
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct BitfinexInterval(pub &'static str);
+
+impl TryFrom<&Interval> for BitfinexInterval {
+    type Error = SocketError;
+
+    fn try_from(interval: &Interval) -> Result<Self, Self::Error> {
+        Ok(BitfinexInterval(match interval {
+            Interval::Minute1 => "1m",
+            Interval::Minute5 => "5m",
+            Interval::Minute15 => "15m",
+            Interval::Minute30 => "30m",
+            Interval::Hour1 => "1h",
+            Interval::Hour6 => "6h",
+            Interval::Hour12 => "12h",
+            Interval::Day1 => "1D",
+            Interval::Week1 => "1W",
+            Interval::Month1 => "1M",
+            other => {
+                return Err(SocketError::Unsupported {
+                    entity: ExchangeId::Bitfinex.as_str(),
+                    item: other.to_string(),
+                })
+            }
+        }))
+    }
+}
+
+/// [`Bitfinex`](super::Bitfinex) message variants that could be received over
+/// [`WebSocket`](crate::WebSocket).
+///
+/// Channel payloads are arrays prefixed with the numeric channel identifier allocated in the
+/// associated [`BitfinexEvent::Subscribed`] confirmation.
+///
+/// See docs: <https://docs.bitfinex.com/docs/ws-general>
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum BitfinexMessage {
+    Event(BitfinexEvent),
+    Heartbeat(u64, BitfinexHeartbeat),
+    Trade(u64, BitfinexTradeUpdate, BitfinexTrade),
+    TradeSnapshot(u64, Vec<BitfinexTrade>),
+    Candle(u64, BitfinexCandle),
+    CandleSnapshot(u64, Vec<BitfinexCandle>),
+    RawOrder(u64, BitfinexRawOrder),
+    RawOrderSnapshot(u64, Vec<BitfinexRawOrder>),
+}
+
+/// [`Bitfinex`](super::Bitfinex) event message.
+///
+/// eg/ {"event":"subscribed","channel":"trades","chanId":19111,"symbol":"tBTCUSD","pair":"BTCUSD"}
+/// eg/ {"event":"subscribed","channel":"candles","chanId":343351,"key":"trade:1m:tBTCUSD"}
+/// eg/ {"event":"subscribed","channel":"book","chanId":260419,"symbol":"tBTCUSD","prec":"R0",...}
+/// eg/ {"event":"error","msg":"symbol: invalid","code":10300}
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum BitfinexEvent {
+    Info,
+    Subscribed {
+        channel: String,
+        #[serde(rename = "chanId")]
+        channel_id: u64,
+        symbol: Option<String>,
+        key: Option<String>,
+    },
+    Error {
+        msg: String,
+        code: u64,
+    },
+}
+
+/// [`Bitfinex`](super::Bitfinex) channel heartbeat, sent every 15 seconds if there is no
+/// channel activity.
+///
+/// eg/ [19111,"hb"]
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub enum BitfinexHeartbeat {
+    #[serde(rename = "hb")]
+    Heartbeat,
+}
+
+/// [`Bitfinex`](super::Bitfinex) trade update type.
+///
+/// "te" updates are sent as soon as a trade executes, and are followed by a duplicate "tu"
+/// update once the trade has been assigned it's final identifier.
+///
+/// See docs: <https://docs.bitfinex.com/reference/ws-public-trades>
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub enum BitfinexTradeUpdate {
+    #[serde(rename = "te")]
+    Executed,
+    #[serde(rename = "tu")]
+    Updated,
+}
+
+/// [`Bitfinex`](super::Bitfinex) trade, received as an array.
+///
+/// eg/ [401597395,1574694478808,0.005,7245.3]
+/// where [ID, MTS, AMOUNT, PRICE] & a negative AMOUNT communicates a sell.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BitfinexTrade {
+    pub id: u64,
+    #[serde(deserialize_with = "de_u64_epoch_ms_as_datetime_utc")]
+    pub time: DateTime<Utc>,
+    pub amount: f64,
+    pub price: f64,
+}
+
+impl From<(ExchangeId, Instrument, BitfinexTrade)> for MarketEvent {
+    fn from((exchange_id, instrument, trade): (ExchangeId, Instrument, BitfinexTrade)) -> Self {
+        let side = if trade.amount < 0.0 {
+            Side::Sell
+        } else {
+            Side::Buy
+        };
+
+        Self {
+            exchange_time: trade.time,
+            received_time: Utc::now(),
+            received_instant: None,
+            raw: None,
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: DataKind::Trade(PublicTrade {
+                id: trade.id.to_string(),
+                price: trade.price,
+                quantity: trade.amount.abs(),
+                side,
+            }),
+        }
+    }
+}
+
+/// [`Bitfinex`](super::Bitfinex) candle, received as an array.
+///
+/// eg/ [1574698260000,7379.785503,7383.8,7388.3,7379.785503,1.68829482]
+/// where [MTS, OPEN, CLOSE, HIGH, LOW, VOLUME].
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BitfinexCandle {
+    #[serde(deserialize_with = "de_u64_epoch_ms_as_datetime_utc")]
+    pub start_time: DateTime<Utc>,
+    pub open: f64,
+    pub close: f64,
+    pub high: f64,
+    pub low: f64,
+    pub volume: f64,
+}
+
+impl From<(ExchangeId, Instrument, Interval, BitfinexCandle)> for MarketEvent {
+    fn from(
+        (exchange_id, instrument, interval, candle): (
+            ExchangeId,
+            Instrument,
+            Interval,
+            BitfinexCandle,
+        ),
+    ) -> Self {
+        let end_time = interval.end_time(candle.start_time);
+
+        Self {
+            exchange_time: end_time,
+            received_time: Utc::now(),
+            received_instant: None,
+            raw: None,
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: DataKind::Candle(Candle {
+                start_time: candle.start_time,
+                end_time,
+                open: candle.open,
+                high: candle.high,
+                low: candle.low,
+                close: candle.close,
+                volume: candle.volume,
+                trade_count: 0,
+            }),
+        }
+    }
+}
+
+/// [`Bitfinex`](super::Bitfinex) raw book (R0) individual order, received as an array.
+///
+/// eg/ [34006738527,7247.3,0.5]
+/// where [ORDER_ID, PRICE, AMOUNT] & a negative AMOUNT communicates an ask. A zero PRICE
+/// communicates the order should be removed from the book.
+///
+/// See docs: <https://docs.bitfinex.com/reference/ws-public-raw-books>
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BitfinexRawOrder {
+    pub id: u64,
+    pub price: f64,
+    pub amount: f64,
+}
+
+impl BitfinexRawOrder {
+    /// Determine if this [`BitfinexRawOrder`] communicates the order has been removed from the
+    /// book.
+    pub fn is_removed(&self) -> bool {
+        self.price == 0.0
+    }
+}
+
+impl
+    From<(
+        ExchangeId,
+        Instrument,
+        OrderBookL3DeltaKind,
+        BitfinexRawOrder,
+    )> for MarketEvent
+{
+    fn from(
+        (exchange_id, instrument, kind, order): (
+            ExchangeId,
+            Instrument,
+            OrderBookL3DeltaKind,
+            BitfinexRawOrder,
+        ),
+    ) -> Self {
+        let side = if order.amount < 0.0 {
+            Side::Sell
+        } else {
+            Side::Buy
+        };
+
+        // Removed orders communicate a unit AMOUNT, so the remaining quantity is zero
+        let quantity = match kind {
+            OrderBookL3DeltaKind::Delete => 0.0,
+            _ => order.amount.abs(),
+        };
+
+        // Raw book payloads do not contain a timestamp
+        let now = Utc::now();
+
+        Self {
+            exchange_time: now,
+            received_time: now,
+            received_instant: None,
+            raw: None,
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: DataKind::OrderBookL3Delta(OrderBookL3Delta {
+                id: order.id.to_string(),
+                kind,
+                side,
+                price: order.price,
+                quantity,
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchange::datetime_utc_from_epoch_duration;
+    use barter_integration::model::InstrumentKind;
+    use std::time::Duration;
+
+    #[test]
+    fn test_bitfinex_symbol() {
+        struct TestCase {
+            input: Instrument,
+            expected: BitfinexSymbol,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: three character base & quote
+                input: Instrument::from(("btc", "usd", InstrumentKind::Spot)),
+                expected: BitfinexSymbol("tBTCUSD".to_owned()),
+            },
+            TestCase {
+                // TC1: four character base is separated by a colon
+                input: Instrument::from(("test", "usd", InstrumentKind::Spot)),
+                expected: BitfinexSymbol("tTEST:USD".to_owned()),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = BitfinexSymbol::from(&test.input);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_deserialise_bitfinex_message() {
+        struct TestCase {
+            input: &'static str,
+            expected: Result<BitfinexMessage, SocketError>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: valid trades subscribed event
+                input: r#"{"event":"subscribed","channel":"trades","chanId":19111,"symbol":"tBTCUSD","pair":"BTCUSD"}"#,
+                expected: Ok(BitfinexMessage::Event(BitfinexEvent::Subscribed {
+                    channel: "trades".to_owned(),
+                    channel_id: 19111,
+                    symbol: Some("tBTCUSD".to_owned()),
+                    key: None,
+                })),
+            },
+            TestCase {
+                // TC1: valid info event
+                input: r#"{"event":"info","version":2,"serverId":"abc","platform":{"status":1}}"#,
+                expected: Ok(BitfinexMessage::Event(BitfinexEvent::Info)),
+            },
+            TestCase {
+                // TC2: valid heartbeat
+                input: r#"[19111,"hb"]"#,
+                expected: Ok(BitfinexMessage::Heartbeat(
+                    19111,
+                    BitfinexHeartbeat::Heartbeat,
+                )),
+            },
+            TestCase {
+                // TC3: valid executed trade update w/ sell amount
+                input: r#"[19111,"te",[401597395,1574694478808,-0.005,7245.3]]"#,
+                expected: Ok(BitfinexMessage::Trade(
+                    19111,
+                    BitfinexTradeUpdate::Executed,
+                    BitfinexTrade {
+                        id: 401597395,
+                        time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1574694478808,
+                        )),
+                        amount: -0.005,
+                        price: 7245.3,
+                    },
+                )),
+            },
+            TestCase {
+                // TC4: valid trade snapshot
+                input: r#"[19111,[[401597395,1574694478808,0.005,7245.3]]]"#,
+                expected: Ok(BitfinexMessage::TradeSnapshot(
+                    19111,
+                    vec![BitfinexTrade {
+                        id: 401597395,
+                        time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1574694478808,
+                        )),
+                        amount: 0.005,
+                        price: 7245.3,
+                    }],
+                )),
+            },
+            TestCase {
+                // TC5: valid candle update
+                input: r#"[343351,[1574698260000,7379.785503,7383.8,7388.3,7379.785503,1.68829482]]"#,
+                expected: Ok(BitfinexMessage::Candle(
+                    343351,
+                    BitfinexCandle {
+                        start_time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1574698260000,
+                        )),
+                        open: 7379.785503,
+                        close: 7383.8,
+                        high: 7388.3,
+                        low: 7379.785503,
+                        volume: 1.68829482,
+                    },
+                )),
+            },
+            TestCase {
+                // TC6: valid candle snapshot
+                input: r#"[343351,[[1574698260000,7379.785503,7383.8,7388.3,7379.785503,1.68829482]]]"#,
+                expected: Ok(BitfinexMessage::CandleSnapshot(
+                    343351,
+                    vec![BitfinexCandle {
+                        start_time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1574698260000,
+                        )),
+                        open: 7379.785503,
+                        close: 7383.8,
+                        high: 7388.3,
+                        low: 7379.785503,
+                        volume: 1.68829482,
+                    }],
+                )),
+            },
+            TestCase {
+                // TC7: valid raw book order update w/ ask amount
+                input: r#"[260419,[34006738527,7247.3,-0.5]]"#,
+                expected: Ok(BitfinexMessage::RawOrder(
+                    260419,
+                    BitfinexRawOrder {
+                        id: 34006738527,
+                        price: 7247.3,
+                        amount: -0.5,
+                    },
+                )),
+            },
+            TestCase {
+                // TC8: valid raw book snapshot
+                input: r#"[260419,[[34006738527,7247.3,0.5]]]"#,
+                expected: Ok(BitfinexMessage::RawOrderSnapshot(
+                    260419,
+                    vec![BitfinexRawOrder {
+                        id: 34006738527,
+                        price: 7247.3,
+                        amount: 0.5,
+                    }],
+                )),
+            },
+            TestCase {
+                // TC9: valid error event
+                input: r#"{"event":"error","msg":"symbol: invalid","code":10300}"#,
+                expected: Ok(BitfinexMessage::Event(BitfinexEvent::Error {
+                    msg: "symbol: invalid".to_owned(),
+                    code: 10300,
+                })),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = serde_json::from_str::<BitfinexMessage>(test.input);
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => {
+                    assert_eq!(actual, expected, "TC{} failed", index)
+                }
+                (Err(_), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+}
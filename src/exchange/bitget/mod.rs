@@ -0,0 +1,367 @@
+use crate::{
+    model::subscription::{SubKind, SubscriptionIds, SubscriptionMeta},
+    ExchangeId, ExchangeTransformer, MarketEvent, Subscriber, Subscription, Validator,
+};
+use barter_integration::{
+    error::SocketError, model::SubscriptionId, protocol::websocket::WsMessage, Transformer,
+};
+use model::{
+    BitgetGranularity, BitgetMessage, BitgetSubResponse, BitgetSubscription, BitgetSymbol,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::collections::HashMap;
+use tokio::sync::mpsc;
+
+/// [`Bitget`] specific data structures.
+pub mod model;
+
+/// [`Bitget`] [`Subscriber`] & [`ExchangeTransformer`] implementor for the collection
+/// of `Spot` & USDT margined `FuturePerpetual` data via the Bitget v2 public WebSocket API.
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct Bitget {
+    pub ids: SubscriptionIds,
+}
+
+impl Subscriber for Bitget {
+    type SubResponse = BitgetSubResponse;
+
+    fn base_url() -> &'static str {
+        ExchangeId::Bitget.ws_base_url()
+    }
+
+    fn build_subscription_meta(
+        subscriptions: &[Subscription],
+    ) -> Result<SubscriptionMeta, SocketError> {
+        // Allocate SubscriptionIds HashMap to track identifiers for each actioned Subscription
+        let mut ids = SubscriptionIds(HashMap::with_capacity(subscriptions.len()));
+
+        // Map Barter Subscriptions to BitgetSubscriptions
+        let subscriptions = subscriptions
+            .iter()
+            .map(|subscription| {
+                // Translate Barter Subscription to the associated BitgetSubscription
+                let bitget_subscription = Self::subscription(subscription)?;
+
+                // Use "instType|channel|instId" as the SubscriptionId key in the SubscriptionIds
+                // eg/ SubscriptionId("SPOT|trade|BTCUSDT")
+                ids.insert(
+                    SubscriptionId::from(&bitget_subscription),
+                    subscription.clone(),
+                );
+
+                // Construct Bitget specific subscription message
+                Ok(Self::subscription_message(&bitget_subscription))
+            })
+            .collect::<Result<Vec<_>, SocketError>>()?;
+
+        Ok(SubscriptionMeta {
+            ids,
+            expected_responses: subscriptions.len(),
+            subscriptions,
+        })
+    }
+}
+
+impl ExchangeTransformer for Bitget {
+    const EXCHANGE: ExchangeId = ExchangeId::Bitget;
+
+    fn new(_: mpsc::UnboundedSender<WsMessage>, ids: SubscriptionIds) -> Self {
+        Self { ids }
+    }
+}
+
+impl Transformer<MarketEvent> for Bitget {
+    type Input = BitgetMessage;
+    type OutputIter = Vec<Result<MarketEvent, SocketError>>;
+
+    fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+        match input {
+            BitgetMessage::Trades(trades) => {
+                // Determine Instrument associated with this BitgetTrades message
+                let subscription_id = SubscriptionId::from(&trades.subscription);
+                let instrument = match self.ids.find_instrument(&subscription_id) {
+                    Ok(instrument) => instrument,
+                    Err(error) => return vec![Err(error)],
+                };
+
+                // Map to MarketEvents
+                trades
+                    .data
+                    .into_iter()
+                    .map(|trade| {
+                        Ok(MarketEvent::from((
+                            Bitget::EXCHANGE,
+                            instrument.clone(),
+                            trade,
+                        )))
+                    })
+                    .collect()
+            }
+            BitgetMessage::Candles(candles) => {
+                // Determine Instrument & Interval associated with this BitgetCandles message
+                let subscription_id = SubscriptionId::from(&candles.subscription);
+                let (instrument, interval) = match self.ids.get(&subscription_id) {
+                    Some(Subscription {
+                        instrument,
+                        kind: SubKind::Candle(interval),
+                        ..
+                    }) => (instrument.clone(), *interval),
+                    _ => return vec![Err(SocketError::Unidentifiable(subscription_id))],
+                };
+
+                // Map to MarketEvents
+                candles
+                    .data
+                    .into_iter()
+                    .map(|candle| {
+                        Ok(MarketEvent::from((
+                            Bitget::EXCHANGE,
+                            instrument.clone(),
+                            interval,
+                            candle,
+                        )))
+                    })
+                    .collect()
+            }
+            BitgetMessage::Error(error) => vec![Err(SocketError::Exchange(format!(
+                "received error code: {} with message: {}",
+                error.code, error.msg
+            )))],
+        }
+    }
+}
+
+impl Bitget {
+    /// [`Bitget`] trades channel.
+    ///
+    /// See docs: <https://www.bitget.com/api-doc/spot/websocket/public/Trades-Channel>
+    pub const CHANNEL_TRADE: &'static str = "trade";
+
+    /// [`Bitget`] candlesticks channel prefix, suffixed with the [`BitgetGranularity`]
+    /// (eg/ "candle1m").
+    ///
+    /// See docs: <https://www.bitget.com/api-doc/spot/websocket/public/Candlesticks-Channel>
+    pub const CHANNEL_CANDLE: &'static str = "candle";
+
+    /// Translate a Barter [`Subscription`] into a [`BitgetSubscription`].
+    ///
+    /// eg/ BitgetSubscription { inst_type: "USDT-FUTURES", channel: "candle1m", inst_id: "BTCUSDT" }
+    pub fn subscription(sub: &Subscription) -> Result<BitgetSubscription, SocketError> {
+        // Validate provided Subscription InstrumentKind & SubKind are supported by Bitget
+        let sub = sub.validate()?;
+
+        // Determine Bitget channel using the Subscription SubKind
+        let channel = match &sub.kind {
+            SubKind::Trade => Self::CHANNEL_TRADE.to_owned(),
+            SubKind::Candle(interval) => format!(
+                "{}{}",
+                Self::CHANNEL_CANDLE,
+                BitgetGranularity::try_from(interval)?.0
+            ),
+            other => {
+                return Err(SocketError::Unsupported {
+                    entity: Self::EXCHANGE.as_str(),
+                    item: other.to_string(),
+                })
+            }
+        };
+
+        Ok(BitgetSubscription::new(
+            BitgetSymbol::try_from(&sub.instrument)?,
+            channel,
+        ))
+    }
+
+    /// Build a [`Bitget`] compatible subscription message using the [`BitgetSubscription`]
+    /// provided.
+    pub fn subscription_message(subscription: &BitgetSubscription) -> WsMessage {
+        WsMessage::Text(
+            json!({
+                "op": "subscribe",
+                "args": [subscription],
+            })
+            .to_string(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchange::bitget::model::{BitgetCandle, BitgetCandles, BitgetTrade, BitgetTrades};
+    use crate::model::{subscription::Interval, Candle, DataKind, PublicTrade};
+    use barter_integration::model::{InstrumentKind, Side};
+    use chrono::{Duration, Utc};
+
+    fn bitget_subscription(inst_type: &str, channel: &str, inst_id: &str) -> BitgetSubscription {
+        BitgetSubscription {
+            inst_type: inst_type.to_owned(),
+            channel: channel.to_owned(),
+            inst_id: inst_id.to_owned(),
+        }
+    }
+
+    #[test]
+    fn test_subscription() {
+        struct TestCase {
+            input: Subscription,
+            expected: Result<BitgetSubscription, SocketError>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: Supported InstrumentKind::Spot trades subscription
+                input: Subscription::new(
+                    ExchangeId::Bitget,
+                    ("btc", "usdt", InstrumentKind::Spot),
+                    SubKind::Trade,
+                ),
+                expected: Ok(bitget_subscription("SPOT", "trade", "BTCUSDT")),
+            },
+            TestCase {
+                // TC1: Supported InstrumentKind::FuturePerpetual candles subscription
+                input: Subscription::new(
+                    ExchangeId::Bitget,
+                    ("btc", "usdt", InstrumentKind::FuturePerpetual),
+                    SubKind::Candle(Interval::Minute1),
+                ),
+                expected: Ok(bitget_subscription("USDT-FUTURES", "candle1m", "BTCUSDT")),
+            },
+            TestCase {
+                // TC2: Unsupported Interval
+                input: Subscription::new(
+                    ExchangeId::Bitget,
+                    ("btc", "usdt", InstrumentKind::Spot),
+                    SubKind::Candle(Interval::Minute3),
+                ),
+                expected: Err(SocketError::Unsupported {
+                    entity: "bitget",
+                    item: Interval::Minute3.to_string(),
+                }),
+            },
+            TestCase {
+                // TC3: Unsupported SubKind
+                input: Subscription::new(
+                    ExchangeId::Bitget,
+                    ("btc", "usdt", InstrumentKind::Spot),
+                    SubKind::OrderBookL3Delta,
+                ),
+                expected: Err(SocketError::Unsupported {
+                    entity: "bitget",
+                    item: SubKind::OrderBookL3Delta.to_string(),
+                }),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = Bitget::subscription(&test.input);
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => {
+                    assert_eq!(actual, expected, "TC{} failed", index)
+                }
+                (Err(_), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_bitget_transform() {
+        let mut transformer = Bitget {
+            ids: SubscriptionIds(HashMap::from([
+                (
+                    SubscriptionId::from("SPOT|trade|BTCUSDT"),
+                    Subscription::new(
+                        ExchangeId::Bitget,
+                        ("btc", "usdt", InstrumentKind::Spot),
+                        SubKind::Trade,
+                    ),
+                ),
+                (
+                    SubscriptionId::from("USDT-FUTURES|candle1m|BTCUSDT"),
+                    Subscription::new(
+                        ExchangeId::Bitget,
+                        ("btc", "usdt", InstrumentKind::FuturePerpetual),
+                        SubKind::Candle(Interval::Minute1),
+                    ),
+                ),
+            ])),
+        };
+
+        let time = Utc::now();
+        let trades = |inst_type: &str| {
+            BitgetMessage::Trades(BitgetTrades {
+                subscription: bitget_subscription(inst_type, "trade", "BTCUSDT"),
+                data: vec![BitgetTrade {
+                    id: "1".to_owned(),
+                    price: 100.0,
+                    quantity: 1.0,
+                    side: Side::Sell,
+                    time,
+                }],
+            })
+        };
+
+        struct TestCase {
+            input: BitgetMessage,
+            expected: Vec<Option<DataKind>>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: BitgetMessage Trades w/ known SubscriptionId
+                input: trades("SPOT"),
+                expected: vec![Some(DataKind::Trade(PublicTrade {
+                    id: "1".to_owned(),
+                    price: 100.0,
+                    quantity: 1.0,
+                    side: Side::Sell,
+                }))],
+            },
+            TestCase {
+                // TC1: BitgetMessage Trades w/ same instId but unsubscribed instType
+                input: trades("USDT-FUTURES"),
+                expected: vec![None],
+            },
+            TestCase {
+                // TC2: BitgetMessage Candles w/ known SubscriptionId
+                input: BitgetMessage::Candles(BitgetCandles {
+                    subscription: bitget_subscription("USDT-FUTURES", "candle1m", "BTCUSDT"),
+                    data: vec![BitgetCandle {
+                        start_time: time,
+                        open: 1.0,
+                        high: 2.0,
+                        low: 0.5,
+                        close: 1.5,
+                        volume: 10.0,
+                    }],
+                }),
+                expected: vec![Some(DataKind::Candle(Candle {
+                    start_time: time,
+                    end_time: time + Duration::minutes(1),
+                    open: 1.0,
+                    high: 2.0,
+                    low: 0.5,
+                    close: 1.5,
+                    volume: 10.0,
+                    trade_count: 0,
+                }))],
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = transformer
+                .transform(test.input)
+                .into_iter()
+                .map(|event| event.ok().map(|event| event.kind))
+                .collect::<Vec<_>>();
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
@@ -0,0 +1,475 @@
+use crate::{
+    exchange::{
+        datetime_utc_from_epoch_duration, de_str, de_u64_epoch_ms_as_datetime_utc, extract_next,
+        Numeric,
+    },
+    model::{subscription::Interval, Candle, DataKind, PublicTrade},
+    ExchangeId, MarketEvent,
+};
+use barter_integration::{
+    error::SocketError,
+    model::{Exchange, Instrument, InstrumentKind, Side, SubscriptionId},
+    Validator,
+};
+use chrono::{DateTime, Utc};
+use serde::{de, Deserialize, Deserializer, Serialize};
+use std::time::Duration;
+
+/// [`Bitget`](super::Bitget) product type & instrument identifier formatted from a Barter
+/// [`Instrument`].
+///
+/// Spot & USDT margined perpetual futures share the same instId (eg/ "BTCUSDT"), so the
+/// instType is used to distinguish between them.
+///
+/// See docs: <https://www.bitget.com/api-doc/common/websocket-intro>
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct BitgetSymbol {
+    pub inst_type: &'static str,
+    pub inst_id: String,
+}
+
+impl BitgetSymbol {
+    /// [`Bitget`](super::Bitget) spot product type.
+    pub const INST_TYPE_SPOT: &'static str = "SPOT";
+
+    /// [`Bitget`](super::Bitget) USDT margined futures product type.
+    pub const INST_TYPE_USDT_FUTURES: &'static str = "USDT-FUTURES";
+}
+
+impl TryFrom<&Instrument> for BitgetSymbol {
+    type Error = SocketError;
+
+    fn try_from(instrument: &Instrument) -> Result<Self, Self::Error> {
+        let inst_type = match instrument.kind {
+            InstrumentKind::Spot => Self::INST_TYPE_SPOT,
+            InstrumentKind::FuturePerpetual if instrument.quote.as_ref() == "usdt" => {
+                Self::INST_TYPE_USDT_FUTURES
+            }
+            InstrumentKind::FuturePerpetual => {
+                return Err(SocketError::Unsupported {
+                    entity: ExchangeId::Bitget.as_str(),
+                    item: format!("{} {}", instrument.quote, instrument.kind),
+                })
+            }
+        };
+
+        Ok(Self {
+            inst_type,
+            inst_id: format!("{}{}", instrument.base, instrument.quote).to_uppercase(),
+        })
+    }
+}
+
+/// [`Bitget`](super::Bitget) product type, channel & instrument identifying a subscription, used
+/// both in subscription requests & as the "arg" of every message received.
+///
+/// eg/ {"instType":"SPOT","channel":"trade","instId":"BTCUSDT"}
+///
+/// See docs: <https://www.bitget.com/api-doc/common/websocket-intro>
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct BitgetSubscription {
+    #[serde(rename = "instType")]
+    pub inst_type: String,
+    pub channel: String,
+    #[serde(rename = "instId")]
+    pub inst_id: String,
+}
+
+impl BitgetSubscription {
+    /// Construct a new [`BitgetSubscription`] for the provided [`BitgetSymbol`] & channel.
+    pub fn new<S>(symbol: BitgetSymbol, channel: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            inst_type: symbol.inst_type.to_owned(),
+            channel: channel.into(),
+            inst_id: symbol.inst_id,
+        }
+    }
+}
+
+impl From<&BitgetSubscription> for SubscriptionId {
+    fn from(subscription: &BitgetSubscription) -> Self {
+        // eg/ SubscriptionId("USDT-FUTURES|candle1m|BTCUSDT")
+        SubscriptionId::from(format!(
+            "{}|{}|{}",
+            subscription.inst_type, subscription.channel, subscription.inst_id
+        ))
+    }
+}
+
+/// [`Bitget`](super::Bitget) message received in response to WebSocket subscription requests.
+///
+/// eg/ BitgetSubResponse::Subscribed {"event":"subscribe","arg":{"instType":"SPOT","channel":"trade","instId":"BTCUSDT"}}
+/// eg/ BitgetSubResponse::Error {"event":"error","code":30001,"msg":"instType:SPOT,channel:trade,instId:BTCUSD doesn't exist"}
+///
+/// See docs: <https://www.bitget.com/api-doc/common/websocket-intro>
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(tag = "event", rename_all = "lowercase")]
+pub enum BitgetSubResponse {
+    #[serde(rename = "subscribe")]
+    Subscribed {
+        arg: BitgetSubscription,
+    },
+    Error {
+        code: i64,
+        msg: String,
+    },
+}
+
+impl Validator for BitgetSubResponse {
+    fn validate(self) -> Result<Self, SocketError>
+    where
+        Self: Sized,
+    {
+        match &self {
+            BitgetSubResponse::Subscribed { .. } => Ok(self),
+            BitgetSubResponse::Error { code, msg } => Err(SocketError::Subscribe(format!(
+                "received failure subscription response code: {code} with message: {msg}",
+            ))),
+        }
+    }
+}
+
+/// [`Bitget`](super::Bitget) granularity used in candle channel names (eg/ "candle1m").
+///
+/// See docs: <https://www.bitget.com/api-doc/spot/websocket/public/Candlesticks-Channel>
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct BitgetGranularity(pub &'static str);
+
+impl TryFrom<&Interval> for BitgetGranularity {
+    type Error = SocketError;
+
+    fn try_from(interval: &Interval) -> Result<Self, Self::Error> {
+        let granularity = match interval {
+            Interval::Minute1 => "1m",
+            Interval::Minute5 => "5m",
+            Interval::Minute15 => "15m",
+            Interval::Minute30 => "30m",
+            Interval::Hour1 => "1H",
+            Interval::Hour4 => "4H",
+            Interval::Hour6 => "6H",
+            Interval::Hour12 => "12H",
+            Interval::Day1 => "1D",
+            Interval::Week1 => "1W",
+            Interval::Month1 => "1M",
+            other => {
+                return Err(SocketError::Unsupported {
+                    entity: ExchangeId::Bitget.as_str(),
+                    item: other.to_string(),
+                })
+            }
+        };
+
+        Ok(Self(granularity))
+    }
+}
+
+/// [`Bitget`](super::Bitget) message variants that can be received over [`WebSocket`].
+///
+/// See docs: <https://www.bitget.com/api-doc/common/websocket-intro>
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum BitgetMessage {
+    Trades(BitgetTrades),
+    Candles(BitgetCandles),
+    Error(BitgetError),
+}
+
+/// [`Bitget`](super::Bitget) trade channel message containing one or more [`BitgetTrade`]s.
+///
+/// eg/ {"action":"update","arg":{"instType":"SPOT","channel":"trade","instId":"BTCUSDT"},"data":[{...}],"ts":1695709835823}
+///
+/// See docs: <https://www.bitget.com/api-doc/spot/websocket/public/Trades-Channel>
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct BitgetTrades {
+    #[serde(rename = "arg")]
+    pub subscription: BitgetSubscription,
+    pub data: Vec<BitgetTrade>,
+}
+
+/// [`Bitget`](super::Bitget) trade.
+///
+/// eg/ {"ts":"1695709835822","price":"26293.4","size":"0.0013","side":"buy","tradeId":"1000000000"}
+///
+/// See docs: <https://www.bitget.com/api-doc/spot/websocket/public/Trades-Channel>
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BitgetTrade {
+    #[serde(rename = "tradeId")]
+    pub id: String,
+    #[serde(deserialize_with = "de_str")]
+    pub price: f64,
+    #[serde(rename = "size", deserialize_with = "de_str")]
+    pub quantity: f64,
+    pub side: Side,
+    #[serde(rename = "ts", deserialize_with = "de_u64_epoch_ms_as_datetime_utc")]
+    pub time: DateTime<Utc>,
+}
+
+/// [`Bitget`](super::Bitget) candle channel message containing one or more [`BitgetCandle`]s.
+///
+/// eg/ {"action":"update","arg":{"instType":"SPOT","channel":"candle1m","instId":"BTCUSDT"},"data":[[...]],"ts":1695685500000}
+///
+/// See docs: <https://www.bitget.com/api-doc/spot/websocket/public/Candlesticks-Channel>
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct BitgetCandles {
+    #[serde(rename = "arg")]
+    pub subscription: BitgetSubscription,
+    pub data: Vec<BitgetCandle>,
+}
+
+/// [`Bitget`](super::Bitget) OHLCV candle, sent as an array of `String`s. The end time is not
+/// sent, so it is determined from the subscribed [`Interval`] when transformed.
+///
+/// eg/ ["1695685500000","27000","27000.5","27000","27000.5","0.057","1539.0155","1539.0155"]
+///
+/// See docs: <https://www.bitget.com/api-doc/spot/websocket/public/Candlesticks-Channel>
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Serialize)]
+pub struct BitgetCandle {
+    pub start_time: DateTime<Utc>,
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+    pub volume: f64,
+}
+
+/// [`Bitget`](super::Bitget) error message received after subscriptions have been validated.
+///
+/// eg/ {"event":"error","code":30016,"msg":"Param error"}
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct BitgetError {
+    pub event: String,
+    pub code: i64,
+    pub msg: String,
+}
+
+impl<'de> Deserialize<'de> for BitgetCandle {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct SeqVisitor;
+
+        impl<'de> de::Visitor<'de> for SeqVisitor {
+            type Value = BitgetCandle;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("BitgetCandle struct from the Bitget WebSocket API")
+            }
+
+            fn visit_seq<SeqAccessor>(
+                self,
+                mut seq: SeqAccessor,
+            ) -> Result<Self::Value, SeqAccessor::Error>
+            where
+                SeqAccessor: de::SeqAccess<'de>,
+            {
+                // BitgetCandle Sequence Format:
+                // [ts, o, h, l, c, baseVol, quoteVol, usdtVol]
+                // <https://www.bitget.com/api-doc/spot/websocket/public/Candlesticks-Channel>
+
+                // Extract numeric start time epoch milliseconds & map to DateTime<Utc>
+                let start_time = extract_next::<SeqAccessor, Numeric<u64>>(&mut seq, "ts").map(
+                    |Numeric(ts)| datetime_utc_from_epoch_duration(Duration::from_millis(ts)),
+                )?;
+
+                let open = extract_next::<SeqAccessor, Numeric<f64>>(&mut seq, "o")?.0;
+                let high = extract_next::<SeqAccessor, Numeric<f64>>(&mut seq, "h")?.0;
+                let low = extract_next::<SeqAccessor, Numeric<f64>>(&mut seq, "l")?.0;
+                let close = extract_next::<SeqAccessor, Numeric<f64>>(&mut seq, "c")?.0;
+                let volume = extract_next::<SeqAccessor, Numeric<f64>>(&mut seq, "baseVol")?.0;
+
+                // Ignore any additional elements or SerDe will fail
+                //  '--> Exchange may add fields without warning
+                while seq.next_element::<de::IgnoredAny>()?.is_some() {}
+
+                Ok(BitgetCandle {
+                    start_time,
+                    open,
+                    high,
+                    low,
+                    close,
+                    volume,
+                })
+            }
+        }
+
+        // Use Visitor implementation to deserialise the BitgetCandle
+        deserializer.deserialize_seq(SeqVisitor)
+    }
+}
+
+impl From<(ExchangeId, Instrument, BitgetTrade)> for MarketEvent {
+    fn from((exchange_id, instrument, trade): (ExchangeId, Instrument, BitgetTrade)) -> Self {
+        Self {
+            exchange_time: trade.time,
+            received_time: Utc::now(),
+            received_instant: None,
+            raw: None,
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: DataKind::Trade(PublicTrade {
+                id: trade.id,
+                price: trade.price,
+                quantity: trade.quantity,
+                side: trade.side,
+            }),
+        }
+    }
+}
+
+impl From<(ExchangeId, Instrument, Interval, BitgetCandle)> for MarketEvent {
+    fn from(
+        (exchange_id, instrument, interval, candle): (
+            ExchangeId,
+            Instrument,
+            Interval,
+            BitgetCandle,
+        ),
+    ) -> Self {
+        let end_time = interval.end_time(candle.start_time);
+
+        Self {
+            exchange_time: end_time,
+            received_time: Utc::now(),
+            received_instant: None,
+            raw: None,
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: DataKind::Candle(Candle {
+                start_time: candle.start_time,
+                end_time,
+                open: candle.open,
+                high: candle.high,
+                low: candle.low,
+                close: candle.close,
+                volume: candle.volume,
+                trade_count: 0,
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bitget_symbol() {
+        struct TestCase {
+            input: Instrument,
+            expected: Result<BitgetSymbol, SocketError>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: Spot instrument
+                input: Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
+                expected: Ok(BitgetSymbol {
+                    inst_type: "SPOT",
+                    inst_id: "BTCUSDT".to_owned(),
+                }),
+            },
+            TestCase {
+                // TC1: USDT margined FuturePerpetual instrument
+                input: Instrument::from(("eth", "usdt", InstrumentKind::FuturePerpetual)),
+                expected: Ok(BitgetSymbol {
+                    inst_type: "USDT-FUTURES",
+                    inst_id: "ETHUSDT".to_owned(),
+                }),
+            },
+            TestCase {
+                // TC2: Unsupported coin margined FuturePerpetual instrument
+                input: Instrument::from(("btc", "usd", InstrumentKind::FuturePerpetual)),
+                expected: Err(SocketError::Unsupported {
+                    entity: "bitget",
+                    item: "usd future_perpetual".to_owned(),
+                }),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = BitgetSymbol::try_from(&test.input);
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => {
+                    assert_eq!(actual, expected, "TC{} failed", index)
+                }
+                (Err(_), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_deserialise_bitget_message() {
+        struct TestCase {
+            input: &'static str,
+            expected: Option<BitgetMessage>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: input is a valid trades message
+                input: r#"{"action":"update","arg":{"instType":"SPOT","channel":"trade","instId":"BTCUSDT"},"data":[{"ts":"1695709835822","price":"26293.4","size":"0.0013","side":"buy","tradeId":"1000000000"}],"ts":1695709835823}"#,
+                expected: Some(BitgetMessage::Trades(BitgetTrades {
+                    subscription: BitgetSubscription {
+                        inst_type: "SPOT".to_owned(),
+                        channel: "trade".to_owned(),
+                        inst_id: "BTCUSDT".to_owned(),
+                    },
+                    data: vec![BitgetTrade {
+                        id: "1000000000".to_owned(),
+                        price: 26293.4,
+                        quantity: 0.0013,
+                        side: Side::Buy,
+                        time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1695709835822,
+                        )),
+                    }],
+                })),
+            },
+            TestCase {
+                // TC1: input is a valid candles message
+                input: r#"{"action":"update","arg":{"instType":"USDT-FUTURES","channel":"candle1m","instId":"BTCUSDT"},"data":[["1695685500000","27000","27000.5","26999","27000.5","0.057","1539.0155","1539.0155"]],"ts":1695715462250}"#,
+                expected: Some(BitgetMessage::Candles(BitgetCandles {
+                    subscription: BitgetSubscription {
+                        inst_type: "USDT-FUTURES".to_owned(),
+                        channel: "candle1m".to_owned(),
+                        inst_id: "BTCUSDT".to_owned(),
+                    },
+                    data: vec![BitgetCandle {
+                        start_time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1695685500000,
+                        )),
+                        open: 27000.0,
+                        high: 27000.5,
+                        low: 26999.0,
+                        close: 27000.5,
+                        volume: 0.057,
+                    }],
+                })),
+            },
+            TestCase {
+                // TC2: input is an error
+                input: r#"{"event":"error","code":30016,"msg":"Param error"}"#,
+                expected: Some(BitgetMessage::Error(BitgetError {
+                    event: "error".to_owned(),
+                    code: 30016,
+                    msg: "Param error".to_owned(),
+                })),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = serde_json::from_str::<BitgetMessage>(test.input).ok();
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
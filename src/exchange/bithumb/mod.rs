@@ -0,0 +1,358 @@
+use crate::{
+    model::subscription::{SubKind, SubscriptionIds, SubscriptionMeta},
+    ExchangeId, ExchangeTransformer, MarketEvent, Subscriber, Subscription, Validator,
+};
+use barter_integration::{
+    error::SocketError, model::SubscriptionId, protocol::websocket::WsMessage, Transformer,
+};
+use model::{BithumbData, BithumbMessage, BithumbStatus, BithumbSymbol};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::collections::HashMap;
+use tokio::sync::mpsc;
+
+/// [`Bithumb`] specific data structures.
+pub mod model;
+
+/// [`Bithumb`] [`Subscriber`] & [`ExchangeTransformer`] implementor for the collection
+/// of `Spot` KRW market data.
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct Bithumb {
+    pub ids: SubscriptionIds,
+}
+
+impl Subscriber for Bithumb {
+    type SubResponse = BithumbStatus;
+
+    fn base_url() -> &'static str {
+        ExchangeId::Bithumb.ws_base_url()
+    }
+
+    fn build_subscription_meta(
+        subscriptions: &[Subscription],
+    ) -> Result<SubscriptionMeta, SocketError> {
+        // Allocate SubscriptionIds HashMap to track identifiers for each actioned Subscription
+        let mut ids = SubscriptionIds(HashMap::with_capacity(subscriptions.len()));
+
+        // Group Bithumb symbols by channel type, preserving the Subscription order
+        let mut channels: Vec<(&str, Vec<String>)> = Vec::new();
+        for subscription in subscriptions {
+            // Determine the Bithumb specific channel type & symbol for this Subscription
+            let (channel, symbol) = Self::build_channel_meta(subscription)?;
+
+            // Use "channel|symbol" as the SubscriptionId key in the SubscriptionIds
+            // eg/ SubscriptionId("transaction|BTC_KRW")
+            ids.insert(
+                Bithumb::subscription_id(channel, &symbol),
+                subscription.clone(),
+            );
+
+            match channels
+                .iter_mut()
+                .find(|(existing, _)| *existing == channel)
+            {
+                Some((_, symbols)) => symbols.push(symbol),
+                None => channels.push((channel, vec![symbol])),
+            }
+        }
+
+        let subscriptions = channels
+            .into_iter()
+            .map(|(channel, symbols)| Self::subscription(channel, &symbols))
+            .collect::<Vec<WsMessage>>();
+
+        Ok(SubscriptionMeta {
+            ids,
+            // Bithumb also sends a status message upon connecting, which is validated alongside
+            // the status message received in response to each subscription filter
+            expected_responses: subscriptions.len() + 1,
+            subscriptions,
+        })
+    }
+}
+
+impl ExchangeTransformer for Bithumb {
+    const EXCHANGE: ExchangeId = ExchangeId::Bithumb;
+    fn new(_: mpsc::UnboundedSender<WsMessage>, ids: SubscriptionIds) -> Self {
+        Self { ids }
+    }
+}
+
+impl Transformer<MarketEvent> for Bithumb {
+    type Input = BithumbMessage;
+    type OutputIter = Vec<Result<MarketEvent, SocketError>>;
+
+    fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+        match input {
+            BithumbMessage::Data(BithumbData::Transaction { content }) => content
+                .list
+                .into_iter()
+                .map(|transaction| {
+                    self.ids
+                        .find_instrument(&SubscriptionId::from(&transaction))
+                        .map(|instrument| {
+                            MarketEvent::from((Self::EXCHANGE, instrument, transaction))
+                        })
+                })
+                .collect(),
+            BithumbMessage::Data(BithumbData::Ticker { content }) => {
+                match self.ids.find_instrument(&SubscriptionId::from(&content)) {
+                    Ok(instrument) => {
+                        vec![Ok(MarketEvent::from((Self::EXCHANGE, instrument, content)))]
+                    }
+                    Err(error) => vec![Err(error)],
+                }
+            }
+            BithumbMessage::Status(status) if status.status == BithumbStatus::STATUS_SUCCESS => {
+                vec![]
+            }
+            BithumbMessage::Status(status) => vec![Err(SocketError::Exchange(format!(
+                "{} {}",
+                status.status, status.resmsg
+            )))],
+        }
+    }
+}
+
+impl Bithumb {
+    /// [`Bithumb`] transactions (trades) channel type.
+    ///
+    /// See docs: <https://apidocs.bithumb.com>
+    pub const CHANNEL_TRANSACTIONS: &'static str = "transaction";
+
+    /// [`Bithumb`] ticker channel type.
+    ///
+    /// See docs: <https://apidocs.bithumb.com>
+    pub const CHANNEL_TICKER: &'static str = "ticker";
+
+    /// [`Bithumb`] ticker aggregation period, used to receive rolling 24 hour statistics.
+    pub const TICK_TYPE_24H: &'static str = "24H";
+
+    /// Determine the [`Bithumb`] channel metadata associated with an input Barter
+    /// [`Subscription`]. This includes the [`Bithumb`] &str channel type, and a `String`
+    /// symbol. Both are used to build a [`Bithumb`] subscription payload.
+    ///
+    /// Example Ok return: Ok("transaction", "BTC_KRW")
+    /// where channel == "transaction" & symbol == "BTC_KRW".
+    pub fn build_channel_meta(sub: &Subscription) -> Result<(&str, String), SocketError> {
+        // Validate provided Subscription InstrumentKind is supported by Bithumb
+        let sub = sub.validate()?;
+
+        // Determine Bithumb channel type using the Subscription SubKind
+        let channel = match &sub.kind {
+            SubKind::Trade => Self::CHANNEL_TRANSACTIONS,
+            SubKind::Ticker => Self::CHANNEL_TICKER,
+            other => {
+                return Err(SocketError::Unsupported {
+                    entity: Self::EXCHANGE.as_str(),
+                    item: other.to_string(),
+                })
+            }
+        };
+
+        // Determine Bithumb symbol using the Instrument (eg/ "BTC_KRW")
+        let BithumbSymbol(symbol) = BithumbSymbol::from(&sub.instrument);
+
+        Ok((channel, symbol))
+    }
+
+    /// Build a [`Bithumb`] compatible subscription message using the channel type & symbols
+    /// provided.
+    ///
+    /// eg/ {"type":"ticker","symbols":["BTC_KRW"],"tickTypes":["24H"]}
+    pub fn subscription(channel: &str, symbols: &[String]) -> WsMessage {
+        let subscription = if channel == Self::CHANNEL_TICKER {
+            json!({
+                "type": channel,
+                "symbols": symbols,
+                "tickTypes": [Self::TICK_TYPE_24H],
+            })
+        } else {
+            json!({
+                "type": channel,
+                "symbols": symbols,
+            })
+        };
+
+        WsMessage::Text(subscription.to_string())
+    }
+
+    /// Build a [`Bithumb`] compatible [`SubscriptionId`] using the channel type & symbol
+    /// provided. This is used to associate [`Bithumb`] data structures received over the
+    /// WebSocket with it's original Barter [`Subscription`].
+    ///
+    /// eg/ SubscriptionId("transaction|BTC_KRW")
+    pub fn subscription_id(channel: &str, symbol: &str) -> SubscriptionId {
+        SubscriptionId::from(format!("{channel}|{symbol}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{DataKind, PublicTrade, Ticker};
+    use barter_integration::model::{InstrumentKind, Side};
+
+    #[test]
+    fn test_build_channel_meta() {
+        struct TestCase {
+            input: Subscription,
+            expected: Result<(&'static str, String), SocketError>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: Supported InstrumentKind::Spot trades subscription
+                input: Subscription::new(
+                    ExchangeId::Bithumb,
+                    ("btc", "krw", InstrumentKind::Spot),
+                    SubKind::Trade,
+                ),
+                expected: Ok(("transaction", "BTC_KRW".to_owned())),
+            },
+            TestCase {
+                // TC1: Supported InstrumentKind::Spot ticker subscription
+                input: Subscription::new(
+                    ExchangeId::Bithumb,
+                    ("eth", "krw", InstrumentKind::Spot),
+                    SubKind::Ticker,
+                ),
+                expected: Ok(("ticker", "ETH_KRW".to_owned())),
+            },
+            TestCase {
+                // TC2: Unsupported InstrumentKind::FuturePerpetual subscription
+                input: Subscription::new(
+                    ExchangeId::Bithumb,
+                    ("btc", "krw", InstrumentKind::FuturePerpetual),
+                    SubKind::Trade,
+                ),
+                expected: Err(SocketError::Subscribe("unsupported".to_owned())),
+            },
+            TestCase {
+                // TC3: Unsupported SubKind::OrderBook subscription
+                input: Subscription::new(
+                    ExchangeId::Bithumb,
+                    ("btc", "krw", InstrumentKind::Spot),
+                    SubKind::OrderBook,
+                ),
+                expected: Err(SocketError::Subscribe("unsupported".to_owned())),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = Bithumb::build_channel_meta(&test.input);
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => {
+                    assert_eq!(actual, expected, "TC{} failed", index)
+                }
+                (Err(_), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_bithumb_transform() {
+        let mut transformer = Bithumb::new(
+            mpsc::unbounded_channel().0,
+            Bithumb::build_subscription_meta(&[
+                Subscription::new(
+                    ExchangeId::Bithumb,
+                    ("btc", "krw", InstrumentKind::Spot),
+                    SubKind::Trade,
+                ),
+                Subscription::new(
+                    ExchangeId::Bithumb,
+                    ("btc", "krw", InstrumentKind::Spot),
+                    SubKind::Ticker,
+                ),
+            ])
+            .unwrap()
+            .ids,
+        );
+
+        struct TestCase {
+            input: BithumbMessage,
+            expected: Vec<Result<DataKind, SocketError>>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: BithumbMessage::Transaction w/ subscribed & unsubscribed symbols
+                input: serde_json::from_str(
+                    r#"{"type":"transaction","content":{"list":[
+                        {"symbol":"BTC_KRW","buySellGb":"2","contPrice":"10579000","contQty":"0.01","contDtm":"2020-01-29 12:24:18.830039"},
+                        {"symbol":"ETH_KRW","buySellGb":"1","contPrice":"210000","contQty":"1.0","contDtm":"2020-01-29 12:24:18.830039"}
+                    ]}}"#,
+                )
+                .unwrap(),
+                expected: vec![
+                    Ok(DataKind::Trade(PublicTrade {
+                        id: "1580268258830039_Buy_10579000_0.01".to_owned(),
+                        price: 10579000.0,
+                        quantity: 0.01,
+                        side: Side::Buy,
+                    })),
+                    Err(SocketError::Unidentifiable(SubscriptionId::from(
+                        "transaction|ETH_KRW",
+                    ))),
+                ],
+            },
+            TestCase {
+                // TC1: BithumbMessage::Ticker for a subscribed symbol
+                input: serde_json::from_str(
+                    r#"{"type":"ticker","content":{"symbol":"BTC_KRW","tickType":"24H","date":"20200129","time":"121844","openPrice":"2302","closePrice":"2317","lowPrice":"2272","highPrice":"2344","volume":"1222314.51355788","chgRate":"0.65"}}"#,
+                )
+                .unwrap(),
+                expected: vec![Ok(DataKind::Ticker(Ticker {
+                    last_price: 2317.0,
+                    volume_24h: 1222314.51355788,
+                    high_24h: 2344.0,
+                    low_24h: 2272.0,
+                    change_percent_24h: 0.65,
+                }))],
+            },
+            TestCase {
+                // TC2: BithumbMessage::Status success is ignored
+                input: serde_json::from_str(
+                    r#"{"status":"0000","resmsg":"Filter Registered Successfully"}"#,
+                )
+                .unwrap(),
+                expected: vec![],
+            },
+            TestCase {
+                // TC3: BithumbMessage::Status failure
+                input: serde_json::from_str(r#"{"status":"5100","resmsg":"Invalid Filter Syntax"}"#)
+                    .unwrap(),
+                expected: vec![Err(SocketError::Exchange(
+                    "5100 Invalid Filter Syntax".to_owned(),
+                ))],
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = transformer.transform(test.input);
+            assert_eq!(actual.len(), test.expected.len(), "TC{} failed", index);
+
+            for (actual, expected) in actual.into_iter().zip(test.expected) {
+                match (actual, expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual.kind, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,403 @@
+use crate::{
+    exchange::de_str,
+    model::{DataKind, PublicTrade, Ticker},
+    ExchangeId, MarketEvent,
+};
+use barter_integration::{
+    error::SocketError,
+    model::{Exchange, Instrument, Side, SubscriptionId},
+    Validator,
+};
+use chrono::{DateTime, Duration, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+
+/// [`Bithumb`](super::Bithumb) status message received upon connecting and in response to
+/// every subscription filter request.
+///
+/// eg/ {"status":"0000","resmsg":"Filter Registered Successfully"}
+///
+/// See docs: <https://apidocs.bithumb.com>
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct BithumbStatus {
+    pub status: String,
+    pub resmsg: String,
+}
+
+impl BithumbStatus {
+    /// [`Bithumb`](super::Bithumb) status code communicating success.
+    pub const STATUS_SUCCESS: &'static str = "0000";
+}
+
+impl Validator for BithumbStatus {
+    fn validate(self) -> Result<Self, SocketError>
+    where
+        Self: Sized,
+    {
+        if self.status == Self::STATUS_SUCCESS {
+            Ok(self)
+        } else {
+            Err(SocketError::Subscribe(format!(
+                "received failure subscription response: {} {}",
+                self.status, self.resmsg
+            )))
+        }
+    }
+}
+
+/// [`Bithumb`](super::Bithumb) symbol, base first (eg/ "BTC_KRW").
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct BithumbSymbol(pub String);
+
+impl From<&Instrument> for BithumbSymbol {
+    fn from(instrument: &Instrument) -> Self {
+        Self(format!("{}_{}", instrument.base, instrument.quote).to_uppercase())
+    }
+}
+
+/// [`Bithumb`](super::Bithumb) message variants that could be received over
+/// [`WebSocket`](crate::WebSocket).
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum BithumbMessage {
+    Data(BithumbData),
+    Status(BithumbStatus),
+}
+
+/// [`Bithumb`](super::Bithumb) market data payloads, tagged by their stream type.
+///
+/// See docs: <https://apidocs.bithumb.com>
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum BithumbData {
+    Transaction { content: BithumbTransactions },
+    Ticker { content: BithumbTicker },
+}
+
+/// [`Bithumb`](super::Bithumb) batch of real-time [`BithumbTransaction`]s.
+///
+/// eg/ {"list":[{"symbol":"BTC_KRW","buySellGb":"1","contPrice":"10579000","contQty":"0.01",
+///      "contAmt":"105790.00","contDtm":"2020-01-29 12:24:18.830039","updn":"dn"}]}
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BithumbTransactions {
+    pub list: Vec<BithumbTransaction>,
+}
+
+/// [`Bithumb`](super::Bithumb) real-time transaction (trade).
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BithumbTransaction {
+    pub symbol: String,
+    #[serde(rename = "buySellGb", deserialize_with = "de_side_from_buy_sell_gb")]
+    pub side: Side,
+    #[serde(rename = "contPrice", deserialize_with = "de_str")]
+    pub price: f64,
+    #[serde(rename = "contQty", deserialize_with = "de_str")]
+    pub quantity: f64,
+    #[serde(
+        rename = "contDtm",
+        deserialize_with = "de_kst_datetime_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+}
+
+impl From<&BithumbTransaction> for SubscriptionId {
+    fn from(transaction: &BithumbTransaction) -> Self {
+        super::Bithumb::subscription_id(super::Bithumb::CHANNEL_TRANSACTIONS, &transaction.symbol)
+    }
+}
+
+impl From<(ExchangeId, Instrument, BithumbTransaction)> for MarketEvent {
+    fn from(
+        (exchange_id, instrument, transaction): (ExchangeId, Instrument, BithumbTransaction),
+    ) -> Self {
+        // bithumb transactions do not come with a unique identifier, so generate a custom one
+        let custom_trade_id = format!(
+            "{}_{:?}_{}_{}",
+            transaction.time.timestamp_micros(),
+            transaction.side,
+            transaction.price,
+            transaction.quantity
+        );
+
+        Self {
+            exchange_time: transaction.time,
+            received_time: Utc::now(),
+            received_instant: None,
+            raw: None,
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: DataKind::Trade(PublicTrade {
+                id: custom_trade_id,
+                price: transaction.price,
+                quantity: transaction.quantity,
+                side: transaction.side,
+            }),
+        }
+    }
+}
+
+/// [`Bithumb`](super::Bithumb) real-time rolling 24 hour ticker.
+///
+/// eg/ {"symbol":"BTC_KRW","tickType":"24H","date":"20200129","time":"121844",
+///      "openPrice":"2302","closePrice":"2317","lowPrice":"2272","highPrice":"2344",
+///      "value":"2831915078.07065789","volume":"1222314.51355788","chgRate":"0.65", ...}
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BithumbTicker {
+    pub symbol: String,
+    #[serde(deserialize_with = "de_kst_date")]
+    pub date: NaiveDate,
+    #[serde(deserialize_with = "de_kst_time")]
+    pub time: NaiveTime,
+    #[serde(rename = "closePrice", deserialize_with = "de_str")]
+    pub last_price: f64,
+    #[serde(rename = "highPrice", deserialize_with = "de_str")]
+    pub high: f64,
+    #[serde(rename = "lowPrice", deserialize_with = "de_str")]
+    pub low: f64,
+    #[serde(deserialize_with = "de_str")]
+    pub volume: f64,
+    #[serde(rename = "chgRate", deserialize_with = "de_str")]
+    pub change_percent: f64,
+}
+
+impl From<&BithumbTicker> for SubscriptionId {
+    fn from(ticker: &BithumbTicker) -> Self {
+        super::Bithumb::subscription_id(super::Bithumb::CHANNEL_TICKER, &ticker.symbol)
+    }
+}
+
+impl From<(ExchangeId, Instrument, BithumbTicker)> for MarketEvent {
+    fn from((exchange_id, instrument, ticker): (ExchangeId, Instrument, BithumbTicker)) -> Self {
+        Self {
+            exchange_time: kst_to_utc(ticker.date.and_time(ticker.time)),
+            received_time: Utc::now(),
+            received_instant: None,
+            raw: None,
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: DataKind::Ticker(Ticker {
+                last_price: ticker.last_price,
+                volume_24h: ticker.volume,
+                high_24h: ticker.high,
+                low_24h: ticker.low,
+                change_percent_24h: ticker.change_percent,
+            }),
+        }
+    }
+}
+
+/// Convert a [`Bithumb`](super::Bithumb) Korea Standard Time (UTC+9) [`NaiveDateTime`] into a
+/// `DateTime<Utc>`.
+pub fn kst_to_utc(datetime: NaiveDateTime) -> DateTime<Utc> {
+    Utc.from_utc_datetime(&(datetime - Duration::hours(9)))
+}
+
+/// Deserialize a [`BithumbTransaction`] "buySellGb" field to a Barter [`Side`].
+///
+/// Variants:
+/// "1" => Side::Sell
+/// "2" => Side::Buy
+pub fn de_side_from_buy_sell_gb<'de, D>(deserializer: D) -> Result<Side, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    let buy_sell_gb = String::deserialize(deserializer)?;
+    match buy_sell_gb.as_str() {
+        "1" => Ok(Side::Sell),
+        "2" => Ok(Side::Buy),
+        other => Err(serde::de::Error::invalid_value(
+            serde::de::Unexpected::Str(other),
+            &"1 | 2",
+        )),
+    }
+}
+
+/// Deserialize a [`Bithumb`](super::Bithumb) KST datetime (eg/ "2020-01-29 12:24:18.830039")
+/// as a `DateTime<Utc>`.
+pub fn de_kst_datetime_as_datetime_utc<'de, D>(deserializer: D) -> Result<DateTime<Utc>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    let datetime = String::deserialize(deserializer)?;
+    NaiveDateTime::parse_from_str(&datetime, "%Y-%m-%d %H:%M:%S%.f")
+        .map(kst_to_utc)
+        .map_err(serde::de::Error::custom)
+}
+
+/// Deserialize a [`BithumbTicker`] KST date (eg/ "20200129") as a [`NaiveDate`].
+pub fn de_kst_date<'de, D>(deserializer: D) -> Result<NaiveDate, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    let date = String::deserialize(deserializer)?;
+    NaiveDate::parse_from_str(&date, "%Y%m%d").map_err(serde::de::Error::custom)
+}
+
+/// Deserialize a [`BithumbTicker`] KST time (eg/ "121844") as a [`NaiveTime`].
+pub fn de_kst_time<'de, D>(deserializer: D) -> Result<NaiveTime, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    let time = String::deserialize(deserializer)?;
+    NaiveTime::parse_from_str(&time, "%H%M%S").map_err(serde::de::Error::custom)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialise_bithumb_message() {
+        struct TestCase {
+            input: &'static str,
+            expected: Result<BithumbMessage, SocketError>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: valid transaction batch
+                input: r#"{
+                    "type":"transaction",
+                    "content":{"list":[
+                        {"symbol":"BTC_KRW","buySellGb":"1","contPrice":"10579000",
+                         "contQty":"0.01","contAmt":"105790.00",
+                         "contDtm":"2020-01-29 12:24:18.830039","updn":"dn"},
+                        {"symbol":"BTC_KRW","buySellGb":"2","contPrice":"10580000",
+                         "contQty":"0.5","contAmt":"5290000.00",
+                         "contDtm":"2020-01-29 12:24:19.000000","updn":"up"}
+                    ]}
+                }"#,
+                expected: Ok(BithumbMessage::Data(BithumbData::Transaction {
+                    content: BithumbTransactions {
+                        list: vec![
+                            BithumbTransaction {
+                                symbol: "BTC_KRW".to_owned(),
+                                side: Side::Sell,
+                                price: 10579000.0,
+                                quantity: 0.01,
+                                time: Utc.with_ymd_and_hms(2020, 1, 29, 3, 24, 18).unwrap()
+                                    + Duration::microseconds(830039),
+                            },
+                            BithumbTransaction {
+                                symbol: "BTC_KRW".to_owned(),
+                                side: Side::Buy,
+                                price: 10580000.0,
+                                quantity: 0.5,
+                                time: Utc.with_ymd_and_hms(2020, 1, 29, 3, 24, 19).unwrap(),
+                            },
+                        ],
+                    },
+                })),
+            },
+            TestCase {
+                // TC1: valid ticker
+                input: r#"{
+                    "type":"ticker",
+                    "content":{
+                        "symbol":"BTC_KRW","tickType":"24H","date":"20200129","time":"121844",
+                        "openPrice":"2302","closePrice":"2317","lowPrice":"2272",
+                        "highPrice":"2344","value":"2831915078.07065789",
+                        "volume":"1222314.51355788","sellVolume":"760129.34079004",
+                        "buyVolume":"462185.17276784","prevClosePrice":"2326","chgRate":"0.65",
+                        "chgAmt":"15","volumePower":"60.80"
+                    }
+                }"#,
+                expected: Ok(BithumbMessage::Data(BithumbData::Ticker {
+                    content: BithumbTicker {
+                        symbol: "BTC_KRW".to_owned(),
+                        date: NaiveDate::from_ymd_opt(2020, 1, 29).unwrap(),
+                        time: NaiveTime::from_hms_opt(12, 18, 44).unwrap(),
+                        last_price: 2317.0,
+                        high: 2344.0,
+                        low: 2272.0,
+                        volume: 1222314.51355788,
+                        change_percent: 0.65,
+                    },
+                })),
+            },
+            TestCase {
+                // TC2: valid connection status
+                input: r#"{"status":"0000","resmsg":"Connected Successfully"}"#,
+                expected: Ok(BithumbMessage::Status(BithumbStatus {
+                    status: "0000".to_owned(),
+                    resmsg: "Connected Successfully".to_owned(),
+                })),
+            },
+            TestCase {
+                // TC3: invalid transaction w/ unknown buySellGb
+                input: r#"{
+                    "type":"transaction",
+                    "content":{"list":[
+                        {"symbol":"BTC_KRW","buySellGb":"3","contPrice":"10579000",
+                         "contQty":"0.01","contDtm":"2020-01-29 12:24:18.830039"}
+                    ]}
+                }"#,
+                expected: Err(SocketError::Unsupported {
+                    entity: "",
+                    item: "".to_owned(),
+                }),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = serde_json::from_str::<BithumbMessage>(test.input);
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => {
+                    assert_eq!(actual, expected, "TC{} failed", index)
+                }
+                (Err(_), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_validate_bithumb_status() {
+        struct TestCase {
+            input: BithumbStatus,
+            expected: Result<BithumbStatus, SocketError>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: successful filter registration
+                input: BithumbStatus {
+                    status: "0000".to_owned(),
+                    resmsg: "Filter Registered Successfully".to_owned(),
+                },
+                expected: Ok(BithumbStatus {
+                    status: "0000".to_owned(),
+                    resmsg: "Filter Registered Successfully".to_owned(),
+                }),
+            },
+            TestCase {
+                // TC1: failed filter registration
+                input: BithumbStatus {
+                    status: "5100".to_owned(),
+                    resmsg: "Invalid Filter Syntax".to_owned(),
+                },
+                expected: Err(SocketError::Subscribe("failure".to_owned())),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = test.input.validate();
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => {
+                    assert_eq!(actual, expected, "TC{} failed", index)
+                }
+                (Err(_), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+}
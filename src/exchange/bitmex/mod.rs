@@ -0,0 +1,455 @@
+use crate::{
+    model::subscription::{SubKind, SubscriptionIds, SubscriptionMeta},
+    ExchangeId, ExchangeTransformer, MarketEvent, Subscriber, Subscription, Validator,
+};
+use barter_integration::{
+    error::SocketError,
+    model::{Instrument, InstrumentKind, SubscriptionId},
+    protocol::websocket::WsMessage,
+    Transformer,
+};
+use model::{
+    BitmexAction, BitmexBinSize, BitmexMessage, BitmexSubResponse, BitmexTable, BitmexTableData,
+};
+use serde_json::json;
+use std::collections::{HashMap, HashSet};
+use tokio::sync::mpsc;
+use tracing::debug;
+
+/// [`Bitmex`] specific data structures.
+pub mod model;
+
+/// [`Bitmex`] [`Subscriber`] & [`ExchangeTransformer`] implementor for the collection
+/// of `Spot` & `FuturePerpetual` data via the BitMEX realtime WebSocket API.
+///
+/// BitMEX tables are delivered as a `partial` image followed by incremental actions. Records
+/// inserted into a table before its `partial` has been received are discarded, as per the
+/// BitMEX docs. The `trade` & `tradeBin` tables are append only, so each inserted record is a
+/// new trade or completed bin, while the records of the `partial` image predate the
+/// subscription & are not emitted.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct Bitmex {
+    pub ids: SubscriptionIds,
+    pub partials: HashSet<SubscriptionId>,
+}
+
+impl Subscriber for Bitmex {
+    type SubResponse = BitmexSubResponse;
+
+    fn base_url() -> &'static str {
+        ExchangeId::Bitmex.ws_base_url()
+    }
+
+    fn build_subscription_meta(
+        subscriptions: &[Subscription],
+    ) -> Result<SubscriptionMeta, SocketError> {
+        // Allocate SubscriptionIds HashMap to track identifiers for each actioned Subscription
+        let mut ids = SubscriptionIds(HashMap::with_capacity(subscriptions.len()));
+
+        // Map Barter Subscriptions to Bitmex topics
+        let subscriptions = subscriptions
+            .iter()
+            .map(|subscription| {
+                // Translate Barter Subscription to the associated Bitmex topic
+                let topic = Self::topic(subscription)?;
+
+                // Use the "table:symbol" topic as the SubscriptionId key in the SubscriptionIds
+                // eg/ SubscriptionId("tradeBin1m:XBTUSD")
+                ids.insert(SubscriptionId::from(topic.as_str()), subscription.clone());
+
+                // Construct Bitmex specific subscription message
+                Ok(Self::subscription_message(&topic))
+            })
+            .collect::<Result<Vec<_>, SocketError>>()?;
+
+        Ok(SubscriptionMeta {
+            ids,
+            expected_responses: subscriptions.len(),
+            subscriptions,
+        })
+    }
+}
+
+impl ExchangeTransformer for Bitmex {
+    const EXCHANGE: ExchangeId = ExchangeId::Bitmex;
+
+    fn new(_: mpsc::UnboundedSender<WsMessage>, ids: SubscriptionIds) -> Self {
+        Self {
+            ids,
+            partials: HashSet::new(),
+        }
+    }
+}
+
+impl Transformer<MarketEvent> for Bitmex {
+    type Input = BitmexMessage;
+    type OutputIter = Vec<Result<MarketEvent, SocketError>>;
+
+    fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+        match input {
+            BitmexMessage::Table(table) => {
+                let name = table.name();
+                match table {
+                    BitmexTable::Trade(data) => self.transform_table(
+                        name,
+                        data,
+                        |trade| &trade.symbol,
+                        |subscription, trade| {
+                            Ok(MarketEvent::from((
+                                Bitmex::EXCHANGE,
+                                subscription.instrument.clone(),
+                                trade,
+                            )))
+                        },
+                    ),
+                    BitmexTable::TradeBin1m(data)
+                    | BitmexTable::TradeBin5m(data)
+                    | BitmexTable::TradeBin1h(data)
+                    | BitmexTable::TradeBin1d(data) => self.transform_table(
+                        name,
+                        data,
+                        |candle| &candle.symbol,
+                        |subscription, candle| match subscription.kind {
+                            SubKind::Candle(interval) => Ok(MarketEvent::from((
+                                Bitmex::EXCHANGE,
+                                subscription.instrument.clone(),
+                                interval,
+                                candle,
+                            ))),
+                            _ => Err(SocketError::Unsupported {
+                                entity: Bitmex::EXCHANGE.as_str(),
+                                item: subscription.kind.to_string(),
+                            }),
+                        },
+                    ),
+                }
+            }
+            BitmexMessage::Response(response) => match response.validate() {
+                Ok(response) => {
+                    debug!(exchange_id = %Bitmex::EXCHANGE, ?response, "received response");
+                    vec![]
+                }
+                Err(error) => vec![Err(error)],
+            },
+        }
+    }
+}
+
+impl Bitmex {
+    /// [`Bitmex`] trade table.
+    ///
+    /// See docs: <https://www.bitmex.com/app/wsAPI#Subscriptions>
+    pub const TABLE_TRADE: &'static str = "trade";
+
+    /// [`Bitmex`] trade bin table prefix, suffixed with the [`BitmexBinSize`]
+    /// (eg/ "tradeBin1m").
+    ///
+    /// See docs: <https://www.bitmex.com/app/wsAPI#Subscriptions>
+    pub const TABLE_TRADE_BIN: &'static str = "tradeBin";
+
+    /// Translate a Barter [`Subscription`] into a [`Bitmex`] "table:symbol" topic.
+    ///
+    /// eg/ "trade:XBTUSD", "tradeBin1m:XBTUSD"
+    pub fn topic(sub: &Subscription) -> Result<String, SocketError> {
+        // Validate provided Subscription InstrumentKind & SubKind are supported by Bitmex
+        let sub = sub.validate()?;
+
+        let table = match &sub.kind {
+            SubKind::Trade => Self::TABLE_TRADE.to_owned(),
+            SubKind::Candle(interval) => format!(
+                "{}{}",
+                Self::TABLE_TRADE_BIN,
+                BitmexBinSize::try_from(interval)?.0
+            ),
+            other => {
+                return Err(SocketError::Unsupported {
+                    entity: Self::EXCHANGE.as_str(),
+                    item: other.to_string(),
+                })
+            }
+        };
+
+        Ok(format!("{table}:{}", Self::symbol(&sub.instrument)))
+    }
+
+    /// Determine the [`Bitmex`] symbol for the provided [`Instrument`]. BitMEX denotes
+    /// Bitcoin as "XBT".
+    ///
+    /// eg/ Spot "XBT_USDT", FuturePerpetual "XBTUSD"
+    pub fn symbol(instrument: &Instrument) -> String {
+        let base = match instrument.base.as_ref() {
+            "btc" | "BTC" => "XBT".to_owned(),
+            base => base.to_uppercase(),
+        };
+        let quote = instrument.quote.as_ref().to_uppercase();
+
+        match instrument.kind {
+            InstrumentKind::Spot => format!("{base}_{quote}"),
+            InstrumentKind::FuturePerpetual => format!("{base}{quote}"),
+        }
+    }
+
+    /// Build a [`Bitmex`] compatible subscription message for the provided topic.
+    ///
+    /// eg/ {"op":"subscribe","args":["trade:XBTUSD"]}
+    pub fn subscription_message(topic: &str) -> WsMessage {
+        WsMessage::Text(
+            json!({
+                "op": "subscribe",
+                "args": [topic],
+            })
+            .to_string(),
+        )
+    }
+
+    /// Apply the [`BitmexAction`] of a [`BitmexTableData`] message, transforming inserted
+    /// records of tables that have received their `partial` into [`MarketEvent`]s.
+    fn transform_table<Record>(
+        &mut self,
+        table: &str,
+        data: BitmexTableData<Record>,
+        symbol: fn(&Record) -> &str,
+        transform: impl Fn(&Subscription, Record) -> Result<MarketEvent, SocketError>,
+    ) -> Vec<Result<MarketEvent, SocketError>> {
+        match data.action {
+            BitmexAction::Partial => {
+                if let Some(filter) = data.filter {
+                    self.partials
+                        .insert(SubscriptionId::from(format!("{table}:{}", filter.symbol)));
+                }
+                vec![]
+            }
+            BitmexAction::Insert => data
+                .data
+                .into_iter()
+                .filter_map(|record| {
+                    let subscription_id =
+                        SubscriptionId::from(format!("{table}:{}", symbol(&record)));
+
+                    // Discard records inserted before the partial image of the table
+                    if !self.partials.contains(&subscription_id) {
+                        return None;
+                    }
+
+                    Some(match self.ids.get(&subscription_id) {
+                        Some(subscription) => transform(subscription, record),
+                        None => Err(SocketError::Unidentifiable(subscription_id)),
+                    })
+                })
+                .collect(),
+            BitmexAction::Update | BitmexAction::Delete => {
+                debug!(
+                    exchange_id = %Bitmex::EXCHANGE,
+                    table,
+                    action = ?data.action,
+                    "ignoring action on append only table"
+                );
+                vec![]
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{subscription::Interval, Candle, DataKind, PublicTrade};
+    use barter_integration::model::Side;
+    use chrono::{Duration, Utc};
+    use model::{BitmexCandle, BitmexFilter, BitmexTrade};
+
+    #[test]
+    fn test_topic() {
+        struct TestCase {
+            input: Subscription,
+            expected: Result<String, SocketError>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: Supported InstrumentKind::FuturePerpetual trades subscription
+                input: Subscription::new(
+                    ExchangeId::Bitmex,
+                    ("btc", "usd", InstrumentKind::FuturePerpetual),
+                    SubKind::Trade,
+                ),
+                expected: Ok("trade:XBTUSD".to_owned()),
+            },
+            TestCase {
+                // TC1: Supported InstrumentKind::Spot candles subscription
+                input: Subscription::new(
+                    ExchangeId::Bitmex,
+                    ("eth", "usdt", InstrumentKind::Spot),
+                    SubKind::Candle(Interval::Minute1),
+                ),
+                expected: Ok("tradeBin1m:ETH_USDT".to_owned()),
+            },
+            TestCase {
+                // TC2: Unsupported Interval
+                input: Subscription::new(
+                    ExchangeId::Bitmex,
+                    ("btc", "usd", InstrumentKind::FuturePerpetual),
+                    SubKind::Candle(Interval::Minute15),
+                ),
+                expected: Err(SocketError::Unsupported {
+                    entity: "bitmex",
+                    item: Interval::Minute15.to_string(),
+                }),
+            },
+            TestCase {
+                // TC3: Unsupported SubKind
+                input: Subscription::new(
+                    ExchangeId::Bitmex,
+                    ("btc", "usd", InstrumentKind::FuturePerpetual),
+                    SubKind::OrderBookL3Delta,
+                ),
+                expected: Err(SocketError::Unsupported {
+                    entity: "bitmex",
+                    item: SubKind::OrderBookL3Delta.to_string(),
+                }),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = Bitmex::topic(&test.input);
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => {
+                    assert_eq!(actual, expected, "TC{} failed", index)
+                }
+                (Err(_), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_bitmex_transform_table_actions() {
+        let (ws_sink_tx, _ws_sink_rx) = mpsc::unbounded_channel();
+        let mut transformer = Bitmex::new(
+            ws_sink_tx,
+            SubscriptionIds(HashMap::from([
+                (
+                    SubscriptionId::from("trade:XBTUSD"),
+                    Subscription::new(
+                        ExchangeId::Bitmex,
+                        ("btc", "usd", InstrumentKind::FuturePerpetual),
+                        SubKind::Trade,
+                    ),
+                ),
+                (
+                    SubscriptionId::from("tradeBin1m:XBTUSD"),
+                    Subscription::new(
+                        ExchangeId::Bitmex,
+                        ("btc", "usd", InstrumentKind::FuturePerpetual),
+                        SubKind::Candle(Interval::Minute1),
+                    ),
+                ),
+            ])),
+        );
+
+        let time = Utc::now();
+        let trade = BitmexTrade {
+            time,
+            symbol: "XBTUSD".to_owned(),
+            side: Side::Buy,
+            quantity: 100.0,
+            price: 20000.0,
+            id: "1".to_owned(),
+        };
+        let trades = |action, filter, data| {
+            BitmexMessage::Table(BitmexTable::Trade(BitmexTableData {
+                action,
+                filter,
+                data,
+            }))
+        };
+        let filter = Some(BitmexFilter {
+            symbol: "XBTUSD".to_owned(),
+        });
+
+        struct TestCase {
+            input: BitmexMessage,
+            expected: Vec<Option<DataKind>>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: insert before the partial is discarded
+                input: trades(BitmexAction::Insert, None, vec![trade.clone()]),
+                expected: vec![],
+            },
+            TestCase {
+                // TC1: partial image records are not emitted
+                input: trades(BitmexAction::Partial, filter, vec![trade.clone()]),
+                expected: vec![],
+            },
+            TestCase {
+                // TC2: insert after the partial is transformed
+                input: trades(BitmexAction::Insert, None, vec![trade.clone()]),
+                expected: vec![Some(DataKind::Trade(PublicTrade {
+                    id: "1".to_owned(),
+                    price: 20000.0,
+                    quantity: 100.0,
+                    side: Side::Buy,
+                }))],
+            },
+            TestCase {
+                // TC3: update on an append only table is ignored
+                input: trades(BitmexAction::Update, None, vec![trade]),
+                expected: vec![],
+            },
+            TestCase {
+                // TC4: tradeBin1m partial initialises the table
+                input: BitmexMessage::Table(BitmexTable::TradeBin1m(BitmexTableData {
+                    action: BitmexAction::Partial,
+                    filter: Some(BitmexFilter {
+                        symbol: "XBTUSD".to_owned(),
+                    }),
+                    data: vec![],
+                })),
+                expected: vec![],
+            },
+            TestCase {
+                // TC5: tradeBin1m insert after the partial is transformed
+                input: BitmexMessage::Table(BitmexTable::TradeBin1m(BitmexTableData {
+                    action: BitmexAction::Insert,
+                    filter: None,
+                    data: vec![BitmexCandle {
+                        end_time: time,
+                        symbol: "XBTUSD".to_owned(),
+                        open: 1.0,
+                        high: 2.0,
+                        low: 0.5,
+                        close: 1.5,
+                        volume: 10.0,
+                        trade_count: 3,
+                    }],
+                })),
+                expected: vec![Some(DataKind::Candle(Candle {
+                    start_time: time - Duration::minutes(1),
+                    end_time: time,
+                    open: 1.0,
+                    high: 2.0,
+                    low: 0.5,
+                    close: 1.5,
+                    volume: 10.0,
+                    trade_count: 3,
+                }))],
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = transformer
+                .transform(test.input)
+                .into_iter()
+                .map(|event| event.ok().map(|event| event.kind))
+                .collect::<Vec<_>>();
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
@@ -0,0 +1,322 @@
+use crate::{
+    exchange::de_str,
+    model::{subscription::Interval, Candle, DataKind, PublicTrade},
+    ExchangeId, MarketEvent,
+};
+use barter_integration::{
+    error::SocketError,
+    model::{Exchange, Instrument, Side},
+    Validator,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// [`Bitmex`](super::Bitmex) message received in response to WebSocket subscription requests.
+///
+/// eg/ BitmexSubResponse::Subscribed {"success":true,"subscribe":"trade:XBTUSD","request":{...}}
+/// eg/ BitmexSubResponse::Error {"status":400,"error":"Unknown table: trades","meta":{},"request":{...}}
+///
+/// See docs: <https://www.bitmex.com/app/wsAPI#Subscriptions>
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum BitmexSubResponse {
+    Subscribed { success: bool, subscribe: String },
+    Error { error: String },
+}
+
+impl Validator for BitmexSubResponse {
+    fn validate(self) -> Result<Self, SocketError>
+    where
+        Self: Sized,
+    {
+        match &self {
+            BitmexSubResponse::Subscribed { success: true, .. } => Ok(self),
+            BitmexSubResponse::Subscribed { subscribe, .. } => Err(SocketError::Subscribe(
+                format!("received failure subscription response for: {subscribe}"),
+            )),
+            BitmexSubResponse::Error { error } => Err(SocketError::Subscribe(format!(
+                "received failure subscription response: {error}"
+            ))),
+        }
+    }
+}
+
+/// [`Bitmex`](super::Bitmex) bin size of the `tradeBin` candle tables (eg/ "tradeBin1m").
+///
+/// See docs: <https://www.bitmex.com/app/wsAPI#Subscriptions>
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct BitmexBinSize(pub &'static str);
+
+impl TryFrom<&Interval> for BitmexBinSize {
+    type Error = SocketError;
+
+    fn try_from(interval: &Interval) -> Result<Self, Self::Error> {
+        let bin_size = match interval {
+            Interval::Minute1 => "1m",
+            Interval::Minute5 => "5m",
+            Interval::Hour1 => "1h",
+            Interval::Day1 => "1d",
+            other => {
+                return Err(SocketError::Unsupported {
+                    entity: ExchangeId::Bitmex.as_str(),
+                    item: other.to_string(),
+                })
+            }
+        };
+
+        Ok(Self(bin_size))
+    }
+}
+
+/// [`Bitmex`](super::Bitmex) message variants that can be received over [`WebSocket`].
+///
+/// See docs: <https://www.bitmex.com/app/wsAPI#Response-Format>
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum BitmexMessage {
+    Table(BitmexTable),
+    Response(BitmexSubResponse),
+}
+
+/// [`Bitmex`](super::Bitmex) table message, tagged by the table name.
+///
+/// eg/ {"table":"trade","action":"insert","data":[{...}]}
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(tag = "table")]
+pub enum BitmexTable {
+    #[serde(rename = "trade")]
+    Trade(BitmexTableData<BitmexTrade>),
+    #[serde(rename = "tradeBin1m")]
+    TradeBin1m(BitmexTableData<BitmexCandle>),
+    #[serde(rename = "tradeBin5m")]
+    TradeBin5m(BitmexTableData<BitmexCandle>),
+    #[serde(rename = "tradeBin1h")]
+    TradeBin1h(BitmexTableData<BitmexCandle>),
+    #[serde(rename = "tradeBin1d")]
+    TradeBin1d(BitmexTableData<BitmexCandle>),
+}
+
+impl BitmexTable {
+    /// Return the name of this [`BitmexTable`].
+    pub fn name(&self) -> &'static str {
+        match self {
+            BitmexTable::Trade(_) => "trade",
+            BitmexTable::TradeBin1m(_) => "tradeBin1m",
+            BitmexTable::TradeBin5m(_) => "tradeBin5m",
+            BitmexTable::TradeBin1h(_) => "tradeBin1h",
+            BitmexTable::TradeBin1d(_) => "tradeBin1d",
+        }
+    }
+}
+
+/// [`Bitmex`](super::Bitmex) table action & the records it applies to. A `partial` contains the
+/// full image of the table for the subscribed `filter`, which `insert`, `update` & `delete`
+/// actions are applied on top of.
+///
+/// eg/ {"action":"partial","keys":[],"filter":{"symbol":"XBTUSD"},"data":[{...}]}
+///
+/// See docs: <https://www.bitmex.com/app/wsAPI#Response-Format>
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct BitmexTableData<Record> {
+    pub action: BitmexAction,
+    #[serde(default)]
+    pub filter: Option<BitmexFilter>,
+    pub data: Vec<Record>,
+}
+
+/// Action communicated by a [`BitmexTableData`] message.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BitmexAction {
+    Partial,
+    Insert,
+    Update,
+    Delete,
+}
+
+/// Filter of a [`BitmexTableData`] `partial`, identifying the subscribed symbol.
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct BitmexFilter {
+    pub symbol: String,
+}
+
+/// [`Bitmex`](super::Bitmex) `trade` table record.
+///
+/// eg/ {"timestamp":"2023-02-18T09:27:59.701Z","symbol":"XBTUSD","side":"Sell","size":200,
+///      "price":24564.5,"tickDirection":"MinusTick","trdMatchID":"31e50cb7-e005-a44e-f354-86e88dff52eb",
+///      "grossValue":814184,"homeNotional":0.00814184,"foreignNotional":200,"trdType":"Regular"}
+///
+/// See docs: <https://www.bitmex.com/app/wsAPI#Subscriptions>
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BitmexTrade {
+    #[serde(rename = "timestamp")]
+    pub time: DateTime<Utc>,
+    pub symbol: String,
+    pub side: Side,
+    #[serde(rename = "size", deserialize_with = "de_str")]
+    pub quantity: f64,
+    #[serde(deserialize_with = "de_str")]
+    pub price: f64,
+    #[serde(rename = "trdMatchID")]
+    pub id: String,
+}
+
+/// [`Bitmex`](super::Bitmex) `tradeBin` table record, where the `timestamp` is the end of the bin.
+///
+/// eg/ {"timestamp":"2023-02-18T09:28:00.000Z","symbol":"XBTUSD","open":24554.5,"high":24565,
+///      "low":24554.5,"close":24564.5,"trades":32,"volume":3800,"vwap":24559.92,"lastSize":200,
+///      "turnover":15470400,"homeNotional":0.154704,"foreignNotional":3800}
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BitmexCandle {
+    #[serde(rename = "timestamp")]
+    pub end_time: DateTime<Utc>,
+    pub symbol: String,
+    #[serde(deserialize_with = "de_str")]
+    pub open: f64,
+    #[serde(deserialize_with = "de_str")]
+    pub high: f64,
+    #[serde(deserialize_with = "de_str")]
+    pub low: f64,
+    #[serde(deserialize_with = "de_str")]
+    pub close: f64,
+    #[serde(deserialize_with = "de_str")]
+    pub volume: f64,
+    #[serde(rename = "trades")]
+    pub trade_count: u64,
+}
+
+impl From<(ExchangeId, Instrument, BitmexTrade)> for MarketEvent {
+    fn from((exchange_id, instrument, trade): (ExchangeId, Instrument, BitmexTrade)) -> Self {
+        Self {
+            exchange_time: trade.time,
+            received_time: Utc::now(),
+            received_instant: None,
+            raw: None,
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: DataKind::Trade(PublicTrade {
+                id: trade.id,
+                price: trade.price,
+                quantity: trade.quantity,
+                side: trade.side,
+            }),
+        }
+    }
+}
+
+impl From<(ExchangeId, Instrument, Interval, BitmexCandle)> for MarketEvent {
+    fn from(
+        (exchange_id, instrument, interval, candle): (
+            ExchangeId,
+            Instrument,
+            Interval,
+            BitmexCandle,
+        ),
+    ) -> Self {
+        // Bins are of a fixed duration, so the start time is the end time less that duration
+        let start_time = candle.end_time - (interval.end_time(candle.end_time) - candle.end_time);
+
+        Self {
+            exchange_time: candle.end_time,
+            received_time: Utc::now(),
+            received_instant: None,
+            raw: None,
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: DataKind::Candle(Candle {
+                start_time,
+                end_time: candle.end_time,
+                open: candle.open,
+                high: candle.high,
+                low: candle.low,
+                close: candle.close,
+                volume: candle.volume,
+                trade_count: candle.trade_count,
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_deserialise_bitmex_message() {
+        struct TestCase {
+            input: &'static str,
+            expected: Option<BitmexMessage>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: input is a trade table partial w/ filter
+                input: r#"{"table":"trade","action":"partial","keys":[],"types":{},"foreignKeys":{},"attributes":{},"filter":{"symbol":"XBTUSD"},"data":[]}"#,
+                expected: Some(BitmexMessage::Table(BitmexTable::Trade(BitmexTableData {
+                    action: BitmexAction::Partial,
+                    filter: Some(BitmexFilter {
+                        symbol: "XBTUSD".to_owned(),
+                    }),
+                    data: vec![],
+                }))),
+            },
+            TestCase {
+                // TC1: input is a trade table insert
+                input: r#"{"table":"trade","action":"insert","data":[{"timestamp":"2023-02-18T09:27:59.701Z","symbol":"XBTUSD","side":"Sell","size":200,"price":24564.5,"tickDirection":"MinusTick","trdMatchID":"31e50cb7-e005-a44e-f354-86e88dff52eb","grossValue":814184,"homeNotional":0.00814184,"foreignNotional":200,"trdType":"Regular"}]}"#,
+                expected: Some(BitmexMessage::Table(BitmexTable::Trade(BitmexTableData {
+                    action: BitmexAction::Insert,
+                    filter: None,
+                    data: vec![BitmexTrade {
+                        time: Utc.timestamp_millis_opt(1676712479701).unwrap(),
+                        symbol: "XBTUSD".to_owned(),
+                        side: Side::Sell,
+                        quantity: 200.0,
+                        price: 24564.5,
+                        id: "31e50cb7-e005-a44e-f354-86e88dff52eb".to_owned(),
+                    }],
+                }))),
+            },
+            TestCase {
+                // TC2: input is a tradeBin1m table insert
+                input: r#"{"table":"tradeBin1m","action":"insert","data":[{"timestamp":"2023-02-18T09:28:00.000Z","symbol":"XBTUSD","open":24554.5,"high":24565,"low":24554.5,"close":24564.5,"trades":32,"volume":3800,"vwap":24559.92,"lastSize":200,"turnover":15470400,"homeNotional":0.154704,"foreignNotional":3800}]}"#,
+                expected: Some(BitmexMessage::Table(BitmexTable::TradeBin1m(
+                    BitmexTableData {
+                        action: BitmexAction::Insert,
+                        filter: None,
+                        data: vec![BitmexCandle {
+                            end_time: Utc.timestamp_millis_opt(1676712480000).unwrap(),
+                            symbol: "XBTUSD".to_owned(),
+                            open: 24554.5,
+                            high: 24565.0,
+                            low: 24554.5,
+                            close: 24564.5,
+                            volume: 3800.0,
+                            trade_count: 32,
+                        }],
+                    },
+                ))),
+            },
+            TestCase {
+                // TC3: input is a successful subscription response
+                input: r#"{"success":true,"subscribe":"trade:XBTUSD","request":{"op":"subscribe","args":["trade:XBTUSD"]}}"#,
+                expected: Some(BitmexMessage::Response(BitmexSubResponse::Subscribed {
+                    success: true,
+                    subscribe: "trade:XBTUSD".to_owned(),
+                })),
+            },
+            TestCase {
+                // TC4: input is a failed subscription response
+                input: r#"{"status":400,"error":"Unknown table: trades","meta":{},"request":{"op":"subscribe","args":["trades:XBTUSD"]}}"#,
+                expected: Some(BitmexMessage::Response(BitmexSubResponse::Error {
+                    error: "Unknown table: trades".to_owned(),
+                })),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = serde_json::from_str::<BitmexMessage>(test.input).ok();
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
@@ -0,0 +1,366 @@
+use crate::{
+    model::subscription::{SubKind, SubscriptionIds, SubscriptionMeta},
+    ExchangeId, ExchangeTransformer, MarketEvent, Subscriber, Subscription, Validator,
+};
+use barter_integration::{
+    error::SocketError, model::SubscriptionId, protocol::websocket::WsMessage, Transformer,
+};
+use model::{BitstampMessage, BitstampSubResponse};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::collections::HashMap;
+use tokio::sync::mpsc;
+
+/// [`Bitstamp`] specific data structures.
+pub mod model;
+
+/// [`Bitstamp`] [`Subscriber`] & [`ExchangeTransformer`] implementor for the collection
+/// of `Spot` data.
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct Bitstamp {
+    pub ids: SubscriptionIds,
+}
+
+impl Subscriber for Bitstamp {
+    type SubResponse = BitstampSubResponse;
+
+    fn base_url() -> &'static str {
+        ExchangeId::Bitstamp.ws_base_url()
+    }
+
+    fn build_subscription_meta(
+        subscriptions: &[Subscription],
+    ) -> Result<SubscriptionMeta, SocketError> {
+        // Allocate SubscriptionIds HashMap to track identifiers for each actioned Subscription
+        let mut ids = SubscriptionIds(HashMap::with_capacity(subscriptions.len()));
+
+        // Map Barter Subscriptions to Bitstamp channels
+        let subscriptions = subscriptions
+            .iter()
+            .map(|subscription| {
+                // Determine the Bitstamp specific channel for this Barter Subscription
+                let channel = Self::build_channel(subscription)?;
+
+                // Use the channel as the SubscriptionId key in the SubscriptionIds HashMap
+                // eg/ SubscriptionId("live_orders_btcusd")
+                ids.insert(SubscriptionId::from(channel.as_str()), subscription.clone());
+
+                // Construct Bitstamp specific subscription message
+                Ok(Self::subscription(&channel))
+            })
+            .collect::<Result<Vec<_>, SocketError>>()?;
+
+        Ok(SubscriptionMeta {
+            ids,
+            expected_responses: subscriptions.len(),
+            subscriptions,
+        })
+    }
+}
+
+impl ExchangeTransformer for Bitstamp {
+    const EXCHANGE: ExchangeId = ExchangeId::Bitstamp;
+
+    fn new(_: mpsc::UnboundedSender<WsMessage>, ids: SubscriptionIds) -> Self {
+        Self { ids }
+    }
+}
+
+impl Transformer<MarketEvent> for Bitstamp {
+    type Input = BitstampMessage;
+    type OutputIter = Vec<Result<MarketEvent, SocketError>>;
+
+    fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+        match self.ids.find_instrument(input.subscription_id()) {
+            Ok(instrument) => vec![Ok(MarketEvent::from((
+                Bitstamp::EXCHANGE,
+                instrument,
+                input,
+            )))],
+            Err(error) => vec![Err(error)],
+        }
+    }
+}
+
+impl Bitstamp {
+    /// [`Bitstamp`] live orders channel prefix, providing L3 order creations, changes & deletions.
+    ///
+    /// See docs: <https://www.bitstamp.net/websocket/v2/>
+    pub const CHANNEL_LIVE_ORDERS: &'static str = "live_orders";
+
+    /// [`Bitstamp`] order book channel prefix, providing top 100 level L2 snapshots.
+    ///
+    /// See docs: <https://www.bitstamp.net/websocket/v2/>
+    pub const CHANNEL_ORDER_BOOK: &'static str = "order_book";
+
+    /// [`Bitstamp`] diff order book channel prefix, providing L2 changed levels.
+    ///
+    /// See docs: <https://www.bitstamp.net/websocket/v2/>
+    pub const CHANNEL_DIFF_ORDER_BOOK: &'static str = "diff_order_book";
+
+    /// Determine the [`Bitstamp`] channel associated with an input Barter [`Subscription`].
+    ///
+    /// Example Ok return: Ok("live_orders_btcusd")
+    pub fn build_channel(sub: &Subscription) -> Result<String, SocketError> {
+        // Validate provided Subscription InstrumentKind is supported by Bitstamp
+        let sub = sub.validate()?;
+
+        // Determine Bitstamp channel prefix using the Subscription SubKind
+        let channel = match &sub.kind {
+            SubKind::OrderBook => Self::CHANNEL_ORDER_BOOK,
+            SubKind::OrderBookL2Delta => Self::CHANNEL_DIFF_ORDER_BOOK,
+            SubKind::OrderBookL3Delta => Self::CHANNEL_LIVE_ORDERS,
+            other => {
+                return Err(SocketError::Unsupported {
+                    entity: Self::EXCHANGE.as_str(),
+                    item: other.to_string(),
+                })
+            }
+        };
+
+        // Determine Bitstamp market identifier using the Instrument (eg/ "btcusd")
+        Ok(format!("{channel}_{}{}", sub.instrument.base, sub.instrument.quote).to_lowercase())
+    }
+
+    /// Build a [`Bitstamp`] compatible subscription message using the channel provided.
+    pub fn subscription(channel: &str) -> WsMessage {
+        WsMessage::Text(
+            json!({
+                "event": "bts:subscribe",
+                "data": {
+                    "channel": channel,
+                },
+            })
+            .to_string(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchange::bitstamp::model::{
+        BitstampLevel, BitstampOrder, BitstampOrderBook, BitstampOrderBookEvent, BitstampOrderEvent,
+    };
+    use crate::model::{DataKind, Level, OrderBookL2Delta, OrderBookL3Delta, OrderBookL3DeltaKind};
+    use barter_integration::model::{Exchange, Instrument, InstrumentKind, Side};
+    use chrono::Utc;
+
+    #[test]
+    fn test_build_channel() {
+        struct TestCase {
+            input: Subscription,
+            expected: Result<String, SocketError>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: Supported InstrumentKind::Spot L3 OrderBook delta subscription
+                input: Subscription::new(
+                    ExchangeId::Bitstamp,
+                    ("btc", "usd", InstrumentKind::Spot),
+                    SubKind::OrderBookL3Delta,
+                ),
+                expected: Ok("live_orders_btcusd".to_owned()),
+            },
+            TestCase {
+                // TC1: Unsupported InstrumentKind::Spot trades subscription
+                input: Subscription::new(
+                    ExchangeId::Bitstamp,
+                    ("btc", "usd", InstrumentKind::Spot),
+                    SubKind::Trade,
+                ),
+                expected: Err(SocketError::Unsupported {
+                    entity: "",
+                    item: "".to_string(),
+                }),
+            },
+            TestCase {
+                // TC2: Supported InstrumentKind::Spot L2 OrderBook snapshot subscription
+                input: Subscription::new(
+                    ExchangeId::Bitstamp,
+                    ("eth", "usd", InstrumentKind::Spot),
+                    SubKind::OrderBook,
+                ),
+                expected: Ok("order_book_ethusd".to_owned()),
+            },
+            TestCase {
+                // TC3: Supported InstrumentKind::Spot L2 OrderBook delta subscription
+                input: Subscription::new(
+                    ExchangeId::Bitstamp,
+                    ("btc", "usd", InstrumentKind::Spot),
+                    SubKind::OrderBookL2Delta,
+                ),
+                expected: Ok("diff_order_book_btcusd".to_owned()),
+            },
+            TestCase {
+                // TC4: Unsupported InstrumentKind::FuturePerpetual L3 subscription
+                input: Subscription::new(
+                    ExchangeId::Bitstamp,
+                    ("btc", "usd", InstrumentKind::FuturePerpetual),
+                    SubKind::OrderBookL3Delta,
+                ),
+                expected: Err(SocketError::Unsupported {
+                    entity: "",
+                    item: "".to_string(),
+                }),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = Bitstamp::build_channel(&test.input);
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => {
+                    assert_eq!(actual, expected, "TC{} failed", index)
+                }
+                (Err(_), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_bitstamp_transform() {
+        let sub = Subscription::new(
+            ExchangeId::Bitstamp,
+            ("btc", "usd", InstrumentKind::Spot),
+            SubKind::OrderBookL3Delta,
+        );
+        let delta_sub = Subscription::new(
+            ExchangeId::Bitstamp,
+            ("btc", "usd", InstrumentKind::Spot),
+            SubKind::OrderBookL2Delta,
+        );
+        let mut transformer = Bitstamp {
+            ids: SubscriptionIds(HashMap::from([
+                (SubscriptionId::from("live_orders_btcusd"), sub),
+                (SubscriptionId::from("diff_order_book_btcusd"), delta_sub),
+            ])),
+        };
+
+        let time = Utc::now();
+        let order = BitstampOrder {
+            id: "1".to_owned(),
+            side: Side::Buy,
+            price: 100.0,
+            quantity: 1.0,
+            time,
+        };
+
+        struct TestCase {
+            input: BitstampMessage,
+            expected: Vec<Result<MarketEvent, SocketError>>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: BitstampMessage order_changed w/ known SubscriptionId
+                input: BitstampMessage::OrderChanged(BitstampOrderEvent {
+                    subscription_id: SubscriptionId::from("live_orders_btcusd"),
+                    data: order.clone(),
+                }),
+                expected: vec![Ok(MarketEvent {
+                    exchange_time: time,
+                    received_time: time,
+                    received_instant: None,
+                    raw: None,
+                    exchange: Exchange::from(ExchangeId::Bitstamp),
+                    instrument: Instrument::from(("btc", "usd", InstrumentKind::Spot)),
+                    kind: DataKind::OrderBookL3Delta(OrderBookL3Delta {
+                        id: "1".to_owned(),
+                        kind: OrderBookL3DeltaKind::Change,
+                        side: Side::Buy,
+                        price: 100.0,
+                        quantity: 1.0,
+                    }),
+                })],
+            },
+            TestCase {
+                // TC1: BitstampMessage with unknown SubscriptionId
+                input: BitstampMessage::OrderCreated(BitstampOrderEvent {
+                    subscription_id: SubscriptionId::from("unknown"),
+                    data: order,
+                }),
+                expected: vec![Err(SocketError::Unidentifiable(SubscriptionId::from(
+                    "unknown",
+                )))],
+            },
+            TestCase {
+                // TC2: BitstampMessage diff_order_book data w/ known SubscriptionId
+                input: BitstampMessage::OrderBook(BitstampOrderBookEvent {
+                    subscription_id: SubscriptionId::from("diff_order_book_btcusd"),
+                    data: BitstampOrderBook {
+                        time,
+                        bids: vec![BitstampLevel {
+                            price: 100.0,
+                            quantity: 1.0,
+                        }],
+                        asks: vec![BitstampLevel {
+                            price: 101.0,
+                            quantity: 0.0,
+                        }],
+                    },
+                }),
+                expected: vec![Ok(MarketEvent {
+                    exchange_time: time,
+                    received_time: time,
+                    received_instant: None,
+                    raw: None,
+                    exchange: Exchange::from(ExchangeId::Bitstamp),
+                    instrument: Instrument::from(("btc", "usd", InstrumentKind::Spot)),
+                    kind: DataKind::OrderBookL2Delta(OrderBookL2Delta {
+                        last_update_time: time,
+                        first_update_id: None,
+                        last_update_id: time.timestamp_micros() as u64,
+                        bids: vec![Level::new(100.0, 1.0)],
+                        asks: vec![Level::new(101.0, 0.0)],
+                    }),
+                })],
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = transformer.transform(test.input);
+            assert_eq!(
+                actual.len(),
+                test.expected.len(),
+                "TestCase {} failed at vector length assert_eq with actual: {:?}",
+                index,
+                actual
+            );
+
+            for (vector_index, (actual, expected)) in
+                actual.into_iter().zip(test.expected).enumerate()
+            {
+                match (actual, expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        // Scrub Utc::now() timestamps to allow comparison
+                        let actual = MarketEvent {
+                            received_time: time,
+                            received_instant: None,
+                            raw: None,
+                            ..actual
+                        };
+                        assert_eq!(
+                            actual, expected,
+                            "TC{} failed at vector index {}",
+                            index, vector_index
+                        )
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed at vector index {vector_index} because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+}
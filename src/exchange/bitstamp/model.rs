@@ -0,0 +1,395 @@
+use crate::{
+    exchange::{datetime_utc_from_epoch_duration, de_str},
+    model::{DataKind, Level, OrderBook, OrderBookL2Delta, OrderBookL3Delta, OrderBookL3DeltaKind},
+    ExchangeId, MarketEvent, Validator,
+};
+use barter_integration::{
+    error::SocketError,
+    model::{Exchange, Instrument, Side, SubscriptionId},
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// [`Bitstamp`](super::Bitstamp) message received in response to WebSocket subscription requests.
+///
+/// eg/ BitstampSubResponse::Subscribed {"event":"bts:subscription_succeeded","channel":"live_orders_btcusd","data":{}}
+/// eg/ BitstampSubResponse::Error {"event":"bts:error","channel":"","data":{"code":null,"message":"Bad subscription string."}}
+///
+/// See docs: <https://www.bitstamp.net/websocket/v2/>
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(tag = "event")]
+pub enum BitstampSubResponse {
+    #[serde(rename = "bts:subscription_succeeded")]
+    Subscribed { channel: String },
+    #[serde(rename = "bts:error")]
+    Error { data: BitstampError },
+}
+
+/// [`Bitstamp`](super::Bitstamp) error message.
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct BitstampError {
+    pub message: String,
+}
+
+impl Validator for BitstampSubResponse {
+    fn validate(self) -> Result<Self, SocketError>
+    where
+        Self: Sized,
+    {
+        match &self {
+            BitstampSubResponse::Subscribed { .. } => Ok(self),
+            BitstampSubResponse::Error { data } => Err(SocketError::Subscribe(format!(
+                "received failure subscription response: {}",
+                data.message
+            ))),
+        }
+    }
+}
+
+/// [`Bitstamp`](super::Bitstamp) message variants that can be received over [`WebSocket`].
+///
+/// See docs: <https://www.bitstamp.net/websocket/v2/>
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum BitstampMessage {
+    OrderCreated(BitstampOrderEvent),
+    OrderChanged(BitstampOrderEvent),
+    OrderDeleted(BitstampOrderEvent),
+    #[serde(rename = "data")]
+    OrderBook(BitstampOrderBookEvent),
+}
+
+/// [`Bitstamp`](super::Bitstamp) live orders channel event wrapping the associated
+/// [`BitstampOrder`].
+///
+/// eg/ {"data":{...},"channel":"live_orders_btcusd","event":"order_created"}
+///
+/// See docs: <https://www.bitstamp.net/websocket/v2/>
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct BitstampOrderEvent {
+    #[serde(alias = "channel")]
+    pub subscription_id: SubscriptionId,
+    pub data: BitstampOrder,
+}
+
+/// [`Bitstamp`](super::Bitstamp) individual order resting in the book.
+///
+/// eg/ {"id":1614893286756353,"id_str":"1614893286756353","order_type":1,"datetime":"1684406493",
+///      "microtimestamp":"1684406493077000","amount":0.0457,"amount_str":"0.04570000",
+///      "price":26887,"price_str":"26887"}
+///
+/// See docs: <https://www.bitstamp.net/websocket/v2/>
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct BitstampOrder {
+    #[serde(rename = "id_str")]
+    pub id: String,
+    #[serde(rename = "order_type", deserialize_with = "de_order_type_side")]
+    pub side: Side,
+    #[serde(rename = "price_str", deserialize_with = "de_str")]
+    pub price: f64,
+    #[serde(rename = "amount_str", deserialize_with = "de_str")]
+    pub quantity: f64,
+    #[serde(
+        rename = "microtimestamp",
+        deserialize_with = "de_str_epoch_us_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+}
+
+/// [`Bitstamp`](super::Bitstamp) L2 order book channel event wrapping the associated
+/// [`BitstampOrderBook`]. Received from both the `order_book` (top 100 level snapshot) and
+/// `diff_order_book` (changed levels) channels, which are distinguished by the channel prefix.
+///
+/// eg/ {"data":{...},"channel":"diff_order_book_btcusd","event":"data"}
+///
+/// See docs: <https://www.bitstamp.net/websocket/v2/>
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct BitstampOrderBookEvent {
+    #[serde(alias = "channel")]
+    pub subscription_id: SubscriptionId,
+    pub data: BitstampOrderBook,
+}
+
+/// [`Bitstamp`](super::Bitstamp) L2 order book levels. A zero quantity level received via the
+/// `diff_order_book` channel communicates the price level should be removed.
+///
+/// eg/ {"timestamp":"1684406493","microtimestamp":"1684406493077000",
+///      "bids":[["26887","0.04570000"]],"asks":[["26888","1.20000000"]]}
+///
+/// See docs: <https://www.bitstamp.net/websocket/v2/>
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct BitstampOrderBook {
+    #[serde(
+        rename = "microtimestamp",
+        deserialize_with = "de_str_epoch_us_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+    pub bids: Vec<BitstampLevel>,
+    pub asks: Vec<BitstampLevel>,
+}
+
+/// [`Bitstamp`](super::Bitstamp) L2 order book level.
+///
+/// eg/ ["26887","0.04570000"]
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BitstampLevel {
+    #[serde(deserialize_with = "de_str")]
+    pub price: f64,
+    #[serde(deserialize_with = "de_str")]
+    pub quantity: f64,
+}
+
+impl From<BitstampLevel> for Level {
+    fn from(level: BitstampLevel) -> Self {
+        Self {
+            price: level.price,
+            quantity: level.quantity,
+        }
+    }
+}
+
+impl From<(ExchangeId, Instrument, BitstampOrderBookEvent)> for MarketEvent {
+    fn from(
+        (exchange_id, instrument, event): (ExchangeId, Instrument, BitstampOrderBookEvent),
+    ) -> Self {
+        // Bitstamp does not provide an update id, so the microsecond timestamp is used in lieu
+        let last_update_time = event.data.time;
+        let last_update_id = last_update_time.timestamp_micros() as u64;
+        let bids = event.data.bids.into_iter().map(Level::from).collect();
+        let asks = event.data.asks.into_iter().map(Level::from).collect();
+
+        let kind = if event
+            .subscription_id
+            .0
+            .starts_with(super::Bitstamp::CHANNEL_DIFF_ORDER_BOOK)
+        {
+            DataKind::OrderBookL2Delta(OrderBookL2Delta {
+                last_update_time,
+                first_update_id: None,
+                last_update_id,
+                bids,
+                asks,
+            })
+        } else {
+            DataKind::OrderBook(OrderBook {
+                last_update_time,
+                last_update_id,
+                bids,
+                asks,
+            })
+        };
+
+        Self {
+            exchange_time: last_update_time,
+            received_time: Utc::now(),
+            received_instant: None,
+            raw: None,
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind,
+        }
+    }
+}
+
+impl From<(ExchangeId, Instrument, BitstampMessage)> for MarketEvent {
+    fn from((exchange_id, instrument, message): (ExchangeId, Instrument, BitstampMessage)) -> Self {
+        let (kind, order) = match message {
+            BitstampMessage::OrderCreated(event) => (OrderBookL3DeltaKind::Open, event.data),
+            BitstampMessage::OrderChanged(event) => (OrderBookL3DeltaKind::Change, event.data),
+            BitstampMessage::OrderDeleted(event) => (OrderBookL3DeltaKind::Delete, event.data),
+            BitstampMessage::OrderBook(event) => {
+                return MarketEvent::from((exchange_id, instrument, event))
+            }
+        };
+
+        Self {
+            exchange_time: order.time,
+            received_time: Utc::now(),
+            received_instant: None,
+            raw: None,
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: DataKind::OrderBookL3Delta(OrderBookL3Delta {
+                id: order.id,
+                kind,
+                side: order.side,
+                price: order.price,
+                quantity: order.quantity,
+            }),
+        }
+    }
+}
+
+impl BitstampMessage {
+    /// Return the [`SubscriptionId`] associated with this [`BitstampMessage`].
+    pub fn subscription_id(&self) -> &SubscriptionId {
+        match self {
+            BitstampMessage::OrderCreated(event)
+            | BitstampMessage::OrderChanged(event)
+            | BitstampMessage::OrderDeleted(event) => &event.subscription_id,
+            BitstampMessage::OrderBook(event) => &event.subscription_id,
+        }
+    }
+}
+
+/// Deserialize a [`BitstampOrder`] "order_type" (0 = buy, 1 = sell) as the associated [`Side`].
+pub fn de_order_type_side<'de, D>(deserializer: D) -> Result<Side, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    let order_type: u8 = serde::de::Deserialize::deserialize(deserializer)?;
+    match order_type {
+        0 => Ok(Side::Buy),
+        1 => Ok(Side::Sell),
+        other => Err(serde::de::Error::invalid_value(
+            serde::de::Unexpected::Unsigned(other as u64),
+            &"0 (buy) or 1 (sell)",
+        )),
+    }
+}
+
+/// Deserialize a `String` epoch microseconds timestamp (eg/ "1684406493077000") as `DateTime<Utc>`.
+pub fn de_str_epoch_us_as_datetime_utc<'de, D>(deserializer: D) -> Result<DateTime<Utc>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    de_str(deserializer)
+        .map(|epoch_us| datetime_utc_from_epoch_duration(Duration::from_micros(epoch_us)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::de::Error;
+
+    #[test]
+    fn test_deserialise_bitstamp_subscription_response() {
+        struct TestCase {
+            input: &'static str,
+            expected: Result<BitstampSubResponse, SocketError>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: input response is Subscribed
+                input: r#"{"event":"bts:subscription_succeeded","channel":"live_orders_btcusd","data":{}}"#,
+                expected: Ok(BitstampSubResponse::Subscribed {
+                    channel: "live_orders_btcusd".to_owned(),
+                }),
+            },
+            TestCase {
+                // TC1: input response is Error
+                input: r#"{"event":"bts:error","channel":"","data":{"code":null,"message":"Bad subscription string."}}"#,
+                expected: Ok(BitstampSubResponse::Error {
+                    data: BitstampError {
+                        message: "Bad subscription string.".to_owned(),
+                    },
+                }),
+            },
+            TestCase {
+                // TC2: input response is malformed gibberish
+                input: r#"{"event": "gibberish", "help": "please"}"#,
+                expected: Err(SocketError::Serde {
+                    error: serde_json::Error::custom(""),
+                    payload: "".to_owned(),
+                }),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = serde_json::from_str::<BitstampSubResponse>(test.input);
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => {
+                    assert_eq!(actual, expected, "TC{} failed", index)
+                }
+                (Err(_), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_deserialise_bitstamp_message() {
+        struct TestCase {
+            input: &'static str,
+            expected: Result<BitstampMessage, SocketError>,
+        }
+
+        let order = |side| BitstampOrder {
+            id: "1614893286756353".to_owned(),
+            side,
+            price: 26887.0,
+            quantity: 0.0457,
+            time: datetime_utc_from_epoch_duration(Duration::from_micros(1684406493077000)),
+        };
+
+        let cases = vec![
+            TestCase {
+                // TC0: input is a valid sell order_created event
+                input: r#"{"data":{"id":1614893286756353,"id_str":"1614893286756353","order_type":1,"datetime":"1684406493","microtimestamp":"1684406493077000","amount":0.0457,"amount_str":"0.04570000","price":26887,"price_str":"26887"},"channel":"live_orders_btcusd","event":"order_created"}"#,
+                expected: Ok(BitstampMessage::OrderCreated(BitstampOrderEvent {
+                    subscription_id: SubscriptionId::from("live_orders_btcusd"),
+                    data: order(Side::Sell),
+                })),
+            },
+            TestCase {
+                // TC1: input is a valid buy order_deleted event
+                input: r#"{"data":{"id":1614893286756353,"id_str":"1614893286756353","order_type":0,"datetime":"1684406493","microtimestamp":"1684406493077000","amount":0.0457,"amount_str":"0.04570000","price":26887,"price_str":"26887"},"channel":"live_orders_btcusd","event":"order_deleted"}"#,
+                expected: Ok(BitstampMessage::OrderDeleted(BitstampOrderEvent {
+                    subscription_id: SubscriptionId::from("live_orders_btcusd"),
+                    data: order(Side::Buy),
+                })),
+            },
+            TestCase {
+                // TC2: input is a valid diff_order_book data event
+                input: r#"{"data":{"timestamp":"1684406493","microtimestamp":"1684406493077000","bids":[["26887","0.04570000"]],"asks":[["26888","0"]]},"channel":"diff_order_book_btcusd","event":"data"}"#,
+                expected: Ok(BitstampMessage::OrderBook(BitstampOrderBookEvent {
+                    subscription_id: SubscriptionId::from("diff_order_book_btcusd"),
+                    data: BitstampOrderBook {
+                        time: datetime_utc_from_epoch_duration(Duration::from_micros(
+                            1684406493077000,
+                        )),
+                        bids: vec![BitstampLevel {
+                            price: 26887.0,
+                            quantity: 0.0457,
+                        }],
+                        asks: vec![BitstampLevel {
+                            price: 26888.0,
+                            quantity: 0.0,
+                        }],
+                    },
+                })),
+            },
+            TestCase {
+                // TC3: input is an order event with an invalid order_type
+                input: r#"{"data":{"id":1614893286756353,"id_str":"1614893286756353","order_type":2,"datetime":"1684406493","microtimestamp":"1684406493077000","amount":0.0457,"amount_str":"0.04570000","price":26887,"price_str":"26887"},"channel":"live_orders_btcusd","event":"order_changed"}"#,
+                expected: Err(SocketError::Serde {
+                    error: serde_json::Error::custom(""),
+                    payload: "".to_owned(),
+                }),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = serde_json::from_str::<BitstampMessage>(test.input);
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => {
+                    assert_eq!(actual, expected, "TC{} failed", index)
+                }
+                (Err(_), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,1049 @@
+use self::model::{BybitMessage, BybitOrderBook, BybitOrderBookKind, BybitTicker, BybitTickerData};
+use crate::{
+    model::{
+        subscription::{Interval, SubKind, Subscription, SubscriptionIds, SubscriptionMeta},
+        DataKind,
+    },
+    ExchangeId, MarketEvent,
+};
+use barter_integration::{
+    error::SocketError,
+    model::{Exchange, SubscriptionId},
+    protocol::websocket::WsMessage,
+    Validator,
+};
+use chrono::Utc;
+use serde_json::json;
+use std::{collections::HashMap, time::Duration};
+use tokio::sync::mpsc;
+use tracing::debug;
+
+/// `Bybit` & `BybitFuturesUsd` specific data structures.
+pub mod model;
+
+/// [`Bybit`](spot::Bybit) specific [`Subscriber`](crate::Subscriber) &
+/// [`ExchangeTransformer`](crate::ExchangeTransformer) implementor for the collection of
+/// Spot data.
+pub mod spot;
+
+/// [`BybitFuturesUsd`](futures::BybitFuturesUsd) specific [`Subscriber`](crate::Subscriber) &
+/// [`ExchangeTransformer`](crate::ExchangeTransformer) implementor for the collection of
+/// USDT margined Futures data.
+pub mod futures;
+
+/// `Bybit` OrderBook channel name. The full topic also contains the depth & market.
+///
+/// eg/ "orderbook.50.BTCUSDT"
+///
+/// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/public/orderbook>
+pub const CHANNEL_ORDER_BOOK: &str = "orderbook";
+
+/// `Bybit` public trade channel name. The full topic also contains the market.
+///
+/// eg/ "publicTrade.BTCUSDT"
+///
+/// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/public/trade>
+pub const CHANNEL_TRADE: &str = "publicTrade";
+
+/// `Bybit` kline channel name. The full topic also contains the interval & market.
+///
+/// eg/ "kline.5.BTCUSDT"
+///
+/// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/public/kline>
+pub const CHANNEL_KLINE: &str = "kline";
+
+/// `Bybit` ticker channel name. The full topic also contains the market.
+///
+/// eg/ "tickers.BTCUSDT"
+///
+/// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/public/ticker>
+pub const CHANNEL_TICKER: &str = "tickers";
+
+/// Maximum number of topics `Bybit` accepts in the args of a single subscription request.
+///
+/// See docs: <https://bybit-exchange.github.io/docs/v5/ws/connect#how-to-subscribe-to-topics>
+pub const MAX_TOPICS_PER_REQUEST: usize = 10;
+
+/// Interval at which a `Bybit` application level ping is sent to keep the connection alive.
+///
+/// See docs: <https://bybit-exchange.github.io/docs/v5/ws/connect#how-to-send-the-heartbeat-packet>
+pub const PING_INTERVAL: Duration = Duration::from_secs(20);
+
+/// Uses the provided Barter [`Subscription`]s to build `Bybit` subscription payloads for the
+/// [`ExchangeId`] server. Any [`SubKind::OrderBookL2`] depth not contained in the supported
+/// `order_book_depths` is rejected.
+pub fn build_subscription_meta(
+    exchange: ExchangeId,
+    order_book_depths: &[u16],
+    subscriptions: &[Subscription],
+) -> Result<SubscriptionMeta, SocketError> {
+    // Allocate SubscriptionIds HashMap to track identifiers for each actioned Subscription
+    let mut ids = SubscriptionIds(HashMap::with_capacity(subscriptions.len()));
+
+    // Map Barter Subscriptions to Bybit topics
+    let mut topics = Vec::with_capacity(subscriptions.len());
+    for subscription in subscriptions {
+        // Determine the Bybit topic for this Barter Subscription
+        let topic = topic(exchange, order_book_depths, subscription)?;
+
+        // Use the topic as the SubscriptionId key in the SubscriptionIds
+        // eg/ SubscriptionId("orderbook.50.BTCUSDT"), SubscriptionId("tickers.BTCUSDT|funding_rate")
+        ids.insert(
+            subscription_id(&topic, &subscription.kind),
+            subscription.clone(),
+        );
+
+        // Ticker topics are shared by multiple SubKinds, so only subscribe to each topic once
+        if !topics.contains(&topic) {
+            topics.push(topic);
+        }
+    }
+
+    // Batch topics into as few subscription requests as Bybit will accept
+    let subscriptions = topics
+        .chunks(MAX_TOPICS_PER_REQUEST)
+        .map(|topics| request("subscribe", topics))
+        .collect::<Vec<_>>();
+
+    Ok(SubscriptionMeta {
+        ids,
+        expected_responses: subscriptions.len(),
+        subscriptions,
+    })
+}
+
+/// Determine the [`SubscriptionId`] associated with a `Bybit` topic & Barter [`SubKind`]. Ticker
+/// topics are shared by the [`SubKind::FundingRate`], [`SubKind::OpenInterest`] &
+/// [`SubKind::Ticker`] Subscriptions of a market, so the [`SubKind`] is appended to distinguish
+/// them.
+///
+/// eg/ SubscriptionId("orderbook.50.BTCUSDT"), SubscriptionId("tickers.BTCUSDT|open_interest")
+pub fn subscription_id(topic: &str, kind: &SubKind) -> SubscriptionId {
+    match kind {
+        SubKind::FundingRate | SubKind::OpenInterest | SubKind::Ticker => {
+            SubscriptionId::from(format!("{topic}|{kind}"))
+        }
+        _ => SubscriptionId::from(topic),
+    }
+}
+
+/// Determine the `Bybit` topic associated with an input Barter [`Subscription`].
+///
+/// eg/ "orderbook.50.BTCUSDT", "publicTrade.BTCUSDT", "kline.5.BTCUSDT", "tickers.BTCUSDT"
+pub fn topic(
+    exchange: ExchangeId,
+    order_book_depths: &[u16],
+    sub: &Subscription,
+) -> Result<String, SocketError> {
+    // Validate provided Subscription InstrumentKind is supported by the Bybit server
+    let sub = sub.validate()?;
+
+    // Determine Bybit market using the Instrument (eg/ "BTCUSDT")
+    let market = format!("{}{}", sub.instrument.base, sub.instrument.quote).to_uppercase();
+
+    match &sub.kind {
+        SubKind::OrderBookL2(depth) if order_book_depths.contains(depth) => {
+            Ok(format!("{CHANNEL_ORDER_BOOK}.{depth}.{market}"))
+        }
+        SubKind::Trade => Ok(format!("{CHANNEL_TRADE}.{market}")),
+        SubKind::Candle(interval) => Ok(format!(
+            "{CHANNEL_KLINE}.{}.{market}",
+            kline_interval(exchange, interval)?
+        )),
+        SubKind::FundingRate | SubKind::OpenInterest | SubKind::Ticker => {
+            Ok(format!("{CHANNEL_TICKER}.{market}"))
+        }
+        other => Err(SocketError::Unsupported {
+            entity: exchange.as_str(),
+            item: other.to_string(),
+        }),
+    }
+}
+
+/// Determine the `Bybit` kline interval associated with an input Barter [`Interval`].
+///
+/// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/public/kline>
+pub fn kline_interval(
+    exchange: ExchangeId,
+    interval: &Interval,
+) -> Result<&'static str, SocketError> {
+    match interval {
+        Interval::Minute1 => Ok("1"),
+        Interval::Minute3 => Ok("3"),
+        Interval::Minute5 => Ok("5"),
+        Interval::Minute15 => Ok("15"),
+        Interval::Minute30 => Ok("30"),
+        Interval::Hour1 => Ok("60"),
+        Interval::Hour2 => Ok("120"),
+        Interval::Hour4 => Ok("240"),
+        Interval::Hour6 => Ok("360"),
+        Interval::Hour12 => Ok("720"),
+        Interval::Day1 => Ok("D"),
+        Interval::Week1 => Ok("W"),
+        Interval::Month1 => Ok("M"),
+        other => Err(SocketError::Unsupported {
+            entity: exchange.as_str(),
+            item: other.to_string(),
+        }),
+    }
+}
+
+/// Build a `Bybit` compatible request message for the provided operation & topics.
+///
+/// eg/ {"op": "subscribe", "args": ["orderbook.50.BTCUSDT"]}
+pub fn request<Topic>(op: &str, topics: &[Topic]) -> WsMessage
+where
+    Topic: AsRef<str>,
+{
+    let topics = topics.iter().map(AsRef::as_ref).collect::<Vec<&str>>();
+
+    WsMessage::Text(json!({ "op": op, "args": topics }).to_string())
+}
+
+/// Spawn a task that sends a `Bybit` ping every [`PING_INTERVAL`] via the [`WsSink`] transmitter.
+/// The task ends once the WebSocket connection has been dropped.
+///
+/// [`WsSink`]: barter_integration::protocol::websocket::WsSink
+pub fn spawn_ping_task(ws_sink_tx: mpsc::UnboundedSender<WsMessage>) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(PING_INTERVAL);
+        loop {
+            interval.tick().await;
+            let ping = WsMessage::Text(json!({ "op": "ping" }).to_string());
+            if ws_sink_tx.send(ping).is_err() {
+                break;
+            }
+        }
+    });
+}
+
+/// Most recent `u` update id & `seq` cross sequence received for an active `Bybit` OrderBook
+/// topic. Used to validate the continuity of subsequent [`BybitOrderBook`] deltas.
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct BybitBookSequence {
+    pub update_id: u64,
+    pub seq: u64,
+}
+
+impl From<&BybitOrderBook> for BybitBookSequence {
+    fn from(book: &BybitOrderBook) -> Self {
+        Self {
+            update_id: book.data.update_id,
+            seq: book.data.seq,
+        }
+    }
+}
+
+/// Transforms [`BybitMessage`]s into Barter [`MarketEvent`]s on behalf of the
+/// [`Bybit`](spot::Bybit) & [`BybitFuturesUsd`](futures::BybitFuturesUsd) servers.
+///
+/// OrderBook topics are validated using the `Bybit` continuity rules:
+///  - A snapshot (or a delta with update id 1 after a Bybit service restart) resets the book.
+///  - A delta must carry the next update id, else the topic is re-subscribed to in order to
+///    receive a fresh snapshot.
+///  - A delta that is not newer than the last applied update is stale and is skipped.
+///
+/// Ticker topics are maintained from the snapshot & subsequent deltas, since deltas only contain
+/// the fields that have changed.
+#[derive(Debug)]
+pub struct BybitTransformer {
+    pub exchange: ExchangeId,
+    pub ids: SubscriptionIds,
+    pub books: HashMap<SubscriptionId, BybitBookSequence>,
+    pub tickers: HashMap<SubscriptionId, BybitTickerData>,
+    pub ws_sink_tx: mpsc::UnboundedSender<WsMessage>,
+}
+
+impl BybitTransformer {
+    /// Construct a new [`BybitTransformer`] for the provided [`ExchangeId`] server.
+    pub fn new(
+        exchange: ExchangeId,
+        ws_sink_tx: mpsc::UnboundedSender<WsMessage>,
+        ids: SubscriptionIds,
+    ) -> Self {
+        Self {
+            exchange,
+            ids,
+            books: HashMap::new(),
+            tickers: HashMap::new(),
+            ws_sink_tx,
+        }
+    }
+
+    /// Transform a [`BybitMessage`] into normalised Barter [`MarketEvent`]s.
+    pub fn transform(&mut self, input: BybitMessage) -> Vec<Result<MarketEvent, SocketError>> {
+        match input {
+            BybitMessage::OrderBook(book) => self.transform_order_book(book),
+            BybitMessage::Trades(trades) => {
+                // Determine Instrument associated with this BybitTrades message
+                let instrument = match self.ids.find_instrument(&trades.subscription_id) {
+                    Ok(instrument) => instrument,
+                    Err(error) => return vec![Err(error)],
+                };
+
+                trades
+                    .data
+                    .into_iter()
+                    .map(|trade| {
+                        Ok(MarketEvent::from((
+                            self.exchange,
+                            instrument.clone(),
+                            trade,
+                        )))
+                    })
+                    .collect()
+            }
+            BybitMessage::Candles(candles) => {
+                // Determine Instrument associated with this BybitCandles message
+                let instrument = match self.ids.find_instrument(&candles.subscription_id) {
+                    Ok(instrument) => instrument,
+                    Err(error) => return vec![Err(error)],
+                };
+
+                candles
+                    .data
+                    .into_iter()
+                    .map(|candle| {
+                        Ok(MarketEvent::from((
+                            self.exchange,
+                            instrument.clone(),
+                            candle,
+                        )))
+                    })
+                    .collect()
+            }
+            BybitMessage::Ticker(ticker) => self.transform_ticker(ticker),
+            BybitMessage::Response(response) => match response.validate() {
+                Ok(response) => {
+                    debug!(exchange_id = %self.exchange, op = %response.op, "received response");
+                    vec![]
+                }
+                Err(error) => vec![Err(error)],
+            },
+        }
+    }
+
+    /// Validate the continuity of a [`BybitOrderBook`] & transform it into a [`MarketEvent`]
+    /// containing an [`OrderBook`](crate::model::OrderBook) snapshot or an
+    /// [`OrderBookL2Delta`](crate::model::OrderBookL2Delta).
+    fn transform_order_book(
+        &mut self,
+        mut book: BybitOrderBook,
+    ) -> Vec<Result<MarketEvent, SocketError>> {
+        // Determine Instrument associated with this BybitOrderBook message
+        let instrument = match self.ids.find_instrument(&book.subscription_id) {
+            Ok(instrument) => instrument,
+            Err(error) => return vec![Err(error)],
+        };
+
+        // Delta w/ update id 1 is a snapshot sent after a Bybit service restart
+        if book.data.update_id == 1 {
+            book.kind = BybitOrderBookKind::Snapshot;
+        }
+
+        let next = BybitBookSequence::from(&book);
+
+        match book.kind {
+            BybitOrderBookKind::Snapshot => {
+                self.books.insert(book.subscription_id.clone(), next);
+            }
+            BybitOrderBookKind::Delta => match self.books.get_mut(&book.subscription_id) {
+                Some(last) if next.update_id <= last.update_id || next.seq < last.seq => {
+                    debug!(
+                        exchange_id = %self.exchange,
+                        subscription_id = %book.subscription_id,
+                        ?last,
+                        ?next,
+                        "skipping stale OrderBook delta"
+                    );
+                    return vec![];
+                }
+                Some(last) if next.update_id == last.update_id + 1 => {
+                    *last = next;
+                }
+                last => {
+                    let error = SocketError::Exchange(format!(
+                        "{} OrderBook delta is discontinuous with last: {:?}, next: {:?}",
+                        book.subscription_id, last, next
+                    ));
+                    self.books.remove(&book.subscription_id);
+                    self.resubscribe(&book.subscription_id);
+                    return vec![Err(error)];
+                }
+            },
+        }
+
+        vec![Ok(MarketEvent::from((self.exchange, instrument, book)))]
+    }
+
+    /// Merge the [`BybitTicker`] into the maintained [`BybitTickerData`] of the topic &
+    /// transform it into [`MarketEvent`]s containing a [`FundingRate`](crate::model::FundingRate),
+    /// [`OpenInterest`](crate::model::OpenInterest) and/or [`Ticker`](crate::model::Ticker) for
+    /// the subscribed [`SubKind`]s whose fields changed.
+    fn transform_ticker(&mut self, ticker: BybitTicker) -> Vec<Result<MarketEvent, SocketError>> {
+        let topic = ticker.subscription_id.as_ref();
+        let funding_rate_sub = self.ids.get(&subscription_id(topic, &SubKind::FundingRate));
+        let open_interest_sub = self
+            .ids
+            .get(&subscription_id(topic, &SubKind::OpenInterest));
+        let ticker_sub = self.ids.get(&subscription_id(topic, &SubKind::Ticker));
+        if funding_rate_sub.is_none() && open_interest_sub.is_none() && ticker_sub.is_none() {
+            return vec![Err(SocketError::Unidentifiable(ticker.subscription_id))];
+        }
+
+        let state = self
+            .tickers
+            .entry(ticker.subscription_id.clone())
+            .or_default();
+        state.merge(&ticker.data);
+
+        let funding_rate = funding_rate_sub
+            .filter(|_| {
+                ticker.data.funding_rate.is_some() || ticker.data.next_funding_time.is_some()
+            })
+            .zip(state.funding_rate())
+            .map(|(sub, funding_rate)| (sub, DataKind::FundingRate(funding_rate)));
+
+        let open_interest = open_interest_sub
+            .filter(|_| {
+                ticker.data.open_interest.is_some() || ticker.data.open_interest_value.is_some()
+            })
+            .zip(state.open_interest())
+            .map(|(sub, open_interest)| (sub, DataKind::OpenInterest(open_interest)));
+
+        let statistics = ticker_sub
+            .filter(|_| ticker.data.has_ticker_fields())
+            .zip(state.ticker())
+            .map(|(sub, statistics)| (sub, DataKind::Ticker(statistics)));
+
+        funding_rate
+            .into_iter()
+            .chain(open_interest)
+            .chain(statistics)
+            .map(|(sub, kind)| {
+                Ok(MarketEvent {
+                    exchange_time: ticker.time,
+                    received_time: Utc::now(),
+                    received_instant: None,
+                    raw: None,
+                    exchange: Exchange::from(self.exchange),
+                    instrument: sub.instrument.clone(),
+                    kind,
+                })
+            })
+            .collect()
+    }
+
+    /// Re-subscribe to the provided topic in order to receive a fresh OrderBook snapshot.
+    fn resubscribe(&self, subscription_id: &SubscriptionId) {
+        debug!(
+            exchange_id = %self.exchange,
+            %subscription_id,
+            "re-subscribing to recover OrderBook snapshot"
+        );
+        for op in ["unsubscribe", "subscribe"] {
+            let _ = self.ws_sink_tx.send(request(op, &[subscription_id]));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::model::{BybitLevel, BybitOrderBookData};
+    use super::*;
+    use crate::exchange::datetime_utc_from_epoch_duration;
+    use crate::model::{
+        subscription::Depth, FundingRate, Level, OpenInterest, OrderBook, OrderBookL2Delta, Ticker,
+    };
+    use barter_integration::model::{Exchange, Instrument, InstrumentKind};
+    use chrono::Utc;
+
+    fn bybit_transformer(
+        exchange: ExchangeId,
+        subscriptions: Vec<Subscription>,
+    ) -> (BybitTransformer, mpsc::UnboundedReceiver<WsMessage>) {
+        let (ws_sink_tx, ws_sink_rx) = mpsc::unbounded_channel();
+        let ids = SubscriptionIds(
+            subscriptions
+                .into_iter()
+                .map(|sub| {
+                    let topic = topic(exchange, &[1, 50, 200, 500], &sub).unwrap();
+                    (subscription_id(&topic, &sub.kind), sub)
+                })
+                .collect(),
+        );
+
+        (BybitTransformer::new(exchange, ws_sink_tx, ids), ws_sink_rx)
+    }
+
+    fn bybit_order_book(kind: BybitOrderBookKind, update_id: u64, seq: u64) -> BybitOrderBook {
+        BybitOrderBook {
+            subscription_id: SubscriptionId::from("orderbook.50.BTCUSDT"),
+            kind,
+            time: Utc::now(),
+            data: BybitOrderBookData {
+                bids: vec![BybitLevel {
+                    price: 100.0,
+                    quantity: 1.0,
+                }],
+                asks: vec![],
+                update_id,
+                seq,
+            },
+        }
+    }
+
+    #[test]
+    fn test_topic() {
+        struct TestCase {
+            exchange: ExchangeId,
+            depths: &'static [u16],
+            input: Subscription,
+            expected: Result<String, SocketError>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: Supported Spot OrderBookL2 w/ supported depth
+                exchange: ExchangeId::Bybit,
+                depths: spot::Bybit::ORDER_BOOK_DEPTHS,
+                input: Subscription::new(
+                    ExchangeId::Bybit,
+                    ("btc", "usdt", InstrumentKind::Spot),
+                    SubKind::OrderBookL2(Depth::from(50)),
+                ),
+                expected: Ok("orderbook.50.BTCUSDT".to_owned()),
+            },
+            TestCase {
+                // TC1: Unsupported Spot OrderBookL2 w/ depth only supported by BybitFuturesUsd
+                exchange: ExchangeId::Bybit,
+                depths: spot::Bybit::ORDER_BOOK_DEPTHS,
+                input: Subscription::new(
+                    ExchangeId::Bybit,
+                    ("btc", "usdt", InstrumentKind::Spot),
+                    SubKind::OrderBookL2(Depth::from(500)),
+                ),
+                expected: Err(SocketError::Unsupported {
+                    entity: "",
+                    item: "".to_string(),
+                }),
+            },
+            TestCase {
+                // TC2: Supported FuturePerpetual OrderBookL2 w/ supported depth
+                exchange: ExchangeId::BybitFuturesUsd,
+                depths: futures::BybitFuturesUsd::ORDER_BOOK_DEPTHS,
+                input: Subscription::new(
+                    ExchangeId::BybitFuturesUsd,
+                    ("eth", "usdt", InstrumentKind::FuturePerpetual),
+                    SubKind::OrderBookL2(Depth::from(500)),
+                ),
+                expected: Ok("orderbook.500.ETHUSDT".to_owned()),
+            },
+            TestCase {
+                // TC3: Unsupported FuturePerpetual OrderBookL2 w/ unknown depth
+                exchange: ExchangeId::BybitFuturesUsd,
+                depths: futures::BybitFuturesUsd::ORDER_BOOK_DEPTHS,
+                input: Subscription::new(
+                    ExchangeId::BybitFuturesUsd,
+                    ("eth", "usdt", InstrumentKind::FuturePerpetual),
+                    SubKind::OrderBookL2(Depth::from(25)),
+                ),
+                expected: Err(SocketError::Unsupported {
+                    entity: "",
+                    item: "".to_string(),
+                }),
+            },
+            TestCase {
+                // TC4: Unsupported Spot Instrument on BybitFuturesUsd
+                exchange: ExchangeId::BybitFuturesUsd,
+                depths: futures::BybitFuturesUsd::ORDER_BOOK_DEPTHS,
+                input: Subscription::new(
+                    ExchangeId::BybitFuturesUsd,
+                    ("eth", "usdt", InstrumentKind::Spot),
+                    SubKind::OrderBookL2(Depth::from(50)),
+                ),
+                expected: Err(SocketError::Unsupported {
+                    entity: "",
+                    item: "".to_string(),
+                }),
+            },
+            TestCase {
+                // TC5: Unsupported Spot OrderBook snapshot SubKind
+                exchange: ExchangeId::Bybit,
+                depths: spot::Bybit::ORDER_BOOK_DEPTHS,
+                input: Subscription::new(
+                    ExchangeId::Bybit,
+                    ("btc", "usdt", InstrumentKind::Spot),
+                    SubKind::OrderBook,
+                ),
+                expected: Err(SocketError::Unsupported {
+                    entity: "",
+                    item: "".to_string(),
+                }),
+            },
+            TestCase {
+                // TC6: Supported Spot trades
+                exchange: ExchangeId::Bybit,
+                depths: spot::Bybit::ORDER_BOOK_DEPTHS,
+                input: Subscription::new(
+                    ExchangeId::Bybit,
+                    ("btc", "usdt", InstrumentKind::Spot),
+                    SubKind::Trade,
+                ),
+                expected: Ok("publicTrade.BTCUSDT".to_owned()),
+            },
+            TestCase {
+                // TC7: Supported FuturePerpetual candles
+                exchange: ExchangeId::BybitFuturesUsd,
+                depths: futures::BybitFuturesUsd::ORDER_BOOK_DEPTHS,
+                input: Subscription::new(
+                    ExchangeId::BybitFuturesUsd,
+                    ("eth", "usdt", InstrumentKind::FuturePerpetual),
+                    SubKind::Candle(Interval::Hour1),
+                ),
+                expected: Ok("kline.60.ETHUSDT".to_owned()),
+            },
+            TestCase {
+                // TC8: Unsupported candles Interval
+                exchange: ExchangeId::Bybit,
+                depths: spot::Bybit::ORDER_BOOK_DEPTHS,
+                input: Subscription::new(
+                    ExchangeId::Bybit,
+                    ("btc", "usdt", InstrumentKind::Spot),
+                    SubKind::Candle(Interval::Day3),
+                ),
+                expected: Err(SocketError::Unsupported {
+                    entity: "",
+                    item: "".to_string(),
+                }),
+            },
+            TestCase {
+                // TC9: Supported FuturePerpetual funding rates
+                exchange: ExchangeId::BybitFuturesUsd,
+                depths: futures::BybitFuturesUsd::ORDER_BOOK_DEPTHS,
+                input: Subscription::new(
+                    ExchangeId::BybitFuturesUsd,
+                    ("btc", "usdt", InstrumentKind::FuturePerpetual),
+                    SubKind::FundingRate,
+                ),
+                expected: Ok("tickers.BTCUSDT".to_owned()),
+            },
+            TestCase {
+                // TC10: Unsupported Spot funding rates
+                exchange: ExchangeId::Bybit,
+                depths: spot::Bybit::ORDER_BOOK_DEPTHS,
+                input: Subscription::new(
+                    ExchangeId::Bybit,
+                    ("btc", "usdt", InstrumentKind::Spot),
+                    SubKind::FundingRate,
+                ),
+                expected: Err(SocketError::Unsupported {
+                    entity: "",
+                    item: "".to_string(),
+                }),
+            },
+            TestCase {
+                // TC11: Supported FuturePerpetual open interest
+                exchange: ExchangeId::BybitFuturesUsd,
+                depths: futures::BybitFuturesUsd::ORDER_BOOK_DEPTHS,
+                input: Subscription::new(
+                    ExchangeId::BybitFuturesUsd,
+                    ("btc", "usdt", InstrumentKind::FuturePerpetual),
+                    SubKind::OpenInterest,
+                ),
+                expected: Ok("tickers.BTCUSDT".to_owned()),
+            },
+            TestCase {
+                // TC12: Supported Spot ticker
+                exchange: ExchangeId::Bybit,
+                depths: spot::Bybit::ORDER_BOOK_DEPTHS,
+                input: Subscription::new(
+                    ExchangeId::Bybit,
+                    ("btc", "usdt", InstrumentKind::Spot),
+                    SubKind::Ticker,
+                ),
+                expected: Ok("tickers.BTCUSDT".to_owned()),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = topic(test.exchange, test.depths, &test.input);
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => {
+                    assert_eq!(actual, expected, "TC{} failed", index)
+                }
+                (Err(_), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_build_subscription_meta_batches_topics() {
+        let subscriptions = (0..11)
+            .map(|index| {
+                Subscription::new(
+                    ExchangeId::Bybit,
+                    (
+                        format!("base{index}"),
+                        "usdt".to_owned(),
+                        InstrumentKind::Spot,
+                    ),
+                    SubKind::OrderBookL2(Depth::from(1)),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let meta = build_subscription_meta(ExchangeId::Bybit, &[1], &subscriptions).unwrap();
+
+        assert_eq!(meta.ids.len(), 11);
+        assert_eq!(meta.subscriptions.len(), 2);
+        assert_eq!(meta.expected_responses, 2);
+    }
+
+    #[test]
+    fn test_bybit_transform_ticker() {
+        let (mut transformer, _ws_sink_rx) = bybit_transformer(
+            ExchangeId::BybitFuturesUsd,
+            vec![
+                Subscription::new(
+                    ExchangeId::BybitFuturesUsd,
+                    ("btc", "usdt", InstrumentKind::FuturePerpetual),
+                    SubKind::FundingRate,
+                ),
+                Subscription::new(
+                    ExchangeId::BybitFuturesUsd,
+                    ("btc", "usdt", InstrumentKind::FuturePerpetual),
+                    SubKind::OpenInterest,
+                ),
+                Subscription::new(
+                    ExchangeId::BybitFuturesUsd,
+                    ("btc", "usdt", InstrumentKind::FuturePerpetual),
+                    SubKind::Ticker,
+                ),
+            ],
+        );
+
+        let time = Utc::now();
+        let next_funding_time = 1673280000000;
+        let ticker = |funding_rate, next_funding_time, open_interest| {
+            BybitMessage::Ticker(BybitTicker {
+                subscription_id: SubscriptionId::from("tickers.BTCUSDT"),
+                time,
+                data: BybitTickerData {
+                    symbol: "BTCUSDT".to_owned(),
+                    funding_rate,
+                    next_funding_time,
+                    open_interest,
+                    ..BybitTickerData::default()
+                },
+            })
+        };
+        let statistics = |last_price, volume_24h| {
+            BybitMessage::Ticker(BybitTicker {
+                subscription_id: SubscriptionId::from("tickers.BTCUSDT"),
+                time,
+                data: BybitTickerData {
+                    symbol: "BTCUSDT".to_owned(),
+                    last_price,
+                    volume_24h,
+                    high_price_24h: Some(120.0),
+                    low_price_24h: Some(90.0),
+                    price_24h_pcnt: Some(0.1),
+                    ..BybitTickerData::default()
+                },
+            })
+        };
+        let funding_rate = |rate| {
+            DataKind::FundingRate(FundingRate {
+                rate,
+                next_funding_time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                    next_funding_time,
+                )),
+            })
+        };
+        let open_interest = |quantity| {
+            DataKind::OpenInterest(OpenInterest {
+                quantity,
+                notional: None,
+            })
+        };
+
+        struct TestCase {
+            input: BybitMessage,
+            expected: Vec<DataKind>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: snapshot w/ funding & open interest fields
+                input: ticker(Some(0.0001), Some(next_funding_time), Some(10.0)),
+                expected: vec![funding_rate(0.0001), open_interest(10.0)],
+            },
+            TestCase {
+                // TC1: delta w/o funding & open interest fields produces no MarketEvents
+                input: ticker(None, None, None),
+                expected: vec![],
+            },
+            TestCase {
+                // TC2: delta w/ only a changed funding rate is merged w/ the next funding time
+                input: ticker(Some(-0.0002), None, None),
+                expected: vec![funding_rate(-0.0002)],
+            },
+            TestCase {
+                // TC3: delta w/ only a changed open interest
+                input: ticker(None, None, Some(12.5)),
+                expected: vec![open_interest(12.5)],
+            },
+            TestCase {
+                // TC4: delta w/ rolling 24 hour statistics
+                input: statistics(Some(110.0), Some(500.0)),
+                expected: vec![DataKind::Ticker(Ticker {
+                    last_price: 110.0,
+                    volume_24h: 500.0,
+                    high_24h: 120.0,
+                    low_24h: 90.0,
+                    change_percent_24h: 10.0,
+                })],
+            },
+            TestCase {
+                // TC5: delta w/ only a changed last price is merged w/ the known statistics
+                input: statistics(Some(111.0), None),
+                expected: vec![DataKind::Ticker(Ticker {
+                    last_price: 111.0,
+                    volume_24h: 500.0,
+                    high_24h: 120.0,
+                    low_24h: 90.0,
+                    change_percent_24h: 10.0,
+                })],
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = transformer
+                .transform(test.input)
+                .into_iter()
+                .map(|event| event.unwrap().kind)
+                .collect::<Vec<_>>();
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_build_subscription_meta_shares_ticker_topics() {
+        let subscriptions =
+            [SubKind::FundingRate, SubKind::OpenInterest, SubKind::Ticker].map(|kind| {
+                Subscription::new(
+                    ExchangeId::BybitFuturesUsd,
+                    ("btc", "usdt", InstrumentKind::FuturePerpetual),
+                    kind,
+                )
+            });
+
+        let meta =
+            build_subscription_meta(ExchangeId::BybitFuturesUsd, &[1], &subscriptions).unwrap();
+
+        assert_eq!(meta.ids.len(), 3);
+        assert_eq!(
+            meta.subscriptions,
+            vec![request("subscribe", &["tickers.BTCUSDT"])]
+        );
+    }
+
+    #[test]
+    fn test_bybit_transform_order_book_continuity() {
+        let (mut transformer, mut ws_sink_rx) = bybit_transformer(
+            ExchangeId::BybitFuturesUsd,
+            vec![Subscription::new(
+                ExchangeId::BybitFuturesUsd,
+                ("btc", "usdt", InstrumentKind::FuturePerpetual),
+                SubKind::OrderBookL2(Depth::from(50)),
+            )],
+        );
+
+        let time = Utc::now();
+        let instrument = Instrument::from(("btc", "usdt", InstrumentKind::FuturePerpetual));
+
+        struct TestCase {
+            input: BybitOrderBook,
+            expected: Vec<Result<MarketEvent, SocketError>>,
+            expected_resubscribe: bool,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: delta received before any snapshot is discontinuous
+                input: bybit_order_book(BybitOrderBookKind::Delta, 10, 100),
+                expected: vec![Err(SocketError::Exchange("".to_owned()))],
+                expected_resubscribe: true,
+            },
+            TestCase {
+                // TC1: snapshot resets the book
+                input: bybit_order_book(BybitOrderBookKind::Snapshot, 20, 200),
+                expected: vec![Ok(MarketEvent {
+                    exchange_time: time,
+                    received_time: time,
+                    received_instant: None,
+                    raw: None,
+                    exchange: Exchange::from(ExchangeId::BybitFuturesUsd),
+                    instrument: instrument.clone(),
+                    kind: DataKind::OrderBook(OrderBook {
+                        last_update_time: time,
+                        last_update_id: 20,
+                        bids: vec![Level::new(100.0, 1.0)],
+                        asks: vec![],
+                    }),
+                })],
+                expected_resubscribe: false,
+            },
+            TestCase {
+                // TC2: continuous delta is applied
+                input: bybit_order_book(BybitOrderBookKind::Delta, 21, 201),
+                expected: vec![Ok(MarketEvent {
+                    exchange_time: time,
+                    received_time: time,
+                    received_instant: None,
+                    raw: None,
+                    exchange: Exchange::from(ExchangeId::BybitFuturesUsd),
+                    instrument: instrument.clone(),
+                    kind: DataKind::OrderBookL2Delta(OrderBookL2Delta {
+                        last_update_time: time,
+                        first_update_id: None,
+                        last_update_id: 21,
+                        bids: vec![Level::new(100.0, 1.0)],
+                        asks: vec![],
+                    }),
+                })],
+                expected_resubscribe: false,
+            },
+            TestCase {
+                // TC3: stale delta is skipped
+                input: bybit_order_book(BybitOrderBookKind::Delta, 21, 201),
+                expected: vec![],
+                expected_resubscribe: false,
+            },
+            TestCase {
+                // TC4: delta w/ update id gap is discontinuous & triggers a re-subscribe
+                input: bybit_order_book(BybitOrderBookKind::Delta, 23, 203),
+                expected: vec![Err(SocketError::Exchange("".to_owned()))],
+                expected_resubscribe: true,
+            },
+            TestCase {
+                // TC5: delta w/ update id 1 after a Bybit service restart is a snapshot
+                input: bybit_order_book(BybitOrderBookKind::Delta, 1, 300),
+                expected: vec![Ok(MarketEvent {
+                    exchange_time: time,
+                    received_time: time,
+                    received_instant: None,
+                    raw: None,
+                    exchange: Exchange::from(ExchangeId::BybitFuturesUsd),
+                    instrument: instrument.clone(),
+                    kind: DataKind::OrderBook(OrderBook {
+                        last_update_time: time,
+                        last_update_id: 1,
+                        bids: vec![Level::new(100.0, 1.0)],
+                        asks: vec![],
+                    }),
+                })],
+                expected_resubscribe: false,
+            },
+            TestCase {
+                // TC6: delta continuous with the restart snapshot is applied
+                input: bybit_order_book(BybitOrderBookKind::Delta, 2, 301),
+                expected: vec![Ok(MarketEvent {
+                    exchange_time: time,
+                    received_time: time,
+                    received_instant: None,
+                    raw: None,
+                    exchange: Exchange::from(ExchangeId::BybitFuturesUsd),
+                    instrument,
+                    kind: DataKind::OrderBookL2Delta(OrderBookL2Delta {
+                        last_update_time: time,
+                        first_update_id: None,
+                        last_update_id: 2,
+                        bids: vec![Level::new(100.0, 1.0)],
+                        asks: vec![],
+                    }),
+                })],
+                expected_resubscribe: false,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = transformer.transform(BybitMessage::OrderBook(test.input));
+            assert_eq!(
+                actual.len(),
+                test.expected.len(),
+                "TestCase {} failed at vector length assert_eq with actual: {:?}",
+                index,
+                actual
+            );
+
+            for (vector_index, (actual, expected)) in
+                actual.into_iter().zip(test.expected).enumerate()
+            {
+                match (actual, expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        // Scrub Utc::now() timestamps to allow comparison
+                        let actual = MarketEvent {
+                            exchange_time: time,
+                            received_time: time,
+                            received_instant: None,
+                            raw: None,
+                            kind: match actual.kind {
+                                DataKind::OrderBook(book) => DataKind::OrderBook(OrderBook {
+                                    last_update_time: time,
+                                    ..book
+                                }),
+                                DataKind::OrderBookL2Delta(delta) => {
+                                    DataKind::OrderBookL2Delta(OrderBookL2Delta {
+                                        last_update_time: time,
+                                        first_update_id: None,
+                                        ..delta
+                                    })
+                                }
+                                other => other,
+                            },
+                            ..actual
+                        };
+                        assert_eq!(
+                            actual, expected,
+                            "TC{} failed at vector index {}",
+                            index, vector_index
+                        )
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed at vector index {vector_index} because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+
+            let resubscribed = match (ws_sink_rx.try_recv(), ws_sink_rx.try_recv()) {
+                (Ok(WsMessage::Text(unsubscribe)), Ok(WsMessage::Text(subscribe))) => {
+                    assert!(unsubscribe.contains("unsubscribe"), "TC{} failed", index);
+                    assert!(
+                        subscribe.contains("orderbook.50.BTCUSDT"),
+                        "TC{} failed",
+                        index
+                    );
+                    true
+                }
+                _ => false,
+            };
+            assert_eq!(
+                resubscribed, test.expected_resubscribe,
+                "TC{} failed",
+                index
+            );
+        }
+    }
+}
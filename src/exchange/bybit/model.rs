@@ -0,0 +1,714 @@
+use crate::{
+    exchange::{datetime_utc_from_epoch_duration, de_str, de_str_opt},
+    model::{
+        Candle, DataKind, FundingRate, Level, OpenInterest, OrderBook, OrderBookL2Delta,
+        PublicTrade, Ticker,
+    },
+    ExchangeId, MarketEvent,
+};
+use barter_integration::{
+    error::SocketError,
+    model::{Exchange, Instrument, Side, SubscriptionId},
+    Validator,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// `Bybit` & `BybitFuturesUsd` message received in response to WebSocket requests
+/// (eg/ subscribe, ping).
+///
+/// eg/ BybitSubResponse {"success":true,"ret_msg":"","conn_id":"2324d924","req_id":"","op":"subscribe"}
+/// eg/ BybitSubResponse {"success":false,"ret_msg":"error:handler not found","op":"subscribe"}
+///
+/// See docs: <https://bybit-exchange.github.io/docs/v5/ws/connect#how-to-subscribe-to-topics>
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct BybitSubResponse {
+    pub op: String,
+    #[serde(default)]
+    pub success: Option<bool>,
+    #[serde(default)]
+    pub ret_msg: String,
+}
+
+impl Validator for BybitSubResponse {
+    fn validate(self) -> Result<Self, SocketError>
+    where
+        Self: Sized,
+    {
+        match self.success {
+            Some(false) => Err(SocketError::Subscribe(format!(
+                "received failure {} response: {}",
+                self.op, self.ret_msg
+            ))),
+            _ => Ok(self),
+        }
+    }
+}
+
+/// `Bybit` & `BybitFuturesUsd` message variants that can be received over
+/// [`WebSocket`](crate::WebSocket).
+///
+/// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/public/orderbook>
+/// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/public/trade>
+/// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/public/kline>
+/// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/public/ticker>
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum BybitMessage {
+    OrderBook(BybitOrderBook),
+    Trades(BybitTrades),
+    Candles(BybitCandles),
+    Ticker(BybitTicker),
+    Response(BybitSubResponse),
+}
+
+/// `Bybit` OrderBook message. The [`BybitOrderBookKind`] communicates if the message is a
+/// full snapshot of the requested depth, or a delta to be applied to the previous snapshot.
+///
+/// eg/ {
+///     "topic":"orderbook.50.BTCUSDT",
+///     "type":"snapshot",
+///     "ts":1672304484978,
+///     "data":{"s":"BTCUSDT","b":[["16493.50","0.006"]],"a":[["16611.00","0.029"]],"u":18521288,"seq":7961638724},
+///     "cts":1672304484976
+/// }
+///
+/// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/public/orderbook>
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct BybitOrderBook {
+    #[serde(alias = "topic")]
+    pub subscription_id: SubscriptionId,
+
+    #[serde(alias = "type")]
+    pub kind: BybitOrderBookKind,
+
+    #[serde(
+        alias = "ts",
+        deserialize_with = "crate::exchange::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+
+    pub data: BybitOrderBookData,
+}
+
+/// Type of [`BybitOrderBook`] message.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BybitOrderBookKind {
+    Snapshot,
+    Delta,
+}
+
+/// `Bybit` OrderBook levels & sequencing data.
+///
+/// Note that `update_id` (u) increments by one with every message on a topic, and `seq` is a
+/// cross sequence that can be used to compare data across different depth topics.
+///
+/// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/public/orderbook>
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct BybitOrderBookData {
+    #[serde(alias = "b")]
+    pub bids: Vec<BybitLevel>,
+
+    #[serde(alias = "a")]
+    pub asks: Vec<BybitLevel>,
+
+    #[serde(alias = "u", deserialize_with = "crate::exchange::de_str")]
+    pub update_id: u64,
+
+    #[serde(deserialize_with = "crate::exchange::de_str")]
+    pub seq: u64,
+}
+
+/// `Bybit` OrderBook level. A zero quantity in a [`BybitOrderBookKind::Delta`] communicates the
+/// price level should be removed.
+///
+/// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/public/orderbook>
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BybitLevel {
+    #[serde(deserialize_with = "de_str")]
+    pub price: f64,
+    #[serde(deserialize_with = "de_str")]
+    pub quantity: f64,
+}
+
+/// `Bybit` public trade message containing one or more [`BybitTrade`]s.
+///
+/// eg/ {"topic":"publicTrade.BTCUSDT","type":"snapshot","ts":1672304486868,"data":[{...}]}
+///
+/// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/public/trade>
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct BybitTrades {
+    #[serde(alias = "topic")]
+    pub subscription_id: SubscriptionId,
+    pub data: Vec<BybitTrade>,
+}
+
+/// `Bybit` public trade.
+///
+/// eg/ {"T":1672304486865,"s":"BTCUSDT","S":"Buy","v":"0.001","p":"16578.50","L":"PlusTick",
+///      "i":"20f43950-d8dd-5b31-9112-a178eb6023af","BT":false}
+///
+/// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/public/trade>
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BybitTrade {
+    #[serde(alias = "i")]
+    pub id: String,
+    #[serde(alias = "p", deserialize_with = "de_str")]
+    pub price: f64,
+    #[serde(alias = "v", deserialize_with = "de_str")]
+    pub quantity: f64,
+    #[serde(alias = "S")]
+    pub side: Side,
+    #[serde(
+        alias = "T",
+        deserialize_with = "crate::exchange::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+}
+
+/// `Bybit` kline message containing one or more [`BybitCandle`]s.
+///
+/// eg/ {"topic":"kline.5.BTCUSDT","type":"snapshot","ts":1672324988882,"data":[{...}]}
+///
+/// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/public/kline>
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct BybitCandles {
+    #[serde(alias = "topic")]
+    pub subscription_id: SubscriptionId,
+    pub data: Vec<BybitCandle>,
+}
+
+/// `Bybit` OHLCV kline.
+///
+/// eg/ {"start":1672324800000,"end":1672325099999,"interval":"5","open":"16649.5",
+///      "close":"16677","high":"16677","low":"16608","volume":"2.081","turnover":"34666.4005",
+///      "confirm":false,"timestamp":1672324988882}
+///
+/// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/public/kline>
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BybitCandle {
+    #[serde(
+        alias = "start",
+        deserialize_with = "crate::exchange::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub start_time: DateTime<Utc>,
+    #[serde(
+        alias = "end",
+        deserialize_with = "crate::exchange::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub end_time: DateTime<Utc>,
+    #[serde(deserialize_with = "de_str")]
+    pub open: f64,
+    #[serde(deserialize_with = "de_str")]
+    pub high: f64,
+    #[serde(deserialize_with = "de_str")]
+    pub low: f64,
+    #[serde(deserialize_with = "de_str")]
+    pub close: f64,
+    #[serde(deserialize_with = "de_str")]
+    pub volume: f64,
+}
+
+/// `Bybit` ticker message. `BybitFuturesUsd` sends a snapshot of every field, followed by deltas
+/// that only contain the fields that have changed.
+///
+/// eg/ {"topic":"tickers.BTCUSDT","type":"snapshot","ts":1673272861686,"cs":24987956059,"data":{...}}
+///
+/// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/public/ticker>
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct BybitTicker {
+    #[serde(alias = "topic")]
+    pub subscription_id: SubscriptionId,
+
+    #[serde(
+        alias = "ts",
+        deserialize_with = "crate::exchange::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+
+    pub data: BybitTickerData,
+}
+
+/// `Bybit` ticker fields, where fields absent from a delta are `None`.
+///
+/// eg/ {"symbol":"BTCUSDT","lastPrice":"17216.00","fundingRate":"-0.000212",
+///      "nextFundingTime":"1673280000000","openInterest":"68744.761",
+///      "openInterestValue":"1183601235.91", ...}
+///
+/// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/public/ticker>
+#[derive(Clone, PartialEq, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BybitTickerData {
+    pub symbol: String,
+    #[serde(default, deserialize_with = "de_str_opt")]
+    pub funding_rate: Option<f64>,
+    /// Next funding time in epoch milliseconds.
+    #[serde(default, deserialize_with = "de_str_opt")]
+    pub next_funding_time: Option<u64>,
+    #[serde(default, deserialize_with = "de_str_opt")]
+    pub open_interest: Option<f64>,
+    #[serde(default, deserialize_with = "de_str_opt")]
+    pub open_interest_value: Option<f64>,
+    #[serde(default, deserialize_with = "de_str_opt")]
+    pub last_price: Option<f64>,
+    #[serde(default, deserialize_with = "de_str_opt")]
+    pub high_price_24h: Option<f64>,
+    #[serde(default, deserialize_with = "de_str_opt")]
+    pub low_price_24h: Option<f64>,
+    /// Rolling 24 hour volume denominated in the base asset.
+    #[serde(default, deserialize_with = "de_str_opt")]
+    pub volume_24h: Option<f64>,
+    /// Rolling 24 hour price change as a fraction (eg/ "0.0215" is 2.15%).
+    #[serde(default, deserialize_with = "de_str_opt")]
+    pub price_24h_pcnt: Option<f64>,
+}
+
+impl BybitTickerData {
+    /// Update this [`BybitTickerData`] with the fields present in the provided delta.
+    pub fn merge(&mut self, delta: &BybitTickerData) {
+        self.funding_rate = delta.funding_rate.or(self.funding_rate);
+        self.next_funding_time = delta.next_funding_time.or(self.next_funding_time);
+        self.open_interest = delta.open_interest.or(self.open_interest);
+        self.open_interest_value = delta.open_interest_value.or(self.open_interest_value);
+        self.last_price = delta.last_price.or(self.last_price);
+        self.high_price_24h = delta.high_price_24h.or(self.high_price_24h);
+        self.low_price_24h = delta.low_price_24h.or(self.low_price_24h);
+        self.volume_24h = delta.volume_24h.or(self.volume_24h);
+        self.price_24h_pcnt = delta.price_24h_pcnt.or(self.price_24h_pcnt);
+    }
+
+    /// Determines if this [`BybitTickerData`] contains any rolling 24 hour [`Ticker`] fields.
+    pub fn has_ticker_fields(&self) -> bool {
+        self.last_price.is_some()
+            || self.high_price_24h.is_some()
+            || self.low_price_24h.is_some()
+            || self.volume_24h.is_some()
+            || self.price_24h_pcnt.is_some()
+    }
+
+    /// Determine the [`FundingRate`] communicated by this [`BybitTickerData`], if both the
+    /// funding rate & next funding time are known.
+    pub fn funding_rate(&self) -> Option<FundingRate> {
+        Some(FundingRate {
+            rate: self.funding_rate?,
+            next_funding_time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                self.next_funding_time?,
+            )),
+        })
+    }
+
+    /// Determine the [`OpenInterest`] communicated by this [`BybitTickerData`], if the open
+    /// interest is known.
+    pub fn open_interest(&self) -> Option<OpenInterest> {
+        Some(OpenInterest {
+            quantity: self.open_interest?,
+            notional: self.open_interest_value,
+        })
+    }
+
+    /// Determine the rolling 24 hour [`Ticker`] communicated by this [`BybitTickerData`], if all
+    /// of it's fields are known.
+    pub fn ticker(&self) -> Option<Ticker> {
+        Some(Ticker {
+            last_price: self.last_price?,
+            volume_24h: self.volume_24h?,
+            high_24h: self.high_price_24h?,
+            low_24h: self.low_price_24h?,
+            change_percent_24h: self.price_24h_pcnt? * 100.0,
+        })
+    }
+}
+
+impl From<BybitLevel> for Level {
+    fn from(level: BybitLevel) -> Self {
+        Self {
+            price: level.price,
+            quantity: level.quantity,
+        }
+    }
+}
+
+impl From<(ExchangeId, Instrument, BybitOrderBook)> for MarketEvent {
+    fn from((exchange_id, instrument, book): (ExchangeId, Instrument, BybitOrderBook)) -> Self {
+        let bids = book.data.bids.into_iter().map(Level::from).collect();
+        let asks = book.data.asks.into_iter().map(Level::from).collect();
+
+        let kind = match book.kind {
+            BybitOrderBookKind::Snapshot => DataKind::OrderBook(OrderBook {
+                last_update_time: book.time,
+                last_update_id: book.data.update_id,
+                bids,
+                asks,
+            }),
+            BybitOrderBookKind::Delta => DataKind::OrderBookL2Delta(OrderBookL2Delta {
+                last_update_time: book.time,
+                first_update_id: None,
+                last_update_id: book.data.update_id,
+                bids,
+                asks,
+            }),
+        };
+
+        Self {
+            exchange_time: book.time,
+            received_time: Utc::now(),
+            received_instant: None,
+            raw: None,
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind,
+        }
+    }
+}
+
+impl From<(ExchangeId, Instrument, BybitTrade)> for MarketEvent {
+    fn from((exchange_id, instrument, trade): (ExchangeId, Instrument, BybitTrade)) -> Self {
+        Self {
+            exchange_time: trade.time,
+            received_time: Utc::now(),
+            received_instant: None,
+            raw: None,
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: DataKind::Trade(PublicTrade {
+                id: trade.id,
+                price: trade.price,
+                quantity: trade.quantity,
+                side: trade.side,
+            }),
+        }
+    }
+}
+
+impl From<(ExchangeId, Instrument, BybitCandle)> for MarketEvent {
+    fn from((exchange_id, instrument, candle): (ExchangeId, Instrument, BybitCandle)) -> Self {
+        Self {
+            exchange_time: candle.end_time,
+            received_time: Utc::now(),
+            received_instant: None,
+            raw: None,
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: DataKind::Candle(Candle {
+                start_time: candle.start_time,
+                end_time: candle.end_time,
+                open: candle.open,
+                high: candle.high,
+                low: candle.low,
+                close: candle.close,
+                volume: candle.volume,
+                trade_count: 0,
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::de::Error;
+
+    #[test]
+    fn test_deserialise_bybit_subscription_response() {
+        struct TestCase {
+            input: &'static str,
+            expected: Result<BybitSubResponse, SocketError>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: input response is Subscribed
+                input: r#"{"success":true,"ret_msg":"","conn_id":"2324d924","req_id":"","op":"subscribe"}"#,
+                expected: Ok(BybitSubResponse {
+                    op: "subscribe".to_owned(),
+                    success: Some(true),
+                    ret_msg: "".to_owned(),
+                }),
+            },
+            TestCase {
+                // TC1: input response is failed subscription
+                input: r#"{"success":false,"ret_msg":"error:handler not found","conn_id":"2324d924","op":"subscribe"}"#,
+                expected: Ok(BybitSubResponse {
+                    op: "subscribe".to_owned(),
+                    success: Some(false),
+                    ret_msg: "error:handler not found".to_owned(),
+                }),
+            },
+            TestCase {
+                // TC2: input response is a BybitFuturesUsd pong w/o success field
+                input: r#"{"req_id":"","op":"pong","args":["1675418560633"],"conn_id":"cfcb4ocsvfriu23r3er0"}"#,
+                expected: Ok(BybitSubResponse {
+                    op: "pong".to_owned(),
+                    success: None,
+                    ret_msg: "".to_owned(),
+                }),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = serde_json::from_str::<BybitSubResponse>(test.input);
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => {
+                    assert_eq!(actual, expected, "TC{} failed", index)
+                }
+                (Err(_), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_validate_bybit_subscription_response() {
+        struct TestCase {
+            input_response: BybitSubResponse,
+            is_valid: bool,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: input response is successful subscription
+                input_response: BybitSubResponse {
+                    op: "subscribe".to_owned(),
+                    success: Some(true),
+                    ret_msg: "".to_owned(),
+                },
+                is_valid: true,
+            },
+            TestCase {
+                // TC1: input response is failed subscription
+                input_response: BybitSubResponse {
+                    op: "subscribe".to_owned(),
+                    success: Some(false),
+                    ret_msg: "error:handler not found".to_owned(),
+                },
+                is_valid: false,
+            },
+            TestCase {
+                // TC2: input response is pong w/o success field
+                input_response: BybitSubResponse {
+                    op: "pong".to_owned(),
+                    success: None,
+                    ret_msg: "".to_owned(),
+                },
+                is_valid: true,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = test.input_response.validate().is_ok();
+            assert_eq!(actual, test.is_valid, "TestCase {} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_deserialise_bybit_message() {
+        struct TestCase {
+            input: &'static str,
+            expected: Result<BybitMessage, SocketError>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: valid BybitMessage OrderBook snapshot
+                input: r#"{
+                    "topic":"orderbook.50.BTCUSDT","type":"snapshot","ts":1672304484978,
+                    "data":{
+                        "s":"BTCUSDT","b":[["16493.50","0.006"],["16493.00","0.100"]],
+                        "a":[["16611.00","0.029"]],"u":18521288,"seq":7961638724
+                    },
+                    "cts":1672304484976
+                }"#,
+                expected: Ok(BybitMessage::OrderBook(BybitOrderBook {
+                    subscription_id: SubscriptionId::from("orderbook.50.BTCUSDT"),
+                    kind: BybitOrderBookKind::Snapshot,
+                    time: datetime_utc_from_epoch_duration(Duration::from_millis(1672304484978)),
+                    data: BybitOrderBookData {
+                        bids: vec![
+                            BybitLevel {
+                                price: 16493.50,
+                                quantity: 0.006,
+                            },
+                            BybitLevel {
+                                price: 16493.00,
+                                quantity: 0.100,
+                            },
+                        ],
+                        asks: vec![BybitLevel {
+                            price: 16611.00,
+                            quantity: 0.029,
+                        }],
+                        update_id: 18521288,
+                        seq: 7961638724,
+                    },
+                })),
+            },
+            TestCase {
+                // TC1: valid BybitMessage OrderBook delta w/ level removal
+                input: r#"{
+                    "topic":"orderbook.50.BTCUSDT","type":"delta","ts":1672304484980,
+                    "data":{
+                        "s":"BTCUSDT","b":[["16493.50","0"]],"a":[],"u":18521289,"seq":7961638725
+                    },
+                    "cts":1672304484979
+                }"#,
+                expected: Ok(BybitMessage::OrderBook(BybitOrderBook {
+                    subscription_id: SubscriptionId::from("orderbook.50.BTCUSDT"),
+                    kind: BybitOrderBookKind::Delta,
+                    time: datetime_utc_from_epoch_duration(Duration::from_millis(1672304484980)),
+                    data: BybitOrderBookData {
+                        bids: vec![BybitLevel {
+                            price: 16493.50,
+                            quantity: 0.0,
+                        }],
+                        asks: vec![],
+                        update_id: 18521289,
+                        seq: 7961638725,
+                    },
+                })),
+            },
+            TestCase {
+                // TC2: valid BybitMessage Response to a Bybit ping
+                input: r#"{"success":true,"ret_msg":"pong","conn_id":"0970e817","op":"ping"}"#,
+                expected: Ok(BybitMessage::Response(BybitSubResponse {
+                    op: "ping".to_owned(),
+                    success: Some(true),
+                    ret_msg: "pong".to_owned(),
+                })),
+            },
+            TestCase {
+                // TC3: valid BybitMessage public Trades
+                input: r#"{
+                    "topic":"publicTrade.BTCUSDT","type":"snapshot","ts":1672304486868,
+                    "data":[{
+                        "T":1672304486865,"s":"BTCUSDT","S":"Buy","v":"0.001","p":"16578.50",
+                        "L":"PlusTick","i":"20f43950-d8dd-5b31-9112-a178eb6023af","BT":false
+                    }]
+                }"#,
+                expected: Ok(BybitMessage::Trades(BybitTrades {
+                    subscription_id: SubscriptionId::from("publicTrade.BTCUSDT"),
+                    data: vec![BybitTrade {
+                        id: "20f43950-d8dd-5b31-9112-a178eb6023af".to_owned(),
+                        price: 16578.50,
+                        quantity: 0.001,
+                        side: Side::Buy,
+                        time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1672304486865,
+                        )),
+                    }],
+                })),
+            },
+            TestCase {
+                // TC4: valid BybitMessage kline Candles
+                input: r#"{
+                    "topic":"kline.5.BTCUSDT","type":"snapshot","ts":1672324988882,
+                    "data":[{
+                        "start":1672324800000,"end":1672325099999,"interval":"5",
+                        "open":"16649.5","close":"16677","high":"16677","low":"16608",
+                        "volume":"2.081","turnover":"34666.4005","confirm":false,
+                        "timestamp":1672324988882
+                    }]
+                }"#,
+                expected: Ok(BybitMessage::Candles(BybitCandles {
+                    subscription_id: SubscriptionId::from("kline.5.BTCUSDT"),
+                    data: vec![BybitCandle {
+                        start_time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1672324800000,
+                        )),
+                        end_time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1672325099999,
+                        )),
+                        open: 16649.5,
+                        high: 16677.0,
+                        low: 16608.0,
+                        close: 16677.0,
+                        volume: 2.081,
+                    }],
+                })),
+            },
+            TestCase {
+                // TC5: invalid BybitMessage OrderBook w/ unknown type
+                input: r#"{
+                    "topic":"orderbook.50.BTCUSDT","type":"unknown","ts":1672304484980,
+                    "data":{"s":"BTCUSDT","b":[],"a":[],"u":18521289,"seq":7961638725}
+                }"#,
+                expected: Err(SocketError::Serde {
+                    error: serde_json::Error::custom(""),
+                    payload: "".to_owned(),
+                }),
+            },
+            TestCase {
+                // TC6: valid BybitMessage linear Ticker snapshot
+                input: r#"{
+                    "topic":"tickers.BTCUSDT","type":"snapshot","ts":1673272861686,
+                    "data":{
+                        "symbol":"BTCUSDT","tickDirection":"PlusTick","price24hPcnt":"0.017103",
+                        "lastPrice":"17216.00","highPrice24h":"17281.50","lowPrice24h":"16915.00",
+                        "markPrice":"17217.33","volume24h":"91705.276","fundingRate":"-0.000212",
+                        "nextFundingTime":"1673280000000","openInterest":"68744.761"
+                    },
+                    "cs":24987956059
+                }"#,
+                expected: Ok(BybitMessage::Ticker(BybitTicker {
+                    subscription_id: SubscriptionId::from("tickers.BTCUSDT"),
+                    time: datetime_utc_from_epoch_duration(Duration::from_millis(1673272861686)),
+                    data: BybitTickerData {
+                        symbol: "BTCUSDT".to_owned(),
+                        funding_rate: Some(-0.000212),
+                        next_funding_time: Some(1673280000000),
+                        open_interest: Some(68744.761),
+                        open_interest_value: None,
+                        last_price: Some(17216.0),
+                        high_price_24h: Some(17281.5),
+                        low_price_24h: Some(16915.0),
+                        volume_24h: Some(91705.276),
+                        price_24h_pcnt: Some(0.017103),
+                    },
+                })),
+            },
+            TestCase {
+                // TC7: valid BybitMessage linear Ticker delta w/o funding fields
+                input: r#"{
+                    "topic":"tickers.BTCUSDT","type":"delta","ts":1673272861786,
+                    "data":{"symbol":"BTCUSDT","lastPrice":"17217.00"},
+                    "cs":24987956060
+                }"#,
+                expected: Ok(BybitMessage::Ticker(BybitTicker {
+                    subscription_id: SubscriptionId::from("tickers.BTCUSDT"),
+                    time: datetime_utc_from_epoch_duration(Duration::from_millis(1673272861786)),
+                    data: BybitTickerData {
+                        symbol: "BTCUSDT".to_owned(),
+                        funding_rate: None,
+                        next_funding_time: None,
+                        open_interest: None,
+                        open_interest_value: None,
+                        last_price: Some(17217.0),
+                        ..BybitTickerData::default()
+                    },
+                })),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = serde_json::from_str::<BybitMessage>(test.input);
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => {
+                    assert_eq!(actual, expected, "TC{} failed", index)
+                }
+                (Err(_), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+}
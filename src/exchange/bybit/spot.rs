@@ -0,0 +1,58 @@
+use super::{
+    build_subscription_meta, model::BybitMessage, model::BybitSubResponse, BybitTransformer,
+};
+use crate::{
+    model::{subscription::SubscriptionMeta, MarketEvent},
+    ExchangeId, ExchangeTransformer, Subscriber, Subscription, SubscriptionIds,
+};
+use barter_integration::{error::SocketError, protocol::websocket::WsMessage, Transformer};
+use tokio::sync::mpsc;
+
+/// [`Bybit`] [`Subscriber`](crate::Subscriber) &
+/// [`ExchangeTransformer`](crate::ExchangeTransformer) implementor for the collection
+/// of `Spot` data.
+#[derive(Debug)]
+pub struct Bybit {
+    pub transformer: BybitTransformer,
+}
+
+impl Subscriber for Bybit {
+    type SubResponse = BybitSubResponse;
+
+    fn base_url() -> &'static str {
+        ExchangeId::Bybit.ws_base_url()
+    }
+
+    fn build_subscription_meta(
+        subscriptions: &[Subscription],
+    ) -> Result<SubscriptionMeta, SocketError> {
+        build_subscription_meta(Self::EXCHANGE, Self::ORDER_BOOK_DEPTHS, subscriptions)
+    }
+}
+
+impl ExchangeTransformer for Bybit {
+    const EXCHANGE: ExchangeId = ExchangeId::Bybit;
+
+    fn new(ws_sink_tx: mpsc::UnboundedSender<WsMessage>, ids: SubscriptionIds) -> Self {
+        super::spawn_ping_task(ws_sink_tx.clone());
+        Self {
+            transformer: BybitTransformer::new(Self::EXCHANGE, ws_sink_tx, ids),
+        }
+    }
+}
+
+impl Transformer<MarketEvent> for Bybit {
+    type Input = BybitMessage;
+    type OutputIter = Vec<Result<MarketEvent, SocketError>>;
+
+    fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+        self.transformer.transform(input)
+    }
+}
+
+impl Bybit {
+    /// [`Bybit`] Spot OrderBook depths that can be subscribed to.
+    ///
+    /// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/public/orderbook>
+    pub const ORDER_BOOK_DEPTHS: &'static [u16] = &[1, 50, 200];
+}
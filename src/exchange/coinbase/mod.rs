@@ -14,6 +14,14 @@ use tokio::sync::mpsc;
 /// [`Coinbase`] specific data structures.
 mod model;
 
+/// [`CoinbaseSandbox`](sandbox::CoinbaseSandbox) [`Subscriber`] & [`ExchangeTransformer`]
+/// implementor for the collection of data from the Coinbase sandbox environment.
+pub mod sandbox;
+
+/// Authenticated [`Coinbase`] user channel, distributing the lifecycle of the account's orders
+/// as [`OrderUpdate`](crate::model::OrderUpdate)s.
+pub mod user;
+
 /// [`Coinbase`] [`Subscriber`] & [`ExchangeTransformer`] implementor for the collection
 /// of `Spot` & `Futures` data.
 #[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
@@ -25,7 +33,7 @@ impl Subscriber for Coinbase {
     type SubResponse = CoinbaseSubResponse;
 
     fn base_url() -> &'static str {
-        "wss://ws-feed.exchange.coinbase.com"
+        ExchangeId::Coinbase.ws_base_url()
     }
 
     fn build_subscription_meta(
@@ -84,6 +92,28 @@ impl Transformer<MarketEvent> for Coinbase {
                     Err(error) => vec![Err(error)],
                 }
             }
+            CoinbaseMessage::Status(status) => {
+                // Status messages contain every product, so only map those subscribed to
+                status
+                    .products
+                    .iter()
+                    .filter_map(|product| {
+                        self.ids
+                            .0
+                            .get(&Coinbase::subscription_id(
+                                Coinbase::CHANNEL_STATUS,
+                                &product.id,
+                            ))
+                            .map(|subscription| {
+                                Ok(MarketEvent::from((
+                                    Coinbase::EXCHANGE,
+                                    subscription.instrument.clone(),
+                                    product,
+                                )))
+                            })
+                    })
+                    .collect()
+            }
         }
     }
 }
@@ -94,6 +124,17 @@ impl Coinbase {
     /// See docs: <https://docs.cloud.coinbase.com/exchange/docs/websocket-channels#match>
     pub const CHANNEL_TRADES: &'static str = "matches";
 
+    /// [`Coinbase`] status channel name, providing the trading status of every product.
+    ///
+    /// See docs: <https://docs.cloud.coinbase.com/exchange/docs/websocket-channels#status-channel>
+    pub const CHANNEL_STATUS: &'static str = "status";
+
+    /// [`Coinbase`] authenticated user channel name, providing the full channel messages of the
+    /// account's orders.
+    ///
+    /// See docs: <https://docs.cloud.coinbase.com/exchange/docs/websocket-channels#user-channel>
+    pub const CHANNEL_USER: &'static str = "user";
+
     /// Determine the [`Coinbase`] channel metadata associated with an input Barter [`Subscription`].
     /// This includes the [`Coinbase`] &str channel, and a `String` market identifier. Both are
     /// used to build an [`Coinbase`] subscription payload.
@@ -107,6 +148,7 @@ impl Coinbase {
         // Determine Coinbase channel using the Subscription SubKind
         let channel = match &sub.kind {
             SubKind::Trade => Self::CHANNEL_TRADES,
+            SubKind::InstrumentStatus => Self::CHANNEL_STATUS,
             other => {
                 return Err(SocketError::Unsupported {
                     entity: Self::EXCHANGE.as_str(),
@@ -217,6 +259,15 @@ mod tests {
                     item: "".to_string(),
                 }),
             },
+            TestCase {
+                // TC3: Supported InstrumentKind::Spot instrument status subscription
+                input: Subscription::new(
+                    ExchangeId::Coinbase,
+                    ("btc", "usd", InstrumentKind::Spot),
+                    SubKind::InstrumentStatus,
+                ),
+                expected: Ok(("status", "BTC-USD".to_owned())),
+            },
         ];
 
         for (index, test) in cases.into_iter().enumerate() {
@@ -267,6 +318,8 @@ mod tests {
                 expected: vec![Ok(MarketEvent {
                     exchange_time: time,
                     received_time: time,
+                    received_instant: None,
+                    raw: None,
                     exchange: Exchange::from(ExchangeId::Coinbase),
                     instrument: Instrument::from(("btc", "usd", InstrumentKind::Spot)),
                     kind: DataKind::Trade(PublicTrade {
@@ -313,6 +366,8 @@ mod tests {
                         // Scrub Utc::now() timestamps to allow comparison
                         let actual = MarketEvent {
                             received_time: time,
+                            received_instant: None,
+                            raw: None,
                             ..actual
                         };
                         assert_eq!(
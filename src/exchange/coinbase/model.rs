@@ -1,7 +1,7 @@
 use super::Coinbase;
 use crate::{
     exchange::de_str,
-    model::{DataKind, PublicTrade},
+    model::{DataKind, InstrumentStatus, PublicTrade},
     ExchangeId, MarketEvent, Validator,
 };
 use barter_integration::{
@@ -63,6 +63,8 @@ impl Validator for CoinbaseSubResponse {
 pub enum CoinbaseMessage {
     #[serde(alias = "match", alias = "last_match")]
     Trade(CoinbaseTrade),
+    #[serde(rename = "status")]
+    Status(CoinbaseStatus),
 }
 
 /// [`Coinbase`] trade message.
@@ -72,7 +74,7 @@ pub enum CoinbaseMessage {
 pub struct CoinbaseTrade {
     #[serde(alias = "product_id", deserialize_with = "de_trade_subscription_id")]
     pub subscription_id: SubscriptionId,
-    #[serde(alias = "trade_id")]
+    #[serde(alias = "trade_id", deserialize_with = "crate::exchange::de_str")]
     pub id: u64,
     pub time: DateTime<Utc>,
     #[serde(alias = "size", deserialize_with = "de_str")]
@@ -87,6 +89,8 @@ impl From<(ExchangeId, Instrument, CoinbaseTrade)> for MarketEvent {
         Self {
             exchange_time: trade.time,
             received_time: Utc::now(),
+            received_instant: None,
+            raw: None,
             exchange: Exchange::from(exchange_id),
             instrument,
             kind: DataKind::Trade(PublicTrade {
@@ -99,6 +103,62 @@ impl From<(ExchangeId, Instrument, CoinbaseTrade)> for MarketEvent {
     }
 }
 
+/// [`Coinbase`] status message containing the trading status of every product.
+///
+/// See docs: <https://docs.cloud.coinbase.com/exchange/docs/websocket-channels#status-channel>
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct CoinbaseStatus {
+    pub products: Vec<CoinbaseProductStatus>,
+}
+
+/// [`Coinbase`] product trading status contained in a [`CoinbaseStatus`] message.
+///
+/// eg/ {"id":"BTC-USD","status":"online","status_message":"","cancel_only":false,"limit_only":false,"post_only":false,"trading_disabled":false}
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct CoinbaseProductStatus {
+    pub id: String,
+    pub status: String,
+    #[serde(default)]
+    pub cancel_only: bool,
+    #[serde(default)]
+    pub limit_only: bool,
+    #[serde(default)]
+    pub post_only: bool,
+    #[serde(default)]
+    pub trading_disabled: bool,
+}
+
+impl From<&CoinbaseProductStatus> for InstrumentStatus {
+    fn from(product: &CoinbaseProductStatus) -> Self {
+        match product.status.as_str() {
+            "delisted" => InstrumentStatus::Delisted,
+            "online" if product.trading_disabled => InstrumentStatus::Halted,
+            "online" if product.cancel_only => InstrumentStatus::CancelOnly,
+            "online" if product.post_only => InstrumentStatus::PostOnly,
+            "online" if product.limit_only => InstrumentStatus::LimitOnly,
+            "online" => InstrumentStatus::Trading,
+            _ => InstrumentStatus::Halted,
+        }
+    }
+}
+
+impl From<(ExchangeId, Instrument, &CoinbaseProductStatus)> for MarketEvent {
+    fn from(
+        (exchange_id, instrument, product): (ExchangeId, Instrument, &CoinbaseProductStatus),
+    ) -> Self {
+        let now = Utc::now();
+        Self {
+            exchange_time: now,
+            received_time: now,
+            received_instant: None,
+            raw: None,
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: DataKind::InstrumentStatus(InstrumentStatus::from(product)),
+        }
+    }
+}
+
 /// Deserialize a [`CoinbaseTrade`] "product_id" (eg/ "BTC-USD") as the associated [`SubscriptionId`]
 /// (eg/ SubscriptionId("matches|BTC-USD").
 pub fn de_trade_subscription_id<'de, D>(deserializer: D) -> Result<SubscriptionId, D::Error>
@@ -238,6 +298,27 @@ mod tests {
                     ),
                 })),
             },
+            TestCase {
+                // TC2: valid CoinbaseMessage status
+                input: r#"{
+                    "type": "status", "currencies": [],
+                    "products": [{
+                        "id": "BTC-USD", "base_currency": "BTC", "quote_currency": "USD",
+                        "status": "online", "status_message": "", "cancel_only": false,
+                        "limit_only": false, "post_only": true, "trading_disabled": false
+                    }]
+                }"#,
+                expected: Ok(CoinbaseMessage::Status(CoinbaseStatus {
+                    products: vec![CoinbaseProductStatus {
+                        id: "BTC-USD".to_owned(),
+                        status: "online".to_owned(),
+                        cancel_only: false,
+                        limit_only: false,
+                        post_only: true,
+                        trading_disabled: false,
+                    }],
+                })),
+            },
         ];
 
         for (index, test) in cases.into_iter().enumerate() {
@@ -256,4 +337,69 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_coinbase_product_status_to_instrument_status() {
+        struct TestCase {
+            input: CoinbaseProductStatus,
+            expected: InstrumentStatus,
+        }
+
+        let product = |status: &str| CoinbaseProductStatus {
+            id: "BTC-USD".to_owned(),
+            status: status.to_owned(),
+            cancel_only: false,
+            limit_only: false,
+            post_only: false,
+            trading_disabled: false,
+        };
+
+        let cases = vec![
+            TestCase {
+                // TC0: online product w/o restrictions is Trading
+                input: product("online"),
+                expected: InstrumentStatus::Trading,
+            },
+            TestCase {
+                // TC1: online product w/ trading disabled is Halted
+                input: CoinbaseProductStatus {
+                    trading_disabled: true,
+                    ..product("online")
+                },
+                expected: InstrumentStatus::Halted,
+            },
+            TestCase {
+                // TC2: online product in cancel only mode is CancelOnly
+                input: CoinbaseProductStatus {
+                    cancel_only: true,
+                    post_only: true,
+                    ..product("online")
+                },
+                expected: InstrumentStatus::CancelOnly,
+            },
+            TestCase {
+                // TC3: online product in limit only mode is LimitOnly
+                input: CoinbaseProductStatus {
+                    limit_only: true,
+                    ..product("online")
+                },
+                expected: InstrumentStatus::LimitOnly,
+            },
+            TestCase {
+                // TC4: offline product is Halted
+                input: product("offline"),
+                expected: InstrumentStatus::Halted,
+            },
+            TestCase {
+                // TC5: delisted product is Delisted
+                input: product("delisted"),
+                expected: InstrumentStatus::Delisted,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = InstrumentStatus::from(&test.input);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
 }
@@ -0,0 +1,73 @@
+use super::{
+    model::{CoinbaseMessage, CoinbaseSubResponse},
+    Coinbase,
+};
+use crate::{
+    model::subscription::{SubscriptionIds, SubscriptionMeta},
+    ExchangeId, ExchangeTransformer, MarketEvent, Subscriber, Subscription,
+};
+use barter_integration::{error::SocketError, protocol::websocket::WsMessage, Transformer};
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc;
+
+/// [`CoinbaseSandbox`] [`Subscriber`] & [`ExchangeTransformer`] implementor for the collection
+/// of `Spot` data from the Coinbase sandbox environment.
+///
+/// Channels & messages are identical to [`Coinbase`], so subscriptions are actioned & messages
+/// are transformed by an inner [`Coinbase`]. Enabled via
+/// [`StreamBuilder::sandbox`](crate::builder::StreamBuilder::sandbox).
+///
+/// See docs: <https://docs.cloud.coinbase.com/exchange/docs/sandbox>
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct CoinbaseSandbox {
+    pub coinbase: Coinbase,
+}
+
+impl Subscriber for CoinbaseSandbox {
+    type SubResponse = CoinbaseSubResponse;
+
+    fn base_url() -> &'static str {
+        ExchangeId::Coinbase
+            .sandbox_ws_base_url()
+            .expect("Coinbase provides a sandbox WebSocket API")
+    }
+
+    fn build_subscription_meta(
+        subscriptions: &[Subscription],
+    ) -> Result<SubscriptionMeta, SocketError> {
+        Coinbase::build_subscription_meta(subscriptions)
+    }
+}
+
+impl ExchangeTransformer for CoinbaseSandbox {
+    const EXCHANGE: ExchangeId = ExchangeId::Coinbase;
+
+    fn new(ws_sink_tx: mpsc::UnboundedSender<WsMessage>, ids: SubscriptionIds) -> Self {
+        Self {
+            coinbase: Coinbase::new(ws_sink_tx, ids),
+        }
+    }
+}
+
+impl Transformer<MarketEvent> for CoinbaseSandbox {
+    type Input = CoinbaseMessage;
+    type OutputIter = Vec<Result<MarketEvent, SocketError>>;
+
+    fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+        self.coinbase.transform(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_coinbase_sandbox_base_url() {
+        assert_eq!(
+            CoinbaseSandbox::base_url(),
+            "wss://ws-feed-public.sandbox.exchange.coinbase.com"
+        );
+        assert_ne!(CoinbaseSandbox::base_url(), Coinbase::base_url());
+    }
+}
@@ -0,0 +1,598 @@
+use super::Coinbase;
+use crate::{
+    model::{subscription::SubscriptionIds, DataKind, OrderFill, OrderStatus, OrderUpdate},
+    ExchangeId, MarketEvent, Subscriber,
+};
+use barter_integration::{
+    error::SocketError,
+    model::{Exchange, Instrument, Side, SubscriptionId},
+    protocol::websocket::{connect, WsMessage},
+};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use chrono::{DateTime, Utc};
+use futures::{SinkExt, StreamExt};
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use sha2::Sha256;
+use std::collections::HashMap;
+use tokio::sync::mpsc;
+use tracing::{debug, info, warn};
+
+/// [`Coinbase`] API key credentials used to sign authenticated subscription requests.
+///
+/// See docs: <https://docs.cloud.coinbase.com/exchange/docs/websocket-auth>
+#[derive(Clone, Eq, PartialEq, Deserialize, Serialize)]
+pub struct CoinbaseCredentials {
+    pub key: String,
+    /// Base64 encoded API secret.
+    pub secret: String,
+    pub passphrase: String,
+}
+
+impl std::fmt::Debug for CoinbaseCredentials {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CoinbaseCredentials")
+            .field("key", &self.key)
+            .finish_non_exhaustive()
+    }
+}
+
+impl CoinbaseCredentials {
+    /// Request path signed to authenticate a WebSocket subscription.
+    pub const SIGNATURE_PATH: &'static str = "/users/self/verify";
+
+    /// Generate the base64 encoded HMAC-SHA256 signature of an authenticated subscription
+    /// request sent at the provided unix timestamp (seconds).
+    pub fn sign(&self, timestamp: i64) -> Result<String, SocketError> {
+        let secret = STANDARD.decode(&self.secret).map_err(|error| {
+            SocketError::Subscribe(format!("invalid base64 Coinbase API secret: {error}"))
+        })?;
+
+        let mut mac = Hmac::<Sha256>::new_from_slice(&secret).map_err(|error| {
+            SocketError::Subscribe(format!("invalid Coinbase API secret: {error}"))
+        })?;
+        mac.update(format!("{timestamp}GET{}", Self::SIGNATURE_PATH).as_bytes());
+
+        Ok(STANDARD.encode(mac.finalize().into_bytes()))
+    }
+}
+
+/// Build a signed [`Coinbase`] user channel subscription message for the provided markets
+/// (eg/ "BTC-USD"), sent at the provided unix timestamp (seconds).
+pub fn user_subscription(
+    credentials: &CoinbaseCredentials,
+    markets: &[String],
+    timestamp: i64,
+) -> Result<WsMessage, SocketError> {
+    Ok(WsMessage::Text(
+        json!({
+            "type": "subscribe",
+            "product_ids": markets,
+            "channels": [Coinbase::CHANNEL_USER],
+            "signature": credentials.sign(timestamp)?,
+            "key": credentials.key,
+            "passphrase": credentials.passphrase,
+            "timestamp": timestamp.to_string(),
+        })
+        .to_string(),
+    ))
+}
+
+/// [`Coinbase`] user channel message variants, describing the lifecycle of the account's orders
+/// with the same messages as the full channel.
+///
+/// See docs: <https://docs.cloud.coinbase.com/exchange/docs/websocket-channels#user-channel>
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum CoinbaseUserMessage {
+    Received(CoinbaseUserReceived),
+    Open(CoinbaseUserOpen),
+    Match(CoinbaseUserMatch),
+    Change(CoinbaseUserChange),
+    Done(CoinbaseUserDone),
+}
+
+impl CoinbaseUserMessage {
+    /// Determine the [`SubscriptionId`] associated with this [`CoinbaseUserMessage`].
+    pub fn subscription_id(&self) -> &SubscriptionId {
+        match self {
+            CoinbaseUserMessage::Received(received) => &received.subscription_id,
+            CoinbaseUserMessage::Open(open) => &open.subscription_id,
+            CoinbaseUserMessage::Match(fill) => &fill.subscription_id,
+            CoinbaseUserMessage::Change(change) => &change.subscription_id,
+            CoinbaseUserMessage::Done(done) => &done.subscription_id,
+        }
+    }
+}
+
+/// [`Coinbase`] user channel message sent when an order is received by the matching engine.
+///
+/// eg/ {"type":"received","time":"2014-11-07T08:19:27.028459Z","product_id":"BTC-USD",
+///      "sequence":10,"order_id":"d50ec984-77a8-460a-b958-66f114b0de9b","size":"1.34",
+///      "price":"502.1","side":"buy","order_type":"limit","client_oid":"d50ec974-..."}
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct CoinbaseUserReceived {
+    #[serde(alias = "product_id", deserialize_with = "de_user_subscription_id")]
+    pub subscription_id: SubscriptionId,
+    pub order_id: String,
+    #[serde(alias = "client_oid", default)]
+    pub client_id: Option<String>,
+    /// Limit price, or `None` for market orders.
+    #[serde(default, deserialize_with = "de_option_str")]
+    pub price: Option<f64>,
+    /// Order size, or `None` for market orders specifying funds.
+    #[serde(alias = "size", default, deserialize_with = "de_option_str")]
+    pub quantity: Option<f64>,
+    pub side: Side,
+    pub time: DateTime<Utc>,
+}
+
+/// [`Coinbase`] user channel message sent when an order is resting in the book.
+///
+/// eg/ {"type":"open","time":"2014-11-07T08:19:27.028459Z","product_id":"BTC-USD",
+///      "sequence":10,"order_id":"d50ec984-77a8-460a-b958-66f114b0de9b","price":"200.2",
+///      "remaining_size":"1.00","side":"sell"}
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct CoinbaseUserOpen {
+    #[serde(alias = "product_id", deserialize_with = "de_user_subscription_id")]
+    pub subscription_id: SubscriptionId,
+    pub order_id: String,
+    #[serde(deserialize_with = "crate::exchange::de_str")]
+    pub price: f64,
+    #[serde(alias = "remaining_size", deserialize_with = "crate::exchange::de_str")]
+    pub remaining: f64,
+    pub side: Side,
+    pub time: DateTime<Utc>,
+}
+
+/// [`Coinbase`] user channel message sent when an order of the account is filled, either as the
+/// maker or the taker. The side is always the side of the maker order, and the taker user
+/// identifiers are only present if the account was the taker.
+///
+/// eg/ {"type":"match","trade_id":10,"sequence":50,"maker_order_id":"ac928c66-...",
+///      "taker_order_id":"132fb6ae-...","time":"2014-11-07T08:19:27.028459Z",
+///      "product_id":"BTC-USD","size":"5.23512","price":"400.23","side":"sell",
+///      "taker_user_id":"5844eceecf7e803e259d0365","taker_profile_id":"765d1549-..."}
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct CoinbaseUserMatch {
+    #[serde(alias = "product_id", deserialize_with = "de_user_subscription_id")]
+    pub subscription_id: SubscriptionId,
+    #[serde(deserialize_with = "crate::exchange::de_str")]
+    pub trade_id: u64,
+    pub maker_order_id: String,
+    pub taker_order_id: String,
+    #[serde(default)]
+    pub taker_profile_id: Option<String>,
+    #[serde(deserialize_with = "crate::exchange::de_str")]
+    pub price: f64,
+    #[serde(alias = "size", deserialize_with = "crate::exchange::de_str")]
+    pub quantity: f64,
+    pub side: Side,
+    pub time: DateTime<Utc>,
+}
+
+impl CoinbaseUserMatch {
+    /// Determines if the account's order was the maker order of this [`CoinbaseUserMatch`].
+    pub fn is_maker(&self) -> bool {
+        self.taker_profile_id.is_none()
+    }
+}
+
+/// [`Coinbase`] user channel message sent when the quantity of an order resting in the book
+/// changes (eg/ due to self trade prevention).
+///
+/// eg/ {"type":"change","reason":"STP","time":"2014-11-07T08:19:27.028459Z","sequence":80,
+///      "order_id":"ac928c66-...","side":"sell","product_id":"BTC-USD","old_size":"12.234412",
+///      "new_size":"5.23512","price":"400.23"}
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct CoinbaseUserChange {
+    #[serde(alias = "product_id", deserialize_with = "de_user_subscription_id")]
+    pub subscription_id: SubscriptionId,
+    pub order_id: String,
+    #[serde(default, deserialize_with = "de_option_str")]
+    pub price: Option<f64>,
+    #[serde(alias = "new_size", deserialize_with = "crate::exchange::de_str")]
+    pub quantity: f64,
+    pub side: Side,
+    pub time: DateTime<Utc>,
+}
+
+/// [`Coinbase`] user channel message sent when an order is no longer resting in the book.
+///
+/// eg/ {"type":"done","time":"2014-11-07T08:19:27.028459Z","product_id":"BTC-USD",
+///      "sequence":10,"price":"200.2","order_id":"d50ec984-...","reason":"filled",
+///      "side":"sell","remaining_size":"0"}
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct CoinbaseUserDone {
+    #[serde(alias = "product_id", deserialize_with = "de_user_subscription_id")]
+    pub subscription_id: SubscriptionId,
+    pub order_id: String,
+    pub reason: CoinbaseDoneReason,
+    #[serde(alias = "remaining_size", default, deserialize_with = "de_option_str")]
+    pub remaining: Option<f64>,
+    pub side: Side,
+    pub time: DateTime<Utc>,
+}
+
+/// Reason a [`Coinbase`] order is no longer resting in the book.
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CoinbaseDoneReason {
+    Filled,
+    Canceled,
+}
+
+/// Latest [`OrderUpdate`] of each open order of the account, folded from the
+/// [`CoinbaseUserMessage`]s of a [`Coinbase`] user channel. Orders are removed once done.
+///
+/// User channel messages only describe the change to an order, so the quantities of an order
+/// first seen after it was received are only known from that point onwards.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct CoinbaseUserOrders {
+    pub orders: HashMap<String, OrderUpdate>,
+}
+
+impl CoinbaseUserOrders {
+    /// Apply the provided [`CoinbaseUserMessage`] to the state of it's order, returning the
+    /// resulting [`OrderUpdate`].
+    pub fn update(&mut self, message: CoinbaseUserMessage) -> OrderUpdate {
+        match message {
+            CoinbaseUserMessage::Received(received) => {
+                let quantity = received.quantity.unwrap_or_default();
+                let order = OrderUpdate {
+                    id: received.order_id,
+                    client_id: received.client_id,
+                    side: received.side,
+                    status: OrderStatus::Received,
+                    price: received.price.unwrap_or_default(),
+                    quantity,
+                    filled: 0.0,
+                    remaining: quantity,
+                    fill: None,
+                    time: received.time,
+                };
+                self.orders.insert(order.id.clone(), order.clone());
+                order
+            }
+            CoinbaseUserMessage::Open(open) => {
+                let order = self.order(open.order_id, open.side, open.time);
+                order.status = OrderStatus::New;
+                order.price = open.price;
+                order.remaining = open.remaining;
+                order.quantity = order.quantity.max(order.filled + open.remaining);
+                order.clone()
+            }
+            CoinbaseUserMessage::Match(fill) => {
+                let is_maker = fill.is_maker();
+
+                // Match side is the maker order side, so the taker order is the opposite side
+                let (id, side) = match (is_maker, fill.side) {
+                    (true, side) => (fill.maker_order_id, side),
+                    (false, Side::Buy) => (fill.taker_order_id, Side::Sell),
+                    (false, Side::Sell) => (fill.taker_order_id, Side::Buy),
+                };
+
+                let order = self.order(id, side, fill.time);
+                order.status = OrderStatus::PartiallyFilled;
+                order.filled += fill.quantity;
+                order.remaining = (order.remaining - fill.quantity).max(0.0);
+                order.quantity = order.quantity.max(order.filled);
+                order.fill = Some(OrderFill {
+                    trade_id: fill.trade_id.to_string(),
+                    price: fill.price,
+                    quantity: fill.quantity,
+                    is_maker,
+                });
+                order.clone()
+            }
+            CoinbaseUserMessage::Change(change) => {
+                let order = self.order(change.order_id, change.side, change.time);
+                if let Some(price) = change.price {
+                    order.price = price;
+                }
+                order.remaining = change.quantity;
+                order.quantity = order.filled + change.quantity;
+                order.clone()
+            }
+            CoinbaseUserMessage::Done(done) => {
+                let mut order = self.order(done.order_id, done.side, done.time).clone();
+                self.orders.remove(&order.id);
+                order.status = match done.reason {
+                    CoinbaseDoneReason::Filled => OrderStatus::Filled,
+                    CoinbaseDoneReason::Canceled => OrderStatus::Cancelled,
+                };
+                order.remaining = done.remaining.unwrap_or(order.remaining);
+                order
+            }
+        }
+    }
+
+    /// Determine the [`OrderUpdate`] of the provided order id, updated at the provided time, with
+    /// any previous [`OrderFill`] cleared. Orders not yet seen are inserted w/o any quantities.
+    fn order(&mut self, id: String, side: Side, time: DateTime<Utc>) -> &mut OrderUpdate {
+        let order = self
+            .orders
+            .entry(id.clone())
+            .or_insert_with(|| OrderUpdate {
+                id,
+                client_id: None,
+                side,
+                status: OrderStatus::New,
+                price: 0.0,
+                quantity: 0.0,
+                filled: 0.0,
+                remaining: 0.0,
+                fill: None,
+                time,
+            });
+        order.time = time;
+        order.fill = None;
+        order
+    }
+}
+
+/// Initialise a [`Coinbase`] user channel stream of the account associated with the provided
+/// [`CoinbaseCredentials`], for the orders of the provided [`Instrument`]s.
+///
+/// A signed user channel subscription is sent & validated, and each order lifecycle message
+/// received is distributed as an [`OrderUpdate`] [`MarketEvent`] (see [`CoinbaseUserOrders`]). The returned receiver is closed
+/// when the connection is dropped.
+pub async fn stream_user_orders(
+    credentials: &CoinbaseCredentials,
+    instruments: &[Instrument],
+) -> Result<mpsc::UnboundedReceiver<MarketEvent>, SocketError> {
+    // Map Instruments to Coinbase markets (eg/ "BTC-USD") & their user SubscriptionIds
+    let markets = instruments
+        .iter()
+        .map(|instrument| format!("{}-{}", instrument.base, instrument.quote).to_uppercase())
+        .collect::<Vec<_>>();
+    let instruments = markets
+        .iter()
+        .zip(instruments.iter().cloned())
+        .map(|(market, instrument)| {
+            (
+                Coinbase::subscription_id(Coinbase::CHANNEL_USER, market),
+                instrument,
+            )
+        })
+        .collect::<HashMap<_, _>>();
+
+    // Connect & action the signed user channel subscription
+    let mut websocket = connect(Coinbase::base_url()).await?;
+    websocket
+        .send(user_subscription(
+            credentials,
+            &markets,
+            Utc::now().timestamp(),
+        )?)
+        .await?;
+    Coinbase::validate(SubscriptionIds(HashMap::new()), &mut websocket, 1).await?;
+    info!(exchange = %ExchangeId::Coinbase, "subscribed to user channel");
+
+    let (event_tx, event_rx) = mpsc::unbounded_channel();
+
+    // Distribute OrderUpdates until the connection or receiver is dropped
+    tokio::spawn(async move {
+        let exchange = ExchangeId::Coinbase;
+        let mut orders = CoinbaseUserOrders::default();
+        while let Some(message) = websocket.next().await {
+            let payload = match message {
+                Ok(WsMessage::Text(payload)) => payload,
+                Ok(WsMessage::Close(frame)) => {
+                    warn!(%exchange, ?frame, "user channel closed by the server");
+                    break;
+                }
+                Ok(_) => continue,
+                Err(error) => {
+                    warn!(%exchange, %error, "user channel connection error");
+                    break;
+                }
+            };
+
+            let message = match serde_json::from_str::<CoinbaseUserMessage>(&payload) {
+                Ok(message) => message,
+                Err(error) => {
+                    debug!(%exchange, %error, %payload, "skipping unsupported user channel message");
+                    continue;
+                }
+            };
+
+            let instrument = match instruments.get(message.subscription_id()) {
+                Some(instrument) => instrument.clone(),
+                None => continue,
+            };
+
+            let update = orders.update(message);
+            let event = MarketEvent {
+                exchange_time: update.time,
+                received_time: Utc::now(),
+                received_instant: None,
+                raw: None,
+                exchange: Exchange::from(exchange),
+                instrument,
+                kind: DataKind::OrderUpdate(update),
+            };
+
+            if event_tx.send(event).is_err() {
+                break;
+            }
+        }
+    });
+
+    Ok(event_rx)
+}
+
+/// Deserialize a [`CoinbaseUserMessage`] "product_id" (eg/ "BTC-USD") as the associated
+/// [`SubscriptionId`] (eg/ SubscriptionId("user|BTC-USD")).
+pub fn de_user_subscription_id<'de, D>(deserializer: D) -> Result<SubscriptionId, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    serde::de::Deserialize::deserialize(deserializer)
+        .map(|product_id| Coinbase::subscription_id(Coinbase::CHANNEL_USER, product_id))
+}
+
+/// Deserialize an optional `String` (eg/ "400.23", null) as an optional `f64`.
+pub fn de_option_str<'de, D>(deserializer: D) -> Result<Option<f64>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    let data: Option<&str> = serde::de::Deserialize::deserialize(deserializer)?;
+    data.map(|data| data.parse().map_err(serde::de::Error::custom))
+        .transpose()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_sign() {
+        let credentials = CoinbaseCredentials {
+            key: "key".to_owned(),
+            secret: "c2VjcmV0".to_owned(),
+            passphrase: "passphrase".to_owned(),
+        };
+
+        assert_eq!(
+            credentials.sign(1700000000).unwrap(),
+            "lhmJXK08fk9SI1ZwFXKFRrPtzfbNOwC+D1xMJJ/1KZg="
+        );
+
+        let invalid = CoinbaseCredentials {
+            secret: "not base64!".to_owned(),
+            ..credentials
+        };
+        assert!(invalid.sign(1700000000).is_err());
+    }
+
+    #[test]
+    fn test_user_subscription() {
+        let credentials = CoinbaseCredentials {
+            key: "key".to_owned(),
+            secret: "c2VjcmV0".to_owned(),
+            passphrase: "passphrase".to_owned(),
+        };
+
+        let actual = match user_subscription(&credentials, &["BTC-USD".to_owned()], 1700000000) {
+            Ok(WsMessage::Text(payload)) => {
+                serde_json::from_str::<serde_json::Value>(&payload).unwrap()
+            }
+            other => panic!("unexpected user subscription: {other:?}"),
+        };
+        let expected = json!({
+            "type": "subscribe",
+            "product_ids": ["BTC-USD"],
+            "channels": ["user"],
+            "signature": "lhmJXK08fk9SI1ZwFXKFRrPtzfbNOwC+D1xMJJ/1KZg=",
+            "key": "key",
+            "passphrase": "passphrase",
+            "timestamp": "1700000000",
+        });
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_coinbase_user_orders_update() {
+        let time = Utc.timestamp_opt(1415348367, 28459000).unwrap();
+        let mut orders = CoinbaseUserOrders::default();
+
+        let order = |status, price, quantity, filled, remaining, fill| OrderUpdate {
+            id: "d50ec984".to_owned(),
+            client_id: Some("client1".to_owned()),
+            side: Side::Buy,
+            status,
+            price,
+            quantity,
+            filled,
+            remaining,
+            fill,
+            time,
+        };
+
+        struct TestCase {
+            input: &'static str,
+            expected: OrderUpdate,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: received order w/ client id
+                input: r#"{"type":"received","time":"2014-11-07T08:19:27.028459Z","product_id":"BTC-USD","sequence":10,"order_id":"d50ec984","size":"1.5","price":"502.5","side":"buy","order_type":"limit","client_oid":"client1"}"#,
+                expected: order(OrderStatus::Received, 502.5, 1.5, 0.0, 1.5, None),
+            },
+            TestCase {
+                // TC1: open order resting in the book
+                input: r#"{"type":"open","time":"2014-11-07T08:19:27.028459Z","product_id":"BTC-USD","sequence":11,"order_id":"d50ec984","price":"502.5","remaining_size":"1.5","side":"buy"}"#,
+                expected: order(OrderStatus::New, 502.5, 1.5, 0.0, 1.5, None),
+            },
+            TestCase {
+                // TC2: match where the account was the maker
+                input: r#"{"type":"match","trade_id":10,"sequence":50,"maker_order_id":"d50ec984","taker_order_id":"taker1","time":"2014-11-07T08:19:27.028459Z","product_id":"BTC-USD","size":"0.5","price":"502.5","side":"buy","maker_profile_id":"profile1"}"#,
+                expected: order(
+                    OrderStatus::PartiallyFilled,
+                    502.5,
+                    1.5,
+                    0.5,
+                    1.0,
+                    Some(OrderFill {
+                        trade_id: "10".to_owned(),
+                        price: 502.5,
+                        quantity: 0.5,
+                        is_maker: true,
+                    }),
+                ),
+            },
+            TestCase {
+                // TC3: change of the remaining quantity w/ null price
+                input: r#"{"type":"change","reason":"STP","time":"2014-11-07T08:19:27.028459Z","sequence":80,"order_id":"d50ec984","side":"buy","product_id":"BTC-USD","old_size":"1.0","new_size":"0.75","price":null}"#,
+                expected: order(OrderStatus::PartiallyFilled, 502.5, 1.25, 0.5, 0.75, None),
+            },
+            TestCase {
+                // TC4: done cancelled order
+                input: r#"{"type":"done","time":"2014-11-07T08:19:27.028459Z","product_id":"BTC-USD","sequence":90,"price":"502.5","order_id":"d50ec984","reason":"canceled","side":"buy","remaining_size":"0.75"}"#,
+                expected: order(OrderStatus::Cancelled, 502.5, 1.25, 0.5, 0.75, None),
+            },
+            TestCase {
+                // TC5: match of an unseen order where the account was the taker is the opposite side
+                input: r#"{"type":"match","trade_id":11,"sequence":95,"maker_order_id":"maker1","taker_order_id":"taker1","time":"2014-11-07T08:19:27.028459Z","product_id":"BTC-USD","size":"2.0","price":"400.5","side":"sell","taker_profile_id":"profile1"}"#,
+                expected: OrderUpdate {
+                    id: "taker1".to_owned(),
+                    client_id: None,
+                    side: Side::Buy,
+                    status: OrderStatus::PartiallyFilled,
+                    price: 0.0,
+                    quantity: 2.0,
+                    filled: 2.0,
+                    remaining: 0.0,
+                    fill: Some(OrderFill {
+                        trade_id: "11".to_owned(),
+                        price: 400.5,
+                        quantity: 2.0,
+                        is_maker: false,
+                    }),
+                    time,
+                },
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let message = serde_json::from_str::<CoinbaseUserMessage>(test.input).unwrap();
+            assert_eq!(
+                message.subscription_id(),
+                &SubscriptionId::from("user|BTC-USD"),
+                "TC{} failed",
+                index
+            );
+
+            let actual = orders.update(message);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+
+        // Done orders are removed
+        assert_eq!(orders.orders.keys().collect::<Vec<_>>(), vec!["taker1"]);
+    }
+}
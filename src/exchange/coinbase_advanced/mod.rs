@@ -0,0 +1,436 @@
+use crate::{
+    model::subscription::{Interval, SubKind, SubscriptionIds, SubscriptionMeta},
+    ExchangeId, ExchangeTransformer, MarketEvent, Subscriber, Subscription, Validator,
+};
+use barter_integration::{
+    error::SocketError, model::SubscriptionId, protocol::websocket::WsMessage, Transformer,
+};
+use model::{CoinbaseAdvancedMessage, CoinbaseAdvancedSubResponse};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::collections::HashMap;
+use tokio::sync::mpsc;
+
+/// [`CoinbaseAdvanced`] specific data structures.
+pub mod model;
+
+/// [`CoinbaseAdvanced`] [`Subscriber`] & [`ExchangeTransformer`] implementor for the collection
+/// of `Spot` data via the public Coinbase Advanced Trade WebSocket API.
+///
+/// Public channels do not require a JWT, so no API credentials are needed. The legacy
+/// [`Coinbase`](super::coinbase::Coinbase) feed remains available for channels not offered by
+/// Advanced Trade.
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct CoinbaseAdvanced {
+    pub ids: SubscriptionIds,
+}
+
+impl Subscriber for CoinbaseAdvanced {
+    type SubResponse = CoinbaseAdvancedSubResponse;
+
+    fn base_url() -> &'static str {
+        ExchangeId::CoinbaseAdvanced.ws_base_url()
+    }
+
+    fn build_subscription_meta(
+        subscriptions: &[Subscription],
+    ) -> Result<SubscriptionMeta, SocketError> {
+        // Allocate SubscriptionIds HashMap to track identifiers for each actioned Subscription
+        let mut ids = SubscriptionIds(HashMap::with_capacity(subscriptions.len()));
+
+        // Map Barter Subscriptions to CoinbaseAdvanced channels
+        let mut subscriptions = subscriptions
+            .iter()
+            .map(|subscription| {
+                // Determine the CoinbaseAdvanced specific channel & market for this Subscription
+                let (channel, market) = Self::build_channel_meta(subscription)?;
+
+                // Use "channel|market" as the SubscriptionId key in the SubscriptionIds HashMap
+                // eg/ SubscriptionId("market_trades|ETH-USD")
+                ids.insert(
+                    CoinbaseAdvanced::subscription_id(channel, &market),
+                    subscription.clone(),
+                );
+
+                // Construct CoinbaseAdvanced specific subscription message
+                Ok(Self::subscription(channel, &[market]))
+            })
+            .collect::<Result<Vec<_>, SocketError>>()?;
+
+        // Channels without updates are closed by the exchange, so also subscribe to heartbeats
+        subscriptions.push(Self::subscription(Self::CHANNEL_HEARTBEATS, &[]));
+
+        Ok(SubscriptionMeta {
+            ids,
+            expected_responses: subscriptions.len(),
+            subscriptions,
+        })
+    }
+}
+
+impl ExchangeTransformer for CoinbaseAdvanced {
+    const EXCHANGE: ExchangeId = ExchangeId::CoinbaseAdvanced;
+    fn new(_: mpsc::UnboundedSender<WsMessage>, ids: SubscriptionIds) -> Self {
+        Self { ids }
+    }
+}
+
+impl Transformer<MarketEvent> for CoinbaseAdvanced {
+    type Input = CoinbaseAdvancedMessage;
+    type OutputIter = Vec<Result<MarketEvent, SocketError>>;
+
+    fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+        match input {
+            CoinbaseAdvancedMessage::MarketTrades { events } => events
+                .into_iter()
+                .flat_map(|event| event.trades)
+                .map(|trade| {
+                    self.ids
+                        .find_instrument(&Self::subscription_id(
+                            Self::CHANNEL_TRADES,
+                            &trade.product_id,
+                        ))
+                        .map(|instrument| MarketEvent::from((Self::EXCHANGE, instrument, trade)))
+                })
+                .collect(),
+            CoinbaseAdvancedMessage::Candles { events } => events
+                .into_iter()
+                .flat_map(|event| event.candles)
+                .map(|candle| {
+                    self.ids
+                        .find_instrument(&Self::subscription_id(
+                            Self::CHANNEL_CANDLES,
+                            &candle.product_id,
+                        ))
+                        .map(|instrument| MarketEvent::from((Self::EXCHANGE, instrument, candle)))
+                })
+                .collect(),
+            CoinbaseAdvancedMessage::L2Data {
+                timestamp,
+                sequence_num,
+                events,
+            } => events
+                .into_iter()
+                .map(|event| {
+                    self.ids
+                        .find_instrument(&Self::subscription_id(
+                            Self::CHANNEL_ORDER_BOOK_L2,
+                            &event.product_id,
+                        ))
+                        .map(|instrument| {
+                            MarketEvent::from((
+                                Self::EXCHANGE,
+                                instrument,
+                                timestamp,
+                                sequence_num,
+                                event,
+                            ))
+                        })
+                })
+                .collect(),
+            CoinbaseAdvancedMessage::Subscriptions | CoinbaseAdvancedMessage::Heartbeats => {
+                vec![]
+            }
+        }
+    }
+}
+
+impl CoinbaseAdvanced {
+    /// [`CoinbaseAdvanced`] trades channel name.
+    ///
+    /// See docs: <https://docs.cdp.coinbase.com/advanced-trade/docs/ws-channels#market-trades-channel>
+    pub const CHANNEL_TRADES: &'static str = "market_trades";
+
+    /// [`CoinbaseAdvanced`] candles channel name. Candles are only offered at
+    /// [`Self::CANDLE_INTERVAL`].
+    ///
+    /// See docs: <https://docs.cdp.coinbase.com/advanced-trade/docs/ws-channels#candles-channel>
+    pub const CHANNEL_CANDLES: &'static str = "candles";
+
+    /// [`CoinbaseAdvanced`] L2 OrderBook channel name. Messages are received on the "l2_data"
+    /// channel, starting with a full snapshot followed by updates.
+    ///
+    /// See docs: <https://docs.cdp.coinbase.com/advanced-trade/docs/ws-channels#level2-channel>
+    pub const CHANNEL_ORDER_BOOK_L2: &'static str = "level2";
+
+    /// [`CoinbaseAdvanced`] heartbeats channel name, used to keep idle subscriptions open.
+    ///
+    /// See docs: <https://docs.cdp.coinbase.com/advanced-trade/docs/ws-channels#heartbeats-channel>
+    pub const CHANNEL_HEARTBEATS: &'static str = "heartbeats";
+
+    /// [`Interval`] of every candle received via the [`Self::CHANNEL_CANDLES`] channel.
+    pub const CANDLE_INTERVAL: Interval = Interval::Minute5;
+
+    /// Determine the [`CoinbaseAdvanced`] channel metadata associated with an input Barter
+    /// [`Subscription`]. This includes the [`CoinbaseAdvanced`] &str channel, and a `String`
+    /// market identifier. Both are used to build a [`CoinbaseAdvanced`] subscription payload.
+    ///
+    /// Example Ok return: Ok("market_trades", "BTC-USD")
+    /// where channel == "market_trades" & market == "BTC-USD".
+    pub fn build_channel_meta(sub: &Subscription) -> Result<(&str, String), SocketError> {
+        // Validate provided Subscription InstrumentKind is supported by CoinbaseAdvanced
+        let sub = sub.validate()?;
+
+        // Determine CoinbaseAdvanced channel using the Subscription SubKind
+        let channel = match &sub.kind {
+            SubKind::Trade => Self::CHANNEL_TRADES,
+            SubKind::Candle(interval) if *interval == Self::CANDLE_INTERVAL => {
+                Self::CHANNEL_CANDLES
+            }
+            SubKind::OrderBookL2Delta => Self::CHANNEL_ORDER_BOOK_L2,
+            other => {
+                return Err(SocketError::Unsupported {
+                    entity: Self::EXCHANGE.as_str(),
+                    item: other.to_string(),
+                })
+            }
+        };
+
+        // Determine CoinbaseAdvanced market identifier using the Instrument (eg/ "BTC-USD")
+        let market = format!("{}-{}", sub.instrument.base, sub.instrument.quote).to_uppercase();
+
+        Ok((channel, market))
+    }
+
+    /// Build a [`CoinbaseAdvanced`] compatible subscription message using the channel & markets
+    /// provided. Each subscription message may only contain a single channel.
+    ///
+    /// eg/ {"type":"subscribe","product_ids":["BTC-USD"],"channel":"market_trades"}
+    pub fn subscription(channel: &str, markets: &[String]) -> WsMessage {
+        WsMessage::Text(
+            json!({
+                "type": "subscribe",
+                "product_ids": markets,
+                "channel": channel,
+            })
+            .to_string(),
+        )
+    }
+
+    /// Build a [`CoinbaseAdvanced`] compatible [`SubscriptionId`] using the channel & market
+    /// provided. This is used to associate [`CoinbaseAdvanced`] data structures received over
+    /// the WebSocket with it's original Barter [`Subscription`].
+    ///
+    /// eg/ SubscriptionId("market_trades|ETH-USD")
+    pub fn subscription_id(channel: &str, market: &str) -> SubscriptionId {
+        SubscriptionId::from(format!("{channel}|{market}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchange::coinbase_advanced::model::{
+        CoinbaseAdvancedLevel, CoinbaseAdvancedLevel2Event, CoinbaseAdvancedLevel2EventKind,
+        CoinbaseAdvancedLevelSide, CoinbaseAdvancedTrade, CoinbaseAdvancedTradeEvent,
+    };
+    use crate::model::{DataKind, Level, OrderBookL2Delta, PublicTrade};
+    use barter_integration::model::{InstrumentKind, Side};
+    use chrono::Utc;
+
+    #[test]
+    fn test_build_channel_meta() {
+        struct TestCase {
+            input: Subscription,
+            expected: Result<(&'static str, String), SocketError>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: Supported InstrumentKind::Spot trades subscription
+                input: Subscription::new(
+                    ExchangeId::CoinbaseAdvanced,
+                    ("btc", "usd", InstrumentKind::Spot),
+                    SubKind::Trade,
+                ),
+                expected: Ok(("market_trades", "BTC-USD".to_owned())),
+            },
+            TestCase {
+                // TC1: Supported InstrumentKind::Spot 5 minute candles subscription
+                input: Subscription::new(
+                    ExchangeId::CoinbaseAdvanced,
+                    ("eth", "usd", InstrumentKind::Spot),
+                    SubKind::Candle(Interval::Minute5),
+                ),
+                expected: Ok(("candles", "ETH-USD".to_owned())),
+            },
+            TestCase {
+                // TC2: Unsupported InstrumentKind::Spot 1 minute candles subscription
+                input: Subscription::new(
+                    ExchangeId::CoinbaseAdvanced,
+                    ("eth", "usd", InstrumentKind::Spot),
+                    SubKind::Candle(Interval::Minute1),
+                ),
+                expected: Err(SocketError::Unsupported {
+                    entity: "",
+                    item: "".to_owned(),
+                }),
+            },
+            TestCase {
+                // TC3: Supported InstrumentKind::Spot OrderBookL2Delta subscription
+                input: Subscription::new(
+                    ExchangeId::CoinbaseAdvanced,
+                    ("btc", "usd", InstrumentKind::Spot),
+                    SubKind::OrderBookL2Delta,
+                ),
+                expected: Ok(("level2", "BTC-USD".to_owned())),
+            },
+            TestCase {
+                // TC4: Unsupported InstrumentKind::FuturePerpetual trades subscription
+                input: Subscription::new(
+                    ExchangeId::CoinbaseAdvanced,
+                    ("btc", "usd", InstrumentKind::FuturePerpetual),
+                    SubKind::Trade,
+                ),
+                expected: Err(SocketError::Unsupported {
+                    entity: "",
+                    item: "".to_owned(),
+                }),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = CoinbaseAdvanced::build_channel_meta(&test.input);
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => {
+                    assert_eq!(actual, expected, "TC{} failed", index)
+                }
+                (Err(_), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_coinbase_advanced_transform() {
+        let subscriptions = vec![
+            Subscription::new(
+                ExchangeId::CoinbaseAdvanced,
+                ("btc", "usd", InstrumentKind::Spot),
+                SubKind::Trade,
+            ),
+            Subscription::new(
+                ExchangeId::CoinbaseAdvanced,
+                ("btc", "usd", InstrumentKind::Spot),
+                SubKind::OrderBookL2Delta,
+            ),
+        ];
+        let mut transformer = CoinbaseAdvanced {
+            ids: CoinbaseAdvanced::build_subscription_meta(&subscriptions)
+                .unwrap()
+                .ids,
+        };
+
+        let time = Utc::now();
+
+        struct TestCase {
+            input: CoinbaseAdvancedMessage,
+            expected: Vec<Result<DataKind, SocketError>>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: market_trades w/ a known & an unknown product
+                input: CoinbaseAdvancedMessage::MarketTrades {
+                    events: vec![CoinbaseAdvancedTradeEvent {
+                        trades: vec![
+                            CoinbaseAdvancedTrade {
+                                id: "1".to_owned(),
+                                product_id: "BTC-USD".to_owned(),
+                                price: 100.0,
+                                quantity: 0.5,
+                                side: Side::Sell,
+                                time,
+                            },
+                            CoinbaseAdvancedTrade {
+                                id: "2".to_owned(),
+                                product_id: "ETH-USD".to_owned(),
+                                price: 10.0,
+                                quantity: 1.0,
+                                side: Side::Buy,
+                                time,
+                            },
+                        ],
+                    }],
+                },
+                expected: vec![
+                    Ok(DataKind::Trade(PublicTrade {
+                        id: "1".to_owned(),
+                        price: 100.0,
+                        quantity: 0.5,
+                        side: Side::Sell,
+                    })),
+                    Err(SocketError::Unidentifiable(SubscriptionId::from(
+                        "market_trades|ETH-USD",
+                    ))),
+                ],
+            },
+            TestCase {
+                // TC1: l2_data update is split into bid & ask Levels
+                input: CoinbaseAdvancedMessage::L2Data {
+                    timestamp: time,
+                    sequence_num: 3,
+                    events: vec![CoinbaseAdvancedLevel2Event {
+                        kind: CoinbaseAdvancedLevel2EventKind::Update,
+                        product_id: "BTC-USD".to_owned(),
+                        updates: vec![
+                            CoinbaseAdvancedLevel {
+                                side: CoinbaseAdvancedLevelSide::Offer,
+                                price: 101.0,
+                                quantity: 0.0,
+                            },
+                            CoinbaseAdvancedLevel {
+                                side: CoinbaseAdvancedLevelSide::Bid,
+                                price: 99.0,
+                                quantity: 2.0,
+                            },
+                        ],
+                    }],
+                },
+                expected: vec![Ok(DataKind::OrderBookL2Delta(OrderBookL2Delta {
+                    last_update_time: time,
+                    first_update_id: None,
+                    last_update_id: 3,
+                    bids: vec![Level {
+                        price: 99.0,
+                        quantity: 2.0,
+                    }],
+                    asks: vec![Level {
+                        price: 101.0,
+                        quantity: 0.0,
+                    }],
+                }))],
+            },
+            TestCase {
+                // TC2: heartbeats produce no MarketEvents
+                input: CoinbaseAdvancedMessage::Heartbeats,
+                expected: vec![],
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = transformer.transform(test.input);
+            assert_eq!(actual.len(), test.expected.len(), "TC{} failed", index);
+
+            for (actual, expected) in actual.into_iter().zip(test.expected) {
+                match (actual, expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual.kind, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+}
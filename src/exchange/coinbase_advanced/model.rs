@@ -0,0 +1,462 @@
+use super::CoinbaseAdvanced;
+use crate::{
+    exchange::{de_str, gateio::model::de_str_epoch_s_as_datetime_utc},
+    model::{Candle, DataKind, Level, OrderBook, OrderBookL2Delta, PublicTrade},
+    ExchangeId, MarketEvent,
+};
+use barter_integration::{
+    error::SocketError,
+    model::{Exchange, Instrument, Side},
+    Validator,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// [`CoinbaseAdvanced`] WebSocket subscription response. A "subscriptions" message listing every
+/// active subscription is sent after each subscribe request.
+///
+/// eg/ {"channel":"subscriptions","events":[{"subscriptions":{"market_trades":["BTC-USD"]}}]}
+///
+/// See docs: <https://docs.cdp.coinbase.com/advanced-trade/docs/ws-overview>
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum CoinbaseAdvancedSubResponse {
+    Subscribed {
+        events: Vec<CoinbaseAdvancedSubscriptions>,
+    },
+    Error {
+        message: String,
+    },
+}
+
+/// Active [`CoinbaseAdvanced`] subscriptions, keyed by channel.
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct CoinbaseAdvancedSubscriptions {
+    pub subscriptions: HashMap<String, Vec<String>>,
+}
+
+impl Validator for CoinbaseAdvancedSubResponse {
+    fn validate(self) -> Result<Self, SocketError>
+    where
+        Self: Sized,
+    {
+        match self {
+            CoinbaseAdvancedSubResponse::Subscribed { .. } => Ok(self),
+            CoinbaseAdvancedSubResponse::Error { message } => Err(SocketError::Subscribe(format!(
+                "received failure subscription response: {message}"
+            ))),
+        }
+    }
+}
+
+/// [`CoinbaseAdvanced`] message variants that could be received over
+/// [`WebSocket`](crate::WebSocket), tagged by "channel".
+///
+/// See docs: <https://docs.cdp.coinbase.com/advanced-trade/docs/ws-channels>
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(tag = "channel", rename_all = "snake_case")]
+pub enum CoinbaseAdvancedMessage {
+    MarketTrades {
+        events: Vec<CoinbaseAdvancedTradeEvent>,
+    },
+    Candles {
+        events: Vec<CoinbaseAdvancedCandleEvent>,
+    },
+    L2Data {
+        timestamp: DateTime<Utc>,
+        sequence_num: u64,
+        events: Vec<CoinbaseAdvancedLevel2Event>,
+    },
+    Subscriptions,
+    Heartbeats,
+}
+
+/// [`CoinbaseAdvanced`] "market_trades" event containing a batch of trades.
+///
+/// See docs: <https://docs.cdp.coinbase.com/advanced-trade/docs/ws-channels#market-trades-channel>
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct CoinbaseAdvancedTradeEvent {
+    pub trades: Vec<CoinbaseAdvancedTrade>,
+}
+
+/// [`CoinbaseAdvanced`] real-time trade.
+///
+/// eg/ {"trade_id":"1","product_id":"BTC-USD","price":"100.0","size":"0.5","side":"BUY","time":"2019-08-14T20:42:27.265Z"}
+///
+/// See docs: <https://docs.cdp.coinbase.com/advanced-trade/docs/ws-channels#market-trades-channel>
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct CoinbaseAdvancedTrade {
+    #[serde(rename = "trade_id")]
+    pub id: String,
+    pub product_id: String,
+    #[serde(deserialize_with = "de_str")]
+    pub price: f64,
+    #[serde(rename = "size", deserialize_with = "de_str")]
+    pub quantity: f64,
+    pub side: Side,
+    pub time: DateTime<Utc>,
+}
+
+impl From<(ExchangeId, Instrument, CoinbaseAdvancedTrade)> for MarketEvent {
+    fn from(
+        (exchange_id, instrument, trade): (ExchangeId, Instrument, CoinbaseAdvancedTrade),
+    ) -> Self {
+        Self {
+            exchange_time: trade.time,
+            received_time: Utc::now(),
+            received_instant: None,
+            raw: None,
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: DataKind::Trade(PublicTrade {
+                id: trade.id,
+                price: trade.price,
+                quantity: trade.quantity,
+                side: trade.side,
+            }),
+        }
+    }
+}
+
+/// [`CoinbaseAdvanced`] "candles" event containing a batch of candles.
+///
+/// See docs: <https://docs.cdp.coinbase.com/advanced-trade/docs/ws-channels#candles-channel>
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct CoinbaseAdvancedCandleEvent {
+    pub candles: Vec<CoinbaseAdvancedCandle>,
+}
+
+/// [`CoinbaseAdvanced`] candle, which is always of [`CoinbaseAdvanced::CANDLE_INTERVAL`].
+///
+/// eg/ {"start":"1688998200","high":"1867.72","low":"1865.63","open":"1867.38","close":"1866.81","volume":"0.20269406","product_id":"ETH-USD"}
+///
+/// See docs: <https://docs.cdp.coinbase.com/advanced-trade/docs/ws-channels#candles-channel>
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct CoinbaseAdvancedCandle {
+    pub product_id: String,
+    #[serde(deserialize_with = "de_str_epoch_s_as_datetime_utc")]
+    pub start: DateTime<Utc>,
+    #[serde(deserialize_with = "de_str")]
+    pub open: f64,
+    #[serde(deserialize_with = "de_str")]
+    pub high: f64,
+    #[serde(deserialize_with = "de_str")]
+    pub low: f64,
+    #[serde(deserialize_with = "de_str")]
+    pub close: f64,
+    #[serde(deserialize_with = "de_str")]
+    pub volume: f64,
+}
+
+impl From<(ExchangeId, Instrument, CoinbaseAdvancedCandle)> for MarketEvent {
+    fn from(
+        (exchange_id, instrument, candle): (ExchangeId, Instrument, CoinbaseAdvancedCandle),
+    ) -> Self {
+        let end_time = CoinbaseAdvanced::CANDLE_INTERVAL.end_time(candle.start);
+
+        Self {
+            exchange_time: end_time,
+            received_time: Utc::now(),
+            received_instant: None,
+            raw: None,
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: DataKind::Candle(Candle {
+                start_time: candle.start,
+                end_time,
+                open: candle.open,
+                high: candle.high,
+                low: candle.low,
+                close: candle.close,
+                volume: candle.volume,
+                trade_count: 0,
+            }),
+        }
+    }
+}
+
+/// [`CoinbaseAdvanced`] "l2_data" event, either a full snapshot sent after subscribing or an
+/// update containing the levels that have changed.
+///
+/// See docs: <https://docs.cdp.coinbase.com/advanced-trade/docs/ws-channels#level2-channel>
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct CoinbaseAdvancedLevel2Event {
+    #[serde(rename = "type")]
+    pub kind: CoinbaseAdvancedLevel2EventKind,
+    pub product_id: String,
+    pub updates: Vec<CoinbaseAdvancedLevel>,
+}
+
+/// Type of [`CoinbaseAdvancedLevel2Event`].
+#[derive(Clone, Copy, Eq, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CoinbaseAdvancedLevel2EventKind {
+    Snapshot,
+    Update,
+}
+
+/// [`CoinbaseAdvanced`] L2 OrderBook level. A zero `new_quantity` removes the level.
+///
+/// eg/ {"side":"bid","event_time":"1970-01-01T00:00:00Z","price_level":"21921.73","new_quantity":"0.06317902"}
+#[derive(Copy, Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct CoinbaseAdvancedLevel {
+    pub side: CoinbaseAdvancedLevelSide,
+    #[serde(rename = "price_level", deserialize_with = "de_str")]
+    pub price: f64,
+    #[serde(rename = "new_quantity", deserialize_with = "de_str")]
+    pub quantity: f64,
+}
+
+/// Side of the book a [`CoinbaseAdvancedLevel`] belongs to.
+#[derive(Clone, Copy, Eq, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CoinbaseAdvancedLevelSide {
+    Bid,
+    Offer,
+}
+
+impl From<CoinbaseAdvancedLevel> for Level {
+    fn from(level: CoinbaseAdvancedLevel) -> Self {
+        Self {
+            price: level.price,
+            quantity: level.quantity,
+        }
+    }
+}
+
+impl
+    From<(
+        ExchangeId,
+        Instrument,
+        DateTime<Utc>,
+        u64,
+        CoinbaseAdvancedLevel2Event,
+    )> for MarketEvent
+{
+    fn from(
+        (exchange_id, instrument, time, sequence, event): (
+            ExchangeId,
+            Instrument,
+            DateTime<Utc>,
+            u64,
+            CoinbaseAdvancedLevel2Event,
+        ),
+    ) -> Self {
+        let (bids, asks): (Vec<_>, Vec<_>) = event
+            .updates
+            .into_iter()
+            .partition(|level| level.side == CoinbaseAdvancedLevelSide::Bid);
+        let bids = bids.into_iter().map(Level::from).collect();
+        let asks = asks.into_iter().map(Level::from).collect();
+
+        Self {
+            exchange_time: time,
+            received_time: Utc::now(),
+            received_instant: None,
+            raw: None,
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: match event.kind {
+                CoinbaseAdvancedLevel2EventKind::Snapshot => DataKind::OrderBook(OrderBook {
+                    last_update_time: time,
+                    last_update_id: sequence,
+                    bids,
+                    asks,
+                }),
+                CoinbaseAdvancedLevel2EventKind::Update => {
+                    DataKind::OrderBookL2Delta(OrderBookL2Delta {
+                        last_update_time: time,
+                        first_update_id: None,
+                        last_update_id: sequence,
+                        bids,
+                        asks,
+                    })
+                }
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchange::datetime_utc_from_epoch_duration;
+    use std::time::Duration;
+
+    #[test]
+    fn test_deserialise_coinbase_advanced_subscription_response() {
+        struct TestCase {
+            input: &'static str,
+            expected: Result<CoinbaseAdvancedSubResponse, SocketError>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: input response is Subscribed
+                input: r#"{
+                    "channel":"subscriptions","client_id":"","timestamp":"2023-02-09T20:29:49.753424311Z",
+                    "sequence_num":1,"events":[{"subscriptions":{"market_trades":["BTC-USD"]}}]
+                }"#,
+                expected: Ok(CoinbaseAdvancedSubResponse::Subscribed {
+                    events: vec![CoinbaseAdvancedSubscriptions {
+                        subscriptions: HashMap::from([(
+                            "market_trades".to_owned(),
+                            vec!["BTC-USD".to_owned()],
+                        )]),
+                    }],
+                }),
+            },
+            TestCase {
+                // TC1: input response is Error
+                input: r#"{"type":"error","message":"failure to subscribe"}"#,
+                expected: Ok(CoinbaseAdvancedSubResponse::Error {
+                    message: "failure to subscribe".to_owned(),
+                }),
+            },
+            TestCase {
+                // TC2: input market_trades message is not a subscription response
+                input: r#"{
+                    "channel":"market_trades","client_id":"","timestamp":"2023-02-09T20:19:35.39625135Z",
+                    "sequence_num":0,"events":[{"type":"snapshot","trades":[]}]
+                }"#,
+                expected: Err(SocketError::Subscribe("".to_owned())),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = serde_json::from_str::<CoinbaseAdvancedSubResponse>(test.input);
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => {
+                    assert_eq!(actual, expected, "TC{} failed", index)
+                }
+                (Err(_), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_deserialise_coinbase_advanced_message() {
+        struct TestCase {
+            input: &'static str,
+            expected: Result<CoinbaseAdvancedMessage, SocketError>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: valid market_trades message
+                input: r#"{
+                    "channel":"market_trades","client_id":"","timestamp":"2023-02-09T20:19:35.39625135Z",
+                    "sequence_num":0,"events":[{"type":"update","trades":[{
+                        "trade_id":"000000000","product_id":"ETH-USD","price":"1260.01",
+                        "size":"0.3","side":"BUY","time":"2019-08-14T20:42:27.265Z"
+                    }]}]
+                }"#,
+                expected: Ok(CoinbaseAdvancedMessage::MarketTrades {
+                    events: vec![CoinbaseAdvancedTradeEvent {
+                        trades: vec![CoinbaseAdvancedTrade {
+                            id: "000000000".to_owned(),
+                            product_id: "ETH-USD".to_owned(),
+                            price: 1260.01,
+                            quantity: 0.3,
+                            side: Side::Buy,
+                            time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                                1565815347265,
+                            )),
+                        }],
+                    }],
+                }),
+            },
+            TestCase {
+                // TC1: valid candles message
+                input: r#"{
+                    "channel":"candles","client_id":"","timestamp":"2023-06-09T20:19:35.39625135Z",
+                    "sequence_num":0,"events":[{"type":"snapshot","candles":[{
+                        "start":"1688998200","high":"1867.72","low":"1865.63","open":"1867.38",
+                        "close":"1866.81","volume":"0.20269406","product_id":"ETH-USD"
+                    }]}]
+                }"#,
+                expected: Ok(CoinbaseAdvancedMessage::Candles {
+                    events: vec![CoinbaseAdvancedCandleEvent {
+                        candles: vec![CoinbaseAdvancedCandle {
+                            product_id: "ETH-USD".to_owned(),
+                            start: datetime_utc_from_epoch_duration(Duration::from_secs(
+                                1688998200,
+                            )),
+                            open: 1867.38,
+                            high: 1867.72,
+                            low: 1865.63,
+                            close: 1866.81,
+                            volume: 0.20269406,
+                        }],
+                    }],
+                }),
+            },
+            TestCase {
+                // TC2: valid l2_data snapshot message
+                input: r#"{
+                    "channel":"l2_data","client_id":"","timestamp":"1970-01-01T00:00:01Z",
+                    "sequence_num":7,"events":[{"type":"snapshot","product_id":"BTC-USD","updates":[
+                        {"side":"bid","event_time":"1970-01-01T00:00:00Z","price_level":"21921.73","new_quantity":"0.06317902"},
+                        {"side":"offer","event_time":"1970-01-01T00:00:00Z","price_level":"21921.74","new_quantity":"0.5"}
+                    ]}]
+                }"#,
+                expected: Ok(CoinbaseAdvancedMessage::L2Data {
+                    timestamp: datetime_utc_from_epoch_duration(Duration::from_secs(1)),
+                    sequence_num: 7,
+                    events: vec![CoinbaseAdvancedLevel2Event {
+                        kind: CoinbaseAdvancedLevel2EventKind::Snapshot,
+                        product_id: "BTC-USD".to_owned(),
+                        updates: vec![
+                            CoinbaseAdvancedLevel {
+                                side: CoinbaseAdvancedLevelSide::Bid,
+                                price: 21921.73,
+                                quantity: 0.06317902,
+                            },
+                            CoinbaseAdvancedLevel {
+                                side: CoinbaseAdvancedLevelSide::Offer,
+                                price: 21921.74,
+                                quantity: 0.5,
+                            },
+                        ],
+                    }],
+                }),
+            },
+            TestCase {
+                // TC3: valid heartbeats message
+                input: r#"{
+                    "channel":"heartbeats","client_id":"","timestamp":"2023-06-23T20:31:26.122969572Z",
+                    "sequence_num":0,"events":[{"current_time":"2023-06-23 20:31:56.121961769 +0000 UTC m=+91717.525857105","heartbeat_counter":"3049"}]
+                }"#,
+                expected: Ok(CoinbaseAdvancedMessage::Heartbeats),
+            },
+            TestCase {
+                // TC4: invalid message w/ unknown channel
+                input: r#"{"channel":"unknown","events":[]}"#,
+                expected: Err(SocketError::Subscribe("".to_owned())),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = serde_json::from_str::<CoinbaseAdvancedMessage>(test.input);
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => {
+                    assert_eq!(actual, expected, "TC{} failed", index)
+                }
+                (Err(_), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,433 @@
+use crate::{
+    model::subscription::{SubKind, SubscriptionIds, SubscriptionMeta},
+    send_subscriptions, ExchangeId, ExchangeTransformer, MarketEvent, RateLimit, Subscriber,
+    Subscription, Validator,
+};
+use async_trait::async_trait;
+use barter_integration::{
+    error::SocketError,
+    model::{Instrument, InstrumentKind, SubscriptionId},
+    protocol::websocket::{connect, WebSocket, WsMessage},
+    Transformer,
+};
+use chrono::Utc;
+use model::{
+    CryptocomInterval, CryptocomMessage, CryptocomResult, CryptocomSubResponse, CryptocomSubscribe,
+};
+use serde_json::json;
+use std::{collections::HashMap, time::Duration};
+use tokio::sync::mpsc;
+
+/// [`Cryptocom`] specific data structures.
+pub mod model;
+
+/// Delay between establishing a [`Cryptocom`] connection & sending requests, as recommended by
+/// the Crypto.com docs to avoid rate limit errors.
+///
+/// See docs: <https://exchange-docs.crypto.com/exchange/v1/rest-ws/index.html#websocket-subscriptions>
+pub const CONNECT_DELAY: Duration = Duration::from_secs(1);
+
+/// [`Cryptocom`] [`Subscriber`] & [`ExchangeTransformer`] implementor for the collection
+/// of `Spot` & `FuturePerpetual` data via the Crypto.com Exchange v1 market data WebSocket API.
+///
+/// Crypto.com closes connections that do not answer its `public/heartbeat` messages, so each
+/// heartbeat is answered with a `public/respond-heartbeat` via the [`WsSink`] transmitter.
+///
+/// [`WsSink`]: barter_integration::protocol::websocket::WsSink
+#[derive(Debug)]
+pub struct Cryptocom {
+    pub ids: SubscriptionIds,
+    pub ws_sink_tx: mpsc::UnboundedSender<WsMessage>,
+}
+
+#[async_trait]
+impl Subscriber for Cryptocom {
+    type SubResponse = CryptocomSubResponse;
+
+    async fn subscribe(
+        subscriptions: &[Subscription],
+    ) -> Result<(WebSocket, SubscriptionIds), SocketError> {
+        // Connect to exchange & wait before sending requests
+        let mut websocket = connect(Self::url(subscriptions)?).await?;
+        tokio::time::sleep(CONNECT_DELAY).await;
+
+        // Subscribe
+        let SubscriptionMeta {
+            ids,
+            subscriptions,
+            expected_responses,
+        } = Self::build_subscription_meta(subscriptions)?;
+
+        send_subscriptions(&mut websocket, subscriptions, Self::ws_rate_limit()).await?;
+
+        // Validate subscriptions
+        let ids = Self::validate(ids, &mut websocket, expected_responses).await?;
+
+        Ok((websocket, ids))
+    }
+
+    fn base_url() -> &'static str {
+        ExchangeId::Cryptocom.ws_base_url()
+    }
+
+    fn ws_rate_limit() -> Option<RateLimit> {
+        ExchangeId::Cryptocom.ws_rate_limit()
+    }
+
+    fn build_subscription_meta(
+        subscriptions: &[Subscription],
+    ) -> Result<SubscriptionMeta, SocketError> {
+        // Allocate SubscriptionIds HashMap to track identifiers for each actioned Subscription
+        let mut ids = SubscriptionIds(HashMap::with_capacity(subscriptions.len()));
+
+        // Map Barter Subscriptions to Cryptocom channels
+        let subscriptions = subscriptions
+            .iter()
+            .enumerate()
+            .map(|(index, subscription)| {
+                // Determine the Cryptocom specific channel for this Barter Subscription
+                let channel = Self::channel(subscription)?;
+
+                // Use the channel as the SubscriptionId key in the SubscriptionIds
+                // eg/ SubscriptionId("candlestick.1m.BTC_USDT")
+                ids.insert(SubscriptionId::from(channel.as_str()), subscription.clone());
+
+                // Construct Cryptocom specific subscription message
+                Ok(Self::subscription_message(index, &channel))
+            })
+            .collect::<Result<Vec<_>, SocketError>>()?;
+
+        Ok(SubscriptionMeta {
+            ids,
+            expected_responses: subscriptions.len(),
+            subscriptions,
+        })
+    }
+}
+
+impl ExchangeTransformer for Cryptocom {
+    const EXCHANGE: ExchangeId = ExchangeId::Cryptocom;
+
+    fn new(ws_sink_tx: mpsc::UnboundedSender<WsMessage>, ids: SubscriptionIds) -> Self {
+        Self { ids, ws_sink_tx }
+    }
+}
+
+impl Transformer<MarketEvent> for Cryptocom {
+    type Input = CryptocomMessage;
+    type OutputIter = Vec<Result<MarketEvent, SocketError>>;
+
+    fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+        match input {
+            CryptocomMessage::Subscribe(CryptocomSubscribe {
+                result:
+                    Some(CryptocomResult::Trades {
+                        subscription_id,
+                        data,
+                    }),
+                ..
+            }) => {
+                // Determine Instrument associated with this CryptocomResult
+                let instrument = match self.ids.find_instrument(&subscription_id) {
+                    Ok(instrument) => instrument,
+                    Err(error) => return vec![Err(error)],
+                };
+
+                data.into_iter()
+                    .map(|trade| {
+                        Ok(MarketEvent::from((
+                            Cryptocom::EXCHANGE,
+                            instrument.clone(),
+                            trade,
+                        )))
+                    })
+                    .collect()
+            }
+            CryptocomMessage::Subscribe(CryptocomSubscribe {
+                result:
+                    Some(CryptocomResult::Candles {
+                        subscription_id,
+                        data,
+                    }),
+                ..
+            }) => {
+                // Determine Instrument & Interval associated with this CryptocomResult
+                let (instrument, interval) = match self.ids.get(&subscription_id) {
+                    Some(Subscription {
+                        instrument,
+                        kind: SubKind::Candle(interval),
+                        ..
+                    }) => (instrument.clone(), *interval),
+                    _ => return vec![Err(SocketError::Unidentifiable(subscription_id))],
+                };
+
+                data.into_iter()
+                    .map(|candle| {
+                        Ok(MarketEvent::from((
+                            Cryptocom::EXCHANGE,
+                            instrument.clone(),
+                            interval,
+                            candle,
+                        )))
+                    })
+                    .collect()
+            }
+            CryptocomMessage::Subscribe(CryptocomSubscribe { code: 0, .. }) => vec![],
+            CryptocomMessage::Subscribe(CryptocomSubscribe { code, message, .. }) => {
+                vec![Err(SocketError::Exchange(format!(
+                    "received error code: {code} with message: {message:?}"
+                )))]
+            }
+            CryptocomMessage::Heartbeat { id } => {
+                let _ = self.ws_sink_tx.send(Self::heartbeat_response(id));
+                vec![]
+            }
+        }
+    }
+}
+
+impl Cryptocom {
+    /// [`Cryptocom`] trades channel prefix, suffixed with the instrument name.
+    ///
+    /// See docs: <https://exchange-docs.crypto.com/exchange/v1/rest-ws/index.html#trade-instrument_name>
+    pub const CHANNEL_TRADE: &'static str = "trade";
+
+    /// [`Cryptocom`] candlestick channel prefix, suffixed with the [`CryptocomInterval`] &
+    /// instrument name.
+    ///
+    /// See docs: <https://exchange-docs.crypto.com/exchange/v1/rest-ws/index.html#candlestick-time_frame-instrument_name>
+    pub const CHANNEL_CANDLESTICK: &'static str = "candlestick";
+
+    /// Determine the [`Cryptocom`] channel associated with an input Barter [`Subscription`].
+    ///
+    /// eg/ "trade.BTC_USDT", "candlestick.1m.BTCUSD-PERP"
+    pub fn channel(sub: &Subscription) -> Result<String, SocketError> {
+        // Validate provided Subscription InstrumentKind & SubKind are supported by Cryptocom
+        let sub = sub.validate()?;
+        let instrument = Self::instrument_name(&sub.instrument);
+
+        match &sub.kind {
+            SubKind::Trade => Ok(format!("{}.{instrument}", Self::CHANNEL_TRADE)),
+            SubKind::Candle(interval) => Ok(format!(
+                "{}.{}.{instrument}",
+                Self::CHANNEL_CANDLESTICK,
+                CryptocomInterval::try_from(interval)?.0
+            )),
+            other => Err(SocketError::Unsupported {
+                entity: Self::EXCHANGE.as_str(),
+                item: other.to_string(),
+            }),
+        }
+    }
+
+    /// Determine the [`Cryptocom`] instrument name for the provided [`Instrument`].
+    ///
+    /// eg/ Spot "BTC_USDT", FuturePerpetual "BTCUSD-PERP"
+    pub fn instrument_name(instrument: &Instrument) -> String {
+        match instrument.kind {
+            InstrumentKind::Spot => format!("{}_{}", instrument.base, instrument.quote),
+            InstrumentKind::FuturePerpetual => {
+                format!("{}{}-PERP", instrument.base, instrument.quote)
+            }
+        }
+        .to_uppercase()
+    }
+
+    /// Build a [`Cryptocom`] compatible subscription request for the provided channel, using the
+    /// index as the request id.
+    ///
+    /// eg/ {"id":0,"method":"subscribe","params":{"channels":["trade.BTC_USDT"]},"nonce":1587523073344}
+    pub fn subscription_message(index: usize, channel: &str) -> WsMessage {
+        WsMessage::Text(
+            json!({
+                "id": index,
+                "method": "subscribe",
+                "params": {
+                    "channels": [channel],
+                },
+                "nonce": Utc::now().timestamp_millis(),
+            })
+            .to_string(),
+        )
+    }
+
+    /// Build the [`Cryptocom`] response to the `public/heartbeat` with the provided id.
+    ///
+    /// eg/ {"id":1587523073344,"method":"public/respond-heartbeat"}
+    pub fn heartbeat_response(id: u64) -> WsMessage {
+        WsMessage::Text(
+            json!({
+                "id": id,
+                "method": "public/respond-heartbeat",
+            })
+            .to_string(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{subscription::Interval, DataKind, PublicTrade};
+    use barter_integration::model::Side;
+    use model::CryptocomTrade;
+
+    #[test]
+    fn test_channel() {
+        struct TestCase {
+            input: Subscription,
+            expected: Result<String, SocketError>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: Supported InstrumentKind::Spot trades subscription
+                input: Subscription::new(
+                    ExchangeId::Cryptocom,
+                    ("btc", "usdt", InstrumentKind::Spot),
+                    SubKind::Trade,
+                ),
+                expected: Ok("trade.BTC_USDT".to_owned()),
+            },
+            TestCase {
+                // TC1: Supported InstrumentKind::FuturePerpetual candles subscription
+                input: Subscription::new(
+                    ExchangeId::Cryptocom,
+                    ("btc", "usd", InstrumentKind::FuturePerpetual),
+                    SubKind::Candle(Interval::Day1),
+                ),
+                expected: Ok("candlestick.1D.BTCUSD-PERP".to_owned()),
+            },
+            TestCase {
+                // TC2: Unsupported Interval
+                input: Subscription::new(
+                    ExchangeId::Cryptocom,
+                    ("btc", "usdt", InstrumentKind::Spot),
+                    SubKind::Candle(Interval::Minute3),
+                ),
+                expected: Err(SocketError::Unsupported {
+                    entity: "cryptocom",
+                    item: Interval::Minute3.to_string(),
+                }),
+            },
+            TestCase {
+                // TC3: Unsupported SubKind
+                input: Subscription::new(
+                    ExchangeId::Cryptocom,
+                    ("btc", "usdt", InstrumentKind::Spot),
+                    SubKind::OrderBookL3Delta,
+                ),
+                expected: Err(SocketError::Unsupported {
+                    entity: "cryptocom",
+                    item: SubKind::OrderBookL3Delta.to_string(),
+                }),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = Cryptocom::channel(&test.input);
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => {
+                    assert_eq!(actual, expected, "TC{} failed", index)
+                }
+                (Err(_), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_cryptocom_transform() {
+        let (ws_sink_tx, mut ws_sink_rx) = mpsc::unbounded_channel();
+        let mut transformer = Cryptocom::new(
+            ws_sink_tx,
+            SubscriptionIds(HashMap::from([(
+                SubscriptionId::from("trade.BTC_USDT"),
+                Subscription::new(
+                    ExchangeId::Cryptocom,
+                    ("btc", "usdt", InstrumentKind::Spot),
+                    SubKind::Trade,
+                ),
+            )])),
+        );
+
+        let trades = |subscription_id: &str| {
+            CryptocomMessage::Subscribe(CryptocomSubscribe {
+                code: 0,
+                message: None,
+                result: Some(CryptocomResult::Trades {
+                    subscription_id: SubscriptionId::from(subscription_id),
+                    data: vec![CryptocomTrade {
+                        id: "1".to_owned(),
+                        time: Utc::now(),
+                        price: 100.0,
+                        quantity: 1.0,
+                        side: Side::Buy,
+                    }],
+                }),
+            })
+        };
+
+        struct TestCase {
+            input: CryptocomMessage,
+            expected: Vec<Option<DataKind>>,
+            expected_sent: Option<WsMessage>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: trades w/ known SubscriptionId
+                input: trades("trade.BTC_USDT"),
+                expected: vec![Some(DataKind::Trade(PublicTrade {
+                    id: "1".to_owned(),
+                    price: 100.0,
+                    quantity: 1.0,
+                    side: Side::Buy,
+                }))],
+                expected_sent: None,
+            },
+            TestCase {
+                // TC1: trades w/ unknown SubscriptionId
+                input: trades("trade.ETH_USDT"),
+                expected: vec![None],
+                expected_sent: None,
+            },
+            TestCase {
+                // TC2: heartbeat is answered w/ the same id
+                input: CryptocomMessage::Heartbeat { id: 1587523073344 },
+                expected: vec![],
+                expected_sent: Some(Cryptocom::heartbeat_response(1587523073344)),
+            },
+            TestCase {
+                // TC3: failed subscribe message is an error
+                input: CryptocomMessage::Subscribe(CryptocomSubscribe {
+                    code: 40003,
+                    message: Some("BAD_REQUEST".to_owned()),
+                    result: None,
+                }),
+                expected: vec![None],
+                expected_sent: None,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = transformer
+                .transform(test.input)
+                .into_iter()
+                .map(|event| event.ok().map(|event| event.kind))
+                .collect::<Vec<_>>();
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+            assert_eq!(
+                ws_sink_rx.try_recv().ok(),
+                test.expected_sent,
+                "TC{} failed",
+                index
+            );
+        }
+    }
+}
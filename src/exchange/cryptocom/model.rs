@@ -0,0 +1,334 @@
+use crate::{
+    exchange::{de_str, de_u64_epoch_ms_as_datetime_utc},
+    model::{subscription::Interval, Candle, DataKind, PublicTrade},
+    ExchangeId, MarketEvent,
+};
+use barter_integration::{
+    error::SocketError,
+    model::{Exchange, Instrument, Side, SubscriptionId},
+    Validator,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// [`Cryptocom`](super::Cryptocom) message received in response to WebSocket subscription
+/// requests. Subscription data is also delivered with the "subscribe" method, but contains a
+/// `result` & is therefore rejected by this type.
+///
+/// eg/ {"id":1,"method":"subscribe","code":0}
+/// eg/ {"id":1,"method":"subscribe","code":40003,"message":"BAD_REQUEST"}
+///
+/// See docs: <https://exchange-docs.crypto.com/exchange/v1/rest-ws/index.html#subscribe>
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(tag = "method", deny_unknown_fields)]
+pub enum CryptocomSubResponse {
+    #[serde(rename = "subscribe")]
+    Subscribe {
+        id: i64,
+        code: i64,
+        #[serde(default)]
+        message: Option<String>,
+    },
+}
+
+impl Validator for CryptocomSubResponse {
+    fn validate(self) -> Result<Self, SocketError>
+    where
+        Self: Sized,
+    {
+        match &self {
+            CryptocomSubResponse::Subscribe { code: 0, .. } => Ok(self),
+            CryptocomSubResponse::Subscribe { code, message, .. } => {
+                Err(SocketError::Subscribe(format!(
+                    "received failure subscription response code: {code} with message: {message:?}",
+                )))
+            }
+        }
+    }
+}
+
+/// [`Cryptocom`](super::Cryptocom) candlestick interval used in candlestick channels
+/// (eg/ "candlestick.1m.BTC_USDT").
+///
+/// See docs: <https://exchange-docs.crypto.com/exchange/v1/rest-ws/index.html#candlestick-time_frame-instrument_name>
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct CryptocomInterval(pub &'static str);
+
+impl TryFrom<&Interval> for CryptocomInterval {
+    type Error = SocketError;
+
+    fn try_from(interval: &Interval) -> Result<Self, Self::Error> {
+        let interval = match interval {
+            Interval::Minute1 => "1m",
+            Interval::Minute5 => "5m",
+            Interval::Minute15 => "15m",
+            Interval::Minute30 => "30m",
+            Interval::Hour1 => "1h",
+            Interval::Hour2 => "2h",
+            Interval::Hour4 => "4h",
+            Interval::Hour12 => "12h",
+            Interval::Day1 => "1D",
+            Interval::Week1 => "7D",
+            Interval::Month1 => "1M",
+            other => {
+                return Err(SocketError::Unsupported {
+                    entity: ExchangeId::Cryptocom.as_str(),
+                    item: other.to_string(),
+                })
+            }
+        };
+
+        Ok(Self(interval))
+    }
+}
+
+/// [`Cryptocom`](super::Cryptocom) message variants that can be received over [`WebSocket`],
+/// tagged by method.
+///
+/// See docs: <https://exchange-docs.crypto.com/exchange/v1/rest-ws/index.html#websocket-subscriptions>
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(tag = "method")]
+pub enum CryptocomMessage {
+    #[serde(rename = "subscribe")]
+    Subscribe(CryptocomSubscribe),
+    #[serde(rename = "public/heartbeat")]
+    Heartbeat { id: u64 },
+}
+
+/// [`Cryptocom`](super::Cryptocom) "subscribe" method message, containing a [`CryptocomResult`]
+/// if it delivers subscription data rather than responding to a subscription request.
+///
+/// eg/ {"id":-1,"method":"subscribe","code":0,"result":{...}}
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct CryptocomSubscribe {
+    pub code: i64,
+    #[serde(default)]
+    pub message: Option<String>,
+    #[serde(default)]
+    pub result: Option<CryptocomResult>,
+}
+
+/// [`Cryptocom`](super::Cryptocom) subscription data, tagged by channel.
+///
+/// eg/ {"instrument_name":"BTC_USDT","subscription":"trade.BTC_USDT","channel":"trade","data":[...]}
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(tag = "channel")]
+pub enum CryptocomResult {
+    #[serde(rename = "trade")]
+    Trades {
+        #[serde(rename = "subscription")]
+        subscription_id: SubscriptionId,
+        data: Vec<CryptocomTrade>,
+    },
+    #[serde(rename = "candlestick")]
+    Candles {
+        #[serde(rename = "subscription")]
+        subscription_id: SubscriptionId,
+        data: Vec<CryptocomCandle>,
+    },
+}
+
+/// [`Cryptocom`](super::Cryptocom) public trade.
+///
+/// eg/ {"d":"2030407068","t":1613581138462,"p":"51327.22","q":"0.000100","s":"BUY","i":"BTC_USDT"}
+///
+/// See docs: <https://exchange-docs.crypto.com/exchange/v1/rest-ws/index.html#trade-instrument_name>
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct CryptocomTrade {
+    #[serde(rename = "d")]
+    pub id: String,
+    #[serde(rename = "t", deserialize_with = "de_u64_epoch_ms_as_datetime_utc")]
+    pub time: DateTime<Utc>,
+    #[serde(rename = "p", deserialize_with = "de_str")]
+    pub price: f64,
+    #[serde(rename = "q", deserialize_with = "de_str")]
+    pub quantity: f64,
+    #[serde(rename = "s")]
+    pub side: Side,
+}
+
+/// [`Cryptocom`](super::Cryptocom) candlestick, where `t` is the start time. The end time is not
+/// sent, so it is determined from the subscribed [`Interval`] when transformed.
+///
+/// eg/ {"o":"51140.21","c":"51141.83","h":"51143.75","l":"51140.21","v":"0.6349","t":1613580960000}
+///
+/// See docs: <https://exchange-docs.crypto.com/exchange/v1/rest-ws/index.html#candlestick-time_frame-instrument_name>
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct CryptocomCandle {
+    #[serde(rename = "t", deserialize_with = "de_u64_epoch_ms_as_datetime_utc")]
+    pub start_time: DateTime<Utc>,
+    #[serde(rename = "o", deserialize_with = "de_str")]
+    pub open: f64,
+    #[serde(rename = "h", deserialize_with = "de_str")]
+    pub high: f64,
+    #[serde(rename = "l", deserialize_with = "de_str")]
+    pub low: f64,
+    #[serde(rename = "c", deserialize_with = "de_str")]
+    pub close: f64,
+    #[serde(rename = "v", deserialize_with = "de_str")]
+    pub volume: f64,
+}
+
+impl From<(ExchangeId, Instrument, CryptocomTrade)> for MarketEvent {
+    fn from((exchange_id, instrument, trade): (ExchangeId, Instrument, CryptocomTrade)) -> Self {
+        Self {
+            exchange_time: trade.time,
+            received_time: Utc::now(),
+            received_instant: None,
+            raw: None,
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: DataKind::Trade(PublicTrade {
+                id: trade.id,
+                price: trade.price,
+                quantity: trade.quantity,
+                side: trade.side,
+            }),
+        }
+    }
+}
+
+impl From<(ExchangeId, Instrument, Interval, CryptocomCandle)> for MarketEvent {
+    fn from(
+        (exchange_id, instrument, interval, candle): (
+            ExchangeId,
+            Instrument,
+            Interval,
+            CryptocomCandle,
+        ),
+    ) -> Self {
+        let end_time = interval.end_time(candle.start_time);
+
+        Self {
+            exchange_time: end_time,
+            received_time: Utc::now(),
+            received_instant: None,
+            raw: None,
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: DataKind::Candle(Candle {
+                start_time: candle.start_time,
+                end_time,
+                open: candle.open,
+                high: candle.high,
+                low: candle.low,
+                close: candle.close,
+                volume: candle.volume,
+                trade_count: 0,
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchange::datetime_utc_from_epoch_duration;
+    use std::time::Duration;
+
+    #[test]
+    fn test_deserialise_cryptocom_sub_response() {
+        struct TestCase {
+            input: &'static str,
+            expected: Option<CryptocomSubResponse>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: input response is successful subscription
+                input: r#"{"id":1,"method":"subscribe","code":0}"#,
+                expected: Some(CryptocomSubResponse::Subscribe {
+                    id: 1,
+                    code: 0,
+                    message: None,
+                }),
+            },
+            TestCase {
+                // TC1: input response is failed subscription
+                input: r#"{"id":1,"method":"subscribe","code":40003,"message":"BAD_REQUEST"}"#,
+                expected: Some(CryptocomSubResponse::Subscribe {
+                    id: 1,
+                    code: 40003,
+                    message: Some("BAD_REQUEST".to_owned()),
+                }),
+            },
+            TestCase {
+                // TC2: input is subscription data, not a subscription response
+                input: r#"{"id":-1,"method":"subscribe","code":0,"result":{"channel":"trade","subscription":"trade.BTC_USDT","data":[]}}"#,
+                expected: None,
+            },
+            TestCase {
+                // TC3: input is a heartbeat, not a subscription response
+                input: r#"{"id":1587523073344,"method":"public/heartbeat","code":0}"#,
+                expected: None,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = serde_json::from_str::<CryptocomSubResponse>(test.input).ok();
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_deserialise_cryptocom_message() {
+        struct TestCase {
+            input: &'static str,
+            expected: Option<CryptocomMessage>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: input is valid trade data
+                input: r#"{"id":-1,"method":"subscribe","code":0,"result":{"instrument_name":"BTC_USDT","subscription":"trade.BTC_USDT","channel":"trade","data":[{"d":"2030407068","t":1613581138462,"p":"51327.22","q":"0.000100","s":"BUY","i":"BTC_USDT"}]}}"#,
+                expected: Some(CryptocomMessage::Subscribe(CryptocomSubscribe {
+                    code: 0,
+                    message: None,
+                    result: Some(CryptocomResult::Trades {
+                        subscription_id: SubscriptionId::from("trade.BTC_USDT"),
+                        data: vec![CryptocomTrade {
+                            id: "2030407068".to_owned(),
+                            time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                                1613581138462,
+                            )),
+                            price: 51327.22,
+                            quantity: 0.0001,
+                            side: Side::Buy,
+                        }],
+                    }),
+                })),
+            },
+            TestCase {
+                // TC1: input is valid candlestick data
+                input: r#"{"id":-1,"method":"subscribe","code":0,"result":{"instrument_name":"BTC_USDT","subscription":"candlestick.1m.BTC_USDT","channel":"candlestick","interval":"1m","data":[{"o":"51140.21","c":"51141.83","h":"51143.75","l":"51140.21","v":"0.6349","t":1613580960000}]}}"#,
+                expected: Some(CryptocomMessage::Subscribe(CryptocomSubscribe {
+                    code: 0,
+                    message: None,
+                    result: Some(CryptocomResult::Candles {
+                        subscription_id: SubscriptionId::from("candlestick.1m.BTC_USDT"),
+                        data: vec![CryptocomCandle {
+                            start_time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                                1613580960000,
+                            )),
+                            open: 51140.21,
+                            high: 51143.75,
+                            low: 51140.21,
+                            close: 51141.83,
+                            volume: 0.6349,
+                        }],
+                    }),
+                })),
+            },
+            TestCase {
+                // TC2: input is a heartbeat
+                input: r#"{"id":1587523073344,"method":"public/heartbeat","code":0}"#,
+                expected: Some(CryptocomMessage::Heartbeat { id: 1587523073344 }),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = serde_json::from_str::<CryptocomMessage>(test.input).ok();
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
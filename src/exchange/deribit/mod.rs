@@ -0,0 +1,956 @@
+use crate::{
+    model::{
+        subscription::{SubKind, SubscriptionIds, SubscriptionMeta},
+        DataKind, OptionContract, OptionKind, OrderBook,
+    },
+    ExchangeId, ExchangeTransformer, MarketEvent, Subscriber, Subscription, Validator,
+};
+use barter_integration::{
+    error::SocketError,
+    model::{InstrumentKind, SubscriptionId},
+    protocol::websocket::WsMessage,
+    Transformer,
+};
+use model::{
+    DeribitMessage, DeribitOrderBook, DeribitOrderBookKind, DeribitResolution, DeribitSubResponse,
+};
+use serde_json::json;
+use std::collections::HashMap;
+use tokio::sync::mpsc;
+use tracing::debug;
+
+/// [`Deribit`] specific data structures.
+pub mod model;
+
+/// [`Deribit`] [`Subscriber`] & [`ExchangeTransformer`] implementor for the collection
+/// of `Spot` & `Futures` data.
+///
+/// Incremental OrderBook channels are validated using the [`Deribit`] change id continuity rules:
+///  - A snapshot resets the last `change_id` of the channel.
+///  - A change must carry a `prev_change_id` equal to the last `change_id`, else the channel is
+///    re-subscribed to in order to receive a fresh snapshot.
+///
+/// [`SubKind::OrderBookL2Managed`] channels apply the validated changes to an internally
+/// maintained [`OrderBook`], which is delivered in full after every update.
+#[derive(Debug)]
+pub struct Deribit {
+    pub ids: SubscriptionIds,
+    pub books: HashMap<SubscriptionId, u64>,
+    pub managed: HashMap<SubscriptionId, OrderBook>,
+    pub ws_sink_tx: mpsc::UnboundedSender<WsMessage>,
+}
+
+impl Subscriber for Deribit {
+    type SubResponse = DeribitSubResponse;
+
+    fn base_url() -> &'static str {
+        ExchangeId::Deribit.ws_base_url()
+    }
+
+    fn build_subscription_meta(
+        subscriptions: &[Subscription],
+    ) -> Result<SubscriptionMeta, SocketError> {
+        // Allocate SubscriptionIds HashMap to track identifiers for each actioned Subscription
+        let mut ids = SubscriptionIds(HashMap::with_capacity(subscriptions.len()));
+
+        // Map Barter Subscriptions to Deribit channels
+        let subscriptions = subscriptions
+            .iter()
+            .map(|subscription| {
+                // Determine the Deribit specific channel for this Barter Subscription
+                let channel = Self::build_channel(subscription)?;
+
+                // Use the channel as the SubscriptionId key in the SubscriptionIds HashMap
+                // eg/ SubscriptionId("book.BTC-PERPETUAL.100ms")
+                ids.insert(SubscriptionId::from(channel.as_str()), subscription.clone());
+
+                // Construct Deribit specific subscription message
+                Ok(Self::request(Self::METHOD_SUBSCRIBE, &channel))
+            })
+            .collect::<Result<Vec<_>, SocketError>>()?;
+
+        Ok(SubscriptionMeta {
+            ids,
+            expected_responses: subscriptions.len(),
+            subscriptions,
+        })
+    }
+}
+
+impl ExchangeTransformer for Deribit {
+    const EXCHANGE: ExchangeId = ExchangeId::Deribit;
+
+    fn new(ws_sink_tx: mpsc::UnboundedSender<WsMessage>, ids: SubscriptionIds) -> Self {
+        Self {
+            ids,
+            books: HashMap::new(),
+            managed: HashMap::new(),
+            ws_sink_tx,
+        }
+    }
+}
+
+impl Transformer<MarketEvent> for Deribit {
+    type Input = DeribitMessage;
+    type OutputIter = Vec<Result<MarketEvent, SocketError>>;
+
+    fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+        match input {
+            DeribitMessage::OrderBook(notification) => self.transform_order_book(
+                notification.params.subscription_id,
+                notification.params.data,
+            ),
+            DeribitMessage::Trades(notification) => {
+                // Determine Instrument associated with this DeribitTradesNotification
+                let instrument = match self
+                    .ids
+                    .find_instrument(&notification.params.subscription_id)
+                {
+                    Ok(instrument) => instrument,
+                    Err(error) => return vec![Err(error)],
+                };
+
+                notification
+                    .params
+                    .data
+                    .into_iter()
+                    .map(|trade| {
+                        Ok(MarketEvent::from((
+                            Deribit::EXCHANGE,
+                            instrument.clone(),
+                            trade,
+                        )))
+                    })
+                    .collect()
+            }
+            DeribitMessage::Candle(notification) => {
+                // Determine Instrument & Interval associated with this DeribitCandleNotification
+                match self.ids.get(&notification.params.subscription_id) {
+                    Some(Subscription {
+                        instrument,
+                        kind: SubKind::Candle(interval),
+                        ..
+                    }) => vec![Ok(MarketEvent::from((
+                        Deribit::EXCHANGE,
+                        instrument.clone(),
+                        *interval,
+                        notification.params.data,
+                    )))],
+                    _ => vec![Err(SocketError::Unidentifiable(
+                        notification.params.subscription_id,
+                    ))],
+                }
+            }
+            DeribitMessage::Ticker(notification) => {
+                // Determine Instrument associated with this DeribitTickerNotification
+                match self
+                    .ids
+                    .find_instrument(&notification.params.subscription_id)
+                {
+                    Ok(instrument) => vec![Ok(MarketEvent::from((
+                        Deribit::EXCHANGE,
+                        instrument,
+                        notification.params.data,
+                    )))],
+                    Err(error) => vec![Err(error)],
+                }
+            }
+            DeribitMessage::Response(response) => match response.validate() {
+                Ok(response) => {
+                    debug!(exchange_id = %Deribit::EXCHANGE, ?response, "received response");
+                    vec![]
+                }
+                Err(error) => vec![Err(error)],
+            },
+        }
+    }
+}
+
+impl Deribit {
+    /// [`Deribit`] incremental OrderBook channel name. The full channel also contains the
+    /// instrument & the interval.
+    ///
+    /// eg/ "book.BTC-PERPETUAL.100ms"
+    ///
+    /// See docs: <https://docs.deribit.com/#book-instrument_name-interval>
+    pub const CHANNEL_ORDER_BOOK: &'static str = "book";
+
+    /// [`Deribit`] public trades channel name. The full channel also contains the instrument &
+    /// the interval.
+    ///
+    /// eg/ "trades.BTC-PERPETUAL.100ms"
+    ///
+    /// See docs: <https://docs.deribit.com/#trades-instrument_name-interval>
+    pub const CHANNEL_TRADES: &'static str = "trades";
+
+    /// [`Deribit`] candle channel name. The full channel also contains the instrument & the
+    /// [`DeribitResolution`].
+    ///
+    /// eg/ "chart.trades.BTC-PERPETUAL.1"
+    ///
+    /// See docs: <https://docs.deribit.com/#chart-trades-instrument_name-resolution>
+    pub const CHANNEL_CHART_TRADES: &'static str = "chart.trades";
+
+    /// [`Deribit`] ticker channel name, used for option mark prices & greeks. The full channel
+    /// also contains the instrument & the interval.
+    ///
+    /// eg/ "ticker.BTC-27DEC24-50000-C.100ms"
+    ///
+    /// See docs: <https://docs.deribit.com/#ticker-instrument_name-interval>
+    pub const CHANNEL_TICKER: &'static str = "ticker";
+
+    /// Format of the expiry date within [`Deribit`] option instrument names (eg/ "27DEC24").
+    pub const OPTION_EXPIRY_FORMAT: &'static str = "%-d%b%y";
+
+    /// [`Deribit`] OrderBook & trades interval delivering every change individually. Note that this
+    /// interval is only available to authorised connections.
+    pub const INTERVAL_RAW: &'static str = "raw";
+
+    /// [`Deribit`] OrderBook & trades interval delivering changes aggregated over 100ms.
+    pub const INTERVAL_100MS: &'static str = "100ms";
+
+    /// [`Deribit`] JSON-RPC subscribe method.
+    ///
+    /// See docs: <https://docs.deribit.com/#public-subscribe>
+    pub const METHOD_SUBSCRIBE: &'static str = "public/subscribe";
+
+    /// [`Deribit`] JSON-RPC unsubscribe method.
+    ///
+    /// See docs: <https://docs.deribit.com/#public-unsubscribe>
+    pub const METHOD_UNSUBSCRIBE: &'static str = "public/unsubscribe";
+
+    /// Determine the [`Deribit`] channel associated with an input Barter [`Subscription`].
+    ///
+    /// Example Ok return: Ok("book.BTC-PERPETUAL.100ms")
+    pub fn build_channel(sub: &Subscription) -> Result<String, SocketError> {
+        // Validate provided Subscription InstrumentKind is supported by Deribit
+        let sub = sub.validate()?;
+
+        // Determine Deribit instrument name using the Instrument (eg/ "BTC-PERPETUAL")
+        let instrument = Self::instrument_name(sub);
+
+        match &sub.kind {
+            SubKind::Trade => Ok(format!(
+                "{}.{instrument}.{}",
+                Self::CHANNEL_TRADES,
+                Self::INTERVAL_100MS
+            )),
+            SubKind::Candle(interval) => Ok(format!(
+                "{}.{instrument}.{}",
+                Self::CHANNEL_CHART_TRADES,
+                DeribitResolution::try_from(interval)?.0
+            )),
+            SubKind::OrderBookL2Delta | SubKind::OrderBookL2Managed => Ok(format!(
+                "{}.{instrument}.{}",
+                Self::CHANNEL_ORDER_BOOK,
+                Self::INTERVAL_100MS
+            )),
+            SubKind::OptionTicker => Ok(format!(
+                "{}.{}.{}",
+                Self::CHANNEL_TICKER,
+                Self::option_name(&OptionContract::try_from(&sub.instrument)?),
+                Self::INTERVAL_100MS
+            )),
+            other => Err(SocketError::Unsupported {
+                entity: Self::EXCHANGE.as_str(),
+                item: other.to_string(),
+            }),
+        }
+    }
+
+    /// Determine the [`Deribit`] instrument name associated with an input Barter
+    /// [`Subscription`]. Inverse perpetuals are quoted in "usd" & have no quote in their name.
+    ///
+    /// eg/ "BTC_USDC", "BTC-PERPETUAL", "ETH_USDC-PERPETUAL"
+    pub fn instrument_name(sub: &Subscription) -> String {
+        let base = sub.instrument.base.as_ref().to_uppercase();
+        let quote = sub.instrument.quote.as_ref().to_uppercase();
+
+        match sub.instrument.kind {
+            InstrumentKind::FuturePerpetual if quote == "USD" => format!("{base}-PERPETUAL"),
+            InstrumentKind::FuturePerpetual => format!("{base}_{quote}-PERPETUAL"),
+            InstrumentKind::Spot => format!("{base}_{quote}"),
+        }
+    }
+
+    /// Determine the [`Deribit`] option instrument name associated with an input Barter
+    /// [`OptionContract`]. Inverse options are quoted in "usd" & have no quote in their name, and
+    /// decimal strikes use a "d" in place of the decimal point.
+    ///
+    /// eg/ "BTC-27DEC24-50000-C", "XRP_USDC-27DEC24-0d625-P"
+    pub fn option_name(contract: &OptionContract) -> String {
+        let underlying = match contract.quote.as_str() {
+            "usd" => contract.underlying.to_uppercase(),
+            quote => format!("{}_{}", contract.underlying, quote).to_uppercase(),
+        };
+        let kind = match contract.kind {
+            OptionKind::Call => "C",
+            OptionKind::Put => "P",
+        };
+
+        format!(
+            "{underlying}-{}-{}-{kind}",
+            contract
+                .expiry
+                .format(Self::OPTION_EXPIRY_FORMAT)
+                .to_string()
+                .to_uppercase(),
+            contract.strike.to_string().replace('.', "d")
+        )
+    }
+
+    /// Build a [`Deribit`] compatible JSON-RPC request for the provided method & channel.
+    ///
+    /// eg/ {"jsonrpc":"2.0","method":"public/subscribe","params":{"channels":["book.BTC-PERPETUAL.100ms"]}}
+    pub fn request(method: &str, channel: &str) -> WsMessage {
+        WsMessage::Text(
+            json!({
+                "jsonrpc": "2.0",
+                "method": method,
+                "params": {
+                    "channels": [channel],
+                },
+            })
+            .to_string(),
+        )
+    }
+
+    /// Validate the continuity of a [`DeribitOrderBook`] & transform it into a [`MarketEvent`]
+    /// containing an [`OrderBook`](crate::model::OrderBook) snapshot or an
+    /// [`OrderBookL2Delta`](crate::model::OrderBookL2Delta).
+    fn transform_order_book(
+        &mut self,
+        subscription_id: SubscriptionId,
+        book: DeribitOrderBook,
+    ) -> Vec<Result<MarketEvent, SocketError>> {
+        // Determine Instrument associated with this DeribitOrderBook message
+        let instrument = match self.ids.find_instrument(&subscription_id) {
+            Ok(instrument) => instrument,
+            Err(error) => return vec![Err(error)],
+        };
+
+        match book.kind {
+            DeribitOrderBookKind::Snapshot => {
+                self.books.insert(subscription_id.clone(), book.change_id);
+            }
+            DeribitOrderBookKind::Change => match self.books.get_mut(&subscription_id) {
+                Some(last) if book.prev_change_id == Some(*last) => {
+                    *last = book.change_id;
+                }
+                last => {
+                    let error = SocketError::Exchange(format!(
+                        "{} OrderBook change is discontinuous with last change_id: {:?}, prev_change_id: {:?}",
+                        subscription_id, last, book.prev_change_id
+                    ));
+                    self.books.remove(&subscription_id);
+                    self.managed.remove(&subscription_id);
+                    self.resubscribe(&subscription_id);
+                    return vec![Err(error)];
+                }
+            },
+        }
+
+        let mut event = MarketEvent::from((Deribit::EXCHANGE, instrument, book));
+
+        // Apply validated changes to the managed OrderBook & deliver it in full
+        let managed = self
+            .ids
+            .get(&subscription_id)
+            .is_some_and(|subscription| subscription.kind == SubKind::OrderBookL2Managed);
+        if managed {
+            match &event.kind {
+                DataKind::OrderBook(snapshot) => {
+                    self.managed.insert(subscription_id, snapshot.clone());
+                }
+                DataKind::OrderBookL2Delta(delta) => match self.managed.get_mut(&subscription_id) {
+                    Some(book) => {
+                        book.apply(delta);
+                        event.kind = DataKind::OrderBook(book.clone());
+                    }
+                    None => return vec![],
+                },
+                _ => {}
+            }
+        }
+
+        vec![Ok(event)]
+    }
+
+    /// Re-subscribe to the provided channel in order to receive a fresh OrderBook snapshot.
+    fn resubscribe(&self, subscription_id: &SubscriptionId) {
+        debug!(
+            exchange_id = %Deribit::EXCHANGE,
+            %subscription_id,
+            "re-subscribing to recover OrderBook snapshot"
+        );
+        for method in [Self::METHOD_UNSUBSCRIBE, Self::METHOD_SUBSCRIBE] {
+            let _ = self
+                .ws_sink_tx
+                .send(Self::request(method, subscription_id.as_ref()));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{
+        subscription::Interval, Candle, DataKind, Level, OptionTicker, OrderBook, OrderBookL2Delta,
+        PublicTrade,
+    };
+    use barter_integration::model::{Exchange, Instrument, Side};
+    use chrono::{NaiveDate, Utc};
+    use model::{DeribitLevel, DeribitLevelAction};
+
+    #[test]
+    fn test_build_channel() {
+        struct TestCase {
+            input: Subscription,
+            expected: Result<String, SocketError>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: Supported InstrumentKind::FuturePerpetual inverse L2 delta subscription
+                input: Subscription::new(
+                    ExchangeId::Deribit,
+                    ("btc", "usd", InstrumentKind::FuturePerpetual),
+                    SubKind::OrderBookL2Delta,
+                ),
+                expected: Ok("book.BTC-PERPETUAL.100ms".to_owned()),
+            },
+            TestCase {
+                // TC1: Supported InstrumentKind::FuturePerpetual linear L2 delta subscription
+                input: Subscription::new(
+                    ExchangeId::Deribit,
+                    ("eth", "usdc", InstrumentKind::FuturePerpetual),
+                    SubKind::OrderBookL2Delta,
+                ),
+                expected: Ok("book.ETH_USDC-PERPETUAL.100ms".to_owned()),
+            },
+            TestCase {
+                // TC2: Supported InstrumentKind::Spot L2 delta subscription
+                input: Subscription::new(
+                    ExchangeId::Deribit,
+                    ("btc", "usdc", InstrumentKind::Spot),
+                    SubKind::OrderBookL2Delta,
+                ),
+                expected: Ok("book.BTC_USDC.100ms".to_owned()),
+            },
+            TestCase {
+                // TC3: Supported InstrumentKind::FuturePerpetual trades subscription
+                input: Subscription::new(
+                    ExchangeId::Deribit,
+                    ("btc", "usd", InstrumentKind::FuturePerpetual),
+                    SubKind::Trade,
+                ),
+                expected: Ok("trades.BTC-PERPETUAL.100ms".to_owned()),
+            },
+            TestCase {
+                // TC4: Supported InstrumentKind::FuturePerpetual candles subscription
+                input: Subscription::new(
+                    ExchangeId::Deribit,
+                    ("btc", "usd", InstrumentKind::FuturePerpetual),
+                    SubKind::Candle(Interval::Hour1),
+                ),
+                expected: Ok("chart.trades.BTC-PERPETUAL.60".to_owned()),
+            },
+            TestCase {
+                // TC5: Unsupported candles Interval
+                input: Subscription::new(
+                    ExchangeId::Deribit,
+                    ("btc", "usd", InstrumentKind::FuturePerpetual),
+                    SubKind::Candle(Interval::Hour4),
+                ),
+                expected: Err(SocketError::Unsupported {
+                    entity: "",
+                    item: "".to_string(),
+                }),
+            },
+            TestCase {
+                // TC6: Unsupported L3 delta subscription
+                input: Subscription::new(
+                    ExchangeId::Deribit,
+                    ("btc", "usd", InstrumentKind::FuturePerpetual),
+                    SubKind::OrderBookL3Delta,
+                ),
+                expected: Err(SocketError::Unsupported {
+                    entity: "",
+                    item: "".to_string(),
+                }),
+            },
+            TestCase {
+                // TC7: Supported inverse call option ticker subscription
+                input: Subscription::new(
+                    ExchangeId::Deribit,
+                    Instrument::from(&OptionContract {
+                        underlying: "btc".to_owned(),
+                        quote: "usd".to_owned(),
+                        expiry: NaiveDate::from_ymd_opt(2024, 12, 27).unwrap(),
+                        strike: 50000.0,
+                        kind: OptionKind::Call,
+                    }),
+                    SubKind::OptionTicker,
+                ),
+                expected: Ok("ticker.BTC-27DEC24-50000-C.100ms".to_owned()),
+            },
+            TestCase {
+                // TC8: Supported linear put option ticker subscription w/ decimal strike
+                input: Subscription::new(
+                    ExchangeId::Deribit,
+                    Instrument::from(&OptionContract {
+                        underlying: "xrp".to_owned(),
+                        quote: "usdc".to_owned(),
+                        expiry: NaiveDate::from_ymd_opt(2024, 3, 8).unwrap(),
+                        strike: 0.625,
+                        kind: OptionKind::Put,
+                    }),
+                    SubKind::OptionTicker,
+                ),
+                expected: Ok("ticker.XRP_USDC-8MAR24-0d625-P.100ms".to_owned()),
+            },
+            TestCase {
+                // TC9: Unsupported option ticker subscription for a non option Instrument
+                input: Subscription::new(
+                    ExchangeId::Deribit,
+                    ("btc", "usdc", InstrumentKind::Spot),
+                    SubKind::OptionTicker,
+                ),
+                expected: Err(SocketError::Unsupported {
+                    entity: "",
+                    item: "".to_string(),
+                }),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = Deribit::build_channel(&test.input);
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => {
+                    assert_eq!(actual, expected, "TC{} failed", index)
+                }
+                (Err(_), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_deribit_transform_order_book_continuity() {
+        let (ws_sink_tx, mut ws_sink_rx) = mpsc::unbounded_channel();
+        let subscription_id = SubscriptionId::from("book.BTC-PERPETUAL.100ms");
+        let mut transformer = Deribit::new(
+            ws_sink_tx,
+            SubscriptionIds(HashMap::from([(
+                subscription_id.clone(),
+                Subscription::new(
+                    ExchangeId::Deribit,
+                    ("btc", "usd", InstrumentKind::FuturePerpetual),
+                    SubKind::OrderBookL2Delta,
+                ),
+            )])),
+        );
+
+        let time = Utc::now();
+        let book = |kind, change_id, prev_change_id| {
+            DeribitMessage::OrderBook(model::DeribitOrderBookNotification {
+                params: model::DeribitOrderBookParams {
+                    subscription_id: subscription_id.clone(),
+                    data: DeribitOrderBook {
+                        kind,
+                        time,
+                        change_id,
+                        prev_change_id,
+                        bids: vec![DeribitLevel(DeribitLevelAction::New, 100.0, 1.0)],
+                        asks: vec![DeribitLevel(DeribitLevelAction::Delete, 101.0, 0.0)],
+                    },
+                },
+            })
+        };
+        let market_event = |kind| MarketEvent {
+            exchange_time: time,
+            received_time: time,
+            received_instant: None,
+            raw: None,
+            exchange: Exchange::from(ExchangeId::Deribit),
+            instrument: Instrument::from(("btc", "usd", InstrumentKind::FuturePerpetual)),
+            kind,
+        };
+
+        struct TestCase {
+            input: DeribitMessage,
+            expected: Vec<Result<MarketEvent, SocketError>>,
+            expected_resubscribe: bool,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: change before any snapshot is discontinuous
+                input: book(DeribitOrderBookKind::Change, 2, Some(1)),
+                expected: vec![Err(SocketError::Exchange("".to_owned()))],
+                expected_resubscribe: true,
+            },
+            TestCase {
+                // TC1: snapshot resets the book
+                input: book(DeribitOrderBookKind::Snapshot, 10, None),
+                expected: vec![Ok(market_event(DataKind::OrderBook(OrderBook {
+                    last_update_time: time,
+                    last_update_id: 10,
+                    bids: vec![Level::new(100.0, 1.0)],
+                    asks: vec![Level::new(101.0, 0.0)],
+                })))],
+                expected_resubscribe: false,
+            },
+            TestCase {
+                // TC2: continuous change is transformed into an OrderBookL2Delta
+                input: book(DeribitOrderBookKind::Change, 11, Some(10)),
+                expected: vec![Ok(market_event(DataKind::OrderBookL2Delta(
+                    OrderBookL2Delta {
+                        last_update_time: time,
+                        first_update_id: None,
+                        last_update_id: 11,
+                        bids: vec![Level::new(100.0, 1.0)],
+                        asks: vec![Level::new(101.0, 0.0)],
+                    },
+                )))],
+                expected_resubscribe: false,
+            },
+            TestCase {
+                // TC3: change w/ prev_change_id gap triggers a re-subscription
+                input: book(DeribitOrderBookKind::Change, 14, Some(13)),
+                expected: vec![Err(SocketError::Exchange("".to_owned()))],
+                expected_resubscribe: true,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = transformer.transform(test.input);
+            assert_eq!(
+                actual.len(),
+                test.expected.len(),
+                "TestCase {} failed at vector length assert_eq with actual: {:?}",
+                index,
+                actual
+            );
+
+            for (vector_index, (actual, expected)) in
+                actual.into_iter().zip(test.expected).enumerate()
+            {
+                match (actual, expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        // Scrub Utc::now() timestamps to allow comparison
+                        let actual = MarketEvent {
+                            received_time: time,
+                            received_instant: None,
+                            raw: None,
+                            ..actual
+                        };
+                        assert_eq!(
+                            actual, expected,
+                            "TC{} failed at vector index {}",
+                            index, vector_index
+                        )
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed at vector index {vector_index} because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+
+            let resubscribed = std::iter::from_fn(|| ws_sink_rx.try_recv().ok()).count() == 2;
+            assert_eq!(
+                resubscribed, test.expected_resubscribe,
+                "TC{} failed re-subscription assert",
+                index
+            );
+        }
+    }
+
+    #[test]
+    fn test_deribit_transform_managed_order_book() {
+        let (ws_sink_tx, _ws_sink_rx) = mpsc::unbounded_channel();
+        let subscription_id = SubscriptionId::from("book.BTC-PERPETUAL.100ms");
+        let mut transformer = Deribit::new(
+            ws_sink_tx,
+            SubscriptionIds(HashMap::from([(
+                subscription_id.clone(),
+                Subscription::new(
+                    ExchangeId::Deribit,
+                    ("btc", "usd", InstrumentKind::FuturePerpetual),
+                    SubKind::OrderBookL2Managed,
+                ),
+            )])),
+        );
+
+        let time = Utc::now();
+        let book = |kind, change_id, prev_change_id, bids, asks| {
+            DeribitMessage::OrderBook(model::DeribitOrderBookNotification {
+                params: model::DeribitOrderBookParams {
+                    subscription_id: subscription_id.clone(),
+                    data: DeribitOrderBook {
+                        kind,
+                        time,
+                        change_id,
+                        prev_change_id,
+                        bids,
+                        asks,
+                    },
+                },
+            })
+        };
+
+        struct TestCase {
+            input: DeribitMessage,
+            expected: Option<OrderBook>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: change before any snapshot is not delivered
+                input: book(DeribitOrderBookKind::Change, 2, Some(1), vec![], vec![]),
+                expected: None,
+            },
+            TestCase {
+                // TC1: snapshot initialises the managed book
+                input: book(
+                    DeribitOrderBookKind::Snapshot,
+                    10,
+                    None,
+                    vec![DeribitLevel(DeribitLevelAction::New, 100.0, 1.0)],
+                    vec![
+                        DeribitLevel(DeribitLevelAction::New, 101.0, 2.0),
+                        DeribitLevel(DeribitLevelAction::New, 102.0, 1.0),
+                    ],
+                ),
+                expected: Some(OrderBook {
+                    last_update_time: time,
+                    last_update_id: 10,
+                    bids: vec![Level::new(100.0, 1.0)],
+                    asks: vec![Level::new(101.0, 2.0), Level::new(102.0, 1.0)],
+                }),
+            },
+            TestCase {
+                // TC2: continuous change is applied & the full managed book delivered
+                input: book(
+                    DeribitOrderBookKind::Change,
+                    11,
+                    Some(10),
+                    vec![DeribitLevel(DeribitLevelAction::New, 99.0, 3.0)],
+                    vec![DeribitLevel(DeribitLevelAction::Delete, 101.0, 0.0)],
+                ),
+                expected: Some(OrderBook {
+                    last_update_time: time,
+                    last_update_id: 11,
+                    bids: vec![Level::new(100.0, 1.0), Level::new(99.0, 3.0)],
+                    asks: vec![Level::new(102.0, 1.0)],
+                }),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual =
+                transformer
+                    .transform(test.input)
+                    .into_iter()
+                    .find_map(|event| match event.map(|event| event.kind) {
+                        Ok(DataKind::OrderBook(book)) => Some(book),
+                        _ => None,
+                    });
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_deribit_transform_trades_and_candles() {
+        let (ws_sink_tx, _ws_sink_rx) = mpsc::unbounded_channel();
+        let mut transformer = Deribit::new(
+            ws_sink_tx,
+            SubscriptionIds(HashMap::from([
+                (
+                    SubscriptionId::from("trades.BTC-PERPETUAL.100ms"),
+                    Subscription::new(
+                        ExchangeId::Deribit,
+                        ("btc", "usd", InstrumentKind::FuturePerpetual),
+                        SubKind::Trade,
+                    ),
+                ),
+                (
+                    SubscriptionId::from("chart.trades.BTC-PERPETUAL.1"),
+                    Subscription::new(
+                        ExchangeId::Deribit,
+                        ("btc", "usd", InstrumentKind::FuturePerpetual),
+                        SubKind::Candle(Interval::Minute1),
+                    ),
+                ),
+            ])),
+        );
+
+        let time = Utc::now();
+
+        struct TestCase {
+            input: DeribitMessage,
+            expected: Vec<Option<DataKind>>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: trades notification w/ known SubscriptionId
+                input: DeribitMessage::Trades(model::DeribitTradesNotification {
+                    params: model::DeribitTradesParams {
+                        subscription_id: SubscriptionId::from("trades.BTC-PERPETUAL.100ms"),
+                        data: vec![model::DeribitTrade {
+                            id: "1".to_owned(),
+                            time,
+                            price: 100.0,
+                            quantity: 10.0,
+                            side: Side::Buy,
+                        }],
+                    },
+                }),
+                expected: vec![Some(DataKind::Trade(PublicTrade {
+                    id: "1".to_owned(),
+                    price: 100.0,
+                    quantity: 10.0,
+                    side: Side::Buy,
+                }))],
+            },
+            TestCase {
+                // TC1: chart.trades notification w/ known SubscriptionId
+                input: DeribitMessage::Candle(model::DeribitCandleNotification {
+                    params: model::DeribitCandleParams {
+                        subscription_id: SubscriptionId::from("chart.trades.BTC-PERPETUAL.1"),
+                        data: model::DeribitCandle {
+                            start_time: time,
+                            open: 1.0,
+                            high: 2.0,
+                            low: 0.5,
+                            close: 1.5,
+                            volume: 10.0,
+                        },
+                    },
+                }),
+                expected: vec![Some(DataKind::Candle(Candle {
+                    start_time: time,
+                    end_time: time + chrono::Duration::minutes(1),
+                    open: 1.0,
+                    high: 2.0,
+                    low: 0.5,
+                    close: 1.5,
+                    volume: 10.0,
+                    trade_count: 0,
+                }))],
+            },
+            TestCase {
+                // TC2: trades notification w/ unknown SubscriptionId
+                input: DeribitMessage::Trades(model::DeribitTradesNotification {
+                    params: model::DeribitTradesParams {
+                        subscription_id: SubscriptionId::from("trades.ETH-PERPETUAL.100ms"),
+                        data: vec![],
+                    },
+                }),
+                expected: vec![None],
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = transformer
+                .transform(test.input)
+                .into_iter()
+                .map(|event| event.ok().map(|event| event.kind))
+                .collect::<Vec<_>>();
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_deribit_transform_option_ticker() {
+        let (ws_sink_tx, _ws_sink_rx) = mpsc::unbounded_channel();
+        let instrument = Instrument::from(&OptionContract {
+            underlying: "btc".to_owned(),
+            quote: "usd".to_owned(),
+            expiry: NaiveDate::from_ymd_opt(2024, 12, 27).unwrap(),
+            strike: 50000.0,
+            kind: OptionKind::Call,
+        });
+        let mut transformer = Deribit::new(
+            ws_sink_tx,
+            SubscriptionIds(HashMap::from([(
+                SubscriptionId::from("ticker.BTC-27DEC24-50000-C.100ms"),
+                Subscription::new(
+                    ExchangeId::Deribit,
+                    instrument.clone(),
+                    SubKind::OptionTicker,
+                ),
+            )])),
+        );
+
+        let time = Utc::now();
+        let ticker = |subscription_id| {
+            DeribitMessage::Ticker(model::DeribitTickerNotification {
+                params: model::DeribitTickerParams {
+                    subscription_id: SubscriptionId::from(subscription_id),
+                    data: model::DeribitTicker {
+                        time,
+                        mark_price: 0.0845,
+                        mark_iv: 55.4,
+                        underlying_price: 51000.5,
+                        greeks: model::DeribitGreeks {
+                            delta: 0.61,
+                            gamma: 0.00002,
+                            vega: 95.3,
+                            theta: -40.1,
+                        },
+                    },
+                },
+            })
+        };
+
+        struct TestCase {
+            input: DeribitMessage,
+            expected: Option<(Instrument, DataKind)>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: ticker notification w/ known SubscriptionId
+                input: ticker("ticker.BTC-27DEC24-50000-C.100ms"),
+                expected: Some((
+                    instrument,
+                    DataKind::OptionTicker(OptionTicker {
+                        mark_price: 0.0845,
+                        mark_iv: 55.4,
+                        underlying_price: 51000.5,
+                        delta: 0.61,
+                        gamma: 0.00002,
+                        vega: 95.3,
+                        theta: -40.1,
+                    }),
+                )),
+            },
+            TestCase {
+                // TC1: ticker notification w/ unknown SubscriptionId
+                input: ticker("ticker.BTC-27DEC24-60000-C.100ms"),
+                expected: None,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = transformer
+                .transform(test.input)
+                .into_iter()
+                .next()
+                .and_then(|event| event.ok())
+                .map(|event| (event.instrument, event.kind));
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
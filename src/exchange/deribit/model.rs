@@ -0,0 +1,589 @@
+use crate::{
+    exchange::de_u64_epoch_ms_as_datetime_utc,
+    model::{
+        subscription::Interval, Candle, DataKind, Level, OptionTicker, OrderBook, OrderBookL2Delta,
+        PublicTrade,
+    },
+    ExchangeId, MarketEvent, Validator,
+};
+use barter_integration::{
+    error::SocketError,
+    model::{Exchange, Instrument, Side, SubscriptionId},
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// [`Deribit`](super::Deribit) JSON-RPC message received in response to WebSocket subscription
+/// requests.
+///
+/// eg/ DeribitSubResponse::Subscribed {"jsonrpc":"2.0","id":1,"result":["book.BTC-PERPETUAL.100ms"]}
+/// eg/ DeribitSubResponse::Error {"jsonrpc":"2.0","id":1,"error":{"code":-32602,"message":"Invalid params"}}
+///
+/// See docs: <https://docs.deribit.com/#public-subscribe>
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum DeribitSubResponse {
+    Subscribed { result: Vec<String> },
+    Error { error: DeribitError },
+}
+
+/// [`Deribit`](super::Deribit) JSON-RPC error.
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct DeribitError {
+    pub code: i64,
+    pub message: String,
+}
+
+impl Validator for DeribitSubResponse {
+    fn validate(self) -> Result<Self, SocketError>
+    where
+        Self: Sized,
+    {
+        match &self {
+            DeribitSubResponse::Subscribed { .. } => Ok(self),
+            DeribitSubResponse::Error { error } => Err(SocketError::Subscribe(format!(
+                "received failure subscription response code: {} with message: {}",
+                error.code, error.message
+            ))),
+        }
+    }
+}
+
+/// [`Deribit`](super::Deribit) message variants that can be received over [`WebSocket`].
+///
+/// See docs: <https://docs.deribit.com/#subscriptions>
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum DeribitMessage {
+    OrderBook(DeribitOrderBookNotification),
+    Trades(DeribitTradesNotification),
+    Candle(DeribitCandleNotification),
+    Ticker(DeribitTickerNotification),
+    Response(DeribitSubResponse),
+}
+
+/// [`Deribit`](super::Deribit) subscription notification containing a [`DeribitOrderBook`].
+///
+/// eg/ {"jsonrpc":"2.0","method":"subscription","params":{"channel":"book.BTC-PERPETUAL.100ms","data":{...}}}
+///
+/// See docs: <https://docs.deribit.com/#book-instrument_name-interval>
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct DeribitOrderBookNotification {
+    pub params: DeribitOrderBookParams,
+}
+
+/// Parameters of a [`DeribitOrderBookNotification`].
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct DeribitOrderBookParams {
+    #[serde(alias = "channel")]
+    pub subscription_id: SubscriptionId,
+    pub data: DeribitOrderBook,
+}
+
+/// [`Deribit`](super::Deribit) incremental OrderBook snapshot or change. Each change carries the
+/// `prev_change_id` it follows on from, which must equal the last received `change_id`.
+///
+/// eg/ {"type":"change","timestamp":1671217200000,"instrument_name":"BTC-PERPETUAL",
+///      "change_id":2,"prev_change_id":1,"bids":[["new",16000.0,10.0]],"asks":[["delete",16001.0,0.0]]}
+///
+/// See docs: <https://docs.deribit.com/#book-instrument_name-interval>
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct DeribitOrderBook {
+    #[serde(rename = "type")]
+    pub kind: DeribitOrderBookKind,
+    #[serde(
+        alias = "timestamp",
+        deserialize_with = "de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+    #[serde(deserialize_with = "crate::exchange::de_str")]
+    pub change_id: u64,
+    #[serde(default, deserialize_with = "crate::exchange::de_str_opt")]
+    pub prev_change_id: Option<u64>,
+    pub bids: Vec<DeribitLevel>,
+    pub asks: Vec<DeribitLevel>,
+}
+
+/// Type of [`DeribitOrderBook`] message.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DeribitOrderBookKind {
+    Snapshot,
+    Change,
+}
+
+/// [`Deribit`](super::Deribit) OrderBook level change in the format [action, price, amount].
+///
+/// eg/ ["new", 16000.0, 10.0]
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct DeribitLevel(
+    pub DeribitLevelAction,
+    #[serde(deserialize_with = "crate::exchange::de_str")] pub f64,
+    #[serde(deserialize_with = "crate::exchange::de_str")] pub f64,
+);
+
+/// Action applied to a [`DeribitLevel`].
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DeribitLevelAction {
+    New,
+    Change,
+    Delete,
+}
+
+impl From<DeribitLevel> for Level {
+    fn from(DeribitLevel(action, price, amount): DeribitLevel) -> Self {
+        match action {
+            DeribitLevelAction::Delete => Level::new(price, 0.0),
+            DeribitLevelAction::New | DeribitLevelAction::Change => Level::new(price, amount),
+        }
+    }
+}
+
+impl From<(ExchangeId, Instrument, DeribitOrderBook)> for MarketEvent {
+    fn from((exchange_id, instrument, book): (ExchangeId, Instrument, DeribitOrderBook)) -> Self {
+        let bids = book.bids.into_iter().map(Level::from).collect();
+        let asks = book.asks.into_iter().map(Level::from).collect();
+
+        let kind = match book.kind {
+            DeribitOrderBookKind::Snapshot => DataKind::OrderBook(OrderBook {
+                last_update_time: book.time,
+                last_update_id: book.change_id,
+                bids,
+                asks,
+            }),
+            DeribitOrderBookKind::Change => DataKind::OrderBookL2Delta(OrderBookL2Delta {
+                last_update_time: book.time,
+                first_update_id: None,
+                last_update_id: book.change_id,
+                bids,
+                asks,
+            }),
+        };
+
+        Self {
+            exchange_time: book.time,
+            received_time: Utc::now(),
+            received_instant: None,
+            raw: None,
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind,
+        }
+    }
+}
+
+/// [`Deribit`](super::Deribit) subscription notification containing [`DeribitTrade`]s.
+///
+/// eg/ {"jsonrpc":"2.0","method":"subscription","params":{"channel":"trades.BTC-PERPETUAL.100ms","data":[{...}]}}
+///
+/// See docs: <https://docs.deribit.com/#trades-instrument_name-interval>
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct DeribitTradesNotification {
+    pub params: DeribitTradesParams,
+}
+
+/// Parameters of a [`DeribitTradesNotification`].
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct DeribitTradesParams {
+    #[serde(alias = "channel")]
+    pub subscription_id: SubscriptionId,
+    pub data: Vec<DeribitTrade>,
+}
+
+/// [`Deribit`](super::Deribit) public trade. The `amount` of perpetuals is denominated in USD.
+///
+/// eg/ {"trade_seq":30289432,"trade_id":"48079254","timestamp":1590484156350,"tick_direction":0,
+///      "price":8950.0,"mark_price":8948.9,"instrument_name":"BTC-PERPETUAL",
+///      "index_price":8955.88,"direction":"sell","amount":10.0}
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct DeribitTrade {
+    #[serde(rename = "trade_id")]
+    pub id: String,
+    #[serde(
+        alias = "timestamp",
+        deserialize_with = "de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+    #[serde(deserialize_with = "crate::exchange::de_str")]
+    pub price: f64,
+    #[serde(rename = "amount", deserialize_with = "crate::exchange::de_str")]
+    pub quantity: f64,
+    #[serde(rename = "direction")]
+    pub side: Side,
+}
+
+impl From<(ExchangeId, Instrument, DeribitTrade)> for MarketEvent {
+    fn from((exchange_id, instrument, trade): (ExchangeId, Instrument, DeribitTrade)) -> Self {
+        Self {
+            exchange_time: trade.time,
+            received_time: Utc::now(),
+            received_instant: None,
+            raw: None,
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: DataKind::Trade(PublicTrade {
+                id: trade.id,
+                price: trade.price,
+                quantity: trade.quantity,
+                side: trade.side,
+            }),
+        }
+    }
+}
+
+/// [`Deribit`](super::Deribit) `chart.trades` resolution, in minutes or "1D".
+///
+/// See docs: <https://docs.deribit.com/#chart-trades-instrument_name-resolution>
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct DeribitResolution(pub &'static str);
+
+impl TryFrom<&Interval> for DeribitResolution {
+    type Error = SocketError;
+
+    fn try_from(interval: &Interval) -> Result<Self, Self::Error> {
+        let resolution = match interval {
+            Interval::Minute1 => "1",
+            Interval::Minute3 => "3",
+            Interval::Minute5 => "5",
+            Interval::Minute15 => "15",
+            Interval::Minute30 => "30",
+            Interval::Hour1 => "60",
+            Interval::Hour2 => "120",
+            Interval::Hour6 => "360",
+            Interval::Hour12 => "720",
+            Interval::Day1 => "1D",
+            other => {
+                return Err(SocketError::Unsupported {
+                    entity: ExchangeId::Deribit.as_str(),
+                    item: other.to_string(),
+                })
+            }
+        };
+
+        Ok(Self(resolution))
+    }
+}
+
+/// [`Deribit`](super::Deribit) subscription notification containing a [`DeribitCandle`].
+///
+/// eg/ {"jsonrpc":"2.0","method":"subscription","params":{"channel":"chart.trades.BTC-PERPETUAL.1","data":{...}}}
+///
+/// See docs: <https://docs.deribit.com/#chart-trades-instrument_name-resolution>
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct DeribitCandleNotification {
+    pub params: DeribitCandleParams,
+}
+
+/// Parameters of a [`DeribitCandleNotification`].
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct DeribitCandleParams {
+    #[serde(alias = "channel")]
+    pub subscription_id: SubscriptionId,
+    pub data: DeribitCandle,
+}
+
+/// [`Deribit`](super::Deribit) `chart.trades` candle, where `tick` is the start time. The end time
+/// is not sent, so it is determined from the subscribed [`Interval`] when transformed.
+///
+/// eg/ {"volume":0.05219351,"tick":1573645080000,"open":8869.79,"low":8788.25,"high":8870.31,
+///      "cost":460,"close":8791.25}
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct DeribitCandle {
+    #[serde(alias = "tick", deserialize_with = "de_u64_epoch_ms_as_datetime_utc")]
+    pub start_time: DateTime<Utc>,
+    #[serde(deserialize_with = "crate::exchange::de_str")]
+    pub open: f64,
+    #[serde(deserialize_with = "crate::exchange::de_str")]
+    pub high: f64,
+    #[serde(deserialize_with = "crate::exchange::de_str")]
+    pub low: f64,
+    #[serde(deserialize_with = "crate::exchange::de_str")]
+    pub close: f64,
+    #[serde(deserialize_with = "crate::exchange::de_str")]
+    pub volume: f64,
+}
+
+impl From<(ExchangeId, Instrument, Interval, DeribitCandle)> for MarketEvent {
+    fn from(
+        (exchange_id, instrument, interval, candle): (
+            ExchangeId,
+            Instrument,
+            Interval,
+            DeribitCandle,
+        ),
+    ) -> Self {
+        let end_time = interval.end_time(candle.start_time);
+
+        Self {
+            exchange_time: end_time,
+            received_time: Utc::now(),
+            received_instant: None,
+            raw: None,
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: DataKind::Candle(Candle {
+                start_time: candle.start_time,
+                end_time,
+                open: candle.open,
+                high: candle.high,
+                low: candle.low,
+                close: candle.close,
+                volume: candle.volume,
+                trade_count: 0,
+            }),
+        }
+    }
+}
+
+/// [`Deribit`](super::Deribit) subscription notification containing a [`DeribitTicker`].
+///
+/// eg/ {"jsonrpc":"2.0","method":"subscription","params":{"channel":"ticker.BTC-27DEC24-50000-C.100ms","data":{...}}}
+///
+/// See docs: <https://docs.deribit.com/#ticker-instrument_name-interval>
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct DeribitTickerNotification {
+    pub params: DeribitTickerParams,
+}
+
+/// Parameters of a [`DeribitTickerNotification`].
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct DeribitTickerParams {
+    #[serde(alias = "channel")]
+    pub subscription_id: SubscriptionId,
+    pub data: DeribitTicker,
+}
+
+/// [`Deribit`](super::Deribit) option ticker containing the mark price, mark implied volatility &
+/// greeks. The `mark_price` of inverse options is denominated in the underlying.
+///
+/// eg/ {"timestamp":1671217200000,"instrument_name":"BTC-27DEC24-50000-C","mark_price":0.0845,
+///      "mark_iv":55.4,"underlying_price":51000.5,"greeks":{"delta":0.61,"gamma":0.00002,
+///      "vega":95.3,"theta":-40.1,"rho":120.2}, ...}
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct DeribitTicker {
+    #[serde(
+        alias = "timestamp",
+        deserialize_with = "de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+    pub mark_price: f64,
+    pub mark_iv: f64,
+    pub underlying_price: f64,
+    pub greeks: DeribitGreeks,
+}
+
+/// Greeks of a [`DeribitTicker`].
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct DeribitGreeks {
+    pub delta: f64,
+    pub gamma: f64,
+    pub vega: f64,
+    pub theta: f64,
+}
+
+impl From<(ExchangeId, Instrument, DeribitTicker)> for MarketEvent {
+    fn from((exchange_id, instrument, ticker): (ExchangeId, Instrument, DeribitTicker)) -> Self {
+        Self {
+            exchange_time: ticker.time,
+            received_time: Utc::now(),
+            received_instant: None,
+            raw: None,
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: DataKind::OptionTicker(OptionTicker {
+                mark_price: ticker.mark_price,
+                mark_iv: ticker.mark_iv,
+                underlying_price: ticker.underlying_price,
+                delta: ticker.greeks.delta,
+                gamma: ticker.greeks.gamma,
+                vega: ticker.greeks.vega,
+                theta: ticker.greeks.theta,
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchange::datetime_utc_from_epoch_duration;
+    use serde::de::Error;
+    use std::time::Duration;
+
+    #[test]
+    fn test_deserialise_deribit_subscription_response() {
+        struct TestCase {
+            input: &'static str,
+            expected: Result<DeribitSubResponse, SocketError>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: input response is Subscribed
+                input: r#"{"jsonrpc":"2.0","id":1,"result":["book.BTC-PERPETUAL.100ms"]}"#,
+                expected: Ok(DeribitSubResponse::Subscribed {
+                    result: vec!["book.BTC-PERPETUAL.100ms".to_owned()],
+                }),
+            },
+            TestCase {
+                // TC1: input response is Error
+                input: r#"{"jsonrpc":"2.0","id":1,"error":{"code":-32602,"message":"Invalid params"}}"#,
+                expected: Ok(DeribitSubResponse::Error {
+                    error: DeribitError {
+                        code: -32602,
+                        message: "Invalid params".to_owned(),
+                    },
+                }),
+            },
+            TestCase {
+                // TC2: input is a subscription notification rather than a response
+                input: r#"{"jsonrpc":"2.0","method":"subscription","params":{}}"#,
+                expected: Err(SocketError::Serde {
+                    error: serde_json::Error::custom(""),
+                    payload: "".to_owned(),
+                }),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = serde_json::from_str::<DeribitSubResponse>(test.input);
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => {
+                    assert_eq!(actual, expected, "TC{} failed", index)
+                }
+                (Err(_), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_deserialise_deribit_message() {
+        struct TestCase {
+            input: &'static str,
+            expected: Result<DeribitMessage, SocketError>,
+        }
+
+        let time = datetime_utc_from_epoch_duration(Duration::from_millis(1671217200000));
+
+        let cases = vec![
+            TestCase {
+                // TC0: input is a valid OrderBook snapshot
+                input: r#"{"jsonrpc":"2.0","method":"subscription","params":{"channel":"book.BTC-PERPETUAL.100ms","data":{"type":"snapshot","timestamp":1671217200000,"instrument_name":"BTC-PERPETUAL","change_id":1,"bids":[["new",16000.0,10.0]],"asks":[["new",16001.0,5.0]]}}}"#,
+                expected: Ok(DeribitMessage::OrderBook(DeribitOrderBookNotification {
+                    params: DeribitOrderBookParams {
+                        subscription_id: SubscriptionId::from("book.BTC-PERPETUAL.100ms"),
+                        data: DeribitOrderBook {
+                            kind: DeribitOrderBookKind::Snapshot,
+                            time,
+                            change_id: 1,
+                            prev_change_id: None,
+                            bids: vec![DeribitLevel(DeribitLevelAction::New, 16000.0, 10.0)],
+                            asks: vec![DeribitLevel(DeribitLevelAction::New, 16001.0, 5.0)],
+                        },
+                    },
+                })),
+            },
+            TestCase {
+                // TC1: input is a valid raw OrderBook change
+                input: r#"{"jsonrpc":"2.0","method":"subscription","params":{"channel":"book.BTC-PERPETUAL.raw","data":{"type":"change","timestamp":1671217200000,"instrument_name":"BTC-PERPETUAL","change_id":2,"prev_change_id":1,"bids":[["change",16000.0,2.0]],"asks":[["delete",16001.0,0.0]]}}}"#,
+                expected: Ok(DeribitMessage::OrderBook(DeribitOrderBookNotification {
+                    params: DeribitOrderBookParams {
+                        subscription_id: SubscriptionId::from("book.BTC-PERPETUAL.raw"),
+                        data: DeribitOrderBook {
+                            kind: DeribitOrderBookKind::Change,
+                            time,
+                            change_id: 2,
+                            prev_change_id: Some(1),
+                            bids: vec![DeribitLevel(DeribitLevelAction::Change, 16000.0, 2.0)],
+                            asks: vec![DeribitLevel(DeribitLevelAction::Delete, 16001.0, 0.0)],
+                        },
+                    },
+                })),
+            },
+            TestCase {
+                // TC2: input is a valid trades notification
+                input: r#"{"jsonrpc":"2.0","method":"subscription","params":{"channel":"trades.BTC-PERPETUAL.100ms","data":[{"trade_seq":30289432,"trade_id":"48079254","timestamp":1671217200000,"tick_direction":0,"price":8950.0,"mark_price":8948.9,"instrument_name":"BTC-PERPETUAL","index_price":8955.88,"direction":"sell","amount":10.0}]}}"#,
+                expected: Ok(DeribitMessage::Trades(DeribitTradesNotification {
+                    params: DeribitTradesParams {
+                        subscription_id: SubscriptionId::from("trades.BTC-PERPETUAL.100ms"),
+                        data: vec![DeribitTrade {
+                            id: "48079254".to_owned(),
+                            time,
+                            price: 8950.0,
+                            quantity: 10.0,
+                            side: Side::Sell,
+                        }],
+                    },
+                })),
+            },
+            TestCase {
+                // TC3: input is a valid chart.trades notification
+                input: r#"{"jsonrpc":"2.0","method":"subscription","params":{"channel":"chart.trades.BTC-PERPETUAL.1","data":{"volume":0.05219351,"tick":1671217200000,"open":8869.79,"low":8788.25,"high":8870.31,"cost":460,"close":8791.25}}}"#,
+                expected: Ok(DeribitMessage::Candle(DeribitCandleNotification {
+                    params: DeribitCandleParams {
+                        subscription_id: SubscriptionId::from("chart.trades.BTC-PERPETUAL.1"),
+                        data: DeribitCandle {
+                            start_time: time,
+                            open: 8869.79,
+                            high: 8870.31,
+                            low: 8788.25,
+                            close: 8791.25,
+                            volume: 0.05219351,
+                        },
+                    },
+                })),
+            },
+            TestCase {
+                // TC4: input is a valid option ticker notification
+                input: r#"{"jsonrpc":"2.0","method":"subscription","params":{"channel":"ticker.BTC-27DEC24-50000-C.100ms","data":{"timestamp":1671217200000,"instrument_name":"BTC-27DEC24-50000-C","state":"open","mark_price":0.0845,"mark_iv":55.4,"underlying_price":51000.5,"underlying_index":"BTC-27DEC24","best_bid_price":0.084,"best_ask_price":0.085,"greeks":{"delta":0.61,"gamma":0.00002,"vega":95.3,"theta":-40.1,"rho":120.2}}}}"#,
+                expected: Ok(DeribitMessage::Ticker(DeribitTickerNotification {
+                    params: DeribitTickerParams {
+                        subscription_id: SubscriptionId::from("ticker.BTC-27DEC24-50000-C.100ms"),
+                        data: DeribitTicker {
+                            time,
+                            mark_price: 0.0845,
+                            mark_iv: 55.4,
+                            underlying_price: 51000.5,
+                            greeks: DeribitGreeks {
+                                delta: 0.61,
+                                gamma: 0.00002,
+                                vega: 95.3,
+                                theta: -40.1,
+                            },
+                        },
+                    },
+                })),
+            },
+            TestCase {
+                // TC5: input is a subscription response
+                input: r#"{"jsonrpc":"2.0","id":1,"result":["book.BTC-PERPETUAL.100ms"]}"#,
+                expected: Ok(DeribitMessage::Response(DeribitSubResponse::Subscribed {
+                    result: vec!["book.BTC-PERPETUAL.100ms".to_owned()],
+                })),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = serde_json::from_str::<DeribitMessage>(test.input);
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => {
+                    assert_eq!(actual, expected, "TC{} failed", index)
+                }
+                (Err(_), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+}
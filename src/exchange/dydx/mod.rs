@@ -0,0 +1,378 @@
+use crate::{
+    model::subscription::{SubKind, SubscriptionIds, SubscriptionMeta},
+    ExchangeId, ExchangeTransformer, MarketEvent, Subscriber, Subscription, Validator,
+};
+use barter_integration::{
+    error::SocketError,
+    model::{Instrument, SubscriptionId},
+    protocol::websocket::WsMessage,
+    Transformer,
+};
+use model::{DydxChannelData, DydxMessage, DydxResolution, DydxSubResponse, DydxSubscription};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::collections::HashMap;
+use tokio::sync::mpsc;
+
+/// [`Dydx`] specific data structures.
+pub mod model;
+
+/// [`Dydx`] [`Subscriber`] & [`ExchangeTransformer`] implementor for the collection
+/// of `FuturePerpetual` data via the dYdX v4 indexer WebSocket API.
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct Dydx {
+    pub ids: SubscriptionIds,
+}
+
+impl Subscriber for Dydx {
+    type SubResponse = DydxSubResponse;
+
+    fn base_url() -> &'static str {
+        ExchangeId::Dydx.ws_base_url()
+    }
+
+    fn build_subscription_meta(
+        subscriptions: &[Subscription],
+    ) -> Result<SubscriptionMeta, SocketError> {
+        // Allocate SubscriptionIds HashMap to track identifiers for each actioned Subscription
+        let mut ids = SubscriptionIds(HashMap::with_capacity(subscriptions.len()));
+
+        // Map Barter Subscriptions to DydxSubscriptions
+        let subscriptions = subscriptions
+            .iter()
+            .map(|subscription| {
+                // Translate Barter Subscription to the associated DydxSubscription
+                let dydx_subscription = Self::subscription(subscription)?;
+
+                // Use "channel|id" as the SubscriptionId key in the SubscriptionIds
+                // eg/ SubscriptionId("v4_trades|BTC-USD")
+                ids.insert(
+                    SubscriptionId::from(&dydx_subscription),
+                    subscription.clone(),
+                );
+
+                // Construct Dydx specific subscription message
+                Ok(Self::subscription_message(&dydx_subscription))
+            })
+            .collect::<Result<Vec<_>, SocketError>>()?;
+
+        Ok(SubscriptionMeta {
+            ids,
+            expected_responses: subscriptions.len(),
+            subscriptions,
+        })
+    }
+}
+
+impl ExchangeTransformer for Dydx {
+    const EXCHANGE: ExchangeId = ExchangeId::Dydx;
+
+    fn new(_: mpsc::UnboundedSender<WsMessage>, ids: SubscriptionIds) -> Self {
+        Self { ids }
+    }
+}
+
+impl Transformer<MarketEvent> for Dydx {
+    type Input = DydxMessage;
+    type OutputIter = Vec<Result<MarketEvent, SocketError>>;
+
+    fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+        match input {
+            DydxMessage::ChannelData(DydxChannelData::Trades { id, contents }) => {
+                // Determine Instrument associated with this v4_trades update
+                let subscription_id =
+                    SubscriptionId::from(format!("{}|{id}", Self::CHANNEL_TRADES));
+                let instrument = match self.ids.find_instrument(&subscription_id) {
+                    Ok(instrument) => instrument,
+                    Err(error) => return vec![Err(error)],
+                };
+
+                // Map to MarketEvents
+                contents
+                    .trades
+                    .into_iter()
+                    .map(|trade| {
+                        Ok(MarketEvent::from((
+                            Dydx::EXCHANGE,
+                            instrument.clone(),
+                            trade,
+                        )))
+                    })
+                    .collect()
+            }
+            DydxMessage::ChannelData(DydxChannelData::Candles { id, contents }) => {
+                // Determine Instrument & Interval associated with this v4_candles update
+                let subscription_id =
+                    SubscriptionId::from(format!("{}|{id}", Self::CHANNEL_CANDLES));
+                match self.ids.get(&subscription_id) {
+                    Some(Subscription {
+                        instrument,
+                        kind: SubKind::Candle(interval),
+                        ..
+                    }) => vec![Ok(MarketEvent::from((
+                        Dydx::EXCHANGE,
+                        instrument.clone(),
+                        *interval,
+                        contents,
+                    )))],
+                    _ => vec![Err(SocketError::Unidentifiable(subscription_id))],
+                }
+            }
+            DydxMessage::Connected | DydxMessage::Subscribed { .. } => vec![],
+            DydxMessage::Error { message } => vec![Err(SocketError::Exchange(format!(
+                "received error with message: {message}"
+            )))],
+        }
+    }
+}
+
+impl Dydx {
+    /// [`Dydx`] trades channel.
+    ///
+    /// See docs: <https://docs.dydx.exchange/api_integration-indexer/indexer_websocket#trades>
+    pub const CHANNEL_TRADES: &'static str = "v4_trades";
+
+    /// [`Dydx`] candles channel, where the subscription id is suffixed with the
+    /// [`DydxResolution`] (eg/ "BTC-USD/1MIN").
+    ///
+    /// See docs: <https://docs.dydx.exchange/api_integration-indexer/indexer_websocket#candles>
+    pub const CHANNEL_CANDLES: &'static str = "v4_candles";
+
+    /// Translate a Barter [`Subscription`] into a [`DydxSubscription`].
+    ///
+    /// eg/ DydxSubscription { channel: "v4_candles", id: "BTC-USD/1MIN" }
+    pub fn subscription(sub: &Subscription) -> Result<DydxSubscription, SocketError> {
+        // Validate provided Subscription InstrumentKind & SubKind are supported by Dydx
+        let sub = sub.validate()?;
+        let market = Self::market(&sub.instrument);
+
+        match &sub.kind {
+            SubKind::Trade => Ok(DydxSubscription {
+                channel: Self::CHANNEL_TRADES,
+                id: market,
+            }),
+            SubKind::Candle(interval) => Ok(DydxSubscription {
+                channel: Self::CHANNEL_CANDLES,
+                id: format!("{market}/{}", DydxResolution::try_from(interval)?.0),
+            }),
+            other => Err(SocketError::Unsupported {
+                entity: Self::EXCHANGE.as_str(),
+                item: other.to_string(),
+            }),
+        }
+    }
+
+    /// Determine the [`Dydx`] market ticker for the provided [`Instrument`].
+    ///
+    /// eg/ "BTC-USD"
+    pub fn market(instrument: &Instrument) -> String {
+        format!("{}-{}", instrument.base, instrument.quote).to_uppercase()
+    }
+
+    /// Build a [`Dydx`] compatible subscription message using the [`DydxSubscription`] provided.
+    ///
+    /// eg/ {"type":"subscribe","channel":"v4_trades","id":"BTC-USD"}
+    pub fn subscription_message(subscription: &DydxSubscription) -> WsMessage {
+        WsMessage::Text(
+            json!({
+                "type": "subscribe",
+                "channel": subscription.channel,
+                "id": subscription.id,
+            })
+            .to_string(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchange::dydx::model::{DydxCandle, DydxTrade, DydxTrades};
+    use crate::model::{subscription::Interval, Candle, DataKind, PublicTrade};
+    use barter_integration::model::{InstrumentKind, Side};
+    use chrono::{Duration, Utc};
+
+    #[test]
+    fn test_subscription() {
+        struct TestCase {
+            input: Subscription,
+            expected: Result<DydxSubscription, SocketError>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: Supported InstrumentKind::FuturePerpetual trades subscription
+                input: Subscription::new(
+                    ExchangeId::Dydx,
+                    ("btc", "usd", InstrumentKind::FuturePerpetual),
+                    SubKind::Trade,
+                ),
+                expected: Ok(DydxSubscription {
+                    channel: "v4_trades",
+                    id: "BTC-USD".to_owned(),
+                }),
+            },
+            TestCase {
+                // TC1: Supported InstrumentKind::FuturePerpetual candles subscription
+                input: Subscription::new(
+                    ExchangeId::Dydx,
+                    ("eth", "usd", InstrumentKind::FuturePerpetual),
+                    SubKind::Candle(Interval::Hour4),
+                ),
+                expected: Ok(DydxSubscription {
+                    channel: "v4_candles",
+                    id: "ETH-USD/4HOURS".to_owned(),
+                }),
+            },
+            TestCase {
+                // TC2: Unsupported InstrumentKind::Spot
+                input: Subscription::new(
+                    ExchangeId::Dydx,
+                    ("btc", "usd", InstrumentKind::Spot),
+                    SubKind::Trade,
+                ),
+                expected: Err(SocketError::Unsupported {
+                    entity: "dydx",
+                    item: InstrumentKind::Spot.to_string(),
+                }),
+            },
+            TestCase {
+                // TC3: Unsupported Interval
+                input: Subscription::new(
+                    ExchangeId::Dydx,
+                    ("btc", "usd", InstrumentKind::FuturePerpetual),
+                    SubKind::Candle(Interval::Week1),
+                ),
+                expected: Err(SocketError::Unsupported {
+                    entity: "dydx",
+                    item: Interval::Week1.to_string(),
+                }),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = Dydx::subscription(&test.input);
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => {
+                    assert_eq!(actual, expected, "TC{} failed", index)
+                }
+                (Err(_), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_dydx_transform() {
+        let mut transformer = Dydx {
+            ids: SubscriptionIds(HashMap::from([
+                (
+                    SubscriptionId::from("v4_trades|BTC-USD"),
+                    Subscription::new(
+                        ExchangeId::Dydx,
+                        ("btc", "usd", InstrumentKind::FuturePerpetual),
+                        SubKind::Trade,
+                    ),
+                ),
+                (
+                    SubscriptionId::from("v4_candles|BTC-USD/1MIN"),
+                    Subscription::new(
+                        ExchangeId::Dydx,
+                        ("btc", "usd", InstrumentKind::FuturePerpetual),
+                        SubKind::Candle(Interval::Minute1),
+                    ),
+                ),
+            ])),
+        };
+
+        let time = Utc::now();
+        let trades = |id: &str| {
+            DydxMessage::ChannelData(DydxChannelData::Trades {
+                id: id.to_owned(),
+                contents: DydxTrades {
+                    trades: vec![DydxTrade {
+                        id: "1".to_owned(),
+                        price: 100.0,
+                        quantity: 1.0,
+                        side: Side::Buy,
+                        time,
+                    }],
+                },
+            })
+        };
+
+        struct TestCase {
+            input: DydxMessage,
+            expected: Vec<Option<DataKind>>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: DydxMessage v4_trades w/ known SubscriptionId
+                input: trades("BTC-USD"),
+                expected: vec![Some(DataKind::Trade(PublicTrade {
+                    id: "1".to_owned(),
+                    price: 100.0,
+                    quantity: 1.0,
+                    side: Side::Buy,
+                }))],
+            },
+            TestCase {
+                // TC1: DydxMessage v4_trades w/ unknown SubscriptionId
+                input: trades("ETH-USD"),
+                expected: vec![None],
+            },
+            TestCase {
+                // TC2: DydxMessage v4_candles w/ known SubscriptionId
+                input: DydxMessage::ChannelData(DydxChannelData::Candles {
+                    id: "BTC-USD/1MIN".to_owned(),
+                    contents: DydxCandle {
+                        start_time: time,
+                        open: 1.0,
+                        high: 2.0,
+                        low: 0.5,
+                        close: 1.5,
+                        volume: 10.0,
+                        trade_count: 3,
+                    },
+                }),
+                expected: vec![Some(DataKind::Candle(Candle {
+                    start_time: time,
+                    end_time: time + Duration::minutes(1),
+                    open: 1.0,
+                    high: 2.0,
+                    low: 0.5,
+                    close: 1.5,
+                    volume: 10.0,
+                    trade_count: 3,
+                }))],
+            },
+            TestCase {
+                // TC3: DydxMessage Connected is ignored
+                input: DydxMessage::Connected,
+                expected: vec![],
+            },
+            TestCase {
+                // TC4: DydxMessage Error
+                input: DydxMessage::Error {
+                    message: "Internal error".to_owned(),
+                },
+                expected: vec![None],
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = transformer
+                .transform(test.input)
+                .into_iter()
+                .map(|event| event.ok().map(|event| event.kind))
+                .collect::<Vec<_>>();
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
@@ -0,0 +1,315 @@
+use crate::{
+    exchange::de_str,
+    model::{subscription::Interval, Candle, DataKind, PublicTrade},
+    ExchangeId, MarketEvent,
+};
+use barter_integration::{
+    error::SocketError,
+    model::{Exchange, Instrument, Side, SubscriptionId},
+    Validator,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// [`Dydx`](super::Dydx) channel & id pair identifying a subscription.
+///
+/// eg/ {"channel":"v4_candles","id":"BTC-USD/1MIN"}
+///
+/// See docs: <https://docs.dydx.exchange/api_integration-indexer/indexer_websocket>
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Serialize)]
+pub struct DydxSubscription {
+    pub channel: &'static str,
+    pub id: String,
+}
+
+impl From<&DydxSubscription> for SubscriptionId {
+    fn from(subscription: &DydxSubscription) -> Self {
+        // eg/ SubscriptionId("v4_candles|BTC-USD/1MIN")
+        SubscriptionId::from(format!("{}|{}", subscription.channel, subscription.id))
+    }
+}
+
+/// [`Dydx`](super::Dydx) message received in response to WebSocket subscription requests. The
+/// `subscribed` message also contains an initial snapshot, which is not normalised.
+///
+/// eg/ DydxSubResponse::Subscribed {"type":"subscribed","connection_id":"...","message_id":1,"channel":"v4_trades","id":"BTC-USD","contents":{...}}
+/// eg/ DydxSubResponse::Error {"type":"error","message":"Invalid subscribe message: channel is not a valid channel","connection_id":"...","message_id":1}
+///
+/// See docs: <https://docs.dydx.exchange/api_integration-indexer/indexer_websocket>
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum DydxSubResponse {
+    Subscribed { channel: String, id: String },
+    Error { message: String },
+}
+
+impl Validator for DydxSubResponse {
+    fn validate(self) -> Result<Self, SocketError>
+    where
+        Self: Sized,
+    {
+        match &self {
+            DydxSubResponse::Subscribed { .. } => Ok(self),
+            DydxSubResponse::Error { message } => Err(SocketError::Subscribe(format!(
+                "received failure subscription response with message: {message}",
+            ))),
+        }
+    }
+}
+
+/// [`Dydx`](super::Dydx) candle resolution used in `v4_candles` subscription ids
+/// (eg/ "BTC-USD/1MIN").
+///
+/// See docs: <https://docs.dydx.exchange/api_integration-indexer/indexer_websocket#candles>
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct DydxResolution(pub &'static str);
+
+impl TryFrom<&Interval> for DydxResolution {
+    type Error = SocketError;
+
+    fn try_from(interval: &Interval) -> Result<Self, Self::Error> {
+        let resolution = match interval {
+            Interval::Minute1 => "1MIN",
+            Interval::Minute5 => "5MINS",
+            Interval::Minute15 => "15MINS",
+            Interval::Minute30 => "30MINS",
+            Interval::Hour1 => "1HOUR",
+            Interval::Hour4 => "4HOURS",
+            Interval::Day1 => "1DAY",
+            other => {
+                return Err(SocketError::Unsupported {
+                    entity: ExchangeId::Dydx.as_str(),
+                    item: other.to_string(),
+                })
+            }
+        };
+
+        Ok(Self(resolution))
+    }
+}
+
+/// [`Dydx`](super::Dydx) message variants that can be received over [`WebSocket`].
+///
+/// See docs: <https://docs.dydx.exchange/api_integration-indexer/indexer_websocket>
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum DydxMessage {
+    ChannelData(DydxChannelData),
+    Connected,
+    Subscribed { channel: String, id: String },
+    Error { message: String },
+}
+
+/// [`Dydx`](super::Dydx) `channel_data` update, tagged by the channel it was published on.
+///
+/// eg/ {"type":"channel_data","connection_id":"...","message_id":2,"id":"BTC-USD","channel":"v4_trades","version":"2.1.0","contents":{...}}
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(tag = "channel")]
+pub enum DydxChannelData {
+    #[serde(rename = "v4_trades")]
+    Trades { id: String, contents: DydxTrades },
+    #[serde(rename = "v4_candles")]
+    Candles { id: String, contents: DydxCandle },
+}
+
+/// [`Dydx`](super::Dydx) `v4_trades` contents containing one or more [`DydxTrade`]s.
+///
+/// See docs: <https://docs.dydx.exchange/api_integration-indexer/indexer_websocket#trades>
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct DydxTrades {
+    pub trades: Vec<DydxTrade>,
+}
+
+/// [`Dydx`](super::Dydx) trade.
+///
+/// eg/ {"id":"8ee6d90d-272d-5edd-bf0f-2e4d6ae3d3b7","size":"0.002","price":"42000.5","side":"BUY",
+///      "createdAt":"2024-01-01T00:00:00.123Z","type":"LIMIT"}
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct DydxTrade {
+    pub id: String,
+    #[serde(deserialize_with = "de_str")]
+    pub price: f64,
+    #[serde(rename = "size", deserialize_with = "de_str")]
+    pub quantity: f64,
+    pub side: Side,
+    #[serde(rename = "createdAt")]
+    pub time: DateTime<Utc>,
+}
+
+/// [`Dydx`](super::Dydx) `v4_candles` candle. The end time is not sent, so it is determined from
+/// the subscribed [`Interval`] when transformed.
+///
+/// eg/ {"startedAt":"2024-01-01T00:00:00.000Z","ticker":"BTC-USD","resolution":"1MIN",
+///      "low":"42000","high":"42010.5","open":"42001","close":"42005","baseTokenVolume":"1.5",
+///      "usdVolume":"63007.5","trades":12,"startingOpenInterest":"100.5","id":"..."}
+///
+/// See docs: <https://docs.dydx.exchange/api_integration-indexer/indexer_websocket#candles>
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct DydxCandle {
+    #[serde(rename = "startedAt")]
+    pub start_time: DateTime<Utc>,
+    #[serde(deserialize_with = "de_str")]
+    pub open: f64,
+    #[serde(deserialize_with = "de_str")]
+    pub high: f64,
+    #[serde(deserialize_with = "de_str")]
+    pub low: f64,
+    #[serde(deserialize_with = "de_str")]
+    pub close: f64,
+    #[serde(rename = "baseTokenVolume", deserialize_with = "de_str")]
+    pub volume: f64,
+    #[serde(rename = "trades")]
+    pub trade_count: u64,
+}
+
+impl From<(ExchangeId, Instrument, DydxTrade)> for MarketEvent {
+    fn from((exchange_id, instrument, trade): (ExchangeId, Instrument, DydxTrade)) -> Self {
+        Self {
+            exchange_time: trade.time,
+            received_time: Utc::now(),
+            received_instant: None,
+            raw: None,
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: DataKind::Trade(PublicTrade {
+                id: trade.id,
+                price: trade.price,
+                quantity: trade.quantity,
+                side: trade.side,
+            }),
+        }
+    }
+}
+
+impl From<(ExchangeId, Instrument, Interval, DydxCandle)> for MarketEvent {
+    fn from(
+        (exchange_id, instrument, interval, candle): (ExchangeId, Instrument, Interval, DydxCandle),
+    ) -> Self {
+        let end_time = interval.end_time(candle.start_time);
+
+        Self {
+            exchange_time: end_time,
+            received_time: Utc::now(),
+            received_instant: None,
+            raw: None,
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: DataKind::Candle(Candle {
+                start_time: candle.start_time,
+                end_time,
+                open: candle.open,
+                high: candle.high,
+                low: candle.low,
+                close: candle.close,
+                volume: candle.volume,
+                trade_count: candle.trade_count,
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_deserialise_dydx_sub_response() {
+        struct TestCase {
+            input: &'static str,
+            expected: Result<DydxSubResponse, SocketError>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: input response is successful subscription w/ initial snapshot
+                input: r#"{"type":"subscribed","connection_id":"c5f5a3c5","message_id":1,"channel":"v4_trades","id":"BTC-USD","contents":{"trades":[]}}"#,
+                expected: Ok(DydxSubResponse::Subscribed {
+                    channel: "v4_trades".to_owned(),
+                    id: "BTC-USD".to_owned(),
+                }),
+            },
+            TestCase {
+                // TC1: input response is failed subscription
+                input: r#"{"type":"error","message":"Invalid subscribe message: channel is not a valid channel","connection_id":"c5f5a3c5","message_id":1}"#,
+                expected: Ok(DydxSubResponse::Error {
+                    message: "Invalid subscribe message: channel is not a valid channel".to_owned(),
+                }),
+            },
+            TestCase {
+                // TC2: input is the connected message, not a subscription response
+                input: r#"{"type":"connected","connection_id":"c5f5a3c5","message_id":0}"#,
+                expected: Err(SocketError::Subscribe("".to_owned())),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = serde_json::from_str::<DydxSubResponse>(test.input);
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => {
+                    assert_eq!(actual, expected, "TC{} failed", index)
+                }
+                (Err(_), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_deserialise_dydx_message() {
+        struct TestCase {
+            input: &'static str,
+            expected: Option<DydxMessage>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: input is a valid v4_trades channel_data message
+                input: r#"{"type":"channel_data","connection_id":"c5f5a3c5","message_id":2,"id":"BTC-USD","channel":"v4_trades","version":"2.1.0","contents":{"trades":[{"id":"8ee6d90d-272d-5edd-bf0f-2e4d6ae3d3b7","size":"0.002","price":"42000.5","side":"BUY","createdAt":"2024-01-01T00:00:00.123Z","type":"LIMIT"}]}}"#,
+                expected: Some(DydxMessage::ChannelData(DydxChannelData::Trades {
+                    id: "BTC-USD".to_owned(),
+                    contents: DydxTrades {
+                        trades: vec![DydxTrade {
+                            id: "8ee6d90d-272d-5edd-bf0f-2e4d6ae3d3b7".to_owned(),
+                            price: 42000.5,
+                            quantity: 0.002,
+                            side: Side::Buy,
+                            time: Utc.timestamp_millis_opt(1704067200123).unwrap(),
+                        }],
+                    },
+                })),
+            },
+            TestCase {
+                // TC1: input is a valid v4_candles channel_data message
+                input: r#"{"type":"channel_data","connection_id":"c5f5a3c5","message_id":3,"id":"BTC-USD/1MIN","channel":"v4_candles","version":"1.0.0","contents":{"startedAt":"2024-01-01T00:00:00.000Z","ticker":"BTC-USD","resolution":"1MIN","low":"42000","high":"42010.5","open":"42001","close":"42005","baseTokenVolume":"1.5","usdVolume":"63007.5","trades":12,"startingOpenInterest":"100.5","id":"f3a1"}}"#,
+                expected: Some(DydxMessage::ChannelData(DydxChannelData::Candles {
+                    id: "BTC-USD/1MIN".to_owned(),
+                    contents: DydxCandle {
+                        start_time: Utc.timestamp_millis_opt(1704067200000).unwrap(),
+                        open: 42001.0,
+                        high: 42010.5,
+                        low: 42000.0,
+                        close: 42005.0,
+                        volume: 1.5,
+                        trade_count: 12,
+                    },
+                })),
+            },
+            TestCase {
+                // TC2: input is the connected message
+                input: r#"{"type":"connected","connection_id":"c5f5a3c5","message_id":0}"#,
+                expected: Some(DydxMessage::Connected),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = serde_json::from_str::<DydxMessage>(test.input).ok();
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
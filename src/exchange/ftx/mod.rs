@@ -31,7 +31,7 @@ impl Subscriber for Ftx {
     type SubResponse = FtxSubResponse;
 
     fn base_url() -> &'static str {
-        "wss://ftx.com/ws/"
+        ExchangeId::Ftx.ws_base_url()
     }
 
     fn build_subscription_meta(
@@ -314,6 +314,8 @@ mod tests {
                     Ok(MarketEvent {
                         exchange_time: time,
                         received_time: time,
+                        received_instant: None,
+                        raw: None,
                         exchange: Exchange::from(ExchangeId::Ftx),
                         instrument: Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
                         kind: DataKind::Trade(PublicTrade {
@@ -326,6 +328,8 @@ mod tests {
                     Ok(MarketEvent {
                         exchange_time: time,
                         received_time: time,
+                        received_instant: None,
+                        raw: None,
                         exchange: Exchange::from(ExchangeId::Ftx),
                         instrument: Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
                         kind: DataKind::Trade(PublicTrade {
@@ -362,6 +366,8 @@ mod tests {
                     Ok(MarketEvent {
                         exchange_time: time,
                         received_time: time,
+                        received_instant: None,
+                        raw: None,
                         exchange: Exchange::from(ExchangeId::Ftx),
                         instrument: Instrument::from((
                             "btc",
@@ -378,6 +384,8 @@ mod tests {
                     Ok(MarketEvent {
                         exchange_time: time,
                         received_time: time,
+                        received_instant: None,
+                        raw: None,
                         exchange: Exchange::from(ExchangeId::Ftx),
                         instrument: Instrument::from((
                             "btc",
@@ -415,6 +423,8 @@ mod tests {
                         // Scrub Utc::now() timestamps to allow comparison
                         let actual = MarketEvent {
                             received_time: time,
+                            received_instant: None,
+                            raw: None,
                             ..actual
                         };
                         assert_eq!(
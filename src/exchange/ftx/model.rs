@@ -68,8 +68,11 @@ impl From<&FtxMessage> for SubscriptionId {
 /// See docs: <https://docs.ftx.com/#trades>
 #[derive(Clone, Copy, PartialEq, Debug, Deserialize)]
 pub struct FtxTrade {
+    #[serde(deserialize_with = "crate::exchange::de_str")]
     pub id: u64,
+    #[serde(deserialize_with = "crate::exchange::de_str")]
     pub price: f64,
+    #[serde(deserialize_with = "crate::exchange::de_str")]
     pub size: f64,
     pub side: Side,
     pub time: DateTime<Utc>,
@@ -80,6 +83,8 @@ impl From<(ExchangeId, Instrument, FtxTrade)> for MarketEvent {
         Self {
             exchange_time: trade.time,
             received_time: Utc::now(),
+            received_instant: None,
+            raw: None,
             exchange: Exchange::from(exchange.as_str()),
             instrument,
             kind: DataKind::Trade(PublicTrade {
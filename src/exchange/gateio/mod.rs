@@ -0,0 +1,443 @@
+use crate::{
+    model::subscription::{SubKind, SubscriptionIds, SubscriptionMeta},
+    ExchangeId, ExchangeTransformer, MarketEvent, Subscriber, Subscription, Validator,
+};
+use barter_integration::{
+    error::SocketError,
+    model::{Instrument, SubscriptionId},
+    protocol::websocket::WsMessage,
+    Transformer,
+};
+use chrono::Utc;
+use model::{GateioInterval, GateioMessage, GateioSubResponse, GateioSubscription};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::collections::HashMap;
+use tokio::sync::mpsc;
+
+/// [`Gateio`] specific data structures.
+pub mod model;
+
+/// [`Gateio`] [`Subscriber`] & [`ExchangeTransformer`] implementor for the collection
+/// of `Spot` data via the Gate.io v4 public WebSocket API.
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct Gateio {
+    pub ids: SubscriptionIds,
+}
+
+impl Subscriber for Gateio {
+    type SubResponse = GateioSubResponse;
+
+    fn base_url() -> &'static str {
+        ExchangeId::Gateio.ws_base_url()
+    }
+
+    fn build_subscription_meta(
+        subscriptions: &[Subscription],
+    ) -> Result<SubscriptionMeta, SocketError> {
+        // Allocate SubscriptionIds HashMap to track identifiers for each actioned Subscription
+        let mut ids = SubscriptionIds(HashMap::with_capacity(subscriptions.len()));
+
+        // Map Barter Subscriptions to GateioSubscriptions
+        let subscriptions = subscriptions
+            .iter()
+            .map(|subscription| {
+                // Translate Barter Subscription to the associated GateioSubscription
+                let gateio_subscription = Self::subscription(subscription)?;
+
+                // Use "channel|payload" as the SubscriptionId key in the SubscriptionIds
+                // eg/ SubscriptionId("spot.candlesticks|1m_BTC_USDT")
+                ids.insert(
+                    SubscriptionId::from(&gateio_subscription),
+                    subscription.clone(),
+                );
+
+                // Construct Gateio specific subscription message
+                Ok(Self::subscription_message(&gateio_subscription))
+            })
+            .collect::<Result<Vec<_>, SocketError>>()?;
+
+        Ok(SubscriptionMeta {
+            ids,
+            expected_responses: subscriptions.len(),
+            subscriptions,
+        })
+    }
+}
+
+impl ExchangeTransformer for Gateio {
+    const EXCHANGE: ExchangeId = ExchangeId::Gateio;
+
+    fn new(_: mpsc::UnboundedSender<WsMessage>, ids: SubscriptionIds) -> Self {
+        Self { ids }
+    }
+}
+
+impl Transformer<MarketEvent> for Gateio {
+    type Input = GateioMessage;
+    type OutputIter = Vec<Result<MarketEvent, SocketError>>;
+
+    fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+        match input {
+            GateioMessage::Trade(trades) => {
+                let subscription_id = SubscriptionId::from(&trades.data);
+                match self.ids.find_instrument(&subscription_id) {
+                    Ok(instrument) => vec![Ok(MarketEvent::from((
+                        Gateio::EXCHANGE,
+                        instrument,
+                        trades.data,
+                    )))],
+                    Err(error) => vec![Err(error)],
+                }
+            }
+            GateioMessage::Candle(candles) => {
+                // Determine Instrument & Interval associated with this GateioCandles message
+                let subscription_id = SubscriptionId::from(&candles.data);
+                match self.ids.get(&subscription_id) {
+                    Some(Subscription {
+                        instrument,
+                        kind: SubKind::Candle(interval),
+                        ..
+                    }) => vec![Ok(MarketEvent::from((
+                        Gateio::EXCHANGE,
+                        instrument.clone(),
+                        *interval,
+                        candles.data,
+                    )))],
+                    _ => vec![Err(SocketError::Unidentifiable(subscription_id))],
+                }
+            }
+            GateioMessage::Event(event) => match event.error {
+                Some(error) => vec![Err(SocketError::Exchange(format!(
+                    "received {} {} error code: {} with message: {}",
+                    event.channel, event.event, error.code, error.message
+                )))],
+                None => vec![],
+            },
+        }
+    }
+}
+
+impl Gateio {
+    /// [`Gateio`] spot public trades channel.
+    ///
+    /// See docs: <https://www.gate.io/docs/developers/apiv4/ws/en/#public-trades-channel>
+    pub const CHANNEL_TRADES: &'static str = "spot.trades";
+
+    /// [`Gateio`] spot candlesticks channel.
+    ///
+    /// See docs: <https://www.gate.io/docs/developers/apiv4/ws/en/#candlesticks-channel>
+    pub const CHANNEL_CANDLESTICKS: &'static str = "spot.candlesticks";
+
+    /// Translate a Barter [`Subscription`] into a [`GateioSubscription`].
+    ///
+    /// eg/ GateioSubscription { channel: "spot.candlesticks", payload: ["1m", "BTC_USDT"] }
+    pub fn subscription(sub: &Subscription) -> Result<GateioSubscription, SocketError> {
+        // Validate provided Subscription InstrumentKind & SubKind are supported by Gateio
+        let sub = sub.validate()?;
+        let market = Self::market(&sub.instrument);
+
+        match &sub.kind {
+            SubKind::Trade => Ok(GateioSubscription {
+                channel: Self::CHANNEL_TRADES,
+                payload: vec![market],
+            }),
+            SubKind::Candle(interval) => Ok(GateioSubscription {
+                channel: Self::CHANNEL_CANDLESTICKS,
+                payload: vec![GateioInterval::try_from(interval)?.0.to_owned(), market],
+            }),
+            other => Err(SocketError::Unsupported {
+                entity: Self::EXCHANGE.as_str(),
+                item: other.to_string(),
+            }),
+        }
+    }
+
+    /// Determine the [`Gateio`] currency pair for the provided [`Instrument`].
+    ///
+    /// eg/ "BTC_USDT"
+    pub fn market(instrument: &Instrument) -> String {
+        format!("{}_{}", instrument.base, instrument.quote).to_uppercase()
+    }
+
+    /// Build a [`Gateio`] compatible subscription message using the [`GateioSubscription`]
+    /// provided. Public channels do not require the request to be signed.
+    pub fn subscription_message(subscription: &GateioSubscription) -> WsMessage {
+        WsMessage::Text(
+            json!({
+                "time": Utc::now().timestamp(),
+                "channel": subscription.channel,
+                "event": "subscribe",
+                "payload": subscription.payload,
+            })
+            .to_string(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchange::gateio::model::{
+        GateioCandle, GateioCandles, GateioError, GateioEvent, GateioTrade, GateioTrades,
+    };
+    use crate::model::{subscription::Interval, Candle, DataKind, PublicTrade};
+    use barter_integration::model::{Exchange, InstrumentKind, Side};
+    use chrono::Duration;
+
+    #[test]
+    fn test_subscription() {
+        struct TestCase {
+            input: Subscription,
+            expected: Result<GateioSubscription, SocketError>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: Supported InstrumentKind::Spot trades subscription
+                input: Subscription::new(
+                    ExchangeId::Gateio,
+                    ("btc", "usdt", InstrumentKind::Spot),
+                    SubKind::Trade,
+                ),
+                expected: Ok(GateioSubscription {
+                    channel: "spot.trades",
+                    payload: vec!["BTC_USDT".to_owned()],
+                }),
+            },
+            TestCase {
+                // TC1: Supported InstrumentKind::Spot candles subscription
+                input: Subscription::new(
+                    ExchangeId::Gateio,
+                    ("btc", "usdt", InstrumentKind::Spot),
+                    SubKind::Candle(Interval::Week1),
+                ),
+                expected: Ok(GateioSubscription {
+                    channel: "spot.candlesticks",
+                    payload: vec!["7d".to_owned(), "BTC_USDT".to_owned()],
+                }),
+            },
+            TestCase {
+                // TC2: Unsupported InstrumentKind::FuturePerpetual
+                input: Subscription::new(
+                    ExchangeId::Gateio,
+                    ("btc", "usdt", InstrumentKind::FuturePerpetual),
+                    SubKind::Trade,
+                ),
+                expected: Err(SocketError::Unsupported {
+                    entity: "gateio",
+                    item: InstrumentKind::FuturePerpetual.to_string(),
+                }),
+            },
+            TestCase {
+                // TC3: Unsupported Interval
+                input: Subscription::new(
+                    ExchangeId::Gateio,
+                    ("btc", "usdt", InstrumentKind::Spot),
+                    SubKind::Candle(Interval::Minute3),
+                ),
+                expected: Err(SocketError::Unsupported {
+                    entity: "gateio",
+                    item: Interval::Minute3.to_string(),
+                }),
+            },
+            TestCase {
+                // TC4: Unsupported SubKind
+                input: Subscription::new(
+                    ExchangeId::Gateio,
+                    ("btc", "usdt", InstrumentKind::Spot),
+                    SubKind::OrderBookL3Delta,
+                ),
+                expected: Err(SocketError::Unsupported {
+                    entity: "gateio",
+                    item: SubKind::OrderBookL3Delta.to_string(),
+                }),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = Gateio::subscription(&test.input);
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => {
+                    assert_eq!(actual, expected, "TC{} failed", index)
+                }
+                (Err(_), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_gateio_transform() {
+        let trade_sub = Subscription::new(
+            ExchangeId::Gateio,
+            ("btc", "usdt", InstrumentKind::Spot),
+            SubKind::Trade,
+        );
+        let candle_sub = Subscription::new(
+            ExchangeId::Gateio,
+            ("btc", "usdt", InstrumentKind::Spot),
+            SubKind::Candle(Interval::Minute1),
+        );
+        let mut transformer = Gateio {
+            ids: SubscriptionIds(HashMap::from([
+                (
+                    SubscriptionId::from("spot.trades|BTC_USDT"),
+                    trade_sub.clone(),
+                ),
+                (
+                    SubscriptionId::from("spot.candlesticks|1m_BTC_USDT"),
+                    candle_sub.clone(),
+                ),
+            ])),
+        };
+
+        let time = Utc::now();
+
+        struct TestCase {
+            input: GateioMessage,
+            expected: Vec<Result<MarketEvent, SocketError>>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: GateioMessage Trade w/ known SubscriptionId
+                input: GateioMessage::Trade(GateioTrades {
+                    data: GateioTrade {
+                        id: 1,
+                        time,
+                        side: Side::Sell,
+                        currency_pair: "BTC_USDT".to_owned(),
+                        quantity: 1.0,
+                        price: 100.0,
+                    },
+                }),
+                expected: vec![Ok(MarketEvent {
+                    exchange_time: time,
+                    received_time: time,
+                    received_instant: None,
+                    raw: None,
+                    exchange: Exchange::from(ExchangeId::Gateio),
+                    instrument: trade_sub.instrument.clone(),
+                    kind: DataKind::Trade(PublicTrade {
+                        id: "1".to_owned(),
+                        price: 100.0,
+                        quantity: 1.0,
+                        side: Side::Sell,
+                    }),
+                })],
+            },
+            TestCase {
+                // TC1: GateioMessage Candle w/ known SubscriptionId
+                input: GateioMessage::Candle(GateioCandles {
+                    data: GateioCandle {
+                        start_time: time,
+                        name: "1m_BTC_USDT".to_owned(),
+                        open: 1.0,
+                        high: 2.0,
+                        low: 0.5,
+                        close: 1.5,
+                        volume: 10.0,
+                    },
+                }),
+                expected: vec![Ok(MarketEvent {
+                    exchange_time: time + Duration::minutes(1),
+                    received_time: time,
+                    received_instant: None,
+                    raw: None,
+                    exchange: Exchange::from(ExchangeId::Gateio),
+                    instrument: candle_sub.instrument,
+                    kind: DataKind::Candle(Candle {
+                        start_time: time,
+                        end_time: time + Duration::minutes(1),
+                        open: 1.0,
+                        high: 2.0,
+                        low: 0.5,
+                        close: 1.5,
+                        volume: 10.0,
+                        trade_count: 0,
+                    }),
+                })],
+            },
+            TestCase {
+                // TC2: GateioMessage Trade w/ unknown SubscriptionId
+                input: GateioMessage::Trade(GateioTrades {
+                    data: GateioTrade {
+                        id: 1,
+                        time,
+                        side: Side::Sell,
+                        currency_pair: "ETH_USDT".to_owned(),
+                        quantity: 1.0,
+                        price: 100.0,
+                    },
+                }),
+                expected: vec![Err(SocketError::Unidentifiable(SubscriptionId::from(
+                    "spot.trades|ETH_USDT",
+                )))],
+            },
+            TestCase {
+                // TC3: GateioMessage Event w/o error is ignored
+                input: GateioMessage::Event(GateioEvent {
+                    channel: "spot.trades".to_owned(),
+                    event: "subscribe".to_owned(),
+                    error: None,
+                }),
+                expected: vec![],
+            },
+            TestCase {
+                // TC4: GateioMessage Event w/ error
+                input: GateioMessage::Event(GateioEvent {
+                    channel: "spot.trades".to_owned(),
+                    event: "subscribe".to_owned(),
+                    error: Some(GateioError {
+                        code: 2,
+                        message: "unknown currency pair GT_USD".to_owned(),
+                    }),
+                }),
+                expected: vec![Err(SocketError::Exchange("".to_owned()))],
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = transformer.transform(test.input);
+            assert_eq!(
+                actual.len(),
+                test.expected.len(),
+                "TestCase {} failed at vector length assert_eq with actual: {:?}",
+                index,
+                actual
+            );
+
+            for (vector_index, (actual, expected)) in
+                actual.into_iter().zip(test.expected).enumerate()
+            {
+                match (actual, expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        // Scrub Utc::now() timestamps to allow comparison
+                        let actual = MarketEvent {
+                            received_time: time,
+                            ..actual
+                        };
+                        assert_eq!(
+                            actual, expected,
+                            "TC{} failed at vector index {}",
+                            index, vector_index
+                        )
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed at vector index {vector_index} because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+}
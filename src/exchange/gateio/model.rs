@@ -0,0 +1,395 @@
+use super::Gateio;
+use crate::{
+    exchange::{datetime_utc_from_epoch_duration, de_str},
+    model::{subscription::Interval, Candle, DataKind, PublicTrade},
+    ExchangeId, MarketEvent,
+};
+use barter_integration::{
+    error::SocketError,
+    model::{Exchange, Instrument, Side, SubscriptionId},
+    Validator,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Deserializer, Serialize};
+use std::time::Duration;
+
+/// [`Gateio`] channel & payload describing a WebSocket subscription.
+///
+/// eg/ GateioSubscription { channel: "spot.candlesticks", payload: ["1m", "BTC_USDT"] }
+///
+/// See docs: <https://www.gate.io/docs/developers/apiv4/ws/en/#client-subscription>
+#[derive(Clone, Eq, PartialEq, Debug, Serialize)]
+pub struct GateioSubscription {
+    pub channel: &'static str,
+    pub payload: Vec<String>,
+}
+
+impl From<&GateioSubscription> for SubscriptionId {
+    fn from(subscription: &GateioSubscription) -> Self {
+        // eg/ SubscriptionId("spot.candlesticks|1m_BTC_USDT")
+        SubscriptionId::from(format!(
+            "{}|{}",
+            subscription.channel,
+            subscription.payload.join("_")
+        ))
+    }
+}
+
+/// [`Gateio`] message received in response to WebSocket subscription requests.
+///
+/// eg/ {"time":1606292218,"time_ms":1606292218231,"channel":"spot.trades","event":"subscribe","result":{"status":"success"}}
+/// eg/ {"time":1606292218,"time_ms":1606292218231,"channel":"spot.trades","event":"subscribe","error":{"code":2,"message":"unknown currency pair GT_USD"},"result":null}
+///
+/// See docs: <https://www.gate.io/docs/developers/apiv4/ws/en/#server-response>
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(tag = "event", rename_all = "lowercase")]
+pub enum GateioSubResponse {
+    Subscribe {
+        #[serde(default)]
+        error: Option<GateioError>,
+    },
+}
+
+/// [`Gateio`] error communicated in a WebSocket message.
+///
+/// eg/ {"code":2,"message":"unknown currency pair GT_USD"}
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct GateioError {
+    pub code: i64,
+    pub message: String,
+}
+
+impl Validator for GateioSubResponse {
+    fn validate(self) -> Result<Self, SocketError>
+    where
+        Self: Sized,
+    {
+        match &self {
+            GateioSubResponse::Subscribe { error: None } => Ok(self),
+            GateioSubResponse::Subscribe { error: Some(error) } => {
+                Err(SocketError::Subscribe(format!(
+                    "received failure subscription response code: {} with message: {}",
+                    error.code, error.message,
+                )))
+            }
+        }
+    }
+}
+
+/// [`Gateio`] candlestick interval used in `spot.candlesticks` subscriptions.
+///
+/// See docs: <https://www.gate.io/docs/developers/apiv4/ws/en/#candlesticks-channel>
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct GateioInterval(pub &'static str);
+
+impl TryFrom<&Interval> for GateioInterval {
+    type Error = SocketError;
+
+    fn try_from(interval: &Interval) -> Result<Self, Self::Error> {
+        let interval = match interval {
+            Interval::Minute1 => "1m",
+            Interval::Minute5 => "5m",
+            Interval::Minute15 => "15m",
+            Interval::Minute30 => "30m",
+            Interval::Hour1 => "1h",
+            Interval::Hour4 => "4h",
+            Interval::Hour8 => "8h",
+            Interval::Day1 => "1d",
+            Interval::Week1 => "7d",
+            other => {
+                return Err(SocketError::Unsupported {
+                    entity: ExchangeId::Gateio.as_str(),
+                    item: other.to_string(),
+                })
+            }
+        };
+
+        Ok(Self(interval))
+    }
+}
+
+/// [`Gateio`] message variants that can be received over [`WebSocket`].
+///
+/// See docs: <https://www.gate.io/docs/developers/apiv4/ws/en/#server-notification>
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum GateioMessage {
+    Trade(GateioTrades),
+    Candle(GateioCandles),
+    Event(GateioEvent),
+}
+
+/// [`Gateio`] `spot.trades` update message.
+///
+/// See docs: <https://www.gate.io/docs/developers/apiv4/ws/en/#public-trades-channel>
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct GateioTrades {
+    #[serde(rename = "result")]
+    pub data: GateioTrade,
+}
+
+/// [`Gateio`] public trade.
+///
+/// eg/ {"id":309143071,"create_time":1606292218,"create_time_ms":"1606292218213.4578",
+///      "side":"sell","currency_pair":"GT_USDT","amount":"16.47","price":"0.4705",
+///      "range":"2390902-2390902"}
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct GateioTrade {
+    pub id: u64,
+    #[serde(
+        rename = "create_time_ms",
+        deserialize_with = "de_str_epoch_ms_f64_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+    pub side: Side,
+    pub currency_pair: String,
+    #[serde(rename = "amount", deserialize_with = "de_str")]
+    pub quantity: f64,
+    #[serde(deserialize_with = "de_str")]
+    pub price: f64,
+}
+
+impl From<&GateioTrade> for SubscriptionId {
+    fn from(trade: &GateioTrade) -> Self {
+        // eg/ SubscriptionId("spot.trades|BTC_USDT")
+        SubscriptionId::from(format!(
+            "{}|{}",
+            Gateio::CHANNEL_TRADES,
+            trade.currency_pair
+        ))
+    }
+}
+
+/// [`Gateio`] `spot.candlesticks` update message.
+///
+/// See docs: <https://www.gate.io/docs/developers/apiv4/ws/en/#candlesticks-channel>
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct GateioCandles {
+    #[serde(rename = "result")]
+    pub data: GateioCandle,
+}
+
+/// [`Gateio`] candlestick. The end time is not sent, so it is determined from the subscribed
+/// [`Interval`] when transformed.
+///
+/// eg/ {"t":"1606292580","v":"2362.32035","c":"19128.1","h":"19128.1","l":"19128.1",
+///      "o":"19128.1","n":"1m_BTC_USDT","a":"3.8283","w":true}
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct GateioCandle {
+    #[serde(rename = "t", deserialize_with = "de_str_epoch_s_as_datetime_utc")]
+    pub start_time: DateTime<Utc>,
+    #[serde(rename = "n")]
+    pub name: String,
+    #[serde(rename = "o", deserialize_with = "de_str")]
+    pub open: f64,
+    #[serde(rename = "h", deserialize_with = "de_str")]
+    pub high: f64,
+    #[serde(rename = "l", deserialize_with = "de_str")]
+    pub low: f64,
+    #[serde(rename = "c", deserialize_with = "de_str")]
+    pub close: f64,
+    #[serde(rename = "a", deserialize_with = "de_str")]
+    pub volume: f64,
+}
+
+impl From<&GateioCandle> for SubscriptionId {
+    fn from(candle: &GateioCandle) -> Self {
+        // eg/ SubscriptionId("spot.candlesticks|1m_BTC_USDT")
+        SubscriptionId::from(format!("{}|{}", Gateio::CHANNEL_CANDLESTICKS, candle.name))
+    }
+}
+
+/// [`Gateio`] messages received over the WebSocket which are not market data
+/// (eg/ subscription responses & errors).
+///
+/// eg/ {"time":1606292218,"channel":"spot.trades","event":"subscribe","error":{"code":2,"message":"unknown currency pair GT_USD"},"result":null}
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct GateioEvent {
+    pub channel: String,
+    pub event: String,
+    #[serde(default)]
+    pub error: Option<GateioError>,
+}
+
+impl From<(ExchangeId, Instrument, GateioTrade)> for MarketEvent {
+    fn from((exchange_id, instrument, trade): (ExchangeId, Instrument, GateioTrade)) -> Self {
+        Self {
+            exchange_time: trade.time,
+            received_time: Utc::now(),
+            received_instant: None,
+            raw: None,
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: DataKind::Trade(PublicTrade {
+                id: trade.id.to_string(),
+                price: trade.price,
+                quantity: trade.quantity,
+                side: trade.side,
+            }),
+        }
+    }
+}
+
+impl From<(ExchangeId, Instrument, Interval, GateioCandle)> for MarketEvent {
+    fn from(
+        (exchange_id, instrument, interval, candle): (
+            ExchangeId,
+            Instrument,
+            Interval,
+            GateioCandle,
+        ),
+    ) -> Self {
+        let end_time = interval.end_time(candle.start_time);
+
+        Self {
+            exchange_time: end_time,
+            received_time: Utc::now(),
+            received_instant: None,
+            raw: None,
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: DataKind::Candle(Candle {
+                start_time: candle.start_time,
+                end_time,
+                open: candle.open,
+                high: candle.high,
+                low: candle.low,
+                close: candle.close,
+                volume: candle.volume,
+                trade_count: 0,
+            }),
+        }
+    }
+}
+
+/// Deserialize a `String` epoch seconds timestamp (eg/ "1606292580") as `DateTime<Utc>`.
+pub fn de_str_epoch_s_as_datetime_utc<'de, D>(deserializer: D) -> Result<DateTime<Utc>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    de_str(deserializer)
+        .map(|epoch_s| datetime_utc_from_epoch_duration(Duration::from_secs(epoch_s)))
+}
+
+/// Deserialize a `String` epoch milliseconds timestamp with fractional part
+/// (eg/ "1606292218213.4578") as `DateTime<Utc>`, truncated to microsecond precision.
+pub fn de_str_epoch_ms_f64_as_datetime_utc<'de, D>(
+    deserializer: D,
+) -> Result<DateTime<Utc>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    de_str(deserializer).map(|epoch_ms: f64| {
+        datetime_utc_from_epoch_duration(Duration::from_micros((epoch_ms * 1000.0) as u64))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialise_gateio_sub_response() {
+        struct TestCase {
+            input: &'static str,
+            expected: Result<GateioSubResponse, SocketError>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: input response is successful subscription
+                input: r#"{"time":1606292218,"time_ms":1606292218231,"channel":"spot.trades","event":"subscribe","result":{"status":"success"}}"#,
+                expected: Ok(GateioSubResponse::Subscribe { error: None }),
+            },
+            TestCase {
+                // TC1: input response is failed subscription
+                input: r#"{"time":1606292218,"time_ms":1606292218231,"channel":"spot.trades","event":"subscribe","error":{"code":2,"message":"unknown currency pair GT_USD"},"result":null}"#,
+                expected: Ok(GateioSubResponse::Subscribe {
+                    error: Some(GateioError {
+                        code: 2,
+                        message: "unknown currency pair GT_USD".to_owned(),
+                    }),
+                }),
+            },
+            TestCase {
+                // TC2: input is a market data update, not a subscription response
+                input: r#"{"time":1606292218,"time_ms":1606292218231,"channel":"spot.trades","event":"update","result":{}}"#,
+                expected: Err(SocketError::Subscribe("".to_owned())),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = serde_json::from_str::<GateioSubResponse>(test.input);
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => {
+                    assert_eq!(actual, expected, "TC{} failed", index)
+                }
+                (Err(_), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_deserialise_gateio_message() {
+        struct TestCase {
+            input: &'static str,
+            expected: Option<GateioMessage>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: input is a valid spot.trades update
+                input: r#"{"time":1606292218,"time_ms":1606292218231,"channel":"spot.trades","event":"update","result":{"id":309143071,"create_time":1606292218,"create_time_ms":"1606292218213.4578","side":"sell","currency_pair":"GT_USDT","amount":"16.47","price":"0.4705","range":"2390902-2390902"}}"#,
+                expected: Some(GateioMessage::Trade(GateioTrades {
+                    data: GateioTrade {
+                        id: 309143071,
+                        time: datetime_utc_from_epoch_duration(Duration::from_micros(
+                            1606292218213457,
+                        )),
+                        side: Side::Sell,
+                        currency_pair: "GT_USDT".to_owned(),
+                        quantity: 16.47,
+                        price: 0.4705,
+                    },
+                })),
+            },
+            TestCase {
+                // TC1: input is a valid spot.candlesticks update
+                input: r#"{"time":1606292600,"time_ms":1606292600376,"channel":"spot.candlesticks","event":"update","result":{"t":"1606292580","v":"2362.32035","c":"19128.1","h":"19128.1","l":"19128.1","o":"19128.1","n":"1m_BTC_USDT","a":"3.8283","w":true}}"#,
+                expected: Some(GateioMessage::Candle(GateioCandles {
+                    data: GateioCandle {
+                        start_time: datetime_utc_from_epoch_duration(Duration::from_secs(
+                            1606292580,
+                        )),
+                        name: "1m_BTC_USDT".to_owned(),
+                        open: 19128.1,
+                        high: 19128.1,
+                        low: 19128.1,
+                        close: 19128.1,
+                        volume: 3.8283,
+                    },
+                })),
+            },
+            TestCase {
+                // TC2: input is a subscription response event
+                input: r#"{"time":1606292218,"time_ms":1606292218231,"channel":"spot.trades","event":"subscribe","result":{"status":"success"}}"#,
+                expected: Some(GateioMessage::Event(GateioEvent {
+                    channel: "spot.trades".to_owned(),
+                    event: "subscribe".to_owned(),
+                    error: None,
+                })),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = serde_json::from_str::<GateioMessage>(test.input).ok();
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
@@ -0,0 +1,472 @@
+use crate::{
+    candles::TradeCandles,
+    model::subscription::{Interval, SubKind, SubscriptionIds, SubscriptionMeta},
+    ExchangeId, ExchangeTransformer, MarketEvent, Subscriber, Subscription, Validator,
+};
+use barter_integration::{
+    error::SocketError,
+    model::{Instrument, SubscriptionId},
+    protocol::websocket::WsMessage,
+    Transformer,
+};
+use model::{GeminiMessage, GeminiSubResponse, GeminiTrade};
+use serde_json::json;
+use std::collections::{BTreeMap, HashMap};
+use tokio::sync::mpsc;
+
+/// [`Gemini`] specific data structures.
+pub mod model;
+
+/// [`Gemini`] [`Subscriber`] & [`ExchangeTransformer`] implementor for the collection
+/// of `Spot` data via the Gemini v2 market data WebSocket API.
+///
+/// Trades are delivered as part of the `l2` subscription. Candles of an [`Interval`] Gemini
+/// offers a native `candles_*` subscription for are consumed directly, while candles of any
+/// other [`Interval`] are synthesised from the trades of the `l2` subscription (see
+/// [`TradeCandles`]).
+#[derive(Clone, PartialEq, Debug)]
+pub struct Gemini {
+    pub ids: SubscriptionIds,
+    /// [`Instrument`] of each symbol with candles synthesised from trades.
+    pub synthetic: HashMap<String, Instrument>,
+    pub candles: TradeCandles,
+}
+
+impl Subscriber for Gemini {
+    type SubResponse = GeminiSubResponse;
+
+    fn base_url() -> &'static str {
+        ExchangeId::Gemini.ws_base_url()
+    }
+
+    fn build_subscription_meta(
+        subscriptions: &[Subscription],
+    ) -> Result<SubscriptionMeta, SocketError> {
+        // Allocate SubscriptionIds HashMap to track identifiers for each actioned Subscription
+        let mut ids = SubscriptionIds(HashMap::with_capacity(subscriptions.len()));
+
+        // Symbols to subscribe to, keyed by Gemini subscription name (eg/ "l2", "candles_1m")
+        let mut names = BTreeMap::<String, Vec<String>>::new();
+
+        for subscription in subscriptions {
+            // Validate provided Subscription InstrumentKind & SubKind are supported by Gemini
+            let subscription = subscription.validate()?;
+            let symbol = Self::symbol(&subscription.instrument);
+
+            let (id, name) = match &subscription.kind {
+                SubKind::Trade => (
+                    Self::CHANNEL_TRADE.to_owned(),
+                    Self::SUBSCRIPTION_L2.to_owned(),
+                ),
+                SubKind::Candle(Interval::Month1 | Interval::Month3) => {
+                    return Err(SocketError::Unsupported {
+                        entity: Self::EXCHANGE.as_str(),
+                        item: subscription.kind.to_string(),
+                    })
+                }
+                SubKind::Candle(interval) => {
+                    // Candles w/o a native subscription are synthesised from l2 trades
+                    let id = format!("{}_{interval}", Self::CHANNEL_CANDLES);
+                    let name = match Self::native_candles(interval) {
+                        true => id.clone(),
+                        false => Self::SUBSCRIPTION_L2.to_owned(),
+                    };
+                    (id, name)
+                }
+                other => {
+                    return Err(SocketError::Unsupported {
+                        entity: Self::EXCHANGE.as_str(),
+                        item: other.to_string(),
+                    })
+                }
+            };
+
+            // Use "name|symbol" as the SubscriptionId key in the SubscriptionIds
+            // eg/ SubscriptionId("candles_1m|BTCUSD")
+            ids.insert(
+                SubscriptionId::from(format!("{id}|{symbol}")),
+                subscription.clone(),
+            );
+
+            let symbols = names.entry(name).or_default();
+            if !symbols.contains(&symbol) {
+                symbols.push(symbol);
+            }
+        }
+
+        // Gemini does not acknowledge subscriptions, so no responses are expected
+        Ok(SubscriptionMeta {
+            ids,
+            subscriptions: vec![Self::subscription_message(names)],
+            expected_responses: 0,
+        })
+    }
+}
+
+impl ExchangeTransformer for Gemini {
+    const EXCHANGE: ExchangeId = ExchangeId::Gemini;
+
+    fn new(_: mpsc::UnboundedSender<WsMessage>, ids: SubscriptionIds) -> Self {
+        // Determine the candles that must be synthesised from trades
+        let synthetic_subscriptions = ids
+            .values()
+            .filter(|subscription| {
+                matches!(subscription.kind, SubKind::Candle(interval) if !Self::native_candles(&interval))
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+
+        let synthetic = synthetic_subscriptions
+            .iter()
+            .map(|subscription| {
+                (
+                    Self::symbol(&subscription.instrument),
+                    subscription.instrument.clone(),
+                )
+            })
+            .collect();
+
+        Self {
+            ids,
+            synthetic,
+            candles: TradeCandles::new(&synthetic_subscriptions),
+        }
+    }
+}
+
+impl Transformer<MarketEvent> for Gemini {
+    type Input = GeminiMessage;
+    type OutputIter = Vec<Result<MarketEvent, SocketError>>;
+
+    fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+        match input {
+            GeminiMessage::Trade(trade) => self.transform_trade(trade),
+            GeminiMessage::Candles(candles) => {
+                // Determine Instrument & Interval associated with this GeminiCandles message
+                let subscription_id = SubscriptionId::from(&candles);
+                let (instrument, interval) = match self.ids.get(&subscription_id) {
+                    Some(Subscription {
+                        instrument,
+                        kind: SubKind::Candle(interval),
+                        ..
+                    }) => (instrument.clone(), *interval),
+                    _ => return vec![Err(SocketError::Unidentifiable(subscription_id))],
+                };
+
+                // Only the most recent candle is delivered, since the initial update contains
+                // the candle history
+                candles
+                    .changes
+                    .into_iter()
+                    .next()
+                    .map(|candle| {
+                        Ok(MarketEvent::from((
+                            Gemini::EXCHANGE,
+                            instrument,
+                            interval,
+                            candle,
+                        )))
+                    })
+                    .into_iter()
+                    .collect()
+            }
+            GeminiMessage::Error(error) => match error.validate() {
+                Ok(_) => vec![],
+                Err(error) => vec![Err(error)],
+            },
+            GeminiMessage::Other(_) => vec![],
+        }
+    }
+}
+
+impl Gemini {
+    /// [`Gemini`] level 2 subscription name, which also delivers trades.
+    ///
+    /// See docs: <https://docs.gemini.com/websocket-api/#level-2-data>
+    pub const SUBSCRIPTION_L2: &'static str = "l2";
+
+    /// [`Gemini`] trade event type, used as the channel of trade [`SubscriptionId`]s.
+    pub const CHANNEL_TRADE: &'static str = "trade";
+
+    /// [`Gemini`] candles subscription prefix, suffixed with the [`Interval`]
+    /// (eg/ "candles_1m").
+    ///
+    /// See docs: <https://docs.gemini.com/websocket-api/#candles-data-feed>
+    pub const CHANNEL_CANDLES: &'static str = "candles";
+
+    /// Determine if [`Gemini`] offers a native candles subscription for the provided
+    /// [`Interval`].
+    pub fn native_candles(interval: &Interval) -> bool {
+        matches!(
+            interval,
+            Interval::Minute1
+                | Interval::Minute5
+                | Interval::Minute15
+                | Interval::Minute30
+                | Interval::Hour1
+                | Interval::Hour6
+                | Interval::Day1
+        )
+    }
+
+    /// Determine the [`Gemini`] symbol for the provided [`Instrument`].
+    ///
+    /// eg/ "BTCUSD"
+    pub fn symbol(instrument: &Instrument) -> String {
+        format!("{}{}", instrument.base, instrument.quote).to_uppercase()
+    }
+
+    /// Build a [`Gemini`] compatible subscription message for the provided subscription names
+    /// & their symbols.
+    ///
+    /// eg/ {"type":"subscribe","subscriptions":[{"name":"l2","symbols":["BTCUSD"]}]}
+    pub fn subscription_message(names: BTreeMap<String, Vec<String>>) -> WsMessage {
+        let subscriptions = names
+            .into_iter()
+            .map(|(name, symbols)| json!({ "name": name, "symbols": symbols }))
+            .collect::<Vec<_>>();
+
+        WsMessage::Text(
+            json!({
+                "type": "subscribe",
+                "subscriptions": subscriptions,
+            })
+            .to_string(),
+        )
+    }
+
+    /// Transform a [`GeminiTrade`] into a trade [`MarketEvent`] if trades of the symbol are
+    /// subscribed to, plus any candles synthesised from the symbol's trades that it completes.
+    fn transform_trade(&mut self, trade: GeminiTrade) -> Vec<Result<MarketEvent, SocketError>> {
+        let mut events = Vec::new();
+
+        if let Some(instrument) = self.synthetic.get(&trade.symbol) {
+            let event = MarketEvent::from((Gemini::EXCHANGE, instrument.clone(), trade.clone()));
+            events.extend(self.candles.update(&event).into_iter().map(Ok));
+        }
+
+        let subscription_id =
+            SubscriptionId::from(format!("{}|{}", Self::CHANNEL_TRADE, trade.symbol));
+        match self.ids.find_instrument(&subscription_id) {
+            Ok(instrument) => {
+                events.push(Ok(MarketEvent::from((Gemini::EXCHANGE, instrument, trade))))
+            }
+            Err(error) if !self.synthetic.contains_key(&trade.symbol) => events.push(Err(error)),
+            Err(_) => {}
+        }
+
+        events
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{Candle, DataKind, PublicTrade};
+    use barter_integration::model::{InstrumentKind, Side};
+    use chrono::{Duration, TimeZone, Utc};
+
+    #[test]
+    fn test_build_subscription_meta() {
+        struct TestCase {
+            input: Vec<Subscription>,
+            expected: Result<(Vec<&'static str>, &'static str), SocketError>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: trades, native & synthesised candles share a single subscribe message
+                input: vec![
+                    Subscription::new(
+                        ExchangeId::Gemini,
+                        ("btc", "usd", InstrumentKind::Spot),
+                        SubKind::Trade,
+                    ),
+                    Subscription::new(
+                        ExchangeId::Gemini,
+                        ("btc", "usd", InstrumentKind::Spot),
+                        SubKind::Candle(Interval::Minute1),
+                    ),
+                    Subscription::new(
+                        ExchangeId::Gemini,
+                        ("btc", "usd", InstrumentKind::Spot),
+                        SubKind::Candle(Interval::Hour4),
+                    ),
+                ],
+                expected: Ok((
+                    vec!["candles_1m|BTCUSD", "candles_4h|BTCUSD", "trade|BTCUSD"],
+                    r#"{"subscriptions":[{"name":"candles_1m","symbols":["BTCUSD"]},{"name":"l2","symbols":["BTCUSD"]}],"type":"subscribe"}"#,
+                )),
+            },
+            TestCase {
+                // TC1: Unsupported month Interval cannot be synthesised
+                input: vec![Subscription::new(
+                    ExchangeId::Gemini,
+                    ("btc", "usd", InstrumentKind::Spot),
+                    SubKind::Candle(Interval::Month1),
+                )],
+                expected: Err(SocketError::Unsupported {
+                    entity: "gemini",
+                    item: SubKind::Candle(Interval::Month1).to_string(),
+                }),
+            },
+            TestCase {
+                // TC2: Unsupported InstrumentKind::FuturePerpetual
+                input: vec![Subscription::new(
+                    ExchangeId::Gemini,
+                    ("btc", "usd", InstrumentKind::FuturePerpetual),
+                    SubKind::Trade,
+                )],
+                expected: Err(SocketError::Unsupported {
+                    entity: "gemini",
+                    item: InstrumentKind::FuturePerpetual.to_string(),
+                }),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = Gemini::build_subscription_meta(&test.input).map(|meta| {
+                let mut ids = meta
+                    .ids
+                    .keys()
+                    .map(|id| id.as_ref().to_owned())
+                    .collect::<Vec<_>>();
+                ids.sort();
+                (ids, meta.subscriptions)
+            });
+            match (actual, test.expected) {
+                (Ok((actual_ids, actual_messages)), Ok((expected_ids, expected_message))) => {
+                    assert_eq!(actual_ids, expected_ids, "TC{} failed", index);
+                    assert_eq!(
+                        actual_messages,
+                        vec![WsMessage::Text(expected_message.to_owned())],
+                        "TC{} failed",
+                        index
+                    );
+                }
+                (Err(_), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_gemini_transform_synthesises_candles() {
+        let synthetic_sub = Subscription::new(
+            ExchangeId::Gemini,
+            ("btc", "usd", InstrumentKind::Spot),
+            SubKind::Candle(Interval::Hour4),
+        );
+        let (ws_sink_tx, _ws_sink_rx) = mpsc::unbounded_channel();
+        let mut transformer = Gemini::new(
+            ws_sink_tx,
+            SubscriptionIds(HashMap::from([(
+                SubscriptionId::from("candles_4h|BTCUSD"),
+                synthetic_sub.clone(),
+            )])),
+        );
+
+        let start = Utc
+            .timestamp_opt(1_680_000_000 - 1_680_000_000 % 14_400, 0)
+            .unwrap();
+        let trade = |minutes: i64, id: u64, price: f64, symbol: &str| GeminiTrade {
+            symbol: symbol.to_owned(),
+            id,
+            time: start + Duration::minutes(minutes),
+            price,
+            quantity: 1.0,
+            side: Side::Buy,
+        };
+
+        struct TestCase {
+            input: GeminiTrade,
+            expected: Vec<Option<DataKind>>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: first trade opens a candle w/o any output
+                input: trade(1, 1, 100.0, "BTCUSD"),
+                expected: vec![],
+            },
+            TestCase {
+                // TC1: trade in the same bucket updates the candle w/o any output
+                input: trade(100, 2, 120.0, "BTCUSD"),
+                expected: vec![],
+            },
+            TestCase {
+                // TC2: trade in the next bucket completes the candle
+                input: trade(240, 3, 90.0, "BTCUSD"),
+                expected: vec![Some(DataKind::Candle(Candle {
+                    start_time: start,
+                    end_time: start + Duration::hours(4),
+                    open: 100.0,
+                    high: 120.0,
+                    low: 100.0,
+                    close: 120.0,
+                    volume: 2.0,
+                    trade_count: 2,
+                }))],
+            },
+            TestCase {
+                // TC3: trade of an unsubscribed symbol is unidentifiable
+                input: trade(241, 4, 90.0, "ETHUSD"),
+                expected: vec![None],
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = transformer
+                .transform(GeminiMessage::Trade(test.input))
+                .into_iter()
+                .map(|event| event.ok().map(|event| event.kind))
+                .collect::<Vec<_>>();
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_gemini_transform_trade() {
+        let trade_sub = Subscription::new(
+            ExchangeId::Gemini,
+            ("btc", "usd", InstrumentKind::Spot),
+            SubKind::Trade,
+        );
+        let (ws_sink_tx, _ws_sink_rx) = mpsc::unbounded_channel();
+        let mut transformer = Gemini::new(
+            ws_sink_tx,
+            SubscriptionIds(HashMap::from([(
+                SubscriptionId::from("trade|BTCUSD"),
+                trade_sub,
+            )])),
+        );
+
+        let actual = transformer
+            .transform(GeminiMessage::Trade(GeminiTrade {
+                symbol: "BTCUSD".to_owned(),
+                id: 1,
+                time: Utc::now(),
+                price: 100.0,
+                quantity: 1.0,
+                side: Side::Sell,
+            }))
+            .into_iter()
+            .map(|event| event.ok().map(|event| event.kind))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            actual,
+            vec![Some(DataKind::Trade(PublicTrade {
+                id: "1".to_owned(),
+                price: 100.0,
+                quantity: 1.0,
+                side: Side::Sell,
+            }))]
+        );
+    }
+}
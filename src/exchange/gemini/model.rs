@@ -0,0 +1,240 @@
+use crate::{
+    exchange::{de_str, de_u64_epoch_ms_as_datetime_utc},
+    model::{subscription::Interval, Candle, DataKind, PublicTrade},
+    ExchangeId, MarketEvent,
+};
+use barter_integration::{
+    error::SocketError,
+    model::{Exchange, Instrument, Side, SubscriptionId},
+    Validator,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// [`Gemini`](super::Gemini) error message. The v2 market data API does not acknowledge
+/// subscriptions, so only failures are communicated.
+///
+/// eg/ {"result":"error","reason":"InvalidJson","message":"..."}
+///
+/// See docs: <https://docs.gemini.com/websocket-api/#market-data-version-2>
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct GeminiSubResponse {
+    pub result: String,
+    pub reason: String,
+    #[serde(default)]
+    pub message: String,
+}
+
+impl Validator for GeminiSubResponse {
+    fn validate(self) -> Result<Self, SocketError>
+    where
+        Self: Sized,
+    {
+        if self.result == "error" {
+            Err(SocketError::Subscribe(format!(
+                "received failure subscription response reason: {} with message: {}",
+                self.reason, self.message
+            )))
+        } else {
+            Ok(self)
+        }
+    }
+}
+
+/// [`Gemini`](super::Gemini) message variants that can be received over [`WebSocket`].
+///
+/// See docs: <https://docs.gemini.com/websocket-api/#market-data-version-2>
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum GeminiMessage {
+    Trade(GeminiTrade),
+    Candles(GeminiCandles),
+    Error(GeminiSubResponse),
+    Other(GeminiOther),
+}
+
+/// [`Gemini`](super::Gemini) trade event, delivered as part of the `l2` subscription.
+///
+/// eg/ {"type":"trade","symbol":"BTCUSD","event_id":3575573053,"timestamp":1560976400428,
+///      "price":"9004.21000000","quantity":"0.09110000","side":"buy"}
+///
+/// See docs: <https://docs.gemini.com/websocket-api/#level-2-data>
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct GeminiTrade {
+    pub symbol: String,
+    #[serde(rename = "event_id")]
+    pub id: u64,
+    #[serde(
+        rename = "timestamp",
+        deserialize_with = "de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+    #[serde(deserialize_with = "de_str")]
+    pub price: f64,
+    #[serde(deserialize_with = "de_str")]
+    pub quantity: f64,
+    pub side: Side,
+}
+
+/// [`Gemini`](super::Gemini) candles update, with the most recent candle first.
+///
+/// eg/ {"type":"candles_15m_updates","symbol":"BTCUSD","changes":[[1561054500000,9350.18,9358.35,9350.18,9355.51,2.07]]}
+///
+/// See docs: <https://docs.gemini.com/websocket-api/#candles-data-feed>
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct GeminiCandles {
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub symbol: String,
+    pub changes: Vec<GeminiCandle>,
+}
+
+impl From<&GeminiCandles> for SubscriptionId {
+    fn from(candles: &GeminiCandles) -> Self {
+        // eg/ SubscriptionId("candles_15m|BTCUSD")
+        SubscriptionId::from(format!(
+            "{}|{}",
+            candles.kind.trim_end_matches("_updates"),
+            candles.symbol
+        ))
+    }
+}
+
+/// [`Gemini`](super::Gemini) candle in the format [start time, open, high, low, close, volume].
+///
+/// eg/ [1561054500000, 9350.18, 9358.35, 9350.18, 9355.51, 2.07]
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct GeminiCandle(
+    #[serde(deserialize_with = "de_u64_epoch_ms_as_datetime_utc")] pub DateTime<Utc>,
+    #[serde(deserialize_with = "de_str")] pub f64,
+    #[serde(deserialize_with = "de_str")] pub f64,
+    #[serde(deserialize_with = "de_str")] pub f64,
+    #[serde(deserialize_with = "de_str")] pub f64,
+    #[serde(deserialize_with = "de_str")] pub f64,
+);
+
+/// [`Gemini`](super::Gemini) message that is not normalised (eg/ "l2_updates").
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct GeminiOther {
+    #[serde(rename = "type")]
+    pub kind: String,
+}
+
+impl From<(ExchangeId, Instrument, GeminiTrade)> for MarketEvent {
+    fn from((exchange_id, instrument, trade): (ExchangeId, Instrument, GeminiTrade)) -> Self {
+        Self {
+            exchange_time: trade.time,
+            received_time: Utc::now(),
+            received_instant: None,
+            raw: None,
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: DataKind::Trade(PublicTrade {
+                id: trade.id.to_string(),
+                price: trade.price,
+                quantity: trade.quantity,
+                side: trade.side,
+            }),
+        }
+    }
+}
+
+impl From<(ExchangeId, Instrument, Interval, GeminiCandle)> for MarketEvent {
+    fn from(
+        (exchange_id, instrument, interval, candle): (
+            ExchangeId,
+            Instrument,
+            Interval,
+            GeminiCandle,
+        ),
+    ) -> Self {
+        let GeminiCandle(start_time, open, high, low, close, volume) = candle;
+        let end_time = interval.end_time(start_time);
+
+        Self {
+            exchange_time: end_time,
+            received_time: Utc::now(),
+            received_instant: None,
+            raw: None,
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: DataKind::Candle(Candle {
+                start_time,
+                end_time,
+                open,
+                high,
+                low,
+                close,
+                volume,
+                trade_count: 0,
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchange::datetime_utc_from_epoch_duration;
+    use std::time::Duration;
+
+    #[test]
+    fn test_deserialise_gemini_message() {
+        struct TestCase {
+            input: &'static str,
+            expected: Option<GeminiMessage>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: input is a valid trade
+                input: r#"{"type":"trade","symbol":"BTCUSD","event_id":3575573053,"timestamp":1560976400428,"price":"9004.21000000","quantity":"0.09110000","side":"buy"}"#,
+                expected: Some(GeminiMessage::Trade(GeminiTrade {
+                    symbol: "BTCUSD".to_owned(),
+                    id: 3575573053,
+                    time: datetime_utc_from_epoch_duration(Duration::from_millis(1560976400428)),
+                    price: 9004.21,
+                    quantity: 0.0911,
+                    side: Side::Buy,
+                })),
+            },
+            TestCase {
+                // TC1: input is a valid candles update
+                input: r#"{"type":"candles_15m_updates","symbol":"BTCUSD","changes":[[1561054500000,9350.18,9358.35,9350.18,9355.51,2.07]]}"#,
+                expected: Some(GeminiMessage::Candles(GeminiCandles {
+                    kind: "candles_15m_updates".to_owned(),
+                    symbol: "BTCUSD".to_owned(),
+                    changes: vec![GeminiCandle(
+                        datetime_utc_from_epoch_duration(Duration::from_millis(1561054500000)),
+                        9350.18,
+                        9358.35,
+                        9350.18,
+                        9355.51,
+                        2.07,
+                    )],
+                })),
+            },
+            TestCase {
+                // TC2: input is an l2_updates message, which is not normalised
+                input: r#"{"type":"l2_updates","symbol":"BTCUSD","changes":[["buy","9122.04","0.00121425"]],"trades":[]}"#,
+                expected: Some(GeminiMessage::Other(GeminiOther {
+                    kind: "l2_updates".to_owned(),
+                })),
+            },
+            TestCase {
+                // TC3: input is an error
+                input: r#"{"result":"error","reason":"InvalidJson","message":"Unexpected end of input"}"#,
+                expected: Some(GeminiMessage::Error(GeminiSubResponse {
+                    result: "error".to_owned(),
+                    reason: "InvalidJson".to_owned(),
+                    message: "Unexpected end of input".to_owned(),
+                })),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = serde_json::from_str::<GeminiMessage>(test.input).ok();
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
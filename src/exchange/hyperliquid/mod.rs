@@ -0,0 +1,366 @@
+use crate::{
+    model::subscription::{SubKind, SubscriptionIds, SubscriptionMeta},
+    ExchangeId, ExchangeTransformer, MarketEvent, Subscriber, Subscription, Validator,
+};
+use barter_integration::{
+    error::SocketError,
+    model::{Instrument, SubscriptionId},
+    protocol::websocket::WsMessage,
+    Transformer,
+};
+use model::{
+    HyperliquidInterval, HyperliquidMessage, HyperliquidSubResponse, HyperliquidSubscription,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::collections::HashMap;
+use tokio::sync::mpsc;
+
+/// [`Hyperliquid`] specific data structures.
+pub mod model;
+
+/// [`Hyperliquid`] [`Subscriber`] & [`ExchangeTransformer`] implementor for the collection
+/// of `FuturePerpetual` data via the Hyperliquid public WebSocket API.
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct Hyperliquid {
+    pub ids: SubscriptionIds,
+}
+
+impl Subscriber for Hyperliquid {
+    type SubResponse = HyperliquidSubResponse;
+
+    fn base_url() -> &'static str {
+        ExchangeId::Hyperliquid.ws_base_url()
+    }
+
+    fn build_subscription_meta(
+        subscriptions: &[Subscription],
+    ) -> Result<SubscriptionMeta, SocketError> {
+        // Allocate SubscriptionIds HashMap to track identifiers for each actioned Subscription
+        let mut ids = SubscriptionIds(HashMap::with_capacity(subscriptions.len()));
+
+        // Map Barter Subscriptions to HyperliquidSubscriptions
+        let subscriptions = subscriptions
+            .iter()
+            .map(|subscription| {
+                // Translate Barter Subscription to the associated HyperliquidSubscription
+                let hyperliquid_subscription = Self::subscription(subscription)?;
+
+                // Use "type|coin(|interval)" as the SubscriptionId key in the SubscriptionIds
+                // eg/ SubscriptionId("candle|BTC|1m")
+                ids.insert(
+                    SubscriptionId::from(&hyperliquid_subscription),
+                    subscription.clone(),
+                );
+
+                // Construct Hyperliquid specific subscription message
+                Ok(Self::subscription_message(&hyperliquid_subscription))
+            })
+            .collect::<Result<Vec<_>, SocketError>>()?;
+
+        Ok(SubscriptionMeta {
+            ids,
+            expected_responses: subscriptions.len(),
+            subscriptions,
+        })
+    }
+}
+
+impl ExchangeTransformer for Hyperliquid {
+    const EXCHANGE: ExchangeId = ExchangeId::Hyperliquid;
+
+    fn new(_: mpsc::UnboundedSender<WsMessage>, ids: SubscriptionIds) -> Self {
+        Self { ids }
+    }
+}
+
+impl Transformer<MarketEvent> for Hyperliquid {
+    type Input = HyperliquidMessage;
+    type OutputIter = Vec<Result<MarketEvent, SocketError>>;
+
+    fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+        match input {
+            HyperliquidMessage::Trades(trades) => trades
+                .into_iter()
+                .map(|trade| {
+                    // Determine Instrument associated with this HyperliquidTrade
+                    let subscription_id =
+                        SubscriptionId::from(format!("{}|{}", Self::CHANNEL_TRADES, trade.coin));
+                    self.ids
+                        .find_instrument(&subscription_id)
+                        .map(|instrument| MarketEvent::from((Self::EXCHANGE, instrument, trade)))
+                })
+                .collect(),
+            HyperliquidMessage::Candle(candle) => {
+                match self.ids.find_instrument(&SubscriptionId::from(&candle)) {
+                    Ok(instrument) => {
+                        vec![Ok(MarketEvent::from((Self::EXCHANGE, instrument, candle)))]
+                    }
+                    Err(error) => vec![Err(error)],
+                }
+            }
+            HyperliquidMessage::SubscriptionResponse(_) => vec![],
+            HyperliquidMessage::Error(message) => vec![Err(SocketError::Exchange(format!(
+                "received error with message: {message}"
+            )))],
+        }
+    }
+}
+
+impl Hyperliquid {
+    /// [`Hyperliquid`] trades subscription type.
+    ///
+    /// See docs: <https://hyperliquid.gitbook.io/hyperliquid-docs/for-developers/api/websocket/subscriptions>
+    pub const CHANNEL_TRADES: &'static str = "trades";
+
+    /// [`Hyperliquid`] candle subscription type, parameterised by a [`HyperliquidInterval`].
+    ///
+    /// See docs: <https://hyperliquid.gitbook.io/hyperliquid-docs/for-developers/api/websocket/subscriptions>
+    pub const CHANNEL_CANDLE: &'static str = "candle";
+
+    /// Translate a Barter [`Subscription`] into a [`HyperliquidSubscription`].
+    ///
+    /// eg/ HyperliquidSubscription { kind: "candle", coin: "BTC", interval: Some("1m") }
+    pub fn subscription(sub: &Subscription) -> Result<HyperliquidSubscription, SocketError> {
+        // Validate provided Subscription InstrumentKind & SubKind are supported by Hyperliquid
+        let sub = sub.validate()?;
+        let coin = Self::coin(&sub.instrument);
+
+        match &sub.kind {
+            SubKind::Trade => Ok(HyperliquidSubscription {
+                kind: Self::CHANNEL_TRADES.to_owned(),
+                coin,
+                interval: None,
+            }),
+            SubKind::Candle(interval) => Ok(HyperliquidSubscription {
+                kind: Self::CHANNEL_CANDLE.to_owned(),
+                coin,
+                interval: Some(HyperliquidInterval::try_from(interval)?.0.to_owned()),
+            }),
+            other => Err(SocketError::Unsupported {
+                entity: Self::EXCHANGE.as_str(),
+                item: other.to_string(),
+            }),
+        }
+    }
+
+    /// Determine the [`Hyperliquid`] coin for the provided [`Instrument`]. Perpetuals are all
+    /// USDC margined & identified by the base asset alone.
+    ///
+    /// eg/ "BTC"
+    pub fn coin(instrument: &Instrument) -> String {
+        instrument.base.as_ref().to_uppercase()
+    }
+
+    /// Build a [`Hyperliquid`] compatible subscription message using the
+    /// [`HyperliquidSubscription`] provided.
+    ///
+    /// eg/ {"method":"subscribe","subscription":{"type":"trades","coin":"BTC"}}
+    pub fn subscription_message(subscription: &HyperliquidSubscription) -> WsMessage {
+        WsMessage::Text(
+            json!({
+                "method": "subscribe",
+                "subscription": subscription,
+            })
+            .to_string(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchange::hyperliquid::model::{HyperliquidCandle, HyperliquidTrade};
+    use crate::model::{subscription::Interval, Candle, DataKind, PublicTrade};
+    use barter_integration::model::{InstrumentKind, Side};
+    use chrono::{Duration, Utc};
+
+    #[test]
+    fn test_subscription() {
+        struct TestCase {
+            input: Subscription,
+            expected: Result<HyperliquidSubscription, SocketError>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: Supported InstrumentKind::FuturePerpetual trades subscription
+                input: Subscription::new(
+                    ExchangeId::Hyperliquid,
+                    ("btc", "usdc", InstrumentKind::FuturePerpetual),
+                    SubKind::Trade,
+                ),
+                expected: Ok(HyperliquidSubscription {
+                    kind: "trades".to_owned(),
+                    coin: "BTC".to_owned(),
+                    interval: None,
+                }),
+            },
+            TestCase {
+                // TC1: Supported InstrumentKind::FuturePerpetual candles subscription
+                input: Subscription::new(
+                    ExchangeId::Hyperliquid,
+                    ("eth", "usdc", InstrumentKind::FuturePerpetual),
+                    SubKind::Candle(Interval::Month1),
+                ),
+                expected: Ok(HyperliquidSubscription {
+                    kind: "candle".to_owned(),
+                    coin: "ETH".to_owned(),
+                    interval: Some("1M".to_owned()),
+                }),
+            },
+            TestCase {
+                // TC2: Unsupported InstrumentKind::Spot
+                input: Subscription::new(
+                    ExchangeId::Hyperliquid,
+                    ("btc", "usdc", InstrumentKind::Spot),
+                    SubKind::Trade,
+                ),
+                expected: Err(SocketError::Unsupported {
+                    entity: "hyperliquid",
+                    item: InstrumentKind::Spot.to_string(),
+                }),
+            },
+            TestCase {
+                // TC3: Unsupported Interval
+                input: Subscription::new(
+                    ExchangeId::Hyperliquid,
+                    ("btc", "usdc", InstrumentKind::FuturePerpetual),
+                    SubKind::Candle(Interval::Hour6),
+                ),
+                expected: Err(SocketError::Unsupported {
+                    entity: "hyperliquid",
+                    item: Interval::Hour6.to_string(),
+                }),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = Hyperliquid::subscription(&test.input);
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => {
+                    assert_eq!(actual, expected, "TC{} failed", index)
+                }
+                (Err(_), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_subscription_message() {
+        let subscription = Hyperliquid::subscription(&Subscription::new(
+            ExchangeId::Hyperliquid,
+            ("btc", "usdc", InstrumentKind::FuturePerpetual),
+            SubKind::Candle(Interval::Minute1),
+        ))
+        .unwrap();
+
+        assert_eq!(
+            Hyperliquid::subscription_message(&subscription),
+            WsMessage::Text(
+                r#"{"method":"subscribe","subscription":{"coin":"BTC","interval":"1m","type":"candle"}}"#
+                    .to_owned()
+            )
+        );
+    }
+
+    #[test]
+    fn test_hyperliquid_transform() {
+        let mut transformer = Hyperliquid {
+            ids: SubscriptionIds(HashMap::from([
+                (
+                    SubscriptionId::from("trades|BTC"),
+                    Subscription::new(
+                        ExchangeId::Hyperliquid,
+                        ("btc", "usdc", InstrumentKind::FuturePerpetual),
+                        SubKind::Trade,
+                    ),
+                ),
+                (
+                    SubscriptionId::from("candle|BTC|1m"),
+                    Subscription::new(
+                        ExchangeId::Hyperliquid,
+                        ("btc", "usdc", InstrumentKind::FuturePerpetual),
+                        SubKind::Candle(Interval::Minute1),
+                    ),
+                ),
+            ])),
+        };
+
+        let time = Utc::now();
+        let trade = |coin: &str| HyperliquidTrade {
+            coin: coin.to_owned(),
+            side: Side::Buy,
+            price: 100.0,
+            quantity: 1.0,
+            time,
+            id: 1,
+        };
+
+        struct TestCase {
+            input: HyperliquidMessage,
+            expected: Vec<Option<DataKind>>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: HyperliquidMessage Trades w/ known & unknown coins
+                input: HyperliquidMessage::Trades(vec![trade("BTC"), trade("ETH")]),
+                expected: vec![
+                    Some(DataKind::Trade(PublicTrade {
+                        id: "1".to_owned(),
+                        price: 100.0,
+                        quantity: 1.0,
+                        side: Side::Buy,
+                    })),
+                    None,
+                ],
+            },
+            TestCase {
+                // TC1: HyperliquidMessage Candle w/ known SubscriptionId
+                input: HyperliquidMessage::Candle(HyperliquidCandle {
+                    start_time: time,
+                    end_time: time + Duration::minutes(1),
+                    coin: "BTC".to_owned(),
+                    interval: "1m".to_owned(),
+                    open: 1.0,
+                    high: 2.0,
+                    low: 0.5,
+                    close: 1.5,
+                    volume: 10.0,
+                    trade_count: 3,
+                }),
+                expected: vec![Some(DataKind::Candle(Candle {
+                    start_time: time,
+                    end_time: time + Duration::minutes(1),
+                    open: 1.0,
+                    high: 2.0,
+                    low: 0.5,
+                    close: 1.5,
+                    volume: 10.0,
+                    trade_count: 3,
+                }))],
+            },
+            TestCase {
+                // TC2: HyperliquidMessage Error
+                input: HyperliquidMessage::Error("Internal error".to_owned()),
+                expected: vec![None],
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = transformer
+                .transform(test.input)
+                .into_iter()
+                .map(|event| event.ok().map(|event| event.kind))
+                .collect::<Vec<_>>();
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
@@ -0,0 +1,365 @@
+use super::Hyperliquid;
+use crate::{
+    exchange::{de_str, de_u64_epoch_ms_as_datetime_utc},
+    model::{subscription::Interval, Candle, DataKind, PublicTrade},
+    ExchangeId, MarketEvent,
+};
+use barter_integration::{
+    error::SocketError,
+    model::{Exchange, Instrument, Side, SubscriptionId},
+    Validator,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Deserializer, Serialize};
+
+/// [`Hyperliquid`](super::Hyperliquid) subscription, sent within the
+/// `{"method":"subscribe","subscription":{...}}` envelope & echoed back in the acknowledgement.
+///
+/// eg/ {"type":"trades","coin":"BTC"}
+/// eg/ {"type":"candle","coin":"BTC","interval":"1m"}
+///
+/// See docs: <https://hyperliquid.gitbook.io/hyperliquid-docs/for-developers/api/websocket/subscriptions>
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct HyperliquidSubscription {
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub coin: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub interval: Option<String>,
+}
+
+impl From<&HyperliquidSubscription> for SubscriptionId {
+    fn from(subscription: &HyperliquidSubscription) -> Self {
+        // eg/ SubscriptionId("trades|BTC"), SubscriptionId("candle|BTC|1m")
+        match &subscription.interval {
+            Some(interval) => SubscriptionId::from(format!(
+                "{}|{}|{interval}",
+                subscription.kind, subscription.coin
+            )),
+            None => SubscriptionId::from(format!("{}|{}", subscription.kind, subscription.coin)),
+        }
+    }
+}
+
+/// [`Hyperliquid`](super::Hyperliquid) message received in response to WebSocket subscription
+/// requests.
+///
+/// eg/ HyperliquidSubResponse::SubscriptionResponse {"channel":"subscriptionResponse","data":{"method":"subscribe","subscription":{"type":"trades","coin":"BTC"}}}
+/// eg/ HyperliquidSubResponse::Error {"channel":"error","data":"Invalid subscription {\"type\":\"trades\",\"coin\":\"BTCX\"}"}
+///
+/// See docs: <https://hyperliquid.gitbook.io/hyperliquid-docs/for-developers/api/websocket>
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(tag = "channel", content = "data", rename_all = "camelCase")]
+pub enum HyperliquidSubResponse {
+    SubscriptionResponse(HyperliquidSubscriptionAck),
+    Error(String),
+}
+
+/// [`Hyperliquid`](super::Hyperliquid) acknowledgement of a subscription request.
+///
+/// eg/ {"method":"subscribe","subscription":{"type":"trades","coin":"BTC"}}
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct HyperliquidSubscriptionAck {
+    pub method: String,
+    pub subscription: HyperliquidSubscription,
+}
+
+impl Validator for HyperliquidSubResponse {
+    fn validate(self) -> Result<Self, SocketError>
+    where
+        Self: Sized,
+    {
+        match &self {
+            HyperliquidSubResponse::SubscriptionResponse(_) => Ok(self),
+            HyperliquidSubResponse::Error(message) => Err(SocketError::Subscribe(format!(
+                "received failure subscription response with message: {message}",
+            ))),
+        }
+    }
+}
+
+/// [`Hyperliquid`](super::Hyperliquid) candle interval used in `candle` subscriptions.
+///
+/// See docs: <https://hyperliquid.gitbook.io/hyperliquid-docs/for-developers/api/websocket/subscriptions>
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct HyperliquidInterval(pub &'static str);
+
+impl TryFrom<&Interval> for HyperliquidInterval {
+    type Error = SocketError;
+
+    fn try_from(interval: &Interval) -> Result<Self, Self::Error> {
+        let interval = match interval {
+            Interval::Minute1 => "1m",
+            Interval::Minute3 => "3m",
+            Interval::Minute5 => "5m",
+            Interval::Minute15 => "15m",
+            Interval::Minute30 => "30m",
+            Interval::Hour1 => "1h",
+            Interval::Hour2 => "2h",
+            Interval::Hour4 => "4h",
+            Interval::Hour8 => "8h",
+            Interval::Hour12 => "12h",
+            Interval::Day1 => "1d",
+            Interval::Day3 => "3d",
+            Interval::Week1 => "1w",
+            Interval::Month1 => "1M",
+            other => {
+                return Err(SocketError::Unsupported {
+                    entity: ExchangeId::Hyperliquid.as_str(),
+                    item: other.to_string(),
+                })
+            }
+        };
+
+        Ok(Self(interval))
+    }
+}
+
+/// [`Hyperliquid`](super::Hyperliquid) message variants that can be received over [`WebSocket`].
+///
+/// See docs: <https://hyperliquid.gitbook.io/hyperliquid-docs/for-developers/api/websocket>
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(tag = "channel", content = "data", rename_all = "camelCase")]
+pub enum HyperliquidMessage {
+    Trades(Vec<HyperliquidTrade>),
+    Candle(HyperliquidCandle),
+    SubscriptionResponse(HyperliquidSubscriptionAck),
+    Error(String),
+}
+
+/// [`Hyperliquid`](super::Hyperliquid) trade, where the side is "B" for buy & "A" for sell.
+///
+/// eg/ {"coin":"BTC","side":"B","px":"42000.5","sz":"0.01","hash":"0x...","time":1704067200123,
+///      "tid":50453718906549,"users":["0x...","0x..."]}
+///
+/// See docs: <https://hyperliquid.gitbook.io/hyperliquid-docs/for-developers/api/websocket/subscriptions>
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct HyperliquidTrade {
+    pub coin: String,
+    #[serde(deserialize_with = "de_side_from_bid_ask")]
+    pub side: Side,
+    #[serde(rename = "px", deserialize_with = "de_str")]
+    pub price: f64,
+    #[serde(rename = "sz", deserialize_with = "de_str")]
+    pub quantity: f64,
+    #[serde(deserialize_with = "de_u64_epoch_ms_as_datetime_utc")]
+    pub time: DateTime<Utc>,
+    #[serde(rename = "tid")]
+    pub id: u64,
+}
+
+/// [`Hyperliquid`](super::Hyperliquid) candle.
+///
+/// eg/ {"t":1704067200000,"T":1704067259999,"s":"BTC","i":"1m","o":"42001.0","c":"42005.0",
+///      "h":"42010.5","l":"42000.0","v":"1.5","n":12}
+///
+/// See docs: <https://hyperliquid.gitbook.io/hyperliquid-docs/for-developers/api/websocket/subscriptions>
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct HyperliquidCandle {
+    #[serde(rename = "t", deserialize_with = "de_u64_epoch_ms_as_datetime_utc")]
+    pub start_time: DateTime<Utc>,
+    #[serde(rename = "T", deserialize_with = "de_u64_epoch_ms_as_datetime_utc")]
+    pub end_time: DateTime<Utc>,
+    #[serde(rename = "s")]
+    pub coin: String,
+    #[serde(rename = "i")]
+    pub interval: String,
+    #[serde(rename = "o", deserialize_with = "de_str")]
+    pub open: f64,
+    #[serde(rename = "h", deserialize_with = "de_str")]
+    pub high: f64,
+    #[serde(rename = "l", deserialize_with = "de_str")]
+    pub low: f64,
+    #[serde(rename = "c", deserialize_with = "de_str")]
+    pub close: f64,
+    #[serde(rename = "v", deserialize_with = "de_str")]
+    pub volume: f64,
+    #[serde(rename = "n")]
+    pub trade_count: u64,
+}
+
+impl From<&HyperliquidCandle> for SubscriptionId {
+    fn from(candle: &HyperliquidCandle) -> Self {
+        // eg/ SubscriptionId("candle|BTC|1m")
+        SubscriptionId::from(format!(
+            "{}|{}|{}",
+            Hyperliquid::CHANNEL_CANDLE,
+            candle.coin,
+            candle.interval
+        ))
+    }
+}
+
+impl From<(ExchangeId, Instrument, HyperliquidTrade)> for MarketEvent {
+    fn from((exchange_id, instrument, trade): (ExchangeId, Instrument, HyperliquidTrade)) -> Self {
+        Self {
+            exchange_time: trade.time,
+            received_time: Utc::now(),
+            received_instant: None,
+            raw: None,
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: DataKind::Trade(PublicTrade {
+                id: trade.id.to_string(),
+                price: trade.price,
+                quantity: trade.quantity,
+                side: trade.side,
+            }),
+        }
+    }
+}
+
+impl From<(ExchangeId, Instrument, HyperliquidCandle)> for MarketEvent {
+    fn from(
+        (exchange_id, instrument, candle): (ExchangeId, Instrument, HyperliquidCandle),
+    ) -> Self {
+        Self {
+            exchange_time: candle.end_time,
+            received_time: Utc::now(),
+            received_instant: None,
+            raw: None,
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: DataKind::Candle(Candle {
+                start_time: candle.start_time,
+                end_time: candle.end_time,
+                open: candle.open,
+                high: candle.high,
+                low: candle.low,
+                close: candle.close,
+                volume: candle.volume,
+                trade_count: candle.trade_count,
+            }),
+        }
+    }
+}
+
+/// Deserialize a [`Hyperliquid`](super::Hyperliquid) trade side ("B" = buy, "A" = sell) as the
+/// associated [`Side`].
+pub fn de_side_from_bid_ask<'de, D>(deserializer: D) -> Result<Side, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let side = String::deserialize(deserializer)?;
+    match side.as_str() {
+        "B" => Ok(Side::Buy),
+        "A" => Ok(Side::Sell),
+        other => Err(serde::de::Error::invalid_value(
+            serde::de::Unexpected::Str(other),
+            &"B (buy) or A (sell)",
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchange::datetime_utc_from_epoch_duration;
+    use std::time::Duration;
+
+    #[test]
+    fn test_deserialise_hyperliquid_sub_response() {
+        struct TestCase {
+            input: &'static str,
+            expected: Result<HyperliquidSubResponse, SocketError>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: input response is successful candle subscription
+                input: r#"{"channel":"subscriptionResponse","data":{"method":"subscribe","subscription":{"type":"candle","coin":"BTC","interval":"1m"}}}"#,
+                expected: Ok(HyperliquidSubResponse::SubscriptionResponse(
+                    HyperliquidSubscriptionAck {
+                        method: "subscribe".to_owned(),
+                        subscription: HyperliquidSubscription {
+                            kind: "candle".to_owned(),
+                            coin: "BTC".to_owned(),
+                            interval: Some("1m".to_owned()),
+                        },
+                    },
+                )),
+            },
+            TestCase {
+                // TC1: input response is failed subscription
+                input: r#"{"channel":"error","data":"Invalid subscription {\"type\":\"trades\",\"coin\":\"BTCX\"}"}"#,
+                expected: Ok(HyperliquidSubResponse::Error(
+                    r#"Invalid subscription {"type":"trades","coin":"BTCX"}"#.to_owned(),
+                )),
+            },
+            TestCase {
+                // TC2: input is a market data update, not a subscription response
+                input: r#"{"channel":"trades","data":[]}"#,
+                expected: Err(SocketError::Subscribe("".to_owned())),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = serde_json::from_str::<HyperliquidSubResponse>(test.input);
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => {
+                    assert_eq!(actual, expected, "TC{} failed", index)
+                }
+                (Err(_), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_deserialise_hyperliquid_message() {
+        struct TestCase {
+            input: &'static str,
+            expected: Option<HyperliquidMessage>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: input is a valid trades message
+                input: r#"{"channel":"trades","data":[{"coin":"BTC","side":"A","px":"42000.5","sz":"0.01","hash":"0xabc","time":1704067200123,"tid":50453718906549,"users":["0x1","0x2"]}]}"#,
+                expected: Some(HyperliquidMessage::Trades(vec![HyperliquidTrade {
+                    coin: "BTC".to_owned(),
+                    side: Side::Sell,
+                    price: 42000.5,
+                    quantity: 0.01,
+                    time: datetime_utc_from_epoch_duration(Duration::from_millis(1704067200123)),
+                    id: 50453718906549,
+                }])),
+            },
+            TestCase {
+                // TC1: input is a valid candle message
+                input: r#"{"channel":"candle","data":{"t":1704067200000,"T":1704067259999,"s":"BTC","i":"1m","o":"42001.0","c":"42005.0","h":"42010.5","l":"42000.0","v":"1.5","n":12}}"#,
+                expected: Some(HyperliquidMessage::Candle(HyperliquidCandle {
+                    start_time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                        1704067200000,
+                    )),
+                    end_time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                        1704067259999,
+                    )),
+                    coin: "BTC".to_owned(),
+                    interval: "1m".to_owned(),
+                    open: 42001.0,
+                    high: 42010.5,
+                    low: 42000.0,
+                    close: 42005.0,
+                    volume: 1.5,
+                    trade_count: 12,
+                })),
+            },
+            TestCase {
+                // TC2: input is a trade with an invalid side
+                input: r#"{"channel":"trades","data":[{"coin":"BTC","side":"X","px":"42000.5","sz":"0.01","hash":"0xabc","time":1704067200123,"tid":1,"users":[]}]}"#,
+                expected: None,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = serde_json::from_str::<HyperliquidMessage>(test.input).ok();
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
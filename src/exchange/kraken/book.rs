@@ -0,0 +1,233 @@
+use super::model::{KrakenBook, KrakenBookLevel};
+use crate::{
+    exchange::crc32,
+    model::{parse_level_value, upsert_parsed_level, Level, OrderBook, ParsedLevel},
+    ExchangeId,
+};
+use barter_integration::error::SocketError;
+use chrono::{DateTime, Utc};
+
+/// [`Kraken`](super::Kraken) L2 order book maintained from the `book` channel snapshot & updates.
+///
+/// Levels retain the original `String` price & quantity sent by [`Kraken`](super::Kraken), since
+/// these are required to validate the CRC32 checksum sent with every update. Levels beyond the
+/// subscribed depth are discarded after every update, as required to match the checksum.
+///
+/// See docs: <https://docs.kraken.com/websockets/#book-checksum>
+#[derive(Clone, PartialEq, Debug)]
+pub struct KrakenOrderBook {
+    pub last_update_time: DateTime<Utc>,
+    pub depth: usize,
+    /// Bid levels ordered from the highest price.
+    pub bids: Vec<ParsedLevel<KrakenBookLevel>>,
+    /// Ask levels ordered from the lowest price.
+    pub asks: Vec<ParsedLevel<KrakenBookLevel>>,
+}
+
+impl KrakenOrderBook {
+    /// Number of bid & ask levels included in the [`Kraken`](super::Kraken) order book checksum.
+    pub const CHECKSUM_DEPTH: usize = 10;
+
+    /// Construct a new [`KrakenOrderBook`] of the provided depth from a [`KrakenBook`] snapshot.
+    pub fn snapshot(depth: usize, book: KrakenBook) -> Result<Self, SocketError> {
+        let mut order_book = Self {
+            last_update_time: Utc::now(),
+            depth,
+            bids: Vec::with_capacity(depth),
+            asks: Vec::with_capacity(depth),
+        };
+        order_book.apply(book)?;
+        Ok(order_book)
+    }
+
+    /// Apply the levels of the provided [`KrakenBook`] update to this [`KrakenOrderBook`],
+    /// replacing the quantity of existing price levels, removing zero quantity price levels &
+    /// truncating each side to the subscribed depth.
+    pub fn apply(&mut self, book: KrakenBook) -> Result<(), SocketError> {
+        if let Some(time) = book
+            .bids
+            .iter()
+            .chain(&book.asks)
+            .map(|level| level.time)
+            .max()
+        {
+            self.last_update_time = time;
+        }
+
+        for level in book.bids {
+            let (level, quantity) = parse(level)?;
+            upsert_parsed_level(&mut self.bids, level, quantity, |existing, new| {
+                new.total_cmp(existing)
+            });
+        }
+        for level in book.asks {
+            let (level, quantity) = parse(level)?;
+            upsert_parsed_level(&mut self.asks, level, quantity, |existing, new| {
+                existing.total_cmp(new)
+            });
+        }
+
+        self.bids.truncate(self.depth);
+        self.asks.truncate(self.depth);
+        Ok(())
+    }
+
+    /// Calculate the [`Kraken`](super::Kraken) checksum of this [`KrakenOrderBook`].
+    ///
+    /// The price & quantity of the top [`Self::CHECKSUM_DEPTH`] ask levels, followed by the top
+    /// [`Self::CHECKSUM_DEPTH`] bid levels, are concatenated into a `String` after removing the
+    /// decimal point & leading zeros of each, and the CRC32 of this is taken.
+    pub fn checksum(&self) -> u32 {
+        let mut input = String::new();
+        let asks = self.asks.iter().take(Self::CHECKSUM_DEPTH);
+        let bids = self.bids.iter().take(Self::CHECKSUM_DEPTH);
+        for level in asks.chain(bids) {
+            for value in [&level.level.price, &level.level.quantity] {
+                input.extend(
+                    value
+                        .chars()
+                        .filter(|char| *char != '.')
+                        .skip_while(|char| *char == '0'),
+                );
+            }
+        }
+
+        crc32(input.as_bytes())
+    }
+}
+
+impl TryFrom<&KrakenOrderBook> for OrderBook {
+    type Error = SocketError;
+
+    fn try_from(book: &KrakenOrderBook) -> Result<Self, Self::Error> {
+        let levels = |levels: &[ParsedLevel<KrakenBookLevel>]| {
+            levels
+                .iter()
+                .map(|level| {
+                    let quantity = parse_level_value(ExchangeId::Kraken, &level.level.quantity)?;
+                    Ok(Level::new(level.price, quantity))
+                })
+                .collect::<Result<Vec<_>, SocketError>>()
+        };
+
+        Ok(Self {
+            last_update_time: book.last_update_time,
+            // Kraken book messages are not sequenced
+            last_update_id: 0,
+            bids: levels(&book.bids)?,
+            asks: levels(&book.asks)?,
+        })
+    }
+}
+
+/// Parse the price & quantity of the provided [`KrakenBookLevel`], keying it by it's parsed price.
+fn parse(level: KrakenBookLevel) -> Result<(ParsedLevel<KrakenBookLevel>, f64), SocketError> {
+    let price = parse_level_value(ExchangeId::Kraken, &level.price)?;
+    let quantity = parse_level_value(ExchangeId::Kraken, &level.quantity)?;
+    Ok((ParsedLevel { price, level }, quantity))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchange::kraken::model::KrakenBookAction;
+    use barter_integration::model::SubscriptionId;
+
+    fn level(price: &str, quantity: &str) -> KrakenBookLevel {
+        KrakenBookLevel {
+            price: price.to_owned(),
+            quantity: quantity.to_owned(),
+            time: Utc::now(),
+        }
+    }
+
+    fn book(bids: Vec<KrakenBookLevel>, asks: Vec<KrakenBookLevel>) -> KrakenBook {
+        KrakenBook {
+            subscription_id: SubscriptionId::from("book-100|XBT/USD"),
+            action: KrakenBookAction::Update,
+            bids,
+            asks,
+            checksum: None,
+        }
+    }
+
+    #[test]
+    fn test_kraken_order_book_checksum() {
+        struct TestCase {
+            depth: usize,
+            input: Vec<KrakenBook>,
+            expected_bids: usize,
+            expected: u32,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: snapshot w/ leading zero quantities
+                depth: 100,
+                input: vec![book(
+                    vec![
+                        level("5541.20000", "1.52900000"),
+                        level("5539.90000", "0.30000000"),
+                    ],
+                    vec![
+                        level("5541.30000", "2.50700000"),
+                        level("5541.80000", "0.33000000"),
+                    ],
+                )],
+                expected_bids: 2,
+                expected: 600453227,
+            },
+            TestCase {
+                // TC1: update removing the best ask & inserting a bid level
+                depth: 100,
+                input: vec![
+                    book(
+                        vec![
+                            level("5541.20000", "1.52900000"),
+                            level("5539.90000", "0.30000000"),
+                        ],
+                        vec![
+                            level("5541.30000", "2.50700000"),
+                            level("5541.80000", "0.33000000"),
+                        ],
+                    ),
+                    book(
+                        vec![level("5540.00000", "0.05005000")],
+                        vec![level("5541.30000", "0.00000000")],
+                    ),
+                ],
+                expected_bids: 3,
+                expected: 250490782,
+            },
+            TestCase {
+                // TC2: update inserting a bid level beyond the subscribed depth is discarded
+                depth: 2,
+                input: vec![
+                    book(
+                        vec![
+                            level("5541.20000", "1.52900000"),
+                            level("5539.90000", "0.30000000"),
+                        ],
+                        vec![
+                            level("5541.30000", "2.50700000"),
+                            level("5541.80000", "0.33000000"),
+                        ],
+                    ),
+                    book(vec![level("5539.00000", "1.00000000")], vec![]),
+                ],
+                expected_bids: 2,
+                expected: 600453227,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let mut input = test.input.into_iter();
+            let mut book = KrakenOrderBook::snapshot(test.depth, input.next().unwrap()).unwrap();
+            for update in input {
+                book.apply(update).unwrap();
+            }
+            assert_eq!(book.bids.len(), test.expected_bids, "TC{} failed", index);
+            assert_eq!(book.checksum(), test.expected, "TC{} failed", index);
+        }
+    }
+}
@@ -0,0 +1,382 @@
+use crate::{
+    model::subscription::SubKind, ExchangeId, ExchangeTransformer, MarketEvent, Subscriber,
+    Subscription, SubscriptionIds, SubscriptionMeta,
+};
+use barter_integration::{
+    error::SocketError,
+    model::{Side, SubscriptionId},
+    protocol::websocket::WsMessage,
+    Transformer, Validator,
+};
+use model::{KrakenL3Message, KrakenL3SubResponse};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::collections::HashMap;
+use tokio::sync::mpsc;
+use tracing::debug;
+
+/// [`KrakenL3`] specific data structures.
+pub mod model;
+
+/// [`KrakenL3`] [`Subscriber`] & [`ExchangeTransformer`] implementor for the collection of
+/// `Spot` L3 order book data via the Kraken v2 `level3` channel.
+///
+/// The `level3` channel requires an authenticated WebSocket token, which is read from the
+/// [`KrakenL3::ENV_TOKEN`] environment variable when subscribing.
+///
+/// See docs: <https://docs.kraken.com/api/docs/websocket-v2/level3>
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct KrakenL3 {
+    pub ids: SubscriptionIds,
+}
+
+impl Subscriber for KrakenL3 {
+    type SubResponse = KrakenL3SubResponse;
+
+    fn base_url() -> &'static str {
+        ExchangeId::KrakenL3.ws_base_url()
+    }
+
+    fn build_subscription_meta(
+        subscriptions: &[Subscription],
+    ) -> Result<SubscriptionMeta, SocketError> {
+        let token = std::env::var(Self::ENV_TOKEN).map_err(|_| {
+            SocketError::Subscribe(format!(
+                "{} requires a WebSocket token set via the {} environment variable",
+                Self::EXCHANGE,
+                Self::ENV_TOKEN
+            ))
+        })?;
+
+        Self::build_subscription_meta_with_token(subscriptions, &token)
+    }
+}
+
+impl ExchangeTransformer for KrakenL3 {
+    const EXCHANGE: ExchangeId = ExchangeId::KrakenL3;
+
+    fn new(_: mpsc::UnboundedSender<WsMessage>, ids: SubscriptionIds) -> Self {
+        Self { ids }
+    }
+}
+
+impl Transformer<MarketEvent> for KrakenL3 {
+    type Input = KrakenL3Message;
+    type OutputIter = Vec<Result<MarketEvent, SocketError>>;
+
+    fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+        match input {
+            KrakenL3Message::Level3(book) => book
+                .data
+                .into_iter()
+                .flat_map(|data| {
+                    // Determine Instrument associated with this symbol's orders
+                    let subscription_id = Self::subscription_id(&data.symbol);
+                    let instrument = match self.ids.find_instrument(&subscription_id) {
+                        Ok(instrument) => instrument,
+                        Err(error) => return vec![Err(error)],
+                    };
+
+                    // Map each bid & ask order to a MarketEvent
+                    data.bids
+                        .into_iter()
+                        .map(|order| (Side::Buy, order))
+                        .chain(data.asks.into_iter().map(|order| (Side::Sell, order)))
+                        .map(|(side, order)| {
+                            Ok(MarketEvent::from((
+                                Self::EXCHANGE,
+                                instrument.clone(),
+                                side,
+                                order,
+                            )))
+                        })
+                        .collect()
+                })
+                .collect(),
+            KrakenL3Message::Heartbeat | KrakenL3Message::Status => {
+                debug!(exchange_id = %Self::EXCHANGE, "received heartbeat or status update");
+                vec![]
+            }
+        }
+    }
+}
+
+impl KrakenL3 {
+    /// [`KrakenL3`] level3 channel, providing L3 order additions, modifications & deletions.
+    ///
+    /// See docs: <https://docs.kraken.com/api/docs/websocket-v2/level3>
+    pub const CHANNEL_LEVEL3: &'static str = "level3";
+
+    /// Environment variable containing the authenticated WebSocket token required to subscribe
+    /// to the [`KrakenL3`] level3 channel.
+    ///
+    /// See docs: <https://docs.kraken.com/api/docs/rest-api/get-websockets-token>
+    pub const ENV_TOKEN: &'static str = "KRAKEN_WS_TOKEN";
+
+    /// Build the [`SubscriptionMeta`] for the provided [`Subscription`]s, authenticating each
+    /// subscription message with the provided WebSocket token.
+    pub fn build_subscription_meta_with_token(
+        subscriptions: &[Subscription],
+        token: &str,
+    ) -> Result<SubscriptionMeta, SocketError> {
+        // Allocate SubscriptionIds HashMap to track identifiers for each actioned Subscription
+        let mut ids = SubscriptionIds(HashMap::with_capacity(subscriptions.len()));
+
+        // Map Barter Subscriptions to KrakenL3 subscription messages
+        let subscriptions = subscriptions
+            .iter()
+            .map(|subscription| {
+                // Determine the KrakenL3 specific symbol for this Barter Subscription
+                let symbol = Self::build_symbol(subscription)?;
+
+                // Use "level3|symbol" as the SubscriptionId key in the SubscriptionIds
+                // eg/ SubscriptionId("level3|BTC/USD")
+                ids.insert(Self::subscription_id(&symbol), subscription.clone());
+
+                Ok(Self::subscription(&symbol, token))
+            })
+            .collect::<Result<Vec<_>, SocketError>>()?;
+
+        Ok(SubscriptionMeta {
+            ids,
+            expected_responses: subscriptions.len(),
+            subscriptions,
+        })
+    }
+
+    /// Determine the [`KrakenL3`] symbol associated with an input Barter [`Subscription`].
+    ///
+    /// Example Ok return: Ok("BTC/USD")
+    pub fn build_symbol(sub: &Subscription) -> Result<String, SocketError> {
+        // Validate provided Subscription InstrumentKind is supported by KrakenL3
+        let sub = sub.validate()?;
+
+        match &sub.kind {
+            SubKind::OrderBookL3Delta => {
+                Ok(format!("{}/{}", sub.instrument.base, sub.instrument.quote).to_uppercase())
+            }
+            other => Err(SocketError::Unsupported {
+                entity: Self::EXCHANGE.as_str(),
+                item: other.to_string(),
+            }),
+        }
+    }
+
+    /// Build a [`KrakenL3`] compatible subscription message using the symbol & token provided.
+    pub fn subscription(symbol: &str, token: &str) -> WsMessage {
+        WsMessage::Text(
+            json!({
+                "method": "subscribe",
+                "params": {
+                    "channel": Self::CHANNEL_LEVEL3,
+                    "symbol": [symbol],
+                    "snapshot": true,
+                    "token": token,
+                },
+            })
+            .to_string(),
+        )
+    }
+
+    /// Build a [`KrakenL3`] compatible [`SubscriptionId`] using the symbol provided. This is used
+    /// to associate [`KrakenL3`] data structures received over the WebSocket with it's original
+    /// Barter [`Subscription`].
+    ///
+    /// eg/ SubscriptionId("level3|BTC/USD")
+    pub fn subscription_id(symbol: &str) -> SubscriptionId {
+        SubscriptionId::from(format!("{}|{symbol}", Self::CHANNEL_LEVEL3))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchange::kraken::l3::model::{
+        KrakenL3Book, KrakenL3BookData, KrakenL3BookKind, KrakenL3Order, KrakenL3OrderEvent,
+    };
+    use crate::model::{DataKind, OrderBookL3Delta, OrderBookL3DeltaKind};
+    use barter_integration::model::{Exchange, Instrument, InstrumentKind};
+    use chrono::Utc;
+
+    #[test]
+    fn test_build_symbol() {
+        struct TestCase {
+            input: Subscription,
+            expected: Result<String, SocketError>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: Supported InstrumentKind::Spot L3 OrderBook delta subscription
+                input: Subscription::new(
+                    ExchangeId::KrakenL3,
+                    ("btc", "usd", InstrumentKind::Spot),
+                    SubKind::OrderBookL3Delta,
+                ),
+                expected: Ok("BTC/USD".to_owned()),
+            },
+            TestCase {
+                // TC1: Unsupported InstrumentKind::Spot trades subscription
+                input: Subscription::new(
+                    ExchangeId::KrakenL3,
+                    ("btc", "usd", InstrumentKind::Spot),
+                    SubKind::Trade,
+                ),
+                expected: Err(SocketError::Unsupported {
+                    entity: "",
+                    item: "".to_string(),
+                }),
+            },
+            TestCase {
+                // TC2: Unsupported InstrumentKind::FuturePerpetual L3 subscription
+                input: Subscription::new(
+                    ExchangeId::KrakenL3,
+                    ("btc", "usd", InstrumentKind::FuturePerpetual),
+                    SubKind::OrderBookL3Delta,
+                ),
+                expected: Err(SocketError::Unsupported {
+                    entity: "",
+                    item: "".to_string(),
+                }),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = KrakenL3::build_symbol(&test.input);
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => {
+                    assert_eq!(actual, expected, "TC{} failed", index)
+                }
+                (Err(_), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_kraken_l3_transform() {
+        let subscriptions = vec![Subscription::new(
+            ExchangeId::KrakenL3,
+            ("btc", "usd", InstrumentKind::Spot),
+            SubKind::OrderBookL3Delta,
+        )];
+        let mut transformer = KrakenL3 {
+            ids: KrakenL3::build_subscription_meta_with_token(&subscriptions, "token")
+                .unwrap()
+                .ids,
+        };
+
+        let time = Utc::now();
+        let order = |event, order_id: &str| KrakenL3Order {
+            event,
+            order_id: order_id.to_owned(),
+            price: 100.0,
+            quantity: 1.0,
+            time,
+        };
+        let expected = |kind, side, id: &str| {
+            Ok(MarketEvent {
+                exchange_time: time,
+                received_time: time,
+                received_instant: None,
+                raw: None,
+                exchange: Exchange::from(ExchangeId::KrakenL3),
+                instrument: Instrument::from(("btc", "usd", InstrumentKind::Spot)),
+                kind: DataKind::OrderBookL3Delta(OrderBookL3Delta {
+                    id: id.to_owned(),
+                    kind,
+                    side,
+                    price: 100.0,
+                    quantity: 1.0,
+                }),
+            })
+        };
+
+        struct TestCase {
+            input: KrakenL3Message,
+            expected: Vec<Result<MarketEvent, SocketError>>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: KrakenL3Message snapshot w/ known symbol maps orders to Open deltas
+                input: KrakenL3Message::Level3(KrakenL3Book {
+                    kind: KrakenL3BookKind::Snapshot,
+                    data: vec![KrakenL3BookData {
+                        symbol: "BTC/USD".to_owned(),
+                        bids: vec![order(None, "1")],
+                        asks: vec![order(None, "2")],
+                    }],
+                }),
+                expected: vec![
+                    expected(OrderBookL3DeltaKind::Open, Side::Buy, "1"),
+                    expected(OrderBookL3DeltaKind::Open, Side::Sell, "2"),
+                ],
+            },
+            TestCase {
+                // TC1: KrakenL3Message update w/ known symbol maps modify & delete events
+                input: KrakenL3Message::Level3(KrakenL3Book {
+                    kind: KrakenL3BookKind::Update,
+                    data: vec![KrakenL3BookData {
+                        symbol: "BTC/USD".to_owned(),
+                        bids: vec![order(Some(KrakenL3OrderEvent::Modify), "1")],
+                        asks: vec![order(Some(KrakenL3OrderEvent::Delete), "2")],
+                    }],
+                }),
+                expected: vec![
+                    expected(OrderBookL3DeltaKind::Change, Side::Buy, "1"),
+                    expected(OrderBookL3DeltaKind::Delete, Side::Sell, "2"),
+                ],
+            },
+            TestCase {
+                // TC2: KrakenL3Message update w/ unknown symbol
+                input: KrakenL3Message::Level3(KrakenL3Book {
+                    kind: KrakenL3BookKind::Update,
+                    data: vec![KrakenL3BookData {
+                        symbol: "ETH/USD".to_owned(),
+                        bids: vec![order(Some(KrakenL3OrderEvent::Add), "1")],
+                        asks: vec![],
+                    }],
+                }),
+                expected: vec![Err(SocketError::Unidentifiable(SubscriptionId::from(
+                    "level3|ETH/USD",
+                )))],
+            },
+            TestCase {
+                // TC3: KrakenL3Message heartbeat
+                input: KrakenL3Message::Heartbeat,
+                expected: vec![],
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = transformer.transform(test.input);
+            assert_eq!(actual.len(), test.expected.len(), "TC{} failed", index);
+
+            for (actual, expected) in actual.into_iter().zip(test.expected) {
+                match (actual, expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        // Scrub Utc::now() timestamps to allow comparison
+                        let actual = MarketEvent {
+                            received_time: time,
+                            ..actual
+                        };
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,236 @@
+use crate::{
+    exchange::de_str,
+    model::{DataKind, OrderBookL3Delta, OrderBookL3DeltaKind},
+    ExchangeId, MarketEvent,
+};
+use barter_integration::{
+    error::SocketError,
+    model::{Exchange, Instrument, Side},
+    Validator,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// [`KrakenL3`](super::KrakenL3) message received in response to WebSocket subscription
+/// requests.
+///
+/// eg/ {"method":"subscribe","result":{"channel":"level3","snapshot":true,"symbol":"BTC/USD"},
+///      "success":true,"time_in":"2023-10-06T17:35:55.219022Z","time_out":"2023-10-06T17:35:55.236555Z"}
+/// eg/ {"error":"Currency pair not supported ETH/XXX","method":"subscribe","success":false,
+///      "symbol":"ETH/XXX","time_in":"2023-10-06T17:35:55.219022Z","time_out":"2023-10-06T17:35:55.236555Z"}
+///
+/// See docs: <https://docs.kraken.com/api/docs/websocket-v2/level3>
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct KrakenL3SubResponse {
+    pub success: bool,
+    #[serde(default)]
+    pub error: Option<String>,
+}
+
+impl Validator for KrakenL3SubResponse {
+    fn validate(self) -> Result<Self, SocketError>
+    where
+        Self: Sized,
+    {
+        if self.success {
+            Ok(self)
+        } else {
+            Err(SocketError::Subscribe(format!(
+                "received failure subscription response: {}",
+                self.error.unwrap_or_default()
+            )))
+        }
+    }
+}
+
+/// [`KrakenL3`](super::KrakenL3) message variants that can be received over
+/// [`WebSocket`](crate::WebSocket).
+///
+/// See docs: <https://docs.kraken.com/api/docs/websocket-v2/level3>
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(tag = "channel", rename_all = "snake_case")]
+pub enum KrakenL3Message {
+    Level3(KrakenL3Book),
+    Heartbeat,
+    Status,
+}
+
+/// [`KrakenL3`](super::KrakenL3) level3 channel message containing a [`KrakenL3BookData`] for
+/// each symbol updated.
+///
+/// eg/ {"channel":"level3","type":"update","data":[{"checksum":2582465429,"symbol":"BTC/USD",
+///      "bids":[],"asks":[{"event":"add","order_id":"OJLZPI-2ALPM-3UHKBS","limit_price":27800.0,
+///      "order_qty":0.3,"timestamp":"2023-10-06T17:35:55.219022Z"}]}]}
+///
+/// See docs: <https://docs.kraken.com/api/docs/websocket-v2/level3>
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct KrakenL3Book {
+    #[serde(rename = "type")]
+    pub kind: KrakenL3BookKind,
+    pub data: Vec<KrakenL3BookData>,
+}
+
+/// Type of [`KrakenL3Book`] message.
+#[derive(Clone, Copy, Eq, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum KrakenL3BookKind {
+    Snapshot,
+    Update,
+}
+
+/// [`KrakenL3`](super::KrakenL3) individual orders added, modified or deleted for a symbol.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct KrakenL3BookData {
+    pub symbol: String,
+    pub bids: Vec<KrakenL3Order>,
+    pub asks: Vec<KrakenL3Order>,
+}
+
+/// [`KrakenL3`](super::KrakenL3) individual order resting in the book. Orders received in a
+/// [`KrakenL3BookKind::Snapshot`] do not contain an event.
+///
+/// eg/ {"event":"add","order_id":"OJLZPI-2ALPM-3UHKBS","limit_price":27800.0,"order_qty":0.3,
+///      "timestamp":"2023-10-06T17:35:55.219022Z"}
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct KrakenL3Order {
+    #[serde(default)]
+    pub event: Option<KrakenL3OrderEvent>,
+    pub order_id: String,
+    #[serde(rename = "limit_price", deserialize_with = "de_str")]
+    pub price: f64,
+    #[serde(rename = "order_qty", deserialize_with = "de_str")]
+    pub quantity: f64,
+    #[serde(rename = "timestamp")]
+    pub time: DateTime<Utc>,
+}
+
+/// Type of change to an individual [`KrakenL3Order`].
+#[derive(Clone, Copy, Eq, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum KrakenL3OrderEvent {
+    Add,
+    Modify,
+    Delete,
+}
+
+impl From<KrakenL3OrderEvent> for OrderBookL3DeltaKind {
+    fn from(event: KrakenL3OrderEvent) -> Self {
+        match event {
+            KrakenL3OrderEvent::Add => OrderBookL3DeltaKind::Open,
+            KrakenL3OrderEvent::Modify => OrderBookL3DeltaKind::Change,
+            KrakenL3OrderEvent::Delete => OrderBookL3DeltaKind::Delete,
+        }
+    }
+}
+
+impl From<(ExchangeId, Instrument, Side, KrakenL3Order)> for MarketEvent {
+    fn from(
+        (exchange_id, instrument, side, order): (ExchangeId, Instrument, Side, KrakenL3Order),
+    ) -> Self {
+        Self {
+            exchange_time: order.time,
+            received_time: Utc::now(),
+            received_instant: None,
+            raw: None,
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: DataKind::OrderBookL3Delta(OrderBookL3Delta {
+                id: order.order_id,
+                // Snapshot orders are resting in the book, so are equivalent to an Open
+                kind: order
+                    .event
+                    .map(OrderBookL3DeltaKind::from)
+                    .unwrap_or(OrderBookL3DeltaKind::Open),
+                side,
+                price: order.price,
+                quantity: order.quantity,
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use serde::de::Error;
+
+    #[test]
+    fn test_deserialise_kraken_l3_message() {
+        struct TestCase {
+            input: &'static str,
+            expected: Result<KrakenL3Message, SocketError>,
+        }
+
+        let time = Utc.with_ymd_and_hms(2023, 10, 6, 17, 35, 55).unwrap()
+            + chrono::Duration::microseconds(219022);
+
+        let cases = vec![
+            TestCase {
+                // TC0: input is a valid level3 snapshot
+                input: r#"{"channel":"level3","type":"snapshot","data":[{"symbol":"BTC/USD","checksum":281817320,"bids":[{"order_id":"O6ZQNQ-BXL4E-5WGINO","limit_price":27791.3,"order_qty":0.01,"timestamp":"2023-10-06T17:35:55.219022Z"}],"asks":[]}]}"#,
+                expected: Ok(KrakenL3Message::Level3(KrakenL3Book {
+                    kind: KrakenL3BookKind::Snapshot,
+                    data: vec![KrakenL3BookData {
+                        symbol: "BTC/USD".to_owned(),
+                        bids: vec![KrakenL3Order {
+                            event: None,
+                            order_id: "O6ZQNQ-BXL4E-5WGINO".to_owned(),
+                            price: 27791.3,
+                            quantity: 0.01,
+                            time,
+                        }],
+                        asks: vec![],
+                    }],
+                })),
+            },
+            TestCase {
+                // TC1: input is a valid level3 update w/ delete event
+                input: r#"{"channel":"level3","type":"update","data":[{"checksum":2582465429,"symbol":"BTC/USD","bids":[],"asks":[{"event":"delete","order_id":"OJLZPI-2ALPM-3UHKBS","limit_price":27800.0,"order_qty":0.3,"timestamp":"2023-10-06T17:35:55.219022Z"}]}]}"#,
+                expected: Ok(KrakenL3Message::Level3(KrakenL3Book {
+                    kind: KrakenL3BookKind::Update,
+                    data: vec![KrakenL3BookData {
+                        symbol: "BTC/USD".to_owned(),
+                        bids: vec![],
+                        asks: vec![KrakenL3Order {
+                            event: Some(KrakenL3OrderEvent::Delete),
+                            order_id: "OJLZPI-2ALPM-3UHKBS".to_owned(),
+                            price: 27800.0,
+                            quantity: 0.3,
+                            time,
+                        }],
+                    }],
+                })),
+            },
+            TestCase {
+                // TC2: input is a heartbeat
+                input: r#"{"channel":"heartbeat"}"#,
+                expected: Ok(KrakenL3Message::Heartbeat),
+            },
+            TestCase {
+                // TC3: input is a level3 update w/ an unknown order event
+                input: r#"{"channel":"level3","type":"update","data":[{"checksum":2582465429,"symbol":"BTC/USD","bids":[],"asks":[{"event":"gibberish","order_id":"OJLZPI-2ALPM-3UHKBS","limit_price":27800.0,"order_qty":0.3,"timestamp":"2023-10-06T17:35:55.219022Z"}]}]}"#,
+                expected: Err(SocketError::Serde {
+                    error: serde_json::Error::custom(""),
+                    payload: "".to_owned(),
+                }),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = serde_json::from_str::<KrakenL3Message>(test.input);
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => {
+                    assert_eq!(actual, expected, "TC{} failed", index)
+                }
+                (Err(_), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+}
@@ -1,12 +1,20 @@
 use crate::{
+    model::{DataKind, OrderBook},
     ExchangeId, ExchangeTransformer, MarketEvent, Subscriber, Subscription, SubscriptionIds,
     SubscriptionMeta,
 };
 use barter_integration::{
-    error::SocketError, model::SubscriptionId, protocol::websocket::WsMessage, Transformer,
+    error::SocketError,
+    model::{Exchange, SubscriptionId},
+    protocol::websocket::WsMessage,
+    Transformer,
+};
+use book::KrakenOrderBook;
+use chrono::Utc;
+use model::{
+    KrakenBook, KrakenBookAction, KrakenEvent, KrakenMessage, KrakenSubKind, KrakenSubResponse,
+    KrakenSubscription,
 };
-use model::{KrakenEvent, KrakenMessage, KrakenSubKind, KrakenSubResponse, KrakenSubscription};
-use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use tokio::sync::mpsc;
 use tracing::debug;
@@ -14,18 +22,37 @@ use tracing::debug;
 /// [`Kraken`] specific data structures.
 pub mod model;
 
+/// [`KrakenOrderBook`] L2 order book maintained from the `book` channel & validated using the
+/// [`Kraken`] CRC32 checksum.
+pub mod book;
+
+/// [`KrakenL3`](l3::KrakenL3) [`Subscriber`] & [`ExchangeTransformer`] implementor for the
+/// collection of L3 order book data via the Kraken v2 WebSocket API.
+pub mod l3;
+
 /// [`Kraken`] [`Subscriber`] & [`ExchangeTransformer`] implementor for the collection
 /// of `Spot` data.
-#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+///
+/// [`SubKind::OrderBookL1`](crate::model::subscription::SubKind) is sourced from the `spread`
+/// channel, providing the best bid & best ask without maintaining the full book.
+///
+/// [`SubKind::OrderBookL2Managed`](crate::model::subscription::SubKind) channels maintain a
+/// [`KrakenOrderBook`] from the `book` channel snapshot & updates, which is delivered in full
+/// after every update. If the checksum of the maintained [`KrakenOrderBook`] does not match the
+/// checksum sent by [`Kraken`], a checksum mismatch error is emitted & the channel is
+/// re-subscribed to in order to receive a fresh snapshot.
+#[derive(Debug)]
 pub struct Kraken {
     pub ids: SubscriptionIds,
+    pub books: HashMap<SubscriptionId, KrakenOrderBook>,
+    pub ws_sink_tx: mpsc::UnboundedSender<WsMessage>,
 }
 
 impl Subscriber for Kraken {
     type SubResponse = KrakenSubResponse;
 
     fn base_url() -> &'static str {
-        "wss://ws.kraken.com/"
+        ExchangeId::Kraken.ws_base_url()
     }
 
     fn build_subscription_meta(
@@ -63,8 +90,12 @@ impl Subscriber for Kraken {
 impl ExchangeTransformer for Kraken {
     const EXCHANGE: ExchangeId = ExchangeId::Kraken;
 
-    fn new(_: mpsc::UnboundedSender<WsMessage>, ids: SubscriptionIds) -> Self {
-        Self { ids }
+    fn new(ws_sink_tx: mpsc::UnboundedSender<WsMessage>, ids: SubscriptionIds) -> Self {
+        Self {
+            ids,
+            books: HashMap::new(),
+            ws_sink_tx,
+        }
     }
 }
 
@@ -108,10 +139,29 @@ impl Transformer<MarketEvent> for Kraken {
                     candle.candle,
                 )))]
             }
+            KrakenMessage::Spread(spread) => {
+                // Determine Instrument associated with this KrakenSpread message
+                let instrument = match self.ids.find_instrument(&spread.subscription_id) {
+                    Ok(instrument) => instrument,
+                    Err(error) => return vec![Err(error)],
+                };
+
+                // Map to MarketEvent
+                vec![Ok(MarketEvent::from((
+                    Kraken::EXCHANGE,
+                    instrument,
+                    spread.spread,
+                )))]
+            }
+            KrakenMessage::Book(book) => self.transform_order_book(book),
             KrakenMessage::KrakenEvent(KrakenEvent::Heartbeat) => {
                 debug!(exchange_id = %Kraken::EXCHANGE, "received heartbeat");
                 vec![]
             }
+            KrakenMessage::KrakenEvent(KrakenEvent::SubscriptionStatus) => {
+                debug!(exchange_id = %Kraken::EXCHANGE, "received subscription status");
+                vec![]
+            }
             KrakenMessage::KrakenEvent(KrakenEvent::Error(error)) => {
                 vec![Err(SocketError::Exchange(error.message))]
             }
@@ -130,20 +180,103 @@ impl Kraken {
 
         Ok(KrakenSubscription::new(market, kind))
     }
+
+    /// Maintain the [`KrakenOrderBook`] associated with the provided [`KrakenBook`] message,
+    /// validate it's checksum, & transform it into a [`MarketEvent`] containing the full
+    /// [`OrderBook`].
+    fn transform_order_book(&mut self, book: KrakenBook) -> Vec<Result<MarketEvent, SocketError>> {
+        // Determine Instrument associated with this KrakenBook message
+        let subscription_id = book.subscription_id.clone();
+        let instrument = match self.ids.find_instrument(&subscription_id) {
+            Ok(instrument) => instrument,
+            Err(error) => return vec![Err(error)],
+        };
+
+        // Apply snapshot or update to the maintained KrakenOrderBook
+        let checksum = book.checksum;
+        let applied = match book.action {
+            KrakenBookAction::Snapshot => {
+                KrakenOrderBook::snapshot(KrakenSubKind::BOOK_DEPTH as usize, book)
+                    .map(|book| self.books.insert(subscription_id.clone(), book))
+                    .map(|_| ())
+            }
+            KrakenBookAction::Update => match self.books.get_mut(&subscription_id) {
+                Some(order_book) => order_book.apply(book),
+                // Updates received before a snapshot cannot be applied
+                None => return vec![],
+            },
+        };
+        let order_book = match applied.map(|_| self.books.get(&subscription_id)) {
+            Ok(Some(order_book)) => order_book,
+            Ok(None) => return vec![],
+            Err(error) => return vec![Err(error)],
+        };
+
+        // Validate the maintained KrakenOrderBook checksum, else re-subscribe
+        if let Some(expected) = checksum {
+            let actual = order_book.checksum();
+            if actual != expected {
+                self.books.remove(&subscription_id);
+                self.resubscribe(&subscription_id);
+                return vec![Err(SocketError::Exchange(format!(
+                    "{subscription_id} OrderBook checksum mismatch with expected: {expected}, actual: {actual}"
+                )))];
+            }
+        }
+
+        vec![OrderBook::try_from(order_book).map(|book| MarketEvent {
+            exchange_time: book.last_update_time,
+            received_time: Utc::now(),
+            received_instant: None,
+            raw: None,
+            exchange: Exchange::from(Self::EXCHANGE),
+            instrument,
+            kind: DataKind::OrderBook(book),
+        })]
+    }
+
+    /// Re-subscribe to the channel associated with the provided [`SubscriptionId`] in order to
+    /// receive a fresh OrderBook snapshot.
+    fn resubscribe(&self, subscription_id: &SubscriptionId) {
+        let subscription = match self.ids.get(subscription_id).map(Self::subscription) {
+            Some(Ok(subscription)) => subscription,
+            _ => return,
+        };
+
+        debug!(
+            exchange_id = %Self::EXCHANGE,
+            ?subscription,
+            "re-subscribing to recover OrderBook snapshot"
+        );
+        for event in ["unsubscribe", "subscribe"] {
+            let request = KrakenSubscription {
+                event,
+                ..subscription.clone()
+            };
+            if let Ok(message) = WsMessage::try_from(&request) {
+                let _ = self.ws_sink_tx.send(message);
+            }
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::exchange::kraken::model::{
-        KrakenCandle, KrakenCandleData, KrakenError, KrakenInterval, KrakenTrade, KrakenTrades,
+    use crate::exchange::{
+        datetime_utc_from_epoch_duration,
+        kraken::model::{
+            KrakenCandle, KrakenCandleData, KrakenError, KrakenInterval, KrakenSpread,
+            KrakenSpreadData, KrakenTrade, KrakenTrades,
+        },
     };
     use crate::model::{
         subscription::{Interval, SubKind},
-        Candle, DataKind, PublicTrade,
+        Candle, DataKind, Level, OrderBookL1, PublicTrade,
     };
     use barter_integration::model::{Exchange, Instrument, InstrumentKind, Side};
     use chrono::{DateTime, Utc};
+    use std::time::Duration;
 
     fn kraken(subscriptions: Vec<Subscription>) -> Kraken {
         let ids = SubscriptionIds(
@@ -167,6 +300,9 @@ mod tests {
 
                             format!("ohlc-{}|{market}", u32::from(interval))
                         }
+                        SubKind::OrderBookL1 => {
+                            format!("spread|{market}")
+                        }
                         _ => panic!(
                             "subscription type not implemented in mod tests kraken() builder"
                         ),
@@ -177,7 +313,7 @@ mod tests {
                 .collect(),
         );
 
-        Kraken { ids }
+        Kraken::new(mpsc::unbounded_channel().0, ids)
     }
 
     fn kraken_trade_id(timestamp: DateTime<Utc>, side: Side, price: f64, volume: f64) -> String {
@@ -233,6 +369,35 @@ mod tests {
                     item: Interval::Month3.to_string(),
                 }),
             },
+            TestCase {
+                // TC3: Valid InstrumentKind::Spot OrderBookL1 Subscription via spread channel
+                input: Subscription::new(
+                    ExchangeId::Kraken,
+                    ("xbt", "usd", InstrumentKind::Spot),
+                    SubKind::OrderBookL1,
+                ),
+                expected: Ok(KrakenSubscription {
+                    event: "subscribe",
+                    market: "XBT/USD".to_string(),
+                    kind: KrakenSubKind::Spread { channel: "spread" },
+                }),
+            },
+            TestCase {
+                // TC4: Valid InstrumentKind::Spot OrderBookL2Managed Subscription via book channel
+                input: Subscription::new(
+                    ExchangeId::Kraken,
+                    ("xbt", "usd", InstrumentKind::Spot),
+                    SubKind::OrderBookL2Managed,
+                ),
+                expected: Ok(KrakenSubscription {
+                    event: "subscribe",
+                    market: "XBT/USD".to_string(),
+                    kind: KrakenSubKind::Book {
+                        channel: "book",
+                        depth: 100,
+                    },
+                }),
+            },
         ];
 
         for (index, test) in cases.into_iter().enumerate() {
@@ -269,6 +434,13 @@ mod tests {
                 InstrumentKind::Spot,
                 SubKind::Candle(Interval::Minute5),
             )),
+            Subscription::from((
+                ExchangeId::Kraken,
+                "XBT",
+                "USD",
+                InstrumentKind::Spot,
+                SubKind::OrderBookL1,
+            )),
         ]);
 
         let timestamp = Utc::now();
@@ -302,6 +474,8 @@ mod tests {
                     Ok(MarketEvent {
                         exchange_time: timestamp,
                         received_time: timestamp,
+                        received_instant: None,
+                        raw: None,
                         exchange: Exchange::from(ExchangeId::Kraken),
                         instrument: Instrument::from(("xbt", "usd", InstrumentKind::Spot)),
                         kind: DataKind::Trade(PublicTrade {
@@ -314,6 +488,8 @@ mod tests {
                     Ok(MarketEvent {
                         exchange_time: timestamp,
                         received_time: timestamp,
+                        received_instant: None,
+                        raw: None,
                         exchange: Exchange::from(ExchangeId::Kraken),
                         instrument: Instrument::from(("xbt", "usd", InstrumentKind::Spot)),
                         kind: DataKind::Trade(PublicTrade {
@@ -360,6 +536,8 @@ mod tests {
                 expected: vec![Ok(MarketEvent {
                     exchange_time: timestamp,
                     received_time: timestamp,
+                    received_instant: None,
+                    raw: None,
                     exchange: Exchange::from(ExchangeId::Kraken),
                     instrument: Instrument::from(("xbt", "usd", InstrumentKind::Spot)),
                     kind: DataKind::Candle(Candle {
@@ -409,6 +587,30 @@ mod tests {
                 })),
                 expected: vec![Err(SocketError::Exchange("error message".to_string()))],
             },
+            TestCase {
+                // TC6: KrakenMessage Spot spread w/ known SubscriptionId
+                input: KrakenMessage::Spread(KrakenSpread {
+                    subscription_id: SubscriptionId::from("spread|XBT/USD"),
+                    spread: KrakenSpreadData {
+                        best_bid: Level::new(5698.4, 1.5),
+                        best_ask: Level::new(5700.0, 0.5),
+                        time: timestamp,
+                    },
+                }),
+                expected: vec![Ok(MarketEvent {
+                    exchange_time: timestamp,
+                    received_time: timestamp,
+                    received_instant: None,
+                    raw: None,
+                    exchange: Exchange::from(ExchangeId::Kraken),
+                    instrument: Instrument::from(("xbt", "usd", InstrumentKind::Spot)),
+                    kind: DataKind::OrderBookL1(OrderBookL1 {
+                        last_update_time: timestamp,
+                        best_bid: Level::new(5698.4, 1.5),
+                        best_ask: Level::new(5700.0, 0.5),
+                    }),
+                })],
+            },
         ];
 
         for (index, test) in cases.into_iter().enumerate() {
@@ -431,6 +633,8 @@ mod tests {
                         // Scrub Utc::now() timestamps to allow comparison
                         let actual = MarketEvent {
                             received_time: timestamp,
+                            received_instant: None,
+                            raw: None,
                             ..actual
                         };
                         assert_eq!(
@@ -450,4 +654,102 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_kraken_order_book_transform() {
+        let (ws_sink_tx, mut ws_sink_rx) = mpsc::unbounded_channel();
+        let mut transformer = Kraken::new(
+            ws_sink_tx,
+            Kraken::build_subscription_meta(&[Subscription::new(
+                ExchangeId::Kraken,
+                ("xbt", "usd", InstrumentKind::Spot),
+                SubKind::OrderBookL2Managed,
+            )])
+            .unwrap()
+            .ids,
+        );
+
+        let time = |secs: f64| datetime_utc_from_epoch_duration(Duration::from_secs_f64(secs));
+
+        struct TestCase {
+            input: &'static str,
+            expected: Vec<Result<DataKind, SocketError>>,
+            expected_resubscribe: bool,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: update received before a snapshot is ignored
+                input: r#"[0,{"b":[["5540.00000","0.05005000","1534614249.0"]],"c":"1"},"book-100","XBT/USD"]"#,
+                expected: vec![],
+                expected_resubscribe: false,
+            },
+            TestCase {
+                // TC1: snapshot delivers the full OrderBook
+                input: r#"[0,{"as":[["5541.30000","2.50700000","1534614248.0"],["5541.80000","0.33000000","1534614248.0"]],"bs":[["5541.20000","1.52900000","1534614248.0"],["5539.90000","0.30000000","1534614248.0"]]},"book-100","XBT/USD"]"#,
+                expected: vec![Ok(DataKind::OrderBook(OrderBook {
+                    last_update_time: time(1534614248.0),
+                    last_update_id: 0,
+                    bids: vec![Level::new(5541.2, 1.529), Level::new(5539.9, 0.3)],
+                    asks: vec![Level::new(5541.3, 2.507), Level::new(5541.8, 0.33)],
+                }))],
+                expected_resubscribe: false,
+            },
+            TestCase {
+                // TC2: update w/ valid checksum delivers the updated OrderBook
+                input: r#"[0,{"a":[["5541.30000","0.00000000","1534614249.0"]]},{"b":[["5540.00000","0.05005000","1534614249.0"]],"c":"250490782"},"book-100","XBT/USD"]"#,
+                expected: vec![Ok(DataKind::OrderBook(OrderBook {
+                    last_update_time: time(1534614249.0),
+                    last_update_id: 0,
+                    bids: vec![
+                        Level::new(5541.2, 1.529),
+                        Level::new(5540.0, 0.05005),
+                        Level::new(5539.9, 0.3),
+                    ],
+                    asks: vec![Level::new(5541.8, 0.33)],
+                }))],
+                expected_resubscribe: false,
+            },
+            TestCase {
+                // TC3: update w/ invalid checksum is an error & re-subscribes
+                input: r#"[0,{"a":[["5541.80000","0.00000000","1534614250.0"]],"c":"1"},"book-100","XBT/USD"]"#,
+                expected: vec![Err(SocketError::Exchange("checksum".to_owned()))],
+                expected_resubscribe: true,
+            },
+            TestCase {
+                // TC4: update received after a checksum mismatch is ignored until a snapshot
+                input: r#"[0,{"b":[["5539.90000","0.00000000","1534614251.0"]],"c":"1"},"book-100","XBT/USD"]"#,
+                expected: vec![],
+                expected_resubscribe: false,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let input = serde_json::from_str::<KrakenMessage>(test.input).unwrap();
+            let actual = transformer.transform(input);
+            assert_eq!(actual.len(), test.expected.len(), "TC{} failed", index);
+
+            for (actual, expected) in actual.into_iter().zip(test.expected) {
+                match (actual, expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual.kind, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+
+            let resubscribed = std::iter::from_fn(|| ws_sink_rx.try_recv().ok()).count() == 2;
+            assert_eq!(
+                resubscribed, test.expected_resubscribe,
+                "TC{} failed",
+                index
+            );
+        }
+    }
 }
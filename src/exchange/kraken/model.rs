@@ -1,9 +1,11 @@
 use super::Kraken;
 use crate::{
-    exchange::{datetime_utc_from_epoch_duration, extract_next, se_element_to_vector},
+    exchange::{
+        datetime_utc_from_epoch_duration, de_str_opt, extract_next, se_element_to_vector, Numeric,
+    },
     model::{
         subscription::{Interval, SubKind},
-        Candle, DataKind, PublicTrade,
+        Candle, DataKind, Level, OrderBookL1, PublicTrade,
     },
     ExchangeId, ExchangeTransformer, MarketEvent,
 };
@@ -41,7 +43,7 @@ pub struct KrakenSubscription {
 impl From<&KrakenSubscription> for SubscriptionId {
     fn from(kraken_subscription: &KrakenSubscription) -> Self {
         match kraken_subscription.kind {
-            KrakenSubKind::Trade { channel } => {
+            KrakenSubKind::Trade { channel } | KrakenSubKind::Spread { channel } => {
                 // eg/ SubscriptionId::from("trade|XBT/USD")
                 SubscriptionId::from(format!("{channel}|{}", kraken_subscription.market))
             }
@@ -52,6 +54,10 @@ impl From<&KrakenSubscription> for SubscriptionId {
                     kraken_subscription.market
                 ))
             }
+            KrakenSubKind::Book { channel, depth } => {
+                // eg/ SubscriptionId::from("book-100|XBT/USD"),
+                SubscriptionId::from(format!("{channel}-{depth}|{}", kraken_subscription.market))
+            }
         }
     }
 }
@@ -102,11 +108,25 @@ pub enum KrakenSubKind {
         channel: &'static str,
         interval: u32,
     },
+    Spread {
+        #[serde(rename = "name")]
+        channel: &'static str,
+    },
+    Book {
+        #[serde(rename = "name")]
+        channel: &'static str,
+        depth: u32,
+    },
 }
 
 impl KrakenSubKind {
     const TRADE: &'static str = "trade";
     const CANDLE: &'static str = "ohlc";
+    const SPREAD: &'static str = "spread";
+    const BOOK: &'static str = "book";
+
+    /// Number of bid & ask levels maintained for [`KrakenSubKind::Book`] subscriptions.
+    pub const BOOK_DEPTH: u32 = 100;
 }
 
 impl TryFrom<&SubKind> for KrakenSubKind {
@@ -121,6 +141,13 @@ impl TryFrom<&SubKind> for KrakenSubKind {
                 channel: KrakenSubKind::CANDLE,
                 interval: u32::from(KrakenInterval::try_from(interval)?),
             }),
+            SubKind::OrderBookL1 => Ok(KrakenSubKind::Spread {
+                channel: KrakenSubKind::SPREAD,
+            }),
+            SubKind::OrderBookL2Managed => Ok(KrakenSubKind::Book {
+                channel: KrakenSubKind::BOOK,
+                depth: KrakenSubKind::BOOK_DEPTH,
+            }),
             other => Err(SocketError::Unsupported {
                 entity: Kraken::EXCHANGE.as_str(),
                 item: other.to_string(),
@@ -250,6 +277,9 @@ impl Validator for KrakenSubResponse {
 pub enum KrakenMessage {
     Trades(KrakenTrades),
     Candle(KrakenCandle),
+    // Must be attempted after Candle since KrakenSpreadData is a prefix of KrakenCandleData
+    Spread(KrakenSpread),
+    Book(KrakenBook),
     KrakenEvent(KrakenEvent),
 }
 
@@ -298,6 +328,88 @@ pub struct KrakenCandleData {
     pub trade_count: u64,
 }
 
+/// `Kraken` best bid & best ask [`KrakenSpreadData`] with an associated [`SubscriptionId`]
+/// (eg/ "spread|XBT/USD").
+///
+/// See docs: <https://docs.kraken.com/websockets/#message-spread>
+#[derive(Clone, PartialEq, PartialOrd, Debug, Serialize)]
+pub struct KrakenSpread {
+    pub subscription_id: SubscriptionId,
+    pub spread: KrakenSpreadData,
+}
+
+/// `Kraken` best bid & best ask data.
+///
+/// See docs: <https://docs.kraken.com/websockets/#message-spread>
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Serialize)]
+pub struct KrakenSpreadData {
+    pub best_bid: Level,
+    pub best_ask: Level,
+    pub time: DateTime<Utc>,
+}
+
+/// `Kraken` order book snapshot or update of [`KrakenBookLevel`]s with an associated
+/// [`SubscriptionId`] (eg/ "book-100|XBT/USD").
+///
+/// Updates include the CRC32 checksum of the top 10 bid & ask levels of the order book after the
+/// update is applied.
+///
+/// See docs: <https://docs.kraken.com/websockets/#message-book>
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Serialize)]
+pub struct KrakenBook {
+    pub subscription_id: SubscriptionId,
+    pub action: KrakenBookAction,
+    pub bids: Vec<KrakenBookLevel>,
+    pub asks: Vec<KrakenBookLevel>,
+    pub checksum: Option<u32>,
+}
+
+/// Type of [`KrakenBook`] message, either a full snapshot of the order book, or an update of the
+/// changed levels.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub enum KrakenBookAction {
+    Snapshot,
+    Update,
+}
+
+/// `Kraken` order book level.
+///
+/// The original `String` price & quantity are retained since they are required to calculate the
+/// order book checksum. A zero quantity level removes the price level from the order book.
+///
+/// See docs: <https://docs.kraken.com/websockets/#book-checksum>
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Serialize)]
+pub struct KrakenBookLevel {
+    pub price: String,
+    pub quantity: String,
+    pub time: DateTime<Utc>,
+}
+
+/// `Kraken` order book snapshot (ie/ "as" & "bs") or update (ie/ "a", "b" & "c") object contained
+/// in a [`KrakenBook`] sequence.
+#[derive(Deserialize)]
+struct KrakenBookData {
+    #[serde(rename = "as")]
+    snapshot_asks: Option<Vec<KrakenBookLevel>>,
+    #[serde(rename = "bs")]
+    snapshot_bids: Option<Vec<KrakenBookLevel>>,
+    #[serde(default, rename = "a")]
+    asks: Vec<KrakenBookLevel>,
+    #[serde(default, rename = "b")]
+    bids: Vec<KrakenBookLevel>,
+    #[serde(default, rename = "c", deserialize_with = "de_str_opt")]
+    checksum: Option<u32>,
+}
+
+/// Element following the first [`KrakenBookData`] of a [`KrakenBook`] sequence, which is either
+/// a second [`KrakenBookData`] (when both asks & bids are updated), or the channelName.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum KrakenBookElement {
+    Data(KrakenBookData),
+    ChannelName(String),
+}
+
 /// `Kraken` messages received over the WebSocket which are not subscription data.
 ///
 /// eg/ `Kraken` sends a `KrakenEvent::Heartbeat` if no subscription traffic has been sent
@@ -308,6 +420,7 @@ pub struct KrakenCandleData {
 #[serde(tag = "event", rename_all = "camelCase")]
 pub enum KrakenEvent {
     Heartbeat,
+    SubscriptionStatus,
     Error(KrakenError),
 }
 
@@ -322,6 +435,8 @@ impl From<(ExchangeId, Instrument, KrakenTrade)> for MarketEvent {
         Self {
             exchange_time: trade.time,
             received_time: Utc::now(),
+            received_instant: None,
+            raw: None,
             exchange: Exchange::from(exchange_id),
             instrument,
             kind: DataKind::Trade(PublicTrade {
@@ -339,6 +454,8 @@ impl From<(ExchangeId, Instrument, KrakenCandleData)> for MarketEvent {
         Self {
             exchange_time: candle.end_time,
             received_time: Utc::now(),
+            received_instant: None,
+            raw: None,
             exchange: Exchange::from(exchange_id),
             instrument,
             kind: DataKind::Candle(Candle {
@@ -355,6 +472,24 @@ impl From<(ExchangeId, Instrument, KrakenCandleData)> for MarketEvent {
     }
 }
 
+impl From<(ExchangeId, Instrument, KrakenSpreadData)> for MarketEvent {
+    fn from((exchange_id, instrument, spread): (ExchangeId, Instrument, KrakenSpreadData)) -> Self {
+        Self {
+            exchange_time: spread.time,
+            received_time: Utc::now(),
+            received_instant: None,
+            raw: None,
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: DataKind::OrderBookL1(OrderBookL1 {
+                last_update_time: spread.time,
+                best_bid: spread.best_bid,
+                best_ask: spread.best_ask,
+            }),
+        }
+    }
+}
+
 impl<'de> Deserialize<'de> for KrakenTrades {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -434,21 +569,16 @@ impl<'de> Deserialize<'de> for KrakenTrade {
                 // [price, volume, time, side, orderType, misc]
                 // <https://docs.kraken.com/websockets/#message-trade>
 
-                // Extract String price & parse to f64
-                let price = extract_next::<SeqAccessor, String>(&mut seq, "price")?
-                    .parse()
-                    .map_err(de::Error::custom)?;
+                // Extract numeric price
+                let price = extract_next::<SeqAccessor, Numeric<f64>>(&mut seq, "price")?.0;
 
-                // Extract String quantity & parse to f64
-                let quantity = extract_next::<SeqAccessor, String>(&mut seq, "quantity")?
-                    .parse()
-                    .map_err(de::Error::custom)?;
+                // Extract numeric quantity
+                let quantity = extract_next::<SeqAccessor, Numeric<f64>>(&mut seq, "quantity")?.0;
 
-                // Extract String price, parse to f64, map to DateTime<Utc>
-                let time = extract_next::<SeqAccessor, String>(&mut seq, "time")?
-                    .parse()
-                    .map(|time| datetime_utc_from_epoch_duration(Duration::from_secs_f64(time)))
-                    .map_err(de::Error::custom)?;
+                // Extract numeric time & map to DateTime<Utc>
+                let time = extract_next::<SeqAccessor, Numeric<f64>>(&mut seq, "time").map(
+                    |Numeric(time)| datetime_utc_from_epoch_duration(Duration::from_secs_f64(time)),
+                )?;
 
                 // Extract Side
                 let side: Side = extract_next(&mut seq, "side")?;
@@ -550,48 +680,38 @@ impl<'de> Deserialize<'de> for KrakenCandleData {
                 // [time, end_time, open, high, low, close, vwap, volume, count]
                 // <https://docs.kraken.com/websockets/#message-ohlc>
 
-                // Extract String candle start time, parse to f64, map to DateTime<Utc>
-                let start_time = extract_next::<SeqAccessor, String>(&mut seq, "start_time")?
-                    .parse()
-                    .map(|time| datetime_utc_from_epoch_duration(Duration::from_secs_f64(time)))
-                    .map_err(de::Error::custom)?;
-
-                // Extract String candle end time, parse to f64, map to DateTime<Utc>
-                let end_time = extract_next::<SeqAccessor, String>(&mut seq, "end_time")?
-                    .parse()
-                    .map(|time| datetime_utc_from_epoch_duration(Duration::from_secs_f64(time)))
-                    .map_err(de::Error::custom)?;
-
-                // Extract String open
-                let open = extract_next::<SeqAccessor, String>(&mut seq, "open")?
-                    .parse()
-                    .map_err(de::Error::custom)?;
-
-                // Extract String high
-                let high = extract_next::<SeqAccessor, String>(&mut seq, "high")?
-                    .parse()
-                    .map_err(de::Error::custom)?;
-
-                // Extract String low
-                let low = extract_next::<SeqAccessor, String>(&mut seq, "low")?
-                    .parse()
-                    .map_err(de::Error::custom)?;
-
-                // Extract String close
-                let close = extract_next::<SeqAccessor, String>(&mut seq, "close")?
-                    .parse()
-                    .map_err(de::Error::custom)?;
+                // Extract numeric candle start time & map to DateTime<Utc>
+                let start_time = extract_next::<SeqAccessor, Numeric<f64>>(&mut seq, "start_time")
+                    .map(|Numeric(time)| {
+                        datetime_utc_from_epoch_duration(Duration::from_secs_f64(time))
+                    })?;
+
+                // Extract numeric candle end time & map to DateTime<Utc>
+                let end_time = extract_next::<SeqAccessor, Numeric<f64>>(&mut seq, "end_time")
+                    .map(|Numeric(time)| {
+                        datetime_utc_from_epoch_duration(Duration::from_secs_f64(time))
+                    })?;
+
+                // Extract numeric open
+                let open = extract_next::<SeqAccessor, Numeric<f64>>(&mut seq, "open")?.0;
+
+                // Extract numeric high
+                let high = extract_next::<SeqAccessor, Numeric<f64>>(&mut seq, "high")?.0;
+
+                // Extract numeric low
+                let low = extract_next::<SeqAccessor, Numeric<f64>>(&mut seq, "low")?.0;
+
+                // Extract numeric close
+                let close = extract_next::<SeqAccessor, Numeric<f64>>(&mut seq, "close")?.0;
 
                 // Extract String vwap & ignore
                 let _: de::IgnoredAny = extract_next(&mut seq, "vwap")?;
 
-                // Extract String volume
-                let volume = extract_next::<SeqAccessor, String>(&mut seq, "volume")?
-                    .parse()
-                    .map_err(de::Error::custom)?;
+                // Extract numeric volume
+                let volume = extract_next::<SeqAccessor, Numeric<f64>>(&mut seq, "volume")?.0;
 
-                // Extract u64 count
-                let trade_count = extract_next(&mut seq, "count")?;
+                // Extract numeric count
+                let trade_count = extract_next::<SeqAccessor, Numeric<u64>>(&mut seq, "count")?.0;
 
                 // Ignore any additional elements or SerDe will fail
                 //  '--> Exchange may add fields without warning
@@ -615,6 +735,252 @@ impl<'de> Deserialize<'de> for KrakenCandleData {
     }
 }
 
+impl<'de> Deserialize<'de> for KrakenSpread {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct SeqVisitor;
+
+        impl<'de> de::Visitor<'de> for SeqVisitor {
+            type Value = KrakenSpread;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("KrakenSpread struct from the Kraken WebSocket API")
+            }
+
+            fn visit_seq<SeqAccessor>(
+                self,
+                mut seq: SeqAccessor,
+            ) -> Result<Self::Value, SeqAccessor::Error>
+            where
+                SeqAccessor: de::SeqAccess<'de>,
+            {
+                // KrakenSpread Sequence Format:
+                // [channelID, [bid, ask, timestamp, bidVolume, askVolume], channelName, pair]
+                // <https://docs.kraken.com/websockets/#message-spread>
+
+                // Extract deprecated channelID & ignore
+                let _: de::IgnoredAny = extract_next(&mut seq, "channelID")?;
+
+                // Extract KrakenSpreadData from inner sequence
+                let spread = extract_next(&mut seq, "KrakenSpreadData")?;
+
+                // Extract channelName (eg/ "spread") & ignore
+                let _: de::IgnoredAny = extract_next(&mut seq, "channelName")?;
+
+                // Extract pair (eg/ "XBT/USD") & map to SubscriptionId (ie/ "spread|{pair}")
+                let subscription_id = extract_next::<SeqAccessor, String>(&mut seq, "pair")
+                    .map(|pair| SubscriptionId::from(format!("spread|{pair}")))?;
+
+                // Ignore any additional elements or SerDe will fail
+                //  '--> Exchange may add fields without warning
+                while seq.next_element::<de::IgnoredAny>()?.is_some() {}
+
+                Ok(KrakenSpread {
+                    subscription_id,
+                    spread,
+                })
+            }
+        }
+
+        // Use Visitor implementation to deserialise the KrakenSpread
+        deserializer.deserialize_seq(SeqVisitor)
+    }
+}
+
+impl<'de> Deserialize<'de> for KrakenSpreadData {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct SeqVisitor;
+
+        impl<'de> de::Visitor<'de> for SeqVisitor {
+            type Value = KrakenSpreadData;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("KrakenSpreadData struct from the Kraken WebSocket API")
+            }
+
+            fn visit_seq<SeqAccessor>(
+                self,
+                mut seq: SeqAccessor,
+            ) -> Result<Self::Value, SeqAccessor::Error>
+            where
+                SeqAccessor: de::SeqAccess<'de>,
+            {
+                // KrakenSpreadData Sequence Format:
+                // [bid, ask, timestamp, bidVolume, askVolume]
+                // <https://docs.kraken.com/websockets/#message-spread>
+
+                // Extract numeric best bid price
+                let bid = extract_next::<SeqAccessor, Numeric<f64>>(&mut seq, "bid")?.0;
+
+                // Extract numeric best ask price
+                let ask = extract_next::<SeqAccessor, Numeric<f64>>(&mut seq, "ask")?.0;
+
+                // Extract numeric time & map to DateTime<Utc>
+                let time = extract_next::<SeqAccessor, Numeric<f64>>(&mut seq, "timestamp").map(
+                    |Numeric(time)| datetime_utc_from_epoch_duration(Duration::from_secs_f64(time)),
+                )?;
+
+                // Extract numeric best bid quantity
+                let bid_volume =
+                    extract_next::<SeqAccessor, Numeric<f64>>(&mut seq, "bidVolume")?.0;
+
+                // Extract numeric best ask quantity
+                let ask_volume =
+                    extract_next::<SeqAccessor, Numeric<f64>>(&mut seq, "askVolume")?.0;
+
+                // Ignore any additional elements or SerDe will fail
+                //  '--> Exchange may add fields without warning
+                while seq.next_element::<de::IgnoredAny>()?.is_some() {}
+
+                Ok(KrakenSpreadData {
+                    best_bid: Level::new(bid, bid_volume),
+                    best_ask: Level::new(ask, ask_volume),
+                    time,
+                })
+            }
+        }
+
+        // Use Visitor implementation to deserialise the KrakenSpreadData
+        deserializer.deserialize_seq(SeqVisitor)
+    }
+}
+
+impl<'de> Deserialize<'de> for KrakenBook {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct SeqVisitor;
+
+        impl<'de> de::Visitor<'de> for SeqVisitor {
+            type Value = KrakenBook;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("KrakenBook struct from the Kraken WebSocket API")
+            }
+
+            fn visit_seq<SeqAccessor>(
+                self,
+                mut seq: SeqAccessor,
+            ) -> Result<Self::Value, SeqAccessor::Error>
+            where
+                SeqAccessor: de::SeqAccess<'de>,
+            {
+                // KrakenBook Sequence Formats:
+                // [channelID, {"as": [levels], "bs": [levels]}, channelName, pair]
+                // [channelID, {"a": [levels], "b": [levels], "c": checksum}, channelName, pair]
+                // [channelID, {"a": [levels]}, {"b": [levels], "c": checksum}, channelName, pair]
+                // <https://docs.kraken.com/websockets/#message-book>
+
+                // Extract deprecated channelID & ignore
+                let _: de::IgnoredAny = extract_next(&mut seq, "channelID")?;
+
+                // Extract KrakenBookData object
+                let mut data: KrakenBookData = extract_next(&mut seq, "KrakenBookData")?;
+
+                // Extract optional second KrakenBookData object, followed by channelName
+                let channel = match extract_next(&mut seq, "channelName")? {
+                    KrakenBookElement::Data(next) => {
+                        data.asks.extend(next.asks);
+                        data.bids.extend(next.bids);
+                        data.checksum = next.checksum.or(data.checksum);
+                        extract_next::<SeqAccessor, String>(&mut seq, "channelName")?
+                    }
+                    KrakenBookElement::ChannelName(channel) => channel,
+                };
+
+                // Extract pair (eg/ "XBT/USD") & map to SubscriptionId (eg/ "book-100|XBT/USD")
+                let subscription_id = extract_next::<SeqAccessor, String>(&mut seq, "pair")
+                    .map(|pair| SubscriptionId::from(format!("{channel}|{pair}")))?;
+
+                // Ignore any additional elements or SerDe will fail
+                //  '--> Exchange may add fields without warning
+                while seq.next_element::<de::IgnoredAny>()?.is_some() {}
+
+                let book = match (data.snapshot_bids, data.snapshot_asks) {
+                    (None, None) => KrakenBook {
+                        subscription_id,
+                        action: KrakenBookAction::Update,
+                        bids: data.bids,
+                        asks: data.asks,
+                        checksum: data.checksum,
+                    },
+                    (bids, asks) => KrakenBook {
+                        subscription_id,
+                        action: KrakenBookAction::Snapshot,
+                        bids: bids.unwrap_or_default(),
+                        asks: asks.unwrap_or_default(),
+                        checksum: data.checksum,
+                    },
+                };
+
+                Ok(book)
+            }
+        }
+
+        // Use Visitor implementation to deserialise the KrakenBook
+        deserializer.deserialize_seq(SeqVisitor)
+    }
+}
+
+impl<'de> Deserialize<'de> for KrakenBookLevel {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct SeqVisitor;
+
+        impl<'de> de::Visitor<'de> for SeqVisitor {
+            type Value = KrakenBookLevel;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("KrakenBookLevel struct from the Kraken WebSocket API")
+            }
+
+            fn visit_seq<SeqAccessor>(
+                self,
+                mut seq: SeqAccessor,
+            ) -> Result<Self::Value, SeqAccessor::Error>
+            where
+                SeqAccessor: de::SeqAccess<'de>,
+            {
+                // KrakenBookLevel Sequence Format:
+                // [price, volume, timestamp, updateType]
+                // <https://docs.kraken.com/websockets/#message-book>
+
+                // Extract String price
+                let price: String = extract_next(&mut seq, "price")?;
+
+                // Extract String volume
+                let quantity: String = extract_next(&mut seq, "volume")?;
+
+                // Extract numeric time & map to DateTime<Utc>
+                let time = extract_next::<SeqAccessor, Numeric<f64>>(&mut seq, "timestamp").map(
+                    |Numeric(time)| datetime_utc_from_epoch_duration(Duration::from_secs_f64(time)),
+                )?;
+
+                // Ignore optional republish updateType & any additional elements
+                //  '--> Exchange may add fields without warning
+                while seq.next_element::<de::IgnoredAny>()?.is_some() {}
+
+                Ok(KrakenBookLevel {
+                    price,
+                    quantity,
+                    time,
+                })
+            }
+        }
+
+        // Use Visitor implementation to deserialise the KrakenBookLevel
+        deserializer.deserialize_seq(SeqVisitor)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -652,13 +1018,28 @@ mod tests {
                 }),
             },
             TestCase {
-                // TC3: Kraken unsupported SubKind::OrderBookL2Delta
+                // TC3: Kraken supported SubKind::OrderBookL1 via the spread channel
+                input: SubKind::OrderBookL1,
+                expected: Ok(KrakenSubKind::Spread {
+                    channel: KrakenSubKind::SPREAD,
+                }),
+            },
+            TestCase {
+                // TC4: Kraken unsupported SubKind::OrderBookL2Delta
                 input: SubKind::OrderBookL2Delta,
                 expected: Err(SocketError::Unsupported {
                     entity: "kraken",
                     item: "order_books_l2".to_string(),
                 }),
             },
+            TestCase {
+                // TC5: Kraken supported SubKind::OrderBookL2Managed via the book channel
+                input: SubKind::OrderBookL2Managed,
+                expected: Ok(KrakenSubKind::Book {
+                    channel: KrakenSubKind::BOOK,
+                    depth: KrakenSubKind::BOOK_DEPTH,
+                }),
+            },
         ];
 
         for (index, test) in cases.into_iter().enumerate() {
@@ -871,12 +1252,26 @@ mod tests {
                 })),
             },
             TestCase {
-                // TC3: valid KrakenMessage Heartbeat
+                // TC3: valid KrakenMessage Spot spread
+                input: r#"[0,["5698.40000","5700.00000","1542057299.545897","1.01234567","0.98765432"],"spread","XBT/USD"]"#,
+                expected: Ok(KrakenMessage::Spread(KrakenSpread {
+                    subscription_id: SubscriptionId::from("spread|XBT/USD"),
+                    spread: KrakenSpreadData {
+                        best_bid: Level::new(5698.4, 1.01234567),
+                        best_ask: Level::new(5700.0, 0.98765432),
+                        time: datetime_utc_from_epoch_duration(Duration::from_secs_f64(
+                            1542057299.545897,
+                        )),
+                    },
+                })),
+            },
+            TestCase {
+                // TC4: valid KrakenMessage Heartbeat
                 input: r#"{"event": "heartbeat"}"#,
                 expected: Ok(KrakenMessage::KrakenEvent(KrakenEvent::Heartbeat)),
             },
             TestCase {
-                // TC4: valid KrakenMessage Error
+                // TC5: valid KrakenMessage Error
                 input: r#"{"errorMessage": "Malformed request", "event": "error"}"#,
                 expected: Ok(KrakenMessage::KrakenEvent(KrakenEvent::Error(
                     KrakenError {
@@ -885,7 +1280,58 @@ mod tests {
                 ))),
             },
             TestCase {
-                // TC5: invalid KrakenMessage gibberish
+                // TC6: valid KrakenMessage Spot book snapshot
+                input: r#"[0,{"as":[["5541.30000","2.50700000","1534614248.123678"]],"bs":[["5541.20000","1.52900000","1534614248.765567"]]},"book-100","XBT/USD"]"#,
+                expected: Ok(KrakenMessage::Book(KrakenBook {
+                    subscription_id: SubscriptionId::from("book-100|XBT/USD"),
+                    action: KrakenBookAction::Snapshot,
+                    bids: vec![KrakenBookLevel {
+                        price: "5541.20000".to_owned(),
+                        quantity: "1.52900000".to_owned(),
+                        time: datetime_utc_from_epoch_duration(Duration::from_secs_f64(
+                            1534614248.765567,
+                        )),
+                    }],
+                    asks: vec![KrakenBookLevel {
+                        price: "5541.30000".to_owned(),
+                        quantity: "2.50700000".to_owned(),
+                        time: datetime_utc_from_epoch_duration(Duration::from_secs_f64(
+                            1534614248.123678,
+                        )),
+                    }],
+                    checksum: None,
+                })),
+            },
+            TestCase {
+                // TC7: valid KrakenMessage Spot book update w/ separate ask & bid objects
+                input: r#"[1234,{"a":[["5541.30000","0.00000000","1534614335.345903"]]},{"b":[["5541.20000","0.40100000","1534614335.345903","r"]],"c":"974942666"},"book-100","XBT/USD"]"#,
+                expected: Ok(KrakenMessage::Book(KrakenBook {
+                    subscription_id: SubscriptionId::from("book-100|XBT/USD"),
+                    action: KrakenBookAction::Update,
+                    bids: vec![KrakenBookLevel {
+                        price: "5541.20000".to_owned(),
+                        quantity: "0.40100000".to_owned(),
+                        time: datetime_utc_from_epoch_duration(Duration::from_secs_f64(
+                            1534614335.345903,
+                        )),
+                    }],
+                    asks: vec![KrakenBookLevel {
+                        price: "5541.30000".to_owned(),
+                        quantity: "0.00000000".to_owned(),
+                        time: datetime_utc_from_epoch_duration(Duration::from_secs_f64(
+                            1534614335.345903,
+                        )),
+                    }],
+                    checksum: Some(974942666),
+                })),
+            },
+            TestCase {
+                // TC8: valid KrakenMessage SubscriptionStatus received after a re-subscription
+                input: r#"{"channelID":10001,"channelName":"book-100","event":"subscriptionStatus","pair":"XBT/USD","status":"unsubscribed","subscription":{"depth":100,"name":"book"}}"#,
+                expected: Ok(KrakenMessage::KrakenEvent(KrakenEvent::SubscriptionStatus)),
+            },
+            TestCase {
+                // TC9: invalid KrakenMessage gibberish
                 input: r#"{"type": "gibberish", "help": "please"}"#,
                 expected: Err(SocketError::Serde {
                     error: serde_json::Error::custom(""),
@@ -942,8 +1388,18 @@ mod tests {
                 }),
             },
             TestCase {
-                // TC2: KrakenTrade is invalid w/ non-string price
-                input: r#"[1000.0,"1000.00010000","1661978265.0","b","l",""]"#,
+                // TC2: KrakenTrade is valid w/ number price & scientific notation quantity
+                input: r#"[1000.0,"1.0001e3","1661978265.0","b","l",""]"#,
+                expected: Ok(KrakenTrade {
+                    price: 1000.0,
+                    quantity: 1000.1,
+                    time: datetime_utc_from_epoch_duration(Duration::from_secs_f64(1661978265.0)),
+                    side: Side::Buy,
+                }),
+            },
+            TestCase {
+                // TC3: KrakenTrade is invalid w/ non-numeric price
+                input: r#"["price","1000.00010000","1661978265.0","b","l",""]"#,
                 expected: Err(SocketError::Serde {
                     error: serde_json::Error::custom(""),
                     payload: "".to_owned(),
@@ -1013,8 +1469,8 @@ mod tests {
                 }),
             },
             TestCase {
-                // TC2: KrakenCandleData is invalid w/ f64 trade_count
-                input: r#"["1542057314.748456","1542057360.435743","3586.70000","3586.70000","3586.60000","3586.60000","3586.68894","0.03373000",2.0]"#,
+                // TC2: KrakenCandleData is invalid w/ fractional trade_count
+                input: r#"["1542057314.748456","1542057360.435743","3586.70000","3586.70000","3586.60000","3586.60000","3586.68894","0.03373000",2.5]"#,
                 expected: Err(SocketError::Serde {
                     error: serde_json::Error::custom(""),
                     payload: "".to_owned(),
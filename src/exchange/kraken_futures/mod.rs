@@ -0,0 +1,413 @@
+use crate::{
+    model::subscription::{SubKind, SubscriptionIds, SubscriptionMeta},
+    ExchangeId, ExchangeTransformer, MarketEvent, Subscriber, Subscription, Validator,
+};
+use barter_integration::{
+    error::SocketError, model::SubscriptionId, protocol::websocket::WsMessage, Transformer,
+};
+use model::{
+    KrakenFuturesContract, KrakenFuturesEvent, KrakenFuturesFeed, KrakenFuturesMessage,
+    KrakenFuturesSubResponse,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::collections::HashMap;
+use tokio::sync::mpsc;
+use tracing::debug;
+
+/// [`KrakenFutures`] specific data structures.
+pub mod model;
+
+/// [`KrakenFutures`] [`Subscriber`] & [`ExchangeTransformer`] implementor for the collection
+/// of `FuturePerpetual` data via the futures.kraken.com WebSocket API.
+///
+/// Barter `FuturePerpetual` [`Instrument`](barter_integration::model::Instrument)s are mapped to
+/// linear multi-collateral perpetual contracts (eg/ "PF_XBTUSD"), or fixed maturity contracts
+/// (eg/ "FF_XBTUSD_240628") if they encode a [`FutureContract`](crate::model::FutureContract).
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct KrakenFutures {
+    pub ids: SubscriptionIds,
+}
+
+impl Subscriber for KrakenFutures {
+    type SubResponse = KrakenFuturesSubResponse;
+
+    fn base_url() -> &'static str {
+        ExchangeId::KrakenFutures.ws_base_url()
+    }
+
+    fn build_subscription_meta(
+        subscriptions: &[Subscription],
+    ) -> Result<SubscriptionMeta, SocketError> {
+        // Allocate SubscriptionIds HashMap to track identifiers for each actioned Subscription
+        let mut ids = SubscriptionIds(HashMap::with_capacity(subscriptions.len()));
+
+        // Map Barter Subscriptions to KrakenFutures feeds
+        let mut subscriptions = subscriptions
+            .iter()
+            .map(|subscription| {
+                // Determine the KrakenFutures specific feed & product for this Subscription
+                let (feed, product_id) = Self::build_channel_meta(subscription)?;
+
+                // Use "feed|product_id" as the SubscriptionId key in the SubscriptionIds
+                // eg/ SubscriptionId("trade|PF_XBTUSD")
+                ids.insert(
+                    KrakenFutures::subscription_id(feed, &product_id),
+                    subscription.clone(),
+                );
+
+                // Construct KrakenFutures specific subscription message
+                Ok(Self::subscription(feed, &[product_id]))
+            })
+            .collect::<Result<Vec<_>, SocketError>>()?;
+
+        // Idle connections are closed by the exchange, so also subscribe to heartbeats
+        subscriptions.push(Self::subscription(Self::FEED_HEARTBEAT, &[]));
+
+        Ok(SubscriptionMeta {
+            ids,
+            expected_responses: subscriptions.len(),
+            subscriptions,
+        })
+    }
+}
+
+impl ExchangeTransformer for KrakenFutures {
+    const EXCHANGE: ExchangeId = ExchangeId::KrakenFutures;
+    fn new(_: mpsc::UnboundedSender<WsMessage>, ids: SubscriptionIds) -> Self {
+        Self { ids }
+    }
+}
+
+impl Transformer<MarketEvent> for KrakenFutures {
+    type Input = KrakenFuturesMessage;
+    type OutputIter = Vec<Result<MarketEvent, SocketError>>;
+
+    fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+        match input {
+            KrakenFuturesMessage::Feed(KrakenFuturesFeed::Trade(trade)) => {
+                match self
+                    .ids
+                    .find_instrument(&Self::subscription_id(Self::FEED_TRADES, &trade.product_id))
+                {
+                    Ok(instrument) => {
+                        vec![Ok(MarketEvent::from((Self::EXCHANGE, instrument, trade)))]
+                    }
+                    Err(error) => vec![Err(error)],
+                }
+            }
+            KrakenFuturesMessage::Feed(KrakenFuturesFeed::TradeSnapshot(snapshot)) => {
+                // Determine Instrument associated with this KrakenFuturesTradeSnapshot
+                let instrument = match self.ids.find_instrument(&Self::subscription_id(
+                    Self::FEED_TRADES,
+                    &snapshot.product_id,
+                )) {
+                    Ok(instrument) => instrument,
+                    Err(error) => return vec![Err(error)],
+                };
+
+                snapshot
+                    .trades
+                    .into_iter()
+                    .map(|trade| {
+                        Ok(MarketEvent::from((
+                            Self::EXCHANGE,
+                            instrument.clone(),
+                            trade,
+                        )))
+                    })
+                    .collect()
+            }
+            KrakenFuturesMessage::Feed(KrakenFuturesFeed::Ticker(ticker)) => {
+                match self.ids.find_instrument(&Self::subscription_id(
+                    Self::FEED_TICKER,
+                    &ticker.product_id,
+                )) {
+                    Ok(instrument) => {
+                        vec![Ok(MarketEvent::from((Self::EXCHANGE, instrument, ticker)))]
+                    }
+                    Err(error) => vec![Err(error)],
+                }
+            }
+            KrakenFuturesMessage::Feed(KrakenFuturesFeed::Heartbeat) => {
+                debug!(exchange_id = %Self::EXCHANGE, "received heartbeat");
+                vec![]
+            }
+            KrakenFuturesMessage::Event(
+                KrakenFuturesEvent::Info | KrakenFuturesEvent::Subscribed,
+            ) => vec![],
+            KrakenFuturesMessage::Event(
+                KrakenFuturesEvent::Alert { message } | KrakenFuturesEvent::Error { message },
+            ) => vec![Err(SocketError::Exchange(message))],
+        }
+    }
+}
+
+impl KrakenFutures {
+    /// [`KrakenFutures`] trades feed name.
+    ///
+    /// See docs: <https://docs.futures.kraken.com/#websocket-api-public-feeds-trade>
+    pub const FEED_TRADES: &'static str = "trade";
+
+    /// [`KrakenFutures`] ticker feed name, normalised into
+    /// [`MarkPrice`](crate::model::MarkPrice)s.
+    ///
+    /// See docs: <https://docs.futures.kraken.com/#websocket-api-public-feeds-ticker>
+    pub const FEED_TICKER: &'static str = "ticker";
+
+    /// [`KrakenFutures`] heartbeat feed name, used to keep idle connections open.
+    ///
+    /// See docs: <https://docs.futures.kraken.com/#websocket-api-public-feeds-heartbeat>
+    pub const FEED_HEARTBEAT: &'static str = "heartbeat";
+
+    /// Determine the [`KrakenFutures`] feed metadata associated with an input Barter
+    /// [`Subscription`]. This includes the [`KrakenFutures`] &str feed, and a `String` product
+    /// identifier. Both are used to build a [`KrakenFutures`] subscription payload.
+    ///
+    /// Example Ok return: Ok("trade", "PF_XBTUSD")
+    /// where feed == "trade" & product_id == "PF_XBTUSD".
+    pub fn build_channel_meta(sub: &Subscription) -> Result<(&str, String), SocketError> {
+        // Validate provided Subscription InstrumentKind is supported by KrakenFutures
+        let sub = sub.validate()?;
+
+        // Determine KrakenFutures feed using the Subscription SubKind
+        let feed = match &sub.kind {
+            SubKind::Trade => Self::FEED_TRADES,
+            SubKind::MarkPrice => Self::FEED_TICKER,
+            other => {
+                return Err(SocketError::Unsupported {
+                    entity: Self::EXCHANGE.as_str(),
+                    item: other.to_string(),
+                })
+            }
+        };
+
+        // Determine KrakenFutures product identifier using the Instrument (eg/ "PF_XBTUSD")
+        let product_id = KrakenFuturesContract::linear(&sub.instrument)?.product_id();
+
+        Ok((feed, product_id))
+    }
+
+    /// Build a [`KrakenFutures`] compatible subscription message using the feed & product
+    /// identifiers provided.
+    ///
+    /// eg/ {"event":"subscribe","feed":"trade","product_ids":["PF_XBTUSD"]}
+    pub fn subscription(feed: &str, product_ids: &[String]) -> WsMessage {
+        let subscription = if product_ids.is_empty() {
+            json!({
+                "event": "subscribe",
+                "feed": feed,
+            })
+        } else {
+            json!({
+                "event": "subscribe",
+                "feed": feed,
+                "product_ids": product_ids,
+            })
+        };
+
+        WsMessage::Text(subscription.to_string())
+    }
+
+    /// Build a [`KrakenFutures`] compatible [`SubscriptionId`] using the feed & product identifier
+    /// provided. This is used to associate [`KrakenFutures`] data structures received over the
+    /// WebSocket with it's original Barter [`Subscription`].
+    ///
+    /// eg/ SubscriptionId("trade|PF_XBTUSD")
+    pub fn subscription_id(feed: &str, product_id: &str) -> SubscriptionId {
+        SubscriptionId::from(format!("{feed}|{product_id}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchange::kraken_futures::model::{KrakenFuturesTicker, KrakenFuturesTrade};
+    use crate::model::{subscription::Interval, DataKind, MarkPrice, PublicTrade};
+    use barter_integration::model::{InstrumentKind, Side};
+    use chrono::Utc;
+
+    #[test]
+    fn test_build_channel_meta() {
+        struct TestCase {
+            input: Subscription,
+            expected: Result<(&'static str, String), SocketError>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: Supported InstrumentKind::FuturePerpetual trades subscription
+                input: Subscription::new(
+                    ExchangeId::KrakenFutures,
+                    ("xbt", "usd", InstrumentKind::FuturePerpetual),
+                    SubKind::Trade,
+                ),
+                expected: Ok(("trade", "PF_XBTUSD".to_owned())),
+            },
+            TestCase {
+                // TC1: Supported InstrumentKind::FuturePerpetual mark price subscription
+                input: Subscription::new(
+                    ExchangeId::KrakenFutures,
+                    ("eth", "usd", InstrumentKind::FuturePerpetual),
+                    SubKind::MarkPrice,
+                ),
+                expected: Ok(("ticker", "PF_ETHUSD".to_owned())),
+            },
+            TestCase {
+                // TC2: Supported dated FutureContract trades subscription
+                input: Subscription::new(
+                    ExchangeId::KrakenFutures,
+                    ("xbt-240628", "usd", InstrumentKind::FuturePerpetual),
+                    SubKind::Trade,
+                ),
+                expected: Ok(("trade", "FF_XBTUSD_240628".to_owned())),
+            },
+            TestCase {
+                // TC3: Unsupported InstrumentKind::Spot trades subscription
+                input: Subscription::new(
+                    ExchangeId::KrakenFutures,
+                    ("xbt", "usd", InstrumentKind::Spot),
+                    SubKind::Trade,
+                ),
+                expected: Err(SocketError::Unsupported {
+                    entity: "",
+                    item: "".to_owned(),
+                }),
+            },
+            TestCase {
+                // TC4: Unsupported InstrumentKind::FuturePerpetual candles subscription
+                input: Subscription::new(
+                    ExchangeId::KrakenFutures,
+                    ("xbt", "usd", InstrumentKind::FuturePerpetual),
+                    SubKind::Candle(Interval::Minute1),
+                ),
+                expected: Err(SocketError::Unsupported {
+                    entity: "",
+                    item: "".to_owned(),
+                }),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = KrakenFutures::build_channel_meta(&test.input);
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => {
+                    assert_eq!(actual, expected, "TC{} failed", index)
+                }
+                (Err(_), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_kraken_futures_transform() {
+        let subscriptions = vec![
+            Subscription::new(
+                ExchangeId::KrakenFutures,
+                ("xbt", "usd", InstrumentKind::FuturePerpetual),
+                SubKind::Trade,
+            ),
+            Subscription::new(
+                ExchangeId::KrakenFutures,
+                ("xbt", "usd", InstrumentKind::FuturePerpetual),
+                SubKind::MarkPrice,
+            ),
+        ];
+        let mut transformer = KrakenFutures {
+            ids: KrakenFutures::build_subscription_meta(&subscriptions)
+                .unwrap()
+                .ids,
+        };
+
+        let time = Utc::now();
+
+        struct TestCase {
+            input: KrakenFuturesMessage,
+            expected: Vec<Result<DataKind, SocketError>>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: trade w/ known product
+                input: KrakenFuturesMessage::Feed(KrakenFuturesFeed::Trade(KrakenFuturesTrade {
+                    product_id: "PF_XBTUSD".to_owned(),
+                    id: "uid".to_owned(),
+                    side: Side::Buy,
+                    time,
+                    quantity: 0.5,
+                    price: 100.0,
+                })),
+                expected: vec![Ok(DataKind::Trade(PublicTrade {
+                    id: "uid".to_owned(),
+                    price: 100.0,
+                    quantity: 0.5,
+                    side: Side::Buy,
+                }))],
+            },
+            TestCase {
+                // TC1: trade w/ unknown product
+                input: KrakenFuturesMessage::Feed(KrakenFuturesFeed::Trade(KrakenFuturesTrade {
+                    product_id: "PF_ETHUSD".to_owned(),
+                    id: "uid".to_owned(),
+                    side: Side::Buy,
+                    time,
+                    quantity: 0.5,
+                    price: 100.0,
+                })),
+                expected: vec![Err(SocketError::Unidentifiable(SubscriptionId::from(
+                    "trade|PF_ETHUSD",
+                )))],
+            },
+            TestCase {
+                // TC2: ticker normalised into MarkPrice
+                input: KrakenFuturesMessage::Feed(KrakenFuturesFeed::Ticker(KrakenFuturesTicker {
+                    product_id: "PF_XBTUSD".to_owned(),
+                    time,
+                    mark_price: 100.0,
+                    index_price: 99.0,
+                    funding_rate: 0.0001,
+                    next_funding_time: time,
+                })),
+                expected: vec![Ok(DataKind::MarkPrice(MarkPrice {
+                    price: 100.0,
+                    index_price: 99.0,
+                    funding_rate: 0.0001,
+                    next_funding_time: time,
+                }))],
+            },
+            TestCase {
+                // TC3: error event
+                input: KrakenFuturesMessage::Event(KrakenFuturesEvent::Error {
+                    message: "Invalid product id".to_owned(),
+                }),
+                expected: vec![Err(SocketError::Exchange("".to_owned()))],
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = transformer.transform(test.input);
+            assert_eq!(actual.len(), test.expected.len(), "TC{} failed", index);
+
+            for (actual, expected) in actual.into_iter().zip(test.expected) {
+                match (actual, expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual.kind, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+}
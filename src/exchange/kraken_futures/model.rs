@@ -0,0 +1,475 @@
+use crate::{
+    model::{DataKind, FutureContract, MarkPrice, PublicTrade},
+    ExchangeId, MarketEvent,
+};
+use barter_integration::{
+    error::SocketError,
+    model::{Exchange, Instrument, InstrumentKind, Side},
+    Validator,
+};
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// [`KrakenFutures`](super::KrakenFutures) subscription response message.
+///
+/// eg/ {"event":"subscribed","feed":"trade","product_ids":["PF_XBTUSD"]}
+///
+/// See docs: <https://docs.futures.kraken.com/#websocket-api-public-feeds-trade>
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum KrakenFuturesSubResponse {
+    Subscribed {
+        feed: String,
+        #[serde(default)]
+        product_ids: Vec<String>,
+    },
+    Error {
+        message: String,
+    },
+}
+
+impl Validator for KrakenFuturesSubResponse {
+    fn validate(self) -> Result<Self, SocketError>
+    where
+        Self: Sized,
+    {
+        match self {
+            KrakenFuturesSubResponse::Subscribed { .. } => Ok(self),
+            KrakenFuturesSubResponse::Error { message } => Err(SocketError::Subscribe(format!(
+                "received failure subscription response: {message}"
+            ))),
+        }
+    }
+}
+
+/// [`KrakenFutures`](super::KrakenFutures) message variants that could be received over
+/// [`WebSocket`](crate::WebSocket).
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum KrakenFuturesMessage {
+    Feed(KrakenFuturesFeed),
+    Event(KrakenFuturesEvent),
+}
+
+/// [`KrakenFutures`](super::KrakenFutures) market data feed messages, tagged by "feed".
+///
+/// See docs: <https://docs.futures.kraken.com/#websocket-api-public-feeds>
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(tag = "feed", rename_all = "snake_case")]
+pub enum KrakenFuturesFeed {
+    Trade(KrakenFuturesTrade),
+    TradeSnapshot(KrakenFuturesTradeSnapshot),
+    Ticker(KrakenFuturesTicker),
+    Heartbeat,
+}
+
+/// [`KrakenFutures`](super::KrakenFutures) connection & subscription events, tagged by "event".
+///
+/// eg/ {"event":"info","version":1}
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum KrakenFuturesEvent {
+    Info,
+    Subscribed,
+    Alert { message: String },
+    Error { message: String },
+}
+
+/// [`KrakenFutures`](super::KrakenFutures) real-time trade message.
+///
+/// eg/ {"feed":"trade","product_id":"PF_XBTUSD","uid":"05af78ac-a774-478c-a50c-8b9c234e071e",
+///      "side":"sell","type":"fill","seq":653355,"time":1612266317519,"qty":0.5,"price":34969.5}
+///
+/// See docs: <https://docs.futures.kraken.com/#websocket-api-public-feeds-trade>
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct KrakenFuturesTrade {
+    pub product_id: String,
+    #[serde(rename = "uid")]
+    pub id: String,
+    pub side: Side,
+    #[serde(deserialize_with = "crate::exchange::de_u64_epoch_ms_as_datetime_utc")]
+    pub time: DateTime<Utc>,
+    #[serde(rename = "qty")]
+    pub quantity: f64,
+    pub price: f64,
+}
+
+impl From<(ExchangeId, Instrument, KrakenFuturesTrade)> for MarketEvent {
+    fn from(
+        (exchange_id, instrument, trade): (ExchangeId, Instrument, KrakenFuturesTrade),
+    ) -> Self {
+        Self {
+            exchange_time: trade.time,
+            received_time: Utc::now(),
+            received_instant: None,
+            raw: None,
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: DataKind::Trade(PublicTrade {
+                id: trade.id,
+                price: trade.price,
+                quantity: trade.quantity,
+                side: trade.side,
+            }),
+        }
+    }
+}
+
+/// [`KrakenFutures`](super::KrakenFutures) snapshot of the most recent trades, sent after
+/// subscribing to the "trade" feed.
+///
+/// See docs: <https://docs.futures.kraken.com/#websocket-api-public-feeds-trade>
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct KrakenFuturesTradeSnapshot {
+    pub product_id: String,
+    pub trades: Vec<KrakenFuturesTrade>,
+}
+
+/// [`KrakenFutures`](super::KrakenFutures) perpetual ticker message, normalised into a
+/// [`MarkPrice`].
+///
+/// eg/ {"feed":"ticker","product_id":"PF_XBTUSD","time":1612270825253,"markPrice":34843.87,
+///      "index":34803.45,"relative_funding_rate":1.3e-5,"next_funding_rate_time":1612281600000, ...}
+///
+/// See docs: <https://docs.futures.kraken.com/#websocket-api-public-feeds-ticker>
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct KrakenFuturesTicker {
+    pub product_id: String,
+    #[serde(deserialize_with = "crate::exchange::de_u64_epoch_ms_as_datetime_utc")]
+    pub time: DateTime<Utc>,
+    #[serde(rename = "markPrice")]
+    pub mark_price: f64,
+    #[serde(rename = "index")]
+    pub index_price: f64,
+    #[serde(rename = "relative_funding_rate")]
+    pub funding_rate: f64,
+    #[serde(
+        rename = "next_funding_rate_time",
+        deserialize_with = "crate::exchange::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub next_funding_time: DateTime<Utc>,
+}
+
+impl From<(ExchangeId, Instrument, KrakenFuturesTicker)> for MarketEvent {
+    fn from(
+        (exchange_id, instrument, ticker): (ExchangeId, Instrument, KrakenFuturesTicker),
+    ) -> Self {
+        Self {
+            exchange_time: ticker.time,
+            received_time: Utc::now(),
+            received_instant: None,
+            raw: None,
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: DataKind::MarkPrice(MarkPrice {
+                price: ticker.mark_price,
+                index_price: ticker.index_price,
+                funding_rate: ticker.funding_rate,
+                next_funding_time: ticker.next_funding_time,
+            }),
+        }
+    }
+}
+
+/// Type of [`KrakenFuturesContract`], determined by it's product identifier prefix.
+///
+/// See docs: <https://docs.futures.kraken.com/#http-api-trading-v3-api-instrument-details>
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum KrakenFuturesContractKind {
+    /// Inverse perpetual (eg/ "PI_XBTUSD").
+    PerpetualInverse,
+    /// Linear multi-collateral perpetual (eg/ "PF_XBTUSD").
+    PerpetualLinear,
+    /// Inverse fixed maturity (eg/ "FI_XBTUSD_210226").
+    FixedInverse,
+    /// Linear fixed maturity (eg/ "FF_XBTUSD_210226").
+    FixedLinear,
+}
+
+impl KrakenFuturesContractKind {
+    /// Product identifier prefix associated with this [`KrakenFuturesContractKind`].
+    pub fn prefix(&self) -> &'static str {
+        match self {
+            KrakenFuturesContractKind::PerpetualInverse => "PI",
+            KrakenFuturesContractKind::PerpetualLinear => "PF",
+            KrakenFuturesContractKind::FixedInverse => "FI",
+            KrakenFuturesContractKind::FixedLinear => "FF",
+        }
+    }
+
+    /// Determines if this [`KrakenFuturesContractKind`] is a perpetual contract.
+    pub fn is_perpetual(&self) -> bool {
+        matches!(
+            self,
+            KrakenFuturesContractKind::PerpetualInverse
+                | KrakenFuturesContractKind::PerpetualLinear
+        )
+    }
+}
+
+/// [`KrakenFutures`](super::KrakenFutures) contract metadata parsed from a product identifier,
+/// either perpetual (eg/ "PF_XBTUSD") or fixed maturity (eg/ "FI_XBTUSD_210226").
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct KrakenFuturesContract {
+    pub kind: KrakenFuturesContractKind,
+    /// Base & quote pair (eg/ "XBTUSD").
+    pub pair: String,
+    /// Maturity date of the contract, or `None` if the contract is perpetual.
+    pub expiry: Option<NaiveDate>,
+}
+
+impl KrakenFuturesContract {
+    /// Format of the product identifier suffix identifying the maturity date of a fixed
+    /// maturity contract.
+    pub const EXPIRY_FORMAT: &'static str = "%y%m%d";
+
+    /// Build the linear perpetual [`KrakenFuturesContract`] associated with the provided
+    /// [`Instrument`]. Only [`InstrumentKind::FuturePerpetual`] is supported.
+    pub fn perpetual(instrument: &Instrument) -> Result<Self, SocketError> {
+        match instrument.kind {
+            InstrumentKind::FuturePerpetual => Ok(Self {
+                kind: KrakenFuturesContractKind::PerpetualLinear,
+                pair: format!("{}{}", instrument.base, instrument.quote).to_uppercase(),
+                expiry: None,
+            }),
+            other => Err(SocketError::Unsupported {
+                entity: ExchangeId::KrakenFutures.as_str(),
+                item: other.to_string(),
+            }),
+        }
+    }
+
+    /// Build the linear [`KrakenFuturesContract`] associated with the provided [`Instrument`],
+    /// either fixed maturity if it encodes a [`FutureContract`], else perpetual.
+    pub fn linear(instrument: &Instrument) -> Result<Self, SocketError> {
+        match FutureContract::try_from(instrument) {
+            Ok(contract) => Ok(Self {
+                kind: KrakenFuturesContractKind::FixedLinear,
+                pair: format!("{}{}", contract.underlying, contract.quote).to_uppercase(),
+                expiry: Some(contract.expiry),
+            }),
+            Err(_) => Self::perpetual(instrument),
+        }
+    }
+
+    /// Build the product identifier associated with this [`KrakenFuturesContract`]
+    /// (eg/ "PF_XBTUSD", "FI_XBTUSD_210226").
+    pub fn product_id(&self) -> String {
+        match self.expiry {
+            Some(expiry) => format!(
+                "{}_{}_{}",
+                self.kind.prefix(),
+                self.pair,
+                expiry.format(Self::EXPIRY_FORMAT)
+            ),
+            None => format!("{}_{}", self.kind.prefix(), self.pair),
+        }
+    }
+}
+
+impl FromStr for KrakenFuturesContract {
+    type Err = SocketError;
+
+    fn from_str(product_id: &str) -> Result<Self, Self::Err> {
+        let unsupported = || SocketError::Unsupported {
+            entity: ExchangeId::KrakenFutures.as_str(),
+            item: product_id.to_owned(),
+        };
+
+        let mut parts = product_id.split('_');
+        let (prefix, pair, expiry) = match (parts.next(), parts.next(), parts.next(), parts.next())
+        {
+            (Some(prefix), Some(pair), expiry, None) if !pair.is_empty() => (prefix, pair, expiry),
+            _ => return Err(unsupported()),
+        };
+
+        let kind = match prefix.to_uppercase().as_str() {
+            "PI" => KrakenFuturesContractKind::PerpetualInverse,
+            "PF" => KrakenFuturesContractKind::PerpetualLinear,
+            "FI" => KrakenFuturesContractKind::FixedInverse,
+            "FF" => KrakenFuturesContractKind::FixedLinear,
+            _ => return Err(unsupported()),
+        };
+
+        let expiry = match (kind.is_perpetual(), expiry) {
+            (true, None) => None,
+            (false, Some(expiry)) => Some(
+                NaiveDate::parse_from_str(expiry, Self::EXPIRY_FORMAT)
+                    .map_err(|_| unsupported())?,
+            ),
+            _ => return Err(unsupported()),
+        };
+
+        Ok(Self {
+            kind,
+            pair: pair.to_uppercase(),
+            expiry,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchange::datetime_utc_from_epoch_duration;
+    use std::time::Duration;
+
+    #[test]
+    fn test_parse_kraken_futures_contract() {
+        struct TestCase {
+            input: &'static str,
+            expected: Result<KrakenFuturesContract, SocketError>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: valid linear perpetual
+                input: "PF_XBTUSD",
+                expected: Ok(KrakenFuturesContract {
+                    kind: KrakenFuturesContractKind::PerpetualLinear,
+                    pair: "XBTUSD".to_owned(),
+                    expiry: None,
+                }),
+            },
+            TestCase {
+                // TC1: valid inverse fixed maturity
+                input: "FI_XBTUSD_210226",
+                expected: Ok(KrakenFuturesContract {
+                    kind: KrakenFuturesContractKind::FixedInverse,
+                    pair: "XBTUSD".to_owned(),
+                    expiry: NaiveDate::from_ymd_opt(2021, 2, 26),
+                }),
+            },
+            TestCase {
+                // TC2: invalid perpetual w/ maturity date
+                input: "PI_XBTUSD_210226",
+                expected: Err(SocketError::Unsupported {
+                    entity: "",
+                    item: "".to_owned(),
+                }),
+            },
+            TestCase {
+                // TC3: invalid fixed maturity w/o maturity date
+                input: "FF_XBTUSD",
+                expected: Err(SocketError::Unsupported {
+                    entity: "",
+                    item: "".to_owned(),
+                }),
+            },
+            TestCase {
+                // TC4: invalid unknown prefix
+                input: "IN_XBTUSD",
+                expected: Err(SocketError::Unsupported {
+                    entity: "",
+                    item: "".to_owned(),
+                }),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = KrakenFuturesContract::from_str(test.input);
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => {
+                    assert_eq!(actual.product_id(), test.input, "TC{} failed", index);
+                    assert_eq!(actual, expected, "TC{} failed", index)
+                }
+                (Err(_), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_deserialise_kraken_futures_message() {
+        struct TestCase {
+            input: &'static str,
+            expected: Result<KrakenFuturesMessage, SocketError>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: valid trade
+                input: r#"{
+                    "feed":"trade","product_id":"PF_XBTUSD","uid":"05af78ac-a774-478c-a50c-8b9c234e071e",
+                    "side":"sell","type":"fill","seq":653355,"time":1612266317519,"qty":0.5,"price":34969.5
+                }"#,
+                expected: Ok(KrakenFuturesMessage::Feed(KrakenFuturesFeed::Trade(
+                    KrakenFuturesTrade {
+                        product_id: "PF_XBTUSD".to_owned(),
+                        id: "05af78ac-a774-478c-a50c-8b9c234e071e".to_owned(),
+                        side: Side::Sell,
+                        time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1612266317519,
+                        )),
+                        quantity: 0.5,
+                        price: 34969.5,
+                    },
+                ))),
+            },
+            TestCase {
+                // TC1: valid perpetual ticker
+                input: r#"{
+                    "time":1612270825253,"feed":"ticker","product_id":"PF_XBTUSD","bid":34832.5,
+                    "ask":34847.5,"bid_size":42864,"ask_size":2300,"volume":262306237,"dtm":0,
+                    "leverage":"50x","index":34803.45,"premium":0.1,"last":34852,"change":2.995,
+                    "funding_rate":3.80032e-10,"funding_rate_prediction":4.2e-10,"suspended":false,
+                    "tag":"perpetual","pair":"XBT:USD","openInterest":263184.0,"markPrice":34843.87,
+                    "maturityTime":0,"relative_funding_rate":1.3e-5,
+                    "relative_funding_rate_prediction":1.4e-5,"next_funding_rate_time":1612281600000
+                }"#,
+                expected: Ok(KrakenFuturesMessage::Feed(KrakenFuturesFeed::Ticker(
+                    KrakenFuturesTicker {
+                        product_id: "PF_XBTUSD".to_owned(),
+                        time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1612270825253,
+                        )),
+                        mark_price: 34843.87,
+                        index_price: 34803.45,
+                        funding_rate: 1.3e-5,
+                        next_funding_time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1612281600000,
+                        )),
+                    },
+                ))),
+            },
+            TestCase {
+                // TC2: valid info event
+                input: r#"{"event":"info","version":1}"#,
+                expected: Ok(KrakenFuturesMessage::Event(KrakenFuturesEvent::Info)),
+            },
+            TestCase {
+                // TC3: valid heartbeat
+                input: r#"{"feed":"heartbeat","time":1534262350627}"#,
+                expected: Ok(KrakenFuturesMessage::Feed(KrakenFuturesFeed::Heartbeat)),
+            },
+            TestCase {
+                // TC4: invalid message w/ unknown feed
+                input: r#"{"feed":"unknown","product_id":"PF_XBTUSD"}"#,
+                expected: Err(SocketError::Subscribe("".to_owned())),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = serde_json::from_str::<KrakenFuturesMessage>(test.input);
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => {
+                    assert_eq!(actual, expected, "TC{} failed", index)
+                }
+                (Err(_), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+}
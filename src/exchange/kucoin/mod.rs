@@ -0,0 +1,498 @@
+use crate::{
+    model::subscription::{SubKind, SubscriptionIds, SubscriptionMeta},
+    send_subscriptions, ExchangeId, ExchangeTransformer, MarketEvent, RateLimit, Subscriber,
+    Subscription, Validator,
+};
+use async_trait::async_trait;
+use barter_integration::{
+    error::SocketError,
+    model::{Instrument, SubscriptionId},
+    protocol::websocket::{connect, WebSocket, WsMessage},
+    Transformer,
+};
+use chrono::Utc;
+use model::{KucoinBullet, KucoinEvent, KucoinInterval, KucoinMessage, KucoinSubResponse};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::{collections::HashMap, time::Duration};
+use tokio::sync::mpsc;
+
+/// [`Kucoin`] specific data structures.
+pub mod model;
+
+/// Interval at which a [`Kucoin`] application level ping is sent to keep the connection alive.
+///
+/// See docs: <https://www.kucoin.com/docs/websocket/basic-info/ping>
+pub const PING_INTERVAL: Duration = Duration::from_secs(18);
+
+/// [`Kucoin`] [`Subscriber`] & [`ExchangeTransformer`] implementor for the collection
+/// of `Spot` data via the KuCoin public WebSocket API.
+///
+/// Connecting requires a public token, so [`Subscriber::subscribe`] first requests one (and the
+/// WebSocket endpoint to use) via the REST bullet-public endpoint.
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct Kucoin {
+    pub ids: SubscriptionIds,
+}
+
+#[async_trait]
+impl Subscriber for Kucoin {
+    type SubResponse = KucoinSubResponse;
+
+    async fn subscribe(
+        subscriptions: &[Subscription],
+    ) -> Result<(WebSocket, SubscriptionIds), SocketError> {
+        // Request a public token & the WebSocket endpoint to connect to
+        let bullet = fetch_bullet(&format!(
+            "{}{}",
+            ExchangeId::Kucoin.rest_base_url(),
+            Self::PATH_BULLET_PUBLIC
+        ))
+        .await
+        .map_err(|error| {
+            SocketError::Subscribe(format!("failed to fetch bullet-public token: {error}"))
+        })?;
+
+        // Connect to exchange
+        let connect_id = Utc::now().timestamp_millis().to_string();
+        let mut websocket = connect(bullet.url(&connect_id)?).await?;
+
+        // Subscribe
+        let SubscriptionMeta {
+            ids,
+            subscriptions,
+            expected_responses,
+        } = Self::build_subscription_meta(subscriptions)?;
+
+        send_subscriptions(&mut websocket, subscriptions, Self::ws_rate_limit()).await?;
+
+        // Validate subscriptions
+        let ids = Self::validate(ids, &mut websocket, expected_responses).await?;
+
+        Ok((websocket, ids))
+    }
+
+    fn base_url() -> &'static str {
+        ExchangeId::Kucoin.ws_base_url()
+    }
+
+    fn ws_rate_limit() -> Option<RateLimit> {
+        ExchangeId::Kucoin.ws_rate_limit()
+    }
+
+    fn build_subscription_meta(
+        subscriptions: &[Subscription],
+    ) -> Result<SubscriptionMeta, SocketError> {
+        // Allocate SubscriptionIds HashMap to track identifiers for each actioned Subscription
+        let mut ids = SubscriptionIds(HashMap::with_capacity(subscriptions.len()));
+
+        // Map Barter Subscriptions to Kucoin topics
+        let subscriptions = subscriptions
+            .iter()
+            .enumerate()
+            .map(|(index, subscription)| {
+                // Translate Barter Subscription to the associated Kucoin topic
+                let topic = Self::topic(subscription)?;
+
+                // Use the topic as the SubscriptionId key in the SubscriptionIds
+                // eg/ SubscriptionId("/market/match:BTC-USDT")
+                ids.insert(SubscriptionId::from(topic.as_str()), subscription.clone());
+
+                // Construct Kucoin specific subscription message
+                Ok(Self::subscription_message(index, &topic))
+            })
+            .collect::<Result<Vec<_>, SocketError>>()?;
+
+        Ok(SubscriptionMeta {
+            ids,
+            expected_responses: subscriptions.len(),
+            subscriptions,
+        })
+    }
+}
+
+impl ExchangeTransformer for Kucoin {
+    const EXCHANGE: ExchangeId = ExchangeId::Kucoin;
+
+    fn new(ws_sink_tx: mpsc::UnboundedSender<WsMessage>, ids: SubscriptionIds) -> Self {
+        spawn_ping_task(ws_sink_tx);
+        Self { ids }
+    }
+}
+
+impl Transformer<MarketEvent> for Kucoin {
+    type Input = KucoinMessage;
+    type OutputIter = Vec<Result<MarketEvent, SocketError>>;
+
+    fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+        match input {
+            KucoinMessage::Trade(trade) => match self.ids.find_instrument(&trade.subscription_id) {
+                Ok(instrument) => vec![Ok(MarketEvent::from((
+                    Kucoin::EXCHANGE,
+                    instrument,
+                    trade.data,
+                )))],
+                Err(error) => vec![Err(error)],
+            },
+            KucoinMessage::Candle(candle) => {
+                // Determine Instrument & Interval associated with this KucoinCandle message
+                match self.ids.get(&candle.subscription_id) {
+                    Some(Subscription {
+                        instrument,
+                        kind: SubKind::Candle(interval),
+                        ..
+                    }) => vec![Ok(MarketEvent::from((
+                        Kucoin::EXCHANGE,
+                        instrument.clone(),
+                        *interval,
+                        candle.data.candles,
+                    )))],
+                    _ => vec![Err(SocketError::Unidentifiable(candle.subscription_id))],
+                }
+            }
+            KucoinMessage::Event(KucoinEvent::Error { data }) => {
+                vec![Err(SocketError::Exchange(format!(
+                    "received error message: {data}"
+                )))]
+            }
+            KucoinMessage::Event(_) => vec![],
+        }
+    }
+}
+
+impl Kucoin {
+    /// [`Kucoin`] REST path used to request a public token & WebSocket endpoint.
+    ///
+    /// See docs: <https://www.kucoin.com/docs/websocket/basic-info/apply-connect-token/public-token-no-authentication-required->
+    pub const PATH_BULLET_PUBLIC: &'static str = "/api/v1/bullet-public";
+
+    /// [`Kucoin`] match execution topic prefix, suffixed with the market.
+    ///
+    /// See docs: <https://www.kucoin.com/docs/websocket/spot-trading/public-channels/match-execution-data>
+    pub const TOPIC_MATCH: &'static str = "/market/match";
+
+    /// [`Kucoin`] klines topic prefix, suffixed with the market & [`KucoinInterval`].
+    ///
+    /// See docs: <https://www.kucoin.com/docs/websocket/spot-trading/public-channels/klines>
+    pub const TOPIC_CANDLES: &'static str = "/market/candles";
+
+    /// Translate a Barter [`Subscription`] into a [`Kucoin`] topic.
+    ///
+    /// eg/ "/market/match:BTC-USDT", "/market/candles:BTC-USDT_1hour"
+    pub fn topic(sub: &Subscription) -> Result<String, SocketError> {
+        // Validate provided Subscription InstrumentKind & SubKind are supported by Kucoin
+        let sub = sub.validate()?;
+        let market = Self::market(&sub.instrument);
+
+        match &sub.kind {
+            SubKind::Trade => Ok(format!("{}:{market}", Self::TOPIC_MATCH)),
+            SubKind::Candle(interval) => Ok(format!(
+                "{}:{market}_{}",
+                Self::TOPIC_CANDLES,
+                KucoinInterval::try_from(interval)?.0
+            )),
+            other => Err(SocketError::Unsupported {
+                entity: Self::EXCHANGE.as_str(),
+                item: other.to_string(),
+            }),
+        }
+    }
+
+    /// Determine the [`Kucoin`] market identifier for the provided [`Instrument`].
+    ///
+    /// eg/ "BTC-USDT"
+    pub fn market(instrument: &Instrument) -> String {
+        format!("{}-{}", instrument.base, instrument.quote).to_uppercase()
+    }
+
+    /// Build a [`Kucoin`] compatible subscription message for the provided topic, using the
+    /// index as the message id.
+    pub fn subscription_message(index: usize, topic: &str) -> WsMessage {
+        WsMessage::Text(
+            json!({
+                "id": index.to_string(),
+                "type": "subscribe",
+                "topic": topic,
+                "privateChannel": false,
+                "response": true,
+            })
+            .to_string(),
+        )
+    }
+}
+
+/// Request a public token & WebSocket endpoint from the [`Kucoin`] bullet-public endpoint.
+pub async fn fetch_bullet(url: &str) -> Result<KucoinBullet, reqwest::Error> {
+    reqwest::Client::new().post(url).send().await?.json().await
+}
+
+/// Spawn a task that sends a [`Kucoin`] ping every [`PING_INTERVAL`] via the [`WsSink`]
+/// transmitter. The task ends once the WebSocket connection has been dropped.
+///
+/// [`WsSink`]: barter_integration::protocol::websocket::WsSink
+pub fn spawn_ping_task(ws_sink_tx: mpsc::UnboundedSender<WsMessage>) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(PING_INTERVAL);
+        loop {
+            interval.tick().await;
+            let id = Utc::now().timestamp_millis().to_string();
+            let ping = WsMessage::Text(json!({ "id": id, "type": "ping" }).to_string());
+            if ws_sink_tx.send(ping).is_err() {
+                break;
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchange::kucoin::model::{
+        KucoinCandle, KucoinCandleData, KucoinCandleValues, KucoinTrade, KucoinTradeData,
+    };
+    use crate::model::{subscription::Interval, Candle, DataKind, PublicTrade};
+    use barter_integration::model::{Exchange, InstrumentKind, Side};
+
+    #[test]
+    fn test_topic() {
+        struct TestCase {
+            input: Subscription,
+            expected: Result<String, SocketError>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: Supported InstrumentKind::Spot trades subscription
+                input: Subscription::new(
+                    ExchangeId::Kucoin,
+                    ("btc", "usdt", InstrumentKind::Spot),
+                    SubKind::Trade,
+                ),
+                expected: Ok("/market/match:BTC-USDT".to_owned()),
+            },
+            TestCase {
+                // TC1: Supported InstrumentKind::Spot candles subscription
+                input: Subscription::new(
+                    ExchangeId::Kucoin,
+                    ("btc", "usdt", InstrumentKind::Spot),
+                    SubKind::Candle(Interval::Hour1),
+                ),
+                expected: Ok("/market/candles:BTC-USDT_1hour".to_owned()),
+            },
+            TestCase {
+                // TC2: Unsupported InstrumentKind::FuturePerpetual
+                input: Subscription::new(
+                    ExchangeId::Kucoin,
+                    ("btc", "usdt", InstrumentKind::FuturePerpetual),
+                    SubKind::Trade,
+                ),
+                expected: Err(SocketError::Unsupported {
+                    entity: "kucoin",
+                    item: InstrumentKind::FuturePerpetual.to_string(),
+                }),
+            },
+            TestCase {
+                // TC3: Unsupported Interval
+                input: Subscription::new(
+                    ExchangeId::Kucoin,
+                    ("btc", "usdt", InstrumentKind::Spot),
+                    SubKind::Candle(Interval::Month1),
+                ),
+                expected: Err(SocketError::Unsupported {
+                    entity: "kucoin",
+                    item: Interval::Month1.to_string(),
+                }),
+            },
+            TestCase {
+                // TC4: Unsupported SubKind
+                input: Subscription::new(
+                    ExchangeId::Kucoin,
+                    ("btc", "usdt", InstrumentKind::Spot),
+                    SubKind::OrderBookL3Delta,
+                ),
+                expected: Err(SocketError::Unsupported {
+                    entity: "kucoin",
+                    item: SubKind::OrderBookL3Delta.to_string(),
+                }),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = Kucoin::topic(&test.input);
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => {
+                    assert_eq!(actual, expected, "TC{} failed", index)
+                }
+                (Err(_), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_kucoin_transform() {
+        let trade_sub = Subscription::new(
+            ExchangeId::Kucoin,
+            ("btc", "usdt", InstrumentKind::Spot),
+            SubKind::Trade,
+        );
+        let candle_sub = Subscription::new(
+            ExchangeId::Kucoin,
+            ("btc", "usdt", InstrumentKind::Spot),
+            SubKind::Candle(Interval::Minute1),
+        );
+        let mut transformer = Kucoin {
+            ids: SubscriptionIds(HashMap::from([
+                (
+                    SubscriptionId::from("/market/match:BTC-USDT"),
+                    trade_sub.clone(),
+                ),
+                (
+                    SubscriptionId::from("/market/candles:BTC-USDT_1min"),
+                    candle_sub.clone(),
+                ),
+            ])),
+        };
+
+        let time = Utc::now();
+
+        struct TestCase {
+            input: KucoinMessage,
+            expected: Vec<Result<MarketEvent, SocketError>>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: KucoinMessage Trade w/ known SubscriptionId
+                input: KucoinMessage::Trade(KucoinTrade {
+                    subscription_id: SubscriptionId::from("/market/match:BTC-USDT"),
+                    data: KucoinTradeData {
+                        id: "1".to_owned(),
+                        price: 100.0,
+                        quantity: 1.0,
+                        side: Side::Buy,
+                        time,
+                    },
+                }),
+                expected: vec![Ok(MarketEvent {
+                    exchange_time: time,
+                    received_time: time,
+                    received_instant: None,
+                    raw: None,
+                    exchange: Exchange::from(ExchangeId::Kucoin),
+                    instrument: trade_sub.instrument.clone(),
+                    kind: DataKind::Trade(PublicTrade {
+                        id: "1".to_owned(),
+                        price: 100.0,
+                        quantity: 1.0,
+                        side: Side::Buy,
+                    }),
+                })],
+            },
+            TestCase {
+                // TC1: KucoinMessage Candle w/ known SubscriptionId
+                input: KucoinMessage::Candle(KucoinCandle {
+                    subscription_id: SubscriptionId::from("/market/candles:BTC-USDT_1min"),
+                    data: KucoinCandleData {
+                        candles: KucoinCandleValues {
+                            start_time: time,
+                            open: 1.0,
+                            close: 1.5,
+                            high: 2.0,
+                            low: 0.5,
+                            volume: 10.0,
+                        },
+                    },
+                }),
+                expected: vec![Ok(MarketEvent {
+                    exchange_time: time + chrono::Duration::minutes(1),
+                    received_time: time,
+                    received_instant: None,
+                    raw: None,
+                    exchange: Exchange::from(ExchangeId::Kucoin),
+                    instrument: candle_sub.instrument,
+                    kind: DataKind::Candle(Candle {
+                        start_time: time,
+                        end_time: time + chrono::Duration::minutes(1),
+                        open: 1.0,
+                        high: 2.0,
+                        low: 0.5,
+                        close: 1.5,
+                        volume: 10.0,
+                        trade_count: 0,
+                    }),
+                })],
+            },
+            TestCase {
+                // TC2: KucoinMessage Trade w/ unknown SubscriptionId
+                input: KucoinMessage::Trade(KucoinTrade {
+                    subscription_id: SubscriptionId::from("/market/match:ETH-USDT"),
+                    data: KucoinTradeData {
+                        id: "1".to_owned(),
+                        price: 100.0,
+                        quantity: 1.0,
+                        side: Side::Buy,
+                        time,
+                    },
+                }),
+                expected: vec![Err(SocketError::Unidentifiable(SubscriptionId::from(
+                    "/market/match:ETH-USDT",
+                )))],
+            },
+            TestCase {
+                // TC3: KucoinMessage Event pong is ignored
+                input: KucoinMessage::Event(KucoinEvent::Pong),
+                expected: vec![],
+            },
+            TestCase {
+                // TC4: KucoinMessage Event error
+                input: KucoinMessage::Event(KucoinEvent::Error {
+                    data: "topic /market/unknown:BTC-USDT is not found".to_owned(),
+                }),
+                expected: vec![Err(SocketError::Exchange("".to_owned()))],
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = transformer.transform(test.input);
+            assert_eq!(
+                actual.len(),
+                test.expected.len(),
+                "TestCase {} failed at vector length assert_eq with actual: {:?}",
+                index,
+                actual
+            );
+
+            for (vector_index, (actual, expected)) in
+                actual.into_iter().zip(test.expected).enumerate()
+            {
+                match (actual, expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        // Scrub Utc::now() timestamps to allow comparison
+                        let actual = MarketEvent {
+                            received_time: time,
+                            ..actual
+                        };
+                        assert_eq!(
+                            actual, expected,
+                            "TC{} failed at vector index {}",
+                            index, vector_index
+                        )
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed at vector index {vector_index} because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,442 @@
+use crate::{
+    exchange::{datetime_utc_from_epoch_duration, de_str, extract_next, Numeric},
+    model::{subscription::Interval, Candle, DataKind, PublicTrade},
+    ExchangeId, MarketEvent,
+};
+use barter_integration::{
+    error::SocketError,
+    model::{Exchange, Instrument, Side, SubscriptionId},
+    Validator,
+};
+use chrono::{DateTime, Utc};
+use serde::{de, Deserialize, Deserializer, Serialize};
+use std::time::Duration;
+
+/// [`Kucoin`](super::Kucoin) response to the public token REST bootstrap request, containing the
+/// token & WebSocket servers required to connect.
+///
+/// eg/ {"code":"200000","data":{"token":"2neAiuYvAU61ZDXANAGAsiL4-iAExhsBXZxftpOeh_55i3Ysy2q2LEsEWU64mdzUOPusi34M_wGoSf7iNyEWJ4aBZXpWhrmY9jKtqkdWoFa75w3istPvPtiYB9J6i9GjsxUuhPw3BlrzazF6ghq4L_5HXgB4YN6pk5H2W27OMBEcMfpBwbNlohgHR5ZLvZ_rBiYB9J6i9GjsxUuhPw3BlrzazF6ghq4L_5HXgB4YN6pk5H2W27OMBEcMfpBwbNlohgHR5ZLvZ_r.WVsn_9p-U4KI1l87WFyoaA==",
+///      "instanceServers":[{"endpoint":"wss://ws-api-spot.kucoin.com/","encrypt":true,
+///      "protocol":"websocket","pingInterval":18000,"pingTimeout":10000}]}}
+///
+/// See docs: <https://www.kucoin.com/docs/websocket/basic-info/apply-connect-token/public-token-no-authentication-required->
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct KucoinBullet {
+    pub code: String,
+    pub data: KucoinBulletData,
+}
+
+/// [`Kucoin`](super::Kucoin) public token & WebSocket servers.
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct KucoinBulletData {
+    pub token: String,
+    #[serde(rename = "instanceServers")]
+    pub instance_servers: Vec<KucoinInstanceServer>,
+}
+
+/// [`Kucoin`](super::Kucoin) WebSocket server that can be connected to using a public token.
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct KucoinInstanceServer {
+    pub endpoint: String,
+}
+
+impl KucoinBullet {
+    /// Successful [`KucoinBullet`] response code.
+    pub const CODE_SUCCESS: &'static str = "200000";
+
+    /// Determine the WebSocket URL to connect to using the provided connection identifier.
+    ///
+    /// eg/ "wss://ws-api-spot.kucoin.com/?token=xxx&connectId=1545910660739"
+    pub fn url(&self, connect_id: &str) -> Result<String, SocketError> {
+        if self.code != Self::CODE_SUCCESS {
+            return Err(SocketError::Subscribe(format!(
+                "received failure bullet-public response code: {}",
+                self.code
+            )));
+        }
+
+        self.data
+            .instance_servers
+            .first()
+            .map(|server| {
+                format!(
+                    "{}?token={}&connectId={connect_id}",
+                    server.endpoint, self.data.token
+                )
+            })
+            .ok_or_else(|| {
+                SocketError::Subscribe("bullet-public response contains no instanceServers".into())
+            })
+    }
+}
+
+/// [`Kucoin`](super::Kucoin) message received in response to WebSocket subscription requests.
+///
+/// eg/ KucoinSubResponse::Ack {"id":"1545910660739","type":"ack"}
+/// eg/ KucoinSubResponse::Error {"id":"1545910660739","type":"error","code":404,"data":"topic /market/unknown:BTC-USDT is not found"}
+///
+/// See docs: <https://www.kucoin.com/docs/websocket/basic-info/subscribe/introduction>
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum KucoinSubResponse {
+    Ack,
+    Error { data: String },
+}
+
+impl Validator for KucoinSubResponse {
+    fn validate(self) -> Result<Self, SocketError>
+    where
+        Self: Sized,
+    {
+        match &self {
+            KucoinSubResponse::Ack => Ok(self),
+            KucoinSubResponse::Error { data } => Err(SocketError::Subscribe(format!(
+                "received failure subscription response: {data}",
+            ))),
+        }
+    }
+}
+
+/// [`Kucoin`](super::Kucoin) candle interval used in candle topics
+/// (eg/ "/market/candles:BTC-USDT_1hour").
+///
+/// See docs: <https://www.kucoin.com/docs/websocket/spot-trading/public-channels/klines>
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct KucoinInterval(pub &'static str);
+
+impl TryFrom<&Interval> for KucoinInterval {
+    type Error = SocketError;
+
+    fn try_from(interval: &Interval) -> Result<Self, Self::Error> {
+        let interval = match interval {
+            Interval::Minute1 => "1min",
+            Interval::Minute3 => "3min",
+            Interval::Minute5 => "5min",
+            Interval::Minute15 => "15min",
+            Interval::Minute30 => "30min",
+            Interval::Hour1 => "1hour",
+            Interval::Hour2 => "2hour",
+            Interval::Hour4 => "4hour",
+            Interval::Hour6 => "6hour",
+            Interval::Hour8 => "8hour",
+            Interval::Hour12 => "12hour",
+            Interval::Day1 => "1day",
+            Interval::Week1 => "1week",
+            other => {
+                return Err(SocketError::Unsupported {
+                    entity: ExchangeId::Kucoin.as_str(),
+                    item: other.to_string(),
+                })
+            }
+        };
+
+        Ok(Self(interval))
+    }
+}
+
+/// [`Kucoin`](super::Kucoin) message variants that can be received over [`WebSocket`].
+///
+/// See docs: <https://www.kucoin.com/docs/websocket/spot-trading/public-channels/match-execution-data>
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum KucoinMessage {
+    Trade(KucoinTrade),
+    Candle(KucoinCandle),
+    Event(KucoinEvent),
+}
+
+/// [`Kucoin`](super::Kucoin) match execution message.
+///
+/// eg/ {"type":"message","topic":"/market/match:BTC-USDT","subject":"trade.l3match","data":{...}}
+///
+/// See docs: <https://www.kucoin.com/docs/websocket/spot-trading/public-channels/match-execution-data>
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct KucoinTrade {
+    #[serde(alias = "topic")]
+    pub subscription_id: SubscriptionId,
+    pub data: KucoinTradeData,
+}
+
+/// [`Kucoin`](super::Kucoin) match execution.
+///
+/// eg/ {"sequence":"1545896669145","type":"match","symbol":"BTC-USDT","side":"buy",
+///      "price":"0.082","size":"0.01022222","tradeId":"5c24c5da03aa673885cd67aa",
+///      "takerOrderId":"5c24c5d903aa6772d55b371e","makerOrderId":"5c2187d003aa677bd09d5c93",
+///      "time":"1545913818099033203"}
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct KucoinTradeData {
+    #[serde(rename = "tradeId")]
+    pub id: String,
+    #[serde(deserialize_with = "de_str")]
+    pub price: f64,
+    #[serde(rename = "size", deserialize_with = "de_str")]
+    pub quantity: f64,
+    pub side: Side,
+    #[serde(deserialize_with = "de_str_epoch_ns_as_datetime_utc")]
+    pub time: DateTime<Utc>,
+}
+
+/// [`Kucoin`](super::Kucoin) klines message.
+///
+/// eg/ {"type":"message","topic":"/market/candles:BTC-USDT_1hour",
+///      "subject":"trade.candles.update","data":{...}}
+///
+/// See docs: <https://www.kucoin.com/docs/websocket/spot-trading/public-channels/klines>
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct KucoinCandle {
+    #[serde(alias = "topic")]
+    pub subscription_id: SubscriptionId,
+    pub data: KucoinCandleData,
+}
+
+/// [`Kucoin`](super::Kucoin) klines data wrapping the [`KucoinCandleValues`].
+///
+/// eg/ {"symbol":"BTC-USDT","candles":["1589968800","9786.9","9740.8","9806.1","9732",
+///      "27.45649579","268280.09830877"],"time":1589970010253893337}
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct KucoinCandleData {
+    pub candles: KucoinCandleValues,
+}
+
+/// [`Kucoin`](super::Kucoin) OHLCV values, sent as an array of `String`s. The end time is not
+/// sent, so it is determined from the subscribed [`Interval`] when transformed.
+///
+/// eg/ ["1589968800","9786.9","9740.8","9806.1","9732","27.45649579","268280.09830877"]
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Serialize)]
+pub struct KucoinCandleValues {
+    pub start_time: DateTime<Utc>,
+    pub open: f64,
+    pub close: f64,
+    pub high: f64,
+    pub low: f64,
+    pub volume: f64,
+}
+
+/// [`Kucoin`](super::Kucoin) messages received over the WebSocket which are not market data.
+///
+/// See docs: <https://www.kucoin.com/docs/websocket/basic-info/ping>
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum KucoinEvent {
+    Welcome,
+    Ack,
+    Pong,
+    Error { data: String },
+}
+
+impl<'de> Deserialize<'de> for KucoinCandleValues {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct SeqVisitor;
+
+        impl<'de> de::Visitor<'de> for SeqVisitor {
+            type Value = KucoinCandleValues;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("KucoinCandleValues struct from the Kucoin WebSocket API")
+            }
+
+            fn visit_seq<SeqAccessor>(
+                self,
+                mut seq: SeqAccessor,
+            ) -> Result<Self::Value, SeqAccessor::Error>
+            where
+                SeqAccessor: de::SeqAccess<'de>,
+            {
+                // KucoinCandleValues Sequence Format:
+                // [start time, open, close, high, low, volume, amount]
+                // <https://www.kucoin.com/docs/websocket/spot-trading/public-channels/klines>
+
+                // Extract numeric start time epoch seconds & map to DateTime<Utc>
+                let start_time = extract_next::<SeqAccessor, Numeric<u64>>(&mut seq, "time").map(
+                    |Numeric(time)| datetime_utc_from_epoch_duration(Duration::from_secs(time)),
+                )?;
+
+                let open = extract_next::<SeqAccessor, Numeric<f64>>(&mut seq, "open")?.0;
+                let close = extract_next::<SeqAccessor, Numeric<f64>>(&mut seq, "close")?.0;
+                let high = extract_next::<SeqAccessor, Numeric<f64>>(&mut seq, "high")?.0;
+                let low = extract_next::<SeqAccessor, Numeric<f64>>(&mut seq, "low")?.0;
+                let volume = extract_next::<SeqAccessor, Numeric<f64>>(&mut seq, "volume")?.0;
+
+                // Ignore any additional elements or SerDe will fail
+                //  '--> Exchange may add fields without warning
+                while seq.next_element::<de::IgnoredAny>()?.is_some() {}
+
+                Ok(KucoinCandleValues {
+                    start_time,
+                    open,
+                    close,
+                    high,
+                    low,
+                    volume,
+                })
+            }
+        }
+
+        // Use Visitor implementation to deserialise the KucoinCandleValues
+        deserializer.deserialize_seq(SeqVisitor)
+    }
+}
+
+impl From<(ExchangeId, Instrument, KucoinTradeData)> for MarketEvent {
+    fn from((exchange_id, instrument, trade): (ExchangeId, Instrument, KucoinTradeData)) -> Self {
+        Self {
+            exchange_time: trade.time,
+            received_time: Utc::now(),
+            received_instant: None,
+            raw: None,
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: DataKind::Trade(PublicTrade {
+                id: trade.id,
+                price: trade.price,
+                quantity: trade.quantity,
+                side: trade.side,
+            }),
+        }
+    }
+}
+
+impl From<(ExchangeId, Instrument, Interval, KucoinCandleValues)> for MarketEvent {
+    fn from(
+        (exchange_id, instrument, interval, candle): (
+            ExchangeId,
+            Instrument,
+            Interval,
+            KucoinCandleValues,
+        ),
+    ) -> Self {
+        let end_time = interval.end_time(candle.start_time);
+
+        Self {
+            exchange_time: end_time,
+            received_time: Utc::now(),
+            received_instant: None,
+            raw: None,
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: DataKind::Candle(Candle {
+                start_time: candle.start_time,
+                end_time,
+                open: candle.open,
+                high: candle.high,
+                low: candle.low,
+                close: candle.close,
+                volume: candle.volume,
+                trade_count: 0,
+            }),
+        }
+    }
+}
+
+/// Deserialize a `String` epoch nanoseconds timestamp (eg/ "1545913818099033203") as
+/// `DateTime<Utc>`.
+pub fn de_str_epoch_ns_as_datetime_utc<'de, D>(deserializer: D) -> Result<DateTime<Utc>, D::Error>
+where
+    D: de::Deserializer<'de>,
+{
+    de_str(deserializer)
+        .map(|epoch_ns| datetime_utc_from_epoch_duration(Duration::from_nanos(epoch_ns)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kucoin_bullet_url() {
+        struct TestCase {
+            input: &'static str,
+            expected: Option<String>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: successful bullet-public response
+                input: r#"{"code":"200000","data":{"token":"abc","instanceServers":[{"endpoint":"wss://ws-api-spot.kucoin.com/","encrypt":true,"protocol":"websocket","pingInterval":18000,"pingTimeout":10000}]}}"#,
+                expected: Some("wss://ws-api-spot.kucoin.com/?token=abc&connectId=1".to_owned()),
+            },
+            TestCase {
+                // TC1: bullet-public response w/o any instanceServers
+                input: r#"{"code":"200000","data":{"token":"abc","instanceServers":[]}}"#,
+                expected: None,
+            },
+            TestCase {
+                // TC2: failed bullet-public response
+                input: r#"{"code":"400100","data":{"token":"","instanceServers":[]}}"#,
+                expected: None,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let bullet = serde_json::from_str::<KucoinBullet>(test.input).unwrap();
+            let actual = bullet.url("1").ok();
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_deserialise_kucoin_message() {
+        struct TestCase {
+            input: &'static str,
+            expected: Option<KucoinMessage>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: input is a valid match execution
+                input: r#"{"type":"message","topic":"/market/match:BTC-USDT","subject":"trade.l3match","data":{"sequence":"1545896669145","type":"match","symbol":"BTC-USDT","side":"buy","price":"0.082","size":"0.01022222","tradeId":"5c24c5da03aa673885cd67aa","takerOrderId":"5c24c5d903aa6772d55b371e","makerOrderId":"5c2187d003aa677bd09d5c93","time":"1545913818099033203"}}"#,
+                expected: Some(KucoinMessage::Trade(KucoinTrade {
+                    subscription_id: SubscriptionId::from("/market/match:BTC-USDT"),
+                    data: KucoinTradeData {
+                        id: "5c24c5da03aa673885cd67aa".to_owned(),
+                        price: 0.082,
+                        quantity: 0.01022222,
+                        side: Side::Buy,
+                        time: datetime_utc_from_epoch_duration(Duration::from_nanos(
+                            1545913818099033203,
+                        )),
+                    },
+                })),
+            },
+            TestCase {
+                // TC1: input is a valid klines update
+                input: r#"{"type":"message","topic":"/market/candles:BTC-USDT_1hour","subject":"trade.candles.update","data":{"symbol":"BTC-USDT","candles":["1589968800","9786.9","9740.8","9806.1","9732","27.45649579","268280.09830877"],"time":1589970010253893337}}"#,
+                expected: Some(KucoinMessage::Candle(KucoinCandle {
+                    subscription_id: SubscriptionId::from("/market/candles:BTC-USDT_1hour"),
+                    data: KucoinCandleData {
+                        candles: KucoinCandleValues {
+                            start_time: datetime_utc_from_epoch_duration(Duration::from_secs(
+                                1589968800,
+                            )),
+                            open: 9786.9,
+                            close: 9740.8,
+                            high: 9806.1,
+                            low: 9732.0,
+                            volume: 27.45649579,
+                        },
+                    },
+                })),
+            },
+            TestCase {
+                // TC2: input is a welcome event
+                input: r#"{"id":"hQvf8jkno","type":"welcome"}"#,
+                expected: Some(KucoinMessage::Event(KucoinEvent::Welcome)),
+            },
+            TestCase {
+                // TC3: input is an error event
+                input: r#"{"id":"1545910660739","type":"error","code":404,"data":"topic /market/unknown:BTC-USDT is not found"}"#,
+                expected: Some(KucoinMessage::Event(KucoinEvent::Error {
+                    data: "topic /market/unknown:BTC-USDT is not found".to_owned(),
+                })),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = serde_json::from_str::<KucoinMessage>(test.input).ok();
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
@@ -0,0 +1,337 @@
+use crate::{
+    model::subscription::{SubKind, SubscriptionIds, SubscriptionMeta},
+    ExchangeId, ExchangeTransformer, MarketEvent, Subscriber, Subscription, Validator,
+};
+use barter_integration::{
+    error::SocketError,
+    model::{Instrument, SubscriptionId},
+    protocol::websocket::WsMessage,
+    Transformer,
+};
+use model::{MexcInterval, MexcMessage, MexcPayload, MexcSubResponse};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::collections::HashMap;
+use tokio::sync::mpsc;
+
+/// [`Mexc`] specific data structures.
+pub mod model;
+
+/// [`Mexc`] [`Subscriber`] & [`ExchangeTransformer`] implementor for the collection
+/// of `Spot` data via the MEXC v3 WebSocket market streams.
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct Mexc {
+    pub ids: SubscriptionIds,
+}
+
+impl Subscriber for Mexc {
+    type SubResponse = MexcSubResponse;
+
+    fn base_url() -> &'static str {
+        ExchangeId::Mexc.ws_base_url()
+    }
+
+    fn build_subscription_meta(
+        subscriptions: &[Subscription],
+    ) -> Result<SubscriptionMeta, SocketError> {
+        // Allocate SubscriptionIds HashMap to track identifiers for each actioned Subscription
+        let mut ids = SubscriptionIds(HashMap::with_capacity(subscriptions.len()));
+
+        // Map Barter Subscriptions to Mexc channels
+        let subscriptions = subscriptions
+            .iter()
+            .map(|subscription| {
+                // Determine the Mexc specific channel for this Barter Subscription
+                let channel = Self::channel(subscription, Self::PAYLOAD)?;
+
+                // Use the channel as the SubscriptionId key in the SubscriptionIds
+                // eg/ SubscriptionId("spot@public.kline.v3.api@BTCUSDT@Min15")
+                ids.insert(SubscriptionId::from(channel.as_str()), subscription.clone());
+
+                // Construct Mexc specific subscription message
+                Ok(Self::subscription_message(&channel))
+            })
+            .collect::<Result<Vec<_>, SocketError>>()?;
+
+        Ok(SubscriptionMeta {
+            ids,
+            expected_responses: subscriptions.len(),
+            subscriptions,
+        })
+    }
+}
+
+impl ExchangeTransformer for Mexc {
+    const EXCHANGE: ExchangeId = ExchangeId::Mexc;
+
+    fn new(_: mpsc::UnboundedSender<WsMessage>, ids: SubscriptionIds) -> Self {
+        Self { ids }
+    }
+}
+
+impl Transformer<MarketEvent> for Mexc {
+    type Input = MexcMessage;
+    type OutputIter = Vec<Result<MarketEvent, SocketError>>;
+
+    fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+        match input {
+            MexcMessage::Trades(trades) => {
+                // Determine Instrument associated with this MexcStream
+                let instrument = match self.ids.find_instrument(&trades.subscription_id) {
+                    Ok(instrument) => instrument,
+                    Err(error) => return vec![Err(error)],
+                };
+
+                trades
+                    .data
+                    .deals
+                    .into_iter()
+                    .map(|deal| {
+                        Ok(MarketEvent::from((
+                            Mexc::EXCHANGE,
+                            instrument.clone(),
+                            deal,
+                        )))
+                    })
+                    .collect()
+            }
+            MexcMessage::Candle(candle) => {
+                match self.ids.find_instrument(&candle.subscription_id) {
+                    Ok(instrument) => vec![Ok(MarketEvent::from((
+                        Mexc::EXCHANGE,
+                        instrument,
+                        candle.data.kline,
+                    )))],
+                    Err(error) => vec![Err(error)],
+                }
+            }
+            MexcMessage::Event(event) => match event.validate() {
+                Ok(_) => vec![],
+                Err(error) => vec![Err(error)],
+            },
+        }
+    }
+}
+
+impl Mexc {
+    /// [`Mexc`] spot trades channel prefix.
+    ///
+    /// See docs: <https://mexcdevelop.github.io/apidocs/spot_v3_en/#trade-streams>
+    pub const CHANNEL_DEALS: &'static str = "spot@public.deals";
+
+    /// [`Mexc`] spot kline channel prefix.
+    ///
+    /// See docs: <https://mexcdevelop.github.io/apidocs/spot_v3_en/#kline-streams>
+    pub const CHANNEL_KLINE: &'static str = "spot@public.kline";
+
+    /// [`MexcPayload`] variant used for all [`Mexc`] subscriptions.
+    pub const PAYLOAD: MexcPayload = MexcPayload::Json;
+
+    /// Determine the [`Mexc`] channel associated with an input Barter [`Subscription`] for the
+    /// provided [`MexcPayload`] variant.
+    ///
+    /// eg/ "spot@public.deals.v3.api@BTCUSDT", "spot@public.kline.v3.api@BTCUSDT@Min15"
+    pub fn channel(sub: &Subscription, payload: MexcPayload) -> Result<String, SocketError> {
+        // Validate provided Subscription InstrumentKind & SubKind are supported by Mexc
+        let sub = sub.validate()?;
+        let symbol = Self::symbol(&sub.instrument);
+
+        match &sub.kind {
+            SubKind::Trade => Ok(format!(
+                "{}.{}@{symbol}",
+                Self::CHANNEL_DEALS,
+                payload.suffix()
+            )),
+            SubKind::Candle(interval) => Ok(format!(
+                "{}.{}@{symbol}@{}",
+                Self::CHANNEL_KLINE,
+                payload.suffix(),
+                MexcInterval::try_from(interval)?.0
+            )),
+            other => Err(SocketError::Unsupported {
+                entity: Self::EXCHANGE.as_str(),
+                item: other.to_string(),
+            }),
+        }
+    }
+
+    /// Determine the [`Mexc`] symbol for the provided [`Instrument`].
+    ///
+    /// eg/ "BTCUSDT"
+    pub fn symbol(instrument: &Instrument) -> String {
+        format!("{}{}", instrument.base, instrument.quote).to_uppercase()
+    }
+
+    /// Build a [`Mexc`] compatible subscription message for the provided channel.
+    ///
+    /// eg/ {"method":"SUBSCRIPTION","params":["spot@public.deals.v3.api@BTCUSDT"]}
+    pub fn subscription_message(channel: &str) -> WsMessage {
+        WsMessage::Text(
+            json!({
+                "method": "SUBSCRIPTION",
+                "params": [channel],
+            })
+            .to_string(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchange::mexc::model::{MexcDeal, MexcDeals, MexcStream};
+    use crate::model::{subscription::Interval, DataKind, PublicTrade};
+    use barter_integration::model::{InstrumentKind, Side};
+    use chrono::Utc;
+
+    #[test]
+    fn test_channel() {
+        struct TestCase {
+            input: (Subscription, MexcPayload),
+            expected: Result<String, SocketError>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: Supported InstrumentKind::Spot trades subscription w/ JSON payload
+                input: (
+                    Subscription::new(
+                        ExchangeId::Mexc,
+                        ("btc", "usdt", InstrumentKind::Spot),
+                        SubKind::Trade,
+                    ),
+                    MexcPayload::Json,
+                ),
+                expected: Ok("spot@public.deals.v3.api@BTCUSDT".to_owned()),
+            },
+            TestCase {
+                // TC1: Supported InstrumentKind::Spot candles subscription w/ protobuf payload
+                input: (
+                    Subscription::new(
+                        ExchangeId::Mexc,
+                        ("btc", "usdt", InstrumentKind::Spot),
+                        SubKind::Candle(Interval::Hour1),
+                    ),
+                    MexcPayload::Protobuf,
+                ),
+                expected: Ok("spot@public.kline.v3.api.pb@BTCUSDT@Min60".to_owned()),
+            },
+            TestCase {
+                // TC2: Unsupported InstrumentKind::FuturePerpetual
+                input: (
+                    Subscription::new(
+                        ExchangeId::Mexc,
+                        ("btc", "usdt", InstrumentKind::FuturePerpetual),
+                        SubKind::Trade,
+                    ),
+                    MexcPayload::Json,
+                ),
+                expected: Err(SocketError::Unsupported {
+                    entity: "mexc",
+                    item: InstrumentKind::FuturePerpetual.to_string(),
+                }),
+            },
+            TestCase {
+                // TC3: Unsupported Interval
+                input: (
+                    Subscription::new(
+                        ExchangeId::Mexc,
+                        ("btc", "usdt", InstrumentKind::Spot),
+                        SubKind::Candle(Interval::Minute3),
+                    ),
+                    MexcPayload::Json,
+                ),
+                expected: Err(SocketError::Unsupported {
+                    entity: "mexc",
+                    item: Interval::Minute3.to_string(),
+                }),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = Mexc::channel(&test.input.0, test.input.1);
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => {
+                    assert_eq!(actual, expected, "TC{} failed", index)
+                }
+                (Err(_), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_mexc_transform() {
+        let mut transformer = Mexc {
+            ids: SubscriptionIds(HashMap::from([(
+                SubscriptionId::from("spot@public.deals.v3.api@BTCUSDT"),
+                Subscription::new(
+                    ExchangeId::Mexc,
+                    ("btc", "usdt", InstrumentKind::Spot),
+                    SubKind::Trade,
+                ),
+            )])),
+        };
+
+        let time = Utc::now();
+        let deals = |subscription_id: &str| {
+            MexcMessage::Trades(MexcStream {
+                subscription_id: SubscriptionId::from(subscription_id),
+                data: MexcDeals {
+                    deals: vec![MexcDeal {
+                        side: Side::Buy,
+                        price: 100.0,
+                        quantity: 1.0,
+                        time,
+                    }],
+                },
+            })
+        };
+
+        struct TestCase {
+            input: MexcMessage,
+            expected: Vec<Option<DataKind>>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: MexcMessage Trades w/ known SubscriptionId
+                input: deals("spot@public.deals.v3.api@BTCUSDT"),
+                expected: vec![Some(DataKind::Trade(PublicTrade {
+                    id: format!("{}_{:?}_{}_{}", time, Side::Buy, 100.0, 1.0),
+                    price: 100.0,
+                    quantity: 1.0,
+                    side: Side::Buy,
+                }))],
+            },
+            TestCase {
+                // TC1: MexcMessage Trades w/ unknown SubscriptionId
+                input: deals("spot@public.deals.v3.api@ETHUSDT"),
+                expected: vec![None],
+            },
+            TestCase {
+                // TC2: MexcMessage Event w/ successful subscription is ignored
+                input: MexcMessage::Event(MexcSubResponse {
+                    id: 0,
+                    code: 0,
+                    msg: "spot@public.deals.v3.api@BTCUSDT".to_owned(),
+                }),
+                expected: vec![],
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = transformer
+                .transform(test.input)
+                .into_iter()
+                .map(|event| event.ok().map(|event| event.kind))
+                .collect::<Vec<_>>();
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
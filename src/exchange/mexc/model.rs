@@ -0,0 +1,357 @@
+use crate::{
+    exchange::{
+        de_str, de_u64_epoch_ms_as_datetime_utc, gateio::model::de_str_epoch_s_as_datetime_utc,
+    },
+    model::{subscription::Interval, Candle, DataKind, PublicTrade},
+    ExchangeId, MarketEvent,
+};
+use barter_integration::{
+    error::SocketError,
+    model::{Exchange, Instrument, Side, SubscriptionId},
+    Validator,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Deserializer, Serialize};
+
+/// [`Mexc`](super::Mexc) stream payload encoding, selected via the channel suffix.
+///
+/// MEXC serves each public stream as either JSON (eg/ "spot@public.deals.v3.api@BTCUSDT") or
+/// protobuf (eg/ "spot@public.deals.v3.api.pb@BTCUSDT"). Only [`MexcPayload::Json`] can be
+/// deserialised by this crate, so it is the variant used by [`Mexc`](super::Mexc).
+///
+/// See docs: <https://mexcdevelop.github.io/apidocs/spot_v3_en/#websocket-market-streams>
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum MexcPayload {
+    Json,
+    Protobuf,
+}
+
+impl MexcPayload {
+    /// Channel version suffix associated with this [`MexcPayload`].
+    pub fn suffix(&self) -> &'static str {
+        match self {
+            MexcPayload::Json => "v3.api",
+            MexcPayload::Protobuf => "v3.api.pb",
+        }
+    }
+}
+
+/// [`Mexc`](super::Mexc) message received in response to WebSocket subscription requests. The
+/// `msg` echoes the subscribed channel on success.
+///
+/// eg/ {"id":0,"code":0,"msg":"spot@public.deals.v3.api@BTCUSDT"}
+/// eg/ {"id":0,"code":0,"msg":"Not Subscribed successfully! [spot@public.deals.v3.api@BTCUSD].  Reason： Blocked! "}
+///
+/// See docs: <https://mexcdevelop.github.io/apidocs/spot_v3_en/#subscribe-to-a-stream>
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct MexcSubResponse {
+    pub id: u64,
+    pub code: i64,
+    pub msg: String,
+}
+
+impl MexcSubResponse {
+    /// Prefix of the `msg` MEXC sends (alongside code 0) when a subscription is rejected.
+    pub const FAILURE_PREFIX: &'static str = "Not Subscribed";
+}
+
+impl Validator for MexcSubResponse {
+    fn validate(self) -> Result<Self, SocketError>
+    where
+        Self: Sized,
+    {
+        if self.code == 0 && !self.msg.starts_with(Self::FAILURE_PREFIX) {
+            Ok(self)
+        } else {
+            Err(SocketError::Subscribe(format!(
+                "received failure subscription response code: {} with message: {}",
+                self.code, self.msg
+            )))
+        }
+    }
+}
+
+/// [`Mexc`](super::Mexc) kline interval used in `spot@public.kline` subscriptions.
+///
+/// See docs: <https://mexcdevelop.github.io/apidocs/spot_v3_en/#kline-streams>
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct MexcInterval(pub &'static str);
+
+impl TryFrom<&Interval> for MexcInterval {
+    type Error = SocketError;
+
+    fn try_from(interval: &Interval) -> Result<Self, Self::Error> {
+        let interval = match interval {
+            Interval::Minute1 => "Min1",
+            Interval::Minute5 => "Min5",
+            Interval::Minute15 => "Min15",
+            Interval::Minute30 => "Min30",
+            Interval::Hour1 => "Min60",
+            Interval::Hour4 => "Hour4",
+            Interval::Hour8 => "Hour8",
+            Interval::Day1 => "Day1",
+            Interval::Week1 => "Week1",
+            Interval::Month1 => "Month1",
+            other => {
+                return Err(SocketError::Unsupported {
+                    entity: ExchangeId::Mexc.as_str(),
+                    item: other.to_string(),
+                })
+            }
+        };
+
+        Ok(Self(interval))
+    }
+}
+
+/// [`Mexc`](super::Mexc) message variants that can be received over [`WebSocket`].
+///
+/// See docs: <https://mexcdevelop.github.io/apidocs/spot_v3_en/#websocket-market-streams>
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum MexcMessage {
+    Trades(MexcStream<MexcDeals>),
+    Candle(MexcStream<MexcKlineData>),
+    Event(MexcSubResponse),
+}
+
+/// [`Mexc`](super::Mexc) stream message, where the channel `c` identifies the subscription.
+///
+/// eg/ {"c":"spot@public.deals.v3.api@BTCUSDT","d":{...},"s":"BTCUSDT","t":1661927587825}
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct MexcStream<T> {
+    #[serde(rename = "c")]
+    pub subscription_id: SubscriptionId,
+    #[serde(rename = "d")]
+    pub data: T,
+}
+
+/// [`Mexc`](super::Mexc) `spot@public.deals` payload.
+///
+/// eg/ {"deals":[{"S":2,"p":"20233.84","t":1661927587825,"v":"0.001028"}],"e":"spot@public.deals.v3.api"}
+///
+/// See docs: <https://mexcdevelop.github.io/apidocs/spot_v3_en/#trade-streams>
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct MexcDeals {
+    pub deals: Vec<MexcDeal>,
+}
+
+/// [`Mexc`](super::Mexc) public trade, where the trade type `S` is 1 for buy & 2 for sell.
+///
+/// eg/ {"S":2,"p":"20233.84","t":1661927587825,"v":"0.001028"}
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct MexcDeal {
+    #[serde(rename = "S", deserialize_with = "de_side_from_trade_type")]
+    pub side: Side,
+    #[serde(rename = "p", deserialize_with = "de_str")]
+    pub price: f64,
+    #[serde(rename = "v", deserialize_with = "de_str")]
+    pub quantity: f64,
+    #[serde(rename = "t", deserialize_with = "de_u64_epoch_ms_as_datetime_utc")]
+    pub time: DateTime<Utc>,
+}
+
+/// [`Mexc`](super::Mexc) `spot@public.kline` payload.
+///
+/// See docs: <https://mexcdevelop.github.io/apidocs/spot_v3_en/#kline-streams>
+#[derive(Copy, Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct MexcKlineData {
+    #[serde(rename = "k")]
+    pub kline: MexcKline,
+}
+
+/// [`Mexc`](super::Mexc) kline, with start & end times in epoch seconds. The `a` field is the
+/// quote volume, while `v` is the base volume.
+///
+/// eg/ {"T":1661931900,"a":29043.48804658,"c":20279.43,"h":20284.93,"i":"Min15","l":20277.52,
+///      "o":20284.93,"t":1661931000,"v":1.43211}
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct MexcKline {
+    #[serde(rename = "t", deserialize_with = "de_str_epoch_s_as_datetime_utc")]
+    pub start_time: DateTime<Utc>,
+    #[serde(rename = "T", deserialize_with = "de_str_epoch_s_as_datetime_utc")]
+    pub end_time: DateTime<Utc>,
+    #[serde(rename = "o", deserialize_with = "de_str")]
+    pub open: f64,
+    #[serde(rename = "h", deserialize_with = "de_str")]
+    pub high: f64,
+    #[serde(rename = "l", deserialize_with = "de_str")]
+    pub low: f64,
+    #[serde(rename = "c", deserialize_with = "de_str")]
+    pub close: f64,
+    #[serde(rename = "v", deserialize_with = "de_str")]
+    pub volume: f64,
+}
+
+impl From<(ExchangeId, Instrument, MexcDeal)> for MarketEvent {
+    fn from((exchange_id, instrument, deal): (ExchangeId, Instrument, MexcDeal)) -> Self {
+        // mexc deals do not come with a unique identifier, so generate a custom one
+        let custom_trade_id = format!(
+            "{}_{:?}_{}_{}",
+            deal.time, deal.side, deal.price, deal.quantity
+        );
+
+        Self {
+            exchange_time: deal.time,
+            received_time: Utc::now(),
+            received_instant: None,
+            raw: None,
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: DataKind::Trade(PublicTrade {
+                id: custom_trade_id,
+                price: deal.price,
+                quantity: deal.quantity,
+                side: deal.side,
+            }),
+        }
+    }
+}
+
+impl From<(ExchangeId, Instrument, MexcKline)> for MarketEvent {
+    fn from((exchange_id, instrument, kline): (ExchangeId, Instrument, MexcKline)) -> Self {
+        Self {
+            exchange_time: kline.end_time,
+            received_time: Utc::now(),
+            received_instant: None,
+            raw: None,
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: DataKind::Candle(Candle {
+                start_time: kline.start_time,
+                end_time: kline.end_time,
+                open: kline.open,
+                high: kline.high,
+                low: kline.low,
+                close: kline.close,
+                volume: kline.volume,
+                trade_count: 0,
+            }),
+        }
+    }
+}
+
+/// Deserialize a [`Mexc`](super::Mexc) trade type (1 = buy, 2 = sell) as the associated [`Side`].
+pub fn de_side_from_trade_type<'de, D>(deserializer: D) -> Result<Side, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match de_str::<D, u8>(deserializer)? {
+        1 => Ok(Side::Buy),
+        2 => Ok(Side::Sell),
+        other => Err(serde::de::Error::invalid_value(
+            serde::de::Unexpected::Unsigned(other as u64),
+            &"1 (buy) or 2 (sell)",
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchange::datetime_utc_from_epoch_duration;
+    use std::time::Duration;
+
+    #[test]
+    fn test_deserialise_mexc_sub_response() {
+        struct TestCase {
+            input: &'static str,
+            expected: Result<MexcSubResponse, SocketError>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: input response is successful subscription
+                input: r#"{"id":0,"code":0,"msg":"spot@public.deals.v3.api@BTCUSDT"}"#,
+                expected: Ok(MexcSubResponse {
+                    id: 0,
+                    code: 0,
+                    msg: "spot@public.deals.v3.api@BTCUSDT".to_owned(),
+                }),
+            },
+            TestCase {
+                // TC1: input response is failed subscription
+                input: r#"{"id":0,"code":0,"msg":"Not Subscribed successfully! [spot@public.deals.v3.api@BTCUSD].  Reason： Blocked! "}"#,
+                expected: Err(SocketError::Subscribe("".to_owned())),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = serde_json::from_str::<MexcSubResponse>(test.input)
+                .map_err(|error| SocketError::Subscribe(error.to_string()))
+                .and_then(MexcSubResponse::validate);
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => {
+                    assert_eq!(actual, expected, "TC{} failed", index)
+                }
+                (Err(_), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_deserialise_mexc_message() {
+        struct TestCase {
+            input: &'static str,
+            expected: Option<MexcMessage>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: input is a valid deals message
+                input: r#"{"c":"spot@public.deals.v3.api@BTCUSDT","d":{"deals":[{"S":2,"p":"20233.84","t":1661927587825,"v":"0.001028"}],"e":"spot@public.deals.v3.api"},"s":"BTCUSDT","t":1661927587836}"#,
+                expected: Some(MexcMessage::Trades(MexcStream {
+                    subscription_id: SubscriptionId::from("spot@public.deals.v3.api@BTCUSDT"),
+                    data: MexcDeals {
+                        deals: vec![MexcDeal {
+                            side: Side::Sell,
+                            price: 20233.84,
+                            quantity: 0.001028,
+                            time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                                1661927587825,
+                            )),
+                        }],
+                    },
+                })),
+            },
+            TestCase {
+                // TC1: input is a valid kline message
+                input: r#"{"c":"spot@public.kline.v3.api@BTCUSDT@Min15","d":{"k":{"T":1661931900,"a":29043.48804658,"c":20279.43,"h":20284.93,"i":"Min15","l":20277.52,"o":20284.93,"t":1661931000,"v":1.43211},"e":"spot@public.kline.v3.api"},"s":"BTCUSDT","t":1661931016878}"#,
+                expected: Some(MexcMessage::Candle(MexcStream {
+                    subscription_id: SubscriptionId::from("spot@public.kline.v3.api@BTCUSDT@Min15"),
+                    data: MexcKlineData {
+                        kline: MexcKline {
+                            start_time: datetime_utc_from_epoch_duration(Duration::from_secs(
+                                1661931000,
+                            )),
+                            end_time: datetime_utc_from_epoch_duration(Duration::from_secs(
+                                1661931900,
+                            )),
+                            open: 20284.93,
+                            high: 20284.93,
+                            low: 20277.52,
+                            close: 20279.43,
+                            volume: 1.43211,
+                        },
+                    },
+                })),
+            },
+            TestCase {
+                // TC2: input is an invalid trade type
+                input: r#"{"c":"spot@public.deals.v3.api@BTCUSDT","d":{"deals":[{"S":3,"p":"20233.84","t":1661927587825,"v":"0.001028"}],"e":"spot@public.deals.v3.api"},"s":"BTCUSDT","t":1661927587836}"#,
+                expected: None,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = serde_json::from_str::<MexcMessage>(test.input).ok();
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
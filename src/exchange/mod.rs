@@ -1,42 +1,338 @@
 use chrono::{DateTime, Utc};
 use serde::ser::SerializeSeq;
 use serde::{de, Serialize};
-use std::{str::FromStr, time::Duration};
+use std::{marker::PhantomData, str::FromStr, time::Duration};
 
 /// Binance `ExchangeTransformer` & `Subscriber` implementations.
 pub mod binance;
 
+/// Bitstamp `ExchangeTransformer` & `Subscriber` implementations.
+pub mod bitstamp;
+
+/// Bybit `ExchangeTransformer` & `Subscriber` implementations.
+pub mod bybit;
+
+/// Deribit `ExchangeTransformer` & `Subscriber` implementations.
+pub mod deribit;
+
 /// Ftx `ExchangeTransformer` & `Subscriber` implementations.
 pub mod ftx;
 
 /// Kraken `ExchangeTransformer` & `Subscriber` implementations.
 pub mod kraken;
 
+/// Okx `ExchangeTransformer` & `Subscriber` implementations.
+pub mod okx;
+
+/// Kucoin `ExchangeTransformer` & `Subscriber` implementations.
+pub mod kucoin;
+
 /// Coinbase `ExchangeTransformer` & `Subscriber` implementations.
 pub mod coinbase;
 
+/// Gateio `ExchangeTransformer` & `Subscriber` implementations.
+pub mod gateio;
+
+/// Bitmex `ExchangeTransformer` & `Subscriber` implementations.
+pub mod bitmex;
+
+/// Gemini `ExchangeTransformer` & `Subscriber` implementations.
+pub mod gemini;
+
+/// Cryptocom `ExchangeTransformer` & `Subscriber` implementations.
+pub mod cryptocom;
+
+/// Mexc `ExchangeTransformer` & `Subscriber` implementations.
+pub mod mexc;
+
+/// Bitget `ExchangeTransformer` & `Subscriber` implementations.
+pub mod bitget;
+
+/// Dydx `ExchangeTransformer` & `Subscriber` implementations.
+pub mod dydx;
+
+/// Hyperliquid `ExchangeTransformer` & `Subscriber` implementations.
+pub mod hyperliquid;
+
+/// CoinbaseAdvanced `ExchangeTransformer` & `Subscriber` implementations.
+pub mod coinbase_advanced;
+
+/// KrakenFutures `ExchangeTransformer` & `Subscriber` implementations.
+pub mod kraken_futures;
+
+/// Upbit `ExchangeTransformer` & `Subscriber` implementations.
+pub mod upbit;
+
+/// Bithumb `ExchangeTransformer` & `Subscriber` implementations.
+pub mod bithumb;
+
+/// Poloniex `ExchangeTransformer` & `Subscriber` implementations.
+pub mod poloniex;
+
+/// Bitfinex `ExchangeTransformer` & `Subscriber` implementations.
+pub mod bitfinex;
+
 /// Determine the `DateTime<Utc>` from the provided `Duration` since the epoch.
 pub fn datetime_utc_from_epoch_duration(duration: Duration) -> DateTime<Utc> {
     DateTime::<Utc>::from(std::time::UNIX_EPOCH + duration)
 }
 
-/// Deserialize a `String` as the desired type.
+/// Calculate the CRC32 (IEEE) checksum of the provided bytes.
+pub fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for byte in bytes {
+        crc ^= *byte as u32;
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xEDB8_8320 & mask);
+        }
+    }
+    !crc
+}
+
+/// Numeric value tolerantly deserialised from either a number or a `String` containing a number,
+/// including scientific notation (eg/ 0.1, "0.1", 1e-8, "1E-8").
+///
+/// Exchanges change the representation of numeric fields without notice, so every client model
+/// deserialises numerics via this type (or the [`de_str`] & [`de_str_opt`] helpers using it).
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct Numeric<T>(pub T);
+
+/// Numeric type that a [`Numeric`] is converted into. Numbers are converted directly, so only
+/// `String` inputs are parsed via [`FromStr`].
+pub trait FromNumber: FromStr + Sized {
+    fn from_u64(value: u64) -> Option<Self>;
+    fn from_i64(value: i64) -> Option<Self>;
+    fn from_f64(value: f64) -> Option<Self>;
+}
+
+macro_rules! impl_from_number_int {
+    ($($int:ty),*) => {$(
+        impl FromNumber for $int {
+            fn from_u64(value: u64) -> Option<Self> {
+                Self::try_from(value).ok()
+            }
+
+            fn from_i64(value: i64) -> Option<Self> {
+                Self::try_from(value).ok()
+            }
+
+            fn from_f64(value: f64) -> Option<Self> {
+                // Integers sent in scientific notation (eg/ 1e1) are deserialised as floats
+                (value.fract() == 0.0 && value >= Self::MIN as f64 && value <= Self::MAX as f64)
+                    .then_some(value as Self)
+            }
+        }
+    )*};
+}
+
+impl_from_number_int!(u8, u16, u32, u64, usize, i8, i16, i32, i64);
+
+impl FromNumber for f64 {
+    fn from_u64(value: u64) -> Option<Self> {
+        Some(value as f64)
+    }
+
+    fn from_i64(value: i64) -> Option<Self> {
+        Some(value as f64)
+    }
+
+    fn from_f64(value: f64) -> Option<Self> {
+        Some(value)
+    }
+}
+
+impl FromNumber for f32 {
+    fn from_u64(value: u64) -> Option<Self> {
+        Some(value as f32)
+    }
+
+    fn from_i64(value: i64) -> Option<Self> {
+        Some(value as f32)
+    }
+
+    fn from_f64(value: f64) -> Option<Self> {
+        Some(value as f32)
+    }
+}
+
+/// Identifiers (eg/ trade ids) sent as either a number or a `String`.
+impl FromNumber for String {
+    fn from_u64(value: u64) -> Option<Self> {
+        Some(value.to_string())
+    }
+
+    fn from_i64(value: i64) -> Option<Self> {
+        Some(value.to_string())
+    }
+
+    fn from_f64(value: f64) -> Option<Self> {
+        Some(value.to_string())
+    }
+}
+
+/// [`de::Visitor`] of a [`Numeric`].
+struct NumericVisitor<T>(PhantomData<T>);
+
+impl<T> NumericVisitor<T> {
+    fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<'de, T> de::Visitor<'de> for NumericVisitor<T>
+where
+    T: FromNumber,
+    T::Err: std::fmt::Display,
+{
+    type Value = Numeric<T>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        formatter.write_str("a number or a String containing a number")
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        value.trim().parse().map(Numeric).map_err(E::custom)
+    }
+
+    fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        T::from_u64(value)
+            .map(Numeric)
+            .ok_or_else(|| E::invalid_value(de::Unexpected::Unsigned(value), &self))
+    }
+
+    fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        T::from_i64(value)
+            .map(Numeric)
+            .ok_or_else(|| E::invalid_value(de::Unexpected::Signed(value), &self))
+    }
+
+    fn visit_f64<E>(self, value: f64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        T::from_f64(value)
+            .map(Numeric)
+            .ok_or_else(|| E::invalid_value(de::Unexpected::Float(value), &self))
+    }
+}
+
+impl<'de, T> de::Deserialize<'de> for Numeric<T>
+where
+    T: FromNumber,
+    T::Err: std::fmt::Display,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        deserializer.deserialize_any(NumericVisitor::new())
+    }
+}
+
+/// Deserialize a number or a `String` containing a number as the desired type.
 pub fn de_str<'de, D, T>(deserializer: D) -> Result<T, D::Error>
 where
     D: de::Deserializer<'de>,
-    T: FromStr,
+    T: FromNumber,
     T::Err: std::fmt::Display,
 {
-    let data: String = de::Deserialize::deserialize(deserializer)?;
-    data.parse::<T>().map_err(de::Error::custom)
+    <Numeric<T> as de::Deserialize>::deserialize(deserializer).map(|Numeric(value)| value)
 }
 
-/// Deserialize a `u64` as `DateTime<Utc>`.
+/// Deserialize an optional number or `String` containing a number as the desired type, where a
+/// null or empty `String` is `None`. Use with `#[serde(default)]` to also tolerate absent fields.
+pub fn de_str_opt<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: de::Deserializer<'de>,
+    T: FromNumber,
+    T::Err: std::fmt::Display,
+{
+    struct OptionVisitor<T>(PhantomData<T>);
+
+    impl<'de, T> de::Visitor<'de> for OptionVisitor<T>
+    where
+        T: FromNumber,
+        T::Err: std::fmt::Display,
+    {
+        type Value = Option<T>;
+
+        fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            formatter.write_str("null, or a number or a String containing a number")
+        }
+
+        fn visit_none<E>(self) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            Ok(None)
+        }
+
+        fn visit_unit<E>(self) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            Ok(None)
+        }
+
+        fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+        where
+            D: de::Deserializer<'de>,
+        {
+            deserializer.deserialize_any(self)
+        }
+
+        fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            match value.trim().is_empty() {
+                true => Ok(None),
+                false => de::Visitor::visit_str(NumericVisitor::new(), value)
+                    .map(|Numeric(value)| Some(value)),
+            }
+        }
+
+        fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            de::Visitor::visit_u64(NumericVisitor::new(), value).map(|Numeric(value)| Some(value))
+        }
+
+        fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            de::Visitor::visit_i64(NumericVisitor::new(), value).map(|Numeric(value)| Some(value))
+        }
+
+        fn visit_f64<E>(self, value: f64) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            de::Visitor::visit_f64(NumericVisitor::new(), value).map(|Numeric(value)| Some(value))
+        }
+    }
+
+    deserializer.deserialize_option(OptionVisitor(PhantomData))
+}
+
+/// Deserialize a `u64` (or a `String` containing a `u64`) as `DateTime<Utc>`.
 pub fn de_u64_epoch_ms_as_datetime_utc<'de, D>(deserializer: D) -> Result<DateTime<Utc>, D::Error>
 where
     D: de::Deserializer<'de>,
 {
-    let epoch_ms: u64 = de::Deserialize::deserialize(deserializer)?;
+    let epoch_ms: u64 = de_str(deserializer)?;
     Ok(datetime_utc_from_epoch_duration(Duration::from_millis(
         epoch_ms,
     )))
@@ -72,3 +368,98 @@ where
     sequence.serialize_element(&element)?;
     sequence.end()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Numerics {
+        #[serde(deserialize_with = "de_str")]
+        price: f64,
+        #[serde(deserialize_with = "de_str")]
+        id: u64,
+        #[serde(default, deserialize_with = "de_str_opt")]
+        optional: Option<f64>,
+    }
+
+    #[test]
+    fn test_de_numerics() {
+        struct TestCase {
+            input: &'static str,
+            expected: Option<Numerics>,
+        }
+
+        let numerics = |price, id, optional| {
+            Some(Numerics {
+                price,
+                id,
+                optional,
+            })
+        };
+
+        let cases = vec![
+            TestCase {
+                // TC0: numerics sent as Strings
+                input: r#"{"price":"0.5","id":"10","optional":"1.5"}"#,
+                expected: numerics(0.5, 10, Some(1.5)),
+            },
+            TestCase {
+                // TC1: numerics sent as numbers
+                input: r#"{"price":0.5,"id":10,"optional":1.5}"#,
+                expected: numerics(0.5, 10, Some(1.5)),
+            },
+            TestCase {
+                // TC2: scientific notation sent as a String & as a number
+                input: r#"{"price":"5E-1","id":1e1,"optional":1.5e0}"#,
+                expected: numerics(0.5, 10, Some(1.5)),
+            },
+            TestCase {
+                // TC3: null optional numeric
+                input: r#"{"price":0.5,"id":10,"optional":null}"#,
+                expected: numerics(0.5, 10, None),
+            },
+            TestCase {
+                // TC4: empty String optional numeric
+                input: r#"{"price":0.5,"id":10,"optional":""}"#,
+                expected: numerics(0.5, 10, None),
+            },
+            TestCase {
+                // TC5: absent optional numeric
+                input: r#"{"price":0.5,"id":10}"#,
+                expected: numerics(0.5, 10, None),
+            },
+            TestCase {
+                // TC6: non-numeric String is invalid
+                input: r#"{"price":"price","id":10}"#,
+                expected: None,
+            },
+            TestCase {
+                // TC7: null required numeric is invalid
+                input: r#"{"price":null,"id":10}"#,
+                expected: None,
+            },
+            TestCase {
+                // TC8: fractional integer is invalid
+                input: r#"{"price":0.5,"id":10.5}"#,
+                expected: None,
+            },
+            TestCase {
+                // TC9: negative integer is invalid
+                input: r#"{"price":0.5,"id":-10}"#,
+                expected: None,
+            },
+            TestCase {
+                // TC10: integer numeric converted to a float
+                input: r#"{"price":1,"id":10,"optional":-2}"#,
+                expected: numerics(1.0, 10, Some(-2.0)),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = serde_json::from_str::<Numerics>(test.input).ok();
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
@@ -0,0 +1,183 @@
+use super::model::{OkxOrderBookData, OkxOrderBookLevel};
+use crate::{
+    exchange::crc32,
+    model::{parse_level_value, upsert_parsed_level, Level, OrderBook, ParsedLevel},
+    ExchangeId,
+};
+use barter_integration::error::SocketError;
+use chrono::{DateTime, Utc};
+
+/// [`Okx`](super::Okx) L2 order book maintained from the tick-by-tick `books-l2-tbt` channel.
+///
+/// Levels retain the original `String` price & size sent by [`Okx`](super::Okx), since these are
+/// required to validate the CRC32 checksum sent with every snapshot & update.
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#order-book-trading-market-data-ws-checksum>
+#[derive(Clone, PartialEq, Debug)]
+pub struct OkxOrderBook {
+    pub last_update_time: DateTime<Utc>,
+    pub sequence: u64,
+    /// Bid levels ordered from the highest price.
+    pub bids: Vec<ParsedLevel<OkxOrderBookLevel>>,
+    /// Ask levels ordered from the lowest price.
+    pub asks: Vec<ParsedLevel<OkxOrderBookLevel>>,
+}
+
+impl OkxOrderBook {
+    /// Number of bid & ask levels included in the [`Okx`](super::Okx) order book checksum.
+    pub const CHECKSUM_DEPTH: usize = 25;
+
+    /// Construct a new [`OkxOrderBook`] from an [`OkxOrderBookData`] snapshot.
+    pub fn snapshot(data: OkxOrderBookData) -> Result<Self, SocketError> {
+        let mut book = Self {
+            last_update_time: data.time,
+            sequence: data.sequence,
+            bids: Vec::with_capacity(data.bids.len()),
+            asks: Vec::with_capacity(data.asks.len()),
+        };
+        book.apply(data)?;
+        Ok(book)
+    }
+
+    /// Apply the levels of the provided [`OkxOrderBookData`] update to this [`OkxOrderBook`],
+    /// replacing the size of existing price levels & removing zero size price levels.
+    pub fn apply(&mut self, data: OkxOrderBookData) -> Result<(), SocketError> {
+        for level in data.bids {
+            let (level, size) = parse(level)?;
+            upsert_parsed_level(&mut self.bids, level, size, |existing, new| {
+                new.total_cmp(existing)
+            });
+        }
+        for level in data.asks {
+            let (level, size) = parse(level)?;
+            upsert_parsed_level(&mut self.asks, level, size, |existing, new| {
+                existing.total_cmp(new)
+            });
+        }
+
+        self.last_update_time = data.time;
+        self.sequence = data.sequence;
+        Ok(())
+    }
+
+    /// Calculate the [`Okx`](super::Okx) checksum of this [`OkxOrderBook`].
+    ///
+    /// The top [`Self::CHECKSUM_DEPTH`] bid & ask levels are alternated into a colon separated
+    /// "bid price:bid size:ask price:ask size" `String`, and the CRC32 of this is interpreted as
+    /// a signed 32 bit integer.
+    pub fn checksum(&self) -> i32 {
+        let mut fields = Vec::with_capacity(Self::CHECKSUM_DEPTH * 4);
+        for index in 0..Self::CHECKSUM_DEPTH {
+            for levels in [&self.bids, &self.asks] {
+                if let Some(level) = levels.get(index) {
+                    fields.push(level.level.price.as_str());
+                    fields.push(level.level.size.as_str());
+                }
+            }
+        }
+
+        crc32(fields.join(":").as_bytes()) as i32
+    }
+}
+
+impl TryFrom<&OkxOrderBook> for OrderBook {
+    type Error = SocketError;
+
+    fn try_from(book: &OkxOrderBook) -> Result<Self, Self::Error> {
+        let levels = |levels: &[ParsedLevel<OkxOrderBookLevel>]| {
+            levels
+                .iter()
+                .map(|level| {
+                    let size = parse_level_value(ExchangeId::Okx, &level.level.size)?;
+                    Ok(Level::new(level.price, size))
+                })
+                .collect::<Result<Vec<_>, SocketError>>()
+        };
+
+        Ok(Self {
+            last_update_time: book.last_update_time,
+            last_update_id: book.sequence,
+            bids: levels(&book.bids)?,
+            asks: levels(&book.asks)?,
+        })
+    }
+}
+
+/// Parse the price & size of the provided [`OkxOrderBookLevel`], keying it by it's parsed price.
+fn parse(level: OkxOrderBookLevel) -> Result<(ParsedLevel<OkxOrderBookLevel>, f64), SocketError> {
+    let price = parse_level_value(ExchangeId::Okx, &level.price)?;
+    let size = parse_level_value(ExchangeId::Okx, &level.size)?;
+    Ok((ParsedLevel { price, level }, size))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn level(price: &str, size: &str) -> OkxOrderBookLevel {
+        OkxOrderBookLevel {
+            price: price.to_owned(),
+            size: size.to_owned(),
+            liquidated_orders: "0".to_owned(),
+            orders: "1".to_owned(),
+        }
+    }
+
+    fn data(bids: Vec<OkxOrderBookLevel>, asks: Vec<OkxOrderBookLevel>) -> OkxOrderBookData {
+        OkxOrderBookData {
+            asks,
+            bids,
+            time: Utc::now(),
+            checksum: 0,
+            sequence: 0,
+        }
+    }
+
+    #[test]
+    fn test_okx_order_book_checksum() {
+        struct TestCase {
+            input: Vec<OkxOrderBookData>,
+            expected: i32,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: snapshot w/ equal bid & ask depth (example from the Okx docs)
+                input: vec![data(
+                    vec![level("3366.1", "7"), level("3366", "6")],
+                    vec![level("3366.8", "9"), level("3368", "8")],
+                )],
+                expected: -1881014294,
+            },
+            TestCase {
+                // TC1: snapshot w/ more bid levels than ask levels
+                input: vec![data(
+                    vec![level("3366.1", "7"), level("3366", "6"), level("3365", "2")],
+                    vec![level("3366.8", "9"), level("3368", "8")],
+                )],
+                expected: 202671967,
+            },
+            TestCase {
+                // TC2: update inserting a bid level, then removing an ask level
+                input: vec![
+                    data(
+                        vec![level("3366.1", "7"), level("3366", "6")],
+                        vec![level("3366.8", "9"), level("3368", "8")],
+                    ),
+                    data(vec![level("3365", "2")], vec![]),
+                    data(vec![], vec![level("3368", "0")]),
+                ],
+                expected: 1743553959,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let mut input = test.input.into_iter();
+            let mut book = OkxOrderBook::snapshot(input.next().unwrap()).unwrap();
+            for update in input {
+                book.apply(update).unwrap();
+            }
+            assert_eq!(book.checksum(), test.expected, "TC{} failed", index);
+        }
+    }
+}
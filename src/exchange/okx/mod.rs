@@ -0,0 +1,1045 @@
+use crate::{
+    model::{
+        subscription::{SubKind, SubscriptionIds, SubscriptionMeta},
+        DataKind, OrderBook,
+    },
+    orderbook::{ChecksumPolicy, ChecksumValidation},
+    ExchangeId, ExchangeTransformer, MarketEvent, Subscriber, Subscription, Validator,
+};
+use barter_integration::{
+    error::SocketError,
+    model::{Exchange, Instrument, InstrumentKind, SubscriptionId},
+    protocol::websocket::WsMessage,
+    Transformer,
+};
+use book::OkxOrderBook;
+use chrono::Utc;
+use model::{
+    OkxBar, OkxMessage, OkxOrderBookAction, OkxOrderBookMessage, OkxSubResponse, OkxSubscription,
+};
+use serde_json::json;
+use std::collections::HashMap;
+use tokio::sync::mpsc;
+use tracing::debug;
+
+/// [`Okx`] specific data structures.
+pub mod model;
+
+/// [`OkxOrderBook`] L2 order book maintained from the tick-by-tick `books-l2-tbt` channel &
+/// validated using the [`Okx`] CRC32 checksum.
+pub mod book;
+
+/// [`OkxSandbox`](sandbox::OkxSandbox) [`Subscriber`] & [`ExchangeTransformer`] implementor for
+/// the OKX demo trading environment.
+pub mod sandbox;
+
+/// [`Okx`] [`Subscriber`] & [`ExchangeTransformer`] implementor for the collection
+/// of `Spot` & `FuturePerpetual` data via the OKX v5 public WebSocket API.
+///
+/// [`SubKind::OrderBookL2Managed`] channels maintain an [`OkxOrderBook`] from the tick-by-tick
+/// `books-l2-tbt` snapshot & updates, which is delivered in full after every update. If the
+/// checksum of the maintained [`OkxOrderBook`] does not match the checksum sent by [`Okx`], the
+/// channel is re-subscribed to in order to receive a fresh snapshot, unless configured otherwise
+/// via the [`ChecksumPolicy`].
+///
+/// `funding-rate` & `open-interest` channels are batched into shared subscription requests of at
+/// most [`Okx::MAX_ARGS_PER_REQUEST`] args.
+#[derive(Debug)]
+pub struct Okx {
+    pub ids: SubscriptionIds,
+    pub books: HashMap<SubscriptionId, OkxOrderBook>,
+    pub checksum_policy: ChecksumPolicy,
+    pub ws_sink_tx: mpsc::UnboundedSender<WsMessage>,
+}
+
+impl Subscriber for Okx {
+    type SubResponse = OkxSubResponse;
+
+    fn base_url() -> &'static str {
+        ExchangeId::Okx.ws_base_url()
+    }
+
+    fn build_subscription_meta(
+        subscriptions: &[Subscription],
+    ) -> Result<SubscriptionMeta, SocketError> {
+        // Allocate SubscriptionIds HashMap to track identifiers for each actioned Subscription
+        let mut ids = SubscriptionIds(HashMap::with_capacity(subscriptions.len()));
+
+        // Map Barter Subscriptions to OkxSubscriptions
+        let okx_subscriptions = subscriptions
+            .iter()
+            .map(|subscription| {
+                // Translate Barter Subscription to the associated OkxSubscription
+                let okx_subscription = Self::subscription(subscription)?;
+
+                // Use "channel|market" as the SubscriptionId key in the SubscriptionIds
+                // eg/ SubscriptionId("trades|BTC-USDT")
+                ids.insert(
+                    SubscriptionId::from(&okx_subscription),
+                    subscription.clone(),
+                );
+
+                Ok(okx_subscription)
+            })
+            .collect::<Result<Vec<_>, SocketError>>()?;
+
+        // Okx sends a subscription response for every arg, including batched args
+        let expected_responses = okx_subscriptions.len();
+
+        // Batch funding-rate & open-interest args into shared subscription messages
+        let (batched, individual): (Vec<_>, Vec<_>) =
+            okx_subscriptions.into_iter().partition(|subscription| {
+                subscription.channel == Self::CHANNEL_FUNDING_RATE
+                    || subscription.channel == Self::CHANNEL_OPEN_INTEREST
+            });
+
+        // Construct Okx specific subscription messages
+        let subscriptions = individual
+            .iter()
+            .map(Self::subscription_message)
+            .chain(
+                batched
+                    .chunks(Self::MAX_ARGS_PER_REQUEST)
+                    .map(|chunk| Self::batch_request("subscribe", chunk)),
+            )
+            .collect();
+
+        Ok(SubscriptionMeta {
+            ids,
+            expected_responses,
+            subscriptions,
+        })
+    }
+}
+
+impl ExchangeTransformer for Okx {
+    const EXCHANGE: ExchangeId = ExchangeId::Okx;
+
+    fn new(ws_sink_tx: mpsc::UnboundedSender<WsMessage>, ids: SubscriptionIds) -> Self {
+        Self {
+            ids,
+            books: HashMap::new(),
+            checksum_policy: ChecksumPolicy::default(),
+            ws_sink_tx,
+        }
+    }
+}
+
+impl ChecksumValidation for Okx {
+    fn set_checksum_policy(&mut self, policy: ChecksumPolicy) {
+        self.checksum_policy = policy;
+    }
+}
+
+impl Transformer<MarketEvent> for Okx {
+    type Input = OkxMessage;
+    type OutputIter = Vec<Result<MarketEvent, SocketError>>;
+
+    fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+        match input {
+            OkxMessage::Trades(trades) => {
+                // Determine Instrument associated with this OkxTrades message
+                let subscription_id = SubscriptionId::from(&trades.subscription);
+                let instrument = match self.ids.find_instrument(&subscription_id) {
+                    Ok(instrument) => instrument,
+                    Err(error) => return vec![Err(error)],
+                };
+
+                // Map to MarketEvents
+                trades
+                    .data
+                    .into_iter()
+                    .map(|trade| {
+                        Ok(MarketEvent::from((
+                            Okx::EXCHANGE,
+                            instrument.clone(),
+                            trade,
+                        )))
+                    })
+                    .collect()
+            }
+            OkxMessage::Candles(candles) => {
+                // Determine Instrument & Interval associated with this OkxCandles message
+                let subscription_id = SubscriptionId::from(&candles.subscription);
+                let (instrument, interval) = match self.ids.get(&subscription_id) {
+                    Some(Subscription {
+                        instrument,
+                        kind: SubKind::Candle(interval),
+                        ..
+                    }) => (instrument.clone(), *interval),
+                    _ => return vec![Err(SocketError::Unidentifiable(subscription_id))],
+                };
+
+                // Map to MarketEvents
+                candles
+                    .data
+                    .into_iter()
+                    .map(|candle| {
+                        Ok(MarketEvent::from((
+                            Okx::EXCHANGE,
+                            instrument.clone(),
+                            interval,
+                            candle,
+                        )))
+                    })
+                    .collect()
+            }
+            OkxMessage::FundingRates(funding_rates) => {
+                // Determine Instrument associated with this OkxFundingRates message
+                let subscription_id = SubscriptionId::from(&funding_rates.subscription);
+                let instrument = match self.ids.find_instrument(&subscription_id) {
+                    Ok(instrument) => instrument,
+                    Err(error) => return vec![Err(error)],
+                };
+
+                // Map to MarketEvents
+                funding_rates
+                    .data
+                    .into_iter()
+                    .map(|funding_rate| {
+                        Ok(MarketEvent::from((
+                            Okx::EXCHANGE,
+                            instrument.clone(),
+                            funding_rate,
+                        )))
+                    })
+                    .collect()
+            }
+            OkxMessage::OpenInterests(open_interests) => {
+                // Determine Instrument associated with this OkxOpenInterests message
+                let subscription_id = SubscriptionId::from(&open_interests.subscription);
+                let instrument = match self.ids.find_instrument(&subscription_id) {
+                    Ok(instrument) => instrument,
+                    Err(error) => return vec![Err(error)],
+                };
+
+                // Map to MarketEvents
+                open_interests
+                    .data
+                    .into_iter()
+                    .map(|open_interest| {
+                        Ok(MarketEvent::from((
+                            Okx::EXCHANGE,
+                            instrument.clone(),
+                            open_interest,
+                        )))
+                    })
+                    .collect()
+            }
+            OkxMessage::Tickers(tickers) => {
+                // Determine Instrument associated with this OkxTickers message
+                let subscription_id = SubscriptionId::from(&tickers.subscription);
+                let instrument = match self.ids.find_instrument(&subscription_id) {
+                    Ok(instrument) => instrument,
+                    Err(error) => return vec![Err(error)],
+                };
+
+                // Map to MarketEvents
+                tickers
+                    .data
+                    .into_iter()
+                    .map(|ticker| {
+                        Ok(MarketEvent::from((
+                            Okx::EXCHANGE,
+                            instrument.clone(),
+                            ticker,
+                        )))
+                    })
+                    .collect()
+            }
+            OkxMessage::OrderBook(message) => self.transform_order_book(message),
+            OkxMessage::Error(error) => vec![Err(SocketError::Exchange(format!(
+                "received error code: {} with message: {}",
+                error.code, error.msg
+            )))],
+        }
+    }
+}
+
+impl Okx {
+    /// [`Okx`] trades channel.
+    ///
+    /// See docs: <https://www.okx.com/docs-v5/en/#public-data-websocket-trades-channel>
+    pub const CHANNEL_TRADES: &'static str = "trades";
+
+    /// [`Okx`] candlesticks channel prefix, suffixed with the [`OkxBar`] (eg/ "candle1m").
+    ///
+    /// See docs: <https://www.okx.com/docs-v5/en/#public-data-websocket-candlesticks-channel>
+    pub const CHANNEL_CANDLE: &'static str = "candle";
+
+    /// [`Okx`] tick-by-tick L2 order book channel, providing an initial snapshot followed by
+    /// every update.
+    ///
+    /// See docs: <https://www.okx.com/docs-v5/en/#order-book-trading-market-data-ws-order-book-channel>
+    pub const CHANNEL_BOOKS_L2_TBT: &'static str = "books-l2-tbt";
+
+    /// [`Okx`] perpetual swap funding rate channel.
+    ///
+    /// See docs: <https://www.okx.com/docs-v5/en/#public-data-websocket-funding-rate-channel>
+    pub const CHANNEL_FUNDING_RATE: &'static str = "funding-rate";
+
+    /// [`Okx`] futures open interest channel.
+    ///
+    /// See docs: <https://www.okx.com/docs-v5/en/#public-data-websocket-open-interest-channel>
+    pub const CHANNEL_OPEN_INTEREST: &'static str = "open-interest";
+
+    /// [`Okx`] rolling 24 hour tickers channel name.
+    ///
+    /// See docs: <https://www.okx.com/docs-v5/en/#public-data-websocket-tickers-channel>
+    pub const CHANNEL_TICKERS: &'static str = "tickers";
+
+    /// Maximum number of args batched into a single [`Okx`] subscription request, keeping each
+    /// request within the [`Okx`] request length limit.
+    ///
+    /// See docs: <https://www.okx.com/docs-v5/en/#overview-websocket-subscribe>
+    pub const MAX_ARGS_PER_REQUEST: usize = 50;
+
+    /// Translate a Barter [`Subscription`] into an [`OkxSubscription`].
+    ///
+    /// eg/ OkxSubscription { channel: "candle1m", market: "BTC-USDT-SWAP" }
+    pub fn subscription(sub: &Subscription) -> Result<OkxSubscription, SocketError> {
+        // Validate provided Subscription InstrumentKind & SubKind are supported by Okx
+        let sub = sub.validate()?;
+
+        // Determine Okx channel using the Subscription SubKind
+        let channel = match &sub.kind {
+            SubKind::Trade => Self::CHANNEL_TRADES.to_owned(),
+            SubKind::Candle(interval) => {
+                format!("{}{}", Self::CHANNEL_CANDLE, OkxBar::try_from(interval)?.0)
+            }
+            SubKind::OrderBookL2Managed => Self::CHANNEL_BOOKS_L2_TBT.to_owned(),
+            SubKind::FundingRate if sub.instrument.kind == InstrumentKind::FuturePerpetual => {
+                Self::CHANNEL_FUNDING_RATE.to_owned()
+            }
+            SubKind::OpenInterest if sub.instrument.kind == InstrumentKind::FuturePerpetual => {
+                Self::CHANNEL_OPEN_INTEREST.to_owned()
+            }
+            SubKind::Ticker => Self::CHANNEL_TICKERS.to_owned(),
+            other => {
+                return Err(SocketError::Unsupported {
+                    entity: Self::EXCHANGE.as_str(),
+                    item: other.to_string(),
+                })
+            }
+        };
+
+        Ok(OkxSubscription::new(channel, Self::market(&sub.instrument)))
+    }
+
+    /// Determine the [`Okx`] instrument identifier for the provided [`Instrument`].
+    ///
+    /// eg/ Spot "BTC-USDT", FuturePerpetual "BTC-USDT-SWAP"
+    pub fn market(instrument: &Instrument) -> String {
+        match instrument.kind {
+            InstrumentKind::Spot => format!("{}-{}", instrument.base, instrument.quote),
+            InstrumentKind::FuturePerpetual => {
+                format!("{}-{}-SWAP", instrument.base, instrument.quote)
+            }
+        }
+        .to_uppercase()
+    }
+
+    /// Build an [`Okx`] compatible subscription message using the [`OkxSubscription`] provided.
+    pub fn subscription_message(subscription: &OkxSubscription) -> WsMessage {
+        Self::request("subscribe", subscription)
+    }
+
+    /// Build an [`Okx`] compatible request for the provided operation & [`OkxSubscription`].
+    ///
+    /// eg/ {"op":"unsubscribe","args":[{"channel":"books-l2-tbt","instId":"BTC-USDT"}]}
+    pub fn request(op: &str, subscription: &OkxSubscription) -> WsMessage {
+        Self::batch_request(op, std::slice::from_ref(subscription))
+    }
+
+    /// Build an [`Okx`] compatible request for the provided operation, containing an arg for
+    /// every [`OkxSubscription`] provided.
+    ///
+    /// eg/ {"op":"subscribe","args":[{"channel":"funding-rate","instId":"BTC-USDT-SWAP"},
+    ///      {"channel":"open-interest","instId":"BTC-USDT-SWAP"}]}
+    pub fn batch_request(op: &str, subscriptions: &[OkxSubscription]) -> WsMessage {
+        WsMessage::Text(
+            json!({
+                "op": op,
+                "args": subscriptions,
+            })
+            .to_string(),
+        )
+    }
+
+    /// Maintain the [`OkxOrderBook`] associated with the provided [`OkxOrderBookMessage`],
+    /// validate it's checksum, & transform it into a [`MarketEvent`] containing the full
+    /// [`OrderBook`].
+    fn transform_order_book(
+        &mut self,
+        message: OkxOrderBookMessage,
+    ) -> Vec<Result<MarketEvent, SocketError>> {
+        // Determine Instrument associated with this OkxOrderBookMessage
+        let subscription_id = SubscriptionId::from(&message.subscription);
+        let instrument = match self.ids.find_instrument(&subscription_id) {
+            Ok(instrument) => instrument,
+            Err(error) => return vec![Err(error)],
+        };
+
+        let mut events = Vec::with_capacity(message.data.len());
+        for data in message.data {
+            let checksum = data.checksum;
+
+            // Apply snapshot or update to the maintained OkxOrderBook
+            let applied = match message.action {
+                OkxOrderBookAction::Snapshot => OkxOrderBook::snapshot(data)
+                    .map(|book| self.books.insert(subscription_id.clone(), book))
+                    .map(|_| ()),
+                OkxOrderBookAction::Update => match self.books.get_mut(&subscription_id) {
+                    Some(book) => book.apply(data),
+                    // Updates received before a snapshot cannot be applied
+                    None => continue,
+                },
+            };
+            let book = match applied.map(|_| self.books.get(&subscription_id)) {
+                Ok(Some(book)) => book,
+                Ok(None) => continue,
+                Err(error) => {
+                    events.push(Err(error));
+                    continue;
+                }
+            };
+
+            // Validate the maintained OkxOrderBook checksum, else react via the ChecksumPolicy
+            let actual = book.checksum();
+            if actual != checksum {
+                let error = SocketError::Exchange(format!(
+                    "{subscription_id} OrderBook checksum mismatch with expected: {checksum}, actual: {actual}"
+                ));
+                match self.checksum_policy {
+                    ChecksumPolicy::Error => {
+                        events.push(Err(error));
+                        continue;
+                    }
+                    ChecksumPolicy::Panic if cfg!(debug_assertions) => panic!("{error}"),
+                    ChecksumPolicy::Resync | ChecksumPolicy::Panic => {
+                        self.books.remove(&subscription_id);
+                        self.resubscribe(&message.subscription);
+                        events.push(Err(error));
+                        break;
+                    }
+                }
+            }
+
+            events.push(OrderBook::try_from(book).map(|book| MarketEvent {
+                exchange_time: book.last_update_time,
+                received_time: Utc::now(),
+                received_instant: None,
+                raw: None,
+                exchange: Exchange::from(Self::EXCHANGE),
+                instrument: instrument.clone(),
+                kind: DataKind::OrderBook(book),
+            }));
+        }
+
+        events
+    }
+
+    /// Re-subscribe to the provided channel in order to receive a fresh OrderBook snapshot.
+    fn resubscribe(&self, subscription: &OkxSubscription) {
+        debug!(
+            exchange_id = %Self::EXCHANGE,
+            ?subscription,
+            "re-subscribing to recover OrderBook snapshot"
+        );
+        for op in ["unsubscribe", "subscribe"] {
+            let _ = self.ws_sink_tx.send(Self::request(op, subscription));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchange::okx::model::{
+        OkxCandle, OkxCandles, OkxError, OkxFundingRate, OkxFundingRates, OkxOpenInterest,
+        OkxOpenInterests, OkxTicker, OkxTickers, OkxTrade, OkxTrades,
+    };
+    use crate::model::{
+        subscription::Interval, Candle, DataKind, FundingRate, Level, OpenInterest, PublicTrade,
+        Ticker,
+    };
+    use barter_integration::model::{Exchange, Side};
+    use chrono::{Duration, TimeZone, Utc};
+
+    #[test]
+    fn test_subscription() {
+        struct TestCase {
+            input: Subscription,
+            expected: Result<OkxSubscription, SocketError>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: Supported InstrumentKind::Spot trades subscription
+                input: Subscription::new(
+                    ExchangeId::Okx,
+                    ("btc", "usdt", InstrumentKind::Spot),
+                    SubKind::Trade,
+                ),
+                expected: Ok(OkxSubscription::new("trades", "BTC-USDT")),
+            },
+            TestCase {
+                // TC1: Supported InstrumentKind::FuturePerpetual candles subscription
+                input: Subscription::new(
+                    ExchangeId::Okx,
+                    ("btc", "usdt", InstrumentKind::FuturePerpetual),
+                    SubKind::Candle(Interval::Day1),
+                ),
+                expected: Ok(OkxSubscription::new("candle1Dutc", "BTC-USDT-SWAP")),
+            },
+            TestCase {
+                // TC2: Unsupported Interval
+                input: Subscription::new(
+                    ExchangeId::Okx,
+                    ("btc", "usdt", InstrumentKind::Spot),
+                    SubKind::Candle(Interval::Hour8),
+                ),
+                expected: Err(SocketError::Unsupported {
+                    entity: "okx",
+                    item: Interval::Hour8.to_string(),
+                }),
+            },
+            TestCase {
+                // TC3: Unsupported SubKind
+                input: Subscription::new(
+                    ExchangeId::Okx,
+                    ("btc", "usdt", InstrumentKind::Spot),
+                    SubKind::OrderBookL3Delta,
+                ),
+                expected: Err(SocketError::Unsupported {
+                    entity: "okx",
+                    item: SubKind::OrderBookL3Delta.to_string(),
+                }),
+            },
+            TestCase {
+                // TC4: Supported InstrumentKind::FuturePerpetual funding rate subscription
+                input: Subscription::new(
+                    ExchangeId::Okx,
+                    ("btc", "usdt", InstrumentKind::FuturePerpetual),
+                    SubKind::FundingRate,
+                ),
+                expected: Ok(OkxSubscription::new("funding-rate", "BTC-USDT-SWAP")),
+            },
+            TestCase {
+                // TC5: Unsupported InstrumentKind::Spot funding rate subscription
+                input: Subscription::new(
+                    ExchangeId::Okx,
+                    ("btc", "usdt", InstrumentKind::Spot),
+                    SubKind::FundingRate,
+                ),
+                expected: Err(SocketError::Unsupported {
+                    entity: "okx",
+                    item: SubKind::FundingRate.to_string(),
+                }),
+            },
+            TestCase {
+                // TC6: Supported InstrumentKind::FuturePerpetual open interest subscription
+                input: Subscription::new(
+                    ExchangeId::Okx,
+                    ("btc", "usdt", InstrumentKind::FuturePerpetual),
+                    SubKind::OpenInterest,
+                ),
+                expected: Ok(OkxSubscription::new("open-interest", "BTC-USDT-SWAP")),
+            },
+            TestCase {
+                // TC7: Supported InstrumentKind::Spot ticker subscription
+                input: Subscription::new(
+                    ExchangeId::Okx,
+                    ("btc", "usdt", InstrumentKind::Spot),
+                    SubKind::Ticker,
+                ),
+                expected: Ok(OkxSubscription::new("tickers", "BTC-USDT")),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = Okx::subscription(&test.input);
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => {
+                    assert_eq!(actual, expected, "TC{} failed", index)
+                }
+                (Err(_), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_build_subscription_meta() {
+        let perpetual = |base: &str, kind| {
+            Subscription::new(
+                ExchangeId::Okx,
+                (base, "usdt", InstrumentKind::FuturePerpetual),
+                kind,
+            )
+        };
+
+        let mut subscriptions = vec![perpetual("btc", SubKind::Trade)];
+        for index in 0..Okx::MAX_ARGS_PER_REQUEST / 2 + 1 {
+            let base = format!("coin{index}");
+            subscriptions.push(perpetual(&base, SubKind::FundingRate));
+            subscriptions.push(perpetual(&base, SubKind::OpenInterest));
+        }
+
+        let actual = Okx::build_subscription_meta(&subscriptions).unwrap();
+
+        assert_eq!(actual.ids.len(), subscriptions.len());
+        assert_eq!(actual.expected_responses, subscriptions.len());
+
+        let args = actual
+            .subscriptions
+            .iter()
+            .map(|message| match message {
+                WsMessage::Text(text) => {
+                    let request = serde_json::from_str::<serde_json::Value>(text).unwrap();
+                    assert_eq!(request["op"], "subscribe");
+                    request["args"].as_array().unwrap().len()
+                }
+                other => panic!("unexpected subscription message: {other:?}"),
+            })
+            .collect::<Vec<_>>();
+
+        // Trades are subscribed individually, while funding-rate & open-interest are batched
+        assert_eq!(args, vec![1, Okx::MAX_ARGS_PER_REQUEST, 2]);
+    }
+
+    #[test]
+    fn test_okx_transform() {
+        let trade_sub = Subscription::new(
+            ExchangeId::Okx,
+            ("btc", "usdt", InstrumentKind::Spot),
+            SubKind::Trade,
+        );
+        let candle_sub = Subscription::new(
+            ExchangeId::Okx,
+            ("btc", "usdt", InstrumentKind::Spot),
+            SubKind::Candle(Interval::Minute1),
+        );
+        let funding_rate_sub = Subscription::new(
+            ExchangeId::Okx,
+            ("btc", "usdt", InstrumentKind::FuturePerpetual),
+            SubKind::FundingRate,
+        );
+        let open_interest_sub = Subscription::new(
+            ExchangeId::Okx,
+            ("btc", "usdt", InstrumentKind::FuturePerpetual),
+            SubKind::OpenInterest,
+        );
+        let ticker_sub = Subscription::new(
+            ExchangeId::Okx,
+            ("btc", "usdt", InstrumentKind::FuturePerpetual),
+            SubKind::Ticker,
+        );
+        let mut transformer = Okx::new(
+            mpsc::unbounded_channel().0,
+            SubscriptionIds(HashMap::from([
+                (SubscriptionId::from("trades|BTC-USDT"), trade_sub.clone()),
+                (
+                    SubscriptionId::from("candle1m|BTC-USDT"),
+                    candle_sub.clone(),
+                ),
+                (
+                    SubscriptionId::from("funding-rate|BTC-USDT-SWAP"),
+                    funding_rate_sub.clone(),
+                ),
+                (
+                    SubscriptionId::from("open-interest|BTC-USDT-SWAP"),
+                    open_interest_sub.clone(),
+                ),
+                (
+                    SubscriptionId::from("tickers|BTC-USDT-SWAP"),
+                    ticker_sub.clone(),
+                ),
+            ])),
+        );
+
+        let time = Utc::now();
+
+        struct TestCase {
+            input: OkxMessage,
+            expected: Vec<Result<MarketEvent, SocketError>>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: OkxMessage Trades w/ known SubscriptionId
+                input: OkxMessage::Trades(OkxTrades {
+                    subscription: OkxSubscription::new("trades", "BTC-USDT"),
+                    data: vec![OkxTrade {
+                        id: "1".to_owned(),
+                        price: 100.0,
+                        quantity: 1.0,
+                        side: Side::Sell,
+                        time,
+                    }],
+                }),
+                expected: vec![Ok(MarketEvent {
+                    exchange_time: time,
+                    received_time: time,
+                    received_instant: None,
+                    raw: None,
+                    exchange: Exchange::from(ExchangeId::Okx),
+                    instrument: trade_sub.instrument.clone(),
+                    kind: DataKind::Trade(PublicTrade {
+                        id: "1".to_owned(),
+                        price: 100.0,
+                        quantity: 1.0,
+                        side: Side::Sell,
+                    }),
+                })],
+            },
+            TestCase {
+                // TC1: OkxMessage Candles w/ known SubscriptionId
+                input: OkxMessage::Candles(OkxCandles {
+                    subscription: OkxSubscription::new("candle1m", "BTC-USDT"),
+                    data: vec![OkxCandle {
+                        start_time: time,
+                        open: 1.0,
+                        high: 2.0,
+                        low: 0.5,
+                        close: 1.5,
+                        volume: 10.0,
+                    }],
+                }),
+                expected: vec![Ok(MarketEvent {
+                    exchange_time: time + Duration::minutes(1),
+                    received_time: time,
+                    received_instant: None,
+                    raw: None,
+                    exchange: Exchange::from(ExchangeId::Okx),
+                    instrument: candle_sub.instrument,
+                    kind: DataKind::Candle(Candle {
+                        start_time: time,
+                        end_time: time + Duration::minutes(1),
+                        open: 1.0,
+                        high: 2.0,
+                        low: 0.5,
+                        close: 1.5,
+                        volume: 10.0,
+                        trade_count: 0,
+                    }),
+                })],
+            },
+            TestCase {
+                // TC2: OkxMessage Trades w/ unknown SubscriptionId
+                input: OkxMessage::Trades(OkxTrades {
+                    subscription: OkxSubscription::new("trades", "ETH-USDT"),
+                    data: vec![],
+                }),
+                expected: vec![Err(SocketError::Unidentifiable(SubscriptionId::from(
+                    "trades|ETH-USDT",
+                )))],
+            },
+            TestCase {
+                // TC3: OkxMessage Error
+                input: OkxMessage::Error(OkxError {
+                    event: "error".to_owned(),
+                    code: "60018".to_owned(),
+                    msg: "Invalid sign".to_owned(),
+                }),
+                expected: vec![Err(SocketError::Exchange("".to_owned()))],
+            },
+            TestCase {
+                // TC4: OkxMessage FundingRates w/ known SubscriptionId
+                input: OkxMessage::FundingRates(OkxFundingRates {
+                    subscription: OkxSubscription::new("funding-rate", "BTC-USDT-SWAP"),
+                    data: vec![OkxFundingRate {
+                        rate: 0.0001,
+                        next_funding_time: time + Duration::hours(8),
+                        time,
+                    }],
+                }),
+                expected: vec![Ok(MarketEvent {
+                    exchange_time: time,
+                    received_time: time,
+                    received_instant: None,
+                    raw: None,
+                    exchange: Exchange::from(ExchangeId::Okx),
+                    instrument: funding_rate_sub.instrument,
+                    kind: DataKind::FundingRate(FundingRate {
+                        rate: 0.0001,
+                        next_funding_time: time + Duration::hours(8),
+                    }),
+                })],
+            },
+            TestCase {
+                // TC5: OkxMessage OpenInterests w/ known SubscriptionId
+                input: OkxMessage::OpenInterests(OkxOpenInterests {
+                    subscription: OkxSubscription::new("open-interest", "BTC-USDT-SWAP"),
+                    data: vec![OkxOpenInterest {
+                        quantity: 100.0,
+                        notional: None,
+                        time,
+                    }],
+                }),
+                expected: vec![Ok(MarketEvent {
+                    exchange_time: time,
+                    received_time: time,
+                    received_instant: None,
+                    raw: None,
+                    exchange: Exchange::from(ExchangeId::Okx),
+                    instrument: open_interest_sub.instrument,
+                    kind: DataKind::OpenInterest(OpenInterest {
+                        quantity: 100.0,
+                        notional: None,
+                    }),
+                })],
+            },
+            TestCase {
+                // TC6: OkxMessage Tickers w/ known SubscriptionId uses base asset volume
+                input: OkxMessage::Tickers(OkxTickers {
+                    subscription: OkxSubscription::new("tickers", "BTC-USDT-SWAP"),
+                    data: vec![OkxTicker {
+                        last_price: 110.0,
+                        open: 100.0,
+                        high: 120.0,
+                        low: 90.0,
+                        volume: 5000.0,
+                        volume_currency: 50.0,
+                        time,
+                    }],
+                }),
+                expected: vec![Ok(MarketEvent {
+                    exchange_time: time,
+                    received_time: time,
+                    received_instant: None,
+                    raw: None,
+                    exchange: Exchange::from(ExchangeId::Okx),
+                    instrument: ticker_sub.instrument,
+                    kind: DataKind::Ticker(Ticker {
+                        last_price: 110.0,
+                        volume_24h: 50.0,
+                        high_24h: 120.0,
+                        low_24h: 90.0,
+                        change_percent_24h: 10.0,
+                    }),
+                })],
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = transformer.transform(test.input);
+            assert_eq!(
+                actual.len(),
+                test.expected.len(),
+                "TestCase {} failed at vector length assert_eq with actual: {:?}",
+                index,
+                actual
+            );
+
+            for (vector_index, (actual, expected)) in
+                actual.into_iter().zip(test.expected).enumerate()
+            {
+                match (actual, expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        // Scrub Utc::now() timestamps to allow comparison
+                        let actual = MarketEvent {
+                            received_time: time,
+                            ..actual
+                        };
+                        assert_eq!(
+                            actual, expected,
+                            "TC{} failed at vector index {}",
+                            index, vector_index
+                        )
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed at vector index {vector_index} because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_okx_order_book_transform() {
+        let (ws_sink_tx, mut ws_sink_rx) = mpsc::unbounded_channel();
+        let mut transformer = Okx::new(
+            ws_sink_tx,
+            Okx::build_subscription_meta(&[Subscription::new(
+                ExchangeId::Okx,
+                ("btc", "usdt", InstrumentKind::Spot),
+                SubKind::OrderBookL2Managed,
+            )])
+            .unwrap()
+            .ids,
+        );
+
+        struct TestCase {
+            input: &'static str,
+            expected: Vec<Result<DataKind, SocketError>>,
+            expected_resubscribe: bool,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: update received before a snapshot is ignored
+                input: r#"{"arg":{"channel":"books-l2-tbt","instId":"BTC-USDT"},"action":"update","data":[{"asks":[],"bids":[["3365","2","0","1"]],"ts":"1597026383085","checksum":0,"prevSeqId":1,"seqId":2}]}"#,
+                expected: vec![],
+                expected_resubscribe: false,
+            },
+            TestCase {
+                // TC1: snapshot w/ valid checksum delivers the full OrderBook
+                input: r#"{"arg":{"channel":"books-l2-tbt","instId":"BTC-USDT"},"action":"snapshot","data":[{"asks":[["3366.8","9","0","3"],["3368","8","0","4"]],"bids":[["3366.1","7","0","3"],["3366","6","0","4"]],"ts":"1597026383085","checksum":-1881014294,"prevSeqId":-1,"seqId":10}]}"#,
+                expected: vec![Ok(DataKind::OrderBook(OrderBook {
+                    last_update_time: Utc.timestamp_millis_opt(1597026383085).unwrap(),
+                    last_update_id: 10,
+                    bids: vec![Level::new(3366.1, 7.0), Level::new(3366.0, 6.0)],
+                    asks: vec![Level::new(3366.8, 9.0), Level::new(3368.0, 8.0)],
+                }))],
+                expected_resubscribe: false,
+            },
+            TestCase {
+                // TC2: update w/ valid checksum delivers the updated OrderBook
+                input: r#"{"arg":{"channel":"books-l2-tbt","instId":"BTC-USDT"},"action":"update","data":[{"asks":[],"bids":[["3365","2","0","1"]],"ts":"1597026383085","checksum":202671967,"prevSeqId":10,"seqId":11}]}"#,
+                expected: vec![Ok(DataKind::OrderBook(OrderBook {
+                    last_update_time: Utc.timestamp_millis_opt(1597026383085).unwrap(),
+                    last_update_id: 11,
+                    bids: vec![
+                        Level::new(3366.1, 7.0),
+                        Level::new(3366.0, 6.0),
+                        Level::new(3365.0, 2.0),
+                    ],
+                    asks: vec![Level::new(3366.8, 9.0), Level::new(3368.0, 8.0)],
+                }))],
+                expected_resubscribe: false,
+            },
+            TestCase {
+                // TC3: update w/ invalid checksum is an error & re-subscribes
+                input: r#"{"arg":{"channel":"books-l2-tbt","instId":"BTC-USDT"},"action":"update","data":[{"asks":[["3368","0","0","0"]],"bids":[],"ts":"1597026383085","checksum":1,"prevSeqId":11,"seqId":12}]}"#,
+                expected: vec![Err(SocketError::Exchange("checksum".to_owned()))],
+                expected_resubscribe: true,
+            },
+            TestCase {
+                // TC4: update received after a checksum mismatch is ignored until a snapshot
+                input: r#"{"arg":{"channel":"books-l2-tbt","instId":"BTC-USDT"},"action":"update","data":[{"asks":[],"bids":[["3365","0","0","0"]],"ts":"1597026383085","checksum":0,"prevSeqId":12,"seqId":13}]}"#,
+                expected: vec![],
+                expected_resubscribe: false,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let input = serde_json::from_str::<OkxMessage>(test.input).unwrap();
+            let actual = transformer.transform(input);
+            assert_eq!(actual.len(), test.expected.len(), "TC{} failed", index);
+
+            for (actual, expected) in actual.into_iter().zip(test.expected) {
+                match (actual, expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual.kind, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+
+            let resubscribed = std::iter::from_fn(|| ws_sink_rx.try_recv().ok()).count() == 2;
+            assert_eq!(
+                resubscribed, test.expected_resubscribe,
+                "TC{} failed",
+                index
+            );
+        }
+    }
+
+    #[test]
+    fn test_okx_order_book_checksum_policy() {
+        let snapshot = r#"{"arg":{"channel":"books-l2-tbt","instId":"BTC-USDT"},"action":"snapshot","data":[{"asks":[["3366.8","9","0","3"],["3368","8","0","4"]],"bids":[["3366.1","7","0","3"],["3366","6","0","4"]],"ts":"1597026383085","checksum":-1881014294,"prevSeqId":-1,"seqId":10}]}"#;
+        let mismatch = r#"{"arg":{"channel":"books-l2-tbt","instId":"BTC-USDT"},"action":"update","data":[{"asks":[["3368","0","0","0"]],"bids":[],"ts":"1597026383085","checksum":1,"prevSeqId":10,"seqId":11}]}"#;
+        let update = r#"{"arg":{"channel":"books-l2-tbt","instId":"BTC-USDT"},"action":"update","data":[{"asks":[],"bids":[["3365","2","0","1"]],"ts":"1597026383085","checksum":1743553959,"prevSeqId":11,"seqId":12}]}"#;
+
+        struct TestCase {
+            policy: ChecksumPolicy,
+            expected_resubscribe: bool,
+            expected_update: usize,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: Resync discards the OrderBook & re-subscribes, so the update is ignored
+                policy: ChecksumPolicy::Resync,
+                expected_resubscribe: true,
+                expected_update: 0,
+            },
+            TestCase {
+                // TC1: Error retains the OrderBook, so the update w/ valid checksum is delivered
+                policy: ChecksumPolicy::Error,
+                expected_resubscribe: false,
+                expected_update: 1,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let (ws_sink_tx, mut ws_sink_rx) = mpsc::unbounded_channel();
+            let mut transformer = Okx::new(
+                ws_sink_tx,
+                Okx::build_subscription_meta(&[Subscription::new(
+                    ExchangeId::Okx,
+                    ("btc", "usdt", InstrumentKind::Spot),
+                    SubKind::OrderBookL2Managed,
+                )])
+                .unwrap()
+                .ids,
+            );
+            transformer.set_checksum_policy(test.policy);
+
+            let mut transform = |input: &str| {
+                transformer.transform(serde_json::from_str::<OkxMessage>(input).unwrap())
+            };
+
+            assert!(transform(snapshot)[0].is_ok(), "TC{} failed", index);
+            assert!(transform(mismatch)[0].is_err(), "TC{} failed", index);
+
+            let resubscribed = std::iter::from_fn(|| ws_sink_rx.try_recv().ok()).count() == 2;
+            assert_eq!(
+                resubscribed, test.expected_resubscribe,
+                "TC{} failed",
+                index
+            );
+
+            let actual = transform(update);
+            assert_eq!(actual.len(), test.expected_update, "TC{} failed", index);
+            assert!(actual.iter().all(Result::is_ok), "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic]
+    fn test_okx_order_book_checksum_policy_panic() {
+        let (ws_sink_tx, _ws_sink_rx) = mpsc::unbounded_channel();
+        let mut transformer = Okx::new(
+            ws_sink_tx,
+            Okx::build_subscription_meta(&[Subscription::new(
+                ExchangeId::Okx,
+                ("btc", "usdt", InstrumentKind::Spot),
+                SubKind::OrderBookL2Managed,
+            )])
+            .unwrap()
+            .ids,
+        );
+        transformer.set_checksum_policy(ChecksumPolicy::Panic);
+
+        let input = r#"{"arg":{"channel":"books-l2-tbt","instId":"BTC-USDT"},"action":"snapshot","data":[{"asks":[["3366.8","9","0","3"]],"bids":[["3366.1","7","0","3"]],"ts":"1597026383085","checksum":1,"prevSeqId":-1,"seqId":10}]}"#;
+        transformer.transform(serde_json::from_str::<OkxMessage>(input).unwrap());
+    }
+}
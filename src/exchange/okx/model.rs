@@ -0,0 +1,706 @@
+use crate::{
+    exchange::{
+        datetime_utc_from_epoch_duration, de_str, de_str_opt, de_u64_epoch_ms_as_datetime_utc,
+        extract_next, Numeric,
+    },
+    model::{
+        subscription::Interval, Candle, DataKind, FundingRate, OpenInterest, PublicTrade, Ticker,
+    },
+    ExchangeId, MarketEvent,
+};
+use barter_integration::{
+    error::SocketError,
+    model::{Exchange, Instrument, InstrumentKind, Side, SubscriptionId},
+    Validator,
+};
+use chrono::{DateTime, Utc};
+use serde::{de, Deserialize, Deserializer, Serialize};
+use std::time::Duration;
+
+/// [`Okx`](super::Okx) channel & instrument pair identifying a subscription, used both in
+/// subscription requests & as the "arg" of every message received.
+///
+/// eg/ {"channel":"trades","instId":"BTC-USDT"}
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#overview-websocket-subscribe>
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct OkxSubscription {
+    pub channel: String,
+    #[serde(rename = "instId")]
+    pub market: String,
+}
+
+impl OkxSubscription {
+    /// Construct a new [`OkxSubscription`] for the provided channel & market.
+    pub fn new<S>(channel: S, market: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            channel: channel.into(),
+            market: market.into(),
+        }
+    }
+}
+
+impl From<&OkxSubscription> for SubscriptionId {
+    fn from(subscription: &OkxSubscription) -> Self {
+        // eg/ SubscriptionId("candle1m|BTC-USDT")
+        SubscriptionId::from(format!("{}|{}", subscription.channel, subscription.market))
+    }
+}
+
+/// [`Okx`](super::Okx) message received in response to WebSocket subscription requests.
+///
+/// eg/ OkxSubResponse::Subscribed {"event":"subscribe","arg":{"channel":"trades","instId":"BTC-USDT"},"connId":"a4d3ae55"}
+/// eg/ OkxSubResponse::Error {"event":"error","code":"60012","msg":"Invalid request: ...","connId":"a4d3ae55"}
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#overview-websocket-subscribe>
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(tag = "event", rename_all = "lowercase")]
+pub enum OkxSubResponse {
+    #[serde(rename = "subscribe")]
+    Subscribed {
+        arg: OkxSubscription,
+    },
+    Error {
+        code: String,
+        msg: String,
+    },
+}
+
+impl Validator for OkxSubResponse {
+    fn validate(self) -> Result<Self, SocketError>
+    where
+        Self: Sized,
+    {
+        match &self {
+            OkxSubResponse::Subscribed { .. } => Ok(self),
+            OkxSubResponse::Error { code, msg } => Err(SocketError::Subscribe(format!(
+                "received failure subscription response code: {code} with message: {msg}",
+            ))),
+        }
+    }
+}
+
+/// [`Okx`](super::Okx) bar used in candle channel names (eg/ "candle1m").
+///
+/// Bars of 6 hours or longer use the UTC aligned variants (eg/ "1Dutc"), rather than the
+/// default Hong Kong time aligned variants.
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#public-data-websocket-candlesticks-channel>
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct OkxBar(pub &'static str);
+
+impl TryFrom<&Interval> for OkxBar {
+    type Error = SocketError;
+
+    fn try_from(interval: &Interval) -> Result<Self, Self::Error> {
+        let bar = match interval {
+            Interval::Minute1 => "1m",
+            Interval::Minute3 => "3m",
+            Interval::Minute5 => "5m",
+            Interval::Minute15 => "15m",
+            Interval::Minute30 => "30m",
+            Interval::Hour1 => "1H",
+            Interval::Hour2 => "2H",
+            Interval::Hour4 => "4H",
+            Interval::Hour6 => "6Hutc",
+            Interval::Hour12 => "12Hutc",
+            Interval::Day1 => "1Dutc",
+            Interval::Day3 => "3Dutc",
+            Interval::Week1 => "1Wutc",
+            Interval::Month1 => "1Mutc",
+            Interval::Month3 => "3Mutc",
+            Interval::Hour8 => {
+                return Err(SocketError::Unsupported {
+                    entity: ExchangeId::Okx.as_str(),
+                    item: interval.to_string(),
+                })
+            }
+        };
+
+        Ok(Self(bar))
+    }
+}
+
+/// [`Okx`](super::Okx) message variants that can be received over [`WebSocket`].
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#overview-websocket-push-data>
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum OkxMessage {
+    Trades(OkxTrades),
+    Candles(OkxCandles),
+    FundingRates(OkxFundingRates),
+    OpenInterests(OkxOpenInterests),
+    Tickers(OkxTickers),
+    OrderBook(OkxOrderBookMessage),
+    Error(OkxError),
+}
+
+/// [`Okx`](super::Okx) trades channel message containing one or more [`OkxTrade`]s.
+///
+/// eg/ {"arg":{"channel":"trades","instId":"BTC-USDT"},"data":[{...}]}
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#public-data-websocket-trades-channel>
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct OkxTrades {
+    #[serde(rename = "arg")]
+    pub subscription: OkxSubscription,
+    pub data: Vec<OkxTrade>,
+}
+
+/// [`Okx`](super::Okx) trade.
+///
+/// eg/ {"instId":"BTC-USDT","tradeId":"130639474","px":"42219.9","sz":"0.12060306",
+///      "side":"buy","ts":"1630048897897","count":"3"}
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#public-data-websocket-trades-channel>
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OkxTrade {
+    #[serde(rename = "tradeId")]
+    pub id: String,
+    #[serde(rename = "px", deserialize_with = "de_str")]
+    pub price: f64,
+    #[serde(rename = "sz", deserialize_with = "de_str")]
+    pub quantity: f64,
+    pub side: Side,
+    #[serde(rename = "ts", deserialize_with = "de_u64_epoch_ms_as_datetime_utc")]
+    pub time: DateTime<Utc>,
+}
+
+/// [`Okx`](super::Okx) candle channel message containing one or more [`OkxCandle`]s.
+///
+/// eg/ {"arg":{"channel":"candle1m","instId":"BTC-USDT"},"data":[[...]]}
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#public-data-websocket-candlesticks-channel>
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct OkxCandles {
+    #[serde(rename = "arg")]
+    pub subscription: OkxSubscription,
+    pub data: Vec<OkxCandle>,
+}
+
+/// [`Okx`](super::Okx) OHLCV candle, sent as an array of `String`s. The end time is not sent, so
+/// it is determined from the subscribed [`Interval`] when transformed.
+///
+/// eg/ ["1597026383085","8533.02","8553.74","8527.17","8548.26","45247","529.5858061","529.58","0"]
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#public-data-websocket-candlesticks-channel>
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Serialize)]
+pub struct OkxCandle {
+    pub start_time: DateTime<Utc>,
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+    pub volume: f64,
+}
+
+/// [`Okx`](super::Okx) funding-rate channel message containing one or more [`OkxFundingRate`]s.
+///
+/// eg/ {"arg":{"channel":"funding-rate","instId":"BTC-USDT-SWAP"},"data":[{...}]}
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#public-data-websocket-funding-rate-channel>
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct OkxFundingRates {
+    #[serde(rename = "arg")]
+    pub subscription: OkxSubscription,
+    pub data: Vec<OkxFundingRate>,
+}
+
+/// [`Okx`](super::Okx) funding rate. The "fundingTime" is the time the current funding rate will
+/// be settled, ie/ the next funding time.
+///
+/// eg/ {"instType":"SWAP","instId":"BTC-USDT-SWAP","fundingRate":"0.0001875391284828",
+///      "fundingTime":"1700726400000","nextFundingRate":"","nextFundingTime":"1700755200000",
+///      "ts":"1700711005346"}
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#public-data-websocket-funding-rate-channel>
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OkxFundingRate {
+    #[serde(rename = "fundingRate", deserialize_with = "de_str")]
+    pub rate: f64,
+    #[serde(
+        rename = "fundingTime",
+        deserialize_with = "de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub next_funding_time: DateTime<Utc>,
+    #[serde(rename = "ts", deserialize_with = "de_u64_epoch_ms_as_datetime_utc")]
+    pub time: DateTime<Utc>,
+}
+
+/// [`Okx`](super::Okx) open-interest channel message containing one or more
+/// [`OkxOpenInterest`]s.
+///
+/// eg/ {"arg":{"channel":"open-interest","instId":"BTC-USDT-SWAP"},"data":[{...}]}
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#public-data-websocket-open-interest-channel>
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct OkxOpenInterests {
+    #[serde(rename = "arg")]
+    pub subscription: OkxSubscription,
+    pub data: Vec<OkxOpenInterest>,
+}
+
+/// [`Okx`](super::Okx) open interest. The "oi" is denominated in contracts, so the base asset
+/// denominated "oiCcy" is used instead.
+///
+/// eg/ {"instType":"SWAP","instId":"BTC-USDT-SWAP","oi":"2216113.01000000309",
+///      "oiCcy":"22161.1301000000309","oiUsd":"834213475.2113","ts":"1701678590838"}
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#public-data-websocket-open-interest-channel>
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OkxOpenInterest {
+    #[serde(rename = "oiCcy", deserialize_with = "de_str")]
+    pub quantity: f64,
+    #[serde(rename = "oiUsd", default, deserialize_with = "de_str_opt")]
+    pub notional: Option<f64>,
+    #[serde(rename = "ts", deserialize_with = "de_u64_epoch_ms_as_datetime_utc")]
+    pub time: DateTime<Utc>,
+}
+
+/// [`Okx`](super::Okx) tickers channel message containing one or more [`OkxTicker`]s.
+///
+/// eg/ {"arg":{"channel":"tickers","instId":"BTC-USDT"},"data":[{...}]}
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#public-data-websocket-tickers-channel>
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct OkxTickers {
+    #[serde(rename = "arg")]
+    pub subscription: OkxSubscription,
+    pub data: Vec<OkxTicker>,
+}
+
+/// [`Okx`](super::Okx) rolling 24 hour ticker. The "vol24h" of derivatives is denominated in
+/// contracts, so the base asset denominated "volCcy24h" is used for them instead.
+///
+/// eg/ {"instType":"SPOT","instId":"BTC-USDT","last":"9999.99","lastSz":"0.1",
+///      "open24h":"9000","high24h":"10000","low24h":"8888.88","volCcy24h":"2222",
+///      "vol24h":"2222","ts":"1597026383085", ...}
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#public-data-websocket-tickers-channel>
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OkxTicker {
+    #[serde(rename = "last", deserialize_with = "de_str")]
+    pub last_price: f64,
+    #[serde(rename = "open24h", deserialize_with = "de_str")]
+    pub open: f64,
+    #[serde(rename = "high24h", deserialize_with = "de_str")]
+    pub high: f64,
+    #[serde(rename = "low24h", deserialize_with = "de_str")]
+    pub low: f64,
+    #[serde(rename = "vol24h", deserialize_with = "de_str")]
+    pub volume: f64,
+    #[serde(rename = "volCcy24h", deserialize_with = "de_str")]
+    pub volume_currency: f64,
+    #[serde(rename = "ts", deserialize_with = "de_u64_epoch_ms_as_datetime_utc")]
+    pub time: DateTime<Utc>,
+}
+
+/// [`Okx`](super::Okx) tick-by-tick L2 order book channel message containing an initial
+/// snapshot or the subsequent updates.
+///
+/// eg/ {"arg":{"channel":"books-l2-tbt","instId":"BTC-USDT"},"action":"snapshot","data":[{...}]}
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#order-book-trading-market-data-ws-order-book-channel>
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct OkxOrderBookMessage {
+    #[serde(rename = "arg")]
+    pub subscription: OkxSubscription,
+    pub action: OkxOrderBookAction,
+    pub data: Vec<OkxOrderBookData>,
+}
+
+/// Type of [`OkxOrderBookMessage`].
+#[derive(Clone, Copy, Eq, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OkxOrderBookAction {
+    Snapshot,
+    Update,
+}
+
+/// [`Okx`](super::Okx) order book levels, along with the CRC32 checksum of the top 25 levels of
+/// the order book after they have been applied.
+///
+/// eg/ {"asks":[["8476.98","415","0","13"]],"bids":[["8476.97","256","0","12"]],
+///      "ts":"1597026383085","checksum":-855196043,"prevSeqId":-1,"seqId":123456}
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#order-book-trading-market-data-ws-order-book-channel>
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct OkxOrderBookData {
+    pub asks: Vec<OkxOrderBookLevel>,
+    pub bids: Vec<OkxOrderBookLevel>,
+    #[serde(rename = "ts", deserialize_with = "de_u64_epoch_ms_as_datetime_utc")]
+    pub time: DateTime<Utc>,
+    pub checksum: i32,
+    #[serde(rename = "seqId", default)]
+    pub sequence: u64,
+}
+
+/// [`Okx`](super::Okx) order book level, sent as an array of `String`s. The original `String`
+/// price & size are retained since they are required to calculate the order book checksum. A
+/// zero size communicates the price level should be removed.
+///
+/// eg/ ["8476.98","415","0","13"]
+/// where [PRICE, SIZE, DEPRECATED, NUMBER_OF_ORDERS].
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#order-book-trading-market-data-ws-order-book-channel>
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct OkxOrderBookLevel {
+    pub price: String,
+    pub size: String,
+    pub liquidated_orders: String,
+    pub orders: String,
+}
+
+/// [`Okx`](super::Okx) error message received after subscriptions have been validated.
+///
+/// eg/ {"event":"error","code":"60018","msg":"Invalid sign","connId":"a4d3ae55"}
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct OkxError {
+    pub event: String,
+    pub code: String,
+    pub msg: String,
+}
+
+impl<'de> Deserialize<'de> for OkxCandle {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct SeqVisitor;
+
+        impl<'de> de::Visitor<'de> for SeqVisitor {
+            type Value = OkxCandle;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("OkxCandle struct from the Okx WebSocket API")
+            }
+
+            fn visit_seq<SeqAccessor>(
+                self,
+                mut seq: SeqAccessor,
+            ) -> Result<Self::Value, SeqAccessor::Error>
+            where
+                SeqAccessor: de::SeqAccess<'de>,
+            {
+                // OkxCandle Sequence Format:
+                // [ts, o, h, l, c, vol, volCcy, volCcyQuote, confirm]
+                // <https://www.okx.com/docs-v5/en/#public-data-websocket-candlesticks-channel>
+
+                // Extract numeric start time epoch milliseconds & map to DateTime<Utc>
+                let start_time = extract_next::<SeqAccessor, Numeric<u64>>(&mut seq, "ts").map(
+                    |Numeric(ts)| datetime_utc_from_epoch_duration(Duration::from_millis(ts)),
+                )?;
+
+                let open = extract_next::<SeqAccessor, Numeric<f64>>(&mut seq, "o")?.0;
+                let high = extract_next::<SeqAccessor, Numeric<f64>>(&mut seq, "h")?.0;
+                let low = extract_next::<SeqAccessor, Numeric<f64>>(&mut seq, "l")?.0;
+                let close = extract_next::<SeqAccessor, Numeric<f64>>(&mut seq, "c")?.0;
+                let volume = extract_next::<SeqAccessor, Numeric<f64>>(&mut seq, "vol")?.0;
+
+                // Ignore any additional elements or SerDe will fail
+                //  '--> Exchange may add fields without warning
+                while seq.next_element::<de::IgnoredAny>()?.is_some() {}
+
+                Ok(OkxCandle {
+                    start_time,
+                    open,
+                    high,
+                    low,
+                    close,
+                    volume,
+                })
+            }
+        }
+
+        // Use Visitor implementation to deserialise the OkxCandle
+        deserializer.deserialize_seq(SeqVisitor)
+    }
+}
+
+impl From<(ExchangeId, Instrument, OkxTrade)> for MarketEvent {
+    fn from((exchange_id, instrument, trade): (ExchangeId, Instrument, OkxTrade)) -> Self {
+        Self {
+            exchange_time: trade.time,
+            received_time: Utc::now(),
+            received_instant: None,
+            raw: None,
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: DataKind::Trade(PublicTrade {
+                id: trade.id,
+                price: trade.price,
+                quantity: trade.quantity,
+                side: trade.side,
+            }),
+        }
+    }
+}
+
+impl From<(ExchangeId, Instrument, Interval, OkxCandle)> for MarketEvent {
+    fn from(
+        (exchange_id, instrument, interval, candle): (ExchangeId, Instrument, Interval, OkxCandle),
+    ) -> Self {
+        let end_time = interval.end_time(candle.start_time);
+
+        Self {
+            exchange_time: end_time,
+            received_time: Utc::now(),
+            received_instant: None,
+            raw: None,
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: DataKind::Candle(Candle {
+                start_time: candle.start_time,
+                end_time,
+                open: candle.open,
+                high: candle.high,
+                low: candle.low,
+                close: candle.close,
+                volume: candle.volume,
+                trade_count: 0,
+            }),
+        }
+    }
+}
+
+impl From<(ExchangeId, Instrument, OkxFundingRate)> for MarketEvent {
+    fn from(
+        (exchange_id, instrument, funding_rate): (ExchangeId, Instrument, OkxFundingRate),
+    ) -> Self {
+        Self {
+            exchange_time: funding_rate.time,
+            received_time: Utc::now(),
+            received_instant: None,
+            raw: None,
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: DataKind::FundingRate(FundingRate {
+                rate: funding_rate.rate,
+                next_funding_time: funding_rate.next_funding_time,
+            }),
+        }
+    }
+}
+
+impl From<(ExchangeId, Instrument, OkxOpenInterest)> for MarketEvent {
+    fn from(
+        (exchange_id, instrument, open_interest): (ExchangeId, Instrument, OkxOpenInterest),
+    ) -> Self {
+        Self {
+            exchange_time: open_interest.time,
+            received_time: Utc::now(),
+            received_instant: None,
+            raw: None,
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: DataKind::OpenInterest(OpenInterest {
+                quantity: open_interest.quantity,
+                notional: open_interest.notional,
+            }),
+        }
+    }
+}
+
+impl From<(ExchangeId, Instrument, OkxTicker)> for MarketEvent {
+    fn from((exchange_id, instrument, ticker): (ExchangeId, Instrument, OkxTicker)) -> Self {
+        // Derivatives "vol24h" is denominated in contracts rather than the base asset
+        let volume_24h = match instrument.kind {
+            InstrumentKind::Spot => ticker.volume,
+            _ => ticker.volume_currency,
+        };
+
+        // Okx does not send the 24 hour change, so derive it from the 24 hour open
+        let change_percent_24h = if ticker.open == 0.0 {
+            0.0
+        } else {
+            (ticker.last_price - ticker.open) / ticker.open * 100.0
+        };
+
+        Self {
+            exchange_time: ticker.time,
+            received_time: Utc::now(),
+            received_instant: None,
+            raw: None,
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: DataKind::Ticker(Ticker {
+                last_price: ticker.last_price,
+                volume_24h,
+                high_24h: ticker.high,
+                low_24h: ticker.low,
+                change_percent_24h,
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialise_okx_subscription_response() {
+        struct TestCase {
+            input: &'static str,
+            expected: Option<OkxSubResponse>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: input response is Subscribed
+                input: r#"{"event":"subscribe","arg":{"channel":"trades","instId":"BTC-USDT"},"connId":"a4d3ae55"}"#,
+                expected: Some(OkxSubResponse::Subscribed {
+                    arg: OkxSubscription::new("trades", "BTC-USDT"),
+                }),
+            },
+            TestCase {
+                // TC1: input response is Error
+                input: r#"{"event":"error","code":"60012","msg":"Invalid request","connId":"a4d3ae55"}"#,
+                expected: Some(OkxSubResponse::Error {
+                    code: "60012".to_owned(),
+                    msg: "Invalid request".to_owned(),
+                }),
+            },
+            TestCase {
+                // TC2: input response is malformed gibberish
+                input: r#"{"event": "gibberish", "help": "please"}"#,
+                expected: None,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = serde_json::from_str::<OkxSubResponse>(test.input).ok();
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_deserialise_okx_message() {
+        struct TestCase {
+            input: &'static str,
+            expected: Option<OkxMessage>,
+        }
+
+        let time = datetime_utc_from_epoch_duration(Duration::from_millis(1630048897897));
+
+        let cases = vec![
+            TestCase {
+                // TC0: input is a valid trades message
+                input: r#"{"arg":{"channel":"trades","instId":"BTC-USDT"},"data":[{"instId":"BTC-USDT","tradeId":"130639474","px":"42219.9","sz":"0.12060306","side":"buy","ts":"1630048897897","count":"3"}]}"#,
+                expected: Some(OkxMessage::Trades(OkxTrades {
+                    subscription: OkxSubscription::new("trades", "BTC-USDT"),
+                    data: vec![OkxTrade {
+                        id: "130639474".to_owned(),
+                        price: 42219.9,
+                        quantity: 0.12060306,
+                        side: Side::Buy,
+                        time,
+                    }],
+                })),
+            },
+            TestCase {
+                // TC1: input is a valid candles message w/ array of Strings payload
+                input: r#"{"arg":{"channel":"candle1m","instId":"BTC-USDT"},"data":[["1630048897897","8533.02","8553.74","8527.17","8548.26","45247","529.5858061","529.58","0"]]}"#,
+                expected: Some(OkxMessage::Candles(OkxCandles {
+                    subscription: OkxSubscription::new("candle1m", "BTC-USDT"),
+                    data: vec![OkxCandle {
+                        start_time: time,
+                        open: 8533.02,
+                        high: 8553.74,
+                        low: 8527.17,
+                        close: 8548.26,
+                        volume: 45247.0,
+                    }],
+                })),
+            },
+            TestCase {
+                // TC2: input is an error message
+                input: r#"{"event":"error","code":"60018","msg":"Invalid sign","connId":"a4d3ae55"}"#,
+                expected: Some(OkxMessage::Error(OkxError {
+                    event: "error".to_owned(),
+                    code: "60018".to_owned(),
+                    msg: "Invalid sign".to_owned(),
+                })),
+            },
+            TestCase {
+                // TC3: input is a valid books-l2-tbt snapshot message
+                input: r#"{"arg":{"channel":"books-l2-tbt","instId":"BTC-USDT"},"action":"snapshot","data":[{"asks":[["8476.98","415","0","13"]],"bids":[["8476.97","256","0","12"]],"ts":"1630048897897","checksum":-855196043,"prevSeqId":-1,"seqId":123456}]}"#,
+                expected: Some(OkxMessage::OrderBook(OkxOrderBookMessage {
+                    subscription: OkxSubscription::new("books-l2-tbt", "BTC-USDT"),
+                    action: OkxOrderBookAction::Snapshot,
+                    data: vec![OkxOrderBookData {
+                        asks: vec![OkxOrderBookLevel {
+                            price: "8476.98".to_owned(),
+                            size: "415".to_owned(),
+                            liquidated_orders: "0".to_owned(),
+                            orders: "13".to_owned(),
+                        }],
+                        bids: vec![OkxOrderBookLevel {
+                            price: "8476.97".to_owned(),
+                            size: "256".to_owned(),
+                            liquidated_orders: "0".to_owned(),
+                            orders: "12".to_owned(),
+                        }],
+                        time,
+                        checksum: -855196043,
+                        sequence: 123456,
+                    }],
+                })),
+            },
+            TestCase {
+                // TC4: input is a candles message w/ a non-numeric price
+                input: r#"{"arg":{"channel":"candle1m","instId":"BTC-USDT"},"data":[["1630048897897","open","8553.74","8527.17","8548.26","45247"]]}"#,
+                expected: None,
+            },
+            TestCase {
+                // TC5: input is a valid funding-rate message
+                input: r#"{"arg":{"channel":"funding-rate","instId":"BTC-USDT-SWAP"},"data":[{"instType":"SWAP","instId":"BTC-USDT-SWAP","fundingRate":"0.0001875391284828","fundingTime":"1630048897897","nextFundingRate":"","nextFundingTime":"1630077697897","ts":"1630048897897"}]}"#,
+                expected: Some(OkxMessage::FundingRates(OkxFundingRates {
+                    subscription: OkxSubscription::new("funding-rate", "BTC-USDT-SWAP"),
+                    data: vec![OkxFundingRate {
+                        rate: 0.0001875391284828,
+                        next_funding_time: time,
+                        time,
+                    }],
+                })),
+            },
+            TestCase {
+                // TC6: input is a valid open-interest message
+                input: r#"{"arg":{"channel":"open-interest","instId":"BTC-USDT-SWAP"},"data":[{"instType":"SWAP","instId":"BTC-USDT-SWAP","oi":"2216113.01","oiCcy":"22161.1301","oiUsd":"834213475.2113","ts":"1630048897897"}]}"#,
+                expected: Some(OkxMessage::OpenInterests(OkxOpenInterests {
+                    subscription: OkxSubscription::new("open-interest", "BTC-USDT-SWAP"),
+                    data: vec![OkxOpenInterest {
+                        quantity: 22161.1301,
+                        notional: Some(834213475.2113),
+                        time,
+                    }],
+                })),
+            },
+            TestCase {
+                // TC7: input is a valid tickers message
+                input: r#"{"arg":{"channel":"tickers","instId":"BTC-USDT"},"data":[{"instType":"SPOT","instId":"BTC-USDT","last":"9999.99","lastSz":"0.1","askPx":"9999.99","askSz":"11","bidPx":"8888.88","bidSz":"5","open24h":"9000","high24h":"10000","low24h":"8888.88","volCcy24h":"2222","vol24h":"2222","sodUtc0":"2222","sodUtc8":"2222","ts":"1630048897897"}]}"#,
+                expected: Some(OkxMessage::Tickers(OkxTickers {
+                    subscription: OkxSubscription::new("tickers", "BTC-USDT"),
+                    data: vec![OkxTicker {
+                        last_price: 9999.99,
+                        open: 9000.0,
+                        high: 10000.0,
+                        low: 8888.88,
+                        volume: 2222.0,
+                        volume_currency: 2222.0,
+                        time,
+                    }],
+                })),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = serde_json::from_str::<OkxMessage>(test.input).ok();
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
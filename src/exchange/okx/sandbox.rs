@@ -0,0 +1,79 @@
+use super::{
+    model::{OkxMessage, OkxSubResponse},
+    Okx,
+};
+use crate::{
+    model::subscription::{SubscriptionIds, SubscriptionMeta},
+    orderbook::{ChecksumPolicy, ChecksumValidation},
+    ExchangeId, ExchangeTransformer, MarketEvent, Subscriber, Subscription,
+};
+use barter_integration::{error::SocketError, protocol::websocket::WsMessage, Transformer};
+use tokio::sync::mpsc;
+
+/// [`OkxSandbox`] [`Subscriber`] & [`ExchangeTransformer`] implementor for the collection
+/// of `Spot` & `FuturePerpetual` data from the OKX demo trading (simulated markets) environment.
+///
+/// Channels & messages are identical to [`Okx`], so subscriptions are actioned & messages are
+/// transformed by an inner [`Okx`]. Enabled via
+/// [`StreamBuilder::sandbox`](crate::builder::StreamBuilder::sandbox).
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#overview-demo-trading-services>
+#[derive(Debug)]
+pub struct OkxSandbox {
+    pub okx: Okx,
+}
+
+impl Subscriber for OkxSandbox {
+    type SubResponse = OkxSubResponse;
+
+    fn base_url() -> &'static str {
+        ExchangeId::Okx
+            .sandbox_ws_base_url()
+            .expect("Okx provides a sandbox WebSocket API")
+    }
+
+    fn build_subscription_meta(
+        subscriptions: &[Subscription],
+    ) -> Result<SubscriptionMeta, SocketError> {
+        Okx::build_subscription_meta(subscriptions)
+    }
+}
+
+impl ExchangeTransformer for OkxSandbox {
+    const EXCHANGE: ExchangeId = ExchangeId::Okx;
+
+    fn new(ws_sink_tx: mpsc::UnboundedSender<WsMessage>, ids: SubscriptionIds) -> Self {
+        Self {
+            okx: Okx::new(ws_sink_tx, ids),
+        }
+    }
+}
+
+impl ChecksumValidation for OkxSandbox {
+    fn set_checksum_policy(&mut self, policy: ChecksumPolicy) {
+        self.okx.set_checksum_policy(policy)
+    }
+}
+
+impl Transformer<MarketEvent> for OkxSandbox {
+    type Input = OkxMessage;
+    type OutputIter = Vec<Result<MarketEvent, SocketError>>;
+
+    fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+        self.okx.transform(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_okx_sandbox_base_url() {
+        assert_eq!(
+            OkxSandbox::base_url(),
+            "wss://wspap.okx.com:8443/ws/v5/public"
+        );
+        assert_ne!(OkxSandbox::base_url(), Okx::base_url());
+    }
+}
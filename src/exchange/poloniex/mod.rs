@@ -0,0 +1,380 @@
+use crate::{
+    model::subscription::{SubKind, SubscriptionIds, SubscriptionMeta},
+    ExchangeId, ExchangeTransformer, MarketEvent, Subscriber, Subscription, Validator,
+};
+use barter_integration::{
+    error::SocketError, model::SubscriptionId, protocol::websocket::WsMessage, Transformer,
+};
+use model::{
+    PoloniexEvent, PoloniexInterval, PoloniexMessage, PoloniexSubResponse, PoloniexSymbol,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::{collections::HashMap, time::Duration};
+use tokio::sync::mpsc;
+
+/// [`Poloniex`] specific data structures.
+pub mod model;
+
+/// Interval at which a [`Poloniex`] application level ping is sent to keep the connection alive.
+///
+/// See docs: <https://api-docs.poloniex.com/spot/websocket/#heartbeats>
+pub const PING_INTERVAL: Duration = Duration::from_secs(20);
+
+/// [`Poloniex`] [`Subscriber`] & [`ExchangeTransformer`] implementor for the collection
+/// of `Spot` data via the Poloniex v3 public WebSocket API.
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct Poloniex {
+    pub ids: SubscriptionIds,
+}
+
+impl Subscriber for Poloniex {
+    type SubResponse = PoloniexSubResponse;
+
+    fn base_url() -> &'static str {
+        ExchangeId::Poloniex.ws_base_url()
+    }
+
+    fn build_subscription_meta(
+        subscriptions: &[Subscription],
+    ) -> Result<SubscriptionMeta, SocketError> {
+        // Allocate SubscriptionIds HashMap to track identifiers for each actioned Subscription
+        let mut ids = SubscriptionIds(HashMap::with_capacity(subscriptions.len()));
+
+        // Group Poloniex symbols by channel, preserving the Subscription order
+        let mut channels: Vec<(String, Vec<String>)> = Vec::new();
+        for subscription in subscriptions {
+            // Determine the Poloniex specific channel & symbol for this Subscription
+            let (channel, symbol) = Self::build_channel_meta(subscription)?;
+
+            // Use "channel|symbol" as the SubscriptionId key in the SubscriptionIds
+            // eg/ SubscriptionId("trades|BTC_USDT")
+            ids.insert(
+                Poloniex::subscription_id(&channel, &symbol),
+                subscription.clone(),
+            );
+
+            match channels
+                .iter_mut()
+                .find(|(existing, _)| *existing == channel)
+            {
+                Some((_, symbols)) => symbols.push(symbol),
+                None => channels.push((channel, vec![symbol])),
+            }
+        }
+
+        let subscriptions = channels
+            .into_iter()
+            .map(|(channel, symbols)| Self::subscription(&channel, &symbols))
+            .collect::<Vec<WsMessage>>();
+
+        Ok(SubscriptionMeta {
+            ids,
+            expected_responses: subscriptions.len(),
+            subscriptions,
+        })
+    }
+}
+
+impl ExchangeTransformer for Poloniex {
+    const EXCHANGE: ExchangeId = ExchangeId::Poloniex;
+
+    fn new(ws_sink_tx: mpsc::UnboundedSender<WsMessage>, ids: SubscriptionIds) -> Self {
+        spawn_ping_task(ws_sink_tx);
+        Self { ids }
+    }
+}
+
+impl Transformer<MarketEvent> for Poloniex {
+    type Input = PoloniexMessage;
+    type OutputIter = Vec<Result<MarketEvent, SocketError>>;
+
+    fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+        match input {
+            PoloniexMessage::Trades(trades) => trades
+                .data
+                .into_iter()
+                .map(|trade| {
+                    self.ids
+                        .find_instrument(&SubscriptionId::from(&trade))
+                        .map(|instrument| MarketEvent::from((Self::EXCHANGE, instrument, trade)))
+                })
+                .collect(),
+            PoloniexMessage::Candles(candles) => candles
+                .data
+                .into_iter()
+                .map(|candle| {
+                    self.ids
+                        .find_instrument(&Self::subscription_id(&candles.channel, &candle.symbol))
+                        .map(|instrument| MarketEvent::from((Self::EXCHANGE, instrument, candle)))
+                })
+                .collect(),
+            PoloniexMessage::Event(PoloniexEvent::Subscribe | PoloniexEvent::Pong) => vec![],
+            PoloniexMessage::Event(PoloniexEvent::Error { message }) => {
+                vec![Err(SocketError::Exchange(message))]
+            }
+        }
+    }
+}
+
+impl Poloniex {
+    /// [`Poloniex`] trades channel name.
+    ///
+    /// See docs: <https://api-docs.poloniex.com/spot/websocket/market-data#trades>
+    pub const CHANNEL_TRADES: &'static str = "trades";
+
+    /// [`Poloniex`] candles channel name prefix, suffixed with a [`PoloniexInterval`]
+    /// (eg/ "candles_minute_1").
+    ///
+    /// See docs: <https://api-docs.poloniex.com/spot/websocket/market-data#candlesticks>
+    pub const CHANNEL_CANDLES: &'static str = "candles_";
+
+    /// Determine the [`Poloniex`] channel metadata associated with an input Barter
+    /// [`Subscription`]. This includes the [`Poloniex`] channel name, and a `String` symbol.
+    /// Both are used to build a [`Poloniex`] subscription payload.
+    ///
+    /// Example Ok return: Ok("candles_minute_1", "BTC_USDT")
+    /// where channel == "candles_minute_1" & symbol == "BTC_USDT".
+    pub fn build_channel_meta(sub: &Subscription) -> Result<(String, String), SocketError> {
+        // Validate provided Subscription InstrumentKind is supported by Poloniex
+        let sub = sub.validate()?;
+
+        // Determine Poloniex channel using the Subscription SubKind
+        let channel = match &sub.kind {
+            SubKind::Trade => Self::CHANNEL_TRADES.to_owned(),
+            SubKind::Candle(interval) => format!(
+                "{}{}",
+                Self::CHANNEL_CANDLES,
+                PoloniexInterval::try_from(interval)?.0
+            ),
+            other => {
+                return Err(SocketError::Unsupported {
+                    entity: Self::EXCHANGE.as_str(),
+                    item: other.to_string(),
+                })
+            }
+        };
+
+        // Determine Poloniex symbol using the Instrument (eg/ "BTC_USDT")
+        let PoloniexSymbol(symbol) = PoloniexSymbol::from(&sub.instrument);
+
+        Ok((channel, symbol))
+    }
+
+    /// Build a [`Poloniex`] compatible subscription message using the channel & symbols
+    /// provided.
+    ///
+    /// eg/ {"event":"subscribe","channel":["trades"],"symbols":["BTC_USDT"]}
+    pub fn subscription(channel: &str, symbols: &[String]) -> WsMessage {
+        WsMessage::Text(
+            json!({
+                "event": "subscribe",
+                "channel": [channel],
+                "symbols": symbols,
+            })
+            .to_string(),
+        )
+    }
+
+    /// Build a [`Poloniex`] compatible [`SubscriptionId`] using the channel & symbol provided.
+    /// This is used to associate [`Poloniex`] data structures received over the WebSocket with
+    /// it's original Barter [`Subscription`].
+    ///
+    /// eg/ SubscriptionId("trades|BTC_USDT")
+    pub fn subscription_id(channel: &str, symbol: &str) -> SubscriptionId {
+        SubscriptionId::from(format!("{channel}|{symbol}"))
+    }
+}
+
+/// Spawn a task that sends a [`Poloniex`] ping every [`PING_INTERVAL`] via the [`WsSink`]
+/// transmitter. The task ends once the WebSocket connection has been dropped.
+///
+/// [`WsSink`]: barter_integration::protocol::websocket::WsSink
+pub fn spawn_ping_task(ws_sink_tx: mpsc::UnboundedSender<WsMessage>) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(PING_INTERVAL);
+        loop {
+            interval.tick().await;
+            let ping = WsMessage::Text(json!({ "event": "ping" }).to_string());
+            if ws_sink_tx.send(ping).is_err() {
+                break;
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchange::datetime_utc_from_epoch_duration;
+    use crate::model::{subscription::Interval, Candle, DataKind, PublicTrade};
+    use barter_integration::model::{InstrumentKind, Side};
+
+    #[test]
+    fn test_build_channel_meta() {
+        struct TestCase {
+            input: Subscription,
+            expected: Result<(String, String), SocketError>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: Supported InstrumentKind::Spot trades subscription
+                input: Subscription::new(
+                    ExchangeId::Poloniex,
+                    ("btc", "usdt", InstrumentKind::Spot),
+                    SubKind::Trade,
+                ),
+                expected: Ok(("trades".to_owned(), "BTC_USDT".to_owned())),
+            },
+            TestCase {
+                // TC1: Supported InstrumentKind::Spot candles subscription
+                input: Subscription::new(
+                    ExchangeId::Poloniex,
+                    ("eth", "usdt", InstrumentKind::Spot),
+                    SubKind::Candle(Interval::Hour4),
+                ),
+                expected: Ok(("candles_hour_4".to_owned(), "ETH_USDT".to_owned())),
+            },
+            TestCase {
+                // TC2: Unsupported candle Interval
+                input: Subscription::new(
+                    ExchangeId::Poloniex,
+                    ("btc", "usdt", InstrumentKind::Spot),
+                    SubKind::Candle(Interval::Minute3),
+                ),
+                expected: Err(SocketError::Subscribe("unsupported".to_owned())),
+            },
+            TestCase {
+                // TC3: Unsupported InstrumentKind::FuturePerpetual subscription
+                input: Subscription::new(
+                    ExchangeId::Poloniex,
+                    ("btc", "usdt", InstrumentKind::FuturePerpetual),
+                    SubKind::Trade,
+                ),
+                expected: Err(SocketError::Subscribe("unsupported".to_owned())),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = Poloniex::build_channel_meta(&test.input);
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => {
+                    assert_eq!(actual, expected, "TC{} failed", index)
+                }
+                (Err(_), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_poloniex_transform() {
+        let mut transformer = Poloniex {
+            ids: Poloniex::build_subscription_meta(&[
+                Subscription::new(
+                    ExchangeId::Poloniex,
+                    ("btc", "usdt", InstrumentKind::Spot),
+                    SubKind::Trade,
+                ),
+                Subscription::new(
+                    ExchangeId::Poloniex,
+                    ("btc", "usdt", InstrumentKind::Spot),
+                    SubKind::Candle(Interval::Minute1),
+                ),
+            ])
+            .unwrap()
+            .ids,
+        };
+
+        struct TestCase {
+            input: PoloniexMessage,
+            expected: Vec<Result<DataKind, SocketError>>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: PoloniexMessage::Trades w/ subscribed & unsubscribed symbols
+                input: serde_json::from_str(
+                    r#"{"channel":"trades","data":[
+                        {"symbol":"BTC_USDT","amount":"70","takerSide":"sell","quantity":"4","createTime":1648059516810,"price":"104","id":"1","ts":1648059516832},
+                        {"symbol":"ETH_USDT","amount":"70","takerSide":"buy","quantity":"4","createTime":1648059516810,"price":"104","id":"2","ts":1648059516832}
+                    ]}"#,
+                )
+                .unwrap(),
+                expected: vec![
+                    Ok(DataKind::Trade(PublicTrade {
+                        id: "1".to_owned(),
+                        price: 104.0,
+                        quantity: 4.0,
+                        side: Side::Sell,
+                    })),
+                    Err(SocketError::Unidentifiable(SubscriptionId::from(
+                        "trades|ETH_USDT",
+                    ))),
+                ],
+            },
+            TestCase {
+                // TC1: PoloniexMessage::Candles for a subscribed symbol
+                input: serde_json::from_str(
+                    r#"{"channel":"candles_minute_1","data":[{"symbol":"BTC_USDT","amount":"0","high":"9999.07","quantity":"0.5","tradeCount":3,"low":"9999.01","closeTime":1648057199999,"startTime":1648057140000,"close":"9999.05","open":"9999.02","ts":1648057141081}]}"#,
+                )
+                .unwrap(),
+                expected: vec![Ok(DataKind::Candle(Candle {
+                    start_time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                        1648057140000,
+                    )),
+                    end_time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                        1648057199999,
+                    )),
+                    open: 9999.02,
+                    high: 9999.07,
+                    low: 9999.01,
+                    close: 9999.05,
+                    volume: 0.5,
+                    trade_count: 3,
+                }))],
+            },
+            TestCase {
+                // TC2: PoloniexMessage::Candles for an unsubscribed interval
+                input: serde_json::from_str(
+                    r#"{"channel":"candles_minute_5","data":[{"symbol":"BTC_USDT","amount":"0","high":"1","quantity":"0","tradeCount":0,"low":"1","closeTime":1648057199999,"startTime":1648056900000,"close":"1","open":"1","ts":1648057141081}]}"#,
+                )
+                .unwrap(),
+                expected: vec![Err(SocketError::Unidentifiable(SubscriptionId::from(
+                    "candles_minute_5|BTC_USDT",
+                )))],
+            },
+            TestCase {
+                // TC3: PoloniexMessage::Event pong is ignored
+                input: serde_json::from_str(r#"{"event":"pong"}"#).unwrap(),
+                expected: vec![],
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = transformer.transform(test.input);
+            assert_eq!(actual.len(), test.expected.len(), "TC{} failed", index);
+
+            for (actual, expected) in actual.into_iter().zip(test.expected) {
+                match (actual, expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual.kind, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+}
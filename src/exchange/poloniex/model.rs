@@ -0,0 +1,382 @@
+use crate::{
+    exchange::{de_str, de_u64_epoch_ms_as_datetime_utc},
+    model::{subscription::Interval, Candle, DataKind, PublicTrade},
+    ExchangeId, MarketEvent,
+};
+use barter_integration::{
+    error::SocketError,
+    model::{Exchange, Instrument, Side, SubscriptionId},
+    Validator,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// [`Poloniex`](super::Poloniex) message received in response to WebSocket subscription
+/// requests.
+///
+/// eg/ {"event":"subscribe","channel":"trades","symbols":["BTC_USDT"]}
+/// eg/ {"event":"error","message":"Subscription failed (generic)"}
+///
+/// See docs: <https://api-docs.poloniex.com/spot/websocket/market-data>
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum PoloniexSubResponse {
+    Subscribe {
+        channel: String,
+        symbols: Vec<String>,
+    },
+    Error {
+        message: String,
+    },
+}
+
+impl Validator for PoloniexSubResponse {
+    fn validate(self) -> Result<Self, SocketError>
+    where
+        Self: Sized,
+    {
+        match &self {
+            PoloniexSubResponse::Subscribe { .. } => Ok(self),
+            PoloniexSubResponse::Error { message } => Err(SocketError::Subscribe(format!(
+                "received failure subscription response: {message}"
+            ))),
+        }
+    }
+}
+
+/// [`Poloniex`](super::Poloniex) symbol, base first (eg/ "BTC_USDT").
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct PoloniexSymbol(pub String);
+
+impl From<&Instrument> for PoloniexSymbol {
+    fn from(instrument: &Instrument) -> Self {
+        Self(format!("{}_{}", instrument.base, instrument.quote).to_uppercase())
+    }
+}
+
+/// [`Poloniex`](super::Poloniex) candle interval, used as the suffix of the candles channel
+/// (eg/ "candles_minute_1").
+///
+/// See docs: <https://api-docs.poloniex.com/spot/websocket/market-data#candlesticks>
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct PoloniexInterval(pub &'static str);
+
+impl TryFrom<&Interval> for PoloniexInterval {
+    type Error = SocketError;
+
+    fn try_from(interval: &Interval) -> Result<Self, Self::Error> {
+        Ok(PoloniexInterval(match interval {
+            Interval::Minute1 => "minute_1",
+            Interval::Minute5 => "minute_5",
+            Interval::Minute15 => "minute_15",
+            Interval::Minute30 => "minute_30",
+            Interval::Hour1 => "hour_1",
+            Interval::Hour2 => "hour_2",
+            Interval::Hour4 => "hour_4",
+            Interval::Hour6 => "hour_6",
+            Interval::Hour12 => "hour_12",
+            Interval::Day1 => "day_1",
+            Interval::Day3 => "day_3",
+            Interval::Week1 => "week_1",
+            Interval::Month1 => "month_1",
+            other => {
+                return Err(SocketError::Unsupported {
+                    entity: ExchangeId::Poloniex.as_str(),
+                    item: other.to_string(),
+                })
+            }
+        }))
+    }
+}
+
+/// [`Poloniex`](super::Poloniex) message variants that could be received over
+/// [`WebSocket`](crate::WebSocket).
+///
+/// See docs: <https://api-docs.poloniex.com/spot/websocket/market-data>
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum PoloniexMessage {
+    Trades(PoloniexTrades),
+    Candles(PoloniexCandles),
+    Event(PoloniexEvent),
+}
+
+/// [`Poloniex`](super::Poloniex) event message (eg/ subscription confirmations & pongs).
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum PoloniexEvent {
+    Subscribe,
+    Pong,
+    Error { message: String },
+}
+
+/// [`Poloniex`](super::Poloniex) batch of real-time [`PoloniexTrade`]s.
+///
+/// eg/ {"channel":"trades","data":[{"symbol":"BTC_USDT","amount":"70","takerSide":"buy",
+///      "quantity":"4","createTime":1648059516810,"price":"104","id":"1648059516810",
+///      "ts":1648059516832}]}
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct PoloniexTrades {
+    pub channel: PoloniexTradesChannel,
+    pub data: Vec<PoloniexTrade>,
+}
+
+/// [`Poloniex`](super::Poloniex) trades channel name.
+#[derive(Copy, Clone, Eq, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PoloniexTradesChannel {
+    Trades,
+}
+
+/// [`Poloniex`](super::Poloniex) real-time trade.
+///
+/// See docs: <https://api-docs.poloniex.com/spot/websocket/market-data#trades>
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct PoloniexTrade {
+    pub symbol: String,
+    #[serde(deserialize_with = "de_str")]
+    pub id: String,
+    #[serde(deserialize_with = "de_str")]
+    pub price: f64,
+    #[serde(deserialize_with = "de_str")]
+    pub quantity: f64,
+    #[serde(rename = "takerSide")]
+    pub side: Side,
+    #[serde(
+        rename = "createTime",
+        deserialize_with = "de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+}
+
+impl From<&PoloniexTrade> for SubscriptionId {
+    fn from(trade: &PoloniexTrade) -> Self {
+        super::Poloniex::subscription_id(super::Poloniex::CHANNEL_TRADES, &trade.symbol)
+    }
+}
+
+impl From<(ExchangeId, Instrument, PoloniexTrade)> for MarketEvent {
+    fn from((exchange_id, instrument, trade): (ExchangeId, Instrument, PoloniexTrade)) -> Self {
+        Self {
+            exchange_time: trade.time,
+            received_time: Utc::now(),
+            received_instant: None,
+            raw: None,
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: DataKind::Trade(PublicTrade {
+                id: trade.id,
+                price: trade.price,
+                quantity: trade.quantity,
+                side: trade.side,
+            }),
+        }
+    }
+}
+
+/// [`Poloniex`](super::Poloniex) batch of real-time [`PoloniexCandle`]s.
+///
+/// eg/ {"channel":"candles_minute_1","data":[{"symbol":"BTC_USDT","amount":"0",
+///      "high":"9999.07","quantity":"0","tradeCount":0,"low":"9999.07",
+///      "closeTime":1648057199999,"startTime":1648057140000,"close":"9999.07",
+///      "open":"9999.07","ts":1648057141081}]}
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct PoloniexCandles {
+    pub channel: String,
+    pub data: Vec<PoloniexCandle>,
+}
+
+/// [`Poloniex`](super::Poloniex) real-time candle, updated as trades occur within the candle.
+///
+/// See docs: <https://api-docs.poloniex.com/spot/websocket/market-data#candlesticks>
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct PoloniexCandle {
+    pub symbol: String,
+    #[serde(
+        rename = "startTime",
+        deserialize_with = "de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub start_time: DateTime<Utc>,
+    #[serde(
+        rename = "closeTime",
+        deserialize_with = "de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub end_time: DateTime<Utc>,
+    #[serde(deserialize_with = "de_str")]
+    pub open: f64,
+    #[serde(deserialize_with = "de_str")]
+    pub high: f64,
+    #[serde(deserialize_with = "de_str")]
+    pub low: f64,
+    #[serde(deserialize_with = "de_str")]
+    pub close: f64,
+    #[serde(deserialize_with = "de_str")]
+    pub quantity: f64,
+    #[serde(rename = "tradeCount")]
+    pub trade_count: u64,
+}
+
+impl From<(ExchangeId, Instrument, PoloniexCandle)> for MarketEvent {
+    fn from((exchange_id, instrument, candle): (ExchangeId, Instrument, PoloniexCandle)) -> Self {
+        Self {
+            exchange_time: candle.end_time,
+            received_time: Utc::now(),
+            received_instant: None,
+            raw: None,
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: DataKind::Candle(Candle {
+                start_time: candle.start_time,
+                end_time: candle.end_time,
+                open: candle.open,
+                high: candle.high,
+                low: candle.low,
+                close: candle.close,
+                volume: candle.quantity,
+                trade_count: candle.trade_count,
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchange::datetime_utc_from_epoch_duration;
+    use std::time::Duration;
+
+    #[test]
+    fn test_deserialise_poloniex_message() {
+        struct TestCase {
+            input: &'static str,
+            expected: Result<PoloniexMessage, SocketError>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: valid trades
+                input: r#"{
+                    "channel":"trades",
+                    "data":[{
+                        "symbol":"BTC_USDT","amount":"70","takerSide":"buy","quantity":"4",
+                        "createTime":1648059516810,"price":"104","id":"1648059516810",
+                        "ts":1648059516832
+                    }]
+                }"#,
+                expected: Ok(PoloniexMessage::Trades(PoloniexTrades {
+                    channel: PoloniexTradesChannel::Trades,
+                    data: vec![PoloniexTrade {
+                        symbol: "BTC_USDT".to_owned(),
+                        id: "1648059516810".to_owned(),
+                        price: 104.0,
+                        quantity: 4.0,
+                        side: Side::Buy,
+                        time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1648059516810,
+                        )),
+                    }],
+                })),
+            },
+            TestCase {
+                // TC1: valid candles
+                input: r#"{
+                    "channel":"candles_minute_1",
+                    "data":[{
+                        "symbol":"BTC_USDT","amount":"0","high":"9999.07","quantity":"0.5",
+                        "tradeCount":3,"low":"9999.01","closeTime":1648057199999,
+                        "startTime":1648057140000,"close":"9999.05","open":"9999.02",
+                        "ts":1648057141081
+                    }]
+                }"#,
+                expected: Ok(PoloniexMessage::Candles(PoloniexCandles {
+                    channel: "candles_minute_1".to_owned(),
+                    data: vec![PoloniexCandle {
+                        symbol: "BTC_USDT".to_owned(),
+                        start_time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1648057140000,
+                        )),
+                        end_time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1648057199999,
+                        )),
+                        open: 9999.02,
+                        high: 9999.07,
+                        low: 9999.01,
+                        close: 9999.05,
+                        quantity: 0.5,
+                        trade_count: 3,
+                    }],
+                })),
+            },
+            TestCase {
+                // TC2: valid pong
+                input: r#"{"event":"pong"}"#,
+                expected: Ok(PoloniexMessage::Event(PoloniexEvent::Pong)),
+            },
+            TestCase {
+                // TC3: valid error
+                input: r#"{"event":"error","message":"Subscription failed (generic)"}"#,
+                expected: Ok(PoloniexMessage::Event(PoloniexEvent::Error {
+                    message: "Subscription failed (generic)".to_owned(),
+                })),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = serde_json::from_str::<PoloniexMessage>(test.input);
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => {
+                    assert_eq!(actual, expected, "TC{} failed", index)
+                }
+                (Err(_), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_validate_poloniex_sub_response() {
+        struct TestCase {
+            input: &'static str,
+            expected: Result<PoloniexSubResponse, SocketError>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: input response is subscription success
+                input: r#"{"event":"subscribe","channel":"trades","symbols":["BTC_USDT"]}"#,
+                expected: Ok(PoloniexSubResponse::Subscribe {
+                    channel: "trades".to_owned(),
+                    symbols: vec!["BTC_USDT".to_owned()],
+                }),
+            },
+            TestCase {
+                // TC1: input response is subscription failure
+                input: r#"{"event":"error","message":"Subscription failed (generic)"}"#,
+                expected: Err(SocketError::Subscribe("failure".to_owned())),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = serde_json::from_str::<PoloniexSubResponse>(test.input)
+                .unwrap()
+                .validate();
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => {
+                    assert_eq!(actual, expected, "TC{} failed", index)
+                }
+                (Err(_), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,393 @@
+use crate::{
+    model::subscription::{SubKind, SubscriptionIds, SubscriptionMeta},
+    ExchangeId, ExchangeTransformer, MarketEvent, Subscriber, Subscription, Validator,
+};
+use barter_integration::{
+    error::SocketError, model::SubscriptionId, protocol::websocket::WsMessage, Transformer,
+};
+use model::{UpbitInterval, UpbitMarket, UpbitMessage, UpbitSubResponse};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::collections::HashMap;
+use tokio::sync::mpsc;
+
+/// [`Upbit`] specific data structures.
+pub mod model;
+
+/// [`Upbit`] [`Subscriber`] & [`ExchangeTransformer`] implementor for the collection
+/// of `Spot` KRW market data.
+///
+/// Note that [`Upbit`] sends JSON payloads in binary WebSocket frames, and expects every
+/// subscription for a connection to be sent in a single request message.
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct Upbit {
+    pub ids: SubscriptionIds,
+}
+
+impl Subscriber for Upbit {
+    type SubResponse = UpbitSubResponse;
+
+    fn base_url() -> &'static str {
+        ExchangeId::Upbit.ws_base_url()
+    }
+
+    fn build_subscription_meta(
+        subscriptions: &[Subscription],
+    ) -> Result<SubscriptionMeta, SocketError> {
+        // Allocate SubscriptionIds HashMap to track identifiers for each actioned Subscription
+        let mut ids = SubscriptionIds(HashMap::with_capacity(subscriptions.len()));
+
+        // Group Upbit market codes by channel type, preserving the Subscription order
+        let mut channels: Vec<(String, Vec<String>)> = Vec::new();
+        for subscription in subscriptions {
+            // Determine the Upbit specific channel type & market code for this Subscription
+            let (channel, code) = Self::build_channel_meta(subscription)?;
+
+            // Use "channel|code" as the SubscriptionId key in the SubscriptionIds
+            // eg/ SubscriptionId("trade|KRW-BTC")
+            ids.insert(
+                Upbit::subscription_id(&channel, &code),
+                subscription.clone(),
+            );
+
+            match channels
+                .iter_mut()
+                .find(|(existing, _)| *existing == channel)
+            {
+                Some((_, codes)) => codes.push(code),
+                None => channels.push((channel, vec![code])),
+            }
+        }
+
+        Ok(SubscriptionMeta {
+            ids,
+            // Upbit does not send subscription responses
+            expected_responses: 0,
+            subscriptions: vec![Self::subscription(&channels)],
+        })
+    }
+}
+
+impl ExchangeTransformer for Upbit {
+    const EXCHANGE: ExchangeId = ExchangeId::Upbit;
+    fn new(_: mpsc::UnboundedSender<WsMessage>, ids: SubscriptionIds) -> Self {
+        Self { ids }
+    }
+}
+
+impl Transformer<MarketEvent> for Upbit {
+    type Input = UpbitMessage;
+    type OutputIter = Vec<Result<MarketEvent, SocketError>>;
+
+    fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+        match input {
+            UpbitMessage::Trade(trade) => {
+                match self.ids.find_instrument(&SubscriptionId::from(&trade)) {
+                    Ok(instrument) => {
+                        vec![Ok(MarketEvent::from((Self::EXCHANGE, instrument, trade)))]
+                    }
+                    Err(error) => vec![Err(error)],
+                }
+            }
+            UpbitMessage::Candle(candle) => {
+                match self.ids.find_instrument(&SubscriptionId::from(&candle)) {
+                    Ok(instrument) => {
+                        vec![MarketEvent::try_from((Self::EXCHANGE, instrument, candle))]
+                    }
+                    Err(error) => vec![Err(error)],
+                }
+            }
+            UpbitMessage::Error(error) => vec![Err(SocketError::Exchange(format!(
+                "{}: {}",
+                error.error.name, error.error.message
+            )))],
+        }
+    }
+}
+
+impl Upbit {
+    /// [`Upbit`] trades channel type.
+    ///
+    /// See docs: <https://global-docs.upbit.com/reference/websocket-trade>
+    pub const CHANNEL_TRADES: &'static str = "trade";
+
+    /// [`Upbit`] candles channel type prefix, suffixed with an [`UpbitInterval`]
+    /// (eg/ "candle.1m").
+    ///
+    /// See docs: <https://global-docs.upbit.com/reference/websocket-candle>
+    pub const CHANNEL_CANDLES: &'static str = "candle.";
+
+    /// [`Upbit`] subscription ticket, used by the exchange to identify the requester.
+    pub const TICKET: &'static str = "barter-data";
+
+    /// Determine the [`Upbit`] channel metadata associated with an input Barter
+    /// [`Subscription`]. This includes the [`Upbit`] channel type, and a `String` market
+    /// code. Both are used to build an [`Upbit`] subscription payload.
+    ///
+    /// Example Ok return: Ok("candle.1m", "KRW-BTC")
+    /// where channel == "candle.1m" & code == "KRW-BTC".
+    pub fn build_channel_meta(sub: &Subscription) -> Result<(String, String), SocketError> {
+        // Validate provided Subscription InstrumentKind is supported by Upbit
+        let sub = sub.validate()?;
+
+        // Determine Upbit channel type using the Subscription SubKind
+        let channel = match &sub.kind {
+            SubKind::Trade => Self::CHANNEL_TRADES.to_owned(),
+            SubKind::Candle(interval) => format!(
+                "{}{}",
+                Self::CHANNEL_CANDLES,
+                UpbitInterval::try_from(interval)?.0
+            ),
+            other => {
+                return Err(SocketError::Unsupported {
+                    entity: Self::EXCHANGE.as_str(),
+                    item: other.to_string(),
+                })
+            }
+        };
+
+        // Determine Upbit market code using the Instrument (eg/ "KRW-BTC")
+        let code = UpbitMarket::from(&sub.instrument).to_string();
+
+        Ok((channel, code))
+    }
+
+    /// Build the single [`Upbit`] compatible subscription message for a connection using the
+    /// channel types & market codes provided.
+    ///
+    /// eg/ [{"ticket":"barter-data"},{"type":"trade","codes":["KRW-BTC"]},{"format":"DEFAULT"}]
+    pub fn subscription(channels: &[(String, Vec<String>)]) -> WsMessage {
+        let mut request = vec![json!({ "ticket": Self::TICKET })];
+        request.extend(
+            channels
+                .iter()
+                .map(|(channel, codes)| json!({ "type": channel, "codes": codes })),
+        );
+        request.push(json!({ "format": "DEFAULT" }));
+
+        WsMessage::Text(serde_json::Value::Array(request).to_string())
+    }
+
+    /// Build an [`Upbit`] compatible [`SubscriptionId`] using the channel type & market code
+    /// provided. This is used to associate [`Upbit`] data structures received over the
+    /// WebSocket with it's original Barter [`Subscription`].
+    ///
+    /// eg/ SubscriptionId("trade|KRW-BTC")
+    pub fn subscription_id(channel: &str, code: &str) -> SubscriptionId {
+        SubscriptionId::from(format!("{channel}|{code}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{subscription::Interval, Candle, DataKind, PublicTrade};
+    use barter_integration::model::{InstrumentKind, Side};
+    use chrono::{TimeZone, Utc};
+
+    #[test]
+    fn test_build_channel_meta() {
+        struct TestCase {
+            input: Subscription,
+            expected: Result<(String, String), SocketError>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: Supported InstrumentKind::Spot trades subscription
+                input: Subscription::new(
+                    ExchangeId::Upbit,
+                    ("btc", "krw", InstrumentKind::Spot),
+                    SubKind::Trade,
+                ),
+                expected: Ok(("trade".to_owned(), "KRW-BTC".to_owned())),
+            },
+            TestCase {
+                // TC1: Supported InstrumentKind::Spot candles subscription
+                input: Subscription::new(
+                    ExchangeId::Upbit,
+                    ("eth", "krw", InstrumentKind::Spot),
+                    SubKind::Candle(Interval::Hour4),
+                ),
+                expected: Ok(("candle.240m".to_owned(), "KRW-ETH".to_owned())),
+            },
+            TestCase {
+                // TC2: Unsupported candle Interval
+                input: Subscription::new(
+                    ExchangeId::Upbit,
+                    ("btc", "krw", InstrumentKind::Spot),
+                    SubKind::Candle(Interval::Day1),
+                ),
+                expected: Err(SocketError::Subscribe(
+                    "unsupported candle interval".to_owned(),
+                )),
+            },
+            TestCase {
+                // TC3: Unsupported InstrumentKind::FuturePerpetual subscription
+                input: Subscription::new(
+                    ExchangeId::Upbit,
+                    ("btc", "krw", InstrumentKind::FuturePerpetual),
+                    SubKind::Trade,
+                ),
+                expected: Err(SocketError::Subscribe("unsupported".to_owned())),
+            },
+            TestCase {
+                // TC4: Unsupported SubKind::OrderBookL2Delta subscription
+                input: Subscription::new(
+                    ExchangeId::Upbit,
+                    ("btc", "krw", InstrumentKind::Spot),
+                    SubKind::OrderBookL2Delta,
+                ),
+                expected: Err(SocketError::Subscribe("unsupported".to_owned())),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = Upbit::build_channel_meta(&test.input);
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => {
+                    assert_eq!(actual, expected, "TC{} failed", index)
+                }
+                (Err(_), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_build_subscription_meta() {
+        let subscriptions = vec![
+            Subscription::new(
+                ExchangeId::Upbit,
+                ("btc", "krw", InstrumentKind::Spot),
+                SubKind::Trade,
+            ),
+            Subscription::new(
+                ExchangeId::Upbit,
+                ("eth", "krw", InstrumentKind::Spot),
+                SubKind::Trade,
+            ),
+            Subscription::new(
+                ExchangeId::Upbit,
+                ("btc", "krw", InstrumentKind::Spot),
+                SubKind::Candle(Interval::Minute1),
+            ),
+        ];
+
+        let actual = Upbit::build_subscription_meta(&subscriptions).unwrap();
+
+        assert_eq!(actual.expected_responses, 0);
+        assert_eq!(actual.ids.0.len(), 3);
+        assert_eq!(
+            actual.subscriptions,
+            vec![WsMessage::Text(
+                r#"[{"ticket":"barter-data"},{"codes":["KRW-BTC","KRW-ETH"],"type":"trade"},{"codes":["KRW-BTC"],"type":"candle.1m"},{"format":"DEFAULT"}]"#
+                    .to_owned()
+            )]
+        );
+    }
+
+    #[test]
+    fn test_upbit_transform() {
+        let mut transformer = Upbit::new(
+            mpsc::unbounded_channel().0,
+            Upbit::build_subscription_meta(&[
+                Subscription::new(
+                    ExchangeId::Upbit,
+                    ("btc", "krw", InstrumentKind::Spot),
+                    SubKind::Trade,
+                ),
+                Subscription::new(
+                    ExchangeId::Upbit,
+                    ("btc", "krw", InstrumentKind::Spot),
+                    SubKind::Candle(Interval::Minute1),
+                ),
+            ])
+            .unwrap()
+            .ids,
+        );
+
+        struct TestCase {
+            input: UpbitMessage,
+            expected: Vec<Result<DataKind, SocketError>>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: UpbitMessage::Trade for a subscribed market
+                input: serde_json::from_str(
+                    r#"{"type":"trade","code":"KRW-BTC","trade_timestamp":1730336862047,"trade_price":100473000,"trade_volume":0.00014208,"ask_bid":"BID","sequential_id":17303368620470000}"#,
+                )
+                .unwrap(),
+                expected: vec![Ok(DataKind::Trade(PublicTrade {
+                    id: "17303368620470000".to_owned(),
+                    price: 100473000.0,
+                    quantity: 0.00014208,
+                    side: Side::Buy,
+                }))],
+            },
+            TestCase {
+                // TC1: UpbitMessage::Candle for a subscribed market
+                input: serde_json::from_str(
+                    r#"{"type":"candle.1m","code":"KRW-BTC","candle_date_time_utc":"2025-01-02T04:28:00","opening_price":142009000,"high_price":142010000,"low_price":142008000,"trade_price":142009000,"candle_acc_trade_volume":0.5}"#,
+                )
+                .unwrap(),
+                expected: vec![Ok(DataKind::Candle(Candle {
+                    start_time: Utc.with_ymd_and_hms(2025, 1, 2, 4, 28, 0).unwrap(),
+                    end_time: Utc.with_ymd_and_hms(2025, 1, 2, 4, 29, 0).unwrap(),
+                    open: 142009000.0,
+                    high: 142010000.0,
+                    low: 142008000.0,
+                    close: 142009000.0,
+                    volume: 0.5,
+                    trade_count: 0,
+                }))],
+            },
+            TestCase {
+                // TC2: UpbitMessage::Trade for an unsubscribed market
+                input: serde_json::from_str(
+                    r#"{"type":"trade","code":"KRW-ETH","trade_timestamp":1730336862047,"trade_price":3500000,"trade_volume":1.0,"ask_bid":"ASK","sequential_id":1}"#,
+                )
+                .unwrap(),
+                expected: vec![Err(SocketError::Unidentifiable(SubscriptionId::from(
+                    "trade|KRW-ETH",
+                )))],
+            },
+            TestCase {
+                // TC3: UpbitMessage::Error
+                input: serde_json::from_str(
+                    r#"{"error":{"name":"INVALID_PARAM","message":"invalid codes"}}"#,
+                )
+                .unwrap(),
+                expected: vec![Err(SocketError::Exchange(
+                    "INVALID_PARAM: invalid codes".to_owned(),
+                ))],
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = transformer.transform(test.input);
+            assert_eq!(actual.len(), test.expected.len(), "TC{} failed", index);
+
+            for (actual, expected) in actual.into_iter().zip(test.expected) {
+                match (actual, expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual.kind, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,458 @@
+use crate::{
+    model::{subscription::Interval, Candle, DataKind, PublicTrade},
+    ExchangeId, MarketEvent,
+};
+use barter_integration::{
+    error::SocketError,
+    model::{Exchange, Instrument, InstrumentKind, Side, SubscriptionId},
+    Validator,
+};
+use chrono::{DateTime, NaiveDateTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt::{Display, Formatter},
+    str::FromStr,
+};
+
+/// [`Upbit`](super::Upbit) does not send subscription responses, so this is never
+/// deserialised. Every [`Upbit`](super::Upbit) subscription is considered successful.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct UpbitSubResponse;
+
+impl Validator for UpbitSubResponse {
+    fn validate(self) -> Result<Self, SocketError>
+    where
+        Self: Sized,
+    {
+        Ok(self)
+    }
+}
+
+/// [`Upbit`](super::Upbit) market code, quote first (eg/ "KRW-BTC").
+///
+/// See docs: <https://global-docs.upbit.com/reference/listing-market-list>
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct UpbitMarket {
+    pub base: String,
+    pub quote: String,
+}
+
+impl UpbitMarket {
+    /// Normalise this [`UpbitMarket`] into a Barter [`InstrumentKind::Spot`] [`Instrument`].
+    ///
+    /// eg/ "KRW-BTC" => Instrument { base: "btc", quote: "krw", kind: Spot }
+    pub fn instrument(&self) -> Instrument {
+        Instrument::from((
+            self.base.to_lowercase(),
+            self.quote.to_lowercase(),
+            InstrumentKind::Spot,
+        ))
+    }
+}
+
+impl From<&Instrument> for UpbitMarket {
+    fn from(instrument: &Instrument) -> Self {
+        Self {
+            base: instrument.base.as_ref().to_uppercase(),
+            quote: instrument.quote.as_ref().to_uppercase(),
+        }
+    }
+}
+
+impl Display for UpbitMarket {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}", self.quote, self.base)
+    }
+}
+
+impl FromStr for UpbitMarket {
+    type Err = SocketError;
+
+    fn from_str(code: &str) -> Result<Self, Self::Err> {
+        match code.split_once('-') {
+            Some((quote, base)) if !quote.is_empty() && !base.is_empty() => Ok(Self {
+                base: base.to_uppercase(),
+                quote: quote.to_uppercase(),
+            }),
+            _ => Err(SocketError::Unsupported {
+                entity: ExchangeId::Upbit.as_str(),
+                item: code.to_owned(),
+            }),
+        }
+    }
+}
+
+/// [`Upbit`](super::Upbit) candle interval, used as the suffix of the candle stream type
+/// (eg/ "candle.1m").
+///
+/// See docs: <https://global-docs.upbit.com/reference/websocket-candle>
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct UpbitInterval(pub &'static str);
+
+impl TryFrom<&Interval> for UpbitInterval {
+    type Error = SocketError;
+
+    fn try_from(interval: &Interval) -> Result<Self, Self::Error> {
+        Ok(UpbitInterval(match interval {
+            Interval::Minute1 => "1m",
+            Interval::Minute3 => "3m",
+            Interval::Minute5 => "5m",
+            Interval::Minute15 => "15m",
+            Interval::Minute30 => "30m",
+            Interval::Hour1 => "60m",
+            Interval::Hour4 => "240m",
+            other => {
+                return Err(SocketError::Unsupported {
+                    entity: ExchangeId::Upbit.as_str(),
+                    item: other.to_string(),
+                })
+            }
+        }))
+    }
+}
+
+/// [`Upbit`](super::Upbit) message variants that could be received over
+/// [`WebSocket`](crate::WebSocket). Messages are JSON encoded, but delivered in binary frames.
+///
+/// See docs: <https://global-docs.upbit.com/reference/websocket-guide>
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum UpbitMessage {
+    Trade(UpbitTrade),
+    Candle(UpbitCandle),
+    Error(UpbitError),
+}
+
+/// [`Upbit`](super::Upbit) error message.
+///
+/// eg/ {"error":{"name":"INVALID_PARAM","message":"..."}}
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct UpbitError {
+    pub error: UpbitErrorDetail,
+}
+
+/// Detail of an [`UpbitError`].
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct UpbitErrorDetail {
+    pub name: String,
+    #[serde(default)]
+    pub message: String,
+}
+
+/// [`Upbit`](super::Upbit) real-time trade message.
+///
+/// eg/ {"type":"trade","code":"KRW-BTC","trade_timestamp":1602812109000,"trade_price":13024000.0,
+///      "trade_volume":0.0195,"ask_bid":"BID","sequential_id":1602812109000000, ...}
+///
+/// See docs: <https://global-docs.upbit.com/reference/websocket-trade>
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct UpbitTrade {
+    #[serde(rename = "type")]
+    pub kind: UpbitTradeKind,
+    pub code: String,
+    #[serde(
+        rename = "trade_timestamp",
+        deserialize_with = "crate::exchange::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+    #[serde(rename = "sequential_id")]
+    pub id: u64,
+    #[serde(rename = "trade_price")]
+    pub price: f64,
+    #[serde(rename = "trade_volume")]
+    pub quantity: f64,
+    #[serde(rename = "ask_bid", deserialize_with = "de_side_from_ask_bid")]
+    pub side: Side,
+}
+
+/// Marker for the "trade" type of an [`UpbitTrade`].
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UpbitTradeKind {
+    Trade,
+}
+
+impl From<&UpbitTrade> for SubscriptionId {
+    fn from(trade: &UpbitTrade) -> Self {
+        SubscriptionId::from(format!("{}|{}", super::Upbit::CHANNEL_TRADES, trade.code))
+    }
+}
+
+impl From<(ExchangeId, Instrument, UpbitTrade)> for MarketEvent {
+    fn from((exchange_id, instrument, trade): (ExchangeId, Instrument, UpbitTrade)) -> Self {
+        Self {
+            exchange_time: trade.time,
+            received_time: Utc::now(),
+            received_instant: None,
+            raw: None,
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: DataKind::Trade(PublicTrade {
+                id: trade.id.to_string(),
+                price: trade.price,
+                quantity: trade.quantity,
+                side: trade.side,
+            }),
+        }
+    }
+}
+
+/// [`Upbit`](super::Upbit) real-time candle message, updated as trades occur within the candle.
+///
+/// eg/ {"type":"candle.1m","code":"KRW-BTC","candle_date_time_utc":"2025-01-02T04:28:00",
+///      "opening_price":142009000,"high_price":142009000,"low_price":142009000,
+///      "trade_price":142009000,"candle_acc_trade_volume":0.00606119, ...}
+///
+/// See docs: <https://global-docs.upbit.com/reference/websocket-candle>
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct UpbitCandle {
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub code: String,
+    #[serde(
+        rename = "candle_date_time_utc",
+        deserialize_with = "de_naive_datetime_as_datetime_utc"
+    )]
+    pub start_time: DateTime<Utc>,
+    #[serde(rename = "opening_price")]
+    pub open: f64,
+    #[serde(rename = "high_price")]
+    pub high: f64,
+    #[serde(rename = "low_price")]
+    pub low: f64,
+    #[serde(rename = "trade_price")]
+    pub close: f64,
+    #[serde(rename = "candle_acc_trade_volume")]
+    pub volume: f64,
+}
+
+impl UpbitCandle {
+    /// Determine the Barter [`Interval`] of this [`UpbitCandle`] from it's type
+    /// (eg/ "candle.1m" => Interval::Minute1).
+    pub fn interval(&self) -> Result<Interval, SocketError> {
+        [
+            Interval::Minute1,
+            Interval::Minute3,
+            Interval::Minute5,
+            Interval::Minute15,
+            Interval::Minute30,
+            Interval::Hour1,
+            Interval::Hour4,
+        ]
+        .into_iter()
+        .find(|interval| {
+            UpbitInterval::try_from(interval).is_ok_and(|upbit_interval| {
+                self.kind.strip_prefix(super::Upbit::CHANNEL_CANDLES) == Some(upbit_interval.0)
+            })
+        })
+        .ok_or_else(|| SocketError::Unsupported {
+            entity: ExchangeId::Upbit.as_str(),
+            item: self.kind.clone(),
+        })
+    }
+}
+
+impl From<&UpbitCandle> for SubscriptionId {
+    fn from(candle: &UpbitCandle) -> Self {
+        SubscriptionId::from(format!("{}|{}", candle.kind, candle.code))
+    }
+}
+
+impl TryFrom<(ExchangeId, Instrument, UpbitCandle)> for MarketEvent {
+    type Error = SocketError;
+
+    fn try_from(
+        (exchange_id, instrument, candle): (ExchangeId, Instrument, UpbitCandle),
+    ) -> Result<Self, Self::Error> {
+        let end_time = candle.interval()?.end_time(candle.start_time);
+
+        Ok(Self {
+            exchange_time: end_time,
+            received_time: Utc::now(),
+            received_instant: None,
+            raw: None,
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            kind: DataKind::Candle(Candle {
+                start_time: candle.start_time,
+                end_time,
+                open: candle.open,
+                high: candle.high,
+                low: candle.low,
+                close: candle.close,
+                volume: candle.volume,
+                trade_count: 0,
+            }),
+        })
+    }
+}
+
+/// Deserialize an [`UpbitTrade`] "ask_bid" field to a Barter [`Side`].
+///
+/// Variants:
+/// "BID" => Side::Buy
+/// "ASK" => Side::Sell
+pub fn de_side_from_ask_bid<'de, D>(deserializer: D) -> Result<Side, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    let ask_bid = String::deserialize(deserializer)?;
+    match ask_bid.as_str() {
+        "BID" => Ok(Side::Buy),
+        "ASK" => Ok(Side::Sell),
+        other => Err(serde::de::Error::invalid_value(
+            serde::de::Unexpected::Str(other),
+            &"BID | ASK",
+        )),
+    }
+}
+
+/// Deserialize a UTC timestamp without an offset (eg/ "2025-01-02T04:28:00") as a
+/// `DateTime<Utc>`.
+pub fn de_naive_datetime_as_datetime_utc<'de, D>(deserializer: D) -> Result<DateTime<Utc>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    NaiveDateTime::deserialize(deserializer).map(|datetime| Utc.from_utc_datetime(&datetime))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchange::datetime_utc_from_epoch_duration;
+    use std::time::Duration;
+
+    #[test]
+    fn test_parse_upbit_market() {
+        struct TestCase {
+            input: &'static str,
+            expected: Result<Instrument, SocketError>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: valid KRW market
+                input: "KRW-BTC",
+                expected: Ok(Instrument::from(("btc", "krw", InstrumentKind::Spot))),
+            },
+            TestCase {
+                // TC1: valid USDT market
+                input: "USDT-ETH",
+                expected: Ok(Instrument::from(("eth", "usdt", InstrumentKind::Spot))),
+            },
+            TestCase {
+                // TC2: invalid market w/o separator
+                input: "KRWBTC",
+                expected: Err(SocketError::Unsupported {
+                    entity: "",
+                    item: "".to_owned(),
+                }),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = UpbitMarket::from_str(test.input);
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => {
+                    assert_eq!(actual.to_string(), test.input, "TC{} failed", index);
+                    assert_eq!(actual.instrument(), expected, "TC{} failed", index)
+                }
+                (Err(_), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_deserialise_upbit_message() {
+        struct TestCase {
+            input: &'static [u8],
+            expected: Result<UpbitMessage, SocketError>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: valid trade
+                input: br#"{
+                    "type":"trade","code":"KRW-BTC","timestamp":1602812109000,"trade_date":"2020-10-16",
+                    "trade_time":"01:35:09","trade_timestamp":1602812109000,"trade_price":13024000.0,
+                    "trade_volume":0.0195,"ask_bid":"ASK","prev_closing_price":13050000.0,
+                    "change":"FALL","change_price":26000.0,"sequential_id":1602812109000000,
+                    "stream_type":"REALTIME"
+                }"#,
+                expected: Ok(UpbitMessage::Trade(UpbitTrade {
+                    kind: UpbitTradeKind::Trade,
+                    code: "KRW-BTC".to_owned(),
+                    time: datetime_utc_from_epoch_duration(Duration::from_millis(1602812109000)),
+                    id: 1602812109000000,
+                    price: 13024000.0,
+                    quantity: 0.0195,
+                    side: Side::Sell,
+                })),
+            },
+            TestCase {
+                // TC1: valid candle
+                input: br#"{
+                    "type":"candle.1m","code":"KRW-BTC","candle_date_time_utc":"2025-01-02T04:28:00",
+                    "candle_date_time_kst":"2025-01-02T13:28:00","opening_price":142009000.0,
+                    "high_price":142009000.0,"low_price":142000000.0,"trade_price":142005000.0,
+                    "candle_acc_trade_volume":0.00606119,"candle_acc_trade_price":860743.5,
+                    "timestamp":1735792132000,"stream_type":"REALTIME"
+                }"#,
+                expected: Ok(UpbitMessage::Candle(UpbitCandle {
+                    kind: "candle.1m".to_owned(),
+                    code: "KRW-BTC".to_owned(),
+                    start_time: datetime_utc_from_epoch_duration(Duration::from_secs(1735792080)),
+                    open: 142009000.0,
+                    high: 142009000.0,
+                    low: 142000000.0,
+                    close: 142005000.0,
+                    volume: 0.00606119,
+                })),
+            },
+            TestCase {
+                // TC2: valid error
+                input: br#"{"error":{"name":"INVALID_PARAM","message":"invalid codes"}}"#,
+                expected: Ok(UpbitMessage::Error(UpbitError {
+                    error: UpbitErrorDetail {
+                        name: "INVALID_PARAM".to_owned(),
+                        message: "invalid codes".to_owned(),
+                    },
+                })),
+            },
+            TestCase {
+                // TC3: invalid trade w/ unknown ask_bid
+                input: br#"{
+                    "type":"trade","code":"KRW-BTC","trade_timestamp":1602812109000,
+                    "trade_price":13024000.0,"trade_volume":0.0195,"ask_bid":"NONE",
+                    "sequential_id":1602812109000000
+                }"#,
+                expected: Err(SocketError::Unsupported {
+                    entity: "",
+                    item: "".to_owned(),
+                }),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = serde_json::from_slice::<UpbitMessage>(test.input);
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => {
+                    assert_eq!(actual, expected, "TC{} failed", index)
+                }
+                (Err(_), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,220 @@
+use crate::model::{DataKind, Level, MarketEvent};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    io::{self, Write},
+};
+
+/// Resolution at which a [`Heatmap`] samples resting liquidity.
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct HeatmapConfig {
+    /// Minimum time between two sampled [`OrderBook`](crate::model::OrderBook)s.
+    pub interval: Duration,
+    /// Width of each price bucket that resting liquidity is aggregated into.
+    pub tick_size: f64,
+}
+
+/// Accumulates a time x price x resting liquidity matrix from the
+/// [`OrderBook`](crate::model::OrderBook)s of a single instrument (eg/ those delivered by a
+/// [`SubKind::OrderBookL2Managed`](crate::model::subscription::SubKind) subscription), for
+/// producing Bookmap-style visualisations.
+///
+/// Each row of the matrix is a sample in time, and each column is a price bucket ascending from
+/// the lowest price bucket observed across every sample.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Heatmap {
+    config: HeatmapConfig,
+    samples: Vec<(DateTime<Utc>, BTreeMap<i64, f64>)>,
+}
+
+impl Heatmap {
+    /// Construct a new empty [`Heatmap`] using the provided [`HeatmapConfig`].
+    pub fn new(config: HeatmapConfig) -> Self {
+        Self {
+            config,
+            samples: Vec::new(),
+        }
+    }
+
+    /// Sample the resting liquidity of the provided [`MarketEvent`] if it contains an
+    /// [`OrderBook`](crate::model::OrderBook) & at least the configured interval has elapsed
+    /// since the last sample. Returns `true` if a sample was taken.
+    pub fn update(&mut self, event: &MarketEvent) -> bool {
+        let book = match &event.kind {
+            DataKind::OrderBook(book) => book,
+            _ => return false,
+        };
+
+        if let Some((last, _)) = self.samples.last() {
+            if event.exchange_time - *last < self.config.interval {
+                return false;
+            }
+        }
+
+        let liquidity = book.bids.iter().chain(book.asks.iter()).fold(
+            BTreeMap::new(),
+            |mut liquidity, level| {
+                *liquidity.entry(self.bucket(level)).or_default() += level.quantity;
+                liquidity
+            },
+        );
+
+        self.samples.push((event.exchange_time, liquidity));
+        true
+    }
+
+    /// Sample times of each row in the matrix.
+    pub fn times(&self) -> Vec<DateTime<Utc>> {
+        self.samples.iter().map(|(time, _)| *time).collect()
+    }
+
+    /// Lower price bound of each price bucket column in the matrix.
+    pub fn prices(&self) -> Vec<f64> {
+        match self.bounds() {
+            Some((min, max)) => (min..=max)
+                .map(|bucket| bucket as f64 * self.config.tick_size)
+                .collect(),
+            None => Vec::new(),
+        }
+    }
+
+    /// Dense time x price resting liquidity matrix, where price buckets without any resting
+    /// liquidity are zero.
+    pub fn matrix(&self) -> Vec<Vec<f64>> {
+        let (min, max) = match self.bounds() {
+            Some(bounds) => bounds,
+            None => return Vec::new(),
+        };
+
+        self.samples
+            .iter()
+            .map(|(_, liquidity)| {
+                (min..=max)
+                    .map(|bucket| liquidity.get(&bucket).copied().unwrap_or_default())
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Write the [`Heatmap::matrix`] in the NumPy `.npy` format as a 2D array of little endian
+    /// `f64`, loadable via `numpy.load`.
+    ///
+    /// See docs: <https://numpy.org/doc/stable/reference/generated/numpy.lib.format.html>
+    pub fn write_npy<W>(&self, mut writer: W) -> io::Result<()>
+    where
+        W: Write,
+    {
+        let matrix = self.matrix();
+        let columns = matrix.first().map_or(0, Vec::len);
+
+        // Header is padded w/ spaces so the data is 64 byte aligned, & terminated by a newline
+        let mut header = format!(
+            "{{'descr': '<f8', 'fortran_order': False, 'shape': ({}, {}), }}",
+            matrix.len(),
+            columns
+        );
+        let unpadded = NPY_MAGIC.len() + 2 + 2 + header.len() + 1;
+        header.push_str(&" ".repeat((64 - unpadded % 64) % 64));
+        header.push('\n');
+
+        let header_len = u16::try_from(header.len())
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidData, "npy header too long"))?;
+
+        writer.write_all(NPY_MAGIC)?;
+        writer.write_all(&[1, 0])?;
+        writer.write_all(&header_len.to_le_bytes())?;
+        writer.write_all(header.as_bytes())?;
+        for value in matrix.iter().flatten() {
+            writer.write_all(&value.to_le_bytes())?;
+        }
+
+        Ok(())
+    }
+
+    /// Determine the price bucket the provided [`Level`] belongs to.
+    fn bucket(&self, level: &Level) -> i64 {
+        (level.price / self.config.tick_size).floor() as i64
+    }
+
+    /// Lowest & highest price bucket observed across every sample.
+    fn bounds(&self) -> Option<(i64, i64)> {
+        let buckets = self
+            .samples
+            .iter()
+            .flat_map(|(_, liquidity)| liquidity.keys().copied());
+
+        buckets.fold(None, |bounds, bucket| match bounds {
+            None => Some((bucket, bucket)),
+            Some((min, max)) => Some((min.min(bucket), max.max(bucket))),
+        })
+    }
+}
+
+/// Magic string prefixing every NumPy `.npy` file.
+const NPY_MAGIC: &[u8] = b"\x93NUMPY";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::OrderBook;
+
+    fn book(time: DateTime<Utc>, bids: Vec<Level>, asks: Vec<Level>) -> MarketEvent {
+        let mut event = crate::test_util::market_trade(barter_integration::model::Side::Buy);
+        event.exchange_time = time;
+        event.kind = DataKind::OrderBook(OrderBook {
+            last_update_time: time,
+            last_update_id: 0,
+            bids,
+            asks,
+        });
+        event
+    }
+
+    #[test]
+    fn test_heatmap() {
+        let mut heatmap = Heatmap::new(HeatmapConfig {
+            interval: Duration::seconds(1),
+            tick_size: 1.0,
+        });
+        let start = Utc::now();
+
+        // Sampled
+        assert!(heatmap.update(&book(
+            start,
+            vec![Level::new(100.5, 1.0), Level::new(100.2, 2.0)],
+            vec![Level::new(102.0, 1.0)],
+        )));
+
+        // Not sampled since the interval has not elapsed
+        assert!(!heatmap.update(&book(
+            start + Duration::milliseconds(500),
+            vec![Level::new(90.0, 1.0)],
+            vec![],
+        )));
+
+        // Sampled
+        assert!(heatmap.update(&book(
+            start + Duration::seconds(1),
+            vec![Level::new(99.0, 4.0)],
+            vec![Level::new(101.0, 1.0)],
+        )));
+
+        assert_eq!(heatmap.times(), vec![start, start + Duration::seconds(1)]);
+        assert_eq!(heatmap.prices(), vec![99.0, 100.0, 101.0, 102.0]);
+        assert_eq!(
+            heatmap.matrix(),
+            vec![vec![0.0, 3.0, 0.0, 1.0], vec![4.0, 0.0, 1.0, 0.0]]
+        );
+
+        let mut npy = Vec::new();
+        heatmap.write_npy(&mut npy).unwrap();
+
+        let header_len = u16::from_le_bytes([npy[8], npy[9]]) as usize;
+        let header = std::str::from_utf8(&npy[10..10 + header_len]).unwrap();
+        assert!(npy.starts_with(b"\x93NUMPY\x01\x00"));
+        assert!(header.contains("'shape': (2, 4)"));
+        assert_eq!((10 + header_len) % 64, 0);
+        assert_eq!(npy.len(), 10 + header_len + 8 * 8);
+    }
+}
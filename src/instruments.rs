@@ -0,0 +1,319 @@
+use crate::{
+    exchange::{de_str, de_u64_epoch_ms_as_datetime_utc},
+    model::{OptionContract, OptionKind},
+    ExchangeId,
+};
+use barter_integration::{error::SocketError, model::Instrument};
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+/// [`Deribit`](crate::exchange::deribit::Deribit) get_instruments endpoint path, relative to the
+/// [`ExchangeId::rest_base_url`], listing every instrument of a currency & kind.
+///
+/// See docs: <https://docs.deribit.com/#public-get_instruments>
+pub const PATH_INSTRUMENTS_DERIBIT: &str = "/public/get_instruments";
+
+/// [`Okx`](crate::exchange::okx::Okx) instruments endpoint path, relative to the
+/// [`ExchangeId::rest_base_url`], listing every instrument of an instrument type.
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#public-data-rest-api-get-instruments>
+pub const PATH_INSTRUMENTS_OKX: &str = "/api/v5/public/instruments";
+
+/// `Deribit` get_instruments response.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct DeribitInstruments {
+    pub result: Vec<DeribitInstrument>,
+}
+
+/// `Deribit` option instrument contained in a [`DeribitInstruments`] response. Inverse options
+/// have a "USD" counter currency, whereas linear options are quoted in their settlement currency.
+///
+/// eg/ {"instrument_name":"BTC-27DEC24-50000-C","kind":"option","base_currency":"BTC",
+///      "counter_currency":"USD","strike":50000.0,"option_type":"call",
+///      "expiration_timestamp":1735286400000,"is_active":true,"tick_size":0.0005, ...}
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct DeribitInstrument {
+    pub instrument_name: String,
+    pub base_currency: String,
+    pub counter_currency: String,
+    pub strike: f64,
+    pub option_type: OptionKind,
+    #[serde(
+        rename = "expiration_timestamp",
+        deserialize_with = "de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub expiry: DateTime<Utc>,
+    pub is_active: bool,
+    pub tick_size: f64,
+}
+
+impl From<&DeribitInstrument> for OptionContract {
+    fn from(instrument: &DeribitInstrument) -> Self {
+        Self {
+            underlying: instrument.base_currency.to_lowercase(),
+            quote: instrument.counter_currency.to_lowercase(),
+            expiry: instrument.expiry.date_naive(),
+            strike: instrument.strike,
+            kind: instrument.option_type,
+        }
+    }
+}
+
+/// `Okx` instruments response.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct OkxInstruments {
+    pub code: String,
+    pub msg: String,
+    pub data: Vec<OkxInstrument>,
+}
+
+/// `Okx` option instrument contained in an [`OkxInstruments`] response, where the underlying
+/// (`uly`) is in the format "{underlying}-{quote}".
+///
+/// eg/ {"instType":"OPTION","instId":"BTC-USD-241227-50000-C","uly":"BTC-USD","stk":"50000",
+///      "optType":"C","expTime":"1735286400000","state":"live","tickSz":"0.0005", ...}
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct OkxInstrument {
+    #[serde(rename = "instId")]
+    pub inst_id: String,
+    pub uly: String,
+    #[serde(rename = "stk", deserialize_with = "de_str")]
+    pub strike: f64,
+    #[serde(rename = "optType")]
+    pub option_type: OkxOptionType,
+    #[serde(
+        rename = "expTime",
+        deserialize_with = "de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub expiry: DateTime<Utc>,
+    pub state: String,
+    #[serde(rename = "tickSz", deserialize_with = "de_str")]
+    pub tick_size: f64,
+}
+
+/// `Okx` option type of an [`OkxInstrument`].
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub enum OkxOptionType {
+    #[serde(rename = "C")]
+    Call,
+    #[serde(rename = "P")]
+    Put,
+}
+
+impl From<OkxOptionType> for OptionKind {
+    fn from(option_type: OkxOptionType) -> Self {
+        match option_type {
+            OkxOptionType::Call => OptionKind::Call,
+            OkxOptionType::Put => OptionKind::Put,
+        }
+    }
+}
+
+impl TryFrom<&OkxInstrument> for OptionContract {
+    type Error = SocketError;
+
+    fn try_from(instrument: &OkxInstrument) -> Result<Self, Self::Error> {
+        let (underlying, quote) =
+            instrument
+                .uly
+                .split_once('-')
+                .ok_or_else(|| SocketError::Unsupported {
+                    entity: ExchangeId::Okx.as_str(),
+                    item: instrument.inst_id.clone(),
+                })?;
+
+        Ok(Self {
+            underlying: underlying.to_lowercase(),
+            quote: quote.to_lowercase(),
+            expiry: instrument.expiry.date_naive(),
+            strike: instrument.strike,
+            kind: OptionKind::from(instrument.option_type),
+        })
+    }
+}
+
+/// Fetch every active [`OptionContract`] of the provided underlying & quote (eg/ "btc", "usd")
+/// listed on the provided [`ExchangeId`]. Supported exchanges are `Deribit` & `Okx`.
+///
+/// Each [`OptionContract`] can be converted into an [`Instrument`] in order to subscribe to it.
+pub async fn fetch_option_contracts(
+    exchange: ExchangeId,
+    underlying: &str,
+    quote: &str,
+) -> Result<Vec<OptionContract>, SocketError> {
+    let contracts = match exchange {
+        ExchangeId::Deribit => {
+            // Linear options are listed under their quote currency (eg/ "USDC")
+            let currency = match quote {
+                "usd" => underlying,
+                quote => quote,
+            };
+
+            reqwest::get(format!(
+                "{}{PATH_INSTRUMENTS_DERIBIT}?currency={}&kind=option&expired=false",
+                exchange.rest_base_url(),
+                currency.to_uppercase()
+            ))
+            .await?
+            .json::<DeribitInstruments>()
+            .await?
+            .result
+            .iter()
+            .filter(|instrument| instrument.is_active)
+            .map(OptionContract::from)
+            .collect::<Vec<_>>()
+        }
+        ExchangeId::Okx => {
+            let response = reqwest::get(format!(
+                "{}{PATH_INSTRUMENTS_OKX}?instType=OPTION&uly={}-{}",
+                exchange.rest_base_url(),
+                underlying.to_uppercase(),
+                quote.to_uppercase()
+            ))
+            .await?
+            .json::<OkxInstruments>()
+            .await?;
+
+            if response.code != "0" {
+                return Err(SocketError::Exchange(format!(
+                    "failed to fetch instruments with code: {} & msg: {}",
+                    response.code, response.msg
+                )));
+            }
+
+            response
+                .data
+                .iter()
+                .filter(|instrument| instrument.state == "live")
+                .map(OptionContract::try_from)
+                .collect::<Result<Vec<_>, SocketError>>()?
+        }
+        other => {
+            return Err(SocketError::Unsupported {
+                entity: other.as_str(),
+                item: "option instruments".to_owned(),
+            })
+        }
+    };
+
+    Ok(contracts
+        .into_iter()
+        .filter(|contract| contract.underlying == underlying && contract.quote == quote)
+        .collect())
+}
+
+/// Build the [`Instrument`]s of every [`OptionContract`] expiring on the provided date, in order
+/// to subscribe to a whole expiry.
+pub fn expiry_instruments(contracts: &[OptionContract], expiry: NaiveDate) -> Vec<Instrument> {
+    contracts
+        .iter()
+        .filter(|contract| contract.expiry == expiry)
+        .map(Instrument::from)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::model::InstrumentKind;
+
+    fn contract(expiry: NaiveDate, strike: f64, kind: OptionKind) -> OptionContract {
+        OptionContract {
+            underlying: "btc".to_owned(),
+            quote: "usd".to_owned(),
+            expiry,
+            strike,
+            kind,
+        }
+    }
+
+    #[test]
+    fn test_deribit_instruments_to_option_contracts() {
+        let input = r#"{"jsonrpc":"2.0","result":[
+            {"instrument_name":"BTC-27DEC24-50000-C","kind":"option","base_currency":"BTC",
+             "counter_currency":"USD","quote_currency":"BTC","settlement_currency":"BTC",
+             "strike":50000.0,"option_type":"call","expiration_timestamp":1735286400000,
+             "is_active":true,"tick_size":0.0005,"contract_size":1.0},
+            {"instrument_name":"SOL_USDC-28JUN24-150-P","kind":"option","base_currency":"SOL",
+             "counter_currency":"USDC","quote_currency":"USDC","settlement_currency":"USDC",
+             "strike":150.0,"option_type":"put","expiration_timestamp":1719561600000,
+             "is_active":true,"tick_size":0.01,"contract_size":1.0}
+        ]}"#;
+
+        let actual = serde_json::from_str::<DeribitInstruments>(input)
+            .unwrap()
+            .result
+            .iter()
+            .map(OptionContract::from)
+            .collect::<Vec<_>>();
+
+        let expected = vec![
+            contract(
+                NaiveDate::from_ymd_opt(2024, 12, 27).unwrap(),
+                50000.0,
+                OptionKind::Call,
+            ),
+            OptionContract {
+                underlying: "sol".to_owned(),
+                quote: "usdc".to_owned(),
+                expiry: NaiveDate::from_ymd_opt(2024, 6, 28).unwrap(),
+                strike: 150.0,
+                kind: OptionKind::Put,
+            },
+        ];
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_okx_instruments_to_option_contracts() {
+        let input = r#"{"code":"0","msg":"","data":[
+            {"instType":"OPTION","instId":"BTC-USD-241227-50000-C","uly":"BTC-USD",
+             "instFamily":"BTC-USD","settleCcy":"BTC","ctVal":"0.01","stk":"50000",
+             "optType":"C","expTime":"1735286400000","state":"live","tickSz":"0.0005"},
+            {"instType":"OPTION","instId":"BTC-USD-241227-40000-P","uly":"BTC-USD",
+             "instFamily":"BTC-USD","settleCcy":"BTC","ctVal":"0.01","stk":"40000",
+             "optType":"P","expTime":"1735286400000","state":"live","tickSz":"0.0005"}
+        ]}"#;
+
+        let actual = serde_json::from_str::<OkxInstruments>(input)
+            .unwrap()
+            .data
+            .iter()
+            .map(OptionContract::try_from)
+            .collect::<Result<Vec<_>, SocketError>>()
+            .unwrap();
+
+        let expiry = NaiveDate::from_ymd_opt(2024, 12, 27).unwrap();
+        let expected = vec![
+            contract(expiry, 50000.0, OptionKind::Call),
+            contract(expiry, 40000.0, OptionKind::Put),
+        ];
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_expiry_instruments() {
+        let december = NaiveDate::from_ymd_opt(2024, 12, 27).unwrap();
+        let march = NaiveDate::from_ymd_opt(2025, 3, 28).unwrap();
+        let contracts = vec![
+            contract(december, 50000.0, OptionKind::Call),
+            contract(march, 50000.0, OptionKind::Call),
+            contract(december, 40000.0, OptionKind::Put),
+        ];
+
+        let actual = expiry_instruments(&contracts, december);
+
+        let expected = vec![
+            Instrument::from(("btc-241227-50000-c", "usd", InstrumentKind::Spot)),
+            Instrument::from(("btc-241227-40000-p", "usd", InstrumentKind::Spot)),
+        ];
+
+        assert_eq!(actual, expected);
+
+        for (instrument, contract) in actual.iter().zip([&contracts[0], &contracts[2]]) {
+            assert_eq!(&OptionContract::try_from(instrument).unwrap(), contract);
+        }
+    }
+}
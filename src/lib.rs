@@ -9,13 +9,15 @@ use crate::model::{
     subscription::{Subscription, SubscriptionIds, SubscriptionMeta},
     MarketEvent,
 };
+use crate::receipt::StampedStream;
 use async_trait::async_trait;
 use barter_integration::{
     error::SocketError,
     model::Exchange,
     protocol::websocket::{connect, WebSocket, WebSocketParser, WsMessage, WsSink, WsStream},
-    Event, ExchangeStream, Transformer, Validator,
+    Event, Transformer, Validator,
 };
+use chrono::NaiveTime;
 use futures::{SinkExt, Stream, StreamExt};
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use std::{
@@ -39,12 +41,59 @@ pub mod exchange;
 /// [`Subscription`]s.
 pub mod builder;
 
-/// Convenient type alias for an [`ExchangeStream`] utilising a tungstenite [`WebSocket`]
-pub type ExchangeWsStream<Exchange> =
-    ExchangeStream<WebSocketParser, WsStream, Exchange, MarketEvent>;
+/// Optional [`MarketStream`] that parses WebSocket frames using a pool of workers.
+pub mod parallel;
+
+/// Paper-trading [`FillSimulator`](simulator::FillSimulator) matching hypothetical orders against
+/// a live L3 book.
+pub mod simulator;
+
+/// Paced replay of recorded [`MarketEvent`]s.
+pub mod replay;
+
+/// Optional [`MarketStream`] wrapper that fairly interleaves bursts of [`MarketEvent`]s across
+/// instruments.
+pub mod scheduler;
+
+/// [`Candle`](model::Candle) aggregation from trades for exchanges without a kline channel.
+pub mod candles;
+
+/// Automatic rollover of dated [`FutureContract`](model::FutureContract) subscriptions.
+pub mod rollover;
+
+/// Aggregated [`OrderbookL2`](orderbook::OrderbookL2) maintained from L2 snapshots & deltas.
+pub mod orderbook;
+
+/// Time x price x resting liquidity [`Heatmap`](heatmap::Heatmap) export from maintained books.
+pub mod heatmap;
+
+/// Validation mode diffing L2 [`OrderBook`](model::OrderBook)s against an L2 projection of the
+/// L3 book.
+pub mod consistency;
+
+/// REST discovery of exchange listed option [`OptionContract`](model::OptionContract)s, used to
+/// subscribe to a whole expiry.
+pub mod instruments;
+
+/// Configurable [`RetryPolicy`](retry::RetryPolicy)s used to re-connect [`MarketStream`]s.
+pub mod retry;
+
+/// Optional [`MarketStream`] attaching the original exchange payload to every [`MarketEvent`].
+pub mod raw;
+
+/// Monotonic socket read timestamps for [`MarketEvent`]s.
+pub mod receipt;
+
+/// Optional per message processing time profiling, enabled via the "profiling" feature.
+#[cfg(feature = "profiling")]
+pub mod profiling;
+
+/// Convenient type alias for a [`StampedStream`] utilising a tungstenite [`WebSocket`]. Every
+/// yielded [`MarketEvent`] is stamped with the monotonic instant its frame was read.
+pub type ExchangeWsStream<Exchange> = StampedStream<WebSocketParser, WsStream, Exchange>;
 
 /// [`Stream`] supertrait for streams that yield [`MarketEvent`]s. Provides an entry-point abstraction
-/// for an [`ExchangeStream`].
+/// for an [`ExchangeWsStream`].
 #[async_trait]
 pub trait MarketStream:
     Stream<Item = Result<Event<MarketEvent>, SocketError>> + Sized + Unpin
@@ -68,7 +117,7 @@ pub trait Subscriber {
         subscriptions: &[Subscription],
     ) -> Result<(WebSocket, SubscriptionIds), SocketError> {
         // Connect to exchange
-        let mut websocket = connect(Self::base_url()).await?;
+        let mut websocket = connect(Self::url(subscriptions)?).await?;
 
         // Subscribe
         let SubscriptionMeta {
@@ -77,9 +126,7 @@ pub trait Subscriber {
             expected_responses,
         } = Self::build_subscription_meta(subscriptions)?;
 
-        for subscription in subscriptions {
-            websocket.send(subscription).await?;
-        }
+        send_subscriptions(&mut websocket, subscriptions, Self::ws_rate_limit()).await?;
 
         // Validate subscriptions
         let ids = Self::validate(ids, &mut websocket, expected_responses).await?;
@@ -90,6 +137,20 @@ pub trait Subscriber {
     /// Returns the Base URL of the exchange to establish a connection with.
     fn base_url() -> &'static str;
 
+    /// Returns the [`RateLimit`] applied to the subscription messages sent to the exchange.
+    /// Defaults to `None`, but should be overridden to return the [`ExchangeId::ws_rate_limit`]
+    /// of exchanges that document a limit.
+    fn ws_rate_limit() -> Option<RateLimit> {
+        None
+    }
+
+    /// Returns the URL to establish a connection with for the provided Barter [`Subscription`]s.
+    /// Defaults to the [`Subscriber::base_url`], but can be overridden by exchanges that action
+    /// [`Subscription`]s via the connection URL (eg/ Binance combined streams).
+    fn url(_: &[Subscription]) -> Result<String, SocketError> {
+        Ok(Self::base_url().to_owned())
+    }
+
     /// Uses the provided Barter [`Subscription`]s to build exchange specific subscription
     /// payloads. Generates a [`SubscriptionIds`] `Hashmap` that is used by an [`ExchangeTransformer`]
     /// to identify the Barter [`Subscription`]s associated with received messages.
@@ -177,7 +238,7 @@ pub trait ExchangeTransformer: Transformer<MarketEvent> + Sized {
 #[async_trait]
 impl<Exchange> MarketStream for ExchangeWsStream<Exchange>
 where
-    Exchange: Subscriber + ExchangeTransformer + Send,
+    Exchange: Subscriber + ExchangeTransformer + Send + Unpin,
 {
     async fn init(subscriptions: &[Subscription]) -> Result<Self, SocketError> {
         // Connect & subscribe
@@ -212,10 +273,33 @@ where
 #[serde(rename = "exchange", rename_all = "snake_case")]
 pub enum ExchangeId {
     BinanceFuturesUsd,
+    BinanceFuturesCoin,
     Binance,
+    Bitstamp,
+    Bybit,
+    BybitFuturesUsd,
     Coinbase,
+    Deribit,
     Ftx,
     Kraken,
+    Okx,
+    Kucoin,
+    Gateio,
+    Bitmex,
+    Gemini,
+    Cryptocom,
+    Mexc,
+    Bitget,
+    Dydx,
+    Hyperliquid,
+    CoinbaseAdvanced,
+    KrakenFutures,
+    Upbit,
+    Bithumb,
+    Poloniex,
+    Bitfinex,
+    BinanceUs,
+    KrakenL3,
 }
 
 impl From<ExchangeId> for Exchange {
@@ -236,10 +320,33 @@ impl ExchangeId {
     /// eg/ ExchangeId::BinanceFuturesUsd => "binance"
     pub fn name(&self) -> &'static str {
         match self {
-            ExchangeId::Binance | ExchangeId::BinanceFuturesUsd => "binance",
+            ExchangeId::Binance
+            | ExchangeId::BinanceFuturesUsd
+            | ExchangeId::BinanceFuturesCoin => "binance",
+            ExchangeId::Bitstamp => "bitstamp",
+            ExchangeId::Bybit | ExchangeId::BybitFuturesUsd => "bybit",
             ExchangeId::Coinbase => "coinbase",
+            ExchangeId::Deribit => "deribit",
             ExchangeId::Ftx => "ftx",
             ExchangeId::Kraken => "kraken",
+            ExchangeId::Okx => "okx",
+            ExchangeId::Kucoin => "kucoin",
+            ExchangeId::Gateio => "gateio",
+            ExchangeId::Bitmex => "bitmex",
+            ExchangeId::Gemini => "gemini",
+            ExchangeId::Cryptocom => "cryptocom",
+            ExchangeId::Mexc => "mexc",
+            ExchangeId::Bitget => "bitget",
+            ExchangeId::Dydx => "dydx",
+            ExchangeId::Hyperliquid => "hyperliquid",
+            ExchangeId::CoinbaseAdvanced => "coinbase",
+            ExchangeId::KrakenFutures => "kraken",
+            ExchangeId::Upbit => "upbit",
+            ExchangeId::Bithumb => "bithumb",
+            ExchangeId::Poloniex => "poloniex",
+            ExchangeId::Bitfinex => "bitfinex",
+            ExchangeId::BinanceUs => "binance_us",
+            ExchangeId::KrakenL3 => "kraken",
         }
     }
 
@@ -248,9 +355,32 @@ impl ExchangeId {
         match self {
             ExchangeId::Binance => "binance",
             ExchangeId::BinanceFuturesUsd => "binance_futures_usd",
+            ExchangeId::BinanceFuturesCoin => "binance_futures_coin",
+            ExchangeId::Bitstamp => "bitstamp",
+            ExchangeId::Bybit => "bybit",
+            ExchangeId::BybitFuturesUsd => "bybit_futures_usd",
             ExchangeId::Coinbase => "coinbase",
+            ExchangeId::Deribit => "deribit",
             ExchangeId::Ftx => "ftx",
             ExchangeId::Kraken => "kraken",
+            ExchangeId::Okx => "okx",
+            ExchangeId::Kucoin => "kucoin",
+            ExchangeId::Gateio => "gateio",
+            ExchangeId::Bitmex => "bitmex",
+            ExchangeId::Gemini => "gemini",
+            ExchangeId::Cryptocom => "cryptocom",
+            ExchangeId::Mexc => "mexc",
+            ExchangeId::Bitget => "bitget",
+            ExchangeId::Dydx => "dydx",
+            ExchangeId::Hyperliquid => "hyperliquid",
+            ExchangeId::CoinbaseAdvanced => "coinbase_advanced",
+            ExchangeId::KrakenFutures => "kraken_futures",
+            ExchangeId::Upbit => "upbit",
+            ExchangeId::Bithumb => "bithumb",
+            ExchangeId::Poloniex => "poloniex",
+            ExchangeId::Bitfinex => "bitfinex",
+            ExchangeId::BinanceUs => "binance_us",
+            ExchangeId::KrakenL3 => "kraken_l3",
         }
     }
 
@@ -259,7 +389,12 @@ impl ExchangeId {
     #[allow(clippy::match_like_matches_macro)]
     pub fn supports_spot(&self) -> bool {
         match self {
-            ExchangeId::BinanceFuturesUsd => false,
+            ExchangeId::BinanceFuturesUsd
+            | ExchangeId::BinanceFuturesCoin
+            | ExchangeId::BybitFuturesUsd
+            | ExchangeId::Dydx
+            | ExchangeId::Hyperliquid
+            | ExchangeId::KrakenFutures => false,
             _ => true,
         }
     }
@@ -270,11 +405,47 @@ impl ExchangeId {
     pub fn supports_futures(&self) -> bool {
         match self {
             ExchangeId::BinanceFuturesUsd => true,
+            ExchangeId::BinanceFuturesCoin => true,
+            ExchangeId::BybitFuturesUsd => true,
+            ExchangeId::Deribit => true,
             ExchangeId::Ftx => true,
+            ExchangeId::Okx => true,
+            ExchangeId::Bitmex => true,
+            ExchangeId::Cryptocom => true,
+            ExchangeId::Bitget => true,
+            ExchangeId::Dydx => true,
+            ExchangeId::Hyperliquid => true,
+            ExchangeId::KrakenFutures => true,
+            _ => false,
+        }
+    }
+
+    /// Determines whether this [`ExchangeId`] supports the collection of dated (delivery) future
+    /// market data, subscribed via an [`Instrument`](barter_integration::model::Instrument)
+    /// encoding a [`FutureContract`](model::FutureContract).
+    #[allow(clippy::match_like_matches_macro)]
+    pub fn supports_dated_futures(&self) -> bool {
+        match self {
+            ExchangeId::BinanceFuturesCoin => true,
+            ExchangeId::KrakenFutures => true,
             _ => false,
         }
     }
 
+    /// Time of day (UTC) at which the dated [`FutureContract`](model::FutureContract)s of this
+    /// [`ExchangeId`] expire, or `None` if it does not
+    /// [support dated futures](Self::supports_dated_futures).
+    pub fn dated_futures_expiry(&self) -> Option<NaiveTime> {
+        match self {
+            // Quarterly delivery contracts settle at 08:00 UTC
+            ExchangeId::BinanceFuturesCoin => NaiveTime::from_hms_opt(8, 0, 0),
+            // Fixed maturity contracts stop trading at 16:00 London time, taken as the earlier
+            // 15:00 UTC of British Summer Time
+            ExchangeId::KrakenFutures => NaiveTime::from_hms_opt(15, 0, 0),
+            _ => None,
+        }
+    }
+
     /// Determines whether this [`ExchangeId`] supports the collection of
     /// [`PublicTrade`](model::PublicTrade) market data.
     #[allow(clippy::match_like_matches_macro)]
@@ -284,12 +455,57 @@ impl ExchangeId {
         }
     }
 
+    /// Determines whether this [`ExchangeId`] supports the collection of exchange aggregated
+    /// [`AggTrade`](model::AggTrade) market data.
+    #[allow(clippy::match_like_matches_macro)]
+    pub fn supports_agg_trades(&self) -> bool {
+        match self {
+            ExchangeId::BinanceFuturesUsd => true,
+            ExchangeId::BinanceFuturesCoin => true,
+            ExchangeId::BinanceUs => true,
+            _ => false,
+        }
+    }
+
+    /// Determines whether this [`ExchangeId`] supports [`Candle`](model::Candle) market data
+    /// aggregated from [`PublicTrade`](model::PublicTrade)s, since it lacks a native kline channel
+    /// but has a working trade channel.
+    #[allow(clippy::match_like_matches_macro)]
+    pub fn supports_trade_candles(&self) -> bool {
+        match self {
+            ExchangeId::Binance => true,
+            ExchangeId::Coinbase => true,
+            ExchangeId::Ftx => true,
+            ExchangeId::KrakenFutures => true,
+            ExchangeId::Bithumb => true,
+            _ => false,
+        }
+    }
+
     /// Determines whether this [`ExchangeId`] supports the collection of
     /// [`Candle`](model::Candle) market data.
     #[allow(clippy::match_like_matches_macro)]
     pub fn supports_candles(&self) -> bool {
         match self {
-            ExchangeId::Kraken => true,
+            ExchangeId::BinanceFuturesUsd => true,
+            ExchangeId::BinanceFuturesCoin => true,
+            ExchangeId::Bybit | ExchangeId::BybitFuturesUsd => true,
+            ExchangeId::Kraken | ExchangeId::Okx => true,
+            ExchangeId::Kucoin => true,
+            ExchangeId::Gateio => true,
+            ExchangeId::Deribit => true,
+            ExchangeId::Bitmex => true,
+            ExchangeId::Gemini => true,
+            ExchangeId::Cryptocom => true,
+            ExchangeId::Mexc => true,
+            ExchangeId::Bitget => true,
+            ExchangeId::Dydx => true,
+            ExchangeId::Hyperliquid => true,
+            ExchangeId::CoinbaseAdvanced => true,
+            ExchangeId::Upbit => true,
+            ExchangeId::Poloniex => true,
+            ExchangeId::Bitfinex => true,
+            ExchangeId::BinanceUs => true,
             _ => false,
         }
     }
@@ -300,6 +516,42 @@ impl ExchangeId {
     pub fn supports_order_books(&self) -> bool {
         match self {
             ExchangeId::BinanceFuturesUsd => true,
+            ExchangeId::BinanceFuturesCoin => true,
+            ExchangeId::Bitstamp => true,
+            _ => false,
+        }
+    }
+
+    /// Determines whether this [`ExchangeId`] supports the collection of top of book
+    /// [`OrderBookL1`](model::OrderBookL1) market data.
+    #[allow(clippy::match_like_matches_macro)]
+    pub fn supports_order_book_l1(&self) -> bool {
+        match self {
+            ExchangeId::BinanceFuturesUsd => true,
+            ExchangeId::BinanceFuturesCoin => true,
+            ExchangeId::Kraken => true,
+            _ => false,
+        }
+    }
+
+    /// Determines whether this [`ExchangeId`] supports the collection of depth limited
+    /// L2 OrderBook market data (ie/ [`SubKind::OrderBookL2`](model::subscription::SubKind)).
+    #[allow(clippy::match_like_matches_macro)]
+    pub fn supports_order_book_l2(&self) -> bool {
+        match self {
+            ExchangeId::Bybit | ExchangeId::BybitFuturesUsd => true,
+            _ => false,
+        }
+    }
+
+    /// Determines whether this [`ExchangeId`] supports the collection of lightweight
+    /// [`OrderBookL2Snapshot`](model::OrderBookL2Snapshot) market data.
+    #[allow(clippy::match_like_matches_macro)]
+    pub fn supports_order_book_l2_snapshots(&self) -> bool {
+        match self {
+            ExchangeId::BinanceFuturesUsd => true,
+            ExchangeId::BinanceFuturesCoin => true,
+            ExchangeId::BinanceUs => true,
             _ => false,
         }
     }
@@ -309,6 +561,24 @@ impl ExchangeId {
     #[allow(clippy::match_like_matches_macro)]
     pub fn supports_order_book_l2_deltas(&self) -> bool {
         match self {
+            ExchangeId::Binance => true,
+            ExchangeId::Deribit => true,
+            ExchangeId::CoinbaseAdvanced => true,
+            ExchangeId::Bitstamp => true,
+            _ => false,
+        }
+    }
+
+    /// Determines whether this [`ExchangeId`] supports the collection of L2 OrderBooks maintained
+    /// internally from snapshots & validated deltas
+    /// (ie/ [`SubKind::OrderBookL2Managed`](model::subscription::SubKind)).
+    #[allow(clippy::match_like_matches_macro)]
+    pub fn supports_order_book_l2_managed(&self) -> bool {
+        match self {
+            ExchangeId::BinanceFuturesUsd | ExchangeId::BinanceUs => true,
+            ExchangeId::Deribit => true,
+            ExchangeId::Kraken => true,
+            ExchangeId::Okx => true,
             _ => false,
         }
     }
@@ -318,6 +588,9 @@ impl ExchangeId {
     #[allow(clippy::match_like_matches_macro)]
     pub fn supports_order_book_l3_deltas(&self) -> bool {
         match self {
+            ExchangeId::Bitstamp => true,
+            ExchangeId::KrakenL3 => true,
+            ExchangeId::Bitfinex => true,
             _ => false,
         }
     }
@@ -328,9 +601,296 @@ impl ExchangeId {
     pub fn supports_liquidations(&self) -> bool {
         match self {
             ExchangeId::BinanceFuturesUsd => true,
+            ExchangeId::BinanceFuturesCoin => true,
+            _ => false,
+        }
+    }
+
+    /// Determines whether this [`ExchangeId`] supports the collection of
+    /// [`MarkPrice`](model::MarkPrice) market data.
+    #[allow(clippy::match_like_matches_macro)]
+    pub fn supports_mark_prices(&self) -> bool {
+        match self {
+            ExchangeId::BinanceFuturesUsd => true,
+            ExchangeId::BinanceFuturesCoin => true,
+            ExchangeId::KrakenFutures => true,
+            _ => false,
+        }
+    }
+
+    /// Determines whether this [`ExchangeId`] supports the collection of
+    /// [`FundingRate`](model::FundingRate) market data.
+    #[allow(clippy::match_like_matches_macro)]
+    pub fn supports_funding_rates(&self) -> bool {
+        match self {
+            ExchangeId::BinanceFuturesUsd => true,
+            ExchangeId::BinanceFuturesCoin => true,
+            ExchangeId::BybitFuturesUsd => true,
+            ExchangeId::Okx => true,
+            _ => false,
+        }
+    }
+
+    /// Determines whether this [`ExchangeId`] supports the collection of
+    /// [`OpenInterest`](model::OpenInterest) market data. Note that
+    /// [`ExchangeId::BinanceFuturesUsd`] does not stream open interest, so it is polled via the
+    /// REST API.
+    #[allow(clippy::match_like_matches_macro)]
+    pub fn supports_open_interest(&self) -> bool {
+        match self {
+            ExchangeId::BinanceFuturesUsd => true,
+            ExchangeId::BybitFuturesUsd => true,
+            ExchangeId::Okx => true,
+            _ => false,
+        }
+    }
+
+    /// Determines whether this [`ExchangeId`] supports the collection of
+    /// [`Ticker`](model::Ticker) market data.
+    #[allow(clippy::match_like_matches_macro)]
+    pub fn supports_tickers(&self) -> bool {
+        match self {
+            ExchangeId::BinanceFuturesUsd => true,
+            ExchangeId::BinanceFuturesCoin => true,
+            ExchangeId::BinanceUs => true,
+            ExchangeId::Bybit | ExchangeId::BybitFuturesUsd => true,
+            ExchangeId::Okx => true,
+            ExchangeId::Bithumb => true,
+            _ => false,
+        }
+    }
+
+    /// Determines whether this [`ExchangeId`] supports the collection of
+    /// [`Ticker`](model::Ticker) market data for every market via a single all market stream.
+    #[allow(clippy::match_like_matches_macro)]
+    pub fn supports_all_market_tickers(&self) -> bool {
+        match self {
+            ExchangeId::BinanceFuturesUsd => true,
+            ExchangeId::BinanceFuturesCoin => true,
+            ExchangeId::BinanceUs => true,
+            _ => false,
+        }
+    }
+
+    /// Determines whether this [`ExchangeId`] supports the collection of
+    /// [`RollingTicker`](model::RollingTicker) market data.
+    #[allow(clippy::match_like_matches_macro)]
+    pub fn supports_rolling_tickers(&self) -> bool {
+        match self {
+            ExchangeId::BinanceUs => true,
             _ => false,
         }
     }
+
+    /// Determines whether this [`ExchangeId`] supports the collection of
+    /// [`OptionTicker`](model::OptionTicker) market data.
+    #[allow(clippy::match_like_matches_macro)]
+    pub fn supports_option_tickers(&self) -> bool {
+        match self {
+            ExchangeId::Deribit => true,
+            _ => false,
+        }
+    }
+
+    /// Determines whether this [`ExchangeId`] supports the collection of
+    /// [`InstrumentStatus`](model::InstrumentStatus) updates.
+    #[allow(clippy::match_like_matches_macro)]
+    pub fn supports_instrument_status(&self) -> bool {
+        match self {
+            ExchangeId::BinanceFuturesUsd | ExchangeId::Coinbase => true,
+            _ => false,
+        }
+    }
+
+    /// Determines whether this [`ExchangeId`] supports the collection of authenticated
+    /// [`SubKind::UserData`](model::subscription::SubKind::UserData) account updates.
+    #[allow(clippy::match_like_matches_macro)]
+    pub fn supports_user_data(&self) -> bool {
+        match self {
+            ExchangeId::Binance
+            | ExchangeId::BinanceFuturesUsd
+            | ExchangeId::BinanceFuturesCoin => true,
+            _ => false,
+        }
+    }
+
+    /// Determines whether this [`ExchangeId`] supports the collection of the provided
+    /// [`SubKind`](model::subscription::SubKind).
+    pub fn supports(&self, kind: &model::subscription::SubKind) -> bool {
+        use model::subscription::SubKind;
+        match kind {
+            SubKind::Trade => self.supports_trades(),
+            SubKind::AggTrade => self.supports_agg_trades(),
+            SubKind::Candle(_) => self.supports_candles() || self.supports_trade_candles(),
+            SubKind::OrderBook => self.supports_order_books(),
+            SubKind::OrderBookL1 => self.supports_order_book_l1(),
+            SubKind::OrderBookL2(_) => self.supports_order_book_l2(),
+            SubKind::OrderBookL2Snapshot(..) => self.supports_order_book_l2_snapshots(),
+            SubKind::OrderBookL2Delta => self.supports_order_book_l2_deltas(),
+            SubKind::OrderBookL2Managed => self.supports_order_book_l2_managed(),
+            SubKind::OrderBookL3Delta => self.supports_order_book_l3_deltas(),
+            SubKind::Liquidation => self.supports_liquidations(),
+            SubKind::MarkPrice => self.supports_mark_prices(),
+            SubKind::FundingRate => self.supports_funding_rates(),
+            SubKind::OpenInterest => self.supports_open_interest(),
+            SubKind::Ticker => self.supports_tickers(),
+            SubKind::RollingTicker(_) => self.supports_rolling_tickers(),
+            SubKind::TickerAll => self.supports_all_market_tickers(),
+            SubKind::OptionTicker => self.supports_option_tickers(),
+            SubKind::InstrumentStatus => self.supports_instrument_status(),
+            SubKind::UserData => self.supports_user_data(),
+        }
+    }
+
+    /// Return the base URL of the public WebSocket API associated with this [`ExchangeId`].
+    ///
+    /// eg/ ExchangeId::Kraken => "wss://ws.kraken.com/"
+    pub fn ws_base_url(&self) -> &'static str {
+        match self {
+            ExchangeId::Binance => "wss://stream.binance.com:9443/stream",
+            ExchangeId::BinanceFuturesUsd => "wss://fstream.binance.com/stream",
+            ExchangeId::BinanceFuturesCoin => "wss://dstream.binance.com/stream",
+            ExchangeId::Bitstamp => "wss://ws.bitstamp.net",
+            ExchangeId::Bybit => "wss://stream.bybit.com/v5/public/spot",
+            ExchangeId::BybitFuturesUsd => "wss://stream.bybit.com/v5/public/linear",
+            ExchangeId::Coinbase => "wss://ws-feed.exchange.coinbase.com",
+            ExchangeId::Deribit => "wss://www.deribit.com/ws/api/v2",
+            ExchangeId::Ftx => "wss://ftx.com/ws/",
+            ExchangeId::Kraken => "wss://ws.kraken.com/",
+            ExchangeId::Okx => "wss://ws.okx.com:8443/ws/v5/public",
+            ExchangeId::Kucoin => "wss://ws-api-spot.kucoin.com/",
+            ExchangeId::Gateio => "wss://api.gateio.ws/ws/v4/",
+            ExchangeId::Bitmex => "wss://ws.bitmex.com/realtime",
+            ExchangeId::Gemini => "wss://api.gemini.com/v2/marketdata",
+            ExchangeId::Cryptocom => "wss://stream.crypto.com/exchange/v1/market",
+            ExchangeId::Mexc => "wss://wbs.mexc.com/ws",
+            ExchangeId::Bitget => "wss://ws.bitget.com/v2/ws/public",
+            ExchangeId::Dydx => "wss://indexer.dydx.trade/v4/ws",
+            ExchangeId::Hyperliquid => "wss://api.hyperliquid.xyz/ws",
+            ExchangeId::CoinbaseAdvanced => "wss://advanced-trade-ws.coinbase.com",
+            ExchangeId::KrakenFutures => "wss://futures.kraken.com/ws/v1",
+            ExchangeId::Upbit => "wss://api.upbit.com/websocket/v1",
+            ExchangeId::Bithumb => "wss://pubwss.bithumb.com/pub/ws",
+            ExchangeId::Poloniex => "wss://ws.poloniex.com/ws/public",
+            ExchangeId::Bitfinex => "wss://api-pub.bitfinex.com/ws/2",
+            ExchangeId::BinanceUs => "wss://stream.binance.us:9443/stream",
+            ExchangeId::KrakenL3 => "wss://ws-l3.kraken.com/v2",
+        }
+    }
+
+    /// Return the base URL of the sandbox public WebSocket API associated with this
+    /// [`ExchangeId`], or `None` if the exchange does not provide a sandbox environment.
+    ///
+    /// eg/ ExchangeId::Coinbase => "wss://ws-feed-public.sandbox.exchange.coinbase.com"
+    pub fn sandbox_ws_base_url(&self) -> Option<&'static str> {
+        match self {
+            ExchangeId::BinanceFuturesUsd => Some("wss://fstream.binancefuture.com/stream"),
+            ExchangeId::BinanceFuturesCoin => Some("wss://dstream.binancefuture.com/stream"),
+            ExchangeId::Coinbase => Some("wss://ws-feed-public.sandbox.exchange.coinbase.com"),
+            ExchangeId::Okx => Some("wss://wspap.okx.com:8443/ws/v5/public"),
+            _ => None,
+        }
+    }
+
+    /// Return the base URL of the REST API associated with this [`ExchangeId`].
+    ///
+    /// eg/ ExchangeId::BinanceFuturesUsd => "https://fapi.binance.com"
+    pub fn rest_base_url(&self) -> &'static str {
+        match self {
+            ExchangeId::Binance => "https://api.binance.com",
+            ExchangeId::BinanceFuturesUsd => "https://fapi.binance.com",
+            ExchangeId::BinanceFuturesCoin => "https://dapi.binance.com",
+            ExchangeId::Bitstamp => "https://www.bitstamp.net/api/v2",
+            ExchangeId::Bybit | ExchangeId::BybitFuturesUsd => "https://api.bybit.com",
+            ExchangeId::Coinbase => "https://api.exchange.coinbase.com",
+            ExchangeId::Deribit => "https://www.deribit.com/api/v2",
+            ExchangeId::Ftx => "https://ftx.com/api",
+            ExchangeId::Kraken => "https://api.kraken.com",
+            ExchangeId::Okx => "https://www.okx.com",
+            ExchangeId::Kucoin => "https://api.kucoin.com",
+            ExchangeId::Gateio => "https://api.gateio.ws/api/v4",
+            ExchangeId::Bitmex => "https://www.bitmex.com/api/v1",
+            ExchangeId::Gemini => "https://api.gemini.com",
+            ExchangeId::Cryptocom => "https://api.crypto.com/exchange/v1",
+            ExchangeId::Mexc => "https://api.mexc.com",
+            ExchangeId::Bitget => "https://api.bitget.com",
+            ExchangeId::Dydx => "https://indexer.dydx.trade/v4",
+            ExchangeId::Hyperliquid => "https://api.hyperliquid.xyz",
+            ExchangeId::CoinbaseAdvanced => "https://api.coinbase.com/api/v3/brokerage",
+            ExchangeId::KrakenFutures => "https://futures.kraken.com/derivatives/api/v3",
+            ExchangeId::Upbit => "https://api.upbit.com/v1",
+            ExchangeId::Bithumb => "https://api.bithumb.com/public",
+            ExchangeId::Poloniex => "https://api.poloniex.com",
+            ExchangeId::Bitfinex => "https://api-pub.bitfinex.com/v2",
+            ExchangeId::BinanceUs => "https://api.binance.us",
+            ExchangeId::KrakenL3 => "https://api.kraken.com",
+        }
+    }
+
+    /// Return the maximum number of streams a single WebSocket connection to this [`ExchangeId`]
+    /// can listen to, or `None` if the exchange does not document a limit.
+    ///
+    /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#websocket-market-streams>
+    pub fn max_streams_per_connection(&self) -> Option<usize> {
+        match self {
+            ExchangeId::Binance | ExchangeId::BinanceUs => Some(1024),
+            ExchangeId::BinanceFuturesUsd | ExchangeId::BinanceFuturesCoin => Some(200),
+            _ => None,
+        }
+    }
+
+    /// Return the [`RateLimit`] applied to messages sent over a WebSocket connection to this
+    /// [`ExchangeId`] (eg/ subscription requests), or `None` if the exchange does not document
+    /// a limit.
+    ///
+    /// See docs: <https://binance-docs.github.io/apidocs/spot/en/#websocket-limits>
+    pub fn ws_rate_limit(&self) -> Option<RateLimit> {
+        match self {
+            ExchangeId::Binance | ExchangeId::BinanceUs => {
+                Some(RateLimit::new(5, Duration::from_secs(1)))
+            }
+            ExchangeId::BinanceFuturesUsd | ExchangeId::BinanceFuturesCoin => {
+                Some(RateLimit::new(10, Duration::from_secs(1)))
+            }
+            ExchangeId::Kucoin => Some(RateLimit::new(100, Duration::from_secs(10))),
+            ExchangeId::Cryptocom => Some(RateLimit::new(100, Duration::from_secs(1))),
+            _ => None,
+        }
+    }
+}
+
+/// Maximum number of messages that can be sent to an exchange within an interval.
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct RateLimit {
+    pub messages: u32,
+    pub interval: Duration,
+}
+
+impl RateLimit {
+    /// Construct a new [`RateLimit`] of `messages` per `interval`.
+    pub fn new(messages: u32, interval: Duration) -> Self {
+        Self { messages, interval }
+    }
+}
+
+/// Send the provided subscription [`WsMessage`]s to the exchange, waiting for the
+/// [`RateLimit`] interval each time its maximum number of messages has been sent.
+pub async fn send_subscriptions(
+    websocket: &mut WebSocket,
+    subscriptions: Vec<WsMessage>,
+    rate_limit: Option<RateLimit>,
+) -> Result<(), SocketError> {
+    for (sent, subscription) in subscriptions.into_iter().enumerate() {
+        if let Some(limit) = rate_limit {
+            if sent > 0 && sent % limit.messages.max(1) as usize == 0 {
+                tokio::time::sleep(limit.interval).await;
+            }
+        }
+
+        websocket.send(subscription).await?;
+    }
+
+    Ok(())
 }
 
 /// Consume [`WsMessage`]s transmitted from the [`ExchangeTransformer`] and send them on to the
@@ -377,6 +937,8 @@ pub mod test_util {
         MarketEvent {
             exchange_time: Utc::now(),
             received_time: Utc::now(),
+            received_instant: None,
+            raw: None,
             exchange: Exchange::from(ExchangeId::Binance),
             instrument: Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
             kind: DataKind::Trade(PublicTrade {
@@ -394,6 +956,8 @@ pub mod test_util {
         MarketEvent {
             exchange_time: now,
             received_time: now.add(chrono::Duration::milliseconds(200)),
+            received_instant: None,
+            raw: None,
             exchange: Exchange::from(ExchangeId::Binance),
             instrument: Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
             kind: DataKind::Candle(Candle {
@@ -0,0 +1,192 @@
+use super::Level;
+use barter_integration::model::Side;
+use std::cmp::Ordering;
+
+/// Maximum number of pending [`Level`] updates a [`ColdLevels`] accumulates before they are
+/// compacted into the encoded representation.
+const MAX_PENDING: usize = 256;
+
+/// Compact compressed storage for the deep [`Level`]s of one side of an
+/// [`OrderBook`](super::OrderBook) that are rarely read (eg/ by top-of-book analytics).
+///
+/// Levels are stored sorted best-to-worst for the [`Side`] (ie/ bids descending, asks ascending),
+/// each encoded as the XOR of its price & quantity bits with the previous level, written as
+/// bit-reversed LEB128 varints. Adjacent levels share most of their bits, so this is typically
+/// several times smaller than a `Vec<Level>`.
+///
+/// Updates are buffered uncompressed & only applied when the levels are rehydrated, or once
+/// [`MAX_PENDING`] updates have accumulated.
+#[derive(Clone, PartialEq, Debug)]
+pub struct ColdLevels {
+    side: Side,
+    encoded: Vec<u8>,
+    pending: Vec<Level>,
+}
+
+impl ColdLevels {
+    /// Construct a new empty [`ColdLevels`] for the provided [`Side`] of the book.
+    pub fn new(side: Side) -> Self {
+        Self {
+            side,
+            encoded: Vec::new(),
+            pending: Vec::new(),
+        }
+    }
+
+    /// Determines if this [`ColdLevels`] contains no levels or pending updates.
+    pub fn is_empty(&self) -> bool {
+        self.encoded.is_empty() && self.pending.is_empty()
+    }
+
+    /// Number of bytes used by the encoded representation.
+    pub fn encoded_len(&self) -> usize {
+        self.encoded.len()
+    }
+
+    /// Remove every level & pending update.
+    pub fn clear(&mut self) {
+        self.encoded.clear();
+        self.pending.clear();
+    }
+
+    /// Buffer an insert, replace or remove (if the quantity is zero) of the provided [`Level`].
+    pub fn upsert(&mut self, level: Level) {
+        self.pending.push(level);
+        if self.pending.len() >= MAX_PENDING {
+            let levels = self.levels();
+            self.store(&levels);
+        }
+    }
+
+    /// Rehydrate every level, sorted best-to-worst, applying any pending updates.
+    pub fn levels(&self) -> Vec<Level> {
+        let mut levels = decode(&self.encoded);
+        for level in &self.pending {
+            let compare = |a: &f64, b: &f64| self.compare(*a, *b);
+            match levels.binary_search_by(|existing| compare(&existing.price, &level.price)) {
+                Ok(index) if level.quantity == 0.0 => {
+                    levels.remove(index);
+                }
+                Ok(index) => levels[index] = *level,
+                Err(_) if level.quantity == 0.0 => {}
+                Err(index) => levels.insert(index, *level),
+            }
+        }
+        levels
+    }
+
+    /// Remove & return up to `n` of the best levels, rehydrating them from the encoded
+    /// representation.
+    pub fn take_best(&mut self, n: usize) -> Vec<Level> {
+        let mut levels = self.levels();
+        let rest = levels.split_off(n.min(levels.len()));
+        self.store(&rest);
+        levels
+    }
+
+    /// Replace the contents with the provided levels, which must be sorted best-to-worst.
+    fn store(&mut self, levels: &[Level]) {
+        self.pending.clear();
+        self.encoded = encode(levels);
+    }
+
+    /// Price ordering of levels, best-to-worst, for the [`Side`] of the book.
+    fn compare(&self, a: f64, b: f64) -> Ordering {
+        match self.side {
+            Side::Buy => b.total_cmp(&a),
+            Side::Sell => a.total_cmp(&b),
+        }
+    }
+}
+
+/// Encode sorted [`Level`]s as bit-reversed XOR deltas of their price & quantity bits.
+fn encode(levels: &[Level]) -> Vec<u8> {
+    let mut encoded = Vec::with_capacity(levels.len() * 4);
+    let (mut price, mut quantity) = (0u64, 0u64);
+
+    for level in levels {
+        let (next_price, next_quantity) = (level.price.to_bits(), level.quantity.to_bits());
+        write_varint(&mut encoded, (next_price ^ price).reverse_bits());
+        write_varint(&mut encoded, (next_quantity ^ quantity).reverse_bits());
+        price = next_price;
+        quantity = next_quantity;
+    }
+
+    encoded
+}
+
+/// Decode [`Level`]s previously encoded with [`encode`].
+fn decode(mut encoded: &[u8]) -> Vec<Level> {
+    let mut levels = Vec::new();
+    let (mut price, mut quantity) = (0u64, 0u64);
+
+    while !encoded.is_empty() {
+        price ^= read_varint(&mut encoded).reverse_bits();
+        quantity ^= read_varint(&mut encoded).reverse_bits();
+        levels.push(Level::new(f64::from_bits(price), f64::from_bits(quantity)));
+    }
+
+    levels
+}
+
+/// Write a LEB128 varint.
+fn write_varint(encoded: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        encoded.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    encoded.push(value as u8);
+}
+
+/// Read a LEB128 varint, advancing the provided slice past it.
+fn read_varint(encoded: &mut &[u8]) -> u64 {
+    let mut value = 0u64;
+    let mut shift = 0;
+    while let Some((&byte, rest)) = encoded.split_first() {
+        *encoded = rest;
+        value |= u64::from(byte & 0x7F) << shift;
+        if byte & 0x80 == 0 {
+            break;
+        }
+        shift += 7;
+    }
+    value
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cold_levels() {
+        let levels = (0..100)
+            .map(|index| Level::new(16000.0 - index as f64 * 0.5, 1.0 + (index % 3) as f64))
+            .collect::<Vec<_>>();
+
+        let mut cold = ColdLevels::new(Side::Buy);
+        assert!(cold.is_empty());
+
+        cold.store(&levels);
+        assert_eq!(cold.levels(), levels);
+        assert!(cold.encoded_len() < levels.len() * std::mem::size_of::<Level>() / 2);
+
+        // Pending updates are applied when the levels are rehydrated
+        cold.upsert(Level::new(16000.0, 0.0));
+        cold.upsert(Level::new(15999.5, 7.0));
+        cold.upsert(Level::new(16000.25, 2.0));
+
+        let best = cold.take_best(2);
+        assert_eq!(
+            best,
+            vec![Level::new(16000.25, 2.0), Level::new(15999.5, 7.0)]
+        );
+        assert_eq!(cold.levels(), levels[2..].to_vec());
+
+        // Asks are ordered ascending
+        let mut cold = ColdLevels::new(Side::Sell);
+        cold.upsert(Level::new(101.0, 1.0));
+        cold.upsert(Level::new(100.0, 1.0));
+        assert_eq!(cold.take_best(1), vec![Level::new(100.0, 1.0)]);
+        assert_eq!(cold.levels(), vec![Level::new(101.0, 1.0)]);
+    }
+}
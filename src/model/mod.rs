@@ -1,20 +1,39 @@
+use crate::ExchangeId;
 use barter_integration::{
-    model::{Exchange, Instrument, Side},
+    error::SocketError,
+    model::{Exchange, Instrument, InstrumentKind, Side},
     Event,
 };
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, TimeZone, Utc};
 use serde::{Deserialize, Serialize};
+use std::{sync::Arc, time::Instant};
+use subscription::TickerWindow;
 
 /// Barter data structures that support subscribing to exchange specific market data.
 ///
 /// eg/ `Subscription`, `SubscriptionId`, etc.
 pub mod subscription;
 
+/// Compact compressed storage for deep [`OrderBook`] [`Level`]s.
+///
+/// eg/ `ColdLevels`
+pub mod cold;
+
 /// Normalised Barter `MarketEvent` containing metadata about the included [`DataKind`] variant.
 #[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
 pub struct MarketEvent {
     pub exchange_time: DateTime<Utc>,
     pub received_time: DateTime<Utc>,
+    /// Monotonic clock reading taken when the frame containing this [`MarketEvent`] was read
+    /// from the socket, before it was deserialised. Populated by [`MarketStream`](crate::MarketStream)s,
+    /// so latency measurements are not polluted by parse or queue time.
+    #[serde(skip)]
+    pub received_instant: Option<Instant>,
+    /// Original exchange payload of the frame containing this [`MarketEvent`], for archiving
+    /// exact exchange data or parsing venue specific fields. Only populated by
+    /// [`RawWsStream`](crate::raw::RawWsStream)s.
+    #[serde(skip)]
+    pub raw: Option<Arc<[u8]>>,
     pub exchange: Exchange,
     pub instrument: Instrument,
     pub kind: DataKind,
@@ -24,9 +43,25 @@ pub struct MarketEvent {
 #[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
 pub enum DataKind {
     Trade(PublicTrade),
+    AggTrade(AggTrade),
     Candle(Candle),
     OrderBook(OrderBook),
+    OrderBookL1(OrderBookL1),
+    OrderBookL2Snapshot(OrderBookL2Snapshot),
+    OrderBookL2Delta(OrderBookL2Delta),
+    OrderBookL3Delta(OrderBookL3Delta),
     Liquidation(Liquidation),
+    MarkPrice(MarkPrice),
+    FundingRate(FundingRate),
+    OpenInterest(OpenInterest),
+    Ticker(Ticker),
+    RollingTicker(RollingTicker),
+    OptionTicker(OptionTicker),
+    InstrumentStatus(InstrumentStatus),
+    OrderUpdate(OrderUpdate),
+    BalanceUpdate(BalanceUpdate),
+    PositionUpdate(PositionUpdate),
+    Rollover(Rollover),
 }
 
 /// Normalised Barter [`PublicTrade`] model.
@@ -38,6 +73,20 @@ pub struct PublicTrade {
     pub side: Side,
 }
 
+/// Normalised Barter [`AggTrade`] model, aggregating the [`PublicTrade`]s filled at the same price
+/// by a single taker order.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct AggTrade {
+    pub id: String,
+    /// Identifier of the first [`PublicTrade`] included in the aggregation.
+    pub first_trade_id: String,
+    /// Identifier of the last [`PublicTrade`] included in the aggregation.
+    pub last_trade_id: String,
+    pub price: f64,
+    pub quantity: f64,
+    pub side: Side,
+}
+
 /// Normalised Barter OHLCV [`Candle`] model.
 #[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
 pub struct Candle {
@@ -60,6 +109,63 @@ pub struct OrderBook {
     pub asks: Vec<Level>,
 }
 
+/// Normalised Barter [`OrderBookL1`] containing only the best bid & best ask [`Level`]s
+/// (ie/ top of book).
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OrderBookL1 {
+    pub last_update_time: DateTime<Utc>,
+    pub best_bid: Level,
+    pub best_ask: Level,
+}
+
+/// Normalised Barter [`OrderBookL2Snapshot`] containing the top N [`Level`]s of each side of the
+/// book. Each snapshot replaces the previous one, so no local book needs to be maintained.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OrderBookL2Snapshot {
+    pub last_update_time: DateTime<Utc>,
+    pub last_update_id: u64,
+    pub bids: Vec<Level>,
+    pub asks: Vec<Level>,
+}
+
+/// Normalised Barter [`OrderBookL2Delta`] containing the [`Level`]s that have changed since the
+/// previous [`OrderBook`] snapshot or [`OrderBookL2Delta`]. A [`Level`] with a zero quantity
+/// indicates the price level should be removed from the book.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OrderBookL2Delta {
+    pub last_update_time: DateTime<Utc>,
+    /// First update id covered by this delta, for exchanges whose deltas may span a range of
+    /// update ids (eg/ `Binance` diff depth). `None` if the delta covers a single update.
+    #[serde(default)]
+    pub first_update_id: Option<u64>,
+    pub last_update_id: u64,
+    pub bids: Vec<Level>,
+    pub asks: Vec<Level>,
+}
+
+/// Normalised Barter [`OrderBookL3Delta`] describing a change to an individual order resting in
+/// the book.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OrderBookL3Delta {
+    pub id: String,
+    pub kind: OrderBookL3DeltaKind,
+    pub side: Side,
+    pub price: f64,
+    pub quantity: f64,
+}
+
+/// Type of change to an individual order described by an [`OrderBookL3Delta`].
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OrderBookL3DeltaKind {
+    /// New order added to the book.
+    Open,
+    /// Existing order had it's price or remaining quantity changed (eg/ partially filled).
+    Change,
+    /// Existing order removed from the book (eg/ fully filled or cancelled).
+    Delete,
+}
+
 /// Normalised Barter [`OrderBook`] [`Level`].
 #[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
 pub struct Level {
@@ -76,6 +182,320 @@ pub struct Liquidation {
     pub time: DateTime<Utc>,
 }
 
+/// Normalised Barter [`MarkPrice`] model for perpetual futures, including the index price &
+/// funding rate used to derive it.
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct MarkPrice {
+    pub price: f64,
+    pub index_price: f64,
+    pub funding_rate: f64,
+    pub next_funding_time: DateTime<Utc>,
+}
+
+/// Normalised Barter [`FundingRate`] model for perpetual futures, communicating the funding rate
+/// that will be exchanged between longs & shorts at the next funding time.
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct FundingRate {
+    pub rate: f64,
+    pub next_funding_time: DateTime<Utc>,
+}
+
+/// Normalised Barter [`OpenInterest`] model for futures, communicating the total size of all
+/// outstanding contracts.
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OpenInterest {
+    /// Open interest denominated in the base asset.
+    pub quantity: f64,
+    /// Open interest value denominated in the quote asset, if provided by the exchange.
+    pub notional: Option<f64>,
+}
+
+/// Normalised Barter rolling 24 hour [`Ticker`] model.
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct Ticker {
+    pub last_price: f64,
+    pub volume_24h: f64,
+    pub high_24h: f64,
+    pub low_24h: f64,
+    pub change_percent_24h: f64,
+}
+
+/// Normalised Barter [`RollingTicker`] model containing statistics over a rolling
+/// [`TickerWindow`], as used by intraday screeners.
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct RollingTicker {
+    pub window: TickerWindow,
+    pub open: f64,
+    pub last_price: f64,
+    pub high: f64,
+    pub low: f64,
+    pub volume: f64,
+    pub quote_volume: f64,
+    pub change_percent: f64,
+    pub trade_count: u64,
+}
+
+/// Normalised Barter [`OptionTicker`] model containing the mark price, mark implied volatility &
+/// greeks of an option, as used to quote options.
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OptionTicker {
+    pub mark_price: f64,
+    /// Mark implied volatility, in percent (eg/ 55.0 for 55%).
+    pub mark_iv: f64,
+    pub underlying_price: f64,
+    pub delta: f64,
+    pub gamma: f64,
+    pub vega: f64,
+    pub theta: f64,
+}
+
+/// Type of option contract.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OptionKind {
+    Call,
+    Put,
+}
+
+/// Normalised Barter [`OptionContract`] identifying an option by its underlying, expiry, strike
+/// & [`OptionKind`].
+///
+/// [`Instrument`] has no option [`InstrumentKind`], so an [`OptionContract`] is encoded into the
+/// [`Instrument`] base as "{underlying}-{expiry}-{strike}-{c|p}" (eg/ "btc-241227-50000-c"),
+/// with an [`InstrumentKind::Spot`] kind.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OptionContract {
+    pub underlying: String,
+    pub quote: String,
+    pub expiry: NaiveDate,
+    pub strike: f64,
+    pub kind: OptionKind,
+}
+
+impl OptionContract {
+    /// Format of the expiry date encoded into the [`Instrument`] base.
+    pub const EXPIRY_FORMAT: &'static str = "%y%m%d";
+}
+
+/// Normalised Barter [`FutureContract`] identifying a dated (delivery) future by its underlying,
+/// quote & expiry.
+///
+/// [`Instrument`] has no dated future [`InstrumentKind`], so a [`FutureContract`] is encoded into
+/// the [`Instrument`] base as "{underlying}-{expiry}" (eg/ "btc-240628"), with an
+/// [`InstrumentKind::FuturePerpetual`] kind. Only exchanges that
+/// [support dated futures](ExchangeId::supports_dated_futures) accept such an [`Instrument`].
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct FutureContract {
+    pub underlying: String,
+    pub quote: String,
+    pub expiry: NaiveDate,
+}
+
+impl FutureContract {
+    /// Format of the expiry date encoded into the [`Instrument`] base.
+    pub const EXPIRY_FORMAT: &'static str = "%y%m%d";
+
+    /// Time at which this [`FutureContract`] expires on the provided [`ExchangeId`], taken as the
+    /// [`ExchangeId::dated_futures_expiry`] of the expiry date. Returns `None` if the
+    /// [`ExchangeId`] does not support dated futures.
+    pub fn expiry_time(&self, exchange: ExchangeId) -> Option<DateTime<Utc>> {
+        exchange
+            .dated_futures_expiry()
+            .map(|time| Utc.from_utc_datetime(&self.expiry.and_time(time)))
+    }
+}
+
+/// Normalised Barter [`Rollover`] communicating that a [`RollManager`](crate::rollover::RollManager)
+/// rolled from one dated [`FutureContract`] to the next.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct Rollover {
+    pub from: Instrument,
+    pub to: Instrument,
+}
+
+/// Normalised Barter [`InstrumentStatus`] communicating the trading state of an [`Instrument`].
+/// Consumers can use this to stop quoting or recording when an [`Instrument`] is halted or
+/// delisted.
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InstrumentStatus {
+    /// Fully open for trading.
+    Trading,
+    /// Only orders that would rest on the book (maker) are accepted.
+    PostOnly,
+    /// Only limit orders are accepted.
+    LimitOnly,
+    /// Only order cancellations are accepted.
+    CancelOnly,
+    /// Trading is temporarily suspended.
+    Halted,
+    /// Permanently removed from trading (eg/ delisted or a settled future).
+    Delisted,
+}
+
+/// Normalised Barter [`OrderUpdate`] communicating the latest state of one of the account's
+/// orders, sourced from an exchange private order channel. The id is the exchange order id, so
+/// updates can be correlated with the [`OrderBookL3Delta`]s of the same order.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OrderUpdate {
+    pub id: String,
+    pub client_id: Option<String>,
+    pub side: Side,
+    pub status: OrderStatus,
+    pub price: f64,
+    pub quantity: f64,
+    pub filled: f64,
+    pub remaining: f64,
+    /// [`OrderFill`] that caused this [`OrderUpdate`], if any.
+    pub fill: Option<OrderFill>,
+    pub time: DateTime<Utc>,
+}
+
+/// Single fill of an order described by an [`OrderUpdate`].
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OrderFill {
+    pub trade_id: String,
+    pub price: f64,
+    pub quantity: f64,
+    /// Determines if the order was the maker (resting) order of the trade.
+    pub is_maker: bool,
+}
+
+/// Lifecycle status of an order described by an [`OrderUpdate`].
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OrderStatus {
+    /// Order received by the exchange, but not yet resting in the book.
+    Received,
+    /// Order accepted by the exchange & resting in the book.
+    New,
+    /// Order partially filled & the remainder is still resting in the book.
+    PartiallyFilled,
+    /// Order completely filled.
+    Filled,
+    /// Order cancelled before being completely filled.
+    Cancelled,
+    /// Order rejected by the exchange.
+    Rejected,
+    /// Order expired according to its time in force (eg/ IOC, FOK).
+    Expired,
+}
+
+/// Normalised Barter [`BalanceUpdate`] communicating the latest [`Balance`]s of the account's
+/// assets that changed (eg/ due to a deposit or fill), sourced from an exchange private account
+/// channel.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BalanceUpdate {
+    pub time: DateTime<Utc>,
+    pub balances: Vec<Balance>,
+}
+
+/// Normalised Barter [`Balance`] of a single asset.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct Balance {
+    pub asset: String,
+    pub total: f64,
+    pub available: f64,
+}
+
+/// Normalised Barter [`PositionUpdate`] communicating the latest state of one of the account's
+/// derivatives positions, sourced from an exchange private position channel.
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct PositionUpdate {
+    pub time: DateTime<Utc>,
+    /// Signed position size, where a negative size is a short position.
+    pub size: f64,
+    pub entry_price: f64,
+    /// Estimated liquidation price, if provided by the exchange.
+    pub liquidation_price: Option<f64>,
+    pub margin: f64,
+}
+
+impl OrderBook {
+    /// Apply the provided [`OrderBookL2Delta`] to this [`OrderBook`], replacing the quantity of
+    /// existing [`Level`]s, inserting new [`Level`]s in price order (bids descending, asks
+    /// ascending), and removing [`Level`]s with a zero quantity.
+    pub fn apply(&mut self, delta: &OrderBookL2Delta) {
+        self.last_update_time = delta.last_update_time;
+        self.last_update_id = delta.last_update_id;
+
+        for level in &delta.bids {
+            upsert_level(&mut self.bids, *level, |existing, new| {
+                new.total_cmp(existing)
+            });
+        }
+        for level in &delta.asks {
+            upsert_level(&mut self.asks, *level, |existing, new| {
+                existing.total_cmp(new)
+            });
+        }
+    }
+}
+
+/// Insert, replace or remove (if the quantity is zero) the provided [`Level`] in the collection
+/// of levels sorted by the provided price comparator.
+pub(crate) fn upsert_level<F>(levels: &mut Vec<Level>, level: Level, compare: F)
+where
+    F: Fn(&f64, &f64) -> std::cmp::Ordering,
+{
+    match levels.binary_search_by(|existing| compare(&existing.price, &level.price)) {
+        Ok(index) if level.quantity == 0.0 => {
+            levels.remove(index);
+        }
+        Ok(index) => levels[index] = level,
+        Err(_) if level.quantity == 0.0 => {}
+        Err(index) => levels.insert(index, level),
+    }
+}
+
+/// Exchange specific order book level that retains it's original representation (eg/ the `String`
+/// price & quantity required to validate a checksum), keyed by it's parsed `f64` price so sorted
+/// collections of levels can be searched without re-parsing every price.
+#[derive(Clone, PartialEq, Debug)]
+pub struct ParsedLevel<L> {
+    pub price: f64,
+    pub level: L,
+}
+
+/// Insert, replace or remove (if the quantity is zero) the provided [`ParsedLevel`] in the
+/// collection of levels sorted by the provided price comparator.
+pub(crate) fn upsert_parsed_level<L, F>(
+    levels: &mut Vec<ParsedLevel<L>>,
+    level: ParsedLevel<L>,
+    quantity: f64,
+    compare: F,
+) where
+    F: Fn(&f64, &f64) -> std::cmp::Ordering,
+{
+    match levels.binary_search_by(|existing| compare(&existing.price, &level.price)) {
+        Ok(index) if quantity == 0.0 => {
+            levels.remove(index);
+        }
+        Ok(index) => levels[index] = level,
+        Err(_) if quantity == 0.0 => {}
+        Err(index) => levels.insert(index, level),
+    }
+}
+
+/// Parse an exchange specific order book level `String` price or quantity as an `f64`.
+pub(crate) fn parse_level_value(exchange: ExchangeId, value: &str) -> Result<f64, SocketError> {
+    value.parse().map_err(|_| SocketError::Unsupported {
+        entity: exchange.as_str(),
+        item: format!("non-numeric order book level value: {value}"),
+    })
+}
+
+impl From<OrderBookL2Snapshot> for OrderBook {
+    fn from(snapshot: OrderBookL2Snapshot) -> Self {
+        Self {
+            last_update_time: snapshot.last_update_time,
+            last_update_id: snapshot.last_update_id,
+            bids: snapshot.bids,
+            asks: snapshot.asks,
+        }
+    }
+}
+
 impl<T> From<(T, T)> for Level
 where
     T: Into<f64>,
@@ -97,6 +517,115 @@ impl Level {
     }
 }
 
+impl From<&OptionContract> for Instrument {
+    fn from(contract: &OptionContract) -> Self {
+        let kind = match contract.kind {
+            OptionKind::Call => "c",
+            OptionKind::Put => "p",
+        };
+
+        Instrument::from((
+            format!(
+                "{}-{}-{}-{kind}",
+                contract.underlying,
+                contract.expiry.format(OptionContract::EXPIRY_FORMAT),
+                contract.strike
+            )
+            .to_lowercase(),
+            contract.quote.to_lowercase(),
+            InstrumentKind::Spot,
+        ))
+    }
+}
+
+impl TryFrom<&Instrument> for OptionContract {
+    type Error = SocketError;
+
+    fn try_from(instrument: &Instrument) -> Result<Self, Self::Error> {
+        let unsupported = || SocketError::Unsupported {
+            entity: "OptionContract",
+            item: instrument.to_string(),
+        };
+
+        if instrument.kind != InstrumentKind::Spot {
+            return Err(unsupported());
+        }
+
+        let mut parts = instrument.base.as_ref().split('-');
+        let (underlying, expiry, strike, kind) = match (
+            parts.next(),
+            parts.next(),
+            parts.next(),
+            parts.next(),
+            parts.next(),
+        ) {
+            (Some(underlying), Some(expiry), Some(strike), Some(kind), None)
+                if !underlying.is_empty() =>
+            {
+                (underlying, expiry, strike, kind)
+            }
+            _ => return Err(unsupported()),
+        };
+
+        let kind = match kind {
+            "c" => OptionKind::Call,
+            "p" => OptionKind::Put,
+            _ => return Err(unsupported()),
+        };
+
+        Ok(Self {
+            underlying: underlying.to_owned(),
+            quote: instrument.quote.to_string(),
+            expiry: NaiveDate::parse_from_str(expiry, Self::EXPIRY_FORMAT)
+                .map_err(|_| unsupported())?,
+            strike: strike.parse().map_err(|_| unsupported())?,
+            kind,
+        })
+    }
+}
+
+impl From<&FutureContract> for Instrument {
+    fn from(contract: &FutureContract) -> Self {
+        Instrument::from((
+            format!(
+                "{}-{}",
+                contract.underlying,
+                contract.expiry.format(FutureContract::EXPIRY_FORMAT)
+            )
+            .to_lowercase(),
+            contract.quote.to_lowercase(),
+            InstrumentKind::FuturePerpetual,
+        ))
+    }
+}
+
+impl TryFrom<&Instrument> for FutureContract {
+    type Error = SocketError;
+
+    fn try_from(instrument: &Instrument) -> Result<Self, Self::Error> {
+        let unsupported = || SocketError::Unsupported {
+            entity: "FutureContract",
+            item: instrument.to_string(),
+        };
+
+        if instrument.kind != InstrumentKind::FuturePerpetual {
+            return Err(unsupported());
+        }
+
+        let (underlying, expiry) = match instrument.base.as_ref().split_once('-') {
+            Some((underlying, expiry)) if !underlying.is_empty() => (underlying, expiry),
+            _ => return Err(unsupported()),
+        };
+
+        Ok(Self {
+            underlying: underlying.to_owned(),
+            quote: instrument.quote.to_string(),
+            expiry: NaiveDate::parse_from_str(expiry, Self::EXPIRY_FORMAT)
+                .map_err(|_| unsupported())?,
+        })
+    }
+}
+
 impl From<Event<MarketEvent>> for MarketEvent {
     fn from(event: Event<MarketEvent>) -> Self {
         event.payload
@@ -1,10 +1,14 @@
-use crate::ExchangeId;
+use crate::{
+    model::{DataKind, FutureContract},
+    ExchangeId,
+};
 use barter_integration::{
     error::SocketError,
     model::{Instrument, InstrumentKind, Market, SubscriptionId, Symbol},
     protocol::websocket::WsMessage,
     Validator,
 };
+use chrono::{DateTime, Datelike, NaiveDate, TimeZone, Utc};
 use serde::{Deserialize, Deserializer, Serialize};
 use std::{
     collections::HashMap,
@@ -40,19 +44,23 @@ impl Validator for &Subscription {
             }
         };
 
+        // Check if ExchangeId supports dated futures if the Instrument encodes a FutureContract
+        if !self.exchange.supports_dated_futures()
+            && FutureContract::try_from(&self.instrument).is_ok()
+        {
+            return Err(SocketError::Unsupported {
+                entity: self.exchange.as_str(),
+                item: self.instrument.to_string(),
+            });
+        }
+
         // Check if ExchangeId supports the Subscription SubKind
-        match self.kind {
-            SubKind::Trade if self.exchange.supports_trades() => {}
-            SubKind::Candle(_) if self.exchange.supports_candles() => {}
-            SubKind::OrderBook if self.exchange.supports_order_books() => {}
-            SubKind::Liquidation if self.exchange.supports_liquidations() => {}
-            other => {
-                return Err(SocketError::Unsupported {
-                    entity: self.exchange.as_str(),
-                    item: other.to_string(),
-                })
-            }
-        };
+        if !self.exchange.supports(&self.kind) {
+            return Err(SocketError::Unsupported {
+                entity: self.exchange.as_str(),
+                item: self.kind.to_string(),
+            });
+        }
 
         Ok(self)
     }
@@ -110,16 +118,133 @@ impl Subscription {
     }
 }
 
+/// Barter [`SubscriptionPattern`] used to subscribe to a market [`SubKind`] for every
+/// [`Instrument`] of an exchange whose base & quote [`Symbol`]s match the provided glob patterns,
+/// where `*` matches any sequence of characters (eg/ base "*" & quote "usdt").
+///
+/// [`SubscriptionPattern`]s are expanded into [`Subscription`]s using the [`Instrument`]s
+/// discovered from the exchange.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct SubscriptionPattern {
+    pub exchange: ExchangeId,
+    pub base: String,
+    pub quote: String,
+    pub instrument_kind: InstrumentKind,
+    #[serde(alias = "type")]
+    pub kind: SubKind,
+}
+
+impl<S> From<(ExchangeId, S, S, InstrumentKind, SubKind)> for SubscriptionPattern
+where
+    S: Into<String>,
+{
+    fn from(
+        (exchange, base, quote, instrument_kind, kind): (ExchangeId, S, S, InstrumentKind, SubKind),
+    ) -> Self {
+        Self {
+            exchange,
+            base: base.into(),
+            quote: quote.into(),
+            instrument_kind,
+            kind,
+        }
+    }
+}
+
+impl SubscriptionPattern {
+    /// Determines if the provided [`Instrument`] matches this [`SubscriptionPattern`].
+    pub fn matches(&self, instrument: &Instrument) -> bool {
+        instrument.kind == self.instrument_kind
+            && glob_match(&self.base, instrument.base.as_ref())
+            && glob_match(&self.quote, instrument.quote.as_ref())
+    }
+
+    /// Expand this [`SubscriptionPattern`] into a [`Subscription`] for every matching
+    /// [`Instrument`] in the provided collection.
+    pub fn expand<'a, Iter>(&self, instruments: Iter) -> Vec<Subscription>
+    where
+        Iter: IntoIterator<Item = &'a Instrument>,
+    {
+        instruments
+            .into_iter()
+            .filter(|instrument| self.matches(instrument))
+            .map(|instrument| Subscription::new(self.exchange, instrument.clone(), self.kind))
+            .collect()
+    }
+}
+
+/// Case-insensitive glob match where `*` in the pattern matches any sequence of characters.
+fn glob_match(pattern: &str, value: &str) -> bool {
+    let pattern = pattern.to_lowercase();
+    let value = value.to_lowercase();
+
+    let mut parts = pattern.split('*');
+    let first = parts.next().unwrap_or_default();
+    let mut rest = match value.strip_prefix(first) {
+        Some(rest) => rest,
+        None => return false,
+    };
+
+    let parts = parts.collect::<Vec<_>>();
+    match parts.split_last() {
+        // Pattern contains no wildcard
+        None => rest.is_empty(),
+        Some((last, middle)) => {
+            for part in middle {
+                match rest.find(part) {
+                    Some(index) => rest = &rest[index + part.len()..],
+                    None => return false,
+                }
+            }
+            rest.len() >= last.len() && rest.ends_with(last)
+        }
+    }
+}
+
 /// Possible Barter [`Subscription`] types.
 #[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "snake_case")]
 pub enum SubKind {
     Trade,
+    /// Trades filled at the same price by a single taker order, aggregated by the exchange &
+    /// delivered as an [`AggTrade`](crate::model::AggTrade).
+    AggTrade,
     Candle(Interval),
     OrderBook,
+    /// Best bid & best ask only (ie/ top of book), delivered as an
+    /// [`OrderBookL1`](crate::model::OrderBookL1).
+    OrderBookL1,
+    OrderBookL2(Depth),
+    /// Lightweight snapshots of the top [`Depth`] levels, pushed every [`UpdateSpeed`] &
+    /// delivered as an [`OrderBookL2Snapshot`](crate::model::OrderBookL2Snapshot) without
+    /// maintaining a local book.
+    OrderBookL2Snapshot(Depth, UpdateSpeed),
     OrderBookL2Delta,
+    /// L2 OrderBook maintained internally from snapshots & validated deltas, delivered to the
+    /// consumer as a full [`OrderBook`](crate::model::OrderBook) after every update.
+    OrderBookL2Managed,
     OrderBookL3Delta,
     Liquidation,
+    MarkPrice,
+    FundingRate,
+    OpenInterest,
+    Ticker,
+    /// Rolling statistics over the provided [`TickerWindow`], delivered as a
+    /// [`RollingTicker`](crate::model::RollingTicker).
+    RollingTicker(TickerWindow),
+    /// Rolling 24 hour statistics of every market, received via a single all market stream &
+    /// delivered per subscribed market as a [`Ticker`](crate::model::Ticker).
+    TickerAll,
+    /// Option mark price, mark implied volatility & greeks, delivered as an
+    /// [`OptionTicker`](crate::model::OptionTicker). The subscribed [`Instrument`] must be
+    /// built from an [`OptionContract`](crate::model::OptionContract).
+    OptionTicker,
+    InstrumentStatus,
+    /// Authenticated account updates relating to the subscribed [`Instrument`], delivered as an
+    /// [`OrderUpdate`](crate::model::OrderUpdate), [`BalanceUpdate`](crate::model::BalanceUpdate)
+    /// or [`PositionUpdate`](crate::model::PositionUpdate). Requires an API key configured via
+    /// [`StreamBuilder::user_data`](crate::builder::StreamBuilder::user_data).
+    UserData,
 }
 
 impl Display for SubKind {
@@ -129,16 +254,64 @@ impl Display for SubKind {
             "{}",
             match self {
                 SubKind::Trade => "trade".to_owned(),
+                SubKind::AggTrade => "agg_trade".to_owned(),
                 SubKind::Candle(interval) => format!("candle_{}", interval),
                 SubKind::OrderBook => "order_book".to_owned(),
+                SubKind::OrderBookL1 => "order_book_l1".to_owned(),
+                SubKind::OrderBookL2(depth) => format!("order_book_l2_{}", depth),
+                SubKind::OrderBookL2Snapshot(depth, speed) => {
+                    format!("order_book_l2_snapshot_{}_{}", depth, speed)
+                }
                 SubKind::OrderBookL2Delta => "order_book_l2_delta".to_owned(),
+                SubKind::OrderBookL2Managed => "order_book_l2_managed".to_owned(),
                 SubKind::OrderBookL3Delta => "order_book_l3_delta".to_owned(),
                 SubKind::Liquidation => "liquidation".to_owned(),
+                SubKind::MarkPrice => "mark_price".to_owned(),
+                SubKind::FundingRate => "funding_rate".to_owned(),
+                SubKind::OpenInterest => "open_interest".to_owned(),
+                SubKind::Ticker => "ticker".to_owned(),
+                SubKind::RollingTicker(window) => format!("rolling_ticker_{}", window),
+                SubKind::TickerAll => "ticker_all".to_owned(),
+                SubKind::OptionTicker => "option_ticker".to_owned(),
+                SubKind::InstrumentStatus => "instrument_status".to_owned(),
+                SubKind::UserData => "user_data".to_owned(),
             }
         )
     }
 }
 
+impl SubKind {
+    /// Determines if a [`Subscription`] of this [`SubKind`] produces the provided [`DataKind`].
+    pub fn produces(&self, kind: &DataKind) -> bool {
+        match self {
+            SubKind::Trade => matches!(kind, DataKind::Trade(_)),
+            SubKind::AggTrade => matches!(kind, DataKind::AggTrade(_)),
+            SubKind::Candle(_) => matches!(kind, DataKind::Candle(_)),
+            SubKind::OrderBook | SubKind::OrderBookL2Managed => {
+                matches!(kind, DataKind::OrderBook(_))
+            }
+            SubKind::OrderBookL1 => matches!(kind, DataKind::OrderBookL1(_)),
+            SubKind::OrderBookL2Snapshot(..) => matches!(kind, DataKind::OrderBookL2Snapshot(_)),
+            SubKind::OrderBookL2(_) | SubKind::OrderBookL2Delta => {
+                matches!(kind, DataKind::OrderBook(_) | DataKind::OrderBookL2Delta(_))
+            }
+            SubKind::OrderBookL3Delta => matches!(kind, DataKind::OrderBookL3Delta(_)),
+            SubKind::Liquidation => matches!(kind, DataKind::Liquidation(_)),
+            SubKind::MarkPrice => matches!(kind, DataKind::MarkPrice(_)),
+            SubKind::FundingRate => matches!(kind, DataKind::FundingRate(_)),
+            SubKind::OpenInterest => matches!(kind, DataKind::OpenInterest(_)),
+            SubKind::Ticker | SubKind::TickerAll => matches!(kind, DataKind::Ticker(_)),
+            SubKind::RollingTicker(_) => matches!(kind, DataKind::RollingTicker(_)),
+            SubKind::OptionTicker => matches!(kind, DataKind::OptionTicker(_)),
+            SubKind::InstrumentStatus => matches!(kind, DataKind::InstrumentStatus(_)),
+            SubKind::UserData => matches!(
+                kind,
+                DataKind::OrderUpdate(_) | DataKind::BalanceUpdate(_) | DataKind::PositionUpdate(_)
+            ),
+        }
+    }
+}
+
 /// Barter time interval used for specifying the interval of a [`SubKind::Candle`].
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
 pub enum Interval {
@@ -203,10 +376,94 @@ impl Display for Interval {
     }
 }
 
-/// Barter OrderBook depth used for specifying the depth of an [`SubKind::OrderBook`] snapshot.
+impl Interval {
+    /// Determine the start time of the [`Candle`](crate::model::Candle) of this [`Interval`]
+    /// containing the provided time. Fixed length intervals are aligned to the Unix epoch, with
+    /// weeks starting on a Monday, whereas month intervals start on the first day of a calendar
+    /// month (or quarter).
+    pub fn start_time(&self, time: DateTime<Utc>) -> DateTime<Utc> {
+        let seconds = match self.minutes() {
+            Some(minutes) => minutes * 60,
+            None => {
+                let months = if *self == Interval::Month3 { 3 } else { 1 };
+                let month0 = time.month0() - time.month0() % months;
+                return Utc
+                    .with_ymd_and_hms(time.year(), month0 + 1, 1, 0, 0, 0)
+                    .single()
+                    .unwrap_or(time);
+            }
+        };
+
+        // 1970-01-01 was a Thursday, so weeks are offset by 3 days in order to start on a Monday
+        let offset = match self {
+            Interval::Week1 => 3 * 24 * 60 * 60,
+            _ => 0,
+        };
+
+        let timestamp = time.timestamp() + offset;
+        Utc.timestamp_opt(timestamp - timestamp.rem_euclid(seconds) - offset, 0)
+            .single()
+            .unwrap_or(time)
+    }
+
+    /// Determine the end time of a [`Candle`](crate::model::Candle) of this [`Interval`] starting
+    /// at the provided time. Month intervals span calendar months.
+    pub fn end_time(&self, start_time: DateTime<Utc>) -> DateTime<Utc> {
+        match (self.minutes(), self) {
+            (Some(minutes), _) => start_time + chrono::Duration::minutes(minutes),
+            (None, Interval::Month3) => add_months(start_time, 3),
+            (None, _) => add_months(start_time, 1),
+        }
+    }
+
+    /// Number of minutes spanned by this [`Interval`], or `None` for calendar month intervals.
+    fn minutes(&self) -> Option<i64> {
+        match self {
+            Interval::Minute1 => Some(1),
+            Interval::Minute3 => Some(3),
+            Interval::Minute5 => Some(5),
+            Interval::Minute15 => Some(15),
+            Interval::Minute30 => Some(30),
+            Interval::Hour1 => Some(60),
+            Interval::Hour2 => Some(2 * 60),
+            Interval::Hour4 => Some(4 * 60),
+            Interval::Hour6 => Some(6 * 60),
+            Interval::Hour8 => Some(8 * 60),
+            Interval::Hour12 => Some(12 * 60),
+            Interval::Day1 => Some(24 * 60),
+            Interval::Day3 => Some(3 * 24 * 60),
+            Interval::Week1 => Some(7 * 24 * 60),
+            Interval::Month1 | Interval::Month3 => None,
+        }
+    }
+}
+
+/// Add the provided number of calendar months to a `DateTime<Utc>`, clamping the day of the
+/// month to the last day of the resulting month.
+fn add_months(time: DateTime<Utc>, months: i32) -> DateTime<Utc> {
+    let month0 = time.year() * 12 + time.month0() as i32 + months;
+    let (year, month) = (month0.div_euclid(12), month0.rem_euclid(12) as u32 + 1);
+
+    (0..4)
+        .find_map(|clamp| {
+            time.day()
+                .checked_sub(clamp)
+                .and_then(|day| NaiveDate::from_ymd_opt(year, month, day))
+        })
+        .map(|date| Utc.from_utc_datetime(&date.and_time(time.time())))
+        .unwrap_or(time)
+}
+
+/// Barter OrderBook depth used for specifying the depth of an [`SubKind::OrderBookL2`] stream.
 #[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
 pub struct Depth(u16);
 
+impl From<u16> for Depth {
+    fn from(depth: u16) -> Self {
+        Self(depth)
+    }
+}
+
 impl Display for Depth {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.0)
@@ -221,6 +478,71 @@ impl Deref for Depth {
     }
 }
 
+/// Barter update speed used for specifying how often an [`SubKind::OrderBookL2Snapshot`] stream
+/// is pushed.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub enum UpdateSpeed {
+    #[serde(alias = "100ms")]
+    Ms100,
+    #[serde(alias = "250ms")]
+    Ms250,
+    #[serde(alias = "500ms")]
+    Ms500,
+    #[serde(alias = "1000ms")]
+    Ms1000,
+}
+
+impl Display for UpdateSpeed {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                UpdateSpeed::Ms100 => "100ms",
+                UpdateSpeed::Ms250 => "250ms",
+                UpdateSpeed::Ms500 => "500ms",
+                UpdateSpeed::Ms1000 => "1000ms",
+            }
+        )
+    }
+}
+
+/// Barter statistics window used for specifying the window of a [`SubKind::RollingTicker`].
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub enum TickerWindow {
+    #[serde(alias = "1h")]
+    Hour1,
+    #[serde(alias = "4h")]
+    Hour4,
+    #[serde(alias = "1d")]
+    Day1,
+}
+
+impl TickerWindow {
+    /// Duration of the statistics window.
+    pub fn duration(&self) -> chrono::Duration {
+        match self {
+            TickerWindow::Hour1 => chrono::Duration::hours(1),
+            TickerWindow::Hour4 => chrono::Duration::hours(4),
+            TickerWindow::Day1 => chrono::Duration::days(1),
+        }
+    }
+}
+
+impl Display for TickerWindow {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                TickerWindow::Hour1 => "1h",
+                TickerWindow::Hour4 => "4h",
+                TickerWindow::Day1 => "1d",
+            }
+        )
+    }
+}
+
 /// Metadata generated from a collection of Barter [`Subscription`]s. This includes the exchange
 /// specific subscription payloads that are sent to the exchange.
 #[derive(Clone, Eq, PartialEq, Debug)]
@@ -342,12 +664,21 @@ mod tests {
                 }),
             },
             TestCase {
-                // TC6: Invalid Subscription w/ unknown exchange
+                // TC6: Valid BybitFuturesUsd btc_usdt FuturePerpetual OrderBookL2(50) Subscription
+                input: r##"{"exchange": "bybit_futures_usd", "base": "btc", "quote": "usdt", "instrument_type": "future_perpetual", "type": {"order_book_l2": 50}}"##,
+                expected: Ok(Subscription {
+                    exchange: ExchangeId::BybitFuturesUsd,
+                    instrument: Instrument::from(("btc", "usdt", InstrumentKind::FuturePerpetual)),
+                    kind: SubKind::OrderBookL2(Depth::from(50)),
+                }),
+            },
+            TestCase {
+                // TC7: Invalid Subscription w/ unknown exchange
                 input: r##"{"exchange": "unknown", "base": "btc", "quote": "usd", "instrument_type": "future_perpetual", "type": "order_book_l2_delta"}"##,
                 expected: Err(serde_json::Error::custom("")),
             },
             TestCase {
-                // TC7: Invalid Subscription w/ unknown SubKind
+                // TC8: Invalid Subscription w/ unknown SubKind
                 input: r##"{"exchange": "binance_futures_usd", "base": "btc", "quote": "usd", "instrument_type": "future_perpetual", "type": "unknown"}"##,
                 expected: Err(serde_json::Error::custom("")),
             },
@@ -360,6 +691,15 @@ mod tests {
                     kind: SubKind::Liquidation,
                 }),
             },
+            TestCase {
+                // TC10: Valid Coinbase btc_usd Spot InstrumentStatus Subscription
+                input: r##"{"exchange": "coinbase", "base": "btc", "quote": "usd", "instrument_type": "spot", "type": "instrument_status"}"##,
+                expected: Ok(Subscription {
+                    exchange: ExchangeId::Coinbase,
+                    instrument: Instrument::from(("btc", "usd", InstrumentKind::Spot)),
+                    kind: SubKind::InstrumentStatus,
+                }),
+            },
         ];
 
         for (index, test) in cases.into_iter().enumerate() {
@@ -490,15 +830,16 @@ mod tests {
                 }),
             },
             TestCase {
-                // TC6: Invalid Subscription w/ BinanceFuturesUsd FuturePerpetual Candles
+                // TC6: Valid Subscription w/ BinanceFuturesUsd FuturePerpetual Candles
                 input: Subscription {
                     exchange: ExchangeId::BinanceFuturesUsd,
                     instrument: Instrument::from(("btc", "usd", InstrumentKind::FuturePerpetual)),
                     kind: SubKind::Candle(Interval::Minute5),
                 },
-                expected: Err(SocketError::Unsupported {
-                    entity: "",
-                    item: "".to_string(),
+                expected: Ok(Subscription {
+                    exchange: ExchangeId::BinanceFuturesUsd,
+                    instrument: Instrument::from(("btc", "usd", InstrumentKind::FuturePerpetual)),
+                    kind: SubKind::Candle(Interval::Minute5),
                 }),
             },
             TestCase {
@@ -527,6 +868,43 @@ mod tests {
                     kind: SubKind::Liquidation,
                 }),
             },
+            TestCase {
+                // Valid Subscription w/ BinanceFuturesCoin dated FutureContract Trades
+                input: Subscription {
+                    exchange: ExchangeId::BinanceFuturesCoin,
+                    instrument: Instrument::from((
+                        "btc-240628",
+                        "usd",
+                        InstrumentKind::FuturePerpetual,
+                    )),
+                    kind: SubKind::Trade,
+                },
+                expected: Ok(Subscription {
+                    exchange: ExchangeId::BinanceFuturesCoin,
+                    instrument: Instrument::from((
+                        "btc-240628",
+                        "usd",
+                        InstrumentKind::FuturePerpetual,
+                    )),
+                    kind: SubKind::Trade,
+                }),
+            },
+            TestCase {
+                // Invalid Subscription w/ BinanceFuturesUsd dated FutureContract Trades
+                input: Subscription {
+                    exchange: ExchangeId::BinanceFuturesUsd,
+                    instrument: Instrument::from((
+                        "btc-240628",
+                        "usdt",
+                        InstrumentKind::FuturePerpetual,
+                    )),
+                    kind: SubKind::Trade,
+                },
+                expected: Err(SocketError::Unsupported {
+                    entity: "",
+                    item: "".to_string(),
+                }),
+            },
         ];
 
         for (index, test) in cases.into_iter().enumerate() {
@@ -597,4 +975,161 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_interval_end_time() {
+        struct TestCase {
+            input: (Interval, &'static str),
+            expected: &'static str,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: fixed length Interval
+                input: (Interval::Hour4, "2023-01-01T22:00:00Z"),
+                expected: "2023-01-02T02:00:00Z",
+            },
+            TestCase {
+                // TC1: calendar month Interval
+                input: (Interval::Month1, "2023-02-01T00:00:00Z"),
+                expected: "2023-03-01T00:00:00Z",
+            },
+            TestCase {
+                // TC2: calendar month Interval across a year clamped to the end of the month
+                input: (Interval::Month3, "2023-11-30T00:00:00Z"),
+                expected: "2024-02-29T00:00:00Z",
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let (interval, start_time) = test.input;
+            let actual = interval.end_time(start_time.parse().unwrap());
+            assert_eq!(
+                actual,
+                test.expected.parse::<DateTime<Utc>>().unwrap(),
+                "TC{} failed",
+                index
+            );
+        }
+    }
+
+    #[test]
+    fn test_interval_start_time() {
+        struct TestCase {
+            input: (Interval, &'static str),
+            expected: &'static str,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: fixed length Interval is aligned to the epoch
+                input: (Interval::Minute15, "2023-01-01T22:14:59Z"),
+                expected: "2023-01-01T22:00:00Z",
+            },
+            TestCase {
+                // TC1: time on the Interval boundary starts a new Interval
+                input: (Interval::Hour4, "2023-01-02T00:00:00Z"),
+                expected: "2023-01-02T00:00:00Z",
+            },
+            TestCase {
+                // TC2: week Interval starts on a Monday
+                input: (Interval::Week1, "2023-01-05T12:00:00Z"),
+                expected: "2023-01-02T00:00:00Z",
+            },
+            TestCase {
+                // TC3: calendar month Interval starts on the first day of the month
+                input: (Interval::Month1, "2023-02-17T08:30:00Z"),
+                expected: "2023-02-01T00:00:00Z",
+            },
+            TestCase {
+                // TC4: calendar quarter Interval starts on the first day of the quarter
+                input: (Interval::Month3, "2023-06-30T23:59:59Z"),
+                expected: "2023-04-01T00:00:00Z",
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let (interval, time) = test.input;
+            let actual = interval.start_time(time.parse().unwrap());
+            assert_eq!(
+                actual,
+                test.expected.parse::<DateTime<Utc>>().unwrap(),
+                "TC{} failed",
+                index
+            );
+        }
+    }
+
+    #[test]
+    fn test_subscription_pattern_matches() {
+        struct TestCase {
+            input: SubscriptionPattern,
+            expected: bool,
+        }
+
+        let instrument = Instrument::from(("btc", "usdt", InstrumentKind::FuturePerpetual));
+
+        let cases = vec![
+            TestCase {
+                // TC0: wildcard base & exact quote matches
+                input: SubscriptionPattern::from((
+                    ExchangeId::BinanceFuturesUsd,
+                    "*",
+                    "USDT",
+                    InstrumentKind::FuturePerpetual,
+                    SubKind::Trade,
+                )),
+                expected: true,
+            },
+            TestCase {
+                // TC1: prefix & suffix wildcards match
+                input: SubscriptionPattern::from((
+                    ExchangeId::BinanceFuturesUsd,
+                    "b*c",
+                    "*dt",
+                    InstrumentKind::FuturePerpetual,
+                    SubKind::Trade,
+                )),
+                expected: true,
+            },
+            TestCase {
+                // TC2: different quote does not match
+                input: SubscriptionPattern::from((
+                    ExchangeId::BinanceFuturesUsd,
+                    "*",
+                    "busd",
+                    InstrumentKind::FuturePerpetual,
+                    SubKind::Trade,
+                )),
+                expected: false,
+            },
+            TestCase {
+                // TC3: different InstrumentKind does not match
+                input: SubscriptionPattern::from((
+                    ExchangeId::BinanceFuturesUsd,
+                    "*",
+                    "*",
+                    InstrumentKind::Spot,
+                    SubKind::Trade,
+                )),
+                expected: false,
+            },
+            TestCase {
+                // TC4: wildcard cannot overlap the literal prefix & suffix
+                input: SubscriptionPattern::from((
+                    ExchangeId::BinanceFuturesUsd,
+                    "bt*tc",
+                    "usdt",
+                    InstrumentKind::FuturePerpetual,
+                    SubKind::Trade,
+                )),
+                expected: false,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = test.input.matches(&instrument);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
 }
@@ -0,0 +1,587 @@
+use crate::model::{
+    upsert_level, DataKind, Level, MarketEvent, OrderBook, OrderBookL2Delta, OrderBookL2Snapshot,
+};
+use barter_integration::{error::SocketError, model::Side};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt::Debug;
+
+/// Aggregated L2 order book keyed by price level, maintained from a snapshot ([`OrderBook`] or
+/// [`OrderBookL2Snapshot`]) followed by [`OrderBookL2Delta`]s, for the majority of exchanges that
+/// only provide L2 market data.
+///
+/// Each side of the book holds at most one [`Level`] per price, with the aggregate quantity
+/// resting at that price. If constructed with the market tick size (see
+/// [`OrderbookL2::with_tick_size`]), price levels are matched via their [`PriceKey`] rather than
+/// floating point equality.
+///
+/// Deltas are validated against the `last_update_id` of the book: stale deltas that have already
+/// been reflected are skipped, and deltas spanning a range of update ids (ie/ with a
+/// `first_update_id`) that do not continue from the book are rejected, discarding the book so a
+/// new snapshot can be provided. Deltas without a `first_update_id` are only checked for
+/// staleness, since their update ids are not guaranteed to be contiguous (eg/ timestamps).
+#[derive(Clone, PartialEq, Debug, Default, Deserialize, Serialize)]
+pub struct OrderbookL2 {
+    pub last_update_time: Option<DateTime<Utc>>,
+    pub last_update_id: u64,
+    pub tick_size: Option<f64>,
+    /// Bid levels ordered from the highest price.
+    bids: Vec<Level>,
+    /// Ask levels ordered from the lowest price.
+    asks: Vec<Level>,
+}
+
+impl OrderbookL2 {
+    /// Construct a new empty [`OrderbookL2`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Construct a new empty [`OrderbookL2`] matching price levels via their [`PriceKey`] in the
+    /// provided tick size (eg/ sourced from the exchange instrument metadata).
+    ///
+    /// Returns a [`SocketError`] if the tick size is not finite & positive, since every price
+    /// would otherwise map to the same (or an undefined) [`PriceKey`].
+    pub fn with_tick_size(tick_size: f64) -> Result<Self, SocketError> {
+        if !tick_size.is_finite() || tick_size <= 0.0 {
+            return Err(SocketError::Unsupported {
+                entity: "OrderbookL2",
+                item: format!("tick_size: {tick_size}"),
+            });
+        }
+
+        Ok(Self {
+            tick_size: Some(tick_size),
+            ..Self::default()
+        })
+    }
+
+    /// Update this [`OrderbookL2`] with the provided [`MarketEvent`] if it contains an
+    /// [`OrderBook`], [`OrderBookL2Snapshot`] or [`OrderBookL2Delta`].
+    ///
+    /// Returns `Ok(true)` if the book was updated, and `Ok(false)` if the event does not contain
+    /// L2 data or is a stale [`OrderBookL2Delta`]. A discontinuous [`OrderBookL2Delta`] discards
+    /// the book & returns a [`SocketError`] (see [`Self::apply`]).
+    pub fn update(&mut self, event: &MarketEvent) -> Result<bool, SocketError> {
+        match &event.kind {
+            DataKind::OrderBook(book) => self.load(
+                book.last_update_time,
+                book.last_update_id,
+                &book.bids,
+                &book.asks,
+            ),
+            DataKind::OrderBookL2Snapshot(snapshot) => self.snapshot(snapshot),
+            DataKind::OrderBookL2Delta(delta) => return self.apply(delta),
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+
+    /// Replace every level of this [`OrderbookL2`] with those of the provided
+    /// [`OrderBookL2Snapshot`].
+    pub fn snapshot(&mut self, snapshot: &OrderBookL2Snapshot) {
+        self.load(
+            snapshot.last_update_time,
+            snapshot.last_update_id,
+            &snapshot.bids,
+            &snapshot.asks,
+        )
+    }
+
+    /// Apply the changed levels of the provided [`OrderBookL2Delta`], replacing the aggregate
+    /// quantity of existing price levels & removing zero quantity price levels.
+    ///
+    /// Returns `Ok(false)` if the delta is stale (ie/ its `last_update_id` has already been
+    /// reflected in the book). If the delta has a `first_update_id` that does not continue from
+    /// the book `last_update_id`, every level is discarded & a [`SocketError`] is returned so a
+    /// new snapshot can be provided.
+    pub fn apply(&mut self, delta: &OrderBookL2Delta) -> Result<bool, SocketError> {
+        // Skip any delta that has already been reflected in the book
+        if delta.last_update_id <= self.last_update_id {
+            return Ok(false);
+        }
+
+        // Validate the delta continues from the book if it spans a range of update ids
+        if let Some(first_update_id) = delta.first_update_id {
+            if first_update_id > self.last_update_id + 1 {
+                let error = SocketError::Exchange(format!(
+                    "OrderbookL2 delta {}..={} is discontinuous with book last_update_id: {}",
+                    first_update_id, delta.last_update_id, self.last_update_id
+                ));
+                self.clear();
+                return Err(error);
+            }
+        }
+
+        self.upsert(
+            delta.last_update_time,
+            delta.last_update_id,
+            &delta.bids,
+            &delta.asks,
+        );
+
+        Ok(true)
+    }
+
+    /// Discard every level of this [`OrderbookL2`], awaiting a new snapshot.
+    fn clear(&mut self) {
+        self.bids.clear();
+        self.asks.clear();
+        self.last_update_time = None;
+        self.last_update_id = 0;
+    }
+
+    /// Replace every level of this [`OrderbookL2`], sorting the provided levels & discarding
+    /// zero quantity levels.
+    fn load(
+        &mut self,
+        last_update_time: DateTime<Utc>,
+        last_update_id: u64,
+        bids: &[Level],
+        asks: &[Level],
+    ) {
+        self.bids.clear();
+        self.asks.clear();
+        self.upsert(last_update_time, last_update_id, bids, asks)
+    }
+
+    /// Insert, replace or remove each of the provided levels.
+    fn upsert(
+        &mut self,
+        last_update_time: DateTime<Utc>,
+        last_update_id: u64,
+        bids: &[Level],
+        asks: &[Level],
+    ) {
+        match self.tick_size {
+            Some(tick_size) => {
+                let key = |price: &f64| PriceKey::new(*price, tick_size);
+                for level in bids {
+                    upsert_level(&mut self.bids, *level, |a, b| key(b).cmp(&key(a)));
+                }
+                for level in asks {
+                    upsert_level(&mut self.asks, *level, |a, b| key(a).cmp(&key(b)));
+                }
+            }
+            None => {
+                for level in bids {
+                    upsert_level(&mut self.bids, *level, |a, b| b.total_cmp(a));
+                }
+                for level in asks {
+                    upsert_level(&mut self.asks, *level, f64::total_cmp);
+                }
+            }
+        }
+
+        self.last_update_time = Some(last_update_time);
+        self.last_update_id = last_update_id;
+    }
+
+    /// Return the best (ie/ highest priced) bid [`Level`], if any.
+    pub fn best_bid(&self) -> Option<Level> {
+        self.bids.first().copied()
+    }
+
+    /// Return the best (ie/ lowest priced) ask [`Level`], if any.
+    pub fn best_ask(&self) -> Option<Level> {
+        self.asks.first().copied()
+    }
+
+    /// Return the [`Level`]s of the provided [`Side`], ordered from the best price.
+    pub fn levels(&self, side: Side) -> &[Level] {
+        match side {
+            Side::Buy => &self.bids,
+            Side::Sell => &self.asks,
+        }
+    }
+
+    /// Return the aggregate quantity resting within the best `levels` price levels of the
+    /// provided [`Side`].
+    pub fn depth(&self, side: Side, levels: usize) -> f64 {
+        self.levels(side)
+            .iter()
+            .take(levels)
+            .map(|level| level.quantity)
+            .sum()
+    }
+
+    /// Return the aggregate quantity resting on the provided [`Side`] at prices at least as good
+    /// as the provided price (ie/ bids priced >= price, asks priced <= price).
+    pub fn depth_to_price(&self, side: Side, price: f64) -> f64 {
+        self.levels(side)
+            .iter()
+            .take_while(|level| match side {
+                Side::Buy => level.price >= price,
+                Side::Sell => level.price <= price,
+            })
+            .map(|level| level.quantity)
+            .sum()
+    }
+
+    /// Build an [`OrderBook`] snapshot of the best `depth` levels of each side, or every level if
+    /// `None`.
+    pub fn to_order_book(&self, depth: Option<usize>) -> OrderBook {
+        let depth = depth.unwrap_or(usize::MAX);
+        OrderBook {
+            last_update_time: self.last_update_time.unwrap_or_default(),
+            last_update_id: self.last_update_id,
+            bids: self.bids.iter().take(depth).copied().collect(),
+            asks: self.asks.iter().take(depth).copied().collect(),
+        }
+    }
+}
+
+/// Price scaled to an integer number of ticks, used to match [`Level`]s exactly rather than
+/// relying on the floating point equality of prices quoted in an odd tick size.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct PriceKey(pub i64);
+
+impl PriceKey {
+    /// Construct the [`PriceKey`] of the provided price, rounded to the nearest multiple of the
+    /// provided tick size. The tick size must be finite & positive (see
+    /// [`OrderbookL2::with_tick_size`]).
+    pub fn new(price: f64, tick_size: f64) -> Self {
+        debug_assert!(tick_size.is_finite() && tick_size > 0.0);
+        Self((price / tick_size).round() as i64)
+    }
+
+    /// Convert this [`PriceKey`] into an `f64` price using the provided tick size.
+    pub fn price(self, tick_size: f64) -> f64 {
+        self.0 as f64 * tick_size
+    }
+}
+
+/// Reaction of an [`ExchangeTransformer`](crate::ExchangeTransformer) maintaining a checksummed
+/// order book (eg/ [`Okx`](crate::exchange::okx::Okx)) when the checksum of the maintained book
+/// does not match the checksum sent by the exchange.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default, Deserialize, Serialize)]
+pub enum ChecksumPolicy {
+    /// Discard the maintained book, emit an error & re-subscribe to receive a fresh snapshot.
+    #[default]
+    Resync,
+    /// Emit an error, but continue maintaining the book without re-synchronising it.
+    Error,
+    /// Panic in debug builds so the mismatch cannot go unnoticed during development, else
+    /// [`ChecksumPolicy::Resync`].
+    Panic,
+}
+
+/// Configures the [`ChecksumPolicy`] of an initialised [`MarketStream`](crate::MarketStream) whose
+/// [`ExchangeTransformer`](crate::ExchangeTransformer) maintains checksummed order books.
+pub trait ChecksumValidation {
+    fn set_checksum_policy(&mut self, policy: ChecksumPolicy);
+}
+
+#[cfg(feature = "profiling")]
+impl<Exchange> ChecksumValidation for crate::profiling::Profiled<Exchange>
+where
+    Exchange: ChecksumValidation,
+{
+    fn set_checksum_policy(&mut self, policy: ChecksumPolicy) {
+        self.exchange.set_checksum_policy(policy)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn levels(levels: &[(f64, f64)]) -> Vec<Level> {
+        levels.iter().copied().map(Level::from).collect()
+    }
+
+    #[test]
+    fn test_orderbook_l2_snapshot_and_delta() {
+        let time = "2023-01-01T00:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        let mut book = OrderbookL2::new();
+
+        // Unsorted snapshot levels are sorted & zero quantity levels discarded
+        book.snapshot(&OrderBookL2Snapshot {
+            last_update_time: time,
+            last_update_id: 10,
+            bids: levels(&[(99.0, 1.0), (100.0, 2.0), (98.0, 0.0)]),
+            asks: levels(&[(102.0, 1.0), (101.0, 3.0)]),
+        });
+
+        assert_eq!(book.best_bid(), Some(Level::new(100.0, 2.0)));
+        assert_eq!(book.best_ask(), Some(Level::new(101.0, 3.0)));
+        assert_eq!(book.levels(Side::Buy), levels(&[(100.0, 2.0), (99.0, 1.0)]));
+
+        struct TestCase {
+            input: OrderBookL2Delta,
+            expected_bids: Vec<Level>,
+            expected_asks: Vec<Level>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: insert new bid level & replace ask level quantity
+                input: OrderBookL2Delta {
+                    last_update_time: time,
+                    first_update_id: None,
+                    last_update_id: 11,
+                    bids: levels(&[(99.5, 4.0)]),
+                    asks: levels(&[(101.0, 1.5)]),
+                },
+                expected_bids: levels(&[(100.0, 2.0), (99.5, 4.0), (99.0, 1.0)]),
+                expected_asks: levels(&[(101.0, 1.5), (102.0, 1.0)]),
+            },
+            TestCase {
+                // TC1: remove best bid & best ask levels, ignore removal of missing level
+                input: OrderBookL2Delta {
+                    last_update_time: time,
+                    first_update_id: None,
+                    last_update_id: 12,
+                    bids: levels(&[(100.0, 0.0), (97.0, 0.0)]),
+                    asks: levels(&[(101.0, 0.0)]),
+                },
+                expected_bids: levels(&[(99.5, 4.0), (99.0, 1.0)]),
+                expected_asks: levels(&[(102.0, 1.0)]),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            assert!(book.apply(&test.input).unwrap(), "TC{} failed", index);
+            assert_eq!(
+                book.levels(Side::Buy),
+                test.expected_bids,
+                "TC{} failed",
+                index
+            );
+            assert_eq!(
+                book.levels(Side::Sell),
+                test.expected_asks,
+                "TC{} failed",
+                index
+            );
+            assert_eq!(
+                book.last_update_id, test.input.last_update_id,
+                "TC{} failed",
+                index
+            );
+        }
+    }
+
+    #[test]
+    fn test_orderbook_l2_apply_sequencing() {
+        let time = "2023-01-01T00:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        let delta = |first_update_id, last_update_id| OrderBookL2Delta {
+            last_update_time: time,
+            first_update_id,
+            last_update_id,
+            bids: levels(&[(99.0, 2.0)]),
+            asks: vec![],
+        };
+
+        struct TestCase {
+            input: OrderBookL2Delta,
+            expected: Result<bool, SocketError>,
+            expected_last_update_id: u64,
+            expected_bids: Vec<Level>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: stale delta already reflected in the snapshot is skipped
+                input: delta(Some(5), 10),
+                expected: Ok(false),
+                expected_last_update_id: 10,
+                expected_bids: levels(&[(100.0, 1.0)]),
+            },
+            TestCase {
+                // TC1: stale delta without a first_update_id is skipped
+                input: delta(None, 9),
+                expected: Ok(false),
+                expected_last_update_id: 10,
+                expected_bids: levels(&[(100.0, 1.0)]),
+            },
+            TestCase {
+                // TC2: delta overlapping the snapshot last_update_id is applied
+                input: delta(Some(8), 12),
+                expected: Ok(true),
+                expected_last_update_id: 12,
+                expected_bids: levels(&[(100.0, 1.0), (99.0, 2.0)]),
+            },
+            TestCase {
+                // TC3: delta continuing from the book last_update_id is applied
+                input: delta(Some(13), 15),
+                expected: Ok(true),
+                expected_last_update_id: 15,
+                expected_bids: levels(&[(100.0, 1.0), (99.0, 2.0)]),
+            },
+            TestCase {
+                // TC4: discontinuous delta discards the book & returns an error
+                input: delta(Some(17), 20),
+                expected: Err(SocketError::Exchange("".to_string())),
+                expected_last_update_id: 0,
+                expected_bids: vec![],
+            },
+            TestCase {
+                // TC5: subsequent delta is rejected until a new snapshot is provided
+                input: delta(Some(21), 22),
+                expected: Err(SocketError::Exchange("".to_string())),
+                expected_last_update_id: 0,
+                expected_bids: vec![],
+            },
+        ];
+
+        let mut book = OrderbookL2::new();
+        book.snapshot(&OrderBookL2Snapshot {
+            last_update_time: time,
+            last_update_id: 10,
+            bids: levels(&[(100.0, 1.0)]),
+            asks: vec![],
+        });
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = book.apply(&test.input);
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => {
+                    assert_eq!(actual, expected, "TC{} failed", index)
+                }
+                (Err(_), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+            assert_eq!(
+                book.last_update_id, test.expected_last_update_id,
+                "TC{} failed",
+                index
+            );
+            assert_eq!(
+                book.levels(Side::Buy),
+                test.expected_bids,
+                "TC{} failed",
+                index
+            );
+        }
+    }
+
+    #[test]
+    fn test_orderbook_l2_tick_size() {
+        // Price of 0.30000000000000004 sent by an exchange w/ a lossy price representation
+        let price = 0.1 + 0.2;
+
+        struct TestCase {
+            book: OrderbookL2,
+            expected_bids: Vec<Level>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: without a tick size the removed price does not match the existing level
+                book: OrderbookL2::new(),
+                expected_bids: levels(&[(price, 1.0)]),
+            },
+            TestCase {
+                // TC1: with a tick size the removed price matches the existing level PriceKey
+                book: OrderbookL2::with_tick_size(0.1).unwrap(),
+                expected_bids: vec![],
+            },
+        ];
+
+        for (index, mut test) in cases.into_iter().enumerate() {
+            test.book.snapshot(&OrderBookL2Snapshot {
+                last_update_time: Utc::now(),
+                last_update_id: 1,
+                bids: levels(&[(price, 1.0)]),
+                asks: vec![],
+            });
+            test.book
+                .apply(&OrderBookL2Delta {
+                    last_update_time: Utc::now(),
+                    first_update_id: None,
+                    last_update_id: 2,
+                    bids: levels(&[(0.3, 0.0)]),
+                    asks: vec![],
+                })
+                .unwrap();
+            assert_eq!(
+                test.book.levels(Side::Buy),
+                test.expected_bids,
+                "TC{} failed",
+                index
+            );
+        }
+
+        assert_eq!(PriceKey::new(price, 0.1), PriceKey(3));
+        assert_eq!(PriceKey(25).price(0.5), 12.5);
+    }
+
+    #[test]
+    fn test_orderbook_l2_with_tick_size() {
+        struct TestCase {
+            input: f64,
+            expected: Result<Option<f64>, SocketError>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: valid positive tick size
+                input: 0.5,
+                expected: Ok(Some(0.5)),
+            },
+            TestCase {
+                // TC1: invalid zero tick size
+                input: 0.0,
+                expected: Err(SocketError::Subscribe("".to_string())),
+            },
+            TestCase {
+                // TC2: invalid negative tick size
+                input: -0.1,
+                expected: Err(SocketError::Subscribe("".to_string())),
+            },
+            TestCase {
+                // TC3: invalid NaN tick size
+                input: f64::NAN,
+                expected: Err(SocketError::Subscribe("".to_string())),
+            },
+            TestCase {
+                // TC4: invalid infinite tick size
+                input: f64::INFINITY,
+                expected: Err(SocketError::Subscribe("".to_string())),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = OrderbookL2::with_tick_size(test.input);
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => {
+                    assert_eq!(actual.tick_size, expected, "TC{} failed", index)
+                }
+                (Err(_), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_orderbook_l2_depth() {
+        let mut book = OrderbookL2::new();
+        book.snapshot(&OrderBookL2Snapshot {
+            last_update_time: Utc::now(),
+            last_update_id: 1,
+            bids: levels(&[(100.0, 1.0), (99.0, 2.0), (98.0, 3.0)]),
+            asks: levels(&[(101.0, 1.5), (102.0, 2.5)]),
+        });
+
+        assert_eq!(book.depth(Side::Buy, 2), 3.0);
+        assert_eq!(book.depth(Side::Sell, 10), 4.0);
+        assert_eq!(book.depth_to_price(Side::Buy, 99.0), 3.0);
+        assert_eq!(book.depth_to_price(Side::Sell, 101.0), 1.5);
+        assert_eq!(book.depth_to_price(Side::Sell, 100.0), 0.0);
+
+        let snapshot = book.to_order_book(Some(1));
+        assert_eq!(snapshot.bids, levels(&[(100.0, 1.0)]));
+        assert_eq!(snapshot.asks, levels(&[(101.0, 1.5)]));
+    }
+}
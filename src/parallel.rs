@@ -0,0 +1,284 @@
+use crate::{
+    distribute_responses_to_the_exchange,
+    model::MarketEvent,
+    orderbook::{ChecksumPolicy, ChecksumValidation},
+    receipt::stamp,
+    ExchangeTransformer, MarketStream, Subscriber, Subscription,
+};
+use async_trait::async_trait;
+use barter_integration::{
+    error::SocketError,
+    protocol::{
+        websocket::{WebSocketParser, WsError, WsMessage},
+        StreamParser,
+    },
+    Event,
+};
+use futures::{Stream, StreamExt};
+use std::{
+    collections::{BTreeMap, VecDeque},
+    fmt::{Debug, Formatter},
+    pin::Pin,
+    task::{Context, Poll},
+    time::Instant,
+};
+use tokio::sync::mpsc;
+
+/// Default number of parsing workers used by [`ParallelWsStream::init`].
+pub const DEFAULT_PARSE_WORKERS: usize = 4;
+
+/// Outcome of parsing a frame, `None` if the frame does not yield a message (eg/ Ping, Pong).
+type Parsed<Input> = Option<Result<Input, SocketError>>;
+
+/// Frame parsed by a worker, tagged with the order & monotonic instant it was read from the socket.
+type ParsedFrame<Input> = (u64, Instant, Parsed<Input>);
+
+/// [`MarketStream`] for very high-rate connections that fans raw WebSocket frames out to a pool
+/// of parsing workers, utilising multiple cores for deserialisation. Parsed frames are
+/// re-assembled in the order they were read from the socket before being transformed, so the
+/// yielded [`Event`] sequence is identical to that of an [`ExchangeWsStream`](crate::ExchangeWsStream).
+pub struct ParallelWsStream<Exchange>
+where
+    Exchange: ExchangeTransformer,
+{
+    transformer: Exchange,
+    parsed_rx: mpsc::UnboundedReceiver<ParsedFrame<Exchange::Input>>,
+    pending: BTreeMap<u64, (Instant, Parsed<Exchange::Input>)>,
+    next_frame: u64,
+    next_sequence: u64,
+    buffer: VecDeque<Result<Event<MarketEvent>, SocketError>>,
+}
+
+impl<Exchange> Debug for ParallelWsStream<Exchange>
+where
+    Exchange: ExchangeTransformer + Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ParallelWsStream")
+            .field("transformer", &self.transformer)
+            .field("pending", &self.pending.len())
+            .field("next_frame", &self.next_frame)
+            .field("next_sequence", &self.next_sequence)
+            .finish()
+    }
+}
+
+impl<Exchange> ChecksumValidation for ParallelWsStream<Exchange>
+where
+    Exchange: ExchangeTransformer + ChecksumValidation,
+{
+    fn set_checksum_policy(&mut self, policy: ChecksumPolicy) {
+        self.transformer.set_checksum_policy(policy)
+    }
+}
+
+#[async_trait]
+impl<Exchange> MarketStream for ParallelWsStream<Exchange>
+where
+    Exchange: Subscriber + ExchangeTransformer + Send + Unpin,
+    Exchange::Input: Send + 'static,
+{
+    async fn init(subscriptions: &[Subscription]) -> Result<Self, SocketError> {
+        Self::init_with_workers(subscriptions, DEFAULT_PARSE_WORKERS).await
+    }
+}
+
+impl<Exchange> ParallelWsStream<Exchange>
+where
+    Exchange: Subscriber + ExchangeTransformer + Send + Unpin,
+    Exchange::Input: Send + 'static,
+{
+    /// Initialises a new [`ParallelWsStream`] using the provided subscriptions, parsing frames
+    /// with the provided number of workers.
+    pub async fn init_with_workers(
+        subscriptions: &[Subscription],
+        workers: usize,
+    ) -> Result<Self, SocketError> {
+        // Connect & subscribe
+        let (websocket, ids) = Exchange::subscribe(subscriptions).await?;
+
+        // Split WebSocket into WsStream & WsSink components
+        let (ws_sink, mut ws_stream) = websocket.split();
+
+        // Task to distribute ExchangeTransformer outgoing messages to the exchange
+        let (ws_sink_tx, ws_sink_rx) = mpsc::unbounded_channel();
+        tokio::spawn(distribute_responses_to_the_exchange(
+            Exchange::EXCHANGE,
+            ws_sink,
+            ws_sink_rx,
+        ));
+
+        // Spawn parsing workers, each returning parsed frames via a shared channel
+        let (parsed_tx, parsed_rx) = mpsc::unbounded_channel();
+        let frame_txs = (0..workers.max(1))
+            .map(|_| {
+                let (frame_tx, mut frame_rx) =
+                    mpsc::unbounded_channel::<(u64, Instant, Result<WsMessage, WsError>)>();
+                let parsed_tx = parsed_tx.clone();
+                tokio::spawn(async move {
+                    while let Some((frame, received, message)) = frame_rx.recv().await {
+                        let parsed = WebSocketParser::parse::<Exchange::Input>(message);
+                        if parsed_tx.send((frame, received, parsed)).is_err() {
+                            break;
+                        }
+                    }
+                });
+                frame_tx
+            })
+            .collect::<Vec<_>>();
+
+        // Task to read frames from the WsStream & distribute them round-robin to the workers
+        tokio::spawn(async move {
+            let mut frame = 0u64;
+            while let Some(message) = ws_stream.next().await {
+                let received = Instant::now();
+                let worker = &frame_txs[(frame % frame_txs.len() as u64) as usize];
+                if worker.send((frame, received, message)).is_err() {
+                    break;
+                }
+                frame += 1;
+            }
+        });
+
+        Ok(Self::new(Exchange::new(ws_sink_tx, ids), parsed_rx))
+    }
+}
+
+impl<Exchange> ParallelWsStream<Exchange>
+where
+    Exchange: ExchangeTransformer,
+{
+    /// Construct a [`ParallelWsStream`] that re-assembles & transforms the parsed frames received
+    /// via the provided `parsed_rx`.
+    fn new(
+        transformer: Exchange,
+        parsed_rx: mpsc::UnboundedReceiver<ParsedFrame<Exchange::Input>>,
+    ) -> Self {
+        Self {
+            transformer,
+            parsed_rx,
+            pending: BTreeMap::new(),
+            next_frame: 0,
+            next_sequence: 0,
+            buffer: VecDeque::new(),
+        }
+    }
+
+    /// Transform the next in-order parsed frame, buffering the outputs.
+    fn process(&mut self, received: Instant, parsed: Parsed<Exchange::Input>) {
+        match parsed {
+            // Frames that do not yield a message (eg/ Ping, Pong)
+            None => {}
+            Some(Err(error)) => self.buffer.push_back(Err(error)),
+            Some(Ok(input)) => {
+                for output in stamp(self.transformer.transform(input), received) {
+                    let output = output.map(|payload| {
+                        let sequence = self.next_sequence;
+                        self.next_sequence += 1;
+                        Event { sequence, payload }
+                    });
+                    self.buffer.push_back(output);
+                }
+            }
+        }
+    }
+}
+
+impl<Exchange> Stream for ParallelWsStream<Exchange>
+where
+    Exchange: ExchangeTransformer + Unpin,
+{
+    type Item = Result<Event<MarketEvent>, SocketError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            // Return buffered outputs first
+            if let Some(output) = self.buffer.pop_front() {
+                return Poll::Ready(Some(output));
+            }
+
+            // Transform the next in-order frame if it has already been parsed
+            let next_frame = self.next_frame;
+            if let Some((received, parsed)) = self.pending.remove(&next_frame) {
+                self.next_frame += 1;
+                self.process(received, parsed);
+                continue;
+            }
+
+            // Wait for more parsed frames to arrive from the workers
+            match self.parsed_rx.poll_recv(cx) {
+                Poll::Ready(Some((frame, received, parsed))) => {
+                    self.pending.insert(frame, (received, parsed));
+                }
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        exchange::coinbase::Coinbase,
+        model::{subscription::SubKind, DataKind},
+        ExchangeId, SubscriptionIds,
+    };
+    use barter_integration::{
+        model::{InstrumentKind, SubscriptionId},
+        Transformer,
+    };
+    use std::collections::HashMap;
+
+    fn trade(id: u64) -> <Coinbase as Transformer<MarketEvent>>::Input {
+        serde_json::from_str(&format!(
+            r#"{{"type":"match","trade_id":{id},"time":"2014-11-07T08:19:27.028459Z","product_id":"BTC-USD","size":"1","price":"1","side":"buy"}}"#
+        ))
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_parallel_ws_stream_reassembles_frames_in_order() {
+        let (parsed_tx, parsed_rx) = mpsc::unbounded_channel();
+        let transformer = Coinbase {
+            ids: SubscriptionIds(HashMap::from([(
+                SubscriptionId::from("matches|BTC-USD"),
+                Subscription::new(
+                    ExchangeId::Coinbase,
+                    ("btc", "usd", InstrumentKind::Spot),
+                    SubKind::Trade,
+                ),
+            )])),
+        };
+        let stream = ParallelWsStream::new(transformer, parsed_rx);
+
+        // Frames parsed out of order, including a frame that yields no message
+        let received = Instant::now();
+        parsed_tx.send((2, received, Some(Ok(trade(2))))).unwrap();
+        parsed_tx.send((1, received, None)).unwrap();
+        parsed_tx.send((3, received, Some(Ok(trade(3))))).unwrap();
+        parsed_tx.send((0, received, Some(Ok(trade(0))))).unwrap();
+        drop(parsed_tx);
+
+        let actual = stream
+            .map(|event| {
+                let event = event.unwrap();
+                assert_eq!(event.payload.received_instant, Some(received));
+                match event.payload.kind {
+                    DataKind::Trade(trade) => (event.sequence, trade.id),
+                    other => panic!("unexpected DataKind: {other:?}"),
+                }
+            })
+            .collect::<Vec<_>>()
+            .await;
+
+        let expected = vec![
+            (0, "0".to_owned()),
+            (1, "2".to_owned()),
+            (2, "3".to_owned()),
+        ];
+
+        assert_eq!(actual, expected);
+    }
+}
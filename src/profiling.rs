@@ -0,0 +1,301 @@
+use crate::{
+    model::{subscription::SubscriptionMeta, DataKind, MarketEvent},
+    ExchangeId, ExchangeTransformer, Subscriber, Subscription, SubscriptionIds,
+};
+use barter_integration::{error::SocketError, protocol::websocket::WsMessage, Transformer};
+use serde::{Deserialize, Deserializer};
+use std::{
+    collections::HashMap,
+    sync::{Mutex, OnceLock},
+    time::{Duration, Instant},
+};
+use tokio::sync::mpsc;
+
+/// Number of power-of-two nanosecond buckets in a [`Distribution`].
+const NUM_BUCKETS: usize = 64;
+
+/// Global registry of [`ProcessingProfile`]s keyed by [`ExchangeId`] & [`DataKind`] name.
+static PROFILES: OnceLock<Mutex<HashMap<(ExchangeId, &'static str), ProcessingProfile>>> =
+    OnceLock::new();
+
+/// Stage of message processing that a measured `Duration` is attributed to.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum Stage {
+    /// Deserialising the exchange payload.
+    Parse,
+    /// Transforming the deserialised payload into [`MarketEvent`]s.
+    Transform,
+    /// Distributing the [`MarketEvent`] downstream to the [`Streams`](crate::builder::Streams).
+    Route,
+}
+
+/// Processing time [`Distribution`]s of each [`Stage`] for one exchange & [`DataKind`].
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct ProcessingProfile {
+    pub parse: Distribution,
+    pub transform: Distribution,
+    pub route: Distribution,
+}
+
+impl ProcessingProfile {
+    fn stage_mut(&mut self, stage: Stage) -> &mut Distribution {
+        match stage {
+            Stage::Parse => &mut self.parse,
+            Stage::Transform => &mut self.transform,
+            Stage::Route => &mut self.route,
+        }
+    }
+}
+
+/// Distribution of recorded `Duration`s using power-of-two nanosecond buckets.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Distribution {
+    pub count: u64,
+    pub total: Duration,
+    pub min: Duration,
+    pub max: Duration,
+    buckets: [u64; NUM_BUCKETS],
+}
+
+impl Default for Distribution {
+    fn default() -> Self {
+        Self {
+            count: 0,
+            total: Duration::ZERO,
+            min: Duration::MAX,
+            max: Duration::ZERO,
+            buckets: [0; NUM_BUCKETS],
+        }
+    }
+}
+
+impl Distribution {
+    /// Record a measured `Duration`.
+    pub fn record(&mut self, duration: Duration) {
+        let nanos = u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX);
+        let bucket = (u64::BITS - nanos.leading_zeros()) as usize;
+
+        self.count += 1;
+        self.total += duration;
+        self.min = self.min.min(duration);
+        self.max = self.max.max(duration);
+        self.buckets[bucket.min(NUM_BUCKETS - 1)] += 1;
+    }
+
+    /// Mean of the recorded `Duration`s.
+    pub fn mean(&self) -> Duration {
+        match self.count {
+            0 => Duration::ZERO,
+            count => Duration::from_nanos((self.total.as_nanos() / count as u128) as u64),
+        }
+    }
+
+    /// Upper bound estimate of the provided quantile (eg/ 0.99) of the recorded `Duration`s.
+    pub fn quantile(&self, quantile: f64) -> Duration {
+        if self.count == 0 {
+            return Duration::ZERO;
+        }
+
+        let target = ((self.count as f64) * quantile.clamp(0.0, 1.0))
+            .ceil()
+            .max(1.0) as u64;
+        let mut cumulative = 0;
+        for (bucket, count) in self.buckets.iter().enumerate() {
+            cumulative += count;
+            if cumulative >= target {
+                let upper =
+                    Duration::from_nanos(1u64.checked_shl(bucket as u32).unwrap_or(u64::MAX));
+                return upper.min(self.max);
+            }
+        }
+
+        self.max
+    }
+}
+
+/// Record a measured `Duration` for the provided exchange, [`DataKind`] name & [`Stage`].
+pub fn record(exchange: ExchangeId, kind: &'static str, stage: Stage, duration: Duration) {
+    let mut profiles = PROFILES
+        .get_or_init(Default::default)
+        .lock()
+        .expect("profiling registry lock poisoned");
+
+    profiles
+        .entry((exchange, kind))
+        .or_default()
+        .stage_mut(stage)
+        .record(duration);
+}
+
+/// Return a snapshot of every [`ProcessingProfile`] recorded so far, keyed by [`ExchangeId`] &
+/// [`DataKind`] name.
+pub fn snapshot() -> HashMap<(ExchangeId, &'static str), ProcessingProfile> {
+    PROFILES
+        .get_or_init(Default::default)
+        .lock()
+        .expect("profiling registry lock poisoned")
+        .clone()
+}
+
+/// Remove every recorded [`ProcessingProfile`].
+pub fn reset() {
+    PROFILES
+        .get_or_init(Default::default)
+        .lock()
+        .expect("profiling registry lock poisoned")
+        .clear()
+}
+
+/// Name used to group [`ProcessingProfile`]s by [`DataKind`]. Messages that do not produce a
+/// [`MarketEvent`] (eg/ heartbeats) are grouped under "other".
+pub fn kind_name(kind: Option<&DataKind>) -> &'static str {
+    match kind {
+        Some(DataKind::Trade(_)) => "trade",
+        Some(DataKind::AggTrade(_)) => "agg_trade",
+        Some(DataKind::Candle(_)) => "candle",
+        Some(DataKind::OrderBook(_)) => "order_book",
+        Some(DataKind::OrderBookL1(_)) => "order_book_l1",
+        Some(DataKind::OrderBookL2Snapshot(_)) => "order_book_l2_snapshot",
+        Some(DataKind::OrderBookL2Delta(_)) => "order_book_l2_delta",
+        Some(DataKind::OrderBookL3Delta(_)) => "order_book_l3_delta",
+        Some(DataKind::Liquidation(_)) => "liquidation",
+        Some(DataKind::MarkPrice(_)) => "mark_price",
+        Some(DataKind::FundingRate(_)) => "funding_rate",
+        Some(DataKind::OpenInterest(_)) => "open_interest",
+        Some(DataKind::Ticker(_)) => "ticker",
+        Some(DataKind::RollingTicker(_)) => "rolling_ticker",
+        Some(DataKind::OptionTicker(_)) => "option_ticker",
+        Some(DataKind::InstrumentStatus(_)) => "instrument_status",
+        Some(DataKind::OrderUpdate(_)) => "order_update",
+        Some(DataKind::BalanceUpdate(_)) => "balance_update",
+        Some(DataKind::PositionUpdate(_)) => "position_update",
+        Some(DataKind::Rollover(_)) => "rollover",
+        None => "other",
+    }
+}
+
+/// Deserialisable wrapper that measures how long the inner value took to deserialise.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Timed<T> {
+    pub value: T,
+    pub elapsed: Duration,
+}
+
+impl<'de, T> Deserialize<'de> for Timed<T>
+where
+    T: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let start = Instant::now();
+        let value = T::deserialize(deserializer)?;
+        Ok(Self {
+            value,
+            elapsed: start.elapsed(),
+        })
+    }
+}
+
+/// [`Subscriber`] & [`ExchangeTransformer`] wrapper that records the parse & transform time of
+/// every message processed by the inner `Exchange`.
+#[derive(Debug)]
+pub struct Profiled<Exchange> {
+    pub exchange: Exchange,
+}
+
+impl<Exchange> Subscriber for Profiled<Exchange>
+where
+    Exchange: Subscriber,
+{
+    type SubResponse = Exchange::SubResponse;
+
+    fn base_url() -> &'static str {
+        Exchange::base_url()
+    }
+
+    fn url(subscriptions: &[Subscription]) -> Result<String, SocketError> {
+        Exchange::url(subscriptions)
+    }
+
+    fn build_subscription_meta(
+        subscriptions: &[Subscription],
+    ) -> Result<SubscriptionMeta, SocketError> {
+        Exchange::build_subscription_meta(subscriptions)
+    }
+
+    fn subscription_timeout() -> Duration {
+        Exchange::subscription_timeout()
+    }
+}
+
+impl<Exchange> ExchangeTransformer for Profiled<Exchange>
+where
+    Exchange: ExchangeTransformer,
+{
+    const EXCHANGE: ExchangeId = Exchange::EXCHANGE;
+
+    fn new(ws_sink_tx: mpsc::UnboundedSender<WsMessage>, ids: SubscriptionIds) -> Self {
+        Self {
+            exchange: Exchange::new(ws_sink_tx, ids),
+        }
+    }
+}
+
+impl<Exchange> Transformer<MarketEvent> for Profiled<Exchange>
+where
+    Exchange: ExchangeTransformer,
+{
+    type Input = Timed<Exchange::Input>;
+    type OutputIter = Vec<Result<MarketEvent, SocketError>>;
+
+    fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+        let start = Instant::now();
+        let output = self
+            .exchange
+            .transform(input.value)
+            .into_iter()
+            .collect::<Vec<_>>();
+        let transform = start.elapsed();
+
+        let kind = kind_name(
+            output
+                .iter()
+                .find_map(|event| event.as_ref().ok().map(|event| &event.kind)),
+        );
+        record(Exchange::EXCHANGE, kind, Stage::Parse, input.elapsed);
+        record(Exchange::EXCHANGE, kind, Stage::Transform, transform);
+
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_distribution() {
+        let mut distribution = Distribution::default();
+        assert_eq!(distribution.mean(), Duration::ZERO);
+        assert_eq!(distribution.quantile(0.5), Duration::ZERO);
+
+        for nanos in [100, 200, 300, 5_000] {
+            distribution.record(Duration::from_nanos(nanos));
+        }
+
+        assert_eq!(distribution.count, 4);
+        assert_eq!(distribution.min, Duration::from_nanos(100));
+        assert_eq!(distribution.max, Duration::from_nanos(5_000));
+        assert_eq!(distribution.mean(), Duration::from_nanos(1_400));
+        assert_eq!(distribution.quantile(0.5), Duration::from_nanos(256));
+        assert_eq!(distribution.quantile(1.0), Duration::from_nanos(5_000));
+    }
+
+    #[test]
+    fn test_deserialise_timed() {
+        let actual = serde_json::from_str::<Timed<Vec<u64>>>("[1, 2, 3]").unwrap();
+        assert_eq!(actual.value, vec![1, 2, 3]);
+    }
+}
@@ -0,0 +1,189 @@
+use crate::{
+    distribute_responses_to_the_exchange,
+    model::MarketEvent,
+    orderbook::{ChecksumPolicy, ChecksumValidation},
+    receipt::stamp,
+    ExchangeTransformer, MarketStream, Subscriber, Subscription,
+};
+use async_trait::async_trait;
+use barter_integration::{
+    error::SocketError,
+    protocol::{
+        websocket::{WebSocketParser, WsError, WsMessage, WsStream},
+        StreamParser,
+    },
+    Event,
+};
+use futures::{Stream, StreamExt};
+use std::{
+    collections::VecDeque,
+    fmt::{Debug, Formatter},
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+    time::Instant,
+};
+use tokio::sync::mpsc;
+
+/// [`MarketStream`] that attaches the original exchange payload of each WebSocket frame to every
+/// [`MarketEvent`] transformed from it (see [`MarketEvent::raw`]), so users can archive exact
+/// exchange data or parse venue specific fields the normalisation does not cover.
+///
+/// Apart from the populated [`MarketEvent::raw`], the yielded [`Event`] sequence is identical to
+/// that of an [`ExchangeWsStream`](crate::ExchangeWsStream).
+pub struct RawWsStream<Exchange> {
+    ws_stream: WsStream,
+    transformer: Exchange,
+    next_sequence: u64,
+    buffer: VecDeque<Result<Event<MarketEvent>, SocketError>>,
+}
+
+impl<Exchange> Debug for RawWsStream<Exchange>
+where
+    Exchange: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RawWsStream")
+            .field("transformer", &self.transformer)
+            .field("next_sequence", &self.next_sequence)
+            .finish()
+    }
+}
+
+impl<Exchange> ChecksumValidation for RawWsStream<Exchange>
+where
+    Exchange: ChecksumValidation,
+{
+    fn set_checksum_policy(&mut self, policy: ChecksumPolicy) {
+        self.transformer.set_checksum_policy(policy)
+    }
+}
+
+#[async_trait]
+impl<Exchange> MarketStream for RawWsStream<Exchange>
+where
+    Exchange: Subscriber + ExchangeTransformer + Send + Unpin,
+{
+    async fn init(subscriptions: &[Subscription]) -> Result<Self, SocketError> {
+        // Connect & subscribe
+        let (websocket, ids) = Exchange::subscribe(subscriptions).await?;
+
+        // Split WebSocket into WsStream & WsSink components
+        let (ws_sink, ws_stream) = websocket.split();
+
+        // Task to distribute ExchangeTransformer outgoing messages to the exchange
+        let (ws_sink_tx, ws_sink_rx) = mpsc::unbounded_channel();
+        tokio::spawn(distribute_responses_to_the_exchange(
+            Exchange::EXCHANGE,
+            ws_sink,
+            ws_sink_rx,
+        ));
+
+        Ok(Self {
+            ws_stream,
+            transformer: Exchange::new(ws_sink_tx, ids),
+            next_sequence: 0,
+            buffer: VecDeque::new(),
+        })
+    }
+}
+
+impl<Exchange> RawWsStream<Exchange>
+where
+    Exchange: ExchangeTransformer,
+{
+    /// Parse & transform the provided [`WsMessage`], buffering the outputs with the raw payload
+    /// attached.
+    fn process(&mut self, message: Result<WsMessage, WsError>, received: Instant) {
+        let raw = message.as_ref().ok().and_then(raw_payload);
+
+        let input = match WebSocketParser::parse::<Exchange::Input>(message) {
+            Some(Ok(input)) => input,
+            Some(Err(error)) => {
+                self.buffer.push_back(Err(error));
+                return;
+            }
+            // Frames that do not yield a message (eg/ Ping, Pong)
+            None => return,
+        };
+
+        for output in stamp(self.transformer.transform(input), received) {
+            let output = output.map(|mut payload| {
+                payload.raw = raw.clone();
+                let sequence = self.next_sequence;
+                self.next_sequence += 1;
+                Event { sequence, payload }
+            });
+            self.buffer.push_back(output);
+        }
+    }
+}
+
+impl<Exchange> Stream for RawWsStream<Exchange>
+where
+    Exchange: ExchangeTransformer + Unpin,
+{
+    type Item = Result<Event<MarketEvent>, SocketError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            // Return buffered outputs first
+            if let Some(output) = self.buffer.pop_front() {
+                return Poll::Ready(Some(output));
+            }
+
+            match self.ws_stream.poll_next_unpin(cx) {
+                Poll::Ready(Some(message)) => self.process(message, Instant::now()),
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+/// Extract the original exchange payload of a text or binary [`WsMessage`].
+pub fn raw_payload(message: &WsMessage) -> Option<Arc<[u8]>> {
+    match message {
+        WsMessage::Text(text) => Some(Arc::from(text.as_bytes())),
+        WsMessage::Binary(binary) => Some(Arc::from(binary.as_slice())),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_raw_payload() {
+        struct TestCase {
+            input: WsMessage,
+            expected: Option<Arc<[u8]>>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: text frame payload is captured verbatim
+                input: WsMessage::Text(r#"{"price": "1.0", "venue_field": 7}"#.to_owned()),
+                expected: Some(Arc::from(
+                    r#"{"price": "1.0", "venue_field": 7}"#.as_bytes(),
+                )),
+            },
+            TestCase {
+                // TC1: binary frame payload is captured verbatim
+                input: WsMessage::Binary(vec![1, 2, 3]),
+                expected: Some(Arc::from([1u8, 2, 3].as_slice())),
+            },
+            TestCase {
+                // TC2: control frames have no payload to capture
+                input: WsMessage::Ping(vec![1]),
+                expected: None,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = raw_payload(&test.input);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
@@ -0,0 +1,193 @@
+use crate::{
+    model::MarketEvent,
+    orderbook::{ChecksumPolicy, ChecksumValidation},
+};
+use barter_integration::{error::SocketError, protocol::StreamParser, Event, Transformer};
+use futures::{Stream, StreamExt};
+use std::{
+    collections::VecDeque,
+    fmt::{Debug, Formatter},
+    marker::PhantomData,
+    pin::Pin,
+    task::{Context, Poll},
+    time::Instant,
+};
+
+/// Protocol message stamped with the monotonic instant it was read from the socket, ie/ before
+/// parsing begins.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Received<T> {
+    pub value: T,
+    pub instant: Instant,
+}
+
+/// [`Stream`] that stamps every protocol message polled from the `InnerStream` with the monotonic
+/// instant it was read, before it is parsed by the `Protocol` [`StreamParser`] & transformed by
+/// the `Exchange` [`Transformer`]. The [`Received`] instant populates the
+/// [`MarketEvent::received_instant`] of every [`MarketEvent`] transformed from the message.
+pub struct StampedStream<Protocol, InnerStream, Exchange> {
+    stream: InnerStream,
+    transformer: Exchange,
+    next_sequence: u64,
+    buffer: VecDeque<Result<Event<MarketEvent>, SocketError>>,
+    protocol_marker: PhantomData<Protocol>,
+}
+
+impl<Protocol, InnerStream, Exchange> Debug for StampedStream<Protocol, InnerStream, Exchange>
+where
+    Exchange: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StampedStream")
+            .field("transformer", &self.transformer)
+            .field("next_sequence", &self.next_sequence)
+            .finish()
+    }
+}
+
+impl<Protocol, InnerStream, Exchange> ChecksumValidation
+    for StampedStream<Protocol, InnerStream, Exchange>
+where
+    Exchange: ChecksumValidation,
+{
+    fn set_checksum_policy(&mut self, policy: ChecksumPolicy) {
+        self.transformer.set_checksum_policy(policy)
+    }
+}
+
+impl<Protocol, InnerStream, Exchange> StampedStream<Protocol, InnerStream, Exchange>
+where
+    Protocol: StreamParser,
+    Exchange: Transformer<MarketEvent>,
+{
+    /// Construct a new [`StampedStream`] reading protocol messages from the provided
+    /// `InnerStream`.
+    pub fn new(stream: InnerStream, transformer: Exchange) -> Self {
+        Self {
+            stream,
+            transformer,
+            next_sequence: 0,
+            buffer: VecDeque::new(),
+            protocol_marker: PhantomData,
+        }
+    }
+
+    /// Parse & transform the provided [`Received`] protocol message, buffering the outputs
+    /// stamped with the instant it was read.
+    fn process(&mut self, message: Received<Result<Protocol::Message, Protocol::Error>>) {
+        let input = match Protocol::parse::<Exchange::Input>(message.value) {
+            Some(Ok(input)) => input,
+            Some(Err(error)) => {
+                self.buffer.push_back(Err(error));
+                return;
+            }
+            // Messages that do not yield an input (eg/ Ping, Pong)
+            None => return,
+        };
+
+        for output in stamp(self.transformer.transform(input), message.instant) {
+            let output = output.map(|payload| {
+                let sequence = self.next_sequence;
+                self.next_sequence += 1;
+                Event { sequence, payload }
+            });
+            self.buffer.push_back(output);
+        }
+    }
+}
+
+impl<Protocol, InnerStream, Exchange> Stream for StampedStream<Protocol, InnerStream, Exchange>
+where
+    Protocol: StreamParser + Unpin,
+    InnerStream: Stream<Item = Result<Protocol::Message, Protocol::Error>> + Unpin,
+    Exchange: Transformer<MarketEvent> + Unpin,
+{
+    type Item = Result<Event<MarketEvent>, SocketError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            // Return buffered outputs first
+            if let Some(output) = self.buffer.pop_front() {
+                return Poll::Ready(Some(output));
+            }
+
+            match self.stream.poll_next_unpin(cx) {
+                Poll::Ready(Some(message)) => self.process(Received {
+                    value: message,
+                    instant: Instant::now(),
+                }),
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+/// Populate the [`MarketEvent::received_instant`] of every transformed [`MarketEvent`] with the
+/// provided socket read `Instant`.
+pub fn stamp<Outputs>(outputs: Outputs, instant: Instant) -> Vec<Result<MarketEvent, SocketError>>
+where
+    Outputs: IntoIterator<Item = Result<MarketEvent, SocketError>>,
+{
+    outputs
+        .into_iter()
+        .map(|output| {
+            output.map(|mut event| {
+                event.received_instant = Some(instant);
+                event
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::market_trade;
+    use barter_integration::{
+        model::Side,
+        protocol::websocket::{WebSocketParser, WsMessage},
+    };
+    use futures::stream;
+
+    struct Echo;
+
+    impl Transformer<MarketEvent> for Echo {
+        type Input = bool;
+        type OutputIter = Vec<Result<MarketEvent, SocketError>>;
+
+        fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+            match input {
+                true => vec![Ok(market_trade(Side::Buy)), Ok(market_trade(Side::Sell))],
+                false => vec![Err(SocketError::Unidentifiable(
+                    barter_integration::model::SubscriptionId::from("unknown"),
+                ))],
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_stamped_stream_populates_received_instant() {
+        let frames = vec![
+            Ok(WsMessage::Text("true".to_owned())),
+            Ok(WsMessage::Ping(vec![])),
+            Ok(WsMessage::Text("false".to_owned())),
+        ];
+
+        let before = Instant::now();
+        let actual = StampedStream::<WebSocketParser, _, _>::new(stream::iter(frames), Echo)
+            .collect::<Vec<_>>()
+            .await;
+        let after = Instant::now();
+
+        // Both MarketEvents transformed from the first frame share it's read instant
+        let (first, second) = match actual.as_slice() {
+            [Ok(first), Ok(second), Err(SocketError::Unidentifiable(_))] => (first, second),
+            other => panic!("unexpected outputs: {other:?}"),
+        };
+        assert_eq!((first.sequence, second.sequence), (0, 1));
+        let instant = first.payload.received_instant.unwrap();
+        assert_eq!(second.payload.received_instant, Some(instant));
+        assert!(before <= instant && instant <= after);
+    }
+}
@@ -0,0 +1,134 @@
+use crate::model::MarketEvent;
+use barter_integration::Event;
+use futures::Stream;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use tokio::time::Instant;
+
+/// Pacing mode used to [`replay`] recorded [`MarketEvent`]s.
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub enum Pacing {
+    /// Yield every [`MarketEvent`] immediately.
+    AsFastAsPossible,
+    /// Reproduce the original gaps between the recorded [`MarketEvent`] receipt timestamps.
+    Original,
+    /// Reproduce the original gaps between the recorded [`MarketEvent`] receipt timestamps,
+    /// sped up by the provided multiplier (eg/ 10.0 replays 10x faster).
+    Multiplier(f64),
+}
+
+impl Pacing {
+    /// Return the wall-clock `Duration` after the start of a replay that a [`MarketEvent`]
+    /// received the provided `Duration` after the first recorded [`MarketEvent`] should be
+    /// yielded, or `None` if it should be yielded immediately.
+    pub fn scale(&self, since_first: Duration) -> Option<Duration> {
+        match self {
+            Pacing::AsFastAsPossible => None,
+            Pacing::Original => Some(since_first),
+            Pacing::Multiplier(multiplier) if *multiplier > 0.0 && multiplier.is_finite() => {
+                Some(since_first.div_f64(*multiplier))
+            }
+            Pacing::Multiplier(_) => None,
+        }
+    }
+}
+
+/// Replay the provided recorded [`MarketEvent`]s as a [`Stream`] of sequenced [`Event`]s, paced
+/// according to the provided [`Pacing`] using the recorded `received_time` of each
+/// [`MarketEvent`].
+///
+/// Every [`MarketEvent`] is scheduled relative to the start of the replay rather than the
+/// previous [`MarketEvent`], so the inter-event gaps stay faithful without accumulating drift.
+pub fn replay<Events>(events: Events, pacing: Pacing) -> impl Stream<Item = Event<MarketEvent>>
+where
+    Events: IntoIterator<Item = MarketEvent>,
+{
+    let state = (events.into_iter(), None, 0u64);
+
+    futures::stream::unfold(
+        state,
+        move |(mut events, mut origin, sequence)| async move {
+            let payload = events.next()?;
+
+            // Replay origin is the first MarketEvent received_time & the wall-clock start instant
+            let (first, start) = *origin.get_or_insert((payload.received_time, Instant::now()));
+
+            let since_first = (payload.received_time - first)
+                .to_std()
+                .unwrap_or(Duration::ZERO);
+            if let Some(delay) = pacing.scale(since_first) {
+                tokio::time::sleep_until(start + delay).await;
+            }
+
+            Some((Event { sequence, payload }, (events, origin, sequence + 1)))
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::market_trade;
+    use barter_integration::model::Side;
+    use futures::StreamExt;
+
+    #[test]
+    fn test_pacing_scale() {
+        struct TestCase {
+            input: Pacing,
+            expected: Option<Duration>,
+        }
+
+        let since_first = Duration::from_millis(1000);
+
+        let cases = vec![
+            TestCase {
+                // TC0: as fast as possible is never delayed
+                input: Pacing::AsFastAsPossible,
+                expected: None,
+            },
+            TestCase {
+                // TC1: original timing preserves the recorded gap
+                input: Pacing::Original,
+                expected: Some(Duration::from_millis(1000)),
+            },
+            TestCase {
+                // TC2: 10x multiplier shrinks the recorded gap
+                input: Pacing::Multiplier(10.0),
+                expected: Some(Duration::from_millis(100)),
+            },
+            TestCase {
+                // TC3: 0.5x multiplier stretches the recorded gap
+                input: Pacing::Multiplier(0.5),
+                expected: Some(Duration::from_millis(2000)),
+            },
+            TestCase {
+                // TC4: invalid multiplier is not delayed
+                input: Pacing::Multiplier(0.0),
+                expected: None,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = test.input.scale(since_first);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_replay_original_pacing() {
+        let first = market_trade(Side::Buy);
+        let mut second = market_trade(Side::Sell);
+        second.received_time = first.received_time + chrono::Duration::milliseconds(50);
+
+        let start = Instant::now();
+        let actual = replay(vec![first.clone(), second.clone()], Pacing::Original)
+            .collect::<Vec<_>>()
+            .await;
+
+        assert!(start.elapsed() >= Duration::from_millis(50));
+        assert_eq!(actual.len(), 2);
+        assert_eq!((actual[0].sequence, &actual[0].payload), (0, &first));
+        assert_eq!((actual[1].sequence, &actual[1].payload), (1, &second));
+    }
+}
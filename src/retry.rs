@@ -0,0 +1,163 @@
+use std::{collections::hash_map::RandomState, fmt::Debug, hash::BuildHasher, time::Duration};
+
+/// Policy determining how long to wait before each retry of a failed operation (eg/ re-connecting
+/// a [`MarketStream`](crate::MarketStream)), and when to give up.
+pub trait RetryPolicy: Debug + Send + Sync {
+    /// Return the `Duration` to wait before the provided retry `attempt` (starting at 1), or
+    /// `None` if the operation should not be retried again.
+    fn backoff(&self, attempt: u32) -> Option<Duration>;
+}
+
+/// [`RetryPolicy`] waiting the same `Duration` before every retry, forever.
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct Fixed {
+    pub delay: Duration,
+}
+
+impl RetryPolicy for Fixed {
+    fn backoff(&self, _: u32) -> Option<Duration> {
+        Some(self.delay)
+    }
+}
+
+/// [`RetryPolicy`] doubling the `Duration` waited before each retry starting from the `initial`
+/// `Duration`, capped at the `max` `Duration`, forever.
+///
+/// If `jitter` is enabled, each backoff is randomised between half & all of the `Duration` so
+/// many clients disconnected at once do not retry in lockstep.
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct Exponential {
+    pub initial: Duration,
+    pub max: Duration,
+    pub jitter: bool,
+}
+
+impl RetryPolicy for Exponential {
+    fn backoff(&self, attempt: u32) -> Option<Duration> {
+        let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+        let delay = self.initial.saturating_mul(factor).min(self.max);
+
+        match self.jitter {
+            true => {
+                let fraction = RandomState::new().hash_one(attempt) as f64 / u64::MAX as f64;
+                Some(delay.mul_f64(0.5 + fraction / 2.0))
+            }
+            false => Some(delay),
+        }
+    }
+}
+
+/// [`RetryPolicy`] wrapper giving up once the inner [`RetryPolicy`] has been retried
+/// `max_attempts` times.
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct GiveUpAfter<Policy> {
+    pub policy: Policy,
+    pub max_attempts: u32,
+}
+
+impl<Policy> RetryPolicy for GiveUpAfter<Policy>
+where
+    Policy: RetryPolicy,
+{
+    fn backoff(&self, attempt: u32) -> Option<Duration> {
+        match attempt <= self.max_attempts {
+            true => self.policy.backoff(attempt),
+            false => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_retry_policy_backoff() {
+        struct TestCase {
+            input: (Box<dyn RetryPolicy>, u32),
+            expected: Option<Duration>,
+        }
+
+        let exponential = Exponential {
+            initial: Duration::from_millis(100),
+            max: Duration::from_secs(1),
+            jitter: false,
+        };
+
+        let cases = vec![
+            TestCase {
+                // TC0: fixed policy always waits the same delay
+                input: (
+                    Box::new(Fixed {
+                        delay: Duration::from_millis(100),
+                    }),
+                    10,
+                ),
+                expected: Some(Duration::from_millis(100)),
+            },
+            TestCase {
+                // TC1: exponential policy first retry waits the initial delay
+                input: (Box::new(exponential), 1),
+                expected: Some(Duration::from_millis(100)),
+            },
+            TestCase {
+                // TC2: exponential policy doubles the delay each retry
+                input: (Box::new(exponential), 3),
+                expected: Some(Duration::from_millis(400)),
+            },
+            TestCase {
+                // TC3: exponential policy is capped at the max delay
+                input: (Box::new(exponential), 30),
+                expected: Some(Duration::from_secs(1)),
+            },
+            TestCase {
+                // TC4: give up policy retries up to the max attempts
+                input: (
+                    Box::new(GiveUpAfter {
+                        policy: exponential,
+                        max_attempts: 2,
+                    }),
+                    2,
+                ),
+                expected: Some(Duration::from_millis(200)),
+            },
+            TestCase {
+                // TC5: give up policy stops retrying after the max attempts
+                input: (
+                    Box::new(GiveUpAfter {
+                        policy: exponential,
+                        max_attempts: 2,
+                    }),
+                    3,
+                ),
+                expected: None,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let (policy, attempt) = test.input;
+            let actual = policy.backoff(attempt);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_exponential_jitter_within_bounds() {
+        let policy = Exponential {
+            initial: Duration::from_millis(100),
+            max: Duration::from_secs(1),
+            jitter: true,
+        };
+
+        for attempt in 1..=10 {
+            let delay = policy.backoff(attempt).unwrap();
+            let max = Duration::from_millis(100)
+                .saturating_mul(2u32.saturating_pow(attempt - 1))
+                .min(Duration::from_secs(1));
+            assert!(
+                delay >= max / 2 && delay <= max,
+                "attempt {attempt}: {delay:?}"
+            );
+        }
+    }
+}
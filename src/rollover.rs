@@ -0,0 +1,598 @@
+use crate::{
+    builder::SubscriptionControl,
+    model::{
+        subscription::{SubKind, Subscription},
+        DataKind, FutureContract, MarketEvent, Rollover,
+    },
+    ExchangeId,
+};
+use barter_integration::{error::SocketError, model::Instrument, Event, Validator};
+use chrono::{DateTime, Duration, Utc};
+use std::collections::VecDeque;
+use tokio::{sync::mpsc, task::JoinHandle};
+use tracing::info;
+
+/// Rule determining when a [`RollManager`] rolls from the active [`FutureContract`] to the next.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum RollRule {
+    /// Roll once the [`MarketEvent::exchange_time`] is within the provided `Duration` of the
+    /// active [`FutureContract::expiry_time`].
+    Date(Duration),
+    /// Roll once the traded volume of the next [`FutureContract`] over the trailing window of the
+    /// provided `Duration` exceeds that of the active [`FutureContract`], or the active
+    /// [`FutureContract`] expires. Requires a [`SubKind::Trade`] [`Subscription`].
+    ///
+    /// Trades are retained across a roll, so the newly active [`FutureContract`] keeps the
+    /// volume that caused the roll, and the new next [`FutureContract`] must out-trade it over
+    /// a full window before rolling again.
+    Volume(Duration),
+}
+
+/// Dated [`FutureContract`]s of an exchange consumed as one rolling position, subscribed via
+/// [`StreamBuilder::subscribe_rolling`](crate::builder::StreamBuilder::subscribe_rolling).
+#[derive(Clone, PartialEq, Debug)]
+pub struct RollingFutures {
+    pub exchange: ExchangeId,
+    /// Contracts to roll through, in any order.
+    pub contracts: Vec<FutureContract>,
+    /// [`SubKind`]s subscribed for the active & next [`FutureContract`].
+    pub kinds: Vec<SubKind>,
+    pub rule: RollRule,
+    /// Stitch a continuous-contract stream by only distributing the [`MarketEvent`]s of the
+    /// active [`FutureContract`], rather than those of the active & next [`FutureContract`].
+    pub continuous: bool,
+}
+
+impl Validator for &RollingFutures {
+    fn validate(self) -> Result<Self, SocketError>
+    where
+        Self: Sized,
+    {
+        if !self.exchange.supports_dated_futures() {
+            return Err(SocketError::Unsupported {
+                entity: self.exchange.as_str(),
+                item: "dated futures".to_owned(),
+            });
+        }
+
+        if self.contracts.is_empty() || self.kinds.is_empty() {
+            return Err(SocketError::Subscribe(format!(
+                "{} RollingFutures requires at least one FutureContract & SubKind",
+                self.exchange
+            )));
+        }
+
+        if matches!(self.rule, RollRule::Volume(_)) && !self.kinds.contains(&SubKind::Trade) {
+            return Err(SocketError::Subscribe(format!(
+                "{} RollingFutures w/ RollRule::Volume requires a SubKind::Trade",
+                self.exchange
+            )));
+        }
+
+        // Validate the Subscriptions of every FutureContract
+        for contract in &self.contracts {
+            let instrument = Instrument::from(contract);
+            for kind in &self.kinds {
+                Subscription {
+                    exchange: self.exchange,
+                    instrument: instrument.clone(),
+                    kind: *kind,
+                }
+                .validate()?;
+            }
+        }
+
+        Ok(self)
+    }
+}
+
+/// Rolls [`RollingFutures`] from the active [`FutureContract`] to the next according to the
+/// configured [`RollRule`], tracking which [`MarketEvent`]s should be distributed.
+#[derive(Clone, PartialEq, Debug)]
+pub struct RollManager {
+    futures: RollingFutures,
+    active: usize,
+    /// Trades (exchange time & quantity) of the active & next [`FutureContract`] within the
+    /// [`RollRule::Volume`] window.
+    trades: [VecDeque<(DateTime<Utc>, f64)>; 2],
+}
+
+impl RollManager {
+    /// Construct a new [`RollManager`] with the earliest expiring [`FutureContract`] active.
+    pub fn new(mut futures: RollingFutures) -> Self {
+        futures.contracts.sort_by_key(|contract| contract.expiry);
+        futures.contracts.dedup();
+
+        Self {
+            futures,
+            active: 0,
+            trades: [VecDeque::new(), VecDeque::new()],
+        }
+    }
+
+    /// Active [`FutureContract`].
+    pub fn active(&self) -> &FutureContract {
+        &self.futures.contracts[self.active]
+    }
+
+    /// [`FutureContract`] rolled into next, if any.
+    pub fn next(&self) -> Option<&FutureContract> {
+        self.futures.contracts.get(self.active + 1)
+    }
+
+    /// [`Subscription`]s for every configured [`SubKind`] of the active & next
+    /// [`FutureContract`].
+    pub fn subscriptions(&self) -> Vec<Subscription> {
+        std::iter::once(self.active())
+            .chain(self.next())
+            .flat_map(|contract| {
+                let instrument = Instrument::from(contract);
+                self.futures.kinds.iter().map(move |kind| Subscription {
+                    exchange: self.futures.exchange,
+                    instrument: instrument.clone(),
+                    kind: *kind,
+                })
+            })
+            .collect()
+    }
+
+    /// Determines if the provided [`MarketEvent`] should be distributed.
+    pub fn forwards(&self, event: &MarketEvent) -> bool {
+        !self.futures.continuous || event.instrument == Instrument::from(self.active())
+    }
+
+    /// Update the [`RollRule`] state with the provided [`MarketEvent`], rolling to the next
+    /// [`FutureContract`] if required. Returns the [`Rollover`] if a roll occurred.
+    pub fn update(&mut self, event: &MarketEvent) -> Option<Rollover> {
+        let next = Instrument::from(self.next()?);
+        let active = Instrument::from(self.active());
+        let expiry_time = self.active().expiry_time(self.futures.exchange)?;
+
+        let roll = match self.futures.rule {
+            RollRule::Date(before_expiry) => event.exchange_time >= expiry_time - before_expiry,
+            RollRule::Volume(window) => {
+                if let DataKind::Trade(trade) = &event.kind {
+                    if event.instrument == active {
+                        self.trades[0].push_back((event.exchange_time, trade.quantity));
+                    } else if event.instrument == next {
+                        self.trades[1].push_back((event.exchange_time, trade.quantity));
+                    }
+                }
+
+                let start = event.exchange_time - window;
+                let [active_volume, next_volume] = self
+                    .trades
+                    .each_mut()
+                    .map(|trades| window_volume(trades, start));
+
+                next_volume > active_volume || event.exchange_time >= expiry_time
+            }
+        };
+
+        if !roll {
+            return None;
+        }
+
+        self.active += 1;
+        self.trades = [std::mem::take(&mut self.trades[1]), VecDeque::new()];
+        Some(Rollover {
+            from: active,
+            to: next,
+        })
+    }
+}
+
+/// Remove the trades before the provided window `start`, returning the volume of those remaining.
+fn window_volume(trades: &mut VecDeque<(DateTime<Utc>, f64)>, start: DateTime<Utc>) -> f64 {
+    while trades.front().is_some_and(|(time, _)| *time < start) {
+        trades.pop_front();
+    }
+    trades.iter().map(|(_, quantity)| quantity).sum()
+}
+
+/// Consume the provided [`RollingFutures`], distributing the [`MarketEvent`]s of the active &
+/// next [`FutureContract`] downstream via the `exchange_tx`, along with a [`DataKind::Rollover`]
+/// [`MarketEvent`] after every roll. Distributed [`Event`]s are re-sequenced.
+///
+/// The `spawn` closure is used to spawn a consumer of the provided [`Subscription`]s. After
+/// every roll the previous consumer is aborted, and a consumer subscribed to the new active &
+/// next [`FutureContract`] is spawned.
+///
+/// The [`SubscriptionControl`] is applied to the distributed [`MarketEvent`]s, so the spawned
+/// consumers should not be paused.
+///
+/// Runs until the consumer or the `exchange_tx` receiver is dropped.
+pub async fn roll_futures<Spawn>(
+    futures: RollingFutures,
+    mut spawn: Spawn,
+    exchange_tx: mpsc::UnboundedSender<Event<MarketEvent>>,
+    control: SubscriptionControl,
+) where
+    Spawn: FnMut(
+        Vec<Subscription>,
+        mpsc::UnboundedSender<Event<MarketEvent>>,
+    ) -> JoinHandle<SocketError>,
+{
+    let exchange = futures.exchange;
+    let mut manager = RollManager::new(futures);
+
+    let (contract_tx, mut contract_rx) = mpsc::unbounded_channel();
+    let mut consumer = spawn(manager.subscriptions(), contract_tx);
+    let mut sequence = 0;
+
+    while let Some(Event { payload, .. }) = contract_rx.recv().await {
+        let forward = manager.forwards(&payload);
+        let rollover = manager.update(&payload).map(|rollover| MarketEvent {
+            exchange_time: payload.exchange_time,
+            received_time: payload.received_time,
+            received_instant: None,
+            raw: None,
+            exchange: payload.exchange.clone(),
+            instrument: rollover.to.clone(),
+            kind: DataKind::Rollover(rollover),
+        });
+
+        // Subscribe to the new active & next FutureContract, discarding any MarketEvents of the
+        // previous consumer that have not yet been received
+        if let Some(rollover) = &rollover {
+            info!(%exchange, ?rollover, "rolled FutureContract");
+            consumer.abort();
+            let (contract_tx, rx) = mpsc::unbounded_channel();
+            contract_rx = rx;
+            consumer = spawn(manager.subscriptions(), contract_tx);
+        }
+
+        for payload in forward.then_some(payload).into_iter().chain(rollover) {
+            if control.is_paused(exchange, &payload) {
+                continue;
+            }
+            if exchange_tx.send(Event { sequence, payload }).is_err() {
+                consumer.abort();
+                return;
+            }
+            sequence += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::PublicTrade;
+    use barter_integration::model::{Exchange, Side};
+    use chrono::NaiveDate;
+
+    fn contract(expiry: &str) -> FutureContract {
+        FutureContract {
+            underlying: "btc".to_owned(),
+            quote: "usd".to_owned(),
+            expiry: NaiveDate::parse_from_str(expiry, "%Y-%m-%d").unwrap(),
+        }
+    }
+
+    fn rolling(rule: RollRule, continuous: bool) -> RollingFutures {
+        RollingFutures {
+            exchange: ExchangeId::BinanceFuturesCoin,
+            contracts: vec![contract("2024-09-27"), contract("2024-06-28")],
+            kinds: vec![SubKind::Trade],
+            rule,
+            continuous,
+        }
+    }
+
+    fn trade(expiry: &str, time: &str, quantity: f64) -> MarketEvent {
+        let time = time.parse::<DateTime<Utc>>().unwrap();
+        MarketEvent {
+            exchange_time: time,
+            received_time: time,
+            received_instant: None,
+            raw: None,
+            exchange: Exchange::from(ExchangeId::BinanceFuturesCoin),
+            instrument: Instrument::from(&contract(expiry)),
+            kind: DataKind::Trade(PublicTrade {
+                id: "id".to_owned(),
+                price: 100.0,
+                quantity,
+                side: Side::Buy,
+            }),
+        }
+    }
+
+    fn rollover(from: &str, to: &str) -> Rollover {
+        Rollover {
+            from: Instrument::from(&contract(from)),
+            to: Instrument::from(&contract(to)),
+        }
+    }
+
+    #[test]
+    fn test_rolling_futures_validate() {
+        struct TestCase {
+            input: RollingFutures,
+            expected: Result<(), SocketError>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: Valid RollingFutures w/ BinanceFuturesCoin
+                input: rolling(RollRule::Volume(Duration::hours(1)), false),
+                expected: Ok(()),
+            },
+            TestCase {
+                // TC1: Invalid RollingFutures w/ exchange without dated futures
+                input: RollingFutures {
+                    exchange: ExchangeId::BinanceFuturesUsd,
+                    ..rolling(RollRule::Date(Duration::days(1)), false)
+                },
+                expected: Err(SocketError::Subscribe("".to_owned())),
+            },
+            TestCase {
+                // TC2: Invalid RollingFutures w/o FutureContracts
+                input: RollingFutures {
+                    contracts: vec![],
+                    ..rolling(RollRule::Date(Duration::days(1)), false)
+                },
+                expected: Err(SocketError::Subscribe("".to_owned())),
+            },
+            TestCase {
+                // TC3: Invalid RollingFutures w/ RollRule::Volume w/o SubKind::Trade
+                input: RollingFutures {
+                    kinds: vec![SubKind::MarkPrice],
+                    ..rolling(RollRule::Volume(Duration::hours(1)), false)
+                },
+                expected: Err(SocketError::Subscribe("".to_owned())),
+            },
+            TestCase {
+                // TC4: Invalid RollingFutures w/ unsupported SubKind
+                input: RollingFutures {
+                    kinds: vec![SubKind::OrderBookL3Delta],
+                    ..rolling(RollRule::Date(Duration::days(1)), false)
+                },
+                expected: Err(SocketError::Subscribe("".to_owned())),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = test.input.validate();
+            match (actual, test.expected) {
+                (Ok(_), Ok(_)) | (Err(_), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_roll_manager_update() {
+        struct TestCase {
+            input: MarketEvent,
+            expected: Option<Rollover>,
+        }
+
+        let runs = vec![
+            (
+                RollManager::new(rolling(RollRule::Date(Duration::days(2)), false)),
+                vec![
+                    TestCase {
+                        // TC0: active contract trade before the roll date
+                        input: trade("2024-06-28", "2024-06-25T12:00:00Z", 1.0),
+                        expected: None,
+                    },
+                    TestCase {
+                        // TC1: active contract trade just before 2 days of 08:00 UTC expiry
+                        input: trade("2024-06-28", "2024-06-26T07:59:59Z", 1.0),
+                        expected: None,
+                    },
+                    TestCase {
+                        // TC2: active contract trade within 2 days of 08:00 UTC expiry rolls
+                        input: trade("2024-06-28", "2024-06-26T08:00:00Z", 1.0),
+                        expected: Some(rollover("2024-06-28", "2024-09-27")),
+                    },
+                    TestCase {
+                        // TC3: last contract never rolls
+                        input: trade("2024-09-27", "2024-09-27T00:00:00Z", 1.0),
+                        expected: None,
+                    },
+                ],
+            ),
+            (
+                RollManager::new(rolling(RollRule::Volume(Duration::hours(1)), false)),
+                vec![
+                    TestCase {
+                        // TC4: active contract trade
+                        input: trade("2024-06-28", "2024-06-01T00:00:00Z", 2.0),
+                        expected: None,
+                    },
+                    TestCase {
+                        // TC5: next contract volume does not exceed active contract volume
+                        input: trade("2024-09-27", "2024-06-01T00:00:01Z", 2.0),
+                        expected: None,
+                    },
+                    TestCase {
+                        // TC6: next contract volume exceeds active contract volume
+                        input: trade("2024-09-27", "2024-06-01T00:00:02Z", 0.5),
+                        expected: Some(rollover("2024-06-28", "2024-09-27")),
+                    },
+                ],
+            ),
+            (
+                RollManager::new(rolling(RollRule::Volume(Duration::hours(1)), false)),
+                vec![
+                    TestCase {
+                        // TC7: active contract trade w/ volume expiring from the window
+                        input: trade("2024-06-28", "2024-06-01T00:00:00Z", 5.0),
+                        expected: None,
+                    },
+                    TestCase {
+                        // TC8: next contract out-trades the active contract within the window
+                        input: trade("2024-09-27", "2024-06-01T01:30:00Z", 1.0),
+                        expected: Some(rollover("2024-06-28", "2024-09-27")),
+                    },
+                ],
+            ),
+        ];
+
+        let mut index = 0;
+        for (mut manager, cases) in runs {
+            for test in cases {
+                let actual = manager.update(&test.input);
+                assert_eq!(actual, test.expected, "TC{} failed", index);
+                index += 1;
+            }
+        }
+    }
+
+    #[test]
+    fn test_roll_manager_volume_rolls_once_per_crossover() {
+        let mut manager = RollManager::new(RollingFutures {
+            contracts: vec![
+                contract("2024-12-27"),
+                contract("2024-06-28"),
+                contract("2024-09-27"),
+            ],
+            ..rolling(RollRule::Volume(Duration::hours(1)), false)
+        });
+
+        let trades = [
+            trade("2024-06-28", "2024-06-01T00:00:00Z", 5.0),
+            trade("2024-09-27", "2024-06-01T00:00:01Z", 3.0),
+            // First crossover: 2024-09-27 volume 6.0 exceeds 2024-06-28 volume 5.0
+            trade("2024-09-27", "2024-06-01T00:00:02Z", 3.0),
+            // First trade of the new next contract does not exceed the 6.0 of the active contract
+            trade("2024-12-27", "2024-06-01T00:00:03Z", 1.0),
+            trade("2024-09-27", "2024-06-01T00:00:04Z", 1.0),
+            trade("2024-12-27", "2024-06-01T00:00:05Z", 2.0),
+            // Second crossover: 2024-12-27 volume 7.5 exceeds 2024-09-27 volume 7.0
+            trade("2024-12-27", "2024-06-01T00:00:06Z", 4.5),
+            trade("2024-12-27", "2024-06-01T00:00:07Z", 10.0),
+        ];
+
+        let actual = trades
+            .iter()
+            .enumerate()
+            .filter_map(|(index, trade)| manager.update(trade).map(|rollover| (index, rollover)))
+            .collect::<Vec<_>>();
+
+        let expected = vec![
+            (2, rollover("2024-06-28", "2024-09-27")),
+            (6, rollover("2024-09-27", "2024-12-27")),
+        ];
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_roll_manager_subscriptions_and_forwards() {
+        let mut manager = RollManager::new(rolling(RollRule::Date(Duration::days(2)), true));
+
+        let subscriptions = |expiries: [&str; 2]| {
+            expiries
+                .into_iter()
+                .map(|expiry| Subscription {
+                    exchange: ExchangeId::BinanceFuturesCoin,
+                    instrument: Instrument::from(&contract(expiry)),
+                    kind: SubKind::Trade,
+                })
+                .collect::<Vec<_>>()
+        };
+
+        // Earliest expiring contract is active, & only it's MarketEvents are forwarded
+        assert_eq!(
+            manager.subscriptions(),
+            subscriptions(["2024-06-28", "2024-09-27"])
+        );
+        assert!(manager.forwards(&trade("2024-06-28", "2024-06-01T00:00:00Z", 1.0)));
+        assert!(!manager.forwards(&trade("2024-09-27", "2024-06-01T00:00:00Z", 1.0)));
+
+        // After rolling, the next contract is active & the expired contract is not subscribed
+        manager.update(&trade("2024-06-28", "2024-06-27T00:00:00Z", 1.0));
+        assert_eq!(manager.active(), &contract("2024-09-27"));
+        assert_eq!(manager.next(), None);
+        assert_eq!(manager.subscriptions().len(), 1);
+        assert!(manager.forwards(&trade("2024-09-27", "2024-06-27T00:00:00Z", 1.0)));
+    }
+
+    #[tokio::test]
+    async fn test_roll_futures() {
+        let (exchange_tx, mut exchange_rx) = mpsc::unbounded_channel();
+        let (consumer_tx, mut consumer_rx) = mpsc::unbounded_channel();
+
+        // Each spawned consumer provides the test with it's Subscriptions & event transmitter
+        let spawn = move |subscriptions: Vec<Subscription>, tx| {
+            consumer_tx.send((subscriptions, tx)).unwrap();
+            tokio::spawn(std::future::pending::<SocketError>())
+        };
+
+        tokio::spawn(roll_futures(
+            rolling(RollRule::Date(Duration::days(2)), true),
+            spawn,
+            exchange_tx,
+            SubscriptionControl::default(),
+        ));
+
+        let (subscriptions, tx) = consumer_rx.recv().await.unwrap();
+        assert_eq!(subscriptions.len(), 2);
+        for payload in [
+            trade("2024-06-28", "2024-06-25T00:00:00Z", 1.0),
+            trade("2024-09-27", "2024-06-25T00:00:01Z", 1.0),
+            trade("2024-06-28", "2024-06-26T08:00:00Z", 1.0),
+        ] {
+            tx.send(Event {
+                sequence: 0,
+                payload,
+            })
+            .unwrap();
+        }
+
+        // Rolling spawns a consumer subscribed to the new active contract only
+        let (subscriptions, tx) = consumer_rx.recv().await.unwrap();
+        assert_eq!(
+            subscriptions,
+            vec![Subscription {
+                exchange: ExchangeId::BinanceFuturesCoin,
+                instrument: Instrument::from(&contract("2024-09-27")),
+                kind: SubKind::Trade,
+            }]
+        );
+        tx.send(Event {
+            sequence: 0,
+            payload: trade("2024-09-27", "2024-06-26T00:00:01Z", 1.0),
+        })
+        .unwrap();
+
+        let mut actual = Vec::new();
+        while actual.len() < 4 {
+            let event = exchange_rx.recv().await.unwrap();
+            actual.push((event.sequence, event.payload.instrument, event.payload.kind));
+        }
+
+        let expected = vec![
+            (
+                0,
+                Instrument::from(&contract("2024-06-28")),
+                trade("2024-06-28", "2024-06-25T00:00:00Z", 1.0).kind,
+            ),
+            (
+                1,
+                Instrument::from(&contract("2024-06-28")),
+                trade("2024-06-28", "2024-06-26T08:00:00Z", 1.0).kind,
+            ),
+            (
+                2,
+                Instrument::from(&contract("2024-09-27")),
+                DataKind::Rollover(rollover("2024-06-28", "2024-09-27")),
+            ),
+            (
+                3,
+                Instrument::from(&contract("2024-09-27")),
+                trade("2024-09-27", "2024-06-26T00:00:01Z", 1.0).kind,
+            ),
+        ];
+
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,167 @@
+use crate::{model::MarketEvent, MarketStream, Subscription};
+use async_trait::async_trait;
+use barter_integration::{error::SocketError, model::Instrument, Event};
+use futures::Stream;
+use std::{
+    collections::{HashMap, VecDeque},
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// Default maximum number of ready [`MarketEvent`]s a [`FairScheduler`] drains from the inner
+/// [`MarketStream`] before interleaving them.
+pub const DEFAULT_MAX_BURST: usize = 1024;
+
+/// [`MarketStream`] wrapper that smooths pathological exchange bursts (eg/ thousands of messages
+/// in one TCP read) by draining every ready [`MarketEvent`] from the inner stream and routing
+/// them round-robin across [`Instrument`]s, preventing one symbol from starving others on a
+/// shared connection.
+///
+/// The order of [`MarketEvent`]s for each [`Instrument`] is preserved, and yielded [`Event`]s are
+/// re-sequenced in delivery order. [`SocketError`]s are yielded as soon as they are drained.
+#[derive(Debug)]
+pub struct FairScheduler<InnerStream> {
+    inner: InnerStream,
+    max_burst: usize,
+    queues: HashMap<Instrument, VecDeque<MarketEvent>>,
+    ready: VecDeque<Instrument>,
+    errors: VecDeque<SocketError>,
+    sequence: u64,
+    finished: bool,
+}
+
+#[async_trait]
+impl<InnerStream> MarketStream for FairScheduler<InnerStream>
+where
+    InnerStream: MarketStream + Send,
+{
+    async fn init(subscriptions: &[Subscription]) -> Result<Self, SocketError> {
+        InnerStream::init(subscriptions).await.map(Self::new)
+    }
+}
+
+impl<InnerStream> FairScheduler<InnerStream> {
+    /// Construct a new [`FairScheduler`] wrapping the provided stream, draining up to
+    /// [`DEFAULT_MAX_BURST`] ready [`MarketEvent`]s at a time.
+    pub fn new(inner: InnerStream) -> Self {
+        Self::with_max_burst(inner, DEFAULT_MAX_BURST)
+    }
+
+    /// Construct a new [`FairScheduler`] wrapping the provided stream, draining up to
+    /// `max_burst` ready [`MarketEvent`]s at a time.
+    pub fn with_max_burst(inner: InnerStream, max_burst: usize) -> Self {
+        Self {
+            inner,
+            max_burst: max_burst.max(1),
+            queues: HashMap::new(),
+            ready: VecDeque::new(),
+            errors: VecDeque::new(),
+            sequence: 0,
+            finished: false,
+        }
+    }
+
+    /// Queue a drained [`MarketEvent`] behind any others for the same [`Instrument`].
+    fn enqueue(&mut self, event: MarketEvent) {
+        let queue = self.queues.entry(event.instrument.clone()).or_default();
+        if queue.is_empty() {
+            self.ready.push_back(event.instrument.clone());
+        }
+        queue.push_back(event);
+    }
+
+    /// Dequeue the next [`MarketEvent`] from the next [`Instrument`] in round-robin order.
+    fn dequeue(&mut self) -> Option<MarketEvent> {
+        let instrument = self.ready.pop_front()?;
+        let queue = self.queues.get_mut(&instrument)?;
+        let event = queue.pop_front();
+        if !queue.is_empty() {
+            self.ready.push_back(instrument);
+        }
+        event
+    }
+}
+
+impl<InnerStream> Stream for FairScheduler<InnerStream>
+where
+    InnerStream: Stream<Item = Result<Event<MarketEvent>, SocketError>> + Unpin,
+{
+    type Item = Result<Event<MarketEvent>, SocketError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        // Drain every ready MarketEvent from the inner stream, up to the max_burst
+        let mut drained = 0;
+        while !self.finished && drained < self.max_burst {
+            match Pin::new(&mut self.inner).poll_next(cx) {
+                Poll::Ready(Some(Ok(event))) => self.enqueue(event.payload),
+                Poll::Ready(Some(Err(error))) => self.errors.push_back(error),
+                Poll::Ready(None) => self.finished = true,
+                Poll::Pending => break,
+            }
+            drained += 1;
+        }
+
+        if let Some(error) = self.errors.pop_front() {
+            return Poll::Ready(Some(Err(error)));
+        }
+
+        if let Some(payload) = self.dequeue() {
+            let sequence = self.sequence;
+            self.sequence += 1;
+            return Poll::Ready(Some(Ok(Event { sequence, payload })));
+        }
+
+        match self.finished {
+            true => Poll::Ready(None),
+            false => Poll::Pending,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::market_trade;
+    use barter_integration::model::{InstrumentKind, Side};
+    use futures::StreamExt;
+
+    fn trade(base: &str) -> Result<Event<MarketEvent>, SocketError> {
+        let mut payload = market_trade(Side::Buy);
+        payload.instrument = Instrument::from((base, "usdt", InstrumentKind::Spot));
+        Ok(Event {
+            sequence: 0,
+            payload,
+        })
+    }
+
+    #[tokio::test]
+    async fn test_fair_scheduler_interleaves_instruments() {
+        let burst = futures::stream::iter(vec![
+            trade("btc"),
+            trade("btc"),
+            trade("btc"),
+            trade("eth"),
+            trade("eth"),
+            trade("sol"),
+        ]);
+
+        let actual = FairScheduler::new(burst)
+            .map(|event| {
+                let event = event.unwrap();
+                (event.sequence, event.payload.instrument.base.to_string())
+            })
+            .collect::<Vec<_>>()
+            .await;
+
+        let expected = vec![
+            (0, "btc".to_owned()),
+            (1, "eth".to_owned()),
+            (2, "sol".to_owned()),
+            (3, "btc".to_owned()),
+            (4, "eth".to_owned()),
+            (5, "btc".to_owned()),
+        ];
+
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,532 @@
+use crate::model::{DataKind, MarketEvent, OrderBookL3Delta, OrderBookL3DeltaKind, PublicTrade};
+use barter_integration::model::Side;
+use serde::{Deserialize, Serialize};
+use std::{
+    cmp::Ordering,
+    collections::{BTreeMap, HashMap, VecDeque},
+};
+
+/// Hypothetical order submitted to a [`FillSimulator`].
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct SimulatedOrder {
+    pub id: u64,
+    pub side: Side,
+    pub kind: SimulatedOrderKind,
+    pub quantity: f64,
+}
+
+/// Type of [`SimulatedOrder`].
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub enum SimulatedOrderKind {
+    /// Fills immediately against the book, with any unfilled quantity discarded.
+    Market,
+    /// Fills immediately against the book up to the limit price, with any unfilled quantity
+    /// joining the back of the queue at the limit price.
+    Limit { price: f64 },
+}
+
+/// Simulated fill of a [`SimulatedOrder`] emitted by a [`FillSimulator`].
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct SimulatedFill {
+    pub order_id: u64,
+    pub side: Side,
+    pub price: f64,
+    pub quantity: f64,
+    /// Quantity of the [`SimulatedOrder`] still resting in the book after this fill.
+    pub remaining: f64,
+    pub liquidity: Liquidity,
+}
+
+/// Whether a [`SimulatedFill`] added or removed liquidity from the book.
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub enum Liquidity {
+    Maker,
+    Taker,
+}
+
+/// Paper-trading engine that matches [`SimulatedOrder`]s against a live L3 book maintained from
+/// [`OrderBookL3Delta`] [`MarketEvent`]s.
+///
+/// Resting [`SimulatedOrder`]s join the back of the queue at their price & track their virtual
+/// queue position as the exchange orders ahead of them are changed or deleted. Incoming
+/// [`PublicTrade`]s are matched against each price level queue in time priority, filling a
+/// [`SimulatedOrder`] once the traded quantity exceeds the exchange quantity ahead of it. Any
+/// [`SimulatedOrder`] priced better than a trade is assumed to have been traded through, and is
+/// filled completely.
+///
+/// [`SimulatedOrder`]s never consume exchange liquidity, so this is most faithful for orders that
+/// are small relative to the book.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct FillSimulator {
+    bids: BTreeMap<Price, VecDeque<QueueEntry>>,
+    asks: BTreeMap<Price, VecDeque<QueueEntry>>,
+    exchange_orders: HashMap<String, (Side, Price)>,
+    simulated_orders: HashMap<u64, (Side, Price)>,
+}
+
+/// Price level key ordered using [`f64::total_cmp`].
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct Price(f64);
+
+impl Eq for Price {}
+
+impl PartialOrd for Price {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Price {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+/// Exchange or simulated order queued at a price level.
+#[derive(Clone, PartialEq, Debug)]
+enum QueueEntry {
+    Exchange { id: String, quantity: f64 },
+    Simulated { id: u64, quantity: f64 },
+}
+
+impl FillSimulator {
+    /// Construct a new empty [`FillSimulator`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Submit a [`SimulatedOrder`], returning any [`SimulatedFill`]s generated by immediately
+    /// matching it against the book.
+    pub fn submit(&mut self, order: SimulatedOrder) -> Vec<SimulatedFill> {
+        let limit = match order.kind {
+            SimulatedOrderKind::Market => None,
+            SimulatedOrderKind::Limit { price } => Some(price),
+        };
+
+        // Take liquidity from the opposite side of the book, best price first
+        let opposite = match order.side {
+            Side::Buy => &self.asks,
+            Side::Sell => &self.bids,
+        };
+        let levels: Box<dyn Iterator<Item = (&Price, &VecDeque<QueueEntry>)>> = match order.side {
+            Side::Buy => Box::new(opposite.iter()),
+            Side::Sell => Box::new(opposite.iter().rev()),
+        };
+
+        let mut remaining = order.quantity;
+        let mut fills = Vec::new();
+        for (price, queue) in levels {
+            let crosses = limit.is_none_or(|limit| match order.side {
+                Side::Buy => price.0 <= limit,
+                Side::Sell => price.0 >= limit,
+            });
+            if remaining <= 0.0 || !crosses {
+                break;
+            }
+
+            let available = queue
+                .iter()
+                .map(|entry| match entry {
+                    QueueEntry::Exchange { quantity, .. } => *quantity,
+                    QueueEntry::Simulated { .. } => 0.0,
+                })
+                .sum::<f64>();
+            let quantity = available.min(remaining);
+            if quantity > 0.0 {
+                remaining -= quantity;
+                fills.push((price.0, quantity));
+            }
+        }
+
+        let resting = match limit {
+            Some(_) => remaining,
+            None => 0.0,
+        };
+        let mut fills = fills
+            .into_iter()
+            .map(|(price, quantity)| SimulatedFill {
+                order_id: order.id,
+                side: order.side,
+                price,
+                quantity,
+                remaining: 0.0,
+                liquidity: Liquidity::Taker,
+            })
+            .collect::<Vec<_>>();
+        if let Some(last) = fills.last_mut() {
+            last.remaining = resting;
+        }
+
+        // Rest any unfilled limit order quantity at the back of the queue
+        if let Some(price) = limit.filter(|_| resting > 0.0) {
+            let price = Price(price);
+            self.side_mut(order.side)
+                .entry(price)
+                .or_default()
+                .push_back(QueueEntry::Simulated {
+                    id: order.id,
+                    quantity: resting,
+                });
+            self.simulated_orders.insert(order.id, (order.side, price));
+        }
+
+        fills
+    }
+
+    /// Cancel a resting [`SimulatedOrder`], returning `true` if it was found.
+    pub fn cancel(&mut self, id: u64) -> bool {
+        match self.simulated_orders.remove(&id) {
+            Some((side, price)) => {
+                self.remove_entry(side, price, |entry| {
+                    matches!(entry, QueueEntry::Simulated { id: queued, .. } if *queued == id)
+                });
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Return the exchange quantity queued ahead of a resting [`SimulatedOrder`].
+    pub fn queue_position(&self, id: u64) -> Option<f64> {
+        let (side, price) = self.simulated_orders.get(&id)?;
+        let queue = self.side(*side).get(price)?;
+
+        let mut ahead = 0.0;
+        for entry in queue {
+            match entry {
+                QueueEntry::Simulated { id: queued, .. } if *queued == id => return Some(ahead),
+                QueueEntry::Exchange { quantity, .. } => ahead += quantity,
+                QueueEntry::Simulated { .. } => {}
+            }
+        }
+
+        None
+    }
+
+    /// Update the book using the provided [`MarketEvent`], returning any [`SimulatedFill`]s of
+    /// resting [`SimulatedOrder`]s generated by [`PublicTrade`] flow.
+    pub fn update(&mut self, event: &MarketEvent) -> Vec<SimulatedFill> {
+        match &event.kind {
+            DataKind::OrderBookL3Delta(delta) => {
+                self.apply(delta);
+                vec![]
+            }
+            DataKind::Trade(trade) => self.match_trade(trade),
+            _ => vec![],
+        }
+    }
+
+    /// Apply an [`OrderBookL3Delta`] to the maintained L3 book.
+    fn apply(&mut self, delta: &OrderBookL3Delta) {
+        // Changes & deletes first remove the existing order from its queue
+        let previous = match delta.kind {
+            OrderBookL3DeltaKind::Open => None,
+            OrderBookL3DeltaKind::Change | OrderBookL3DeltaKind::Delete => {
+                self.exchange_orders.remove(&delta.id)
+            }
+        };
+
+        let price = Price(delta.price);
+        match (delta.kind, previous) {
+            // A change that keeps the price & side only updates the quantity, keeping priority
+            (OrderBookL3DeltaKind::Change, Some((side, previous_price)))
+                if side == delta.side && previous_price == price =>
+            {
+                if let Some(QueueEntry::Exchange { quantity, .. }) = self
+                    .side_mut(side)
+                    .get_mut(&price)
+                    .and_then(|queue| queue.iter_mut().find(|entry| entry.is_exchange(&delta.id)))
+                {
+                    *quantity = delta.quantity;
+                }
+                self.exchange_orders.insert(delta.id.clone(), (side, price));
+            }
+            (OrderBookL3DeltaKind::Delete, Some((side, previous_price))) => {
+                self.remove_entry(side, previous_price, |entry| entry.is_exchange(&delta.id));
+            }
+            (OrderBookL3DeltaKind::Delete, None) => {}
+            (_, previous) => {
+                if let Some((side, previous_price)) = previous {
+                    self.remove_entry(side, previous_price, |entry| entry.is_exchange(&delta.id));
+                }
+                self.side_mut(delta.side)
+                    .entry(price)
+                    .or_default()
+                    .push_back(QueueEntry::Exchange {
+                        id: delta.id.clone(),
+                        quantity: delta.quantity,
+                    });
+                self.exchange_orders
+                    .insert(delta.id.clone(), (delta.side, price));
+            }
+        }
+    }
+
+    /// Match a [`PublicTrade`] against the resting [`SimulatedOrder`]s on the passive side.
+    fn match_trade(&mut self, trade: &PublicTrade) -> Vec<SimulatedFill> {
+        // Aggressive buys take liquidity from asks, and aggressive sells from bids
+        let passive = match trade.side {
+            Side::Buy => Side::Sell,
+            Side::Sell => Side::Buy,
+        };
+        let trade_price = Price(trade.price);
+
+        let mut fills = Vec::new();
+        let mut filled = Vec::new();
+        for (price, queue) in self.side_mut(passive).iter_mut() {
+            let traded_through = match passive {
+                Side::Sell => *price < trade_price,
+                Side::Buy => *price > trade_price,
+            };
+            if !traded_through && *price != trade_price {
+                continue;
+            }
+
+            let mut budget = trade.quantity;
+            for entry in queue.iter_mut() {
+                match entry {
+                    QueueEntry::Exchange { quantity, .. } if !traded_through => {
+                        budget -= *quantity;
+                    }
+                    QueueEntry::Exchange { .. } => {}
+                    QueueEntry::Simulated { id, quantity } => {
+                        let fill = match traded_through {
+                            true => *quantity,
+                            false => budget.max(0.0).min(*quantity),
+                        };
+                        if fill <= 0.0 {
+                            continue;
+                        }
+                        budget -= fill;
+                        *quantity -= fill;
+                        fills.push(SimulatedFill {
+                            order_id: *id,
+                            side: passive,
+                            price: price.0,
+                            quantity: fill,
+                            remaining: *quantity,
+                            liquidity: Liquidity::Maker,
+                        });
+                        if *quantity <= 0.0 {
+                            filled.push(*id);
+                        }
+                    }
+                }
+            }
+        }
+
+        filled.into_iter().for_each(|id| {
+            self.cancel(id);
+        });
+
+        fills
+    }
+
+    /// Remove the first queue entry at the price level matching the provided predicate.
+    fn remove_entry<F>(&mut self, side: Side, price: Price, predicate: F)
+    where
+        F: Fn(&QueueEntry) -> bool,
+    {
+        let levels = self.side_mut(side);
+        if let Some(queue) = levels.get_mut(&price) {
+            if let Some(index) = queue.iter().position(predicate) {
+                queue.remove(index);
+            }
+            if queue.is_empty() {
+                levels.remove(&price);
+            }
+        }
+    }
+
+    fn side(&self, side: Side) -> &BTreeMap<Price, VecDeque<QueueEntry>> {
+        match side {
+            Side::Buy => &self.bids,
+            Side::Sell => &self.asks,
+        }
+    }
+
+    fn side_mut(&mut self, side: Side) -> &mut BTreeMap<Price, VecDeque<QueueEntry>> {
+        match side {
+            Side::Buy => &mut self.bids,
+            Side::Sell => &mut self.asks,
+        }
+    }
+}
+
+impl QueueEntry {
+    fn is_exchange(&self, order_id: &str) -> bool {
+        matches!(self, QueueEntry::Exchange { id, .. } if id == order_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_util, ExchangeId};
+    use barter_integration::model::{Exchange, Instrument, InstrumentKind};
+    use chrono::Utc;
+
+    fn event(kind: DataKind) -> MarketEvent {
+        MarketEvent {
+            exchange_time: Utc::now(),
+            received_time: Utc::now(),
+            received_instant: None,
+            raw: None,
+            exchange: Exchange::from(ExchangeId::Bitstamp),
+            instrument: Instrument::from(("btc", "usd", InstrumentKind::Spot)),
+            kind,
+        }
+    }
+
+    fn l3(
+        id: &str,
+        kind: OrderBookL3DeltaKind,
+        side: Side,
+        price: f64,
+        quantity: f64,
+    ) -> MarketEvent {
+        event(DataKind::OrderBookL3Delta(OrderBookL3Delta {
+            id: id.to_owned(),
+            kind,
+            side,
+            price,
+            quantity,
+        }))
+    }
+
+    fn trade(side: Side, price: f64, quantity: f64) -> MarketEvent {
+        let mut trade = test_util::market_trade(side);
+        if let DataKind::Trade(inner) = &mut trade.kind {
+            inner.price = price;
+            inner.quantity = quantity;
+        }
+        trade
+    }
+
+    #[test]
+    fn test_fill_simulator_queue_position_and_maker_fills() {
+        let mut simulator = FillSimulator::new();
+        simulator.update(&l3("a", OrderBookL3DeltaKind::Open, Side::Sell, 101.0, 2.0));
+        simulator.update(&l3("b", OrderBookL3DeltaKind::Open, Side::Sell, 101.0, 3.0));
+
+        // Resting simulated ask joins the back of the queue
+        let fills = simulator.submit(SimulatedOrder {
+            id: 1,
+            side: Side::Sell,
+            kind: SimulatedOrderKind::Limit { price: 101.0 },
+            quantity: 1.0,
+        });
+        assert!(fills.is_empty());
+        assert_eq!(simulator.queue_position(1), Some(5.0));
+
+        // Orders ahead being changed or deleted advances the queue position
+        simulator.update(&l3(
+            "a",
+            OrderBookL3DeltaKind::Delete,
+            Side::Sell,
+            101.0,
+            0.0,
+        ));
+        simulator.update(&l3(
+            "b",
+            OrderBookL3DeltaKind::Change,
+            Side::Sell,
+            101.0,
+            2.5,
+        ));
+        assert_eq!(simulator.queue_position(1), Some(2.5));
+
+        // Trade smaller than the queue ahead does not fill
+        assert!(simulator.update(&trade(Side::Buy, 101.0, 2.0)).is_empty());
+
+        // Trade exceeding the queue ahead partially fills
+        let fills = simulator.update(&trade(Side::Buy, 101.0, 3.0));
+        assert_eq!(
+            fills,
+            vec![SimulatedFill {
+                order_id: 1,
+                side: Side::Sell,
+                price: 101.0,
+                quantity: 0.5,
+                remaining: 0.5,
+                liquidity: Liquidity::Maker,
+            }]
+        );
+
+        // Trade through the price level completely fills
+        let fills = simulator.update(&trade(Side::Buy, 102.0, 0.1));
+        assert_eq!(fills.len(), 1);
+        assert_eq!(fills[0].quantity, 0.5);
+        assert_eq!(fills[0].remaining, 0.0);
+        assert_eq!(simulator.queue_position(1), None);
+        assert!(!simulator.cancel(1));
+    }
+
+    #[test]
+    fn test_fill_simulator_taker_fills() {
+        let mut simulator = FillSimulator::new();
+        simulator.update(&l3("a", OrderBookL3DeltaKind::Open, Side::Sell, 101.0, 1.0));
+        simulator.update(&l3("b", OrderBookL3DeltaKind::Open, Side::Sell, 102.0, 1.0));
+        simulator.update(&l3("c", OrderBookL3DeltaKind::Open, Side::Buy, 100.0, 1.0));
+
+        struct TestCase {
+            input: SimulatedOrder,
+            expected: Vec<(f64, f64, f64)>,
+            expected_resting: bool,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: market buy walks the asks
+                input: SimulatedOrder {
+                    id: 1,
+                    side: Side::Buy,
+                    kind: SimulatedOrderKind::Market,
+                    quantity: 1.5,
+                },
+                expected: vec![(101.0, 1.0, 0.0), (102.0, 0.5, 0.0)],
+                expected_resting: false,
+            },
+            TestCase {
+                // TC1: crossing limit buy fills up to the limit & rests the remainder
+                input: SimulatedOrder {
+                    id: 2,
+                    side: Side::Buy,
+                    kind: SimulatedOrderKind::Limit { price: 101.0 },
+                    quantity: 1.5,
+                },
+                expected: vec![(101.0, 1.0, 0.5)],
+                expected_resting: true,
+            },
+            TestCase {
+                // TC2: market sell with insufficient liquidity discards the remainder
+                input: SimulatedOrder {
+                    id: 3,
+                    side: Side::Sell,
+                    kind: SimulatedOrderKind::Market,
+                    quantity: 2.0,
+                },
+                expected: vec![(100.0, 1.0, 0.0)],
+                expected_resting: false,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = simulator
+                .submit(test.input)
+                .into_iter()
+                .map(|fill| {
+                    assert_eq!(fill.liquidity, Liquidity::Taker, "TC{} failed", index);
+                    (fill.price, fill.quantity, fill.remaining)
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+            assert_eq!(
+                simulator.queue_position(test.input.id).is_some(),
+                test.expected_resting,
+                "TC{} failed",
+                index
+            );
+        }
+    }
+}